@@ -0,0 +1,930 @@
+//! Standalone conversions between Markdown, ADF, and Confluence storage format
+//!
+//! Markdown to ADF is [`super::jira::markdown_to_adf`]; this module adds the
+//! other directions. Markdown to storage goes through ADF, so both targets
+//! accept the same Markdown subset. Storage is parsed leniently with the
+//! HTML parser used by `md`, after code macros and `CDATA` sections are
+//! rewritten into plain HTML.
+
+use regex::Regex;
+use scraper::{ElementRef, Html, Node};
+use serde_json::Value;
+
+use super::jira::markdown_to_adf;
+
+fn children(node: &Value) -> &[Value] {
+    node.get("content")
+        .and_then(Value::as_array)
+        .map_or(&[], Vec::as_slice)
+}
+
+fn attr<'a>(node: &'a Value, name: &str) -> Option<&'a Value> {
+    node.get("attrs").and_then(|a| a.get(name))
+}
+
+fn attr_str<'a>(node: &'a Value, name: &str) -> Option<&'a str> {
+    attr(node, name).and_then(Value::as_str)
+}
+
+/// Wrap `text` in a Markdown delimiter, keeping surrounding whitespace
+/// outside it (`** bold**` would not parse).
+fn delimit(text: &str, open: &str, close: &str) -> String {
+    let trimmed = text.trim();
+    if trimmed.is_empty() {
+        return text.to_string();
+    }
+    let start = text.len() - text.trim_start().len();
+    let end = text.trim_end().len();
+    format!(
+        "{}{}{}{}{}",
+        &text[..start],
+        open,
+        trimmed,
+        close,
+        &text[end..]
+    )
+}
+
+/// Prefix the first line with `marker` and indent the rest to match.
+fn indent_item(body: &str, marker: &str) -> String {
+    let pad = " ".repeat(marker.len());
+    body.lines()
+        .enumerate()
+        .map(|(i, line)| match (i, line.is_empty()) {
+            (0, _) => format!("{}{}", marker, line),
+            (_, true) => String::new(),
+            _ => format!("{}{}", pad, line),
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn capitalize(word: &str) -> String {
+    let mut chars = word.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
+
+fn quote(body: &str) -> String {
+    body.lines()
+        .map(|line| {
+            if line.is_empty() {
+                ">".to_string()
+            } else {
+                format!("> {}", line)
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Render rows as a GitHub-flavored Markdown table; the first row is the
+/// header. `None` when there are no cells.
+pub(crate) fn markdown_table(rows: Vec<Vec<String>>) -> Option<String> {
+    let columns = rows.iter().map(Vec::len).max().filter(|c| *c > 0)?;
+    let line = |cells: &[String]| {
+        let cells: Vec<String> = (0..columns)
+            .map(|i| {
+                cells
+                    .get(i)
+                    .map_or(String::new(), |c| c.replace('|', "\\|"))
+            })
+            .collect();
+        format!("| {} |", cells.join(" | "))
+    };
+    let mut lines = vec![line(&rows[0]), format!("|{}", " --- |".repeat(columns))];
+    lines.extend(rows[1..].iter().map(|r| line(r)));
+    Some(lines.join("\n"))
+}
+
+// ---------------------------------------------------------------------------
+// ADF -> Markdown
+// ---------------------------------------------------------------------------
+
+fn adf_inline(nodes: &[Value]) -> String {
+    nodes.iter().map(adf_inline_node).collect()
+}
+
+fn adf_inline_node(node: &Value) -> String {
+    match node.get("type").and_then(Value::as_str).unwrap_or_default() {
+        "text" => {
+            let mut text = node
+                .get("text")
+                .and_then(Value::as_str)
+                .unwrap_or_default()
+                .to_string();
+            let marks = node.get("marks").and_then(Value::as_array);
+            let has = |name: &str| {
+                marks.is_some_and(|m| m.iter().any(|m| m["type"].as_str() == Some(name)))
+            };
+            if has("code") {
+                text = delimit(&text, "`", "`");
+            }
+            if has("strike") {
+                text = delimit(&text, "~~", "~~");
+            }
+            if has("em") {
+                text = delimit(&text, "*", "*");
+            }
+            if has("strong") {
+                text = delimit(&text, "**", "**");
+            }
+            let href = marks.and_then(|m| {
+                m.iter()
+                    .find(|m| m["type"].as_str() == Some("link"))
+                    .and_then(|m| m["attrs"]["href"].as_str())
+            });
+            match href {
+                Some(href) => format!("[{}]({})", text, href),
+                None => text,
+            }
+        }
+        "hardBreak" => "  \n".to_string(),
+        "mention" | "status" => attr_str(node, "text").unwrap_or_default().to_string(),
+        "emoji" => attr_str(node, "text")
+            .or_else(|| attr_str(node, "shortName"))
+            .unwrap_or_default()
+            .to_string(),
+        "inlineCard" => attr_str(node, "url").map_or(String::new(), |u| format!("<{}>", u)),
+        "date" => attr_str(node, "timestamp")
+            .and_then(|t| t.parse::<i64>().ok())
+            .and_then(chrono::DateTime::from_timestamp_millis)
+            .map_or(String::new(), |d| d.format("%Y-%m-%d").to_string()),
+        _ => adf_inline(children(node)),
+    }
+}
+
+fn adf_blocks(nodes: &[Value], separator: &str) -> String {
+    nodes
+        .iter()
+        .filter_map(adf_block)
+        .filter(|b| !b.is_empty())
+        .collect::<Vec<_>>()
+        .join(separator)
+}
+
+fn adf_list(node: &Value, ordered: bool) -> String {
+    let start = attr(node, "order").and_then(Value::as_u64).unwrap_or(1);
+    children(node)
+        .iter()
+        .enumerate()
+        .map(|(i, item)| {
+            let marker = if ordered {
+                format!("{}. ", start + i as u64)
+            } else {
+                "- ".to_string()
+            };
+            indent_item(&adf_blocks(children(item), "\n"), &marker)
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn adf_block(node: &Value) -> Option<String> {
+    let kind = node.get("type").and_then(Value::as_str)?;
+    Some(match kind {
+        "paragraph" => adf_inline(children(node)).trim().to_string(),
+        "heading" => {
+            let level = attr(node, "level").and_then(Value::as_u64).unwrap_or(1);
+            format!(
+                "{} {}",
+                "#".repeat(level.clamp(1, 6) as usize),
+                adf_inline(children(node)).trim()
+            )
+        }
+        "codeBlock" => format!(
+            "```{}\n{}\n```",
+            attr_str(node, "language").unwrap_or_default(),
+            adf_inline(children(node)).trim_end_matches('\n')
+        ),
+        "bulletList" => adf_list(node, false),
+        "orderedList" => adf_list(node, true),
+        "taskList" => children(node)
+            .iter()
+            .map(|item| {
+                let done = attr_str(item, "state") == Some("DONE");
+                let marker = if done { "- [x] " } else { "- [ ] " };
+                indent_item(adf_inline(children(item)).trim(), marker)
+            })
+            .collect::<Vec<_>>()
+            .join("\n"),
+        "blockquote" => quote(&adf_blocks(children(node), "\n\n")),
+        "panel" => {
+            let label = match attr_str(node, "panelType").unwrap_or("info") {
+                "success" => "Tip".to_string(),
+                "error" => "Warning".to_string(),
+                other => capitalize(other),
+            };
+            quote(&format!(
+                "**{}:** {}",
+                label,
+                adf_blocks(children(node), "\n\n")
+            ))
+        }
+        "rule" => "---".to_string(),
+        "expand" | "nestedExpand" => {
+            let body = adf_blocks(children(node), "\n\n");
+            match attr_str(node, "title").filter(|t| !t.is_empty()) {
+                Some(title) => format!("**{}**\n\n{}", title, body),
+                None => body,
+            }
+        }
+        "table" => markdown_table(
+            children(node)
+                .iter()
+                .map(|row| {
+                    children(row)
+                        .iter()
+                        .map(|cell| adf_blocks(children(cell), " ").replace('\n', " "))
+                        .collect()
+                })
+                .collect(),
+        )?,
+        "mediaSingle" | "mediaGroup" => children(node)
+            .iter()
+            .filter(|m| attr_str(m, "type") == Some("external"))
+            .filter_map(|m| {
+                let url = attr_str(m, "url")?;
+                Some(format!(
+                    "![{}]({})",
+                    attr_str(m, "alt").unwrap_or_default(),
+                    url
+                ))
+            })
+            .collect::<Vec<_>>()
+            .join("\n"),
+        _ if node.get("text").is_some() => adf_inline_node(node),
+        _ => {
+            let nested = children(node);
+            if nested.iter().any(adf_is_block) {
+                adf_blocks(nested, "\n\n")
+            } else {
+                adf_inline(nested).trim().to_string()
+            }
+        }
+    })
+}
+
+fn adf_is_block(node: &Value) -> bool {
+    matches!(
+        node.get("type").and_then(Value::as_str),
+        Some(
+            "paragraph"
+                | "heading"
+                | "codeBlock"
+                | "bulletList"
+                | "orderedList"
+                | "taskList"
+                | "blockquote"
+                | "panel"
+                | "rule"
+                | "table"
+                | "expand"
+                | "mediaSingle"
+        )
+    )
+}
+
+/// Render an ADF document (or any ADF node) as Markdown.
+///
+/// Unlike [`super::jira::render_adf`], which produces plain text for
+/// display, this keeps marks, nesting, and code block languages so the
+/// result converts back with [`markdown_to_adf`]. Unknown nodes fall back to
+/// their text content.
+pub fn adf_to_markdown(doc: &Value) -> String {
+    let markdown = match doc.get("type").and_then(Value::as_str) {
+        Some("doc") | None => adf_blocks(children(doc), "\n\n"),
+        Some(_) => adf_block(doc).unwrap_or_default(),
+    };
+    if markdown.is_empty() {
+        markdown
+    } else {
+        markdown + "\n"
+    }
+}
+
+// ---------------------------------------------------------------------------
+// ADF -> storage
+// ---------------------------------------------------------------------------
+
+fn escape(text: &str) -> String {
+    html_escape::encode_text(text).into_owned()
+}
+
+fn escape_attr(text: &str) -> String {
+    html_escape::encode_double_quoted_attribute(text).into_owned()
+}
+
+fn cdata(text: &str) -> String {
+    format!("<![CDATA[{}]]>", text.replace("]]>", "]]]]><![CDATA[>"))
+}
+
+fn macro_xml(name: &str, parameters: &[(&str, &str)], body: &str) -> String {
+    let parameters: String = parameters
+        .iter()
+        .map(|(k, v)| {
+            format!(
+                "<ac:parameter ac:name=\"{}\">{}</ac:parameter>",
+                k,
+                escape(v)
+            )
+        })
+        .collect();
+    format!(
+        "<ac:structured-macro ac:name=\"{}\">{}{}</ac:structured-macro>",
+        name, parameters, body
+    )
+}
+
+fn storage_inline(nodes: &[Value]) -> String {
+    nodes.iter().map(storage_inline_node).collect()
+}
+
+fn storage_inline_node(node: &Value) -> String {
+    match node.get("type").and_then(Value::as_str).unwrap_or_default() {
+        "text" => {
+            let mut text = escape(node.get("text").and_then(Value::as_str).unwrap_or_default());
+            for mark in node
+                .get("marks")
+                .and_then(Value::as_array)
+                .map_or(&[][..], Vec::as_slice)
+            {
+                text = match mark["type"].as_str().unwrap_or_default() {
+                    "strong" => format!("<strong>{}</strong>", text),
+                    "em" => format!("<em>{}</em>", text),
+                    "code" => format!("<code>{}</code>", text),
+                    "strike" => format!("<s>{}</s>", text),
+                    "underline" => format!("<u>{}</u>", text),
+                    "link" => format!(
+                        "<a href=\"{}\">{}</a>",
+                        escape_attr(mark["attrs"]["href"].as_str().unwrap_or_default()),
+                        text
+                    ),
+                    _ => text,
+                };
+            }
+            text
+        }
+        "hardBreak" => "<br />".to_string(),
+        "inlineCard" => attr_str(node, "url").map_or(String::new(), |u| {
+            format!("<a href=\"{}\">{}</a>", escape_attr(u), escape(u))
+        }),
+        _ => escape(&adf_inline_node(node)),
+    }
+}
+
+fn storage_blocks(nodes: &[Value]) -> String {
+    nodes.iter().map(storage_block).collect()
+}
+
+fn storage_block(node: &Value) -> String {
+    let kind = node.get("type").and_then(Value::as_str).unwrap_or_default();
+    let content = children(node);
+    match kind {
+        "paragraph" => format!("<p>{}</p>", storage_inline(content)),
+        "heading" => {
+            let level = attr(node, "level")
+                .and_then(Value::as_u64)
+                .unwrap_or(1)
+                .clamp(1, 6);
+            format!("<h{0}>{1}</h{0}>", level, storage_inline(content))
+        }
+        "codeBlock" => {
+            let code = adf_inline(content);
+            let body = format!("<ac:plain-text-body>{}</ac:plain-text-body>", cdata(&code));
+            match attr_str(node, "language").filter(|l| !l.is_empty()) {
+                Some(language) => macro_xml("code", &[("language", language)], &body),
+                None => macro_xml("code", &[], &body),
+            }
+        }
+        "bulletList" => format!("<ul>{}</ul>", storage_blocks(content)),
+        "orderedList" => match attr(node, "order").and_then(Value::as_u64) {
+            Some(start) if start != 1 => {
+                format!("<ol start=\"{}\">{}</ol>", start, storage_blocks(content))
+            }
+            _ => format!("<ol>{}</ol>", storage_blocks(content)),
+        },
+        "listItem" => format!("<li>{}</li>", storage_blocks(content)),
+        "taskList" => {
+            let tasks: String = content
+                .iter()
+                .map(|item| {
+                    let status = if attr_str(item, "state") == Some("DONE") {
+                        "complete"
+                    } else {
+                        "incomplete"
+                    };
+                    format!(
+                        "<ac:task><ac:task-status>{}</ac:task-status><ac:task-body>{}</ac:task-body></ac:task>",
+                        status,
+                        storage_inline(children(item))
+                    )
+                })
+                .collect();
+            format!("<ac:task-list>{}</ac:task-list>", tasks)
+        }
+        "blockquote" => format!("<blockquote>{}</blockquote>", storage_blocks(content)),
+        "panel" => {
+            let name = match attr_str(node, "panelType").unwrap_or("info") {
+                "note" => "note",
+                "warning" | "error" => "warning",
+                "success" => "tip",
+                _ => "info",
+            };
+            macro_xml(
+                name,
+                &[],
+                &format!(
+                    "<ac:rich-text-body>{}</ac:rich-text-body>",
+                    storage_blocks(content)
+                ),
+            )
+        }
+        "rule" => "<hr />".to_string(),
+        "table" => format!("<table><tbody>{}</tbody></table>", storage_blocks(content)),
+        "tableRow" => format!("<tr>{}</tr>", storage_blocks(content)),
+        "tableHeader" => format!("<th>{}</th>", storage_blocks(content)),
+        "tableCell" => format!("<td>{}</td>", storage_blocks(content)),
+        "expand" | "nestedExpand" => {
+            let title = attr_str(node, "title").unwrap_or_default();
+            macro_xml(
+                "expand",
+                &[("title", title)],
+                &format!(
+                    "<ac:rich-text-body>{}</ac:rich-text-body>",
+                    storage_blocks(content)
+                ),
+            )
+        }
+        _ if node.get("text").is_some() => storage_inline_node(node),
+        _ if content.iter().any(adf_is_block) => storage_blocks(content),
+        _ if content.is_empty() => String::new(),
+        _ => format!("<p>{}</p>", storage_inline(content)),
+    }
+}
+
+/// Render an ADF document as Confluence storage format (XHTML with `ac:`
+/// macros). Code blocks become `code` macros and panels become
+/// `info`/`note`/`tip`/`warning` macros.
+pub fn adf_to_storage(doc: &Value) -> String {
+    match doc.get("type").and_then(Value::as_str) {
+        Some("doc") | None => storage_blocks(children(doc)),
+        Some(_) => storage_block(doc),
+    }
+}
+
+/// Convert Markdown to Confluence storage format, by way of ADF.
+pub fn markdown_to_storage(markdown: &str) -> String {
+    adf_to_storage(&markdown_to_adf(markdown))
+}
+
+// ---------------------------------------------------------------------------
+// storage -> Markdown
+// ---------------------------------------------------------------------------
+
+/// Rewrite what the HTML parser cannot read: `CDATA` sections (code macro
+/// bodies) and self-closing namespaced tags such as `<ri:page ... />`.
+fn prepare_storage(storage: &str) -> String {
+    let code_macro = Regex::new(
+        r#"(?s)<ac:structured-macro\b[^>]*\bac:name="(?:code|noformat)"[^>]*>(.*?)</ac:structured-macro>"#,
+    )
+    .expect("valid regex");
+    let language =
+        Regex::new(r#"(?s)<ac:parameter\b[^>]*\bac:name="language"[^>]*>(.*?)</ac:parameter>"#)
+            .expect("valid regex");
+    let body =
+        Regex::new(r"(?s)<ac:plain-text-body>(.*?)</ac:plain-text-body>").expect("valid regex");
+    let cdata = Regex::new(r"(?s)<!\[CDATA\[(.*?)\]\]>").expect("valid regex");
+    let self_closing =
+        Regex::new(r"<([A-Za-z]+:[A-Za-z-]+)((?:\s[^>]*?)?)\s*/>").expect("valid regex");
+
+    let storage = code_macro.replace_all(storage, |caps: &regex::Captures| {
+        let inner = &caps[1];
+        let language = language
+            .captures(inner)
+            .map(|c| escape_attr(c[1].trim()))
+            .unwrap_or_default();
+        let code = body.captures(inner).map_or(String::new(), |c| {
+            cdata
+                .captures_iter(&c[1])
+                .map(|c| c[1].to_string())
+                .collect::<String>()
+        });
+        format!(
+            "<pre data-language=\"{}\">{}</pre>",
+            language,
+            escape(&code)
+        )
+    });
+    let storage = cdata.replace_all(&storage, |caps: &regex::Captures| escape(&caps[1]));
+    self_closing
+        .replace_all(&storage, "<$1$2></$1>")
+        .into_owned()
+}
+
+fn is_storage_block(name: &str) -> bool {
+    matches!(
+        name,
+        "p" | "h1"
+            | "h2"
+            | "h3"
+            | "h4"
+            | "h5"
+            | "h6"
+            | "ul"
+            | "ol"
+            | "pre"
+            | "blockquote"
+            | "hr"
+            | "table"
+            | "div"
+            | "section"
+            | "ac:structured-macro"
+            | "ac:task-list"
+            | "ac:layout"
+            | "ac:layout-section"
+            | "ac:layout-cell"
+            | "ac:rich-text-body"
+    )
+}
+
+fn child_element<'a>(element: &ElementRef<'a>, name: &str) -> Option<ElementRef<'a>> {
+    element
+        .descendants()
+        .filter_map(ElementRef::wrap)
+        .find(|e| e.value().name() == name)
+}
+
+fn macro_parameter(element: &ElementRef, name: &str) -> Option<String> {
+    element
+        .children()
+        .filter_map(ElementRef::wrap)
+        .find(|e| e.value().name() == "ac:parameter" && e.value().attr("ac:name") == Some(name))
+        .map(|e| e.text().collect::<String>().trim().to_string())
+}
+
+/// Block-level Markdown for an element's children; loose inline content
+/// between blocks becomes its own paragraph.
+fn storage_children(element: &ElementRef) -> Vec<String> {
+    let mut blocks = Vec::new();
+    let mut inline = String::new();
+    let flush = |inline: &mut String, blocks: &mut Vec<String>| {
+        let text = inline.trim();
+        if !text.is_empty() {
+            blocks.push(text.to_string());
+        }
+        inline.clear();
+    };
+    for child in element.children() {
+        match child.value() {
+            Node::Text(text) => inline.push_str(&collapse(text)),
+            Node::Element(e) if is_storage_block(e.name()) => {
+                flush(&mut inline, &mut blocks);
+                let child = ElementRef::wrap(child).expect("element");
+                if let Some(block) = storage_to_block(&child).filter(|b| !b.is_empty()) {
+                    blocks.push(block);
+                }
+            }
+            Node::Element(_) => inline.push_str(&storage_to_inline(
+                &ElementRef::wrap(child).expect("element"),
+            )),
+            _ => {}
+        }
+    }
+    flush(&mut inline, &mut blocks);
+    blocks
+}
+
+fn collapse(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut space = false;
+    for c in text.chars() {
+        if c.is_whitespace() {
+            if !space {
+                out.push(' ');
+            }
+            space = true;
+        } else {
+            out.push(c);
+            space = false;
+        }
+    }
+    out
+}
+
+fn storage_inline_children(element: &ElementRef) -> String {
+    let blocks = storage_children(element);
+    blocks.join(" ")
+}
+
+fn storage_list(element: &ElementRef, ordered: bool) -> String {
+    let start: u64 = element
+        .value()
+        .attr("start")
+        .and_then(|s| s.parse().ok())
+        .unwrap_or(1);
+    element
+        .children()
+        .filter_map(ElementRef::wrap)
+        .filter(|e| e.value().name() == "li")
+        .enumerate()
+        .map(|(i, item)| {
+            let marker = if ordered {
+                format!("{}. ", start + i as u64)
+            } else {
+                "- ".to_string()
+            };
+            indent_item(&storage_children(&item).join("\n"), &marker)
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn storage_to_block(element: &ElementRef) -> Option<String> {
+    let name = element.value().name();
+    Some(match name {
+        "p" => storage_inline_children(element).trim().to_string(),
+        "h1" | "h2" | "h3" | "h4" | "h5" | "h6" => format!(
+            "{} {}",
+            "#".repeat(name[1..].parse().unwrap_or(1)),
+            storage_inline_children(element).trim()
+        ),
+        "ul" => storage_list(element, false),
+        "ol" => storage_list(element, true),
+        "pre" => {
+            let language = element.value().attr("data-language").or_else(|| {
+                child_element(element, "code")
+                    .and_then(|c| c.value().attr("class"))
+                    .and_then(|c| c.strip_prefix("language-"))
+            });
+            let code = element.text().collect::<String>();
+            format!(
+                "```{}\n{}\n```",
+                language.unwrap_or_default(),
+                code.trim_end_matches('\n')
+            )
+        }
+        "blockquote" => quote(&storage_children(element).join("\n\n")),
+        "hr" => "---".to_string(),
+        "table" => markdown_table(
+            element
+                .descendants()
+                .filter_map(ElementRef::wrap)
+                .filter(|e| e.value().name() == "tr")
+                .map(|row| {
+                    row.children()
+                        .filter_map(ElementRef::wrap)
+                        .filter(|c| matches!(c.value().name(), "th" | "td"))
+                        .map(|cell| storage_children(&cell).join(" ").replace('\n', " "))
+                        .collect()
+                })
+                .collect(),
+        )?,
+        "ac:task-list" => element
+            .children()
+            .filter_map(ElementRef::wrap)
+            .filter(|e| e.value().name() == "ac:task")
+            .map(|task| {
+                let done = child_element(&task, "ac:task-status")
+                    .is_some_and(|s| s.text().collect::<String>().trim() == "complete");
+                let body = child_element(&task, "ac:task-body")
+                    .map(|b| storage_inline_children(&b))
+                    .unwrap_or_default();
+                let marker = if done { "- [x] " } else { "- [ ] " };
+                indent_item(body.trim(), marker)
+            })
+            .collect::<Vec<_>>()
+            .join("\n"),
+        "ac:structured-macro" => {
+            let macro_name = element.value().attr("ac:name").unwrap_or_default();
+            let body = child_element(element, "ac:rich-text-body")
+                .map(|b| storage_children(&b).join("\n\n"))
+                .unwrap_or_default();
+            let title = macro_parameter(element, "title").filter(|t| !t.is_empty());
+            match macro_name {
+                "info" | "note" | "tip" | "warning" | "panel" => {
+                    let label = title.unwrap_or_else(|| capitalize(macro_name));
+                    quote(&format!("**{}:** {}", label, body))
+                }
+                "expand" => match title {
+                    Some(title) => format!("**{}**\n\n{}", title, body),
+                    None => body,
+                },
+                // Macros without a body (toc, children, jira, ...) have no
+                // Markdown equivalent.
+                _ => body,
+            }
+        }
+        _ => storage_children(element).join("\n\n"),
+    })
+}
+
+fn storage_to_inline(element: &ElementRef) -> String {
+    let name = element.value().name();
+    let inner = || storage_inline_children(element);
+    match name {
+        "strong" | "b" => delimit(&inner(), "**", "**"),
+        "em" | "i" => delimit(&inner(), "*", "*"),
+        "code" => delimit(&element.text().collect::<String>(), "`", "`"),
+        "s" | "del" | "strike" => delimit(&inner(), "~~", "~~"),
+        "br" => "  \n".to_string(),
+        "a" => {
+            let href = element.value().attr("href").unwrap_or_default();
+            let text = inner();
+            if text.trim().is_empty() {
+                format!("<{}>", href)
+            } else {
+                format!("[{}]({})", text.trim(), href)
+            }
+        }
+        "img" => format!(
+            "![{}]({})",
+            element.value().attr("alt").unwrap_or_default(),
+            element.value().attr("src").unwrap_or_default()
+        ),
+        "ac:image" => {
+            let source = child_element(element, "ri:url")
+                .and_then(|u| u.value().attr("ri:value"))
+                .or_else(|| {
+                    child_element(element, "ri:attachment")
+                        .and_then(|a| a.value().attr("ri:filename"))
+                })
+                .unwrap_or_default();
+            format!(
+                "![{}]({})",
+                element.value().attr("ac:alt").unwrap_or_default(),
+                source
+            )
+        }
+        "ac:link" => {
+            let body = child_element(element, "ac:plain-text-link-body")
+                .or_else(|| child_element(element, "ac:link-body"))
+                .map(|b| b.text().collect::<String>());
+            let target = child_element(element, "ri:page")
+                .and_then(|p| p.value().attr("ri:content-title"))
+                .or_else(|| {
+                    child_element(element, "ri:attachment")
+                        .and_then(|a| a.value().attr("ri:filename"))
+                })
+                .or_else(|| element.value().attr("ac:anchor"));
+            body.filter(|b| !b.trim().is_empty())
+                .or(target.map(str::to_string))
+                .unwrap_or_default()
+        }
+        "ac:emoticon" => element
+            .value()
+            .attr("ac:emoji-fallback")
+            .unwrap_or_default()
+            .to_string(),
+        "ac:structured-macro" => macro_parameter(element, "title").unwrap_or_default(),
+        "time" => element
+            .value()
+            .attr("datetime")
+            .unwrap_or_default()
+            .to_string(),
+        "ac:parameter" | "ac:placeholder" => String::new(),
+        _ if name.starts_with("ri:") => String::new(),
+        _ => inner(),
+    }
+}
+
+/// Convert Confluence storage format to Markdown. Code macros become fenced
+/// blocks, info/note/tip/warning macros become block quotes, task lists
+/// become `- [ ]` items, and page links keep their link text. Macros with
+/// no Markdown equivalent are reduced to their body text.
+pub fn storage_to_markdown(storage: &str) -> String {
+    let prepared = prepare_storage(storage);
+    let fragment = Html::parse_fragment(&prepared);
+    let markdown = storage_children(&fragment.root_element()).join("\n\n");
+    if markdown.is_empty() {
+        markdown
+    } else {
+        markdown + "\n"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    const MARKDOWN: &str = "\
+# Release plan
+
+Ship **v2** with *care*, see [docs](https://example.com/docs) and `cargo test`.
+
+- first
+- second
+
+1. one
+2. two
+
+```rust
+fn main() {}
+```
+";
+
+    #[test]
+    fn test_markdown_adf_round_trip() {
+        let adf = markdown_to_adf(MARKDOWN);
+        assert_eq!(adf_to_markdown(&adf), MARKDOWN);
+    }
+
+    #[test]
+    fn test_markdown_storage_round_trip() {
+        let storage = markdown_to_storage(MARKDOWN);
+        assert!(storage.starts_with("<h1>Release plan</h1><p>Ship <strong>v2</strong> with <em>care</em>, see <a href=\"https://example.com/docs\">docs</a> and <code>cargo test</code>.</p>"));
+        assert!(storage.contains("<ul><li><p>first</p></li><li><p>second</p></li></ul>"));
+        assert!(storage.contains(
+            "<ac:structured-macro ac:name=\"code\"><ac:parameter ac:name=\"language\">rust</ac:parameter><ac:plain-text-body><![CDATA[fn main() {}]]></ac:plain-text-body></ac:structured-macro>"
+        ));
+        assert_eq!(storage_to_markdown(&storage), MARKDOWN);
+    }
+
+    #[test]
+    fn test_adf_to_markdown_nodes() {
+        let adf = json!({
+            "type": "doc",
+            "version": 1,
+            "content": [
+                {"type": "bulletList", "content": [
+                    {"type": "listItem", "content": [
+                        {"type": "paragraph", "content": [{"type": "text", "text": "parent "}, {"type": "text", "text": "gone", "marks": [{"type": "strike"}]}]},
+                        {"type": "orderedList", "attrs": {"order": 3}, "content": [
+                            {"type": "listItem", "content": [{"type": "paragraph", "content": [{"type": "text", "text": "child"}]}]}
+                        ]}
+                    ]}
+                ]},
+                {"type": "panel", "attrs": {"panelType": "warning"}, "content": [
+                    {"type": "paragraph", "content": [{"type": "text", "text": "Careful"}]}
+                ]},
+                {"type": "taskList", "content": [
+                    {"type": "taskItem", "attrs": {"state": "DONE"}, "content": [{"type": "text", "text": "done"}]},
+                    {"type": "taskItem", "attrs": {"state": "TODO"}, "content": [{"type": "mention", "attrs": {"text": "@ana"}}]}
+                ]},
+                {"type": "table", "content": [
+                    {"type": "tableRow", "content": [
+                        {"type": "tableHeader", "content": [{"type": "paragraph", "content": [{"type": "text", "text": "a|b"}]}]},
+                        {"type": "tableHeader", "content": [{"type": "paragraph", "content": [{"type": "text", "text": "c"}]}]}
+                    ]},
+                    {"type": "tableRow", "content": [
+                        {"type": "tableCell", "content": [{"type": "paragraph", "content": [{"type": "text", "text": "1"}]}]}
+                    ]}
+                ]},
+                {"type": "rule"},
+                {"type": "unknownBlock", "content": [{"type": "text", "text": "fallback"}]}
+            ]
+        });
+        let expected = "\
+- parent ~~gone~~
+  3. child
+
+> **Warning:** Careful
+
+- [x] done
+- [ ] @ana
+
+| a\\|b | c |
+| --- | --- |
+| 1 |  |
+
+---
+
+fallback
+";
+        assert_eq!(adf_to_markdown(&adf), expected);
+        assert_eq!(adf_to_markdown(&json!({"type": "doc", "content": []})), "");
+    }
+
+    #[test]
+    fn test_storage_to_markdown_macros() {
+        let storage = r#"<h2>Notes</h2>
+<ac:structured-macro ac:name="info" ac:schema-version="1"><ac:rich-text-body><p>Read   this <b>first</b>.</p></ac:rich-text-body></ac:structured-macro>
+<ac:structured-macro ac:name="code"><ac:parameter ac:name="language">sh</ac:parameter><ac:plain-text-body><![CDATA[echo "a < b" ]]]]><![CDATA[>]]></ac:plain-text-body></ac:structured-macro>
+<p>See <ac:link><ri:page ri:content-title="Runbook" /><ac:plain-text-link-body><![CDATA[the runbook]]></ac:plain-text-link-body></ac:link> and <ac:image ac:alt="chart"><ri:attachment ri:filename="chart.png" /></ac:image>.<br/>Next line</p>
+<ac:task-list><ac:task><ac:task-status>complete</ac:task-status><ac:task-body>Deploy</ac:task-body></ac:task></ac:task-list>
+<ac:structured-macro ac:name="toc" />
+<table><tbody><tr><th>k</th><th>v</th></tr><tr><td>x</td><td><p>1</p></td></tr></tbody></table>"#;
+        let expected = "\
+## Notes
+
+> **Info:** Read this **first**.
+
+```sh
+echo \"a < b\" ]]>
+```
+
+See the runbook and ![chart](chart.png).  \nNext line
+
+- [x] Deploy
+
+| k | v |
+| --- | --- |
+| x | 1 |
+";
+        assert_eq!(storage_to_markdown(storage), expected);
+        assert_eq!(storage_to_markdown("  "), "");
+    }
+}
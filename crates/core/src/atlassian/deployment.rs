@@ -0,0 +1,426 @@
+//! Atlassian deployment detection (Cloud vs. Data Center / Server)
+//!
+//! Cloud and self-hosted installs expose different REST API versions and
+//! expect different authentication schemes. These pure helpers decide which
+//! flavor to talk to and build the matching endpoint prefixes and headers.
+
+use std::fmt;
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+
+/// Where an Atlassian product is hosted.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Deployment {
+    /// Atlassian Cloud (`*.atlassian.net`): REST v3, Basic auth with email + API token.
+    Cloud,
+    /// Self-hosted Data Center or Server: REST v2, Bearer personal access token.
+    DataCenter,
+}
+
+/// The Jira REST dialect a request or response follows.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum JiraFlavor {
+    /// REST v3: ADF rich text, account IDs, token-paged search.
+    V3,
+    /// REST v2: plain-text rich text, usernames, offset-paged search.
+    V2,
+}
+
+impl From<Deployment> for JiraFlavor {
+    /// Cloud is spoken to over v3; Server / Data Center only serve v2.
+    fn from(deployment: Deployment) -> Self {
+        match deployment {
+            Deployment::Cloud => JiraFlavor::V3,
+            Deployment::DataCenter => JiraFlavor::V2,
+        }
+    }
+}
+
+/// Deployment setting as configured by the user.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DeploymentSetting {
+    /// Detect from the base URL, falling back to the `serverInfo` endpoint.
+    #[default]
+    Auto,
+    /// Always use the given deployment.
+    Fixed(Deployment),
+}
+
+/// Error returned when a deployment setting cannot be parsed.
+#[derive(Debug, PartialEq, Eq, thiserror::Error)]
+#[error("Invalid deployment type '{0}' (expected 'cloud', 'datacenter', 'server', or 'auto')")]
+pub struct InvalidDeployment(pub String);
+
+impl fmt::Display for Deployment {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Deployment::Cloud => write!(f, "cloud"),
+            Deployment::DataCenter => write!(f, "datacenter"),
+        }
+    }
+}
+
+impl FromStr for DeploymentSetting {
+    type Err = InvalidDeployment;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_lowercase().as_str() {
+            "" | "auto" => Ok(DeploymentSetting::Auto),
+            "cloud" => Ok(DeploymentSetting::Fixed(Deployment::Cloud)),
+            "datacenter" | "data-center" | "dc" | "server" => {
+                Ok(DeploymentSetting::Fixed(Deployment::DataCenter))
+            }
+            other => Err(InvalidDeployment(other.to_string())),
+        }
+    }
+}
+
+impl DeploymentSetting {
+    /// Parse a Jira deployment setting, which may also name the Jira REST
+    /// API version: `v3` is served by Cloud, `v2` by Server / Data Center.
+    /// Other products number their APIs differently, so only Jira takes these.
+    pub fn parse_jira(s: &str) -> Result<Self, InvalidDeployment> {
+        match s.trim().to_lowercase().as_str() {
+            "v3" => Ok(DeploymentSetting::Fixed(Deployment::Cloud)),
+            "v2" => Ok(DeploymentSetting::Fixed(Deployment::DataCenter)),
+            _ => s.parse(),
+        }
+    }
+}
+
+/// Subset of the `/rest/api/2/serverInfo` response used for detection.
+#[derive(Debug, Clone, Deserialize, Serialize, Default)]
+pub struct ServerInfo {
+    #[serde(default, rename = "baseUrl")]
+    pub base_url: Option<String>,
+    #[serde(default)]
+    pub version: Option<String>,
+    #[serde(default, rename = "deploymentType")]
+    pub deployment_type: Option<String>,
+}
+
+/// Guess the deployment from the base URL alone.
+///
+/// Atlassian Cloud sites always live under `atlassian.net` (or the legacy
+/// `jira.com`). Any other host is ambiguous and returns `None`.
+pub fn deployment_from_url(base_url: &str) -> Option<Deployment> {
+    let host = base_url
+        .trim()
+        .trim_start_matches("https://")
+        .trim_start_matches("http://")
+        .split(['/', ':'])
+        .next()
+        .unwrap_or_default()
+        .to_lowercase();
+
+    if host.ends_with(".atlassian.net") || host.ends_with(".jira.com") {
+        Some(Deployment::Cloud)
+    } else {
+        None
+    }
+}
+
+/// Determine the deployment from a `serverInfo` response.
+///
+/// Cloud reports `deploymentType: "Cloud"`; Server and Data Center report
+/// `"Server"` or `"DataCenter"` (older Server versions omit the field).
+pub fn deployment_from_server_info(info: &ServerInfo) -> Deployment {
+    match info.deployment_type.as_deref() {
+        Some(t) if t.eq_ignore_ascii_case("cloud") => Deployment::Cloud,
+        _ => Deployment::DataCenter,
+    }
+}
+
+/// Confluence's anonymous application-links manifest. Confluence has no
+/// `serverInfo` endpoint, so this is what its deployment probe reads.
+pub const CONFLUENCE_MANIFEST_PATH: &str = "/rest/applinks/1.0/manifest";
+
+/// Subset of the [`CONFLUENCE_MANIFEST_PATH`] response used for detection.
+#[derive(Debug, Clone, Deserialize, Default)]
+pub struct ApplinksManifest {
+    #[serde(default, rename = "typeId")]
+    pub type_id: Option<String>,
+    #[serde(default)]
+    pub version: Option<String>,
+}
+
+/// Read a Confluence applinks manifest as [`ServerInfo`].
+///
+/// Returns `None` when the manifest is not Confluence's. Cloud builds report
+/// versions numbered 1000 and up, while self-hosted releases are numbered
+/// like `8.5.4`.
+pub fn server_info_from_manifest(manifest: &ApplinksManifest) -> Option<ServerInfo> {
+    if !manifest
+        .type_id
+        .as_deref()
+        .is_some_and(|t| t.eq_ignore_ascii_case("confluence"))
+    {
+        return None;
+    }
+    let major: Option<u32> = manifest
+        .version
+        .as_deref()
+        .and_then(|v| v.split('.').next())
+        .and_then(|major| major.parse().ok());
+    let deployment_type = if major.is_some_and(|major| major >= 1000) {
+        "Cloud"
+    } else {
+        "DataCenter"
+    };
+    Some(ServerInfo {
+        base_url: None,
+        version: manifest.version.clone(),
+        deployment_type: Some(deployment_type.to_string()),
+    })
+}
+
+/// Resolve the effective deployment from the user's setting.
+///
+/// Explicit settings always win. In `Auto` mode, Cloud-looking hosts are
+/// recognized from the URL; otherwise the `serverInfo` probe decides, and a
+/// host that neither looks like Cloud nor answers the probe is treated as
+/// self-hosted.
+pub fn resolve_deployment(
+    setting: DeploymentSetting,
+    base_url: &str,
+    server_info: Option<&ServerInfo>,
+) -> Deployment {
+    match setting {
+        DeploymentSetting::Fixed(deployment) => deployment,
+        DeploymentSetting::Auto => deployment_from_url(base_url)
+            .or_else(|| server_info.map(deployment_from_server_info))
+            .unwrap_or(Deployment::DataCenter),
+    }
+}
+
+/// REST API path prefix for Jira on the given deployment.
+pub fn jira_api_path(deployment: Deployment) -> &'static str {
+    match deployment {
+        Deployment::Cloud => "/rest/api/3",
+        Deployment::DataCenter => "/rest/api/2",
+    }
+}
+
+/// JQL search endpoint, relative to [`jira_api_path`].
+///
+/// Cloud deprecated `/search` in favor of the token-paginated `/search/jql`,
+/// which Data Center does not provide.
+pub fn jira_search_path(deployment: Deployment) -> &'static str {
+    match deployment {
+        Deployment::Cloud => "/search/jql",
+        Deployment::DataCenter => "/search",
+    }
+}
+
+/// Content search endpoint path for Confluence on the given deployment.
+///
+/// Cloud serves Confluence under `/wiki`; Data Center serves it at the root
+/// of its own base URL.
+pub fn confluence_search_path(deployment: Deployment) -> &'static str {
+    match deployment {
+        Deployment::Cloud => "/wiki/api/v2/pages/search",
+        Deployment::DataCenter => "/rest/api/content/search",
+    }
+}
+
+/// Build the `Authorization` header value for the given deployment.
+///
+/// Cloud uses Basic auth with `email:api_token`; Data Center uses the token
+/// as a Bearer personal access token and ignores the email.
+pub fn authorization_header(deployment: Deployment, email: Option<&str>, token: &str) -> String {
+    use base64::Engine;
+
+    match deployment {
+        Deployment::Cloud => {
+            let credentials = format!("{}:{}", email.unwrap_or_default(), token);
+            format!(
+                "Basic {}",
+                base64::engine::general_purpose::STANDARD.encode(credentials)
+            )
+        }
+        Deployment::DataCenter => format!("Bearer {token}"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_deployment_setting_from_str() {
+        assert_eq!("auto".parse(), Ok(DeploymentSetting::Auto));
+        assert_eq!("".parse(), Ok(DeploymentSetting::Auto));
+        assert_eq!(
+            "Cloud".parse(),
+            Ok(DeploymentSetting::Fixed(Deployment::Cloud))
+        );
+        assert_eq!(
+            "datacenter".parse(),
+            Ok(DeploymentSetting::Fixed(Deployment::DataCenter))
+        );
+        assert_eq!(
+            "server".parse(),
+            Ok(DeploymentSetting::Fixed(Deployment::DataCenter))
+        );
+        assert!("onprem".parse::<DeploymentSetting>().is_err());
+        assert!("v2".parse::<DeploymentSetting>().is_err());
+
+        assert_eq!(
+            DeploymentSetting::parse_jira("v2"),
+            Ok(DeploymentSetting::Fixed(Deployment::DataCenter))
+        );
+        assert_eq!(
+            DeploymentSetting::parse_jira("V3"),
+            Ok(DeploymentSetting::Fixed(Deployment::Cloud))
+        );
+        assert_eq!(
+            DeploymentSetting::parse_jira("server"),
+            Ok(DeploymentSetting::Fixed(Deployment::DataCenter))
+        );
+    }
+
+    #[test]
+    fn test_deployment_from_url_cloud() {
+        assert_eq!(
+            deployment_from_url("https://acme.atlassian.net"),
+            Some(Deployment::Cloud)
+        );
+        assert_eq!(
+            deployment_from_url("https://ACME.atlassian.net/wiki/"),
+            Some(Deployment::Cloud)
+        );
+        assert_eq!(
+            deployment_from_url("https://legacy.jira.com"),
+            Some(Deployment::Cloud)
+        );
+    }
+
+    #[test]
+    fn test_deployment_from_url_ambiguous() {
+        assert_eq!(deployment_from_url("https://jira.acme.internal"), None);
+        assert_eq!(deployment_from_url("http://localhost:8080"), None);
+        // A look-alike path must not be mistaken for a Cloud host.
+        assert_eq!(
+            deployment_from_url("https://jira.acme.com/atlassian.net"),
+            None
+        );
+    }
+
+    #[test]
+    fn test_deployment_from_server_info() {
+        let cloud = ServerInfo {
+            deployment_type: Some("Cloud".to_string()),
+            ..Default::default()
+        };
+        let dc = ServerInfo {
+            deployment_type: Some("DataCenter".to_string()),
+            ..Default::default()
+        };
+        let legacy = ServerInfo::default();
+
+        assert_eq!(deployment_from_server_info(&cloud), Deployment::Cloud);
+        assert_eq!(deployment_from_server_info(&dc), Deployment::DataCenter);
+        assert_eq!(deployment_from_server_info(&legacy), Deployment::DataCenter);
+    }
+
+    #[test]
+    fn test_server_info_deserialize() {
+        let json =
+            r#"{"baseUrl":"https://jira.acme.com","version":"9.12.1","deploymentType":"Server"}"#;
+        let info: ServerInfo = serde_json::from_str(json).unwrap();
+        assert_eq!(info.version.as_deref(), Some("9.12.1"));
+        assert_eq!(deployment_from_server_info(&info), Deployment::DataCenter);
+    }
+
+    #[test]
+    fn test_server_info_from_manifest() {
+        let parse = |json: &str| {
+            let manifest: ApplinksManifest = serde_json::from_str(json).unwrap();
+            server_info_from_manifest(&manifest).map(|info| deployment_from_server_info(&info))
+        };
+
+        assert_eq!(
+            parse(r#"{"typeId":"confluence","version":"8.5.4","buildNumber":9012}"#),
+            Some(Deployment::DataCenter)
+        );
+        assert_eq!(
+            parse(r#"{"typeId":"confluence","version":"1000.0.0-5d2f1b"}"#),
+            Some(Deployment::Cloud)
+        );
+        assert_eq!(parse(r#"{"typeId":"jira","version":"9.12.1"}"#), None);
+        assert_eq!(parse("{}"), None);
+    }
+
+    #[test]
+    fn test_resolve_deployment() {
+        let cloud_info = ServerInfo {
+            deployment_type: Some("Cloud".to_string()),
+            ..Default::default()
+        };
+
+        // Explicit setting wins over everything else.
+        assert_eq!(
+            resolve_deployment(
+                DeploymentSetting::Fixed(Deployment::DataCenter),
+                "https://acme.atlassian.net",
+                Some(&cloud_info)
+            ),
+            Deployment::DataCenter
+        );
+        // URL heuristic short-circuits the probe.
+        assert_eq!(
+            resolve_deployment(DeploymentSetting::Auto, "https://acme.atlassian.net", None),
+            Deployment::Cloud
+        );
+        // Custom domain answered by serverInfo.
+        assert_eq!(
+            resolve_deployment(
+                DeploymentSetting::Auto,
+                "https://jira.acme.com",
+                Some(&cloud_info)
+            ),
+            Deployment::Cloud
+        );
+        // Unknown host with no probe result is assumed self-hosted.
+        assert_eq!(
+            resolve_deployment(DeploymentSetting::Auto, "https://jira.acme.com", None),
+            Deployment::DataCenter
+        );
+    }
+
+    #[test]
+    fn test_api_paths() {
+        assert_eq!(jira_api_path(Deployment::Cloud), "/rest/api/3");
+        assert_eq!(jira_api_path(Deployment::DataCenter), "/rest/api/2");
+        assert_eq!(jira_search_path(Deployment::Cloud), "/search/jql");
+        assert_eq!(jira_search_path(Deployment::DataCenter), "/search");
+        assert_eq!(
+            confluence_search_path(Deployment::Cloud),
+            "/wiki/api/v2/pages/search"
+        );
+        assert_eq!(
+            confluence_search_path(Deployment::DataCenter),
+            "/rest/api/content/search"
+        );
+    }
+
+    #[test]
+    fn test_authorization_header() {
+        assert_eq!(
+            authorization_header(Deployment::Cloud, Some("me@acme.com"), "tok"),
+            "Basic bWVAYWNtZS5jb206dG9r"
+        );
+        assert_eq!(
+            authorization_header(Deployment::DataCenter, Some("me@acme.com"), "pat"),
+            "Bearer pat"
+        );
+    }
+
+    #[test]
+    fn test_deployment_display() {
+        assert_eq!(Deployment::Cloud.to_string(), "cloud");
+        assert_eq!(Deployment::DataCenter.to_string(), "datacenter");
+    }
+}
@@ -1,7 +1,14 @@
 //! Transformation functions for Jira API responses
+//!
+//! Cloud (REST v3) and Server / Data Center (REST v2) differ in rich text
+//! (ADF documents vs. plain strings), user references (account IDs vs.
+//! usernames), and search pagination (tokens vs. offsets). Functions that
+//! build requests or read those parts branch on the [`JiraFlavor`].
 
 use serde::{Deserialize, Serialize};
 
+use super::deployment::JiraFlavor;
+
 /// Jira issue response from API
 #[derive(Debug, Deserialize, Serialize, Clone)]
 pub struct JiraIssueResponse {
@@ -18,6 +25,8 @@ pub struct JiraIssueFields {
     pub status: JiraStatus,
     #[serde(default)]
     pub assignee: Option<JiraAssignee>,
+    #[serde(default)]
+    pub updated: Option<String>,
 }
 
 /// Jira status field
@@ -65,6 +74,9 @@ pub struct IssueOutput {
     pub description: Option<String>,
     pub status: String,
     pub assignee: Option<String>,
+    /// Last update time as Jira reports it (e.g. `2024-01-02T03:04:05.000+0000`)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub updated: Option<String>,
 }
 
 /// Output structure for search command
@@ -118,6 +130,11 @@ pub struct JiraExtendedFields {
     pub labels: Vec<String>,
     #[serde(default)]
     pub components: Vec<JiraComponent>,
+    #[serde(default)]
+    pub issuelinks: Vec<JiraIssueLink>,
+    /// Every other field, including site-specific `customfield_*` fields
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Extended issue response for detailed read
@@ -156,6 +173,15 @@ pub struct TicketOutput {
     pub components: Vec<String>,
     pub comments: Vec<JiraComment>,
     pub attachments: Vec<AttachmentOutput>,
+    #[serde(default)]
+    pub links: Vec<IssueLinkOutput>,
+    /// Non-empty custom fields, keyed by field ID until named with
+    /// [`name_custom_fields`]
+    #[serde(default)]
+    pub custom_fields: std::collections::BTreeMap<String, serde_json::Value>,
+    /// Field change history, oldest first; only when requested
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub changelog: Option<Vec<ChangelogEntry>>,
 }
 
 /// Jira attachment response from API
@@ -358,7 +384,20 @@ fn render_adf_node(node: &serde_json::Value, depth: usize) -> Option<String> {
 ///
 /// # Returns
 /// * `SearchOutput` - Cleaned and transformed search results
-pub fn transform_search_response(search_response: JiraSearchResponse) -> SearchOutput {
+pub fn transform_search_response(
+    search_response: JiraSearchResponse,
+    flavor: JiraFlavor,
+) -> SearchOutput {
+    let next_page_token = match flavor {
+        JiraFlavor::V3 => search_response.next_page_token,
+        // Data Center pages by offset; the next offset stands in for a token
+        JiraFlavor::V2 => {
+            let next = search_response.start_at.unwrap_or(0) + search_response.issues.len() as u64;
+            (!search_response.issues.is_empty() && next < search_response.total.unwrap_or(0))
+                .then(|| next.to_string())
+        }
+    };
+
     let issues: Vec<IssueOutput> = search_response
         .issues
         .into_iter()
@@ -375,6 +414,7 @@ pub fn transform_search_response(search_response: JiraSearchResponse) -> SearchO
                 description: None, // Description is now ADF format, skip for now
                 status: issue.fields.status.name,
                 assignee,
+                updated: issue.fields.updated,
             }
         })
         .collect();
@@ -385,7 +425,7 @@ pub fn transform_search_response(search_response: JiraSearchResponse) -> SearchO
     SearchOutput {
         issues,
         total,
-        next_page_token: search_response.next_page_token,
+        next_page_token,
     }
 }
 
@@ -397,6 +437,8 @@ pub fn transform_search_response(search_response: JiraSearchResponse) -> SearchO
 /// * `issue` - The raw extended issue response from Jira API
 /// * `comments` - The parsed comments array
 /// * `attachments` - The transformed attachment outputs
+/// * `aliases` - Configured field aliases; aliased custom fields are keyed by
+///   their alias, the rest by field ID
 ///
 /// # Returns
 /// * `TicketOutput` - Cleaned and transformed ticket with all details
@@ -404,7 +446,9 @@ pub fn transform_ticket_response(
     issue: JiraExtendedIssueResponse,
     comments: Vec<JiraComment>,
     attachments: Vec<AttachmentOutput>,
+    aliases: &FieldAliases,
 ) -> TicketOutput {
+    let aliases = aliases_by_id(aliases);
     TicketOutput {
         key: issue.key,
         summary: issue.fields.summary,
@@ -434,7 +478,416 @@ pub fn transform_ticket_response(
             .collect(),
         comments,
         attachments,
+        links: transform_issue_links(issue.fields.issuelinks),
+        custom_fields: issue
+            .fields
+            .extra
+            .iter()
+            .filter(|(id, _)| id.starts_with("customfield_"))
+            .filter_map(|(id, value)| {
+                let key = aliases.get(id.as_str()).copied().unwrap_or(id).to_string();
+                Some((key, simplify_field_value(value)?))
+            })
+            .collect(),
+        changelog: None,
+    }
+}
+
+/// Field definition from the Jira API (`/field`)
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
+pub struct JiraField {
+    pub id: String,
+    pub name: String,
+    #[serde(default)]
+    pub custom: bool,
+    #[serde(default)]
+    pub schema: Option<JiraFieldSchema>,
+}
+
+/// Value type of a Jira field
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
+pub struct JiraFieldSchema {
+    #[serde(rename = "type", default)]
+    pub field_type: Option<String>,
+    /// Element type when `field_type` is `array`
+    #[serde(default)]
+    pub items: Option<String>,
+}
+
+/// Output structure for a field definition
+#[derive(Debug, Serialize, Clone, Deserialize, PartialEq)]
+pub struct FieldOutput {
+    pub id: String,
+    pub name: String,
+    pub custom: bool,
+    /// Value type, e.g. `number`, `option`, or `array<string>`
+    pub field_type: Option<String>,
+    /// Alias configured for this field in `[jira.fields]`
+    pub alias: Option<String>,
+}
+
+/// Transform field definitions, system fields first, each group by name.
+pub fn transform_fields_response(
+    fields: Vec<JiraField>,
+    aliases: &FieldAliases,
+) -> Vec<FieldOutput> {
+    let aliases = aliases_by_id(aliases);
+    let mut output: Vec<FieldOutput> = fields
+        .into_iter()
+        .map(|f| FieldOutput {
+            alias: aliases.get(f.id.as_str()).map(|alias| alias.to_string()),
+            field_type: f.schema.and_then(|s| match (s.field_type, s.items) {
+                (Some(t), Some(items)) if t == "array" => Some(format!("array<{items}>")),
+                (t, _) => t,
+            }),
+            id: f.id,
+            name: f.name,
+            custom: f.custom,
+        })
+        .collect();
+    output.sort_by(|a, b| {
+        (a.custom, a.name.to_lowercase(), &a.id).cmp(&(b.custom, b.name.to_lowercase(), &b.id))
+    });
+    output
+}
+
+/// Friendly names for fields, from the `[jira.fields]` table of the config
+/// file, e.g. `story_points = "customfield_10016"`. Maps alias to field ID.
+pub type FieldAliases = std::collections::BTreeMap<String, String>;
+
+/// Parse the field aliases from a config file's contents. A missing file or
+/// `[jira.fields]` table gives no aliases; other tables are ignored.
+pub fn parse_field_aliases(toml_content: Option<&str>) -> Result<FieldAliases, String> {
+    #[derive(Deserialize, Default)]
+    struct RawFile {
+        #[serde(default)]
+        jira: RawJira,
+    }
+
+    #[derive(Deserialize, Default)]
+    struct RawJira {
+        #[serde(default)]
+        fields: FieldAliases,
+    }
+
+    let raw: RawFile = match toml_content {
+        Some(content) => toml::from_str(content).map_err(|e| format!("invalid config: {e}"))?,
+        None => RawFile::default(),
+    };
+
+    for (alias, id) in &raw.jira.fields {
+        if id.trim().is_empty() || id.contains(char::is_whitespace) {
+            return Err(format!(
+                "jira.fields.{alias} must be a field ID such as customfield_10016, got '{id}'"
+            ));
+        }
+    }
+
+    Ok(raw.jira.fields)
+}
+
+/// Invert the aliases into a field ID to alias map (keeping the first alias,
+/// alphabetically, when several share an ID)
+pub fn aliases_by_id(aliases: &FieldAliases) -> std::collections::HashMap<&str, &str> {
+    let mut by_id = std::collections::HashMap::new();
+    for (alias, id) in aliases {
+        by_id.entry(id.as_str()).or_insert(alias.as_str());
+    }
+    by_id
+}
+
+/// Resolve a field reference to its ID: a configured alias first, then a
+/// field ID or name known to the site.
+pub fn resolve_field_id(
+    fields: &[JiraField],
+    aliases: &FieldAliases,
+    name_or_id: &str,
+) -> Option<String> {
+    aliases
+        .get(name_or_id.trim())
+        .cloned()
+        .or_else(|| find_field_id(fields, name_or_id))
+}
+
+/// Build the request value for a field from its text form, by the field's
+/// schema type
+///
+/// Numbers are parsed, select options become `{"value": ...}` (`Parent /
+/// Child` for cascading selects), users `{"accountId": ...}` on v3 and
+/// `{"name": ...}` on v2, versions and components `{"name": ...}`. Arrays
+/// take comma-separated items. An empty string clears the field. Fields of
+/// unknown type are sent as text.
+pub fn build_field_value(
+    field: Option<&JiraField>,
+    raw: &str,
+    flavor: JiraFlavor,
+) -> Result<serde_json::Value, String> {
+    use serde_json::{json, Value};
+
+    let raw = raw.trim();
+    let schema = field.and_then(|f| f.schema.as_ref());
+    let field_type = schema.and_then(|s| s.field_type.as_deref());
+    let name = field.map_or("field", |f| f.name.as_str());
+
+    let item = |item_type: Option<&str>, raw: &str| -> Result<Value, String> {
+        Ok(match item_type {
+            Some("number") => raw
+                .parse::<f64>()
+                .ok()
+                .and_then(serde_json::Number::from_f64)
+                .map(Value::Number)
+                .ok_or_else(|| format!("{name} expects a number, got '{raw}'"))?,
+            Some("option") => json!({ "value": raw }),
+            Some("option-with-child") => match raw.split_once(" / ") {
+                Some((parent, child)) => {
+                    json!({ "value": parent.trim(), "child": { "value": child.trim() } })
+                }
+                None => json!({ "value": raw }),
+            },
+            Some("user") => match flavor {
+                JiraFlavor::V3 => json!({ "accountId": raw }),
+                JiraFlavor::V2 => json!({ "name": raw }),
+            },
+            Some("version") | Some("component") => json!({ "name": raw }),
+            _ => json!(raw),
+        })
+    };
+
+    if raw.is_empty() {
+        return Ok(if field_type == Some("array") {
+            json!([])
+        } else {
+            Value::Null
+        });
+    }
+
+    if field_type == Some("array") {
+        let item_type = schema.and_then(|s| s.items.as_deref());
+        return raw
+            .split(',')
+            .map(str::trim)
+            .filter(|s| !s.is_empty())
+            .map(|s| item(item_type, s))
+            .collect::<Result<Vec<_>, _>>()
+            .map(Value::Array);
+    }
+
+    item(field_type, raw)
+}
+
+/// Find a field's ID from its ID or its name (case-insensitive).
+pub fn find_field_id(fields: &[JiraField], name_or_id: &str) -> Option<String> {
+    fields
+        .iter()
+        .find(|f| f.id == name_or_id)
+        .or_else(|| {
+            fields
+                .iter()
+                .find(|f| f.name.eq_ignore_ascii_case(name_or_id.trim()))
+        })
+        .map(|f| f.id.clone())
+}
+
+/// Reduce a raw field value to its readable form
+///
+/// Select options become their `value`, users their `displayName`, named
+/// objects (versions, sprints, components) their `name`, and ADF documents
+/// their text. Arrays are reduced element-wise. Returns `None` for `null`
+/// and empty arrays or strings.
+pub fn simplify_field_value(value: &serde_json::Value) -> Option<serde_json::Value> {
+    use serde_json::Value;
+
+    match value {
+        Value::Null => None,
+        Value::String(s) if s.is_empty() => None,
+        Value::Array(items) => {
+            let items: Vec<Value> = items.iter().filter_map(simplify_field_value).collect();
+            (!items.is_empty()).then_some(Value::Array(items))
+        }
+        Value::Object(object) => {
+            if object.get("type").and_then(Value::as_str) == Some("doc") {
+                return render_adf(value).map(Value::String);
+            }
+            if let Some(option) = object.get("value").and_then(Value::as_str) {
+                let child = object
+                    .get("child")
+                    .and_then(|c| c.get("value"))
+                    .and_then(Value::as_str);
+                return Some(Value::String(match child {
+                    Some(child) => format!("{option} / {child}"),
+                    None => option.to_string(),
+                }));
+            }
+            ["displayName", "name"]
+                .iter()
+                .find_map(|key| object.get(*key).and_then(Value::as_str))
+                .map(|s| Value::String(s.to_string()))
+                .or_else(|| Some(value.clone()))
+        }
+        other => Some(other.clone()),
+    }
+}
+
+/// Re-key custom field values from field IDs to field names
+///
+/// IDs without a matching field keep their ID. When two fields share a name,
+/// the later one is keyed `Name (customfield_N)` so no value is lost.
+pub fn name_custom_fields(
+    custom_fields: std::collections::BTreeMap<String, serde_json::Value>,
+    fields: &[JiraField],
+) -> std::collections::BTreeMap<String, serde_json::Value> {
+    let mut named = std::collections::BTreeMap::new();
+    for (id, value) in custom_fields {
+        let name = fields
+            .iter()
+            .find(|f| f.id == id)
+            .map(|f| f.name.clone())
+            .unwrap_or_else(|| id.clone());
+        let key = if named.contains_key(&name) {
+            format!("{name} ({id})")
+        } else {
+            name
+        };
+        named.insert(key, value);
     }
+    named
+}
+
+/// Issue link type from the Jira API (e.g. Blocks: "blocks" / "is blocked by")
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
+pub struct JiraIssueLinkType {
+    #[serde(default)]
+    pub id: String,
+    pub name: String,
+    /// How the link reads from the inward issue's side (e.g. "is blocked by")
+    #[serde(default)]
+    pub inward: String,
+    /// How the link reads from the outward issue's side (e.g. "blocks")
+    #[serde(default)]
+    pub outward: String,
+}
+
+/// Response of the issue link type listing endpoint
+#[derive(Debug, Deserialize, Clone)]
+pub struct JiraIssueLinkTypesResponse {
+    #[serde(rename = "issueLinkTypes", default)]
+    pub issue_link_types: Vec<JiraIssueLinkType>,
+}
+
+/// Fields of the issue on the other end of a link
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct JiraLinkedIssueFields {
+    #[serde(default)]
+    pub summary: Option<String>,
+    #[serde(default)]
+    pub status: Option<JiraStatus>,
+}
+
+/// Issue on the other end of a link
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct JiraLinkedIssue {
+    pub key: String,
+    #[serde(default)]
+    pub fields: Option<JiraLinkedIssueFields>,
+}
+
+/// Entry of an issue's `issuelinks` field; exactly one of the two issues
+/// is set, and it is the issue on the other end
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct JiraIssueLink {
+    #[serde(default)]
+    pub id: String,
+    #[serde(rename = "type")]
+    pub link_type: JiraIssueLinkType,
+    #[serde(rename = "inwardIssue", default)]
+    pub inward_issue: Option<JiraLinkedIssue>,
+    #[serde(rename = "outwardIssue", default)]
+    pub outward_issue: Option<JiraLinkedIssue>,
+}
+
+/// Output struct for a link from a ticket to another issue
+#[derive(Debug, Serialize, Clone, Deserialize, PartialEq)]
+pub struct IssueLinkOutput {
+    pub id: String,
+    /// Link type name (e.g. "Blocks")
+    pub link_type: String,
+    /// How the ticket relates to `issue_key` (e.g. "blocks", "is blocked by")
+    pub relationship: String,
+    pub issue_key: String,
+    pub summary: Option<String>,
+    pub status: Option<String>,
+}
+
+/// Transform an issue's raw `issuelinks` into link outputs, read from the
+/// issue's own side
+pub fn transform_issue_links(links: Vec<JiraIssueLink>) -> Vec<IssueLinkOutput> {
+    links
+        .into_iter()
+        .filter_map(|link| {
+            let (relationship, other) = match (link.outward_issue, link.inward_issue) {
+                (Some(other), _) => (link.link_type.outward, other),
+                (None, Some(other)) => (link.link_type.inward, other),
+                (None, None) => return None,
+            };
+            let fields = other.fields.unwrap_or(JiraLinkedIssueFields {
+                summary: None,
+                status: None,
+            });
+            Some(IssueLinkOutput {
+                id: link.id,
+                link_type: link.link_type.name,
+                relationship,
+                issue_key: other.key,
+                summary: fields.summary,
+                status: fields.status.map(|s| s.name),
+            })
+        })
+        .collect()
+}
+
+/// Build the request body linking `issue_key` to `other_key` so that
+/// "`issue_key` `relationship` `other_key`" reads true
+///
+/// `relationship` is matched, case-insensitively, against each link type's
+/// outward and inward descriptions, then its name, so both "blocks" and
+/// "is blocked by" work with the standard Blocks type. Jira reads a link as
+/// "inwardIssue <outward> outwardIssue".
+///
+/// # Returns
+/// * `Ok(serde_json::Value)` - The body for `POST /issueLink`
+/// * `Err(String)` - No link type matches, listing the relationships there are
+pub fn build_issue_link_payload(
+    link_types: &[JiraIssueLinkType],
+    issue_key: &str,
+    relationship: &str,
+    other_key: &str,
+) -> Result<serde_json::Value, String> {
+    let wanted = relationship.trim();
+    let matches = |text: &str| text.eq_ignore_ascii_case(wanted);
+
+    let (link_type, from, to) = if let Some(t) = link_types.iter().find(|t| matches(&t.outward)) {
+        (t, issue_key, other_key)
+    } else if let Some(t) = link_types.iter().find(|t| matches(&t.inward)) {
+        (t, other_key, issue_key)
+    } else if let Some(t) = link_types.iter().find(|t| matches(&t.name)) {
+        (t, issue_key, other_key)
+    } else {
+        let available: Vec<&str> = link_types
+            .iter()
+            .flat_map(|t| [t.outward.as_str(), t.inward.as_str()])
+            .filter(|text| !text.is_empty())
+            .collect();
+        return Err(format!(
+            "No issue link type for '{wanted}'. Available relationships: {}",
+            available.join(", ")
+        ));
+    };
+
+    Ok(serde_json::json!({
+        "type": { "name": link_type.name },
+        "inwardIssue": { "key": from },
+        "outwardIssue": { "key": to },
+    }))
 }
 
 /// Transition representation from Jira API
@@ -443,6 +896,18 @@ pub struct JiraTransition {
     pub id: String,
     pub name: String,
     pub to: JiraStatus,
+    /// Transition screen fields, only present with `expand=transitions.fields`
+    #[serde(default)]
+    pub fields: std::collections::BTreeMap<String, JiraTransitionField>,
+}
+
+/// Field shown on a transition screen
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct JiraTransitionField {
+    #[serde(default)]
+    pub required: bool,
+    #[serde(default)]
+    pub name: Option<String>,
 }
 
 /// Transitions response from Jira API
@@ -451,11 +916,62 @@ pub struct JiraTransitionsResponse {
     pub transitions: Vec<JiraTransition>,
 }
 
+/// A transition available from an issue's current status
+#[derive(Debug, Serialize, Clone, PartialEq)]
+pub struct TransitionOutput {
+    pub id: String,
+    pub name: String,
+    pub to_status: String,
+    /// Display names of fields that must be set to perform the transition
+    pub required_fields: Vec<String>,
+}
+
+/// Output structure for the transitions command
+#[derive(Debug, Serialize, Clone, PartialEq)]
+pub struct TransitionListOutput {
+    pub ticket_key: String,
+    pub transitions: Vec<TransitionOutput>,
+}
+
+/// Convert a raw transitions response into the domain output model.
+///
+/// Required fields are reported by display name, falling back to the field
+/// key when Jira does not provide one.
+pub fn transform_transitions_response(
+    ticket_key: &str,
+    response: JiraTransitionsResponse,
+) -> TransitionListOutput {
+    let transitions = response
+        .transitions
+        .into_iter()
+        .map(|t| TransitionOutput {
+            required_fields: t
+                .fields
+                .into_iter()
+                .filter(|(_, field)| field.required)
+                .map(|(key, field)| field.name.unwrap_or(key))
+                .collect(),
+            id: t.id,
+            name: t.name,
+            to_status: t.to.name,
+        })
+        .collect();
+
+    TransitionListOutput {
+        ticket_key: ticket_key.to_string(),
+        transitions,
+    }
+}
+
 /// User search result from Jira API
 #[derive(Debug, Deserialize, Clone)]
 pub struct JiraUser {
-    #[serde(rename = "accountId")]
+    /// Cloud account ID (empty on Server / Data Center)
+    #[serde(rename = "accountId", default)]
     pub account_id: String,
+    /// Server / Data Center username
+    #[serde(default)]
+    pub name: Option<String>,
     #[serde(rename = "displayName", default)]
     pub display_name: Option<String>,
     #[serde(default)]
@@ -463,6 +979,35 @@ pub struct JiraUser {
     pub email_address: Option<String>,
 }
 
+impl JiraUser {
+    /// How this flavor refers to the user: the account ID on Cloud, the
+    /// username on Server / Data Center
+    pub fn user_ref(&self, flavor: JiraFlavor) -> Option<&str> {
+        match flavor {
+            JiraFlavor::V3 => Some(self.account_id.as_str()).filter(|id| !id.is_empty()),
+            JiraFlavor::V2 => self.name.as_deref(),
+        }
+    }
+}
+
+/// A user reference in a request body: `{"id": ...}` (account ID) on Cloud,
+/// `{"name": ...}` (username) on Server / Data Center
+pub fn user_ref_payload(flavor: JiraFlavor, user_ref: &str) -> serde_json::Value {
+    match flavor {
+        JiraFlavor::V3 => serde_json::json!({ "id": user_ref }),
+        JiraFlavor::V2 => serde_json::json!({ "name": user_ref }),
+    }
+}
+
+/// A rich text value (description, comment) from markdown: an ADF document
+/// on Cloud, the text as-is on Server / Data Center
+pub fn rich_text_payload(flavor: JiraFlavor, markdown: &str) -> serde_json::Value {
+    match flavor {
+        JiraFlavor::V3 => markdown_to_adf(markdown),
+        JiraFlavor::V2 => serde_json::Value::String(markdown.to_string()),
+    }
+}
+
 /// User search response from Jira API - the API returns a bare array
 /// See: https://developer.atlassian.com/cloud/jira/platform/rest/v3/api-group-user-search/#api-rest-api-3-users-search-get
 pub type JiraUserSearchResponse = Vec<JiraUser>;
@@ -542,18 +1087,22 @@ pub fn find_transition_by_status(
 /// Build update payload from field values
 ///
 /// # Arguments
+/// * `flavor` - v3 (Cloud) or v2 (Server / Data Center), for the assignee reference
 /// * `priority` - Optional priority name
 /// * `issue_type` - Optional issue type name
-/// * `assignee_account_id` - Optional assignee account ID
-/// * `description` - Optional description in ADF format
+/// * `assignee` - Optional assignee account ID (Cloud) or username (Data Center)
+/// * `description` - Optional description, from [`rich_text_payload`]
+/// * `custom_fields` - `(field ID, value)` pairs, e.g. from [`build_field_value`]
 ///
 /// # Returns
 /// * `serde_json::Value` - The fields object for the update request
 pub fn build_update_payload(
+    flavor: JiraFlavor,
     priority: Option<&str>,
     issue_type: Option<&str>,
-    assignee_account_id: Option<&str>,
+    assignee: Option<&str>,
     description: Option<&serde_json::Value>,
+    custom_fields: &[(String, serde_json::Value)],
 ) -> serde_json::Value {
     let mut fields = serde_json::json!({});
 
@@ -565,99 +1114,1207 @@ pub fn build_update_payload(
         fields["issuetype"] = serde_json::json!({ "name": type_name });
     }
 
-    if let Some(account_id) = assignee_account_id {
-        fields["assignee"] = serde_json::json!({ "id": account_id });
+    if let Some(user_ref) = assignee {
+        fields["assignee"] = user_ref_payload(flavor, user_ref);
     }
 
     if let Some(desc) = description {
         fields["description"] = desc.clone();
     }
 
+    for (field_id, value) in custom_fields {
+        fields[field_id.as_str()] = value.clone();
+    }
+
     fields
 }
 
-/// Format a byte count as a human-readable size string (e.g., "1.5 KB", "3.0 MB").
-pub fn format_file_size(bytes: u64) -> String {
-    const KB: f64 = 1024.0;
-    const MB: f64 = KB * 1024.0;
-    const GB: f64 = MB * 1024.0;
-    let size_float = bytes as f64;
-    if bytes == 0 {
-        return "0 B".to_string();
-    }
-    if size_float < KB {
-        format!("{} B", bytes)
-    } else if size_float < MB {
-        format!("{:.1} KB", size_float / KB)
-    } else if size_float < GB {
-        format!("{:.1} MB", size_float / MB)
-    } else {
-        format!("{:.1} GB", size_float / GB)
+/// Per-issue result of a bulk comment run
+#[derive(Debug, Serialize, Clone, PartialEq)]
+pub struct BulkCommentResult {
+    pub ticket_key: String,
+    pub success: bool,
+    /// Rendered comment body (markdown)
+    pub body: String,
+    pub comment_id: Option<String>,
+    pub error: Option<String>,
+}
+
+/// Output structure for the bulk-comment command
+#[derive(Debug, Serialize, Clone, PartialEq)]
+pub struct BulkCommentOutput {
+    pub jql: String,
+    pub dry_run: bool,
+    pub succeeded: usize,
+    pub failed: usize,
+    pub results: Vec<BulkCommentResult>,
+}
+
+impl BulkCommentOutput {
+    /// Build the output and tally successes and failures
+    pub fn new(jql: String, dry_run: bool, results: Vec<BulkCommentResult>) -> Self {
+        let succeeded = results.iter().filter(|r| r.success).count();
+        BulkCommentOutput {
+            jql,
+            dry_run,
+            succeeded,
+            failed: results.len() - succeeded,
+            results,
+        }
     }
 }
 
-/// Convert raw Jira attachment API responses into the domain output model.
-pub fn transform_attachment_response(raw: Vec<JiraAttachmentResponse>) -> Vec<AttachmentOutput> {
-    raw.into_iter()
-        .map(|resp| AttachmentOutput {
-            id: resp.id,
-            filename: resp.filename,
-            mime_type: resp.mime_type,
-            size_bytes: resp.size,
-            size_human: format_file_size(resp.size),
-            created: resp.created,
+/// Template variables describing an issue: `key`, `summary`, `status`, and
+/// `assignee` (empty when unassigned)
+pub fn issue_template_vars(issue: &IssueOutput) -> crate::template::TemplateVars {
+    [
+        ("key", issue.key.clone()),
+        ("summary", issue.summary.clone()),
+        ("status", issue.status.clone()),
+        ("assignee", issue.assignee.clone().unwrap_or_default()),
+    ]
+    .into_iter()
+    .map(|(k, v)| (k.to_string(), v))
+    .collect()
+}
+
+/// Render a comment body for each issue
+///
+/// Each issue's built-in variables (see [`issue_template_vars`]) are merged
+/// with `vars`; user-supplied variables win on conflict. Fails on the first
+/// template error so nothing is posted when a placeholder is misspelled.
+///
+/// # Returns
+/// * `Ok(Vec<(String, String)>)` - `(ticket_key, body)` pairs in issue order
+pub fn render_bulk_comments(
+    issues: &[IssueOutput],
+    template: &str,
+    vars: &crate::template::TemplateVars,
+) -> Result<Vec<(String, String)>, crate::template::TemplateError> {
+    issues
+        .iter()
+        .map(|issue| {
+            let mut all_vars = issue_template_vars(issue);
+            all_vars.extend(vars.iter().map(|(k, v)| (k.clone(), v.clone())));
+            crate::template::render(template, &all_vars).map(|body| (issue.key.clone(), body))
         })
         .collect()
 }
 
-/// Transform a raw Jira comment API response into the domain output model.
-pub fn transform_comment_response(ticket_key: &str, comment: JiraComment) -> CommentOutput {
-    let author = comment
-        .author
-        .and_then(|a| a.display_name.or(a.email_address));
+/// Field edits applied on top of [`build_update_payload`]
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct FieldEdits {
+    pub summary: Option<String>,
+    /// New due date (`YYYY-MM-DD`); `Some(None)` clears it
+    pub due_date: Option<Option<String>>,
+    /// Replaces the ticket's components with these names
+    pub components: Option<Vec<String>>,
+    pub add_labels: Vec<String>,
+    pub remove_labels: Vec<String>,
+}
 
-    CommentOutput {
-        ticket_key: ticket_key.to_string(),
-        comment_id: comment.comment_id,
-        author,
-        body: render_adf(&comment.body),
-        created_at: comment.created_at,
+impl FieldEdits {
+    /// Whether any edit is present
+    pub fn is_empty(&self) -> bool {
+        self == &FieldEdits::default()
     }
 }
 
-/// Convert a vector of raw Jira comments into the domain output model.
-pub fn transform_comment_list_response(
-    ticket_key: &str,
-    comments: Vec<JiraComment>,
-) -> Vec<CommentOutput> {
-    comments
-        .into_iter()
-        .map(|c| transform_comment_response(ticket_key, c))
-        .collect()
+/// Parse a due date argument
+///
+/// Accepts `YYYY-MM-DD`, or `none` / an empty string to clear the due date.
+///
+/// # Returns
+/// * `Ok(Some(date))` - A valid date
+/// * `Ok(None)` - The due date should be cleared
+/// * `Err(String)` - The input is not a valid date
+pub fn parse_due_date(input: &str) -> Result<Option<String>, String> {
+    let trimmed = input.trim();
+    if trimmed.is_empty() || trimmed.eq_ignore_ascii_case("none") {
+        return Ok(None);
+    }
+    chrono::NaiveDate::parse_from_str(trimmed, "%Y-%m-%d")
+        .map(|date| Some(date.format("%Y-%m-%d").to_string()))
+        .map_err(|_| format!("Invalid due date '{trimmed}' (expected YYYY-MM-DD or 'none')"))
 }
 
-/// Convert markdown text to Atlassian Document Format (ADF) JSON.
+/// Build the full issue edit request body
 ///
-/// Handles block-level elements (headings, code blocks, lists, paragraphs)
-/// and inline marks (bold, italic, code, links). Never fails — malformed
-/// markdown degrades to plain text paragraphs.
-pub fn markdown_to_adf(input: &str) -> serde_json::Value {
-    let lines: Vec<&str> = input.lines().collect();
-    let mut blocks: Vec<serde_json::Value> = Vec::new();
-    let mut i = 0;
+/// Merges `fields` (from [`build_update_payload`]) with `edits`. Summary, due
+/// date, and components are set directly under `fields`; labels use the
+/// `update` verbs so existing labels are preserved.
+///
+/// # Returns
+/// * `Ok(serde_json::Value)` - `{"fields": {...}}`, plus `"update"` when labels change
+/// * `Err(String)` - A label contains whitespace or is both added and removed
+pub fn build_update_request(
+    fields: serde_json::Value,
+    edits: &FieldEdits,
+) -> Result<serde_json::Value, String> {
+    let mut fields = fields;
+
+    if let Some(summary) = &edits.summary {
+        fields["summary"] = serde_json::json!(summary);
+    }
 
-    while i < lines.len() {
-        let line = lines[i];
+    if let Some(due_date) = &edits.due_date {
+        fields["duedate"] = serde_json::json!(due_date);
+    }
 
-        // Fenced code block
-        if let Some(rest) = line.strip_prefix("```") {
-            let language = rest.trim().to_string();
-            let mut code_lines: Vec<&str> = Vec::new();
-            i += 1;
-            while i < lines.len() && !lines[i].starts_with("```") {
-                code_lines.push(lines[i]);
-                i += 1;
-            }
+    if let Some(components) = &edits.components {
+        fields["components"] = components
+            .iter()
+            .map(|name| serde_json::json!({ "name": name }))
+            .collect();
+    }
+
+    let mut request = serde_json::json!({ "fields": fields });
+
+    for label in edits.add_labels.iter().chain(&edits.remove_labels) {
+        validate_label(label)?;
+    }
+    if let Some(label) = edits
+        .add_labels
+        .iter()
+        .find(|l| edits.remove_labels.contains(l))
+    {
+        return Err(format!("Label '{label}' cannot be both added and removed"));
+    }
+
+    let label_ops: Vec<serde_json::Value> = edits
+        .add_labels
+        .iter()
+        .map(|l| serde_json::json!({ "add": l }))
+        .chain(
+            edits
+                .remove_labels
+                .iter()
+                .map(|l| serde_json::json!({ "remove": l })),
+        )
+        .collect();
+    if !label_ops.is_empty() {
+        request["update"] = serde_json::json!({ "labels": label_ops });
+    }
+
+    Ok(request)
+}
+
+/// Reject labels Jira would split or refuse
+fn validate_label(label: &str) -> Result<(), String> {
+    if label.is_empty() || label.chars().any(char::is_whitespace) {
+        return Err(format!(
+            "Invalid label '{label}': labels cannot contain spaces"
+        ));
+    }
+    Ok(())
+}
+
+/// One validated row of a bulk update file
+#[derive(Debug, Default, Clone, PartialEq, Serialize)]
+pub struct BulkUpdateRow {
+    /// 1-based position of the row in the input, after any CSV header
+    pub row: usize,
+    pub key: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub priority: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub issue_type: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub assignee: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub summary: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// Due date as given (`YYYY-MM-DD` or `none`), checked with [`parse_due_date`]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub due_date: Option<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub components: Vec<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub add_labels: Vec<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub remove_labels: Vec<String>,
+}
+
+impl BulkUpdateRow {
+    /// The fields this row changes, with their new values, for previews
+    pub fn planned_fields(&self) -> Vec<FieldUpdateResult> {
+        let list = |values: &Vec<String>| (!values.is_empty()).then(|| values.join(", "));
+        [
+            ("status", self.status.clone()),
+            ("priority", self.priority.clone()),
+            ("issue_type", self.issue_type.clone()),
+            ("assignee", self.assignee.clone()),
+            ("summary", self.summary.clone()),
+            (
+                "description",
+                self.description.as_ref().map(|_| "(updated)".to_string()),
+            ),
+            ("duedate", self.due_date.clone()),
+            ("components", list(&self.components)),
+            ("add_labels", list(&self.add_labels)),
+            ("remove_labels", list(&self.remove_labels)),
+        ]
+        .into_iter()
+        .filter_map(|(field, value)| {
+            value.map(|value| FieldUpdateResult {
+                field: field.to_string(),
+                success: true,
+                value: Some(value),
+                error: None,
+            })
+        })
+        .collect()
+    }
+}
+
+/// Outcome of one row of a bulk update: the row's [`UpdateOutput`] plus its
+/// position and any error that stopped the update as a whole
+#[derive(Debug, Serialize, Clone, PartialEq)]
+pub struct BulkUpdateResult {
+    pub row: usize,
+    pub success: bool,
+    #[serde(flatten)]
+    pub update: UpdateOutput,
+    pub error: Option<String>,
+}
+
+/// Output structure for the bulk-update command
+#[derive(Debug, Serialize, Clone, PartialEq)]
+pub struct BulkUpdateOutput {
+    pub dry_run: bool,
+    pub succeeded: usize,
+    pub failed: usize,
+    pub results: Vec<BulkUpdateResult>,
+}
+
+impl BulkUpdateOutput {
+    /// Build the output and tally successes and failures
+    pub fn new(dry_run: bool, results: Vec<BulkUpdateResult>) -> Self {
+        let succeeded = results.iter().filter(|r| r.success).count();
+        BulkUpdateOutput {
+            dry_run,
+            succeeded,
+            failed: results.len() - succeeded,
+            results,
+        }
+    }
+}
+
+/// Split a CSV document into records (RFC 4180: quoted fields may contain
+/// commas, newlines, and `""` escapes). Blank lines are skipped.
+fn parse_csv_records(input: &str) -> Result<Vec<Vec<String>>, String> {
+    let mut records = Vec::new();
+    let mut record = Vec::new();
+    let mut field = String::new();
+    let mut in_quotes = false;
+    let mut chars = input.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        if in_quotes {
+            match c {
+                '"' if chars.peek() == Some(&'"') => {
+                    field.push('"');
+                    chars.next();
+                }
+                '"' => in_quotes = false,
+                _ => field.push(c),
+            }
+            continue;
+        }
+        match c {
+            '"' if field.is_empty() => in_quotes = true,
+            ',' => record.push(std::mem::take(&mut field)),
+            '\r' if chars.peek() == Some(&'\n') => {}
+            '\n' => {
+                record.push(std::mem::take(&mut field));
+                if record.iter().any(|f| !f.is_empty()) {
+                    records.push(std::mem::take(&mut record));
+                }
+                record.clear();
+            }
+            _ => field.push(c),
+        }
+    }
+    if in_quotes {
+        return Err("Unterminated quoted field in CSV".to_string());
+    }
+    record.push(field);
+    if record.iter().any(|f| !f.is_empty()) {
+        records.push(record);
+    }
+    Ok(records)
+}
+
+/// Split a list cell on `;` (CSV) into trimmed, non-empty items
+fn split_list_cell(value: &str) -> Vec<String> {
+    value
+        .split(';')
+        .map(str::trim)
+        .filter(|v| !v.is_empty())
+        .map(str::to_string)
+        .collect()
+}
+
+/// Check that `key` looks like an issue key such as `PROJ-123`
+fn is_issue_key(key: &str) -> bool {
+    match key.rsplit_once('-') {
+        Some((project, number)) => {
+            !project.is_empty()
+                && project
+                    .chars()
+                    .all(|c| c.is_ascii_alphanumeric() || c == '_')
+                && !number.is_empty()
+                && number.chars().all(|c| c.is_ascii_digit())
+        }
+        None => false,
+    }
+}
+
+/// Validate one bulk update record of `(column, value)` pairs
+///
+/// Column names are case-insensitive and may use spaces, dashes, or
+/// underscores: `key`, `status`, `priority`, `type`, `assignee`, `summary`,
+/// `description`, `due_date`, `components`, `add_labels`, `remove_labels`.
+/// Empty values are ignored; list columns take several values separated by
+/// `;`.
+pub fn validate_bulk_update_record(
+    row: usize,
+    record: &[(String, String)],
+) -> Result<BulkUpdateRow, String> {
+    let mut update = BulkUpdateRow {
+        row,
+        ..Default::default()
+    };
+
+    for (column, value) in record {
+        let value = value.trim();
+        let single = || (!value.is_empty()).then(|| value.to_string());
+        match column
+            .trim()
+            .to_ascii_lowercase()
+            .replace([' ', '-'], "_")
+            .as_str()
+        {
+            "key" | "issue_key" => update.key = value.to_string(),
+            "status" => update.status = single(),
+            "priority" => update.priority = single(),
+            "type" | "issue_type" => update.issue_type = single(),
+            "assignee" => update.assignee = single(),
+            "summary" => update.summary = single(),
+            "description" => update.description = single(),
+            "due_date" | "duedate" => update.due_date = single(),
+            "components" => update.components = split_list_cell(value),
+            "add_labels" => update.add_labels = split_list_cell(value),
+            "remove_labels" => update.remove_labels = split_list_cell(value),
+            other => return Err(format!("row {row}: unknown column '{other}'")),
+        }
+    }
+
+    if update.key.is_empty() {
+        return Err(format!("row {row}: missing issue key"));
+    }
+    if !is_issue_key(&update.key) {
+        return Err(format!(
+            "row {row}: '{}' is not an issue key (expected e.g. PROJ-123)",
+            update.key
+        ));
+    }
+    if let Some(due_date) = &update.due_date {
+        parse_due_date(due_date).map_err(|e| format!("row {row}: {e}"))?;
+    }
+    for label in update.add_labels.iter().chain(&update.remove_labels) {
+        validate_label(label).map_err(|e| format!("row {row}: {e}"))?;
+    }
+
+    let unchanged = BulkUpdateRow {
+        row,
+        key: update.key.clone(),
+        ..Default::default()
+    };
+    if update == unchanged {
+        return Err(format!("row {row}: no fields to update for {}", update.key));
+    }
+
+    Ok(update)
+}
+
+/// Collect every row, or fail with all row errors joined by newlines
+fn collect_bulk_rows(
+    results: Vec<Result<BulkUpdateRow, String>>,
+) -> Result<Vec<BulkUpdateRow>, String> {
+    let errors: Vec<String> = results
+        .iter()
+        .filter_map(|r| r.as_ref().err().cloned())
+        .collect();
+    if !errors.is_empty() {
+        return Err(errors.join("\n"));
+    }
+    let rows: Vec<BulkUpdateRow> = results.into_iter().flatten().collect();
+    if rows.is_empty() {
+        return Err("No rows to update".to_string());
+    }
+    Ok(rows)
+}
+
+/// Parse and validate a bulk update CSV with a header row
+///
+/// Every row is validated before any is returned; the error lists each
+/// invalid row on its own line.
+pub fn parse_bulk_update_csv(input: &str) -> Result<Vec<BulkUpdateRow>, String> {
+    let mut records = parse_csv_records(input)?.into_iter();
+    let header = records
+        .next()
+        .ok_or_else(|| "CSV is empty; expected a header row with a 'key' column".to_string())?;
+
+    let results = records
+        .enumerate()
+        .map(|(index, record)| {
+            let row = index + 1;
+            if record.len() > header.len() {
+                return Err(format!(
+                    "row {row}: {} values for {} columns",
+                    record.len(),
+                    header.len()
+                ));
+            }
+            let pairs: Vec<(String, String)> = header.iter().cloned().zip(record).collect();
+            validate_bulk_update_record(row, &pairs)
+        })
+        .collect();
+
+    collect_bulk_rows(results)
+}
+
+/// Parse and validate a bulk update JSON array of objects
+///
+/// Objects use the same keys as the CSV columns; list fields may be arrays
+/// or `;`-separated strings.
+pub fn parse_bulk_update_json(value: &serde_json::Value) -> Result<Vec<BulkUpdateRow>, String> {
+    let items = value
+        .as_array()
+        .ok_or_else(|| "Expected a JSON array of update objects".to_string())?;
+
+    let results = items
+        .iter()
+        .enumerate()
+        .map(|(index, item)| {
+            let row = index + 1;
+            let object = item
+                .as_object()
+                .ok_or_else(|| format!("row {row}: expected an object"))?;
+            let pairs: Vec<(String, String)> = object
+                .iter()
+                .map(|(column, value)| {
+                    let value = match value {
+                        serde_json::Value::Null => String::new(),
+                        serde_json::Value::String(s) => s.clone(),
+                        serde_json::Value::Array(items) => items
+                            .iter()
+                            .map(|v| v.as_str().map(str::to_string).unwrap_or(v.to_string()))
+                            .collect::<Vec<_>>()
+                            .join(";"),
+                        other => other.to_string(),
+                    };
+                    (column.clone(), value)
+                })
+                .collect();
+            validate_bulk_update_record(row, &pairs)
+        })
+        .collect();
+
+    collect_bulk_rows(results)
+}
+
+/// Fields of a ticket to create
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct NewIssue {
+    pub project: String,
+    pub issue_type: String,
+    pub summary: String,
+    /// Description in markdown, converted to ADF in the payload on Cloud
+    pub description: Option<String>,
+    pub priority: Option<String>,
+    /// Assignee account ID (Cloud) or username (Data Center)
+    pub assignee: Option<String>,
+    pub labels: Vec<String>,
+}
+
+/// Build the issue create request body
+///
+/// # Returns
+/// * `Ok(serde_json::Value)` - `{"fields": {...}}` with the description as ADF
+///   on Cloud and as text on Server / Data Center
+/// * `Err(String)` - The summary is blank or a label contains whitespace
+pub fn build_create_payload(
+    issue: &NewIssue,
+    flavor: JiraFlavor,
+) -> Result<serde_json::Value, String> {
+    let summary = issue.summary.trim();
+    if summary.is_empty() {
+        return Err("Summary cannot be empty".to_string());
+    }
+    for label in &issue.labels {
+        validate_label(label)?;
+    }
+
+    let mut fields = build_update_payload(
+        flavor,
+        issue.priority.as_deref(),
+        Some(&issue.issue_type),
+        issue.assignee.as_deref(),
+        issue
+            .description
+            .as_deref()
+            .map(|d| rich_text_payload(flavor, d))
+            .as_ref(),
+        &[],
+    );
+    fields["summary"] = serde_json::json!(summary);
+    fields["project"] = serde_json::json!({ "key": issue.project });
+    if !issue.labels.is_empty() {
+        fields["labels"] = serde_json::json!(issue.labels);
+    }
+
+    Ok(serde_json::json!({ "fields": fields }))
+}
+
+/// Format a byte count as a human-readable size string (e.g., "1.5 KB", "3.0 MB").
+pub fn format_file_size(bytes: u64) -> String {
+    const KB: f64 = 1024.0;
+    const MB: f64 = KB * 1024.0;
+    const GB: f64 = MB * 1024.0;
+    let size_float = bytes as f64;
+    if bytes == 0 {
+        return "0 B".to_string();
+    }
+    if size_float < KB {
+        format!("{} B", bytes)
+    } else if size_float < MB {
+        format!("{:.1} KB", size_float / KB)
+    } else if size_float < GB {
+        format!("{:.1} MB", size_float / MB)
+    } else {
+        format!("{:.1} GB", size_float / GB)
+    }
+}
+
+/// Convert raw Jira attachment API responses into the domain output model.
+pub fn transform_attachment_response(raw: Vec<JiraAttachmentResponse>) -> Vec<AttachmentOutput> {
+    raw.into_iter()
+        .map(|resp| AttachmentOutput {
+            id: resp.id,
+            filename: resp.filename,
+            mime_type: resp.mime_type,
+            size_bytes: resp.size,
+            size_human: format_file_size(resp.size),
+            created: resp.created,
+        })
+        .collect()
+}
+
+/// Local file name for saving an attachment
+///
+/// Only the last path component of the filename is kept, so a name like
+/// `../notes.txt` cannot escape the directory it is saved in. Names with no
+/// usable component fall back to the attachment ID.
+pub fn attachment_file_name(attachment: &JiraAttachmentResponse) -> String {
+    std::path::Path::new(&attachment.filename)
+        .file_name()
+        .map(|name| name.to_string_lossy().to_string())
+        .unwrap_or_else(|| attachment.id.clone())
+}
+
+/// Local file names for saving attachments side by side in one directory
+///
+/// Each name goes through [`attachment_file_name`]. When several attachments
+/// share a name, all but the first are prefixed with their attachment ID,
+/// and then with a counter until the name is free.
+pub fn attachment_file_names(attachments: &[JiraAttachmentResponse]) -> Vec<String> {
+    let mut taken = std::collections::HashSet::new();
+    attachments
+        .iter()
+        .map(|attachment| {
+            let base = attachment_file_name(attachment);
+            let mut name = base.clone();
+            let mut n = 1;
+            while taken.contains(&name) {
+                name = if n == 1 {
+                    format!("{}-{}", attachment.id, base)
+                } else {
+                    format!("{}-{}-{}", attachment.id, n, base)
+                };
+                n += 1;
+            }
+            taken.insert(name.clone());
+            name
+        })
+        .collect()
+}
+
+/// Transform a raw Jira comment API response into the domain output model.
+pub fn transform_comment_response(ticket_key: &str, comment: JiraComment) -> CommentOutput {
+    let author = comment
+        .author
+        .and_then(|a| a.display_name.or(a.email_address));
+
+    CommentOutput {
+        ticket_key: ticket_key.to_string(),
+        comment_id: comment.comment_id,
+        author,
+        body: extract_description(Some(comment.body)),
+        created_at: comment.created_at,
+    }
+}
+
+/// Convert a vector of raw Jira comments into the domain output model.
+pub fn transform_comment_list_response(
+    ticket_key: &str,
+    comments: Vec<JiraComment>,
+) -> Vec<CommentOutput> {
+    comments
+        .into_iter()
+        .map(|c| transform_comment_response(ticket_key, c))
+        .collect()
+}
+
+/// Seconds in a Jira working day (8h), used for `d` in durations
+pub const WORKLOG_DAY_SECS: u64 = 8 * 60 * 60;
+
+/// Seconds in a Jira working week (5d), used for `w` in durations
+pub const WORKLOG_WEEK_SECS: u64 = 5 * WORKLOG_DAY_SECS;
+
+/// Worklog entry from the Jira API
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
+pub struct JiraWorklog {
+    pub id: String,
+    pub author: Option<JiraAssignee>,
+    /// Comment in ADF, absent when the worklog has none
+    #[serde(default)]
+    pub comment: Option<serde_json::Value>,
+    pub started: String,
+    #[serde(rename = "timeSpent")]
+    pub time_spent: String,
+    #[serde(rename = "timeSpentSeconds")]
+    pub time_spent_seconds: u64,
+}
+
+/// Worklog list response from the Jira API
+#[derive(Debug, Deserialize, Clone)]
+pub struct JiraWorklogListResponse {
+    #[serde(default)]
+    pub worklogs: Vec<JiraWorklog>,
+}
+
+/// Output structure for a Jira worklog entry (used by add and list)
+#[derive(Debug, Serialize, Clone, Deserialize, PartialEq)]
+pub struct WorklogOutput {
+    pub ticket_key: String,
+    pub worklog_id: String,
+    pub author: Option<String>,
+    pub comment: Option<String>,
+    pub started: String,
+    pub time_spent: String,
+    pub time_spent_seconds: u64,
+}
+
+/// Output structure for the worklog list command
+#[derive(Debug, Serialize, Clone, Deserialize, PartialEq)]
+pub struct WorklogListOutput {
+    pub ticket_key: String,
+    pub worklogs: Vec<WorklogOutput>,
+    pub total_seconds: u64,
+    /// `total_seconds` in Jira's duration notation (e.g. "1d 2h 30m")
+    pub total_time_spent: String,
+}
+
+/// Parse a duration in Jira's notation into seconds
+///
+/// Accepts one or more `<number><unit>` parts, optionally separated by
+/// spaces, with units `w` (5 working days), `d` (8 hours), `h`, and `m`:
+/// `1h30m`, `2d 4h`, `45m`.
+///
+/// # Returns
+/// * `Ok(u64)` - The duration in seconds, at least one minute
+/// * `Err(String)` - The input is empty, has an unknown unit, or is zero
+pub fn parse_worklog_duration(input: &str) -> Result<u64, String> {
+    let invalid = || format!("Invalid duration '{input}' (expected e.g. 1h30m, 2d, 45m)");
+    let compact: String = input.chars().filter(|c| !c.is_whitespace()).collect();
+    if compact.is_empty() {
+        return Err(invalid());
+    }
+
+    let mut total = 0u64;
+    let mut digits = String::new();
+    for c in compact.chars() {
+        if c.is_ascii_digit() {
+            digits.push(c);
+            continue;
+        }
+        let unit = match c.to_ascii_lowercase() {
+            'w' => WORKLOG_WEEK_SECS,
+            'd' => WORKLOG_DAY_SECS,
+            'h' => 60 * 60,
+            'm' => 60,
+            _ => return Err(invalid()),
+        };
+        let amount: u64 = digits.parse().map_err(|_| invalid())?;
+        total += amount * unit;
+        digits.clear();
+    }
+    if !digits.is_empty() {
+        return Err(invalid());
+    }
+    if total == 0 {
+        return Err(format!("Duration '{input}' must be at least 1m"));
+    }
+    Ok(total)
+}
+
+/// Render seconds in Jira's duration notation, e.g. `1w 2d 3h 30m`
+///
+/// Uses the same working day and week as [`parse_worklog_duration`].
+/// Seconds below a minute are dropped; zero renders as `0m`.
+pub fn format_worklog_duration(seconds: u64) -> String {
+    let mut remaining = seconds;
+    let parts: Vec<String> = [
+        (WORKLOG_WEEK_SECS, "w"),
+        (WORKLOG_DAY_SECS, "d"),
+        (60 * 60, "h"),
+        (60, "m"),
+    ]
+    .iter()
+    .filter_map(|&(unit, suffix)| {
+        let amount = remaining / unit;
+        remaining %= unit;
+        (amount > 0).then(|| format!("{amount}{suffix}"))
+    })
+    .collect();
+
+    if parts.is_empty() {
+        "0m".to_string()
+    } else {
+        parts.join(" ")
+    }
+}
+
+/// Build the request body for adding a worklog
+///
+/// `comment` is markdown, converted with [`rich_text_payload`].
+pub fn build_worklog_payload(
+    time_spent_seconds: u64,
+    comment: Option<&str>,
+    flavor: JiraFlavor,
+) -> serde_json::Value {
+    let mut payload = serde_json::json!({ "timeSpentSeconds": time_spent_seconds });
+    if let Some(comment) = comment.filter(|c| !c.trim().is_empty()) {
+        payload["comment"] = rich_text_payload(flavor, comment);
+    }
+    payload
+}
+
+/// Transform a raw Jira worklog into the domain output model.
+pub fn transform_worklog_response(ticket_key: &str, worklog: JiraWorklog) -> WorklogOutput {
+    WorklogOutput {
+        ticket_key: ticket_key.to_string(),
+        worklog_id: worklog.id,
+        author: worklog
+            .author
+            .and_then(|a| a.display_name.or(a.email_address)),
+        comment: extract_description(worklog.comment),
+        started: worklog.started,
+        time_spent: worklog.time_spent,
+        time_spent_seconds: worklog.time_spent_seconds,
+    }
+}
+
+/// Transform a worklog list response, totalling the time logged.
+pub fn transform_worklog_list_response(
+    ticket_key: &str,
+    response: JiraWorklogListResponse,
+) -> WorklogListOutput {
+    let worklogs: Vec<WorklogOutput> = response
+        .worklogs
+        .into_iter()
+        .map(|w| transform_worklog_response(ticket_key, w))
+        .collect();
+    let total_seconds = worklogs.iter().map(|w| w.time_spent_seconds).sum();
+
+    WorklogListOutput {
+        ticket_key: ticket_key.to_string(),
+        worklogs,
+        total_seconds,
+        total_time_spent: format_worklog_duration(total_seconds),
+    }
+}
+
+/// Watchers response from the Jira API
+#[derive(Debug, Deserialize, Clone)]
+pub struct JiraWatchersResponse {
+    #[serde(rename = "watchCount", default)]
+    pub watch_count: u64,
+    #[serde(rename = "isWatching", default)]
+    pub is_watching: bool,
+    #[serde(default)]
+    pub watchers: Vec<JiraUser>,
+}
+
+/// Output structure for a single watcher
+#[derive(Debug, Serialize, Clone, Deserialize, PartialEq)]
+pub struct WatcherOutput {
+    /// Account ID on Cloud, username on Server / Data Center
+    pub account_id: String,
+    pub display_name: Option<String>,
+    pub email: Option<String>,
+}
+
+/// Output structure for the watch command
+#[derive(Debug, Serialize, Clone, Deserialize, PartialEq)]
+pub struct WatchersOutput {
+    pub ticket_key: String,
+    pub watch_count: u64,
+    /// Whether the authenticated user watches the ticket
+    pub is_watching: bool,
+    pub watchers: Vec<WatcherOutput>,
+}
+
+/// Transform a watchers response into the domain output model.
+pub fn transform_watchers_response(
+    ticket_key: &str,
+    response: JiraWatchersResponse,
+) -> WatchersOutput {
+    WatchersOutput {
+        ticket_key: ticket_key.to_string(),
+        watch_count: response.watch_count,
+        is_watching: response.is_watching,
+        watchers: response
+            .watchers
+            .into_iter()
+            .map(|user| WatcherOutput {
+                account_id: match user.name {
+                    Some(name) if user.account_id.is_empty() => name,
+                    _ => user.account_id,
+                },
+                display_name: user.display_name,
+                email: user.email_address,
+            })
+            .collect(),
+    }
+}
+
+/// One change set from an issue's history: the fields one user changed at once
+#[derive(Debug, Deserialize, Clone)]
+pub struct JiraChangelogHistory {
+    #[serde(default)]
+    pub id: String,
+    #[serde(default)]
+    pub author: Option<JiraUser>,
+    pub created: String,
+    #[serde(default)]
+    pub items: Vec<JiraChangelogItem>,
+}
+
+/// A single field change within a change set
+#[derive(Debug, Deserialize, Clone)]
+pub struct JiraChangelogItem {
+    pub field: String,
+    #[serde(rename = "fieldId", default)]
+    pub field_id: Option<String>,
+    /// Readable previous value
+    #[serde(rename = "fromString", default)]
+    pub from_text: Option<String>,
+    /// Readable new value
+    #[serde(rename = "toString", default)]
+    pub to_text: Option<String>,
+}
+
+/// A page of issue history: `/issue/{key}/changelog` on Cloud (`values`), or
+/// the `changelog` of an issue fetched with `expand=changelog` (`histories`)
+#[derive(Debug, Deserialize, Clone)]
+pub struct JiraChangelogPage {
+    #[serde(alias = "histories", default)]
+    pub values: Vec<JiraChangelogHistory>,
+    #[serde(rename = "startAt", default)]
+    pub start_at: u64,
+    #[serde(default)]
+    pub total: Option<u64>,
+    #[serde(rename = "isLast", default)]
+    pub is_last: Option<bool>,
+}
+
+/// Output structure for a single field change
+#[derive(Debug, Serialize, Clone, Deserialize, PartialEq)]
+pub struct ChangelogEntry {
+    pub created: String,
+    pub author: Option<String>,
+    pub field: String,
+    pub from: Option<String>,
+    pub to: Option<String>,
+}
+
+/// Output structure for the history command
+#[derive(Debug, Serialize, Clone, Deserialize, PartialEq)]
+pub struct ChangelogOutput {
+    pub ticket_key: String,
+    /// Field changes, oldest first
+    pub entries: Vec<ChangelogEntry>,
+}
+
+/// Flatten change sets into one entry per field change, oldest first
+///
+/// `field` keeps only changes to that field, matched case-insensitively on
+/// its name or ID.
+pub fn transform_changelog(
+    ticket_key: &str,
+    histories: Vec<JiraChangelogHistory>,
+    field: Option<&str>,
+) -> ChangelogOutput {
+    let matches = |item: &JiraChangelogItem| {
+        field.is_none_or(|f| {
+            item.field.eq_ignore_ascii_case(f)
+                || item
+                    .field_id
+                    .as_deref()
+                    .is_some_and(|id| id.eq_ignore_ascii_case(f))
+        })
+    };
+
+    let mut histories = histories;
+    histories.sort_by_key(|h| {
+        chrono::DateTime::parse_from_str(&h.created, "%Y-%m-%dT%H:%M:%S%.f%z").ok()
+    });
+
+    let entries = histories
+        .into_iter()
+        .flat_map(|history| {
+            let author = history
+                .author
+                .and_then(|a| a.display_name.or(a.email_address).or(a.name));
+            let created = history.created;
+            history
+                .items
+                .into_iter()
+                .filter(|item| matches(item))
+                .map(move |item| ChangelogEntry {
+                    created: created.clone(),
+                    author: author.clone(),
+                    field: item.field,
+                    from: item.from_text.filter(|s| !s.is_empty()),
+                    to: item.to_text.filter(|s| !s.is_empty()),
+                })
+        })
+        .collect();
+
+    ChangelogOutput {
+        ticket_key: ticket_key.to_string(),
+        entries,
+    }
+}
+
+/// Default field for story points: "Story point estimate" on Jira Cloud
+pub const DEFAULT_STORY_POINTS_FIELD: &str = "customfield_10016";
+
+/// The story points field ID: `requested` (an alias or an ID) when given,
+/// else the `story_points` alias, else [`DEFAULT_STORY_POINTS_FIELD`]
+pub fn story_points_field(aliases: &FieldAliases, requested: Option<&str>) -> String {
+    match requested {
+        Some(field) => aliases.get(field).map_or(field, String::as_str),
+        None => aliases
+            .get("story_points")
+            .map_or(DEFAULT_STORY_POINTS_FIELD, String::as_str),
+    }
+    .to_string()
+}
+
+/// Status category of a Jira status (`new`, `indeterminate`, or `done`)
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct JiraStatusCategory {
+    pub key: String,
+}
+
+/// Jira status with its category, as returned when searching child issues
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct JiraChildStatus {
+    pub name: String,
+    #[serde(rename = "statusCategory", default)]
+    pub status_category: Option<JiraStatusCategory>,
+}
+
+/// Fields of an epic's child issue. Custom fields such as story points are
+/// kept in `custom`, since their IDs differ between sites.
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct JiraChildIssueFields {
+    pub summary: String,
+    pub status: JiraChildStatus,
+    #[serde(default)]
+    pub assignee: Option<JiraAssignee>,
+    #[serde(default)]
+    pub issuetype: Option<JiraIssueType>,
+    #[serde(flatten)]
+    pub custom: serde_json::Map<String, serde_json::Value>,
+}
+
+/// Child issue of an epic from the Jira search API
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct JiraChildIssue {
+    pub key: String,
+    pub fields: JiraChildIssueFields,
+}
+
+/// One page of an epic's child issues from the Jira search API
+#[derive(Debug, Deserialize, Clone)]
+pub struct JiraChildSearchResponse {
+    pub issues: Vec<JiraChildIssue>,
+    #[serde(default)]
+    pub total: Option<u64>,
+    #[serde(rename = "nextPageToken", default)]
+    pub next_page_token: Option<String>,
+}
+
+/// Output structure for a child issue of an epic
+#[derive(Debug, Serialize, Clone, Deserialize, PartialEq)]
+pub struct EpicChildOutput {
+    pub key: String,
+    pub summary: String,
+    pub issue_type: Option<String>,
+    pub status: String,
+    /// Whether the status is in the Done category
+    pub done: bool,
+    pub assignee: Option<String>,
+    pub story_points: Option<f64>,
+}
+
+/// Issue count and story points for one status
+#[derive(Debug, Serialize, Clone, Deserialize, PartialEq)]
+pub struct EpicStatusCount {
+    pub status: String,
+    pub issues: usize,
+    pub story_points: f64,
+}
+
+/// Progress of an epic, summed over its children
+#[derive(Debug, Serialize, Clone, Deserialize, PartialEq)]
+pub struct EpicRollup {
+    pub total_issues: usize,
+    pub done_issues: usize,
+    pub total_points: f64,
+    pub done_points: f64,
+    /// Children without story points
+    pub unestimated_issues: usize,
+    /// Done share in percent, by story points when any child is estimated,
+    /// otherwise by issue count
+    pub percent_done: f64,
+    /// Per-status totals, in the order statuses first appear
+    pub by_status: Vec<EpicStatusCount>,
+}
+
+/// Output structure for the epic command
+#[derive(Debug, Serialize, Clone, Deserialize, PartialEq)]
+pub struct EpicOutput {
+    pub epic: TicketOutput,
+    pub children: Vec<EpicChildOutput>,
+    pub rollup: EpicRollup,
+}
+
+/// Transform an epic's child issues, reading story points from `points_field`.
+pub fn transform_epic_children(
+    issues: Vec<JiraChildIssue>,
+    points_field: &str,
+) -> Vec<EpicChildOutput> {
+    issues
+        .into_iter()
+        .map(|issue| {
+            let fields = issue.fields;
+            let done = match &fields.status.status_category {
+                Some(category) => category.key == "done",
+                None => ["done", "closed", "resolved"]
+                    .iter()
+                    .any(|s| fields.status.name.eq_ignore_ascii_case(s)),
+            };
+            EpicChildOutput {
+                key: issue.key,
+                summary: fields.summary,
+                issue_type: fields.issuetype.map(|t| t.name),
+                status: fields.status.name,
+                done,
+                assignee: fields
+                    .assignee
+                    .and_then(|a| a.display_name.or(a.email_address)),
+                story_points: fields.custom.get(points_field).and_then(|v| v.as_f64()),
+            }
+        })
+        .collect()
+}
+
+/// Sum issue counts and story points over an epic's children.
+pub fn epic_rollup(children: &[EpicChildOutput]) -> EpicRollup {
+    let mut by_status: Vec<EpicStatusCount> = Vec::new();
+    for child in children {
+        let points = child.story_points.unwrap_or(0.0);
+        match by_status.iter_mut().find(|s| s.status == child.status) {
+            Some(entry) => {
+                entry.issues += 1;
+                entry.story_points += points;
+            }
+            None => by_status.push(EpicStatusCount {
+                status: child.status.clone(),
+                issues: 1,
+                story_points: points,
+            }),
+        }
+    }
+
+    let total_issues = children.len();
+    let done_issues = children.iter().filter(|c| c.done).count();
+    let total_points: f64 = children.iter().filter_map(|c| c.story_points).sum();
+    let done_points: f64 = children
+        .iter()
+        .filter(|c| c.done)
+        .filter_map(|c| c.story_points)
+        .sum();
+    let percent_done = if total_points > 0.0 {
+        done_points / total_points * 100.0
+    } else if total_issues > 0 {
+        done_issues as f64 / total_issues as f64 * 100.0
+    } else {
+        0.0
+    };
+
+    EpicRollup {
+        total_issues,
+        done_issues,
+        total_points,
+        done_points,
+        unestimated_issues: children.iter().filter(|c| c.story_points.is_none()).count(),
+        percent_done,
+        by_status,
+    }
+}
+
+/// Convert markdown text to Atlassian Document Format (ADF) JSON.
+///
+/// Handles block-level elements (headings, code blocks, lists, paragraphs)
+/// and inline marks (bold, italic, code, links). Never fails — malformed
+/// markdown degrades to plain text paragraphs.
+pub fn markdown_to_adf(input: &str) -> serde_json::Value {
+    let lines: Vec<&str> = input.lines().collect();
+    let mut blocks: Vec<serde_json::Value> = Vec::new();
+    let mut i = 0;
+
+    while i < lines.len() {
+        let line = lines[i];
+
+        // Fenced code block
+        if let Some(rest) = line.strip_prefix("```") {
+            let language = rest.trim().to_string();
+            let mut code_lines: Vec<&str> = Vec::new();
+            i += 1;
+            while i < lines.len() && !lines[i].starts_with("```") {
+                code_lines.push(lines[i]);
+                i += 1;
+            }
             if i < lines.len() {
                 i += 1; // skip closing fence
             }
@@ -1072,6 +2729,117 @@ pub fn find_sprint_by_name(sprints: &[JiraSprintResponse], target_name: &str) ->
         .map(|s| s.id)
 }
 
+/// Project a board belongs to, from the Jira Agile API
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct JiraBoardLocation {
+    #[serde(rename = "projectKey", default)]
+    pub project_key: Option<String>,
+    #[serde(rename = "projectName", default)]
+    pub project_name: Option<String>,
+}
+
+/// Representation of a single board from the Jira Agile API
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct JiraBoardResponse {
+    pub id: u64,
+    pub name: String,
+    #[serde(rename = "type")]
+    pub board_type: String,
+    #[serde(default)]
+    pub location: Option<JiraBoardLocation>,
+}
+
+/// Container for a paginated list of boards
+#[derive(Debug, Deserialize, Clone)]
+pub struct JiraBoardListResponse {
+    #[serde(rename = "maxResults")]
+    pub max_results: u64,
+    #[serde(rename = "startAt")]
+    pub start_at: u64,
+    #[serde(rename = "isLast")]
+    pub is_last: bool,
+    pub values: Vec<JiraBoardResponse>,
+}
+
+/// Output structure for a board after transformation
+#[derive(Debug, Serialize, Clone, PartialEq)]
+pub struct BoardOutput {
+    pub id: u64,
+    pub name: String,
+    pub board_type: String,
+    pub project_key: Option<String>,
+    pub project_name: Option<String>,
+}
+
+/// Output structure for a list of boards
+#[derive(Debug, Serialize, Clone, PartialEq)]
+pub struct BoardListOutput {
+    pub boards: Vec<BoardOutput>,
+    pub total: usize,
+}
+
+/// Convert raw board list pages to the clean domain model.
+pub fn transform_board_list_response(pages: Vec<JiraBoardListResponse>) -> BoardListOutput {
+    let boards: Vec<BoardOutput> = pages
+        .into_iter()
+        .flat_map(|page| page.values)
+        .map(|b| {
+            let location = b.location.unwrap_or(JiraBoardLocation {
+                project_key: None,
+                project_name: None,
+            });
+            BoardOutput {
+                id: b.id,
+                name: b.name,
+                board_type: b.board_type,
+                project_key: location.project_key,
+                project_name: location.project_name,
+            }
+        })
+        .collect();
+
+    let total = boards.len();
+
+    BoardListOutput { boards, total }
+}
+
+/// Build the JQL condition that selects issues in a sprint.
+///
+/// `active` (or `open`), `future`, and `closed` select every sprint in that
+/// state; a number is a sprint ID; anything else is a sprint name.
+pub fn sprint_jql_clause(sprint: &str) -> String {
+    let sprint = sprint.trim();
+    match sprint.to_ascii_lowercase().as_str() {
+        "active" | "open" => "sprint in openSprints()".to_string(),
+        "future" => "sprint in futureSprints()".to_string(),
+        "closed" => "sprint in closedSprints()".to_string(),
+        _ if !sprint.is_empty() && sprint.chars().all(|c| c.is_ascii_digit()) => {
+            format!("sprint = {sprint}")
+        }
+        _ => format!("sprint = \"{}\"", sprint.replace('"', "\\\"")),
+    }
+}
+
+/// Restrict a JQL query to the issues in a sprint, keeping any `ORDER BY`.
+pub fn with_sprint_filter(jql: &str, sprint: &str) -> String {
+    let clause = sprint_jql_clause(sprint);
+    let order_at = jql.to_ascii_uppercase().find("ORDER BY");
+    let (condition, order) = match order_at {
+        Some(at) => (jql[..at].trim(), Some(jql[at..].trim())),
+        None => (jql.trim(), None),
+    };
+
+    let filtered = if condition.is_empty() {
+        clause
+    } else {
+        format!("({condition}) AND {clause}")
+    };
+    match order {
+        Some(order) => format!("{filtered} {order}"),
+        None => filtered,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1092,6 +2860,7 @@ mod tests {
                     name: status.to_string(),
                 },
                 assignee,
+                updated: None,
             },
         }
     }
@@ -1117,7 +2886,7 @@ mod tests {
         };
 
         // Act: Transform the response
-        let output = transform_search_response(response);
+        let output = transform_search_response(response, JiraFlavor::V3);
 
         // Assert: Verify the transformation
         assert_eq!(output.total, 1);
@@ -1145,7 +2914,7 @@ mod tests {
         };
 
         // Act: Transform the response
-        let output = transform_search_response(response);
+        let output = transform_search_response(response, JiraFlavor::V3);
 
         // Assert: Verify empty results
         assert_eq!(output.total, 0);
@@ -1153,6 +2922,49 @@ mod tests {
         assert_eq!(output.next_page_token, None);
     }
 
+    #[test]
+    fn test_transform_search_response_data_center_offsets() {
+        let page = |start_at: u64, count: usize| JiraSearchResponse {
+            issues: (0..count)
+                .map(|i| create_issue_response(&format!("PROJ-{i}"), "Issue", "Open", None))
+                .collect(),
+            total: Some(3),
+            is_last: None,
+            next_page_token: None,
+            start_at: Some(start_at),
+            max_results: Some(2),
+        };
+
+        let first = transform_search_response(page(0, 2), JiraFlavor::V2);
+        assert_eq!(first.next_page_token.as_deref(), Some("2"));
+
+        let last = transform_search_response(page(2, 1), JiraFlavor::V2);
+        assert_eq!(last.next_page_token, None);
+    }
+
+    #[test]
+    fn test_data_center_request_payloads() {
+        let user: JiraUser = serde_json::from_value(serde_json::json!({
+            "name": "ada", "key": "JIRAUSER10100", "displayName": "Ada"
+        }))
+        .unwrap();
+        assert_eq!(user.user_ref(JiraFlavor::V2), Some("ada"));
+        assert_eq!(user.user_ref(JiraFlavor::V3), None);
+
+        let fields = build_update_payload(
+            JiraFlavor::V2,
+            None,
+            None,
+            Some("ada"),
+            Some(&rich_text_payload(JiraFlavor::V2, "Plain *text*")),
+            &[],
+        );
+        assert_eq!(fields["assignee"], serde_json::json!({ "name": "ada" }));
+        assert_eq!(fields["description"], "Plain *text*");
+
+        assert_eq!(rich_text_payload(JiraFlavor::V3, "Plain")["type"], "doc");
+    }
+
     #[test]
     fn test_transform_search_response_multiple_issues() {
         // Arrange: Create a search response with multiple issues
@@ -1186,7 +2998,7 @@ mod tests {
         };
 
         // Act: Transform the response
-        let output = transform_search_response(response);
+        let output = transform_search_response(response, JiraFlavor::V3);
 
         // Assert: Verify all issues transformed correctly
         assert_eq!(output.total, 3);
@@ -1226,7 +3038,7 @@ mod tests {
         };
 
         // Act: Transform the response
-        let output = transform_search_response(response);
+        let output = transform_search_response(response, JiraFlavor::V3);
 
         // Assert: Verify issue with no assignee
         assert_eq!(output.issues.len(), 1);
@@ -1256,7 +3068,7 @@ mod tests {
         };
 
         // Act: Transform the response
-        let output = transform_search_response(response);
+        let output = transform_search_response(response, JiraFlavor::V3);
 
         // Assert: Verify displayName is preferred over emailAddress
         assert_eq!(output.issues.len(), 1);
@@ -1288,7 +3100,7 @@ mod tests {
         };
 
         // Act: Transform the response
-        let output = transform_search_response(response);
+        let output = transform_search_response(response, JiraFlavor::V3);
 
         // Assert: Verify emailAddress is used as fallback
         assert_eq!(output.issues.len(), 1);
@@ -1315,7 +3127,7 @@ mod tests {
         };
 
         // Act: Transform the response
-        let output = transform_search_response(response);
+        let output = transform_search_response(response, JiraFlavor::V3);
 
         // Assert: Verify pagination info is preserved
         assert_eq!(output.total, 100);
@@ -1339,7 +3151,7 @@ mod tests {
         };
 
         // Act: Transform the response
-        let output = transform_search_response(response);
+        let output = transform_search_response(response, JiraFlavor::V3);
 
         // Assert: Verify default value of 0 for missing total
         assert_eq!(output.total, 0);
@@ -1381,6 +3193,8 @@ mod tests {
                         name: "API".to_string(),
                     },
                 ],
+                issuelinks: vec![],
+                extra: Default::default(),
             },
         }
     }
@@ -1406,7 +3220,8 @@ mod tests {
         }];
 
         // Act: Transform the ticket
-        let output = transform_ticket_response(issue, comments.clone(), vec![]);
+        let output =
+            transform_ticket_response(issue, comments.clone(), vec![], &FieldAliases::new());
 
         // Assert: Verify all fields are transformed correctly
         assert_eq!(output.key, "PROJ-456");
@@ -1443,11 +3258,13 @@ mod tests {
                 duedate: None,
                 labels: vec![],
                 components: vec![],
+                issuelinks: vec![],
+                extra: Default::default(),
             },
         };
 
         // Act: Transform the ticket
-        let output = transform_ticket_response(issue, vec![], vec![]);
+        let output = transform_ticket_response(issue, vec![], vec![], &FieldAliases::new());
 
         // Assert: Verify minimal fields work correctly
         assert_eq!(output.key, "PROJ-789");
@@ -1492,7 +3309,7 @@ mod tests {
         ];
 
         // Act: Transform the ticket
-        let output = transform_ticket_response(issue, comments, vec![]);
+        let output = transform_ticket_response(issue, comments, vec![], &FieldAliases::new());
 
         // Assert: Verify comments are preserved
         assert_eq!(output.comments.len(), 2);
@@ -1521,11 +3338,13 @@ mod tests {
                 duedate: None,
                 labels: vec![],
                 components: vec![],
+                issuelinks: vec![],
+                extra: Default::default(),
             },
         };
 
         // Act: Transform the ticket
-        let output = transform_ticket_response(issue, vec![], vec![]);
+        let output = transform_ticket_response(issue, vec![], vec![], &FieldAliases::new());
 
         // Assert: Verify empty priority is filtered out
         assert_eq!(output.priority, None);
@@ -1782,6 +3601,7 @@ mod tests {
                 to: JiraStatus {
                     name: "In Progress".to_string(),
                 },
+                fields: Default::default(),
             },
             JiraTransition {
                 id: "2".to_string(),
@@ -1789,6 +3609,7 @@ mod tests {
                 to: JiraStatus {
                     name: "Done".to_string(),
                 },
+                fields: Default::default(),
             },
         ];
 
@@ -1808,6 +3629,7 @@ mod tests {
             to: JiraStatus {
                 name: "Done".to_string(),
             },
+            fields: Default::default(),
         }];
 
         // Act: Find transition with different case
@@ -1826,6 +3648,7 @@ mod tests {
             to: JiraStatus {
                 name: "In Progress".to_string(),
             },
+            fields: Default::default(),
         }];
 
         // Act: Find non-existent transition
@@ -1843,76 +3666,354 @@ mod tests {
         // Act: Find transition
         let result = find_transition_by_status(&transitions, "Done");
 
-        // Assert: Verify None is returned
-        assert_eq!(result, None);
+        // Assert: Verify None is returned
+        assert_eq!(result, None);
+    }
+
+    // Tests for build_update_payload
+    #[test]
+    fn test_build_update_payload_all_fields() {
+        // Arrange: All fields provided
+        let payload = build_update_payload(
+            JiraFlavor::V3,
+            Some("High"),
+            Some("Story"),
+            Some("5b10a2844c20165700edge21g"),
+            None,
+            &[("customfield_10016".to_string(), serde_json::json!(5.0))],
+        );
+
+        // Assert: Verify all fields are in payload
+        assert_eq!(payload["priority"]["name"], "High");
+        assert_eq!(payload["issuetype"]["name"], "Story");
+        assert_eq!(payload["assignee"]["id"], "5b10a2844c20165700edge21g");
+        assert_eq!(payload["customfield_10016"], 5.0);
+    }
+
+    #[test]
+    fn test_build_update_payload_single_field() {
+        // Arrange: Only priority provided
+        let payload = build_update_payload(JiraFlavor::V3, Some("Low"), None, None, None, &[]);
+
+        // Assert: Verify only priority is in payload
+        assert_eq!(payload["priority"]["name"], "Low");
+        assert!(payload.get("issuetype").is_none());
+        assert!(payload.get("assignee").is_none());
+    }
+
+    #[test]
+    fn test_build_update_payload_empty() {
+        // Arrange: No fields provided
+        let payload = build_update_payload(JiraFlavor::V3, None, None, None, None, &[]);
+
+        // Assert: Verify payload is empty object
+        assert_eq!(payload, serde_json::json!({}));
+    }
+
+    #[test]
+    fn test_build_update_payload_with_assignee() {
+        // Arrange: Assignee account ID provided
+        let payload =
+            build_update_payload(JiraFlavor::V3, None, None, Some("account123"), None, &[]);
+
+        // Assert: Verify assignee is in payload with id field
+        assert_eq!(payload["assignee"]["id"], "account123");
+    }
+
+    #[test]
+    fn test_build_update_payload_with_description() {
+        // Arrange: Description ADF value provided
+        let adf = serde_json::json!({
+            "version": 1,
+            "type": "doc",
+            "content": [{
+                "type": "paragraph",
+                "content": [{ "type": "text", "text": "Updated description" }]
+            }]
+        });
+        let payload = build_update_payload(JiraFlavor::V3, None, None, None, Some(&adf), &[]);
+
+        // Assert: Verify description is in payload
+        assert_eq!(payload["description"]["type"], "doc");
+        assert_eq!(payload["description"]["version"], 1);
+        assert_eq!(
+            payload["description"]["content"][0]["content"][0]["text"],
+            "Updated description"
+        );
+    }
+
+    // Tests for bulk comment rendering
+    fn bulk_issue(key: &str, assignee: Option<&str>) -> IssueOutput {
+        IssueOutput {
+            key: key.to_string(),
+            summary: format!("Summary of {key}"),
+            description: None,
+            status: "Done".to_string(),
+            assignee: assignee.map(|a| a.to_string()),
+            updated: None,
+        }
+    }
+
+    #[test]
+    fn test_render_bulk_comments() {
+        let issues = vec![
+            bulk_issue("PROJ-1", Some("Jane")),
+            bulk_issue("PROJ-2", None),
+        ];
+        let vars = crate::template::parse_vars(&["version=1.2.0"]).unwrap();
+
+        let rendered =
+            render_bulk_comments(&issues, "{key} ({assignee}) released in {version}", &vars)
+                .unwrap();
+
+        assert_eq!(
+            rendered,
+            vec![
+                (
+                    "PROJ-1".to_string(),
+                    "PROJ-1 (Jane) released in 1.2.0".to_string()
+                ),
+                (
+                    "PROJ-2".to_string(),
+                    "PROJ-2 () released in 1.2.0".to_string()
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_render_bulk_comments_user_vars_override() {
+        let issues = vec![bulk_issue("PROJ-1", None)];
+        let vars = crate::template::parse_vars(&["status=Shipped"]).unwrap();
+        let rendered = render_bulk_comments(&issues, "{status}", &vars).unwrap();
+        assert_eq!(rendered[0].1, "Shipped");
+    }
+
+    #[test]
+    fn test_render_bulk_comments_unknown_variable() {
+        let issues = vec![bulk_issue("PROJ-1", None)];
+        let result = render_bulk_comments(&issues, "{verison}", &Default::default());
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_bulk_comment_output_tallies() {
+        let result = |key: &str, success: bool| BulkCommentResult {
+            ticket_key: key.to_string(),
+            success,
+            body: String::new(),
+            comment_id: None,
+            error: None,
+        };
+        let output = BulkCommentOutput::new(
+            "project = PROJ".to_string(),
+            false,
+            vec![
+                result("A-1", true),
+                result("A-2", false),
+                result("A-3", true),
+            ],
+        );
+        assert_eq!(output.succeeded, 2);
+        assert_eq!(output.failed, 1);
+    }
+
+    // Tests for parse_due_date
+    #[test]
+    fn test_parse_due_date() {
+        assert_eq!(
+            parse_due_date("2025-03-07"),
+            Ok(Some("2025-03-07".to_string()))
+        );
+        assert_eq!(parse_due_date("none"), Ok(None));
+        assert_eq!(parse_due_date(""), Ok(None));
+        assert!(parse_due_date("2025-13-01").is_err());
+        assert!(parse_due_date("next friday").is_err());
+    }
+
+    // Tests for build_update_request
+    #[test]
+    fn test_build_update_request_fields_only() {
+        let fields = build_update_payload(JiraFlavor::V3, Some("High"), None, None, None, &[]);
+        let edits = FieldEdits {
+            summary: Some("New title".to_string()),
+            due_date: Some(Some("2025-03-07".to_string())),
+            components: Some(vec!["API".to_string(), "Web".to_string()]),
+            ..Default::default()
+        };
+
+        let request = build_update_request(fields, &edits).unwrap();
+
+        assert_eq!(request["fields"]["priority"]["name"], "High");
+        assert_eq!(request["fields"]["summary"], "New title");
+        assert_eq!(request["fields"]["duedate"], "2025-03-07");
+        assert_eq!(request["fields"]["components"][1]["name"], "Web");
+        assert!(request.get("update").is_none());
     }
 
-    // Tests for build_update_payload
     #[test]
-    fn test_build_update_payload_all_fields() {
-        // Arrange: All fields provided
-        let payload = build_update_payload(
-            Some("High"),
-            Some("Story"),
-            Some("5b10a2844c20165700edge21g"),
-            None,
-        );
+    fn test_build_update_request_clear_due_date() {
+        let edits = FieldEdits {
+            due_date: Some(None),
+            ..Default::default()
+        };
+        let request = build_update_request(serde_json::json!({}), &edits).unwrap();
+        assert!(request["fields"]["duedate"].is_null());
+        assert!(request["fields"]
+            .as_object()
+            .unwrap()
+            .contains_key("duedate"));
+    }
 
-        // Assert: Verify all fields are in payload
-        assert_eq!(payload["priority"]["name"], "High");
-        assert_eq!(payload["issuetype"]["name"], "Story");
-        assert_eq!(payload["assignee"]["id"], "5b10a2844c20165700edge21g");
+    #[test]
+    fn test_parse_bulk_update_csv() {
+        let csv = "Key,Status,Add Labels,Summary,Due Date\n\
+                   PROJ-1,In Progress,backend;urgent,\"Fix login, again\",2024-06-01\n\
+                   \n\
+                   PROJ-2,,,\"Say \"\"hi\"\"\",\n";
+
+        let rows = parse_bulk_update_csv(csv).unwrap();
+        assert_eq!(rows.len(), 2);
+        assert_eq!(rows[0].row, 1);
+        assert_eq!(rows[0].key, "PROJ-1");
+        assert_eq!(rows[0].status.as_deref(), Some("In Progress"));
+        assert_eq!(rows[0].add_labels, vec!["backend", "urgent"]);
+        assert_eq!(rows[0].summary.as_deref(), Some("Fix login, again"));
+        assert_eq!(rows[0].due_date.as_deref(), Some("2024-06-01"));
+        assert_eq!(rows[1].status, None);
+        assert_eq!(rows[1].summary.as_deref(), Some("Say \"hi\""));
+
+        let planned: Vec<String> = rows[0]
+            .planned_fields()
+            .into_iter()
+            .map(|f| f.field)
+            .collect();
+        assert_eq!(planned, vec!["status", "summary", "duedate", "add_labels"]);
     }
 
     #[test]
-    fn test_build_update_payload_single_field() {
-        // Arrange: Only priority provided
-        let payload = build_update_payload(Some("Low"), None, None, None);
+    fn test_parse_bulk_update_csv_reports_every_invalid_row() {
+        let csv =
+            "key,status,due_date\nPROJ-1,Done,\nnot a key,Done,\nPROJ-3,,tomorrow\nPROJ-4,,\n";
+
+        let err = parse_bulk_update_csv(csv).unwrap_err();
+        let lines: Vec<&str> = err.lines().collect();
+        assert_eq!(lines.len(), 3);
+        assert!(lines[0].starts_with("row 2:"));
+        assert!(lines[1].starts_with("row 3:"));
+        assert!(lines[2].contains("no fields to update"));
+
+        assert!(parse_bulk_update_csv("key,colour\nPROJ-1,red\n")
+            .unwrap_err()
+            .contains("unknown column 'colour'"));
+        assert!(parse_bulk_update_csv("key,status\n\"PROJ-1,Done\n").is_err());
+        assert!(parse_bulk_update_csv("key,status\n").is_err());
+    }
 
-        // Assert: Verify only priority is in payload
-        assert_eq!(payload["priority"]["name"], "Low");
-        assert!(payload.get("issuetype").is_none());
-        assert!(payload.get("assignee").is_none());
+    #[test]
+    fn test_parse_bulk_update_json() {
+        let value = serde_json::json!([
+            { "key": "PROJ-1", "priority": "High", "components": ["API", "Web"] },
+            { "key": "PROJ-2", "remove_labels": "old;stale", "assignee": null, "summary": "A; B" }
+        ]);
+
+        let rows = parse_bulk_update_json(&value).unwrap();
+        assert_eq!(rows[0].priority.as_deref(), Some("High"));
+        assert_eq!(rows[0].components, vec!["API", "Web"]);
+        assert_eq!(rows[1].remove_labels, vec!["old", "stale"]);
+        assert_eq!(rows[1].assignee, None);
+        assert_eq!(rows[1].summary.as_deref(), Some("A; B"));
+
+        assert!(parse_bulk_update_json(&serde_json::json!({ "key": "PROJ-1" })).is_err());
+        assert!(parse_bulk_update_json(
+            &serde_json::json!([{ "key": "PROJ-1", "add_labels": ["two words"] }])
+        )
+        .is_err());
     }
 
     #[test]
-    fn test_build_update_payload_empty() {
-        // Arrange: No fields provided
-        let payload = build_update_payload(None, None, None, None);
+    fn test_build_create_payload() {
+        let issue = NewIssue {
+            project: "PROJ".to_string(),
+            issue_type: "Story".to_string(),
+            summary: "  Add dark mode ".to_string(),
+            description: Some("Use **CSS** variables".to_string()),
+            labels: vec!["frontend".to_string(), "ui".to_string()],
+            ..Default::default()
+        };
 
-        // Assert: Verify payload is empty object
-        assert_eq!(payload, serde_json::json!({}));
+        let payload = build_create_payload(&issue, JiraFlavor::V3).unwrap();
+        let fields = &payload["fields"];
+        assert_eq!(fields["project"]["key"], "PROJ");
+        assert_eq!(fields["issuetype"]["name"], "Story");
+        assert_eq!(fields["summary"], "Add dark mode");
+        assert_eq!(fields["description"]["type"], "doc");
+        assert_eq!(fields["labels"], serde_json::json!(["frontend", "ui"]));
+        assert!(fields.get("priority").is_none());
+        assert!(fields.get("assignee").is_none());
+
+        let no_labels = build_create_payload(
+            &NewIssue {
+                labels: Vec::new(),
+                description: None,
+                ..issue.clone()
+            },
+            JiraFlavor::V3,
+        )
+        .unwrap();
+        assert!(no_labels["fields"].get("labels").is_none());
+        assert!(no_labels["fields"].get("description").is_none());
     }
 
     #[test]
-    fn test_build_update_payload_with_assignee() {
-        // Arrange: Assignee account ID provided
-        let payload = build_update_payload(None, None, Some("account123"), None);
+    fn test_build_create_payload_rejects_invalid_fields() {
+        let blank = NewIssue {
+            project: "PROJ".to_string(),
+            issue_type: "Task".to_string(),
+            summary: "   ".to_string(),
+            ..Default::default()
+        };
+        assert!(build_create_payload(&blank, JiraFlavor::V3).is_err());
 
-        // Assert: Verify assignee is in payload with id field
-        assert_eq!(payload["assignee"]["id"], "account123");
+        let spaced_label = NewIssue {
+            summary: "Fix login".to_string(),
+            labels: vec!["needs triage".to_string()],
+            ..blank
+        };
+        assert!(build_create_payload(&spaced_label, JiraFlavor::V3)
+            .unwrap_err()
+            .contains("needs triage"));
     }
 
     #[test]
-    fn test_build_update_payload_with_description() {
-        // Arrange: Description ADF value provided
-        let adf = serde_json::json!({
-            "version": 1,
-            "type": "doc",
-            "content": [{
-                "type": "paragraph",
-                "content": [{ "type": "text", "text": "Updated description" }]
-            }]
-        });
-        let payload = build_update_payload(None, None, None, Some(&adf));
-
-        // Assert: Verify description is in payload
-        assert_eq!(payload["description"]["type"], "doc");
-        assert_eq!(payload["description"]["version"], 1);
+    fn test_build_update_request_labels() {
+        let edits = FieldEdits {
+            add_labels: vec!["backend".to_string()],
+            remove_labels: vec!["triage".to_string()],
+            ..Default::default()
+        };
+        let request = build_update_request(serde_json::json!({}), &edits).unwrap();
         assert_eq!(
-            payload["description"]["content"][0]["content"][0]["text"],
-            "Updated description"
+            request["update"]["labels"],
+            serde_json::json!([{ "add": "backend" }, { "remove": "triage" }])
         );
+        assert_eq!(request["fields"], serde_json::json!({}));
+    }
+
+    #[test]
+    fn test_build_update_request_rejects_invalid_labels() {
+        let conflicting = FieldEdits {
+            add_labels: vec!["x".to_string()],
+            remove_labels: vec!["x".to_string()],
+            ..Default::default()
+        };
+        assert!(build_update_request(serde_json::json!({}), &conflicting).is_err());
+
+        let spaced = FieldEdits {
+            add_labels: vec!["two words".to_string()],
+            ..Default::default()
+        };
+        assert!(build_update_request(serde_json::json!({}), &spaced).is_err());
     }
 
     // Tests for format_file_size
@@ -1975,6 +4076,322 @@ mod tests {
         assert_eq!(result[0].created, "2024-01-15T10:30:00Z");
     }
 
+    #[test]
+    fn test_attachment_file_names() {
+        let attachment = |id: &str, filename: &str| JiraAttachmentResponse {
+            id: id.to_string(),
+            filename: filename.to_string(),
+            mime_type: "text/plain".to_string(),
+            size: 1,
+            created: "2024-01-01T00:00:00Z".to_string(),
+            content: format!("https://example.com/{id}"),
+        };
+
+        let names = attachment_file_names(&[
+            attachment("1", "log.txt"),
+            attachment("2", "log.txt"),
+            attachment("3", "../../etc/passwd"),
+            attachment("4", ".."),
+        ]);
+        assert_eq!(names, vec!["log.txt", "2-log.txt", "passwd", "4"]);
+
+        // An earlier attachment already holds the ID-prefixed name
+        let names = attachment_file_names(&[
+            attachment("1", "log.txt"),
+            attachment("3", "2-log.txt"),
+            attachment("2", "log.txt"),
+            attachment("2", "log.txt"),
+        ]);
+        assert_eq!(
+            names,
+            vec!["log.txt", "2-log.txt", "2-2-log.txt", "2-3-log.txt"]
+        );
+    }
+
+    fn standard_link_types() -> Vec<JiraIssueLinkType> {
+        serde_json::from_value::<JiraIssueLinkTypesResponse>(serde_json::json!({
+            "issueLinkTypes": [
+                { "id": "1", "name": "Blocks", "inward": "is blocked by", "outward": "blocks" },
+                { "id": "2", "name": "Relates", "inward": "relates to", "outward": "relates to" },
+                { "id": "3", "name": "Duplicate", "inward": "is duplicated by", "outward": "duplicates" }
+            ]
+        }))
+        .unwrap()
+        .issue_link_types
+    }
+
+    #[test]
+    fn test_build_issue_link_payload() {
+        let types = standard_link_types();
+
+        let blocks = build_issue_link_payload(&types, "PROJ-1", "blocks", "PROJ-2").unwrap();
+        assert_eq!(blocks["type"]["name"], "Blocks");
+        assert_eq!(blocks["inwardIssue"]["key"], "PROJ-1");
+        assert_eq!(blocks["outwardIssue"]["key"], "PROJ-2");
+
+        // The inward phrasing swaps the two ends.
+        let blocked =
+            build_issue_link_payload(&types, "PROJ-1", "Is Blocked By", "PROJ-2").unwrap();
+        assert_eq!(blocked["inwardIssue"]["key"], "PROJ-2");
+        assert_eq!(blocked["outwardIssue"]["key"], "PROJ-1");
+
+        let duplicate = build_issue_link_payload(&types, "PROJ-1", "Duplicate", "PROJ-3").unwrap();
+        assert_eq!(duplicate["type"]["name"], "Duplicate");
+
+        let err = build_issue_link_payload(&types, "PROJ-1", "clones", "PROJ-2").unwrap_err();
+        assert!(err.contains("is duplicated by"));
+    }
+
+    #[test]
+    fn test_transform_ticket_response_custom_fields() {
+        let issue: JiraExtendedIssueResponse = serde_json::from_value(serde_json::json!({
+            "key": "PROJ-1",
+            "fields": {
+                "summary": "Custom fields",
+                "status": { "name": "Open" },
+                "customfield_10016": 5.0,
+                "customfield_10020": [{ "id": 7, "name": "Sprint 7", "state": "active" }],
+                "customfield_10050": { "value": "DevOps", "id": "1" },
+                "customfield_10060": null,
+                "customfield_10070": [],
+                "environment": "prod"
+            }
+        }))
+        .unwrap();
+
+        let ticket = transform_ticket_response(issue, vec![], vec![], &FieldAliases::new());
+        assert_eq!(ticket.custom_fields.len(), 3);
+        assert_eq!(ticket.custom_fields["customfield_10016"], 5.0);
+        assert_eq!(
+            ticket.custom_fields["customfield_10020"],
+            serde_json::json!(["Sprint 7"])
+        );
+        assert_eq!(ticket.custom_fields["customfield_10050"], "DevOps");
+    }
+
+    #[test]
+    fn test_simplify_field_value() {
+        use serde_json::json;
+
+        assert_eq!(simplify_field_value(&json!(null)), None);
+        assert_eq!(simplify_field_value(&json!("")), None);
+        assert_eq!(
+            simplify_field_value(&json!({ "value": "EMEA", "child": { "value": "Paris" } })),
+            Some(json!("EMEA / Paris"))
+        );
+        assert_eq!(
+            simplify_field_value(&json!({ "accountId": "abc", "displayName": "Ada" })),
+            Some(json!("Ada"))
+        );
+        assert_eq!(
+            simplify_field_value(&json!({
+                "type": "doc",
+                "version": 1,
+                "content": [{ "type": "paragraph", "content": [{ "type": "text", "text": "Notes" }] }]
+            })),
+            Some(json!("Notes"))
+        );
+        assert_eq!(
+            simplify_field_value(&json!([{ "name": "1.0" }, null, { "name": "1.1" }])),
+            Some(json!(["1.0", "1.1"]))
+        );
+        assert_eq!(
+            simplify_field_value(&json!({ "hasEpicLinkFieldDependency": false })),
+            Some(json!({ "hasEpicLinkFieldDependency": false }))
+        );
+    }
+
+    #[test]
+    fn test_fields_discovery() {
+        let fields: Vec<JiraField> = serde_json::from_value(serde_json::json!([
+            { "id": "customfield_10016", "name": "Story point estimate", "custom": true,
+              "schema": { "type": "number" } },
+            { "id": "summary", "name": "Summary", "custom": false, "schema": { "type": "string" } },
+            { "id": "customfield_10020", "name": "Sprint", "custom": true,
+              "schema": { "type": "array", "items": "json" } },
+            { "id": "customfield_10099", "name": "Sprint", "custom": true }
+        ]))
+        .unwrap();
+
+        let output = transform_fields_response(fields.clone(), &FieldAliases::new());
+        assert_eq!(output[0].id, "summary");
+        assert_eq!(output[1].field_type.as_deref(), Some("array<json>"));
+        assert_eq!(output[3].name, "Story point estimate");
+
+        assert_eq!(
+            find_field_id(&fields, "story POINT estimate").as_deref(),
+            Some("customfield_10016")
+        );
+        assert_eq!(
+            find_field_id(&fields, "customfield_10099").as_deref(),
+            Some("customfield_10099")
+        );
+        assert_eq!(find_field_id(&fields, "Team"), None);
+
+        let custom = [
+            ("customfield_10016", serde_json::json!(3)),
+            ("customfield_10020", serde_json::json!(["Sprint 7"])),
+            ("customfield_10099", serde_json::json!("other")),
+            ("customfield_99999", serde_json::json!("unknown")),
+        ]
+        .into_iter()
+        .map(|(k, v)| (k.to_string(), v))
+        .collect();
+        let named = name_custom_fields(custom, &fields);
+        assert_eq!(named["Story point estimate"], 3);
+        assert_eq!(named["Sprint"], serde_json::json!(["Sprint 7"]));
+        assert_eq!(named["Sprint (customfield_10099)"], "other");
+        assert_eq!(named["customfield_99999"], "unknown");
+    }
+
+    #[test]
+    fn test_field_aliases() {
+        let aliases = parse_field_aliases(Some(
+            r#"
+[search]
+title_boost = 2.0
+
+[jira.fields]
+story_points = "customfield_10016"
+team = "customfield_10050"
+"#,
+        ))
+        .unwrap();
+        assert_eq!(aliases.len(), 2);
+        let by_id = aliases_by_id(&aliases);
+        assert_eq!(by_id.get("customfield_10050"), Some(&"team"));
+        assert_eq!(by_id.get("customfield_10099"), None);
+
+        assert!(parse_field_aliases(None).unwrap().is_empty());
+        assert!(parse_field_aliases(Some("[search]\ntitle_boost = 1.0"))
+            .unwrap()
+            .is_empty());
+        let err = parse_field_aliases(Some("[jira.fields]\nteam = \"Team Name\"")).unwrap_err();
+        assert!(err.contains("jira.fields.team"));
+
+        let fields: Vec<JiraField> = serde_json::from_value(serde_json::json!([
+            { "id": "customfield_10016", "name": "Story point estimate", "custom": true },
+            { "id": "customfield_10050", "name": "Team", "custom": true }
+        ]))
+        .unwrap();
+        assert_eq!(
+            resolve_field_id(&fields, &aliases, "story_points").as_deref(),
+            Some("customfield_10016")
+        );
+        assert_eq!(
+            resolve_field_id(&fields, &aliases, "team").as_deref(),
+            Some("customfield_10050")
+        );
+        assert_eq!(resolve_field_id(&fields, &aliases, "Pod"), None);
+
+        let output = transform_fields_response(fields, &aliases);
+        assert_eq!(output[0].alias.as_deref(), Some("story_points"));
+
+        let issue: JiraExtendedIssueResponse = serde_json::from_value(serde_json::json!({
+            "key": "PROJ-1",
+            "fields": {
+                "summary": "Aliased",
+                "status": { "name": "Open" },
+                "customfield_10016": 3,
+                "customfield_10070": "other"
+            }
+        }))
+        .unwrap();
+        let ticket = transform_ticket_response(issue, vec![], vec![], &aliases);
+        assert_eq!(ticket.custom_fields["story_points"], 3);
+        assert_eq!(ticket.custom_fields["customfield_10070"], "other");
+    }
+
+    #[test]
+    fn test_build_field_value() {
+        use serde_json::json;
+
+        let field = |schema: serde_json::Value| -> JiraField {
+            serde_json::from_value(
+                json!({ "id": "customfield_1", "name": "Points", "schema": schema }),
+            )
+            .unwrap()
+        };
+
+        let number = field(json!({ "type": "number" }));
+        assert_eq!(
+            build_field_value(Some(&number), "5", JiraFlavor::V3).unwrap(),
+            json!(5.0)
+        );
+        assert!(build_field_value(Some(&number), "five", JiraFlavor::V3)
+            .unwrap_err()
+            .contains("Points expects a number"));
+        assert_eq!(
+            build_field_value(Some(&number), "", JiraFlavor::V3).unwrap(),
+            json!(null)
+        );
+
+        let option = field(json!({ "type": "option" }));
+        assert_eq!(
+            build_field_value(Some(&option), "DevOps", JiraFlavor::V3).unwrap(),
+            json!({ "value": "DevOps" })
+        );
+
+        let cascading = field(json!({ "type": "option-with-child" }));
+        assert_eq!(
+            build_field_value(Some(&cascading), "EMEA / Paris", JiraFlavor::V3).unwrap(),
+            json!({ "value": "EMEA", "child": { "value": "Paris" } })
+        );
+
+        let versions = field(json!({ "type": "array", "items": "version" }));
+        assert_eq!(
+            build_field_value(Some(&versions), "1.0, 1.1", JiraFlavor::V3).unwrap(),
+            json!([{ "name": "1.0" }, { "name": "1.1" }])
+        );
+        assert_eq!(
+            build_field_value(Some(&versions), "", JiraFlavor::V3).unwrap(),
+            json!([])
+        );
+
+        assert_eq!(
+            build_field_value(None, "free text", JiraFlavor::V3).unwrap(),
+            json!("free text")
+        );
+
+        let reviewers = field(json!({ "type": "array", "items": "user" }));
+        assert_eq!(
+            build_field_value(Some(&reviewers), "abc123", JiraFlavor::V3).unwrap(),
+            json!([{ "accountId": "abc123" }])
+        );
+        assert_eq!(
+            build_field_value(Some(&reviewers), "jdoe, asmith", JiraFlavor::V2).unwrap(),
+            json!([{ "name": "jdoe" }, { "name": "asmith" }])
+        );
+    }
+
+    #[test]
+    fn test_transform_issue_links() {
+        let links: Vec<JiraIssueLink> = serde_json::from_value(serde_json::json!([
+            {
+                "id": "10",
+                "type": { "name": "Blocks", "inward": "is blocked by", "outward": "blocks" },
+                "outwardIssue": {
+                    "key": "PROJ-2",
+                    "fields": { "summary": "Ship it", "status": { "name": "To Do" } }
+                }
+            },
+            {
+                "id": "11",
+                "type": { "name": "Blocks", "inward": "is blocked by", "outward": "blocks" },
+                "inwardIssue": { "key": "PROJ-0" }
+            }
+        ]))
+        .unwrap();
+
+        let output = transform_issue_links(links);
+        assert_eq!(output.len(), 2);
+        assert_eq!(output[0].relationship, "blocks");
+        assert_eq!(output[0].issue_key, "PROJ-2");
+        assert_eq!(output[0].status.as_deref(), Some("To Do"));
+        assert_eq!(output[1].relationship, "is blocked by");
+        assert_eq!(output[1].summary, None);
+    }
+
     #[test]
     fn test_transform_attachment_response_multiple() {
         let raw = vec![
@@ -2016,7 +4433,7 @@ mod tests {
             created: "2024-06-01T12:00:00Z".to_string(),
         }];
 
-        let output = transform_ticket_response(issue, vec![], attachments);
+        let output = transform_ticket_response(issue, vec![], attachments, &FieldAliases::new());
         assert_eq!(output.attachments.len(), 1);
         assert_eq!(output.attachments[0].filename, "screenshot.png");
     }
@@ -2320,6 +4737,55 @@ mod tests {
         assert_eq!(find_sprint_by_name(&sprints, "Sprint 30"), None);
     }
 
+    #[test]
+    fn test_transform_board_list_response() {
+        let page: JiraBoardListResponse = serde_json::from_value(serde_json::json!({
+            "maxResults": 50,
+            "startAt": 0,
+            "isLast": true,
+            "values": [
+                {
+                    "id": 84,
+                    "name": "PROJ board",
+                    "type": "scrum",
+                    "location": { "projectKey": "PROJ", "projectName": "Project" }
+                },
+                { "id": 92, "name": "Kanban", "type": "kanban" }
+            ]
+        }))
+        .unwrap();
+
+        let output = transform_board_list_response(vec![page]);
+        assert_eq!(output.total, 2);
+        assert_eq!(output.boards[0].board_type, "scrum");
+        assert_eq!(output.boards[0].project_key.as_deref(), Some("PROJ"));
+        assert_eq!(output.boards[1].project_key, None);
+    }
+
+    #[test]
+    fn test_sprint_jql_clause() {
+        assert_eq!(sprint_jql_clause("active"), "sprint in openSprints()");
+        assert_eq!(sprint_jql_clause("Future"), "sprint in futureSprints()");
+        assert_eq!(sprint_jql_clause("42"), "sprint = 42");
+        assert_eq!(sprint_jql_clause("Sprint 7"), "sprint = \"Sprint 7\"");
+    }
+
+    #[test]
+    fn test_with_sprint_filter() {
+        assert_eq!(
+            with_sprint_filter("project = PROJ OR assignee = currentUser()", "42"),
+            "(project = PROJ OR assignee = currentUser()) AND sprint = 42"
+        );
+        assert_eq!(
+            with_sprint_filter("project = PROJ order by rank", "active"),
+            "(project = PROJ) AND sprint in openSprints() order by rank"
+        );
+        assert_eq!(
+            with_sprint_filter("ORDER BY updated DESC", "active"),
+            "sprint in openSprints() ORDER BY updated DESC"
+        );
+    }
+
     #[test]
     fn test_transform_comment_response_basic() {
         let raw = JiraComment {
@@ -2378,6 +4844,256 @@ mod tests {
         assert_eq!(output.author, Some("jane@example.com".to_string()));
     }
 
+    #[test]
+    fn test_parse_worklog_duration() {
+        assert_eq!(parse_worklog_duration("1h30m"), Ok(90 * 60));
+        assert_eq!(parse_worklog_duration("2d 4h"), Ok(20 * 60 * 60));
+        assert_eq!(parse_worklog_duration("1w"), Ok(40 * 60 * 60));
+        assert_eq!(parse_worklog_duration(" 45M "), Ok(45 * 60));
+
+        for invalid in ["", "90", "1x", "h", "1h30", "0m"] {
+            assert!(
+                parse_worklog_duration(invalid).is_err(),
+                "{invalid:?} should be rejected"
+            );
+        }
+    }
+
+    #[test]
+    fn test_format_worklog_duration() {
+        assert_eq!(format_worklog_duration(0), "0m");
+        assert_eq!(format_worklog_duration(90 * 60), "1h 30m");
+        assert_eq!(
+            format_worklog_duration(WORKLOG_WEEK_SECS + WORKLOG_DAY_SECS + 59),
+            "1w 1d"
+        );
+        assert_eq!(
+            parse_worklog_duration(&format_worklog_duration(123 * 60)),
+            Ok(123 * 60)
+        );
+    }
+
+    #[test]
+    fn test_build_worklog_payload() {
+        let payload = build_worklog_payload(5400, Some("Reviewed **PR**"), JiraFlavor::V3);
+        assert_eq!(payload["timeSpentSeconds"], 5400);
+        assert_eq!(payload["comment"]["type"], "doc");
+
+        let bare = build_worklog_payload(60, Some("  "), JiraFlavor::V3);
+        assert!(bare.get("comment").is_none());
+
+        let server = build_worklog_payload(60, Some("Reviewed"), JiraFlavor::V2);
+        assert_eq!(server["comment"], "Reviewed");
+    }
+
+    #[test]
+    fn test_transform_worklog_list_response() {
+        let response: JiraWorklogListResponse = serde_json::from_value(serde_json::json!({
+            "startAt": 0,
+            "total": 2,
+            "worklogs": [
+                {
+                    "id": "100",
+                    "author": { "displayName": "Ada" },
+                    "comment": {
+                        "type": "doc",
+                        "version": 1,
+                        "content": [{
+                            "type": "paragraph",
+                            "content": [{ "type": "text", "text": "Pairing" }]
+                        }]
+                    },
+                    "started": "2024-05-01T09:00:00.000+0000",
+                    "timeSpent": "1h 30m",
+                    "timeSpentSeconds": 5400
+                },
+                {
+                    "id": "101",
+                    "author": null,
+                    "started": "2024-05-02T09:00:00.000+0000",
+                    "timeSpent": "7h",
+                    "timeSpentSeconds": 25200
+                }
+            ]
+        }))
+        .unwrap();
+
+        let output = transform_worklog_list_response("PROJ-1", response);
+        assert_eq!(output.worklogs.len(), 2);
+        assert_eq!(output.worklogs[0].author.as_deref(), Some("Ada"));
+        assert_eq!(output.worklogs[0].comment.as_deref(), Some("Pairing"));
+        assert_eq!(output.worklogs[1].comment, None);
+        assert_eq!(output.total_seconds, 30600);
+        assert_eq!(output.total_time_spent, "1d 30m");
+    }
+
+    #[test]
+    fn test_transform_epic_children() {
+        let page: JiraChildSearchResponse = serde_json::from_value(serde_json::json!({
+            "issues": [
+                {
+                    "key": "PROJ-2",
+                    "fields": {
+                        "summary": "Login form",
+                        "status": { "name": "Shipped", "statusCategory": { "key": "done" } },
+                        "assignee": { "displayName": "Ada" },
+                        "issuetype": { "name": "Story" },
+                        "customfield_10016": 5.0
+                    }
+                },
+                {
+                    "key": "PROJ-3",
+                    "fields": {
+                        "summary": "Logout",
+                        "status": { "name": "Closed" },
+                        "customfield_10016": null
+                    }
+                }
+            ]
+        }))
+        .unwrap();
+
+        let mut aliases = FieldAliases::new();
+        assert_eq!(
+            story_points_field(&aliases, None),
+            DEFAULT_STORY_POINTS_FIELD
+        );
+        aliases.insert("story_points".to_string(), "customfield_10028".to_string());
+        assert_eq!(story_points_field(&aliases, None), "customfield_10028");
+        assert_eq!(
+            story_points_field(&aliases, Some("story_points")),
+            "customfield_10028"
+        );
+        assert_eq!(
+            story_points_field(&aliases, Some("customfield_10002")),
+            "customfield_10002"
+        );
+
+        let children = transform_epic_children(page.issues, DEFAULT_STORY_POINTS_FIELD);
+        assert_eq!(children[0].story_points, Some(5.0));
+        assert!(children[0].done);
+        assert_eq!(children[0].assignee.as_deref(), Some("Ada"));
+        assert_eq!(children[0].issue_type.as_deref(), Some("Story"));
+        assert_eq!(children[1].story_points, None);
+        assert!(children[1].done);
+    }
+
+    #[test]
+    fn test_epic_rollup() {
+        let child = |key: &str, status: &str, done: bool, points: Option<f64>| EpicChildOutput {
+            key: key.to_string(),
+            summary: String::new(),
+            issue_type: None,
+            status: status.to_string(),
+            done,
+            assignee: None,
+            story_points: points,
+        };
+        let children = vec![
+            child("PROJ-2", "Done", true, Some(5.0)),
+            child("PROJ-3", "In Progress", false, Some(3.0)),
+            child("PROJ-4", "Done", true, Some(2.0)),
+            child("PROJ-5", "To Do", false, None),
+        ];
+
+        let rollup = epic_rollup(&children);
+        assert_eq!(rollup.total_issues, 4);
+        assert_eq!(rollup.done_issues, 2);
+        assert_eq!(rollup.total_points, 10.0);
+        assert_eq!(rollup.done_points, 7.0);
+        assert_eq!(rollup.unestimated_issues, 1);
+        assert_eq!(rollup.percent_done, 70.0);
+        assert_eq!(rollup.by_status.len(), 3);
+        assert_eq!(rollup.by_status[0].status, "Done");
+        assert_eq!(rollup.by_status[0].issues, 2);
+        assert_eq!(rollup.by_status[0].story_points, 7.0);
+
+        // Without estimates, progress is by issue count
+        let unestimated = vec![
+            child("PROJ-6", "Done", true, None),
+            child("PROJ-7", "To Do", false, None),
+        ];
+        assert_eq!(epic_rollup(&unestimated).percent_done, 50.0);
+        assert_eq!(epic_rollup(&[]).percent_done, 0.0);
+    }
+
+    #[test]
+    fn test_transform_changelog() {
+        let page: JiraChangelogPage = serde_json::from_value(serde_json::json!({
+            "startAt": 0,
+            "maxResults": 100,
+            "total": 2,
+            "isLast": true,
+            "values": [
+                {
+                    "id": "10002",
+                    "author": { "accountId": "abc", "displayName": "Ada" },
+                    "created": "2024-01-16T09:00:00.000+0000",
+                    "items": [
+                        { "field": "status", "fieldId": "status",
+                          "fromString": "In Progress", "toString": "Done" },
+                        { "field": "resolution", "fieldId": "resolution",
+                          "fromString": null, "toString": "Done" }
+                    ]
+                },
+                {
+                    "id": "10001",
+                    "created": "2024-01-15T10:30:00.000+0100",
+                    "items": [
+                        { "field": "status", "fieldId": "status",
+                          "fromString": "To Do", "toString": "In Progress" }
+                    ]
+                }
+            ]
+        }))
+        .unwrap();
+
+        let output = transform_changelog("PROJ-1", page.values.clone(), None);
+        assert_eq!(output.ticket_key, "PROJ-1");
+        assert_eq!(output.entries.len(), 3);
+        assert_eq!(output.entries[0].to.as_deref(), Some("In Progress"));
+        assert_eq!(output.entries[0].author, None);
+        assert_eq!(output.entries[1].author.as_deref(), Some("Ada"));
+        assert_eq!(output.entries[2].from, None);
+
+        let status_only = transform_changelog("PROJ-1", page.values, Some("STATUS"));
+        assert_eq!(status_only.entries.len(), 2);
+
+        // Data Center embeds the history under `histories`
+        let embedded: JiraChangelogPage = serde_json::from_value(serde_json::json!({
+            "startAt": 0, "total": 0, "histories": []
+        }))
+        .unwrap();
+        assert!(embedded.values.is_empty());
+    }
+
+    #[test]
+    fn test_transform_watchers_response() {
+        let response: JiraWatchersResponse = serde_json::from_value(serde_json::json!({
+            "self": "https://example.atlassian.net/rest/api/3/issue/PROJ-1/watchers",
+            "isWatching": true,
+            "watchCount": 2,
+            "watchers": [
+                {
+                    "accountId": "5b10a2844c20165700ede21g",
+                    "displayName": "Ada",
+                    "emailAddress": "ada@example.com"
+                },
+                { "accountId": "5b10ac8d82e05b22cc7d4ef5" }
+            ]
+        }))
+        .unwrap();
+
+        let output = transform_watchers_response("PROJ-1", response);
+        assert_eq!(output.ticket_key, "PROJ-1");
+        assert_eq!(output.watch_count, 2);
+        assert!(output.is_watching);
+        assert_eq!(output.watchers[0].display_name.as_deref(), Some("Ada"));
+        assert_eq!(output.watchers[0].email.as_deref(), Some("ada@example.com"));
+        assert_eq!(output.watchers[1].account_id, "5b10ac8d82e05b22cc7d4ef5");
+        assert_eq!(output.watchers[1].display_name, None);
+    }
+
     #[test]
     fn test_transform_comment_list_response_empty() {
         let result = transform_comment_list_response("PROJ-1", vec![]);
@@ -2425,4 +5141,36 @@ mod tests {
         assert_eq!(result[1].ticket_key, "PROJ-1");
         assert_eq!(result[1].author, None);
     }
+
+    #[test]
+    fn test_transform_transitions_response() {
+        let json = r#"{
+            "transitions": [
+                {"id": "11", "name": "Start Progress", "to": {"name": "In Progress"}},
+                {
+                    "id": "31",
+                    "name": "Resolve",
+                    "to": {"name": "Done"},
+                    "fields": {
+                        "resolution": {"required": true, "name": "Resolution"},
+                        "comment": {"required": false, "name": "Comment"},
+                        "customfield_10010": {"required": true}
+                    }
+                }
+            ]
+        }"#;
+        let response: JiraTransitionsResponse = serde_json::from_str(json).unwrap();
+
+        let output = transform_transitions_response("PROJ-1", response);
+
+        assert_eq!(output.ticket_key, "PROJ-1");
+        assert_eq!(output.transitions.len(), 2);
+        assert_eq!(output.transitions[0].to_status, "In Progress");
+        assert!(output.transitions[0].required_fields.is_empty());
+        assert_eq!(output.transitions[1].id, "31");
+        assert_eq!(
+            output.transitions[1].required_fields,
+            vec!["customfield_10010".to_string(), "Resolution".to_string()]
+        );
+    }
 }
@@ -4,4 +4,6 @@
 /// All functions are free of I/O operations and testable with fixture data.
 pub mod bitbucket;
 pub mod confluence;
+pub mod convert;
+pub mod deployment;
 pub mod jira;
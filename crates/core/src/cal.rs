@@ -0,0 +1,1264 @@
+//! iCalendar (RFC 5545) parsing and recurrence expansion
+//!
+//! Parses `VEVENT` components from `.ics` text and expands them into concrete
+//! occurrences inside a date range. Time zone lookups are injected by the
+//! caller so expansion stays pure and testable with fixed offsets.
+
+use chrono::{Datelike, Duration, NaiveDate, NaiveDateTime, NaiveTime, Timelike, Weekday};
+use jiff::tz::TimeZone;
+use jiff::Timestamp;
+use serde::Serialize;
+
+/// Upper bound on recurrence periods examined per event.
+const MAX_PERIODS: i64 = 50_000;
+
+/// A `DTSTART`/`DTEND`/`EXDATE` value.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum EventTime {
+    /// All-day value (`VALUE=DATE`).
+    Date(NaiveDate),
+    /// Local time with no zone; interpreted in the output time zone.
+    Floating(NaiveDateTime),
+    /// UTC time (`...Z`).
+    Utc(NaiveDateTime),
+    /// Local time in a named zone (`TZID=...`).
+    Zoned { local: NaiveDateTime, tzid: String },
+}
+
+impl EventTime {
+    fn local(&self) -> NaiveDateTime {
+        match self {
+            EventTime::Date(d) => d.and_time(NaiveTime::MIN),
+            EventTime::Floating(l) | EventTime::Utc(l) => *l,
+            EventTime::Zoned { local, .. } => *local,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Frequency {
+    Daily,
+    Weekly,
+    Monthly,
+    Yearly,
+}
+
+/// The supported subset of `RRULE`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RecurrenceRule {
+    pub freq: Frequency,
+    pub interval: u32,
+    pub count: Option<u32>,
+    pub until: Option<EventTime>,
+    /// Weekdays with an optional ordinal (`2MO`, `-1FR`).
+    pub by_day: Vec<(Option<i32>, Weekday)>,
+    pub by_month_day: Vec<i32>,
+    pub by_month: Vec<u32>,
+    pub by_set_pos: Vec<i32>,
+    pub week_start: Weekday,
+}
+
+/// A parsed `VEVENT`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CalendarEvent {
+    pub uid: String,
+    pub summary: String,
+    pub description: Option<String>,
+    pub location: Option<String>,
+    pub status: Option<String>,
+    pub start: EventTime,
+    pub end: Option<EventTime>,
+    pub duration: Option<Duration>,
+    pub rrule: Option<RecurrenceRule>,
+    pub rdates: Vec<EventTime>,
+    pub exdates: Vec<EventTime>,
+    pub recurrence_id: Option<EventTime>,
+}
+
+/// A parsed `VCALENDAR`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Calendar {
+    /// `X-WR-CALNAME`
+    pub name: Option<String>,
+    /// `X-WR-TIMEZONE`, used for floating times when present.
+    pub timezone: Option<String>,
+    pub events: Vec<CalendarEvent>,
+    /// Events that could not be parsed.
+    pub warnings: Vec<String>,
+}
+
+/// One concrete occurrence of an event.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct EventOccurrence {
+    pub uid: String,
+    pub summary: String,
+    /// RFC 3339 in the output zone, or `YYYY-MM-DD` for all-day events.
+    pub start: String,
+    pub end: Option<String>,
+    pub all_day: bool,
+    pub recurring: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub location: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<String>,
+    #[serde(skip)]
+    pub start_timestamp: i64,
+}
+
+/// Output of `cal parse` and the `calendar_events` tool.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct CalendarEventsOutput {
+    pub source: String,
+    pub calendar_name: Option<String>,
+    pub timezone: String,
+    /// First day of the range (inclusive).
+    pub from: String,
+    /// Last day of the range (inclusive).
+    pub to: String,
+    pub events: Vec<EventOccurrence>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub warnings: Vec<String>,
+}
+
+/// A content line: `NAME;PARAM=value:VALUE`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Property {
+    name: String,
+    params: Vec<(String, String)>,
+    value: String,
+}
+
+impl Property {
+    fn param(&self, name: &str) -> Option<&str> {
+        self.params
+            .iter()
+            .find(|(n, _)| n.eq_ignore_ascii_case(name))
+            .map(|(_, v)| v.as_str())
+    }
+}
+
+/// Join folded lines (continuations start with a space or tab).
+fn unfold(text: &str) -> Vec<String> {
+    let mut lines: Vec<String> = Vec::new();
+    for line in text.lines() {
+        let line = line.trim_end_matches('\r');
+        match (line.strip_prefix([' ', '\t']), lines.last_mut()) {
+            (Some(rest), Some(last)) => last.push_str(rest),
+            _ if line.is_empty() => {}
+            _ => lines.push(line.to_string()),
+        }
+    }
+    lines
+}
+
+fn parse_property(line: &str) -> Option<Property> {
+    // The value starts at the first ':' outside a quoted parameter value.
+    let mut in_quotes = false;
+    let colon = line.char_indices().find_map(|(i, c)| match c {
+        '"' => {
+            in_quotes = !in_quotes;
+            None
+        }
+        ':' if !in_quotes => Some(i),
+        _ => None,
+    })?;
+    let (head, value) = (&line[..colon], &line[colon + 1..]);
+
+    let mut parts = head.split(';');
+    let name = parts.next()?.trim().to_uppercase();
+    let params = parts
+        .filter_map(|p| p.split_once('='))
+        .map(|(k, v)| (k.trim().to_uppercase(), v.trim_matches('"').to_string()))
+        .collect();
+
+    Some(Property {
+        name,
+        params,
+        value: value.to_string(),
+    })
+}
+
+/// Unescape a TEXT value (`\n`, `\,`, `\;`, `\\`).
+fn unescape_text(value: &str) -> String {
+    let mut out = String::with_capacity(value.len());
+    let mut chars = value.chars();
+    while let Some(c) = chars.next() {
+        if c == '\\' {
+            match chars.next() {
+                Some('n') | Some('N') => out.push('\n'),
+                Some(other) => out.push(other),
+                None => out.push('\\'),
+            }
+        } else {
+            out.push(c);
+        }
+    }
+    out
+}
+
+fn parse_date(value: &str) -> Result<NaiveDate, String> {
+    NaiveDate::parse_from_str(value, "%Y%m%d").map_err(|_| format!("Invalid date '{}'", value))
+}
+
+fn parse_datetime(value: &str) -> Result<NaiveDateTime, String> {
+    NaiveDateTime::parse_from_str(value, "%Y%m%dT%H%M%S")
+        .map_err(|_| format!("Invalid date-time '{}'", value))
+}
+
+/// Parse a date or date-time value, honoring `VALUE=DATE` and `TZID`.
+fn parse_time_value(value: &str, tzid: Option<&str>, is_date: bool) -> Result<EventTime, String> {
+    let value = value.trim();
+    if is_date || value.len() == 8 {
+        return parse_date(value).map(EventTime::Date);
+    }
+    if let Some(utc) = value.strip_suffix('Z') {
+        return parse_datetime(utc).map(EventTime::Utc);
+    }
+    let local = parse_datetime(value)?;
+    Ok(match tzid {
+        Some(tzid) => EventTime::Zoned {
+            local,
+            tzid: tzid.to_string(),
+        },
+        None => EventTime::Floating(local),
+    })
+}
+
+fn parse_time_property(prop: &Property) -> Result<Vec<EventTime>, String> {
+    let is_date = prop
+        .param("VALUE")
+        .is_some_and(|v| v.eq_ignore_ascii_case("DATE"));
+    prop.value
+        .split(',')
+        .filter(|v| !v.trim().is_empty())
+        .map(|v| parse_time_value(v, prop.param("TZID"), is_date))
+        .collect()
+}
+
+/// Parse an RFC 5545 duration (`P1D`, `PT1H30M`, `-PT15M`, `P2W`).
+pub fn parse_duration(value: &str) -> Result<Duration, String> {
+    let invalid = || format!("Invalid duration '{}'", value);
+    let (negative, rest) = match value.trim().strip_prefix('-') {
+        Some(rest) => (true, rest),
+        None => (
+            false,
+            value.trim().strip_prefix('+').unwrap_or(value.trim()),
+        ),
+    };
+    let rest = rest.strip_prefix('P').ok_or_else(invalid)?;
+
+    let mut total = Duration::zero();
+    let mut number = String::new();
+    let mut in_time = false;
+    for c in rest.chars() {
+        match c {
+            'T' => in_time = true,
+            '0'..='9' => number.push(c),
+            _ => {
+                let n: i64 = number.parse().map_err(|_| invalid())?;
+                number.clear();
+                total += match (c, in_time) {
+                    ('W', false) => Duration::weeks(n),
+                    ('D', false) => Duration::days(n),
+                    ('H', true) => Duration::hours(n),
+                    ('M', true) => Duration::minutes(n),
+                    ('S', true) => Duration::seconds(n),
+                    _ => return Err(invalid()),
+                };
+            }
+        }
+    }
+    if !number.is_empty() {
+        return Err(invalid());
+    }
+    Ok(if negative { -total } else { total })
+}
+
+fn parse_weekday(value: &str) -> Option<Weekday> {
+    match value.to_uppercase().as_str() {
+        "MO" => Some(Weekday::Mon),
+        "TU" => Some(Weekday::Tue),
+        "WE" => Some(Weekday::Wed),
+        "TH" => Some(Weekday::Thu),
+        "FR" => Some(Weekday::Fri),
+        "SA" => Some(Weekday::Sat),
+        "SU" => Some(Weekday::Sun),
+        _ => None,
+    }
+}
+
+fn parse_number_list<T: std::str::FromStr>(value: &str, part: &str) -> Result<Vec<T>, String> {
+    value
+        .split(',')
+        .map(|v| {
+            v.trim()
+                .parse()
+                .map_err(|_| format!("Invalid {} value '{}'", part, v))
+        })
+        .collect()
+}
+
+/// Parse an `RRULE` value. `BYHOUR`/`BYMINUTE`/`BYSECOND` are ignored (the
+/// time comes from `DTSTART`); other unsupported parts are an error.
+pub fn parse_rrule(value: &str) -> Result<RecurrenceRule, String> {
+    let mut freq = None;
+    let mut rule = RecurrenceRule {
+        freq: Frequency::Daily,
+        interval: 1,
+        count: None,
+        until: None,
+        by_day: vec![],
+        by_month_day: vec![],
+        by_month: vec![],
+        by_set_pos: vec![],
+        week_start: Weekday::Mon,
+    };
+
+    for part in value.split(';').filter(|p| !p.is_empty()) {
+        let (key, val) = part
+            .split_once('=')
+            .ok_or_else(|| format!("Invalid RRULE part '{}'", part))?;
+        match key.to_uppercase().as_str() {
+            "FREQ" => {
+                freq = Some(match val.to_uppercase().as_str() {
+                    "DAILY" => Frequency::Daily,
+                    "WEEKLY" => Frequency::Weekly,
+                    "MONTHLY" => Frequency::Monthly,
+                    "YEARLY" => Frequency::Yearly,
+                    other => return Err(format!("Unsupported RRULE frequency '{}'", other)),
+                })
+            }
+            "INTERVAL" => {
+                rule.interval = val
+                    .parse()
+                    .ok()
+                    .filter(|i| *i > 0)
+                    .ok_or_else(|| format!("Invalid INTERVAL '{}'", val))?
+            }
+            "COUNT" => {
+                rule.count = Some(
+                    val.parse()
+                        .map_err(|_| format!("Invalid COUNT '{}'", val))?,
+                )
+            }
+            "UNTIL" => rule.until = Some(parse_time_value(val, None, false)?),
+            "BYDAY" => {
+                rule.by_day = val
+                    .split(',')
+                    .map(|d| {
+                        let d = d.trim();
+                        let split = d.len().saturating_sub(2);
+                        let (ordinal, day) = d.split_at(split);
+                        let weekday =
+                            parse_weekday(day).ok_or_else(|| format!("Invalid BYDAY '{}'", d))?;
+                        let ordinal = match ordinal {
+                            "" => None,
+                            o => Some(o.parse().map_err(|_| format!("Invalid BYDAY '{}'", d))?),
+                        };
+                        Ok((ordinal, weekday))
+                    })
+                    .collect::<Result<_, String>>()?
+            }
+            "BYMONTHDAY" => rule.by_month_day = parse_number_list(val, "BYMONTHDAY")?,
+            "BYMONTH" => rule.by_month = parse_number_list(val, "BYMONTH")?,
+            "BYSETPOS" => rule.by_set_pos = parse_number_list(val, "BYSETPOS")?,
+            "WKST" => {
+                rule.week_start =
+                    parse_weekday(val).ok_or_else(|| format!("Invalid WKST '{}'", val))?
+            }
+            "BYHOUR" | "BYMINUTE" | "BYSECOND" => {}
+            other => return Err(format!("Unsupported RRULE part '{}'", other)),
+        }
+    }
+
+    rule.freq = freq.ok_or("RRULE is missing FREQ")?;
+    Ok(rule)
+}
+
+/// Parse `.ics` text into events. Malformed events are skipped and reported
+/// in [`Calendar::warnings`]; only a missing `VCALENDAR` is an error.
+pub fn parse_ics(text: &str) -> Result<Calendar, String> {
+    let lines = unfold(text);
+    if !lines
+        .iter()
+        .any(|l| l.eq_ignore_ascii_case("BEGIN:VCALENDAR"))
+    {
+        return Err("Not an iCalendar file (missing BEGIN:VCALENDAR)".to_string());
+    }
+
+    let mut calendar = Calendar::default();
+    let mut stack: Vec<String> = Vec::new();
+    let mut current: Vec<Property> = Vec::new();
+
+    for line in &lines {
+        let Some(prop) = parse_property(line) else {
+            continue;
+        };
+        match prop.name.as_str() {
+            "BEGIN" => {
+                stack.push(prop.value.to_uppercase());
+                if prop.value.eq_ignore_ascii_case("VEVENT") {
+                    current.clear();
+                }
+            }
+            "END" => {
+                if stack.pop().as_deref() == Some("VEVENT") {
+                    match build_event(&current) {
+                        Ok(event) => calendar.events.push(event),
+                        Err(e) => calendar.warnings.push(e),
+                    }
+                }
+            }
+            _ => match stack.last().map(String::as_str) {
+                Some("VEVENT") => current.push(prop),
+                Some("VCALENDAR") => match prop.name.as_str() {
+                    "X-WR-CALNAME" => calendar.name = Some(unescape_text(&prop.value)),
+                    "X-WR-TIMEZONE" => calendar.timezone = Some(prop.value.clone()),
+                    _ => {}
+                },
+                _ => {}
+            },
+        }
+    }
+
+    Ok(calendar)
+}
+
+fn build_event(props: &[Property]) -> Result<CalendarEvent, String> {
+    let find = |name: &str| props.iter().find(|p| p.name == name);
+    let text = |name: &str| find(name).map(|p| unescape_text(&p.value));
+
+    let uid = text("UID").unwrap_or_default();
+    let summary = text("SUMMARY").unwrap_or_default();
+    let label = if summary.is_empty() { &uid } else { &summary };
+
+    let single_time = |name: &str| -> Result<Option<EventTime>, String> {
+        find(name)
+            .map(|p| {
+                parse_time_property(p)?
+                    .into_iter()
+                    .next()
+                    .ok_or_else(|| format!("Empty {}", name))
+            })
+            .transpose()
+            .map_err(|e| format!("Skipping event '{}': {}", label, e))
+    };
+
+    let start = single_time("DTSTART")?
+        .ok_or_else(|| format!("Skipping event '{}': missing DTSTART", label))?;
+    let end = single_time("DTEND")?;
+    let recurrence_id = single_time("RECURRENCE-ID")?;
+    let duration = find("DURATION")
+        .map(|p| parse_duration(&p.value))
+        .transpose()
+        .map_err(|e| format!("Skipping event '{}': {}", label, e))?;
+    let rrule = find("RRULE")
+        .map(|p| parse_rrule(&p.value))
+        .transpose()
+        .map_err(|e| format!("Skipping event '{}': {}", label, e))?;
+
+    let mut rdates = Vec::new();
+    let mut exdates = Vec::new();
+    for prop in props {
+        let list = match prop.name.as_str() {
+            "RDATE" => &mut rdates,
+            "EXDATE" => &mut exdates,
+            _ => continue,
+        };
+        list.extend(
+            parse_time_property(prop).map_err(|e| format!("Skipping event '{}': {}", label, e))?,
+        );
+    }
+
+    Ok(CalendarEvent {
+        uid,
+        summary,
+        description: text("DESCRIPTION").filter(|d| !d.is_empty()),
+        location: text("LOCATION").filter(|l| !l.is_empty()),
+        status: text("STATUS").map(|s| s.to_uppercase()),
+        start,
+        end,
+        duration,
+        rrule,
+        rdates,
+        exdates,
+        recurrence_id,
+    })
+}
+
+/// Map common Windows time zone names (Outlook/Exchange `TZID`s) to IANA.
+pub fn windows_zone_to_iana(name: &str) -> Option<&'static str> {
+    Some(match name {
+        "UTC" | "Coordinated Universal Time" | "GMT Standard Time" => "Europe/London",
+        "Pacific Standard Time" => "America/Los_Angeles",
+        "Mountain Standard Time" => "America/Denver",
+        "US Mountain Standard Time" => "America/Phoenix",
+        "Central Standard Time" => "America/Chicago",
+        "Eastern Standard Time" => "America/New_York",
+        "Atlantic Standard Time" => "America/Halifax",
+        "SA Pacific Standard Time" => "America/Bogota",
+        "Central Standard Time (Mexico)" => "America/Mexico_City",
+        "E. South America Standard Time" => "America/Sao_Paulo",
+        "Argentina Standard Time" => "America/Argentina/Buenos_Aires",
+        "W. Europe Standard Time" => "Europe/Berlin",
+        "Romance Standard Time" => "Europe/Paris",
+        "Central Europe Standard Time" => "Europe/Budapest",
+        "Central European Standard Time" => "Europe/Warsaw",
+        "E. Europe Standard Time" => "Europe/Chisinau",
+        "FLE Standard Time" => "Europe/Kiev",
+        "Russian Standard Time" => "Europe/Moscow",
+        "India Standard Time" => "Asia/Kolkata",
+        "China Standard Time" => "Asia/Shanghai",
+        "Singapore Standard Time" => "Asia/Singapore",
+        "Tokyo Standard Time" => "Asia/Tokyo",
+        "AUS Eastern Standard Time" => "Australia/Sydney",
+        "New Zealand Standard Time" => "Pacific/Auckland",
+        _ => return None,
+    })
+}
+
+fn to_civil(local: NaiveDateTime) -> Option<jiff::civil::DateTime> {
+    jiff::civil::DateTime::new(
+        i16::try_from(local.year()).ok()?,
+        local.month() as i8,
+        local.day() as i8,
+        local.hour() as i8,
+        local.minute() as i8,
+        local.second() as i8,
+        0,
+    )
+    .ok()
+}
+
+/// Convert wall-clock time in `tz` to an instant. Nonexistent and ambiguous
+/// times (DST transitions) resolve like most calendar clients do.
+fn local_to_timestamp(local: NaiveDateTime, tz: &TimeZone) -> Option<Timestamp> {
+    to_civil(local)?
+        .to_zoned(tz.clone())
+        .ok()
+        .map(|z| z.timestamp())
+}
+
+fn utc_to_timestamp(utc: NaiveDateTime) -> Option<Timestamp> {
+    Timestamp::from_second(utc.and_utc().timestamp()).ok()
+}
+
+fn format_timestamp(ts: Timestamp, tz: &TimeZone) -> String {
+    ts.to_zoned(tz.clone())
+        .strftime("%Y-%m-%dT%H:%M:%S%:z")
+        .to_string()
+}
+
+/// Resolves the zone an event's local times are expressed in.
+struct EventZone {
+    tz: TimeZone,
+    utc: bool,
+}
+
+impl EventZone {
+    fn to_timestamp(&self, local: NaiveDateTime) -> Option<Timestamp> {
+        if self.utc {
+            utc_to_timestamp(local)
+        } else {
+            local_to_timestamp(local, &self.tz)
+        }
+    }
+}
+
+fn add_months(year: i32, month: u32, months: i64) -> Option<(i32, u32)> {
+    let total = i64::from(year) * 12 + i64::from(month) - 1 + months;
+    Some((
+        i32::try_from(total.div_euclid(12)).ok()?,
+        (total.rem_euclid(12) + 1) as u32,
+    ))
+}
+
+fn days_in_month(year: i32, month: u32) -> Vec<NaiveDate> {
+    let Some(first) = NaiveDate::from_ymd_opt(year, month, 1) else {
+        return vec![];
+    };
+    first
+        .iter_days()
+        .take_while(|d| d.month() == month)
+        .collect()
+}
+
+fn resolve_month_day(days: &[NaiveDate], month_day: i32) -> Option<NaiveDate> {
+    let len = days.len() as i32;
+    let index = if month_day > 0 {
+        month_day - 1
+    } else {
+        len + month_day
+    };
+    (0..len).contains(&index).then(|| days[index as usize])
+}
+
+/// Days in `days` matching a weekday, optionally only the nth (`-1` = last).
+fn nth_weekdays(days: &[NaiveDate], ordinal: Option<i32>, weekday: Weekday) -> Vec<NaiveDate> {
+    let matching: Vec<NaiveDate> = days
+        .iter()
+        .copied()
+        .filter(|d| d.weekday() == weekday)
+        .collect();
+    match ordinal {
+        None => matching,
+        Some(n) => resolve_month_day(&matching, n).into_iter().collect(),
+    }
+}
+
+/// Candidate dates in a set of days (a month or a year) per BYMONTHDAY/BYDAY.
+fn expand_days(
+    rule: &RecurrenceRule,
+    days: &[NaiveDate],
+    default: Option<NaiveDate>,
+) -> Vec<NaiveDate> {
+    if !rule.by_month_day.is_empty() {
+        rule.by_month_day
+            .iter()
+            .filter_map(|md| resolve_month_day(days, *md))
+            .filter(|d| {
+                rule.by_day.is_empty() || rule.by_day.iter().any(|(_, wd)| *wd == d.weekday())
+            })
+            .collect()
+    } else if !rule.by_day.is_empty() {
+        rule.by_day
+            .iter()
+            .flat_map(|(ordinal, weekday)| nth_weekdays(days, *ordinal, *weekday))
+            .collect()
+    } else {
+        default.into_iter().collect()
+    }
+}
+
+/// Candidate dates for the `k`-th period of a rule starting on `start`.
+fn period_dates(rule: &RecurrenceRule, start: NaiveDate, k: i64) -> Vec<NaiveDate> {
+    let step = k * i64::from(rule.interval);
+    let mut dates = match rule.freq {
+        Frequency::Daily => {
+            let day = start + Duration::days(step);
+            let ok_month_day = rule.by_month_day.is_empty()
+                || rule.by_month_day.iter().any(|md| {
+                    resolve_month_day(&days_in_month(day.year(), day.month()), *md) == Some(day)
+                });
+            let ok_weekday =
+                rule.by_day.is_empty() || rule.by_day.iter().any(|(_, wd)| *wd == day.weekday());
+            if ok_month_day && ok_weekday {
+                vec![day]
+            } else {
+                vec![]
+            }
+        }
+        Frequency::Weekly => {
+            let offset = |wd: Weekday| i64::from(wd.days_since(rule.week_start));
+            let week = start - Duration::days(offset(start.weekday())) + Duration::weeks(step);
+            if rule.by_day.is_empty() {
+                vec![week + Duration::days(offset(start.weekday()))]
+            } else {
+                rule.by_day
+                    .iter()
+                    .map(|(_, wd)| week + Duration::days(offset(*wd)))
+                    .collect()
+            }
+        }
+        Frequency::Monthly => match add_months(start.year(), start.month(), step) {
+            Some((year, month)) => {
+                let days = days_in_month(year, month);
+                let default = NaiveDate::from_ymd_opt(year, month, start.day());
+                expand_days(rule, &days, default)
+            }
+            None => vec![],
+        },
+        Frequency::Yearly => {
+            let Ok(year) = i32::try_from(i64::from(start.year()) + step) else {
+                return vec![];
+            };
+            if rule.by_month.is_empty() && !rule.by_day.is_empty() && rule.by_month_day.is_empty() {
+                // BYDAY ordinals count within the whole year.
+                let days: Vec<NaiveDate> = (1..=12).flat_map(|m| days_in_month(year, m)).collect();
+                expand_days(rule, &days, None)
+            } else {
+                let months = if rule.by_month.is_empty() {
+                    vec![start.month()]
+                } else {
+                    rule.by_month.clone()
+                };
+                months
+                    .into_iter()
+                    .flat_map(|month| {
+                        let days = days_in_month(year, month);
+                        let default = NaiveDate::from_ymd_opt(year, month, start.day());
+                        expand_days(rule, &days, default)
+                    })
+                    .collect()
+            }
+        }
+    };
+
+    if !rule.by_month.is_empty() {
+        dates.retain(|d| rule.by_month.contains(&d.month()));
+    }
+    dates.sort();
+    dates.dedup();
+
+    if !rule.by_set_pos.is_empty() {
+        let all = dates;
+        dates = rule
+            .by_set_pos
+            .iter()
+            .filter_map(|pos| resolve_month_day(&all, *pos))
+            .collect();
+        dates.sort();
+        dates.dedup();
+    }
+    dates
+}
+
+/// Wall-clock start times of every occurrence of `event` up to `range_end`.
+fn series_starts(
+    event: &CalendarEvent,
+    zone: &EventZone,
+    range_end: Timestamp,
+) -> Vec<NaiveDateTime> {
+    let start = event.start.local();
+    let mut starts = vec![start];
+
+    if let Some(rule) = &event.rrule {
+        let until = rule.until.as_ref().and_then(|until| match until {
+            EventTime::Date(d) => zone.to_timestamp(d.succ_opt()?.and_time(NaiveTime::MIN)),
+            EventTime::Utc(l) => utc_to_timestamp(*l + Duration::seconds(1)),
+            other => zone.to_timestamp(other.local() + Duration::seconds(1)),
+        });
+        let mut count = 1;
+
+        'periods: for k in 0..MAX_PERIODS {
+            for date in period_dates(rule, start.date(), k) {
+                let local = date.and_time(start.time());
+                if local <= start {
+                    continue;
+                }
+                let Some(ts) = zone.to_timestamp(local) else {
+                    continue;
+                };
+                if until.is_some_and(|u| ts >= u)
+                    || rule.count.is_some_and(|c| count >= c)
+                    || ts >= range_end
+                {
+                    break 'periods;
+                }
+                count += 1;
+                starts.push(local);
+            }
+        }
+    }
+
+    starts.extend(event.rdates.iter().map(|r| match r {
+        EventTime::Date(d) => d.and_time(start.time()),
+        other => other.local(),
+    }));
+    starts
+}
+
+/// Expand events into occurrences overlapping `[from, to]` (whole days in
+/// `output_tz`), sorted by start time.
+///
+/// `resolve_tz` maps a `TZID` to a zone; unknown zones fall back to
+/// `output_tz` with a warning. `RECURRENCE-ID` overrides replace the matching
+/// occurrence and cancelled events are dropped.
+pub fn expand_events(
+    calendar: &Calendar,
+    from: NaiveDate,
+    to: NaiveDate,
+    output_tz: &TimeZone,
+    resolve_tz: &dyn Fn(&str) -> Option<TimeZone>,
+) -> (Vec<EventOccurrence>, Vec<String>) {
+    let mut warnings = Vec::new();
+    let (Some(range_start), Some(range_end)) = (
+        local_to_timestamp(from.and_time(NaiveTime::MIN), output_tz),
+        to.succ_opt()
+            .and_then(|d| local_to_timestamp(d.and_time(NaiveTime::MIN), output_tz)),
+    ) else {
+        return (vec![], vec!["Invalid date range".to_string()]);
+    };
+
+    let floating_tz = calendar
+        .timezone
+        .as_deref()
+        .and_then(resolve_tz)
+        .unwrap_or_else(|| output_tz.clone());
+
+    let mut zone_for = |time: &EventTime| -> EventZone {
+        match time {
+            EventTime::Utc(_) => EventZone {
+                tz: TimeZone::UTC,
+                utc: true,
+            },
+            EventTime::Zoned { tzid, .. } => {
+                let tz = resolve_tz(tzid)
+                    .or_else(|| windows_zone_to_iana(tzid).and_then(resolve_tz))
+                    .unwrap_or_else(|| {
+                        let warning = format!("Unknown time zone '{}', using output zone", tzid);
+                        if !warnings.contains(&warning) {
+                            warnings.push(warning);
+                        }
+                        output_tz.clone()
+                    });
+                EventZone { tz, utc: false }
+            }
+            EventTime::Date(_) => EventZone {
+                tz: output_tz.clone(),
+                utc: false,
+            },
+            EventTime::Floating(_) => EventZone {
+                tz: floating_tz.clone(),
+                utc: false,
+            },
+        }
+    };
+
+    // (uid, instant) of occurrences replaced by RECURRENCE-ID overrides
+    let mut overridden: Vec<(String, Timestamp)> = Vec::new();
+    for event in &calendar.events {
+        if let Some(id) = &event.recurrence_id {
+            if let Some(ts) = zone_for(id).to_timestamp(id.local()) {
+                overridden.push((event.uid.clone(), ts));
+            }
+        }
+    }
+
+    let mut occurrences = Vec::new();
+    for event in &calendar.events {
+        let zone = zone_for(&event.start);
+        let all_day = matches!(event.start, EventTime::Date(_));
+        let length = match (&event.end, event.duration) {
+            (Some(end), _) if all_day => end.local() - event.start.local(),
+            (Some(end), _) => {
+                // Compare as instants so DTEND in another zone still works.
+                let end_zone = zone_for(end);
+                match (
+                    zone.to_timestamp(event.start.local()),
+                    end_zone.to_timestamp(end.local()),
+                ) {
+                    (Some(s), Some(e)) => Duration::seconds(e.as_second() - s.as_second()),
+                    _ => Duration::zero(),
+                }
+            }
+            (None, Some(duration)) => duration,
+            (None, None) if all_day => Duration::days(1),
+            (None, None) => Duration::zero(),
+        };
+        let excluded: Vec<Timestamp> = event
+            .exdates
+            .iter()
+            .filter_map(|ex| match ex {
+                EventTime::Date(d) => zone.to_timestamp(d.and_time(event.start.local().time())),
+                other => zone_for(other).to_timestamp(other.local()),
+            })
+            .collect();
+        let cancelled = event.status.as_deref() == Some("CANCELLED");
+        let recurring =
+            event.rrule.is_some() || !event.rdates.is_empty() || event.recurrence_id.is_some();
+
+        if cancelled {
+            continue;
+        }
+
+        for local in series_starts(event, &zone, range_end) {
+            let Some(start_ts) = zone.to_timestamp(local) else {
+                continue;
+            };
+            if excluded.contains(&start_ts) {
+                continue;
+            }
+            if event.recurrence_id.is_none()
+                && overridden
+                    .iter()
+                    .any(|(uid, ts)| *uid == event.uid && *ts == start_ts)
+            {
+                continue;
+            }
+
+            let end_local = local + length;
+            let end_ts = if all_day {
+                zone.to_timestamp(end_local)
+            } else {
+                Timestamp::from_second(start_ts.as_second() + length.num_seconds()).ok()
+            }
+            .unwrap_or(start_ts);
+
+            // Zero-length events overlap the range when they start inside it.
+            let overlaps = start_ts < range_end
+                && (end_ts > range_start || (end_ts == start_ts && start_ts >= range_start));
+            if !overlaps {
+                continue;
+            }
+
+            let (start, end) = if all_day {
+                let last_day = (end_local - Duration::days(1)).date();
+                let end = (last_day > local.date()).then(|| last_day.to_string());
+                (local.date().to_string(), end)
+            } else {
+                let end = (end_ts != start_ts).then(|| format_timestamp(end_ts, output_tz));
+                (format_timestamp(start_ts, output_tz), end)
+            };
+
+            occurrences.push(EventOccurrence {
+                uid: event.uid.clone(),
+                summary: event.summary.clone(),
+                start,
+                end,
+                all_day,
+                recurring,
+                location: event.location.clone(),
+                description: event.description.clone(),
+                status: event.status.clone(),
+                start_timestamp: start_ts.as_second(),
+            });
+        }
+    }
+
+    occurrences.sort_by(|a, b| {
+        a.start_timestamp
+            .cmp(&b.start_timestamp)
+            .then(b.all_day.cmp(&a.all_day))
+            .then(a.summary.cmp(&b.summary))
+    });
+    (occurrences, warnings)
+}
+
+/// Resolve the inclusive day range for a query. Without `from` the range
+/// starts `today`; without `to` it spans `days` days.
+pub fn resolve_range(
+    today: NaiveDate,
+    from: Option<NaiveDate>,
+    to: Option<NaiveDate>,
+    days: u32,
+) -> Result<(NaiveDate, NaiveDate), String> {
+    let from = from.unwrap_or(today);
+    let to = match to {
+        Some(to) => to,
+        None if days == 0 => return Err("days must be at least 1".to_string()),
+        None => from + Duration::days(i64::from(days) - 1),
+    };
+    if to < from {
+        return Err(format!("End date {} is before start date {}", to, from));
+    }
+    Ok((from, to))
+}
+
+/// Render occurrences as Markdown grouped by day.
+pub fn render_events_markdown(output: &CalendarEventsOutput) -> String {
+    let title = output.calendar_name.as_deref().unwrap_or("Events");
+    let mut out = format!(
+        "# {}\n\n**Range:** {} to {} ({})\n",
+        title, output.from, output.to, output.timezone
+    );
+
+    if output.events.is_empty() {
+        out.push_str("\nNo events in range.\n");
+    }
+
+    let mut current_day: Option<NaiveDate> = None;
+    for event in &output.events {
+        // Start strings are already in the output zone.
+        let start = if event.all_day {
+            NaiveDate::parse_from_str(&event.start, "%Y-%m-%d")
+                .ok()
+                .map(|d| d.and_time(NaiveTime::MIN))
+        } else {
+            chrono::DateTime::parse_from_rfc3339(&event.start)
+                .ok()
+                .map(|d| d.naive_local())
+        };
+        let Some(start) = start else {
+            continue;
+        };
+        let day = start.date();
+        if current_day != Some(day) {
+            out.push_str(&format!("\n## {}\n\n", day.format("%a, %Y-%m-%d")));
+            current_day = Some(day);
+        }
+
+        let when = if event.all_day {
+            match &event.end {
+                Some(end) => format!("All day (until {})", end),
+                None => "All day".to_string(),
+            }
+        } else {
+            let end = event
+                .end
+                .as_deref()
+                .and_then(|e| chrono::DateTime::parse_from_rfc3339(e).ok())
+                .map(|e| {
+                    if e.date_naive() == start.date() {
+                        format!("–{}", e.format("%H:%M"))
+                    } else {
+                        format!("–{}", e.format("%a %H:%M"))
+                    }
+                })
+                .unwrap_or_default();
+            format!("{}{}", start.format("%H:%M"), end)
+        };
+
+        let summary = if event.summary.is_empty() {
+            "(no title)"
+        } else {
+            &event.summary
+        };
+        out.push_str(&format!("- {} **{}**", when, summary));
+        if let Some(location) = &event.location {
+            out.push_str(&format!(" @ {}", location));
+        }
+        if event.status.as_deref() == Some("TENTATIVE") {
+            out.push_str(" _(tentative)_");
+        }
+        out.push('\n');
+    }
+
+    for warning in &output.warnings {
+        out.push_str(&format!("\n> Warning: {}\n", warning));
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fixed(hours: i8) -> TimeZone {
+        TimeZone::fixed(jiff::tz::offset(hours))
+    }
+
+    /// Resolver with fixed-offset zones so tests don't depend on the system tzdb.
+    fn resolver(name: &str) -> Option<TimeZone> {
+        match name {
+            "America/New_York" => Some(fixed(-5)),
+            "Europe/Berlin" => Some(fixed(1)),
+            _ => None,
+        }
+    }
+
+    fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    fn ics(events: &str) -> String {
+        format!(
+            "BEGIN:VCALENDAR\r\nVERSION:2.0\r\nX-WR-CALNAME:Work\r\n{}END:VCALENDAR\r\n",
+            events
+        )
+    }
+
+    fn expand(text: &str, from: NaiveDate, to: NaiveDate, tz: &TimeZone) -> Vec<EventOccurrence> {
+        let calendar = parse_ics(text).unwrap();
+        expand_events(&calendar, from, to, tz, &resolver).0
+    }
+
+    #[test]
+    fn test_parse_ics_properties() {
+        let text = ics("BEGIN:VEVENT\r\nUID:1\r\nSUMMARY:Plan\\, review\\; ship\r\nDESCRIPTION:Line one\\nline\r\n  two\r\nLOCATION;LANGUAGE=en:\"Room: 4\"\r\nDTSTART;TZID=America/New_York:20240105T090000\r\nDTEND;TZID=America/New_York:20240105T100000\r\nEND:VEVENT\r\n");
+        let calendar = parse_ics(&text).unwrap();
+
+        assert_eq!(calendar.name.as_deref(), Some("Work"));
+        let event = &calendar.events[0];
+        assert_eq!(event.summary, "Plan, review; ship");
+        assert_eq!(event.description.as_deref(), Some("Line one\nline two"));
+        assert_eq!(event.location.as_deref(), Some("\"Room: 4\""));
+        assert_eq!(
+            event.start,
+            EventTime::Zoned {
+                local: date(2024, 1, 5).and_hms_opt(9, 0, 0).unwrap(),
+                tzid: "America/New_York".to_string()
+            }
+        );
+    }
+
+    #[test]
+    fn test_parse_ics_rejects_non_calendar() {
+        assert!(parse_ics("hello").is_err());
+    }
+
+    #[test]
+    fn test_parse_ics_warns_on_bad_event() {
+        let calendar =
+            parse_ics(&ics("BEGIN:VEVENT\r\nSUMMARY:No start\r\nEND:VEVENT\r\n")).unwrap();
+        assert!(calendar.events.is_empty());
+        assert_eq!(
+            calendar.warnings,
+            vec!["Skipping event 'No start': missing DTSTART"]
+        );
+    }
+
+    #[test]
+    fn test_parse_duration() {
+        assert_eq!(parse_duration("PT1H30M"), Ok(Duration::minutes(90)));
+        assert_eq!(parse_duration("P1W"), Ok(Duration::days(7)));
+        assert_eq!(parse_duration("-PT15M"), Ok(Duration::minutes(-15)));
+        assert_eq!(parse_duration("P1DT12H"), Ok(Duration::hours(36)));
+        assert!(parse_duration("1H").is_err());
+        assert!(parse_duration("PT5").is_err());
+    }
+
+    #[test]
+    fn test_parse_rrule() {
+        let rule = parse_rrule("FREQ=MONTHLY;INTERVAL=2;BYDAY=2TU,-1FR;COUNT=5").unwrap();
+        assert_eq!(rule.freq, Frequency::Monthly);
+        assert_eq!(rule.interval, 2);
+        assert_eq!(rule.count, Some(5));
+        assert_eq!(
+            rule.by_day,
+            vec![(Some(2), Weekday::Tue), (Some(-1), Weekday::Fri)]
+        );
+        assert!(parse_rrule("FREQ=HOURLY").is_err());
+        assert!(parse_rrule("INTERVAL=2").is_err());
+        assert!(parse_rrule("FREQ=DAILY;BYWEEKNO=3").is_err());
+    }
+
+    #[test]
+    fn test_expand_zoned_event_to_output_zone() {
+        let text = ics("BEGIN:VEVENT\r\nUID:1\r\nSUMMARY:Call\r\nDTSTART;TZID=America/New_York:20240105T090000\r\nDURATION:PT30M\r\nEND:VEVENT\r\n");
+        let events = expand(&text, date(2024, 1, 1), date(2024, 1, 7), &fixed(1));
+
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].start, "2024-01-05T15:00:00+01:00");
+        assert_eq!(events[0].end.as_deref(), Some("2024-01-05T15:30:00+01:00"));
+        assert!(!events[0].recurring);
+    }
+
+    #[test]
+    fn test_expand_weekly_with_exdate_and_override() {
+        let text = ics("BEGIN:VEVENT\r\nUID:standup\r\nSUMMARY:Standup\r\nDTSTART:20240101T140000Z\r\nDTEND:20240101T141500Z\r\nRRULE:FREQ=WEEKLY;BYDAY=MO,WE\r\nEXDATE:20240103T140000Z\r\nEND:VEVENT\r\nBEGIN:VEVENT\r\nUID:standup\r\nSUMMARY:Standup (moved)\r\nRECURRENCE-ID:20240108T140000Z\r\nDTSTART:20240108T160000Z\r\nDTEND:20240108T161500Z\r\nEND:VEVENT\r\n");
+        let events = expand(&text, date(2024, 1, 1), date(2024, 1, 10), &TimeZone::UTC);
+        let summary: Vec<(&str, &str)> = events
+            .iter()
+            .map(|e| (e.start.as_str(), e.summary.as_str()))
+            .collect();
+
+        assert_eq!(
+            summary,
+            vec![
+                ("2024-01-01T14:00:00+00:00", "Standup"),
+                ("2024-01-08T16:00:00+00:00", "Standup (moved)"),
+                ("2024-01-10T14:00:00+00:00", "Standup"),
+            ]
+        );
+        assert!(events.iter().all(|e| e.recurring));
+    }
+
+    #[test]
+    fn test_expand_count_counts_from_dtstart() {
+        let text = ics("BEGIN:VEVENT\r\nUID:1\r\nSUMMARY:Daily\r\nDTSTART:20240101T090000\r\nRRULE:FREQ=DAILY;COUNT=3\r\nEND:VEVENT\r\n");
+        let events = expand(&text, date(2024, 1, 2), date(2024, 1, 31), &TimeZone::UTC);
+        let starts: Vec<&str> = events.iter().map(|e| e.start.as_str()).collect();
+        assert_eq!(
+            starts,
+            vec!["2024-01-02T09:00:00+00:00", "2024-01-03T09:00:00+00:00"]
+        );
+    }
+
+    #[test]
+    fn test_expand_monthly_nth_weekday_and_until() {
+        let text = ics("BEGIN:VEVENT\r\nUID:1\r\nSUMMARY:Review\r\nDTSTART:20240126T100000\r\nRRULE:FREQ=MONTHLY;BYDAY=-1FR;UNTIL=20240430T000000Z\r\nEND:VEVENT\r\n");
+        let events = expand(&text, date(2024, 1, 1), date(2024, 12, 31), &TimeZone::UTC);
+        let starts: Vec<&str> = events.iter().map(|e| &e.start[..10]).collect();
+        assert_eq!(
+            starts,
+            vec!["2024-01-26", "2024-02-23", "2024-03-29", "2024-04-26"]
+        );
+    }
+
+    #[test]
+    fn test_expand_monthly_bysetpos_last_weekday() {
+        let text = ics("BEGIN:VEVENT\r\nUID:1\r\nSUMMARY:Payroll\r\nDTSTART;VALUE=DATE:20240131\r\nRRULE:FREQ=MONTHLY;BYDAY=MO,TU,WE,TH,FR;BYSETPOS=-1;COUNT=3\r\nEND:VEVENT\r\n");
+        let events = expand(&text, date(2024, 1, 1), date(2024, 12, 31), &TimeZone::UTC);
+        let starts: Vec<&str> = events.iter().map(|e| e.start.as_str()).collect();
+        // June 30 2024 is a Sunday, but COUNT stops at March.
+        assert_eq!(starts, vec!["2024-01-31", "2024-02-29", "2024-03-29"]);
+        assert!(events.iter().all(|e| e.all_day && e.end.is_none()));
+    }
+
+    #[test]
+    fn test_expand_yearly_skips_missing_dates() {
+        let text = ics("BEGIN:VEVENT\r\nUID:1\r\nSUMMARY:Leap\r\nDTSTART;VALUE=DATE:20240229\r\nRRULE:FREQ=YEARLY\r\nEND:VEVENT\r\n");
+        let events = expand(&text, date(2024, 1, 1), date(2028, 12, 31), &TimeZone::UTC);
+        let starts: Vec<&str> = events.iter().map(|e| e.start.as_str()).collect();
+        assert_eq!(starts, vec!["2024-02-29", "2028-02-29"]);
+    }
+
+    #[test]
+    fn test_expand_multi_day_all_day_overlaps_range() {
+        let text = ics("BEGIN:VEVENT\r\nUID:1\r\nSUMMARY:Offsite\r\nDTSTART;VALUE=DATE:20240104\r\nDTEND;VALUE=DATE:20240107\r\nEND:VEVENT\r\n");
+        let events = expand(&text, date(2024, 1, 6), date(2024, 1, 10), &TimeZone::UTC);
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].start, "2024-01-04");
+        assert_eq!(events[0].end.as_deref(), Some("2024-01-06"));
+    }
+
+    #[test]
+    fn test_expand_unknown_zone_and_cancelled() {
+        let text = ics("BEGIN:VEVENT\r\nUID:1\r\nSUMMARY:Somewhere\r\nDTSTART;TZID=Mars/Olympus:20240105T090000\r\nEND:VEVENT\r\nBEGIN:VEVENT\r\nUID:2\r\nSUMMARY:Gone\r\nSTATUS:CANCELLED\r\nDTSTART:20240105T090000Z\r\nEND:VEVENT\r\n");
+        let calendar = parse_ics(&text).unwrap();
+        let (events, warnings) = expand_events(
+            &calendar,
+            date(2024, 1, 1),
+            date(2024, 1, 7),
+            &TimeZone::UTC,
+            &resolver,
+        );
+
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].start, "2024-01-05T09:00:00+00:00");
+        assert_eq!(
+            warnings,
+            vec!["Unknown time zone 'Mars/Olympus', using output zone"]
+        );
+    }
+
+    #[test]
+    fn test_windows_zone_fallback() {
+        let text = ics("BEGIN:VEVENT\r\nUID:1\r\nSUMMARY:Sync\r\nDTSTART;TZID=Eastern Standard Time:20240105T090000\r\nEND:VEVENT\r\n");
+        let events = expand(&text, date(2024, 1, 1), date(2024, 1, 7), &TimeZone::UTC);
+        assert_eq!(events[0].start, "2024-01-05T14:00:00+00:00");
+    }
+
+    #[test]
+    fn test_resolve_range() {
+        let today = date(2024, 1, 10);
+        assert_eq!(
+            resolve_range(today, None, None, 7),
+            Ok((today, date(2024, 1, 16)))
+        );
+        assert_eq!(
+            resolve_range(today, Some(date(2024, 2, 1)), None, 1),
+            Ok((date(2024, 2, 1), date(2024, 2, 1)))
+        );
+        assert_eq!(
+            resolve_range(today, None, Some(date(2024, 1, 12)), 7),
+            Ok((today, date(2024, 1, 12)))
+        );
+        assert!(resolve_range(today, None, Some(date(2024, 1, 9)), 7).is_err());
+        assert!(resolve_range(today, None, None, 0).is_err());
+    }
+
+    #[test]
+    fn test_render_events_markdown() {
+        let text = ics("BEGIN:VEVENT\r\nUID:1\r\nSUMMARY:Call\r\nLOCATION:Zoom\r\nDTSTART:20240105T090000Z\r\nDTEND:20240105T093000Z\r\nEND:VEVENT\r\nBEGIN:VEVENT\r\nUID:2\r\nSUMMARY:Holiday\r\nDTSTART;VALUE=DATE:20240105\r\nEND:VEVENT\r\n");
+        let events = expand(&text, date(2024, 1, 1), date(2024, 1, 7), &TimeZone::UTC);
+        let output = CalendarEventsOutput {
+            source: "work.ics".to_string(),
+            calendar_name: Some("Work".to_string()),
+            timezone: "UTC".to_string(),
+            from: "2024-01-01".to_string(),
+            to: "2024-01-07".to_string(),
+            events,
+            warnings: vec![],
+        };
+        let markdown = render_events_markdown(&output);
+
+        assert_eq!(
+            markdown,
+            "# Work\n\n**Range:** 2024-01-01 to 2024-01-07 (UTC)\n\n## Fri, 2024-01-05\n\n- All day **Holiday**\n- 09:00–09:30 **Call** @ Zoom\n"
+        );
+    }
+}
@@ -0,0 +1,820 @@
+//! Content chunking for embedding pipelines
+//!
+//! Pure functions that split extracted document text (PDF sections, web pages
+//! converted to Markdown) into overlapping, token-bounded chunks. Each chunk
+//! records where it came from so retrieval results can be traced back to a
+//! section, page range, and character range of the source.
+//!
+//! Token counts are estimated as characters / 4, matching the estimate used
+//! elsewhere in mcptools; no tokenizer is required.
+//!
+//! [`split_for_streaming`] serves a different consumer: the MCP server's
+//! chunked tool results, where pieces must concatenate back to the input.
+
+use serde::{Deserialize, Serialize};
+
+/// Characters per estimated token.
+pub const CHARS_PER_TOKEN: usize = 4;
+
+/// Separator placed between segments when computing character offsets.
+pub const SEGMENT_SEPARATOR: &str = "\n\n";
+
+/// Error type for chunking operations
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ChunkError {
+    ZeroMaxTokens,
+    OverlapTooLarge { overlap: usize, max_tokens: usize },
+}
+
+impl std::fmt::Display for ChunkError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ChunkError::ZeroMaxTokens => write!(f, "--max-tokens must be greater than 0"),
+            ChunkError::OverlapTooLarge {
+                overlap,
+                max_tokens,
+            } => write!(
+                f,
+                "--overlap ({}) must be smaller than --max-tokens ({})",
+                overlap, max_tokens
+            ),
+        }
+    }
+}
+
+impl std::error::Error for ChunkError {}
+
+/// Chunk size limits, in estimated tokens.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ChunkOptions {
+    pub max_tokens: usize,
+    pub overlap: usize,
+}
+
+impl Default for ChunkOptions {
+    fn default() -> Self {
+        Self {
+            max_tokens: 512,
+            overlap: 64,
+        }
+    }
+}
+
+impl ChunkOptions {
+    /// Validate the limits: chunks must hold at least one token and the
+    /// overlap must leave room for new content in every chunk.
+    pub fn validate(&self) -> Result<(), ChunkError> {
+        if self.max_tokens == 0 {
+            return Err(ChunkError::ZeroMaxTokens);
+        }
+        if self.overlap >= self.max_tokens {
+            return Err(ChunkError::OverlapTooLarge {
+                overlap: self.overlap,
+                max_tokens: self.max_tokens,
+            });
+        }
+        Ok(())
+    }
+
+    fn max_chars(&self) -> usize {
+        self.max_tokens.saturating_mul(CHARS_PER_TOKEN)
+    }
+
+    fn overlap_chars(&self) -> usize {
+        self.overlap.saturating_mul(CHARS_PER_TOKEN)
+    }
+}
+
+/// A unit of extracted content (a paragraph, list, table, ...) with its origin.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct ChunkSegment {
+    pub text: String,
+    pub section_id: Option<String>,
+    pub section_title: Option<String>,
+    /// 1-based page number, for paged sources such as PDFs.
+    pub page: Option<usize>,
+}
+
+/// A chunk record ready for an embedding pipeline.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Chunk {
+    /// 0-based position of the chunk in the output.
+    pub index: usize,
+    pub text: String,
+    /// File path or URL the content was extracted from.
+    pub source: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub section_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub section: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub page_start: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub page_end: Option<usize>,
+    /// Character offset of the chunk start in the extracted text.
+    pub char_start: usize,
+    /// Character offset one past the chunk end in the extracted text.
+    pub char_end: usize,
+    pub token_count: usize,
+}
+
+/// Estimate the token count of `text`.
+pub fn estimate_tokens(text: &str) -> usize {
+    text.chars().count().div_ceil(CHARS_PER_TOKEN)
+}
+
+/// Split Markdown into segments, one per block separated by blank lines.
+///
+/// Headings start a new section: the heading text becomes the section title
+/// of every following segment, and its GitHub-style anchor the section ID.
+/// Fenced code blocks are kept whole even when they contain blank lines.
+pub fn markdown_segments(markdown: &str) -> Vec<ChunkSegment> {
+    let mut segments = Vec::new();
+    let mut section: Option<(String, String)> = None;
+    let mut current: Vec<&str> = Vec::new();
+    let mut in_fence = false;
+
+    let flush = |lines: &mut Vec<&str>,
+                 segments: &mut Vec<ChunkSegment>,
+                 section: &Option<(String, String)>| {
+        let text = lines.join("\n").trim().to_string();
+        lines.clear();
+        if !text.is_empty() {
+            segments.push(ChunkSegment {
+                text,
+                section_id: section.as_ref().map(|(id, _)| id.clone()),
+                section_title: section.as_ref().map(|(_, title)| title.clone()),
+                page: None,
+            });
+        }
+    };
+
+    for line in markdown.lines() {
+        let trimmed = line.trim_start();
+        if trimmed.starts_with("```") || trimmed.starts_with("~~~") {
+            in_fence = !in_fence;
+            current.push(line);
+            continue;
+        }
+        if in_fence {
+            current.push(line);
+            continue;
+        }
+        if line.trim().is_empty() {
+            flush(&mut current, &mut segments, &section);
+            continue;
+        }
+        if let Some(title) = heading_title(trimmed) {
+            flush(&mut current, &mut segments, &section);
+            section = Some((heading_anchor(&title), title));
+        }
+        current.push(line);
+    }
+    flush(&mut current, &mut segments, &section);
+
+    segments
+}
+
+/// Return the text of an ATX heading line (`# Title`), if it is one.
+fn heading_title(line: &str) -> Option<String> {
+    let hashes = line.chars().take_while(|&c| c == '#').count();
+    if hashes == 0 || hashes > 6 {
+        return None;
+    }
+    let rest = &line[hashes..];
+    if !rest.is_empty() && !rest.starts_with(' ') {
+        return None;
+    }
+    let title = rest.trim().trim_end_matches('#').trim();
+    (!title.is_empty()).then(|| title.to_string())
+}
+
+/// GitHub-style heading anchor: lowercase, spaces to dashes, punctuation dropped.
+fn heading_anchor(title: &str) -> String {
+    title
+        .chars()
+        .filter_map(|c| match c {
+            c if c.is_alphanumeric() => Some(c.to_lowercase().next().unwrap_or(c)),
+            ' ' | '-' => Some('-'),
+            '_' => Some('_'),
+            _ => None,
+        })
+        .collect()
+}
+
+/// A run of characters within a group, in char offsets relative to the group.
+#[derive(Debug, Clone, Copy)]
+struct Span {
+    start: usize,
+    end: usize,
+}
+
+/// Consecutive segments that share a section, joined into one text.
+struct Group<'a> {
+    chars: Vec<char>,
+    /// Offset of the group's first character in the whole extracted text.
+    offset: usize,
+    /// `(start, end, page)` of each segment, relative to the group.
+    pages: Vec<(usize, usize, Option<usize>)>,
+    first: &'a ChunkSegment,
+}
+
+/// Split segments into token-bounded chunks with overlap.
+///
+/// Segments are joined with [`SEGMENT_SEPARATOR`] to form the extracted text
+/// that `char_start`/`char_end` refer to. Chunks never span two sections, so
+/// every chunk carries a single section attribution. Within a section, chunks
+/// break at word boundaries, preferring paragraph ends and then sentence ends
+/// in the second half of the window. Words longer than a whole chunk are split.
+/// Each chunk after the first in a section repeats up to `overlap` tokens of
+/// the previous one, starting on a word boundary.
+pub fn chunk_segments(
+    source: &str,
+    segments: &[ChunkSegment],
+    options: &ChunkOptions,
+) -> Result<Vec<Chunk>, ChunkError> {
+    options.validate()?;
+
+    let mut chunks = Vec::new();
+    for group in group_segments(segments) {
+        for span in split_group(&group.chars, options) {
+            let text: String = group.chars[span.start..span.end].iter().collect();
+            let pages: Vec<usize> = group
+                .pages
+                .iter()
+                .filter(|(start, end, _)| *start < span.end && span.start < *end)
+                .filter_map(|(_, _, page)| *page)
+                .collect();
+            chunks.push(Chunk {
+                index: chunks.len(),
+                token_count: estimate_tokens(&text),
+                text,
+                source: source.to_string(),
+                section_id: group.first.section_id.clone(),
+                section: group.first.section_title.clone(),
+                page_start: pages.iter().min().copied(),
+                page_end: pages.iter().max().copied(),
+                char_start: group.offset + span.start,
+                char_end: group.offset + span.end,
+            });
+        }
+    }
+
+    Ok(chunks)
+}
+
+/// Join consecutive same-section segments, tracking global character offsets.
+fn group_segments(segments: &[ChunkSegment]) -> Vec<Group<'_>> {
+    let separator_len = SEGMENT_SEPARATOR.chars().count();
+    let mut groups: Vec<Group> = Vec::new();
+    let mut offset = 0;
+
+    for (i, segment) in segments.iter().enumerate() {
+        if i > 0 {
+            offset += separator_len;
+        }
+        let len = segment.text.chars().count();
+
+        let same_section = groups
+            .last()
+            .is_some_and(|g| g.first.section_id == segment.section_id);
+        if same_section {
+            let group = groups.last_mut().expect("checked above");
+            group.chars.extend(SEGMENT_SEPARATOR.chars());
+            let start = group.chars.len();
+            group.chars.extend(segment.text.chars());
+            group.pages.push((start, start + len, segment.page));
+        } else {
+            groups.push(Group {
+                chars: segment.text.chars().collect(),
+                offset,
+                pages: vec![(0, len, segment.page)],
+                first: segment,
+            });
+        }
+        offset += len;
+    }
+
+    groups
+}
+
+/// Split text into whitespace-separated words, breaking words that are
+/// longer than `max_chars` into `max_chars`-sized pieces.
+fn words(chars: &[char], max_chars: usize) -> Vec<Span> {
+    let mut words = Vec::new();
+    let mut i = 0;
+    while i < chars.len() {
+        if chars[i].is_whitespace() {
+            i += 1;
+            continue;
+        }
+        let start = i;
+        while i < chars.len() && !chars[i].is_whitespace() {
+            i += 1;
+        }
+        let mut piece = start;
+        while piece < i {
+            let end = (piece + max_chars).min(i);
+            words.push(Span { start: piece, end });
+            piece = end;
+        }
+    }
+    words
+}
+
+/// Compute chunk spans for one group of text.
+fn split_group(chars: &[char], options: &ChunkOptions) -> Vec<Span> {
+    let max_chars = options.max_chars();
+    let overlap_chars = options.overlap_chars();
+    let words = words(chars, max_chars);
+    let mut spans = Vec::new();
+
+    let mut first = 0;
+    while first < words.len() {
+        let start = words[first].start;
+
+        // Furthest word that still fits; the first word always fits because
+        // long words were pre-split.
+        let mut last = first;
+        while last + 1 < words.len() && words[last + 1].end - start <= max_chars {
+            last += 1;
+        }
+
+        if last + 1 < words.len() {
+            last = preferred_break(chars, &words, first, last, max_chars);
+        }
+
+        let end = words[last].end;
+        spans.push(Span { start, end });
+
+        if last + 1 >= words.len() {
+            break;
+        }
+
+        // Rewind to the earliest word that starts inside the overlap window,
+        // always making progress past the current chunk's first word.
+        let overlap_start = end.saturating_sub(overlap_chars);
+        let mut next = last + 1;
+        while next > first + 1 && words[next - 1].start >= overlap_start && overlap_chars > 0 {
+            next -= 1;
+        }
+        first = next;
+    }
+
+    spans
+}
+
+/// Pick the last word of a chunk, preferring a paragraph break and then a
+/// sentence end within the second half of the window.
+fn preferred_break(
+    chars: &[char],
+    words: &[Span],
+    first: usize,
+    last: usize,
+    max_chars: usize,
+) -> usize {
+    let start = words[first].start;
+    let min_end = start + max_chars / 2;
+    let candidates = || (first..last).rev().take_while(|&w| words[w].end >= min_end);
+
+    let paragraph = candidates().find(|&w| {
+        let gap = &chars[words[w].end..words[w + 1].start];
+        gap.iter().filter(|&&c| c == '\n').count() >= 2
+    });
+    if let Some(w) = paragraph {
+        return w;
+    }
+
+    let sentence = candidates().find(|&w| {
+        let gap_has_space = words[w].end < words[w + 1].start;
+        gap_has_space && matches!(chars[words[w].end - 1], '.' | '!' | '?')
+    });
+    sentence.unwrap_or(last)
+}
+
+/// Line-end break quality for [`split_for_streaming`], best first.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum BreakKind {
+    /// After a blank line outside fenced code
+    Paragraph,
+    /// After a line outside fenced code
+    Line,
+    /// After a line inside fenced code
+    CodeLine,
+}
+
+/// Split `text` into pieces of at most `max_chars` characters for streaming
+/// a large tool result in order.
+///
+/// Unlike [`chunk_segments`], pieces neither overlap nor normalize
+/// whitespace: concatenating them yields `text` exactly. Each piece ends at
+/// the best break in the second half of its window: a blank line outside
+/// fenced code, then a line end outside fenced code, a line end inside it,
+/// whitespace, and finally a hard cut at `max_chars`.
+pub fn split_for_streaming(text: &str, max_chars: usize) -> Vec<&str> {
+    let max_chars = max_chars.max(1);
+    // Byte offset of every char, plus the end of the text.
+    let offsets: Vec<usize> = text
+        .char_indices()
+        .map(|(i, _)| i)
+        .chain(std::iter::once(text.len()))
+        .collect();
+    let total = offsets.len() - 1;
+
+    // Line-end breaks, as (char position after the newline, kind).
+    let mut breaks = Vec::new();
+    let mut in_fence = false;
+    let mut line_start = 0;
+    let mut previous_blank = false;
+    for (pos, c) in text.chars().enumerate() {
+        if c != '\n' {
+            continue;
+        }
+        let line = &text[offsets[line_start]..offsets[pos]];
+        let trimmed = line.trim_start();
+        let fence = trimmed.starts_with("```") || trimmed.starts_with("~~~");
+        if fence {
+            in_fence = !in_fence;
+        }
+        let blank = line.trim().is_empty();
+        // An opening fence counts as code so a piece never ends right after it.
+        let kind = if in_fence {
+            BreakKind::CodeLine
+        } else if blank && !previous_blank {
+            BreakKind::Paragraph
+        } else {
+            BreakKind::Line
+        };
+        breaks.push((pos + 1, kind));
+        previous_blank = blank;
+        line_start = pos + 1;
+    }
+
+    let chars: Vec<char> = text.chars().collect();
+    let mut pieces = Vec::new();
+    let mut start = 0;
+    while total - start > max_chars {
+        let limit = start + max_chars;
+        let min_end = start + max_chars.div_ceil(2);
+        let line_break = breaks
+            .iter()
+            .filter(|&&(pos, _)| pos >= min_end && pos <= limit)
+            .min_by_key(|&&(pos, kind)| (kind, std::cmp::Reverse(pos)))
+            .map(|&(pos, _)| pos);
+        let end = line_break
+            .or_else(|| {
+                (min_end..=limit)
+                    .rev()
+                    .find(|&pos| chars[pos - 1].is_whitespace())
+            })
+            .unwrap_or(limit);
+        pieces.push(&text[offsets[start]..offsets[end]]);
+        start = end;
+    }
+    if start < total || pieces.is_empty() {
+        pieces.push(&text[offsets[start]..]);
+    }
+    pieces
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn seg(text: &str, section: Option<&str>, page: Option<usize>) -> ChunkSegment {
+        ChunkSegment {
+            text: text.to_string(),
+            section_id: section.map(|s| s.to_string()),
+            section_title: section.map(|s| s.to_uppercase()),
+            page,
+        }
+    }
+
+    fn opts(max_tokens: usize, overlap: usize) -> ChunkOptions {
+        ChunkOptions {
+            max_tokens,
+            overlap,
+        }
+    }
+
+    /// The text every chunk's offsets refer to.
+    fn joined(segments: &[ChunkSegment]) -> String {
+        segments
+            .iter()
+            .map(|s| s.text.as_str())
+            .collect::<Vec<_>>()
+            .join(SEGMENT_SEPARATOR)
+    }
+
+    fn slice(text: &str, start: usize, end: usize) -> String {
+        text.chars().skip(start).take(end - start).collect()
+    }
+
+    #[test]
+    fn test_validate_options() {
+        assert!(opts(512, 64).validate().is_ok());
+        assert_eq!(opts(0, 0).validate(), Err(ChunkError::ZeroMaxTokens));
+        assert_eq!(
+            opts(10, 10).validate(),
+            Err(ChunkError::OverlapTooLarge {
+                overlap: 10,
+                max_tokens: 10
+            })
+        );
+    }
+
+    #[test]
+    fn test_estimate_tokens() {
+        assert_eq!(estimate_tokens(""), 0);
+        assert_eq!(estimate_tokens("abcd"), 1);
+        assert_eq!(estimate_tokens("abcde"), 2);
+        // Counted in characters, not bytes.
+        assert_eq!(estimate_tokens("ññññ"), 1);
+    }
+
+    #[test]
+    fn test_empty_input() {
+        assert!(chunk_segments("doc", &[], &opts(10, 2)).unwrap().is_empty());
+        let blank = [seg("   ", None, None)];
+        assert!(chunk_segments("doc", &blank, &opts(10, 2))
+            .unwrap()
+            .is_empty());
+    }
+
+    #[test]
+    fn test_small_input_single_chunk() {
+        let segments = [seg("Hello world.", Some("s-1-0"), Some(1))];
+        let chunks = chunk_segments("a.pdf", &segments, &opts(100, 10)).unwrap();
+
+        assert_eq!(chunks.len(), 1);
+        let c = &chunks[0];
+        assert_eq!(c.index, 0);
+        assert_eq!(c.text, "Hello world.");
+        assert_eq!(c.source, "a.pdf");
+        assert_eq!(c.section_id.as_deref(), Some("s-1-0"));
+        assert_eq!(c.section.as_deref(), Some("S-1-0"));
+        assert_eq!((c.page_start, c.page_end), (Some(1), Some(1)));
+        assert_eq!((c.char_start, c.char_end), (0, 12));
+        assert_eq!(c.token_count, 3);
+    }
+
+    #[test]
+    fn test_chunks_respect_max_tokens() {
+        let text = "lorem ipsum dolor sit amet ".repeat(50);
+        let segments = [seg(&text, None, None)];
+        let options = opts(16, 4);
+        let chunks = chunk_segments("doc", &segments, &options).unwrap();
+
+        assert!(chunks.len() > 1);
+        for c in &chunks {
+            assert!(c.token_count <= options.max_tokens, "{:?}", c);
+            assert!(!c.text.starts_with(' ') && !c.text.ends_with(' '));
+        }
+    }
+
+    #[test]
+    fn test_offsets_match_extracted_text() {
+        let segments = [
+            seg("First paragraph has some words in it.", Some("a"), Some(1)),
+            seg("Second paragraph is here ñandú.", Some("a"), Some(2)),
+            seg("Another section starts now.", Some("b"), Some(3)),
+        ];
+        let full = joined(&segments);
+        let chunks = chunk_segments("doc", &segments, &opts(6, 2)).unwrap();
+
+        for c in &chunks {
+            assert_eq!(slice(&full, c.char_start, c.char_end), c.text);
+        }
+        assert_eq!(
+            chunks.last().unwrap().char_end,
+            full.chars().count(),
+            "last chunk must reach the end of the text"
+        );
+    }
+
+    #[test]
+    fn test_overlap_repeats_previous_words() {
+        let text = (0..40).map(|i| format!("w{:02}", i)).collect::<Vec<_>>();
+        let segments = [seg(&text.join(" "), None, None)];
+        let chunks = chunk_segments("doc", &segments, &opts(6, 2)).unwrap();
+
+        for pair in chunks.windows(2) {
+            assert!(pair[1].char_start < pair[0].char_end, "{:?}", pair);
+            assert!(pair[1].char_start > pair[0].char_start);
+            let shared = slice(
+                &pair[0].text,
+                pair[1].char_start - pair[0].char_start,
+                pair[0].text.chars().count(),
+            );
+            assert!(pair[1].text.starts_with(&shared));
+        }
+    }
+
+    #[test]
+    fn test_zero_overlap_is_contiguous() {
+        let text = "alpha beta gamma delta epsilon zeta eta theta iota kappa";
+        let segments = [seg(text, None, None)];
+        let chunks = chunk_segments("doc", &segments, &opts(3, 0)).unwrap();
+
+        assert!(chunks.len() > 1);
+        for pair in chunks.windows(2) {
+            assert!(pair[1].char_start >= pair[0].char_end);
+        }
+        let words: Vec<&str> = chunks
+            .iter()
+            .flat_map(|c| c.text.split_whitespace())
+            .collect();
+        assert_eq!(words, text.split_whitespace().collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_every_word_is_covered() {
+        let text = "one two three four five six seven eight nine ten eleven twelve";
+        let segments = [seg(text, None, None)];
+        let chunks = chunk_segments("doc", &segments, &opts(4, 2)).unwrap();
+
+        for word in text.split_whitespace() {
+            assert!(
+                chunks
+                    .iter()
+                    .any(|c| c.text.split_whitespace().any(|w| w == word)),
+                "missing {word}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_long_word_is_split() {
+        let word = "x".repeat(30);
+        let segments = [seg(&word, None, None)];
+        let chunks = chunk_segments("doc", &segments, &opts(2, 0)).unwrap();
+
+        assert_eq!(chunks.len(), 4);
+        assert!(chunks.iter().all(|c| c.text.chars().count() <= 8));
+        assert_eq!(
+            chunks.iter().map(|c| c.text.as_str()).collect::<String>(),
+            word
+        );
+    }
+
+    #[test]
+    fn test_long_word_with_overlap_makes_progress() {
+        let segments = [seg(&"y".repeat(100), None, None)];
+        let chunks = chunk_segments("doc", &segments, &opts(5, 4)).unwrap();
+        assert_eq!(chunks.last().unwrap().char_end, 100);
+    }
+
+    #[test]
+    fn test_chunks_do_not_span_sections() {
+        let segments = [
+            seg("Intro text.", Some("intro"), Some(1)),
+            seg("Body text.", Some("body"), Some(2)),
+        ];
+        let chunks = chunk_segments("doc", &segments, &opts(100, 10)).unwrap();
+
+        assert_eq!(chunks.len(), 2);
+        assert_eq!(chunks[0].section_id.as_deref(), Some("intro"));
+        assert_eq!(chunks[1].section_id.as_deref(), Some("body"));
+        // "Intro text." (11) + separator (2)
+        assert_eq!(chunks[1].char_start, 13);
+        assert_eq!(chunks[1].index, 1);
+    }
+
+    #[test]
+    fn test_page_range_spans_segments() {
+        let segments = [
+            seg("Page one text.", Some("s"), Some(1)),
+            seg("Page two text.", Some("s"), Some(2)),
+            seg("Page three text.", Some("s"), Some(3)),
+        ];
+        let chunks = chunk_segments("doc", &segments, &opts(100, 0)).unwrap();
+
+        assert_eq!(chunks.len(), 1);
+        assert_eq!(chunks[0].page_start, Some(1));
+        assert_eq!(chunks[0].page_end, Some(3));
+        assert_eq!(chunks[0].text, joined(&segments));
+    }
+
+    #[test]
+    fn test_no_pages_for_unpaged_sources() {
+        let segments = [seg("Web content.", None, None)];
+        let chunks = chunk_segments("https://x.io", &segments, &opts(10, 0)).unwrap();
+        assert_eq!(chunks[0].page_start, None);
+        assert_eq!(chunks[0].page_end, None);
+    }
+
+    #[test]
+    fn test_prefers_paragraph_break() {
+        let segments = [
+            seg("aaaa bbbb cccc dddd", None, None),
+            seg("eeee ffff gggg hhhh", None, None),
+        ];
+        // 9 tokens = 36 chars: the window reaches into the second paragraph,
+        // but the paragraph end is in the second half.
+        let chunks = chunk_segments("doc", &segments, &opts(9, 0)).unwrap();
+
+        assert_eq!(chunks[0].text, "aaaa bbbb cccc dddd");
+        assert_eq!(chunks[1].text, "eeee ffff gggg hhhh");
+    }
+
+    #[test]
+    fn test_prefers_sentence_break() {
+        let text = "One two three four five. Six seven eight nine ten eleven";
+        let segments = [seg(text, None, None)];
+        let chunks = chunk_segments("doc", &segments, &opts(10, 0)).unwrap();
+
+        assert_eq!(chunks[0].text, "One two three four five.");
+    }
+
+    #[test]
+    fn test_ignores_break_in_first_half() {
+        let text = "Hi. aaaa bbbb cccc dddd eeee ffff gggg hhhh iiii";
+        let segments = [seg(text, None, None)];
+        let chunks = chunk_segments("doc", &segments, &opts(5, 0)).unwrap();
+
+        // "Hi." ends well before half of the 20-char window, so the chunk
+        // fills up to the word limit instead of stopping there.
+        assert_eq!(chunks[0].text, "Hi. aaaa bbbb cccc");
+    }
+
+    #[test]
+    fn test_deterministic() {
+        let text = "The quick brown fox jumps over the lazy dog. ".repeat(20);
+        let segments = [seg(&text, Some("s"), Some(4))];
+        let a = chunk_segments("doc", &segments, &opts(12, 3)).unwrap();
+        let b = chunk_segments("doc", &segments, &opts(12, 3)).unwrap();
+        assert_eq!(a, b);
+    }
+
+    #[test]
+    fn test_chunk_serialization_skips_missing_fields() {
+        let segments = [seg("Some text.", None, None)];
+        let chunks = chunk_segments("https://x.io", &segments, &opts(10, 0)).unwrap();
+        let json = serde_json::to_string(&chunks[0]).unwrap();
+
+        assert!(json.contains("\"source\":\"https://x.io\""));
+        assert!(json.contains("\"char_start\":0"));
+        assert!(!json.contains("page_start"));
+        assert!(!json.contains("section"));
+    }
+
+    #[test]
+    fn test_markdown_segments_tracks_headings() {
+        let md = "Preamble.\n\n# Getting Started\n\nInstall it.\n\n## Next Steps!\nRun it.\n";
+        let segments = markdown_segments(md);
+
+        assert_eq!(segments.len(), 4);
+        assert_eq!(segments[0].text, "Preamble.");
+        assert_eq!(segments[0].section_id, None);
+        assert_eq!(segments[1].text, "# Getting Started");
+        assert_eq!(segments[1].section_id.as_deref(), Some("getting-started"));
+        assert_eq!(
+            segments[2].section_title.as_deref(),
+            Some("Getting Started")
+        );
+        assert_eq!(segments[3].text, "## Next Steps!\nRun it.");
+        assert_eq!(segments[3].section_id.as_deref(), Some("next-steps"));
+    }
+
+    #[test]
+    fn test_markdown_segments_keeps_code_fences_whole() {
+        let md = "Before.\n\n```rust\nfn a() {}\n\n# not a heading\nfn b() {}\n```\n\nAfter.";
+        let segments = markdown_segments(md);
+
+        assert_eq!(segments.len(), 3);
+        assert!(segments[1].text.contains("# not a heading"));
+        assert!(segments.iter().all(|s| s.section_id.is_none()));
+    }
+
+    #[test]
+    fn test_split_for_streaming_is_lossless_and_prefers_paragraphs() {
+        let text = "# Title\n\nFirst paragraph here.\nSecond line.\n\nTail text é";
+        let pieces = split_for_streaming(text, 45);
+        assert_eq!(pieces.concat(), text);
+        assert!(pieces.iter().all(|p| p.chars().count() <= 45));
+        assert_eq!(
+            pieces[0],
+            "# Title\n\nFirst paragraph here.\nSecond line.\n\n"
+        );
+
+        // A break before a code fence beats one inside it.
+        let code = "para one\n\n```\nlet a = 1;\nlet b = 2;\n```\nafter";
+        let pieces = split_for_streaming(code, 20);
+        assert_eq!(pieces.concat(), code);
+        assert_eq!(pieces[0], "para one\n\n");
+
+        // No whitespace at all: hard cuts on char boundaries.
+        let pieces = split_for_streaming("ééééé", 2);
+        assert_eq!(pieces, vec!["éé", "éé", "é"]);
+        assert_eq!(split_for_streaming("", 10), vec![""]);
+    }
+
+    #[test]
+    fn test_heading_title() {
+        assert_eq!(heading_title("# Title"), Some("Title".to_string()));
+        assert_eq!(heading_title("### Closed ###"), Some("Closed".to_string()));
+        assert_eq!(heading_title("#hashtag"), None);
+        assert_eq!(heading_title("####### seven"), None);
+        assert_eq!(heading_title("#"), None);
+    }
+}
@@ -0,0 +1,306 @@
+//! Checksums and text encodings
+//!
+//! Pure implementations behind `mcptools hash`, `mcptools encode`, and the
+//! matching MCP tools.
+
+use base64::alphabet;
+use base64::engine::general_purpose::{GeneralPurpose, GeneralPurposeConfig};
+use base64::engine::DecodePaddingMode;
+use base64::Engine;
+use serde::Serialize;
+use sha2::{Digest, Sha256};
+
+/// Padded on encode; padding optional on decode.
+const BASE64: GeneralPurpose = GeneralPurpose::new(
+    &alphabet::STANDARD,
+    GeneralPurposeConfig::new().with_decode_padding_mode(DecodePaddingMode::Indifferent),
+);
+
+/// Unpadded on encode; padding optional on decode.
+const BASE64_URL: GeneralPurpose = GeneralPurpose::new(
+    &alphabet::URL_SAFE,
+    GeneralPurposeConfig::new()
+        .with_encode_padding(false)
+        .with_decode_padding_mode(DecodePaddingMode::Indifferent),
+);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HashAlgorithm {
+    Sha256,
+    Md5,
+}
+
+impl HashAlgorithm {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            HashAlgorithm::Sha256 => "sha256",
+            HashAlgorithm::Md5 => "md5",
+        }
+    }
+}
+
+impl std::str::FromStr for HashAlgorithm {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().replace('-', "").as_str() {
+            "sha256" => Ok(HashAlgorithm::Sha256),
+            "md5" => Ok(HashAlgorithm::Md5),
+            _ => Err(format!(
+                "Unsupported hash algorithm '{}' (expected sha256 or md5)",
+                s
+            )),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Encoding {
+    /// Standard alphabet, padded
+    Base64,
+    /// URL-safe alphabet, unpadded
+    Base64Url,
+    /// Percent-encoding of everything outside the RFC 3986 unreserved set
+    Url,
+    /// Lowercase hexadecimal
+    Hex,
+}
+
+impl Encoding {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Encoding::Base64 => "base64",
+            Encoding::Base64Url => "base64url",
+            Encoding::Url => "url",
+            Encoding::Hex => "hex",
+        }
+    }
+}
+
+impl std::str::FromStr for Encoding {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().replace(['-', '_'], "").as_str() {
+            "base64" => Ok(Encoding::Base64),
+            "base64url" => Ok(Encoding::Base64Url),
+            "url" | "percent" => Ok(Encoding::Url),
+            "hex" => Ok(Encoding::Hex),
+            _ => Err(format!(
+                "Unsupported encoding '{}' (expected base64, base64url, url, or hex)",
+                s
+            )),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct HashOutput {
+    pub algorithm: String,
+    /// Lowercase hex digest
+    pub digest: String,
+    /// Number of input bytes hashed
+    pub bytes: usize,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct EncodeOutput {
+    pub encoding: String,
+    /// `encode` or `decode`
+    pub operation: String,
+    pub output: String,
+}
+
+/// Hash `data` with `algorithm`.
+pub fn hash(data: &[u8], algorithm: HashAlgorithm) -> HashOutput {
+    let digest = match algorithm {
+        HashAlgorithm::Sha256 => hex_encode(&Sha256::digest(data)),
+        HashAlgorithm::Md5 => format!("{:x}", md5::compute(data)),
+    };
+    HashOutput {
+        algorithm: algorithm.as_str().to_string(),
+        digest,
+        bytes: data.len(),
+    }
+}
+
+fn hex_encode(data: &[u8]) -> String {
+    data.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+fn hex_decode(text: &str) -> Result<Vec<u8>, String> {
+    let digits: Vec<u8> = text.bytes().filter(|b| !b.is_ascii_whitespace()).collect();
+    if !digits.len().is_multiple_of(2) {
+        return Err("Invalid hex: odd number of digits".to_string());
+    }
+    digits
+        .chunks(2)
+        .map(|pair| {
+            let pair = std::str::from_utf8(pair).map_err(|_| "Invalid hex digit".to_string())?;
+            u8::from_str_radix(pair, 16).map_err(|_| format!("Invalid hex digits '{}'", pair))
+        })
+        .collect()
+}
+
+fn url_encode(data: &[u8]) -> String {
+    let mut out = String::with_capacity(data.len());
+    for &b in data {
+        if b.is_ascii_alphanumeric() || matches!(b, b'-' | b'_' | b'.' | b'~') {
+            out.push(b as char);
+        } else {
+            out.push_str(&format!("%{:02X}", b));
+        }
+    }
+    out
+}
+
+/// Decode `%XX` escapes; `+` is kept literal since it only means a space in
+/// form bodies.
+fn url_decode(text: &str) -> Result<Vec<u8>, String> {
+    let bytes = text.as_bytes();
+    let mut out = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        if bytes[i] == b'%' {
+            let escape = text.get(i + 1..i + 3).unwrap_or_default();
+            let value = Some(escape)
+                .filter(|e| e.len() == 2 && e.bytes().all(|b| b.is_ascii_hexdigit()))
+                .and_then(|e| u8::from_str_radix(e, 16).ok())
+                .ok_or_else(|| format!("Invalid percent escape at byte {}", i))?;
+            out.push(value);
+            i += 3;
+        } else {
+            out.push(bytes[i]);
+            i += 1;
+        }
+    }
+    Ok(out)
+}
+
+/// Encode `data` as text.
+pub fn encode(data: &[u8], encoding: Encoding) -> String {
+    match encoding {
+        Encoding::Base64 => BASE64.encode(data),
+        Encoding::Base64Url => BASE64_URL.encode(data),
+        Encoding::Url => url_encode(data),
+        Encoding::Hex => hex_encode(data),
+    }
+}
+
+/// Decode `text` to bytes. Whitespace is ignored for base64 and hex, so
+/// wrapped input decodes as-is.
+pub fn decode(text: &str, encoding: Encoding) -> Result<Vec<u8>, String> {
+    let compact = || -> String { text.chars().filter(|c| !c.is_whitespace()).collect() };
+    match encoding {
+        Encoding::Base64 => BASE64
+            .decode(compact())
+            .map_err(|e| format!("Invalid base64: {}", e)),
+        Encoding::Base64Url => BASE64_URL
+            .decode(compact())
+            .map_err(|e| format!("Invalid base64url: {}", e)),
+        Encoding::Url => url_decode(text),
+        Encoding::Hex => hex_decode(text),
+    }
+}
+
+/// Encode or decode text, for callers that need a string result. Decoded
+/// bytes must be UTF-8.
+pub fn transcode(
+    text: &str,
+    encoding: Encoding,
+    decode_input: bool,
+) -> Result<EncodeOutput, String> {
+    let output = if decode_input {
+        String::from_utf8(decode(text, encoding)?).map_err(|e| {
+            format!(
+                "Decoded {} bytes that are not valid UTF-8",
+                e.as_bytes().len()
+            )
+        })?
+    } else {
+        encode(text.as_bytes(), encoding)
+    };
+    Ok(EncodeOutput {
+        encoding: encoding.as_str().to_string(),
+        operation: if decode_input { "decode" } else { "encode" }.to_string(),
+        output,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hash_known_digests() {
+        assert_eq!(
+            hash(b"abc", HashAlgorithm::Sha256).digest,
+            "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+        );
+        let md5 = hash(b"", HashAlgorithm::Md5);
+        assert_eq!(md5.digest, "d41d8cd98f00b204e9800998ecf8427e");
+        assert_eq!(md5.bytes, 0);
+    }
+
+    #[test]
+    fn test_parse_names() {
+        assert_eq!("SHA-256".parse(), Ok(HashAlgorithm::Sha256));
+        assert!("sha1".parse::<HashAlgorithm>().is_err());
+        assert_eq!("base64_url".parse(), Ok(Encoding::Base64Url));
+        assert!("rot13".parse::<Encoding>().is_err());
+    }
+
+    #[test]
+    fn test_roundtrip_all_encodings() {
+        let data = "héllo wörld/?&=+~\u{0}\u{ff}".as_bytes();
+        for encoding in [
+            Encoding::Base64,
+            Encoding::Base64Url,
+            Encoding::Url,
+            Encoding::Hex,
+        ] {
+            let encoded = encode(data, encoding);
+            assert_eq!(decode(&encoded, encoding).unwrap(), data, "{:?}", encoding);
+        }
+    }
+
+    #[test]
+    fn test_encode_examples() {
+        assert_eq!(encode(b"hi?", Encoding::Base64), "aGk/");
+        assert_eq!(encode(b"hi?", Encoding::Base64Url), "aGk_");
+        assert_eq!(encode(b"a b&c", Encoding::Url), "a%20b%26c");
+        assert_eq!(encode(&[0, 171], Encoding::Hex), "00ab");
+    }
+
+    #[test]
+    fn test_decode_is_lenient_about_padding_and_whitespace() {
+        assert_eq!(decode("aGVs\nbG8=", Encoding::Base64).unwrap(), b"hello");
+        assert_eq!(decode("aGVsbG8", Encoding::Base64).unwrap(), b"hello");
+        assert_eq!(decode("DE AD", Encoding::Hex).unwrap(), vec![0xde, 0xad]);
+        assert_eq!(decode("a+b%2B", Encoding::Url).unwrap(), b"a+b+");
+    }
+
+    #[test]
+    fn test_decode_errors() {
+        assert!(decode("abc", Encoding::Hex).is_err());
+        assert!(decode("zz", Encoding::Hex).is_err());
+        assert!(decode("100%", Encoding::Url).is_err());
+        assert!(decode("%g1", Encoding::Url).is_err());
+        assert!(decode("%+1", Encoding::Url).is_err());
+        assert!(decode("!!!", Encoding::Base64).is_err());
+    }
+
+    #[test]
+    fn test_transcode() {
+        let output = transcode("aGk=", Encoding::Base64, true).unwrap();
+        assert_eq!(
+            (output.operation.as_str(), output.output.as_str()),
+            ("decode", "hi")
+        );
+        assert!(transcode("ff", Encoding::Hex, true).is_err());
+        assert_eq!(
+            transcode("hi", Encoding::Hex, false).unwrap().output,
+            "6869"
+        );
+    }
+}
@@ -0,0 +1,244 @@
+//! Link discovery and page naming for `md crawl`
+//!
+//! The crawler itself (browser, queue, file writes) lives in the shell; this
+//! module decides which links a converted page contributes, whether they are
+//! in scope, and where each page is written.
+
+use regex::Regex;
+use serde::Serialize;
+use std::sync::LazyLock;
+use url::Url;
+
+/// Markdown links and autolinks. Group 1 is `!` for images, which are skipped.
+static LINK_RE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(concat!(
+        r#"(!?)\[[^\]]*\]\(\s*<?([^)\s>]+)>?(?:\s+"[^"]*")?\s*\)|<(https?://[^>\s]+)>"#,
+        // Reference definitions, as written by `--md-links reference`
+        r#"|(?m:^\[[^\]]+\]:\s*<?([^\s>]+)>?)"#,
+    ))
+    .expect("valid link regex")
+});
+
+/// Extensions of linked files that are not web pages.
+const SKIPPED_EXTENSIONS: &[&str] = &[
+    "7z", "avi", "bmp", "css", "csv", "dmg", "doc", "docx", "exe", "gif", "gz", "ico", "jpeg",
+    "jpg", "js", "json", "mov", "mp3", "mp4", "pdf", "png", "ppt", "pptx", "rar", "svg", "tar",
+    "tgz", "wav", "webm", "webp", "woff", "woff2", "xls", "xlsx", "xml", "zip",
+];
+
+/// A page written by the crawler.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct CrawlPage {
+    pub url: String,
+    pub depth: usize,
+    /// Path relative to the output directory
+    pub file: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    pub links_found: usize,
+}
+
+/// A page that could not be fetched.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct CrawlError {
+    pub url: String,
+    pub depth: usize,
+    pub error: String,
+}
+
+/// Summary written to `crawl.json` in the output directory.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct CrawlManifest {
+    pub root: String,
+    pub max_depth: usize,
+    pub same_origin: bool,
+    pub pages: Vec<CrawlPage>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub errors: Vec<CrawlError>,
+    /// True when `--max-pages` stopped the crawl with links left to visit
+    pub truncated: bool,
+}
+
+/// Parse and normalize a crawl URL: http(s) only, fragment removed.
+pub fn normalize_url(value: &str) -> Result<Url, String> {
+    let mut url = Url::parse(value).map_err(|e| format!("Invalid URL '{}': {}", value, e))?;
+    if !matches!(url.scheme(), "http" | "https") {
+        return Err(format!("Unsupported URL scheme in '{}'", value));
+    }
+    url.set_fragment(None);
+    Ok(url)
+}
+
+/// Whether two URLs share scheme, host, and port.
+pub fn same_origin(a: &Url, b: &Url) -> bool {
+    a.origin() == b.origin()
+}
+
+fn is_skipped_file(url: &Url) -> bool {
+    url.path_segments()
+        .and_then(|mut segments| segments.next_back())
+        .and_then(|last| last.rsplit_once('.'))
+        .is_some_and(|(_, ext)| SKIPPED_EXTENSIONS.contains(&ext.to_lowercase().as_str()))
+}
+
+/// Links in converted Markdown, resolved against `base`, normalized, and
+/// deduplicated in document order. Images, non-http(s) links, and links to
+/// common non-page files are skipped.
+pub fn extract_links(markdown: &str, base: &Url) -> Vec<String> {
+    let mut links: Vec<String> = Vec::new();
+    for captures in LINK_RE.captures_iter(markdown) {
+        if captures.get(1).is_some_and(|m| m.as_str() == "!") {
+            continue;
+        }
+        let Some(target) = captures
+            .get(2)
+            .or_else(|| captures.get(3))
+            .or_else(|| captures.get(4))
+        else {
+            continue;
+        };
+        let Ok(mut url) = base.join(target.as_str()) else {
+            continue;
+        };
+        if !matches!(url.scheme(), "http" | "https") || is_skipped_file(&url) {
+            continue;
+        }
+        url.set_fragment(None);
+        let url = url.to_string();
+        if !links.contains(&url) {
+            links.push(url);
+        }
+    }
+    links
+}
+
+fn sanitize_segment(segment: &str) -> String {
+    let cleaned: String = segment
+        .chars()
+        .map(|c| {
+            if c.is_alphanumeric() || matches!(c, '-' | '_' | '.') {
+                c
+            } else {
+                '_'
+            }
+        })
+        .collect();
+    match cleaned.trim_matches('.') {
+        "" => "_".to_string(),
+        s => s.to_string(),
+    }
+}
+
+/// Output path for a page, relative to the output directory:
+/// `host/path/segments.md`, with `index.md` for directory URLs and a short
+/// hash suffix distinguishing query strings.
+pub fn page_file_path(url: &Url) -> String {
+    let mut host = sanitize_segment(url.host_str().unwrap_or("unknown"));
+    if let Some(port) = url.port() {
+        host.push_str(&format!("_{}", port));
+    }
+
+    let mut segments: Vec<String> = url
+        .path_segments()
+        .map(|s| s.filter(|s| !s.is_empty()).map(sanitize_segment).collect())
+        .unwrap_or_default();
+    if url.path().ends_with('/') || segments.is_empty() {
+        segments.push("index".to_string());
+    }
+
+    let mut last = segments.pop().unwrap_or_else(|| "index".to_string());
+    for ext in [".html", ".htm", ".php", ".aspx", ".md"] {
+        if let Some(stem) = last.strip_suffix(ext).filter(|s| !s.is_empty()) {
+            last = stem.to_string();
+            break;
+        }
+    }
+    if let Some(query) = url.query() {
+        let digest = format!("{:x}", md5::compute(query));
+        last.push_str(&format!("__{}", &digest[..8]));
+    }
+    segments.push(format!("{}.md", last));
+
+    std::iter::once(host)
+        .chain(segments)
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn url(s: &str) -> Url {
+        Url::parse(s).unwrap()
+    }
+
+    #[test]
+    fn test_normalize_url() {
+        assert_eq!(
+            normalize_url("https://example.com/docs#intro")
+                .unwrap()
+                .as_str(),
+            "https://example.com/docs"
+        );
+        assert!(normalize_url("ftp://example.com").is_err());
+        assert!(normalize_url("not a url").is_err());
+    }
+
+    #[test]
+    fn test_extract_links() {
+        let markdown = "See [intro](intro.html#top), [api](/api/ \"API\"), \
+            ![logo](logo.png), [mail](mailto:a@b.c), [pdf](guide.pdf), \
+            <https://other.example/x>, and [intro again](intro.html).";
+        let links = extract_links(markdown, &url("https://example.com/docs/"));
+
+        assert_eq!(
+            links,
+            vec![
+                "https://example.com/docs/intro.html",
+                "https://example.com/api/",
+                "https://other.example/x",
+            ]
+        );
+
+        let markdown = "See [guide][1] and ![logo][2].\n\n[1]: /guide\n[2]: logo.png\n";
+        assert_eq!(
+            extract_links(markdown, &url("https://example.com/docs/")),
+            vec!["https://example.com/guide"]
+        );
+    }
+
+    #[test]
+    fn test_same_origin() {
+        let root = url("https://example.com/docs");
+        assert!(same_origin(&root, &url("https://example.com/blog")));
+        assert!(!same_origin(&root, &url("http://example.com/docs")));
+        assert!(!same_origin(&root, &url("https://sub.example.com/")));
+    }
+
+    #[test]
+    fn test_page_file_path() {
+        assert_eq!(
+            page_file_path(&url("https://example.com")),
+            "example.com/index.md"
+        );
+        assert_eq!(
+            page_file_path(&url("https://example.com/docs/")),
+            "example.com/docs/index.md"
+        );
+        assert_eq!(
+            page_file_path(&url("https://example.com/docs/intro.html")),
+            "example.com/docs/intro.md"
+        );
+        assert_eq!(
+            page_file_path(&url("http://localhost:8080/a b/..")),
+            "localhost_8080/index.md"
+        );
+        let with_query = page_file_path(&url("https://example.com/search?q=rust"));
+        assert!(with_query.starts_with("example.com/search__"));
+        assert_ne!(
+            with_query,
+            page_file_path(&url("https://example.com/search?q=go"))
+        );
+    }
+}
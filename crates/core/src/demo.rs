@@ -0,0 +1,412 @@
+//! Scenarios, fixtures, and transcript formatting for the `demo` command.
+//!
+//! Each scenario is a scripted multi-tool workflow. The fixtures let every
+//! scenario run offline; the formatting helpers keep the printed transcript
+//! readable when tool results are large.
+
+use serde_json::Value;
+
+/// A scripted workflow run by `mcptools demo`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Scenario {
+    /// Outline a documentation page, read one section, and summarize it
+    SummarizePage,
+    /// Inspect a PDF, walk its outline, and summarize its first section
+    SummarizePdf,
+    /// Read a Jira ticket, pull out error signals, and draft a triage note
+    TriageTicket,
+}
+
+impl Scenario {
+    pub const ALL: [Scenario; 3] = [
+        Scenario::SummarizePage,
+        Scenario::SummarizePdf,
+        Scenario::TriageTicket,
+    ];
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Scenario::SummarizePage => "summarize-page",
+            Scenario::SummarizePdf => "summarize-pdf",
+            Scenario::TriageTicket => "triage-ticket",
+        }
+    }
+
+    pub fn description(self) -> &'static str {
+        match self {
+            Scenario::SummarizePage => {
+                "md_toc -> json_query -> md_fetch -> regex_extract -> render_template"
+            }
+            Scenario::SummarizePdf => {
+                "pdf_info -> pdf_toc -> json_query -> pdf_read -> render_template"
+            }
+            Scenario::TriageTicket => "jira_get -> json_query -> regex_extract -> render_template",
+        }
+    }
+
+    /// What `--input` means for this scenario in live mode.
+    pub fn live_input(self) -> &'static str {
+        match self {
+            Scenario::SummarizePage => "URL of the page to summarize",
+            Scenario::SummarizePdf => "path to the PDF to summarize",
+            Scenario::TriageTicket => "Jira issue key (read-only; nothing is posted)",
+        }
+    }
+}
+
+/// Documentation page used by `summarize-page` without `--live`.
+pub const FIXTURE_PAGE_HTML: &str = r#"<!DOCTYPE html>
+<html>
+<head><title>Widget CLI Guide</title></head>
+<body>
+<nav><a href="/">Home</a> | <a href="/docs">Docs</a></nav>
+<main>
+<h1>Widget CLI Guide</h1>
+<p>The widget CLI builds, tests, and ships widgets from your terminal.</p>
+<h2>Installation</h2>
+<p>Install the binary with <code>cargo install widget-cli</code>, or download a release from <a href="https://example.com/widget/releases">the releases page</a>.</p>
+<h2>Configuration</h2>
+<p>Settings are read from <code>widget.toml</code> in the project root. Every key can be overridden by an environment variable named <code>WIDGET_&lt;KEY&gt;</code>.</p>
+<h3>Environment</h3>
+<p>Set <code>WIDGET_TOKEN</code> to publish. See <a href="https://example.com/widget/tokens">creating tokens</a> and <a href="https://example.com/widget/scopes">token scopes</a>.</p>
+<h2>Troubleshooting</h2>
+<p>Run with <code>--verbose</code> and check <a href="https://example.com/widget/faq">the FAQ</a> before filing an issue.</p>
+</main>
+<footer>Copyright Widget Co.</footer>
+</body>
+</html>
+"#;
+
+/// Ticket used by `triage-ticket` without `--live`, shaped like `jira_get`
+/// output.
+pub const FIXTURE_TICKET_JSON: &str = r#"{
+  "key": "DEMO-42",
+  "summary": "CSV export fails with 500 for workspaces over 10k rows",
+  "description": "Since upgrading to v2.14.0, exporting large workspaces fails.\n\nSteps:\n1. Open a workspace with more than 10,000 rows\n2. Click Export > CSV\n\nServer log:\nERROR export::csv: row buffer exceeded limit (10240)\nCaused by: allocation of 64 MiB refused\n\nv2.13.2 exports the same workspace fine.",
+  "status": "Open",
+  "priority": "High",
+  "issue_type": "Bug",
+  "assignee": null,
+  "created": "2024-05-02T09:14:00.000+0000",
+  "updated": "2024-05-03T16:40:00.000+0000",
+  "due_date": null,
+  "labels": ["export", "regression"],
+  "components": ["api"],
+  "comments": [
+    {
+      "id": "10001",
+      "body": {"type": "doc", "version": 1, "content": [{"type": "paragraph", "content": [{"type": "text", "text": "Same here on a 12k row workspace."}]}]},
+      "created": "2024-05-02T11:02:00.000+0000",
+      "author": {"displayName": "Sam Rivera"}
+    },
+    {
+      "id": "10002",
+      "body": {"type": "doc", "version": 1, "content": [{"type": "paragraph", "content": [{"type": "text", "text": "Smaller workspaces are unaffected."}]}]},
+      "created": "2024-05-03T16:40:00.000+0000",
+      "author": {"displayName": "Alex Chen"}
+    }
+  ],
+  "attachments": []
+}
+"#;
+
+/// Heading and body lines for each page of the `summarize-pdf` fixture.
+pub const FIXTURE_PDF_PAGES: &[(&str, &[&str])] = &[
+    (
+        "Quarterly Operations Report",
+        &[
+            "This report covers service reliability for the second quarter.",
+            "Availability held at 99.95 percent across all regions.",
+            "Two incidents exceeded the 30 minute response target.",
+        ],
+    ),
+    (
+        "Incident Review",
+        &[
+            "The longest incident lasted 47 minutes and affected exports.",
+            "Root cause was an undersized row buffer introduced in v2.14.0.",
+            "The fix shipped in v2.14.1 along with a regression test.",
+        ],
+    ),
+];
+
+/// Template for the `summarize-page` summary step.
+pub const PAGE_SUMMARY_TEMPLATE: &str = "# {{title}}
+
+Sections:
+{{#each sections}}
+- {{text}} ({{char_limit}} chars at offset {{char_offset}})
+{{/each}}
+
+Read \"{{section.text}}\": {{excerpt}}
+
+Links in that section:
+{{#each links}}
+- {{this}}
+{{else}}
+- none
+{{/each}}
+";
+
+/// Template for the `summarize-pdf` summary step.
+pub const PDF_SUMMARY_TEMPLATE: &str = "# {{info.title}}
+
+{{info.page_count}} page(s){{#if info.author}} by {{info.author}}{{/if}}.
+
+Outline:
+{{#each sections}}
+- {{this}}
+{{/each}}
+
+First section: {{excerpt}}
+";
+
+/// Template for the `triage-ticket` draft note.
+pub const TRIAGE_TEMPLATE: &str = "## Triage: {{ticket.key}}
+
+{{ticket.summary}}
+
+- Status: {{ticket.status}}, priority {{ticket.priority}}
+- Labels: {{#each ticket.labels}}{{this}}{{#unless @last}}, {{/unless}}{{/each}}
+- Discussion: {{ticket.comments}} comment(s)
+
+Signals from the description:
+{{#each signals}}
+- `{{this}}`
+{{else}}
+- no error lines found
+{{/each}}
+
+Versions mentioned: {{#each versions}}{{this}}{{#unless @last}}, {{/unless}}{{else}}none{{/each}}
+";
+
+fn escape_pdf_string(text: &str) -> String {
+    text.replace('\\', "\\\\")
+        .replace('(', "\\(")
+        .replace(')', "\\)")
+}
+
+/// Build a minimal PDF with one page per `(heading, lines)` entry, using the
+/// standard Helvetica fonts so no font data is embedded.
+pub fn build_text_pdf(title: &str, pages: &[(&str, &[&str])]) -> Vec<u8> {
+    // Objects 1-4 are fixed; each page adds a page object and its content.
+    let page_ids: Vec<usize> = (0..pages.len()).map(|i| 5 + i * 2).collect();
+    let mut objects = vec![
+        "<< /Type /Catalog /Pages 2 0 R >>".to_string(),
+        format!(
+            "<< /Type /Pages /Kids [{}] /Count {} >>",
+            page_ids
+                .iter()
+                .map(|id| format!("{} 0 R", id))
+                .collect::<Vec<_>>()
+                .join(" "),
+            pages.len()
+        ),
+        "<< /Type /Font /Subtype /Type1 /BaseFont /Helvetica /Encoding /WinAnsiEncoding >>"
+            .to_string(),
+        "<< /Type /Font /Subtype /Type1 /BaseFont /Helvetica-Bold /Encoding /WinAnsiEncoding >>"
+            .to_string(),
+    ];
+    for (index, (heading, lines)) in pages.iter().enumerate() {
+        let mut stream = format!(
+            "BT /F2 20 Tf 72 720 Td ({}) Tj ET\nBT /F1 12 Tf 16 TL 72 680 Td",
+            escape_pdf_string(heading)
+        );
+        for line in lines.iter() {
+            stream.push_str(&format!(" ({}) Tj T*", escape_pdf_string(line)));
+        }
+        stream.push_str(" ET");
+        objects.push(format!(
+            "<< /Type /Page /Parent 2 0 R /MediaBox [0 0 612 792] \
+             /Resources << /Font << /F1 3 0 R /F2 4 0 R >> >> /Contents {} 0 R >>",
+            page_ids[index] + 1
+        ));
+        objects.push(format!(
+            "<< /Length {} >>\nstream\n{}\nendstream",
+            stream.len(),
+            stream
+        ));
+    }
+    objects.push(format!("<< /Title ({}) >>", escape_pdf_string(title)));
+    let info_id = objects.len();
+
+    let mut pdf = b"%PDF-1.4\n".to_vec();
+    let mut offsets = Vec::with_capacity(objects.len());
+    for (index, object) in objects.iter().enumerate() {
+        offsets.push(pdf.len());
+        pdf.extend_from_slice(format!("{} 0 obj\n{}\nendobj\n", index + 1, object).as_bytes());
+    }
+    let xref_start = pdf.len();
+    pdf.extend_from_slice(
+        format!("xref\n0 {}\n0000000000 65535 f \n", objects.len() + 1).as_bytes(),
+    );
+    for offset in offsets {
+        pdf.extend_from_slice(format!("{:010} 00000 n \n", offset).as_bytes());
+    }
+    pdf.extend_from_slice(
+        format!(
+            "trailer\n<< /Size {} /Root 1 0 R /Info {} 0 R >>\nstartxref\n{}\n%%EOF\n",
+            objects.len() + 1,
+            info_id,
+            xref_start
+        )
+        .as_bytes(),
+    );
+    pdf
+}
+
+/// The `summarize-pdf` fixture document.
+pub fn fixture_pdf() -> Vec<u8> {
+    build_text_pdf("Quarterly Operations Report", FIXTURE_PDF_PAGES)
+}
+
+/// Concatenate the text content items of an MCP tool result.
+pub fn result_text(result: &Value) -> String {
+    result
+        .get("content")
+        .and_then(Value::as_array)
+        .map(|content| {
+            content
+                .iter()
+                .filter_map(|item| item.get("text").and_then(Value::as_str))
+                .collect::<Vec<_>>()
+                .join("\n")
+        })
+        .unwrap_or_default()
+}
+
+/// Shorten string values longer than `max_chars` so tool arguments fit on
+/// one transcript line.
+pub fn abbreviate_strings(value: &Value, max_chars: usize) -> Value {
+    match value {
+        Value::String(s) if s.chars().count() > max_chars => {
+            let head: String = s.chars().take(max_chars).collect();
+            Value::String(format!("{}... ({} chars)", head, s.chars().count()))
+        }
+        Value::Array(items) => Value::Array(
+            items
+                .iter()
+                .map(|item| abbreviate_strings(item, max_chars))
+                .collect(),
+        ),
+        Value::Object(map) => Value::Object(
+            map.iter()
+                .map(|(key, item)| (key.clone(), abbreviate_strings(item, max_chars)))
+                .collect(),
+        ),
+        other => other.clone(),
+    }
+}
+
+/// The first `max_lines` lines of `text`, noting how many were left out.
+pub fn preview(text: &str, max_lines: usize) -> String {
+    let lines: Vec<&str> = text.lines().collect();
+    if lines.len() <= max_lines {
+        return text.trim_end().to_string();
+    }
+    format!(
+        "{}\n... ({} more lines; --full shows everything)",
+        lines[..max_lines].join("\n"),
+        lines.len() - max_lines
+    )
+}
+
+/// Drop ATX headings and setext titles with their underlines, leaving the
+/// body text of a Markdown section.
+pub fn section_body(markdown: &str) -> String {
+    let lines: Vec<&str> = markdown.lines().collect();
+    let is_underline = |line: &str| {
+        let line = line.trim();
+        !line.is_empty() && (line.chars().all(|c| c == '=') || line.chars().all(|c| c == '-'))
+    };
+    lines
+        .iter()
+        .enumerate()
+        .filter(|(i, line)| {
+            !line.trim_start().starts_with('#')
+                && !is_underline(line)
+                && !lines.get(i + 1).is_some_and(|next| is_underline(next))
+        })
+        .map(|(_, line)| *line)
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Collapse whitespace and cut `text` to at most `max_chars` characters on a
+/// word boundary, for one-line excerpts.
+pub fn excerpt(text: &str, max_chars: usize) -> String {
+    let collapsed = text.split_whitespace().collect::<Vec<_>>().join(" ");
+    if collapsed.chars().count() <= max_chars {
+        return collapsed;
+    }
+    let cut: String = collapsed.chars().take(max_chars).collect();
+    let cut = cut.rsplit_once(' ').map_or(cut.as_str(), |(head, _)| head);
+    format!("{}...", cut)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_build_text_pdf_xref_offsets() {
+        let pdf = fixture_pdf();
+        let text = String::from_utf8(pdf.clone()).unwrap();
+        let xref_start: usize = text
+            .rsplit("startxref\n")
+            .next()
+            .and_then(|tail| tail.lines().next())
+            .and_then(|n| n.parse().ok())
+            .unwrap();
+        assert!(text[xref_start..].starts_with("xref\n0 10\n"));
+
+        // Every in-use xref entry points at its object header.
+        let entries: Vec<usize> = text[xref_start..]
+            .lines()
+            .filter(|line| line.ends_with(" n "))
+            .map(|line| line[..10].parse().unwrap())
+            .collect();
+        assert_eq!(entries.len(), 9);
+        for (index, offset) in entries.iter().enumerate() {
+            assert!(text[*offset..].starts_with(&format!("{} 0 obj\n", index + 1)));
+        }
+        assert!(
+            text.contains("(Root cause was an undersized row buffer introduced in v2.14.0.) Tj")
+        );
+        assert_eq!(escape_pdf_string(r"a (b) \c"), r"a \(b\) \\c");
+    }
+
+    #[test]
+    fn test_transcript_helpers() {
+        let result = json!({"content": [
+            {"type": "text", "text": "one"},
+            {"type": "image", "data": "..."},
+            {"type": "text", "text": "two"}
+        ]});
+        assert_eq!(result_text(&result), "one\ntwo");
+        assert_eq!(result_text(&json!({})), "");
+
+        let args = json!({"template": "abcdefghij", "nested": ["xyz"], "limit": 3});
+        assert_eq!(
+            abbreviate_strings(&args, 4),
+            json!({"template": "abcd... (10 chars)", "nested": ["xyz"], "limit": 3})
+        );
+
+        assert_eq!(preview("a\nb\n", 2), "a\nb");
+        assert_eq!(
+            preview("a\nb\nc\nd", 2),
+            "a\nb\n... (2 more lines; --full shows everything)"
+        );
+
+        assert_eq!(excerpt("  short\n text ", 20), "short text");
+        assert_eq!(excerpt("one two three four", 12), "one two...");
+        assert_eq!(
+            section_body("Setup\n-----\n\nBody\n### Env ###\nMore"),
+            "\nBody\nMore"
+        );
+
+        let ticket: Value = serde_json::from_str(FIXTURE_TICKET_JSON).unwrap();
+        assert_eq!(ticket["comments"].as_array().unwrap().len(), 2);
+    }
+}
@@ -0,0 +1,620 @@
+//! Line-based text diffing and unified patch application
+//!
+//! [`unified_diff`] computes a Myers shortest edit script between two texts and
+//! renders it as a unified diff. [`parse_patch`] and [`apply_patch`] do the
+//! reverse, tolerating hunks that moved because of earlier edits.
+//!
+//! Lines keep their terminators, so a missing final newline is a real change
+//! and is rendered with the usual `\ No newline at end of file` marker.
+
+use serde::Serialize;
+
+/// Beyond this edit distance the diff falls back to replacing the whole
+/// changed region, bounding time and memory on unrelated inputs.
+const MAX_EDIT_DISTANCE: usize = 4_000;
+
+const NO_NEWLINE_MARKER: &str = "\\ No newline at end of file";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Op {
+    Equal,
+    Delete,
+    Insert,
+}
+
+/// One line of a hunk. The content includes its line terminator, if any.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum HunkLine {
+    Context(String),
+    Remove(String),
+    Add(String),
+}
+
+/// A unified diff hunk. Starts are 1-based; a zero-length side starts at the
+/// line it follows (0 for the top of the file).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Hunk {
+    pub old_start: usize,
+    pub old_lines: usize,
+    pub new_start: usize,
+    pub new_lines: usize,
+    pub lines: Vec<HunkLine>,
+}
+
+impl Hunk {
+    fn old_block(&self) -> Vec<&str> {
+        self.lines
+            .iter()
+            .filter_map(|l| match l {
+                HunkLine::Context(s) | HunkLine::Remove(s) => Some(s.as_str()),
+                HunkLine::Add(_) => None,
+            })
+            .collect()
+    }
+
+    fn new_block(&self) -> Vec<&str> {
+        self.lines
+            .iter()
+            .filter_map(|l| match l {
+                HunkLine::Context(s) | HunkLine::Add(s) => Some(s.as_str()),
+                HunkLine::Remove(_) => None,
+            })
+            .collect()
+    }
+}
+
+/// Result of diffing two texts.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct DiffOutput {
+    /// Unified diff, empty when the texts are identical
+    pub diff: String,
+    pub identical: bool,
+    pub hunks: usize,
+    pub additions: usize,
+    pub deletions: usize,
+}
+
+/// Result of applying a patch.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct PatchOutput {
+    pub text: String,
+    pub hunks_applied: usize,
+    /// Hunks applied away from their recorded position, as
+    /// `(hunk number, line offset)`
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub offsets: Vec<(usize, isize)>,
+}
+
+fn split_lines(text: &str) -> Vec<&str> {
+    text.split_inclusive('\n').collect()
+}
+
+/// Myers shortest edit script between `a` and `b`.
+fn edit_script(a: &[&str], b: &[&str]) -> Vec<Op> {
+    // Common prefix and suffix never need the quadratic search.
+    let prefix = a.iter().zip(b).take_while(|(x, y)| x == y).count();
+    let suffix = a[prefix..]
+        .iter()
+        .rev()
+        .zip(b[prefix..].iter().rev())
+        .take_while(|(x, y)| x == y)
+        .count();
+    let (a_mid, b_mid) = (&a[prefix..a.len() - suffix], &b[prefix..b.len() - suffix]);
+
+    let mut ops = vec![Op::Equal; prefix];
+    ops.extend(middle_script(a_mid, b_mid));
+    ops.extend(std::iter::repeat_n(Op::Equal, suffix));
+    ops
+}
+
+fn middle_script(a: &[&str], b: &[&str]) -> Vec<Op> {
+    let (n, m) = (a.len() as isize, b.len() as isize);
+    let replace_all = || {
+        let mut ops = vec![Op::Delete; a.len()];
+        ops.extend(std::iter::repeat_n(Op::Insert, b.len()));
+        ops
+    };
+    if n == 0 || m == 0 {
+        return replace_all();
+    }
+
+    // trace[d] holds the furthest x per diagonal k in -d..=d before round d.
+    let max = (a.len() + b.len()).min(MAX_EDIT_DISTANCE) as isize;
+    let offset = max + 1;
+    let mut v = vec![0isize; (2 * offset + 1) as usize];
+    let mut trace: Vec<Vec<isize>> = Vec::new();
+    let at = |v: &[isize], k: isize| v[(k + offset) as usize];
+
+    let mut found = false;
+    'search: for d in 0..=max {
+        trace.push(v[(offset - d) as usize..=(offset + d) as usize].to_vec());
+        for k in (-d..=d).step_by(2) {
+            let mut x = if k == -d || (k != d && at(&v, k - 1) < at(&v, k + 1)) {
+                at(&v, k + 1)
+            } else {
+                at(&v, k - 1) + 1
+            };
+            let mut y = x - k;
+            while x < n && y < m && a[x as usize] == b[y as usize] {
+                x += 1;
+                y += 1;
+            }
+            v[(k + offset) as usize] = x;
+            if x >= n && y >= m {
+                found = true;
+                break 'search;
+            }
+        }
+    }
+    if !found {
+        return replace_all();
+    }
+
+    let mut ops = Vec::new();
+    let (mut x, mut y) = (n, m);
+    for (d, window) in trace.iter().enumerate().rev() {
+        let d = d as isize;
+        let get = |k: isize| window[(k + d) as usize];
+        let k = x - y;
+        let prev_k = if k == -d || (k != d && get(k - 1) < get(k + 1)) {
+            k + 1
+        } else {
+            k - 1
+        };
+        let prev_x = if d == 0 { 0 } else { get(prev_k) };
+        let prev_y = prev_x - prev_k;
+        while x > prev_x && y > prev_y {
+            ops.push(Op::Equal);
+            x -= 1;
+            y -= 1;
+        }
+        if d > 0 {
+            ops.push(if x == prev_x { Op::Insert } else { Op::Delete });
+        }
+        x = prev_x;
+        y = prev_y;
+    }
+    ops.reverse();
+    ops
+}
+
+/// Group an edit script into hunks with `context` lines around each change.
+fn build_hunks(a: &[&str], b: &[&str], ops: &[Op], context: usize) -> Vec<Hunk> {
+    // (op, old index, new index) at the point each op applies
+    let mut entries = Vec::with_capacity(ops.len());
+    let (mut i, mut j) = (0, 0);
+    for op in ops {
+        entries.push((*op, i, j));
+        match op {
+            Op::Equal => {
+                i += 1;
+                j += 1;
+            }
+            Op::Delete => i += 1,
+            Op::Insert => j += 1,
+        }
+    }
+
+    let changes: Vec<usize> = entries
+        .iter()
+        .enumerate()
+        .filter(|(_, (op, _, _))| *op != Op::Equal)
+        .map(|(index, _)| index)
+        .collect();
+
+    let mut hunks = Vec::new();
+    let mut c = 0;
+    while c < changes.len() {
+        let first = changes[c];
+        let mut last = first;
+        while c + 1 < changes.len() && changes[c + 1] - last - 1 <= 2 * context {
+            c += 1;
+            last = changes[c];
+        }
+        c += 1;
+
+        let start = first.saturating_sub(context);
+        let end = (last + context + 1).min(entries.len());
+        let slice = &entries[start..end];
+        let lines: Vec<HunkLine> = slice
+            .iter()
+            .map(|(op, i, j)| match op {
+                Op::Equal => HunkLine::Context(a[*i].to_string()),
+                Op::Delete => HunkLine::Remove(a[*i].to_string()),
+                Op::Insert => HunkLine::Add(b[*j].to_string()),
+            })
+            .collect();
+        let old_lines = slice.iter().filter(|(op, _, _)| *op != Op::Insert).count();
+        let new_lines = slice.iter().filter(|(op, _, _)| *op != Op::Delete).count();
+        let (_, i0, j0) = slice[0];
+        hunks.push(Hunk {
+            old_start: if old_lines == 0 { i0 } else { i0 + 1 },
+            old_lines,
+            new_start: if new_lines == 0 { j0 } else { j0 + 1 },
+            new_lines,
+            lines,
+        });
+    }
+    hunks
+}
+
+fn format_range(start: usize, lines: usize) -> String {
+    if lines == 1 {
+        start.to_string()
+    } else {
+        format!("{},{}", start, lines)
+    }
+}
+
+/// Render hunks as a unified diff with `---`/`+++` headers.
+pub fn format_unified(hunks: &[Hunk], old_name: &str, new_name: &str) -> String {
+    if hunks.is_empty() {
+        return String::new();
+    }
+    let mut out = format!("--- {}\n+++ {}\n", old_name, new_name);
+    for hunk in hunks {
+        out.push_str(&format!(
+            "@@ -{} +{} @@\n",
+            format_range(hunk.old_start, hunk.old_lines),
+            format_range(hunk.new_start, hunk.new_lines)
+        ));
+        for line in &hunk.lines {
+            let (prefix, content) = match line {
+                HunkLine::Context(s) => (' ', s),
+                HunkLine::Remove(s) => ('-', s),
+                HunkLine::Add(s) => ('+', s),
+            };
+            out.push(prefix);
+            out.push_str(content);
+            if !content.ends_with('\n') {
+                out.push('\n');
+                out.push_str(NO_NEWLINE_MARKER);
+                out.push('\n');
+            }
+        }
+    }
+    out
+}
+
+/// Diff two texts line by line into a unified diff.
+pub fn unified_diff(
+    old: &str,
+    new: &str,
+    old_name: &str,
+    new_name: &str,
+    context: usize,
+) -> DiffOutput {
+    let (a, b) = (split_lines(old), split_lines(new));
+    let ops = edit_script(&a, &b);
+    let hunks = build_hunks(&a, &b, &ops, context);
+
+    DiffOutput {
+        diff: format_unified(&hunks, old_name, new_name),
+        identical: hunks.is_empty(),
+        hunks: hunks.len(),
+        additions: ops.iter().filter(|op| **op == Op::Insert).count(),
+        deletions: ops.iter().filter(|op| **op == Op::Delete).count(),
+    }
+}
+
+/// Parse `-start,count +start,count` from a hunk header.
+fn parse_hunk_header(line: &str) -> Result<(usize, usize, usize, usize), String> {
+    let invalid = || format!("Invalid hunk header '{}'", line.trim_end());
+    let inner = line
+        .strip_prefix("@@ ")
+        .and_then(|rest| rest.split(" @@").next())
+        .ok_or_else(invalid)?;
+    let (old, new) = inner.split_once(' ').ok_or_else(invalid)?;
+    let range = |spec: &str, sign: char| -> Result<(usize, usize), String> {
+        let spec = spec.strip_prefix(sign).ok_or_else(invalid)?;
+        let (start, count) = spec.split_once(',').unwrap_or((spec, "1"));
+        Ok((
+            start.parse().map_err(|_| invalid())?,
+            count.parse().map_err(|_| invalid())?,
+        ))
+    };
+    let (old_start, old_lines) = range(old.trim(), '-')?;
+    let (new_start, new_lines) = range(new.trim(), '+')?;
+    Ok((old_start, old_lines, new_start, new_lines))
+}
+
+/// Parse the hunks of a single-file unified diff. File headers and any text
+/// before the first hunk are ignored. A blank line inside a hunk is read as
+/// an empty context line, and a missing final newline on the patch itself
+/// is not treated as a change.
+pub fn parse_patch(patch: &str) -> Result<Vec<Hunk>, String> {
+    let lines = split_lines(patch);
+    let mut hunks: Vec<Hunk> = Vec::new();
+    let mut index = 0;
+
+    while index < lines.len() {
+        let line = lines[index];
+        index += 1;
+        if !line.starts_with("@@") {
+            continue;
+        }
+
+        let (old_start, old_lines, new_start, new_lines) = parse_hunk_header(line)?;
+        let mut hunk = Hunk {
+            old_start,
+            old_lines,
+            new_start,
+            new_lines,
+            lines: Vec::new(),
+        };
+        let (mut old_seen, mut new_seen) = (0, 0);
+
+        while old_seen < old_lines || new_seen < new_lines {
+            let Some(line) = lines.get(index) else {
+                return Err(format!(
+                    "Hunk {} is truncated (expected -{} +{} lines)",
+                    hunks.len() + 1,
+                    old_lines,
+                    new_lines
+                ));
+            };
+            index += 1;
+
+            let mut content = match line.chars().next() {
+                Some(' ') | Some('-') | Some('+') => line[1..].to_string(),
+                Some('\n') | Some('\r') => line.to_string(),
+                Some('\\') => continue,
+                _ => {
+                    return Err(format!(
+                        "Unexpected line in hunk {}: '{}'",
+                        hunks.len() + 1,
+                        line.trim_end()
+                    ))
+                }
+            };
+            if !content.ends_with('\n') {
+                content.push('\n');
+            }
+            if lines
+                .get(index)
+                .is_some_and(|next| next.starts_with(NO_NEWLINE_MARKER))
+            {
+                content.pop();
+                index += 1;
+            }
+
+            hunk.lines.push(match line.chars().next() {
+                Some('-') => {
+                    old_seen += 1;
+                    HunkLine::Remove(content)
+                }
+                Some('+') => {
+                    new_seen += 1;
+                    HunkLine::Add(content)
+                }
+                _ => {
+                    old_seen += 1;
+                    new_seen += 1;
+                    HunkLine::Context(content)
+                }
+            });
+        }
+
+        if old_seen != old_lines || new_seen != new_lines {
+            return Err(format!(
+                "Hunk {} line counts do not match its header",
+                hunks.len() + 1
+            ));
+        }
+        hunks.push(hunk);
+    }
+
+    if hunks.is_empty() {
+        return Err("Patch contains no hunks".to_string());
+    }
+    Ok(hunks)
+}
+
+/// Find `block` in `lines` at or after `from`, nearest to `expected`.
+fn find_block(lines: &[&str], block: &[&str], from: usize, expected: usize) -> Option<usize> {
+    let last = lines.len().checked_sub(block.len())?;
+    if from > last {
+        return None;
+    }
+    let expected = expected.clamp(from, last);
+    let matches = |p: usize| lines[p..p + block.len()] == *block;
+
+    (0..=last - from).find_map(|distance| {
+        let after = expected + distance;
+        if after <= last && matches(after) {
+            return Some(after);
+        }
+        let before = expected.checked_sub(distance)?;
+        (before >= from && matches(before)).then_some(before)
+    })
+}
+
+/// Apply a parsed patch to `text`. Each hunk must match exactly, but may be
+/// found above or below its recorded position (reported in
+/// [`PatchOutput::offsets`]). Any hunk that does not match fails the whole
+/// patch, leaving the caller's text untouched.
+pub fn apply_patch(text: &str, hunks: &[Hunk]) -> Result<PatchOutput, String> {
+    let lines = split_lines(text);
+    let mut out = String::with_capacity(text.len());
+    let mut cursor = 0;
+    let mut drift: isize = 0;
+    let mut offsets = Vec::new();
+
+    for (number, hunk) in hunks.iter().enumerate().map(|(i, h)| (i + 1, h)) {
+        let old_block = hunk.old_block();
+        let recorded = if hunk.old_lines == 0 {
+            hunk.old_start
+        } else {
+            hunk.old_start.saturating_sub(1)
+        };
+        let expected = (recorded as isize + drift).max(0) as usize;
+
+        let position = if old_block.is_empty() {
+            Some(expected.clamp(cursor, lines.len()))
+        } else {
+            find_block(&lines, &old_block, cursor, expected)
+        }
+        .ok_or_else(|| {
+            let preview = old_block
+                .first()
+                .map(|l| format!(": '{}'", l.trim_end()))
+                .unwrap_or_default();
+            format!(
+                "Hunk {} does not apply (context not found near line {}{})",
+                number,
+                recorded + 1,
+                preview
+            )
+        })?;
+
+        let offset = position as isize - recorded as isize;
+        if offset != 0 {
+            offsets.push((number, offset));
+        }
+        drift = offset;
+
+        out.extend(lines[cursor..position].iter().copied());
+        out.extend(hunk.new_block());
+        cursor = position + old_block.len();
+    }
+    out.extend(lines[cursor..].iter().copied());
+
+    Ok(PatchOutput {
+        text: out,
+        hunks_applied: hunks.len(),
+        offsets,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn roundtrip(old: &str, new: &str) {
+        let diff = unified_diff(old, new, "a", "b", 3);
+        if old == new {
+            assert!(diff.identical);
+            return;
+        }
+        let hunks = parse_patch(&diff.diff).unwrap();
+        assert_eq!(apply_patch(old, &hunks).unwrap().text, new);
+    }
+
+    #[test]
+    fn test_unified_diff_format() {
+        let old = "one\ntwo\nthree\nfour\nfive\n";
+        let new = "one\ntwo\nTHREE\nfour\nfive\nsix\n";
+        let diff = unified_diff(old, new, "a/notes.txt", "b/notes.txt", 1);
+
+        assert_eq!(
+            diff.diff,
+            "--- a/notes.txt\n+++ b/notes.txt\n@@ -2,4 +2,5 @@\n two\n-three\n+THREE\n four\n five\n+six\n"
+        );
+        assert_eq!((diff.hunks, diff.additions, diff.deletions), (1, 2, 1));
+    }
+
+    #[test]
+    fn test_unified_diff_separate_hunks() {
+        let old: String = (1..=20).map(|i| format!("line {}\n", i)).collect();
+        let new = old
+            .replace("line 2\n", "line two\n")
+            .replace("line 18\n", "");
+        let diff = unified_diff(&old, &new, "a", "b", 3);
+
+        assert_eq!(diff.hunks, 2);
+        assert!(diff.diff.contains("@@ -1,5 +1,5 @@\n"));
+        assert!(diff.diff.contains("@@ -15,6 +15,5 @@\n"));
+    }
+
+    #[test]
+    fn test_unified_diff_identical() {
+        let diff = unified_diff("same\n", "same\n", "a", "b", 3);
+        assert!(diff.identical);
+        assert_eq!(diff.diff, "");
+    }
+
+    #[test]
+    fn test_unified_diff_missing_newline() {
+        let diff = unified_diff("a\nb\n", "a\nb", "a", "b", 3);
+        assert_eq!(
+            diff.diff,
+            "--- a\n+++ b\n@@ -1,2 +1,2 @@\n a\n-b\n+b\n\\ No newline at end of file\n"
+        );
+    }
+
+    #[test]
+    fn test_unified_diff_from_empty() {
+        let diff = unified_diff("", "new\n", "a", "b", 3);
+        assert!(diff.diff.contains("@@ -0,0 +1 @@\n+new\n"));
+    }
+
+    #[test]
+    fn test_roundtrips() {
+        roundtrip("", "");
+        roundtrip("", "a\nb\n");
+        roundtrip("a\nb\n", "");
+        roundtrip("a\nb\nc\n", "c\nb\na\n");
+        roundtrip("x\ny", "x\ny\n");
+        roundtrip(
+            "fn main() {\n    println!(\"hi\");\n}\n",
+            "fn main() {\n    let name = \"you\";\n    println!(\"hi {}\", name);\n}\n",
+        );
+        let long: String = (0..200).map(|i| format!("{}\n", i % 7)).collect();
+        let edited = long.replacen("3\n", "three\n", 5).replace("6\n", "");
+        roundtrip(&long, &edited);
+    }
+
+    #[test]
+    fn test_parse_patch_errors() {
+        assert_eq!(
+            parse_patch("just text"),
+            Err("Patch contains no hunks".to_string())
+        );
+        assert!(parse_patch("@@ -1,2 +1,2 @@\n a\n").is_err());
+        assert!(parse_patch("@@ bogus @@\n").is_err());
+        assert!(parse_patch("@@ -1 +1 @@\n*a\n").is_err());
+    }
+
+    #[test]
+    fn test_parse_patch_lenient_whitespace() {
+        // Blank context line without its leading space, and no final newline.
+        let hunks = parse_patch("@@ -1,3 +1,3 @@\n a\n\n-b\n+c").unwrap();
+        assert_eq!(
+            hunks[0].lines,
+            vec![
+                HunkLine::Context("a\n".to_string()),
+                HunkLine::Context("\n".to_string()),
+                HunkLine::Remove("b\n".to_string()),
+                HunkLine::Add("c\n".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_apply_patch_with_offset() {
+        let patch = "@@ -2,3 +2,3 @@\n b\n-c\n+C\n d\n";
+        let hunks = parse_patch(patch).unwrap();
+        // Two lines were inserted above since the patch was made.
+        let result = apply_patch("x\ny\na\nb\nc\nd\ne\n", &hunks).unwrap();
+
+        assert_eq!(result.text, "x\ny\na\nb\nC\nd\ne\n");
+        assert_eq!(result.offsets, vec![(1, 2)]);
+    }
+
+    #[test]
+    fn test_apply_patch_rejects_mismatch() {
+        let hunks = parse_patch("@@ -1,2 +1,2 @@\n a\n-b\n+c\n").unwrap();
+        let err = apply_patch("a\nz\n", &hunks).unwrap_err();
+        assert_eq!(
+            err,
+            "Hunk 1 does not apply (context not found near line 1: 'a')"
+        );
+    }
+
+    #[test]
+    fn test_apply_patch_pure_insertion() {
+        let hunks = parse_patch("@@ -0,0 +1 @@\n+header\n").unwrap();
+        let result = apply_patch("body\n", &hunks).unwrap();
+        assert_eq!(result.text, "header\nbody\n");
+    }
+}
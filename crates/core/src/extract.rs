@@ -0,0 +1,269 @@
+//! Regex extraction with resource limits
+//!
+//! Backed by the `regex` crate, whose automata-based engine runs in time
+//! linear in the input, so patterns like `(a+)+$` cannot backtrack
+//! catastrophically. What remains bounded here is the pattern size, the
+//! compiled program size, the input size, the number of matches returned,
+//! and, given a deadline, how long the scan runs.
+
+use std::time::Instant;
+
+use regex::{Regex, RegexBuilder};
+use serde::Serialize;
+
+/// Longest accepted pattern, in bytes.
+pub const MAX_PATTERN_LEN: usize = 10_000;
+
+/// Largest accepted input, in bytes.
+pub const MAX_INPUT_LEN: usize = 16 * 1024 * 1024;
+
+/// Compiled program size limit (the `regex` default is 10 MiB).
+const COMPILED_SIZE_LIMIT: usize = 2 * 1024 * 1024;
+
+/// Lazy DFA cache limit.
+const DFA_SIZE_LIMIT: usize = 8 * 1024 * 1024;
+
+/// Maximum nesting depth of groups and repetitions.
+const NEST_LIMIT: u32 = 64;
+
+/// Inline flags accepted as a string like `"im"`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct RegexFlags {
+    /// `i`: case-insensitive
+    pub case_insensitive: bool,
+    /// `m`: `^`/`$` match at line boundaries
+    pub multi_line: bool,
+    /// `s`: `.` matches `\n`
+    pub dot_matches_new_line: bool,
+    /// `x`: ignore whitespace and allow `#` comments
+    pub ignore_whitespace: bool,
+}
+
+impl std::str::FromStr for RegexFlags {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut flags = Self::default();
+        for c in s.chars() {
+            match c {
+                'i' => flags.case_insensitive = true,
+                'm' => flags.multi_line = true,
+                's' => flags.dot_matches_new_line = true,
+                'x' => flags.ignore_whitespace = true,
+                other => {
+                    return Err(format!(
+                        "Unsupported regex flag '{}' (expected i, m, s, or x)",
+                        other
+                    ))
+                }
+            }
+        }
+        Ok(flags)
+    }
+}
+
+/// A capture group within a match. Unmatched optional groups have no text.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct GroupMatch {
+    pub index: usize,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    pub text: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end: Option<usize>,
+}
+
+/// One match. Offsets are byte offsets into the input; `line` is 1-based.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct RegexMatch {
+    pub text: String,
+    pub start: usize,
+    pub end: usize,
+    pub line: usize,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub groups: Vec<GroupMatch>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ExtractOutput {
+    pub pattern: String,
+    pub match_count: usize,
+    /// True when more matches exist beyond `max_matches`
+    pub truncated: bool,
+    pub matches: Vec<RegexMatch>,
+}
+
+/// Compile a pattern with size and nesting limits.
+pub fn build_regex(pattern: &str, flags: RegexFlags) -> Result<Regex, String> {
+    if pattern.is_empty() {
+        return Err("Pattern is empty".to_string());
+    }
+    if pattern.len() > MAX_PATTERN_LEN {
+        return Err(format!(
+            "Pattern is {} bytes; the limit is {}",
+            pattern.len(),
+            MAX_PATTERN_LEN
+        ));
+    }
+    RegexBuilder::new(pattern)
+        .case_insensitive(flags.case_insensitive)
+        .multi_line(flags.multi_line)
+        .dot_matches_new_line(flags.dot_matches_new_line)
+        .ignore_whitespace(flags.ignore_whitespace)
+        .size_limit(COMPILED_SIZE_LIMIT)
+        .dfa_size_limit(DFA_SIZE_LIMIT)
+        .nest_limit(NEST_LIMIT)
+        .build()
+        .map_err(|e| format!("Invalid regex: {}", e))
+}
+
+/// Find up to `max_matches` matches of `regex` in `text`, with capture groups
+/// when the pattern has any. Once `deadline` passes, the scan stops at the
+/// next match and returns an error.
+pub fn extract_matches(
+    regex: &Regex,
+    text: &str,
+    max_matches: usize,
+    deadline: Option<Instant>,
+) -> Result<ExtractOutput, String> {
+    if text.len() > MAX_INPUT_LEN {
+        return Err(format!(
+            "Input is {} bytes; the limit is {}",
+            text.len(),
+            MAX_INPUT_LEN
+        ));
+    }
+
+    let names: Vec<Option<String>> = regex
+        .capture_names()
+        .map(|n| n.map(str::to_string))
+        .collect();
+
+    let mut matches = Vec::new();
+    let mut truncated = false;
+    // Line numbers are counted incrementally since matches come in order.
+    let (mut line, mut line_pos) = (1, 0);
+
+    for captures in regex.captures_iter(text) {
+        if matches.len() == max_matches {
+            truncated = true;
+            break;
+        }
+        if deadline.is_some_and(|d| Instant::now() >= d) {
+            return Err(format!(
+                "Gave up after {} matches: time ran out",
+                matches.len()
+            ));
+        }
+        let whole = captures.get(0).expect("group 0 always matches");
+        line += text[line_pos..whole.start()].matches('\n').count();
+        line_pos = whole.start();
+
+        let groups = (1..captures.len())
+            .map(|index| {
+                let group = captures.get(index);
+                GroupMatch {
+                    index,
+                    name: names[index].clone(),
+                    text: group.map(|g| g.as_str().to_string()),
+                    start: group.map(|g| g.start()),
+                    end: group.map(|g| g.end()),
+                }
+            })
+            .collect();
+
+        matches.push(RegexMatch {
+            text: whole.as_str().to_string(),
+            start: whole.start(),
+            end: whole.end(),
+            line,
+            groups,
+        });
+    }
+
+    Ok(ExtractOutput {
+        pattern: regex.as_str().to_string(),
+        match_count: matches.len(),
+        truncated,
+        matches,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn extract(pattern: &str, flags: &str, text: &str, max: usize) -> ExtractOutput {
+        let regex = build_regex(pattern, flags.parse().unwrap()).unwrap();
+        extract_matches(&regex, text, max, None).unwrap()
+    }
+
+    #[test]
+    fn test_parse_flags() {
+        let flags: RegexFlags = "ims".parse().unwrap();
+        assert!(flags.case_insensitive && flags.multi_line && flags.dot_matches_new_line);
+        assert!(!flags.ignore_whitespace);
+        assert!("g".parse::<RegexFlags>().is_err());
+    }
+
+    #[test]
+    fn test_extract_with_named_and_optional_groups() {
+        let output = extract(r"(?P<key>\w+)=(\d+)?", "", "a=1\nb=\nc=33", 10);
+
+        assert_eq!(output.match_count, 3);
+        assert!(!output.truncated);
+        let third = &output.matches[2];
+        assert_eq!(
+            (third.text.as_str(), third.start, third.end, third.line),
+            ("c=33", 7, 11, 3)
+        );
+        assert_eq!(third.groups[0].name.as_deref(), Some("key"));
+        assert_eq!(third.groups[0].text.as_deref(), Some("c"));
+        assert_eq!(third.groups[1].text.as_deref(), Some("33"));
+        assert_eq!(output.matches[1].groups[1].text, None);
+    }
+
+    #[test]
+    fn test_extract_without_groups_and_flags() {
+        let output = extract("^todo", "im", "TODO one\nnope\ntodo two", 10);
+        assert_eq!(output.match_count, 2);
+        assert!(output.matches[0].groups.is_empty());
+        assert_eq!(output.matches[1].line, 3);
+    }
+
+    #[test]
+    fn test_extract_truncates() {
+        let output = extract(r"\d", "", "1 2 3 4", 2);
+        assert_eq!(output.match_count, 2);
+        assert!(output.truncated);
+    }
+
+    #[test]
+    fn test_extract_stops_at_deadline() {
+        let regex = build_regex(r"\d", RegexFlags::default()).unwrap();
+        let err = extract_matches(&regex, "1 2 3", 10, Some(Instant::now())).unwrap_err();
+        assert!(err.contains("time ran out"), "{err}");
+    }
+
+    #[test]
+    fn test_pathological_pattern_is_linear() {
+        // Exponential for backtracking engines; the input has no match.
+        let text = format!("{}!", "a".repeat(10_000));
+        let output = extract("^(a+)+$", "", &text, 10);
+        assert_eq!(output.match_count, 0);
+    }
+
+    #[test]
+    fn test_build_regex_limits() {
+        assert!(build_regex("", RegexFlags::default()).is_err());
+        assert!(build_regex("(", RegexFlags::default()).is_err());
+        assert!(build_regex(&"a".repeat(MAX_PATTERN_LEN + 1), RegexFlags::default()).is_err());
+        // Counted repetition blows up the compiled program size.
+        let err = build_regex(r"(\w{500}){500}", RegexFlags::default()).unwrap_err();
+        assert!(err.starts_with("Invalid regex"), "{}", err);
+        let nested = format!("{}a{}", "(".repeat(100), ")".repeat(100));
+        assert!(build_regex(&nested, RegexFlags::default()).is_err());
+    }
+}
@@ -0,0 +1,397 @@
+//! UUID, ULID, and password generation
+//!
+//! Every generator takes the RNG (and, for time-ordered IDs, the timestamp)
+//! as a parameter, so callers decide between OS entropy and a fixed seed and
+//! the output is reproducible in tests.
+
+use rand::seq::SliceRandom;
+use rand::Rng;
+use serde::Serialize;
+
+/// Crockford base32, as used by ULIDs.
+const CROCKFORD: &[u8; 32] = b"0123456789ABCDEFGHJKMNPQRSTVWXYZ";
+
+const LOWERCASE: &str = "abcdefghijklmnopqrstuvwxyz";
+const UPPERCASE: &str = "ABCDEFGHIJKLMNOPQRSTUVWXYZ";
+const DIGITS: &str = "0123456789";
+const SYMBOLS: &str = "!#$%&()*+,-./:;<=>?@[]^_{|}~";
+/// Characters easily confused with one another in many fonts.
+const AMBIGUOUS: &str = "0O1lI|";
+
+/// Largest number of values produced per call.
+pub const MAX_COUNT: usize = 1000;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UuidVersion {
+    /// Random
+    V4,
+    /// Unix-millisecond timestamp followed by random bits; sorts by creation time
+    V7,
+}
+
+impl std::str::FromStr for UuidVersion {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim_start_matches(['v', 'V']) {
+            "4" => Ok(UuidVersion::V4),
+            "7" => Ok(UuidVersion::V7),
+            _ => Err(format!(
+                "Unsupported UUID version '{}' (expected 4 or 7)",
+                s
+            )),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UuidFormat {
+    /// `xxxxxxxx-xxxx-xxxx-xxxx-xxxxxxxxxxxx`
+    Hyphenated,
+    /// 32 hex digits, no separators
+    Simple,
+    /// `urn:uuid:` followed by the hyphenated form
+    Urn,
+    /// Hyphenated form in braces
+    Braced,
+}
+
+impl std::str::FromStr for UuidFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "hyphenated" => Ok(UuidFormat::Hyphenated),
+            "simple" => Ok(UuidFormat::Simple),
+            "urn" => Ok(UuidFormat::Urn),
+            "braced" => Ok(UuidFormat::Braced),
+            _ => Err(format!(
+                "Unsupported UUID format '{}' (expected hyphenated, simple, urn, or braced)",
+                s
+            )),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PasswordOptions {
+    pub length: usize,
+    pub lowercase: bool,
+    pub uppercase: bool,
+    pub digits: bool,
+    pub symbols: bool,
+    /// Leave out look-alike characters such as `0`/`O` and `1`/`l`/`I`
+    pub exclude_ambiguous: bool,
+}
+
+impl Default for PasswordOptions {
+    fn default() -> Self {
+        Self {
+            length: 20,
+            lowercase: true,
+            uppercase: true,
+            digits: true,
+            symbols: true,
+            exclude_ambiguous: false,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct GenerateOutput {
+    /// `uuid`, `ulid`, or `password`
+    pub kind: String,
+    pub values: Vec<String>,
+    /// Seed used, when the output is reproducible
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub seed: Option<u64>,
+}
+
+/// Generate the 16 bytes of a UUID. `unix_ms` is only used by v7.
+pub fn uuid_bytes<R: Rng + ?Sized>(rng: &mut R, version: UuidVersion, unix_ms: u64) -> [u8; 16] {
+    let mut bytes: [u8; 16] = rng.gen();
+    let version_bits = match version {
+        UuidVersion::V4 => 0x40,
+        UuidVersion::V7 => {
+            bytes[..6].copy_from_slice(&unix_ms.to_be_bytes()[2..]);
+            0x70
+        }
+    };
+    bytes[6] = (bytes[6] & 0x0f) | version_bits;
+    // RFC 9562 variant: 10xx
+    bytes[8] = (bytes[8] & 0x3f) | 0x80;
+    bytes
+}
+
+/// Render UUID bytes in `format`, lowercase unless `uppercase`.
+pub fn format_uuid(bytes: &[u8; 16], format: UuidFormat, uppercase: bool) -> String {
+    let hex: String = bytes.iter().map(|b| format!("{:02x}", b)).collect();
+    let hyphenated = || {
+        format!(
+            "{}-{}-{}-{}-{}",
+            &hex[..8],
+            &hex[8..12],
+            &hex[12..16],
+            &hex[16..20],
+            &hex[20..]
+        )
+    };
+    let text = match format {
+        UuidFormat::Simple => hex.clone(),
+        UuidFormat::Hyphenated => hyphenated(),
+        UuidFormat::Braced => format!("{{{}}}", hyphenated()),
+        UuidFormat::Urn => return format!("urn:uuid:{}", hyphenated()),
+    };
+    if uppercase {
+        text.to_uppercase()
+    } else {
+        text
+    }
+}
+
+fn encode_ulid(value: u128) -> String {
+    (0..26)
+        .rev()
+        .map(|i| CROCKFORD[((value >> (i * 5)) & 0x1f) as usize] as char)
+        .collect()
+}
+
+/// Generate `count` ULIDs for the millisecond `unix_ms`. Values after the
+/// first increment the random part, so the batch is strictly increasing as
+/// the ULID spec requires for IDs within one millisecond.
+pub fn ulids<R: Rng + ?Sized>(rng: &mut R, unix_ms: u64, count: usize) -> Vec<String> {
+    const RANDOM_MASK: u128 = (1 << 80) - 1;
+
+    let timestamp = u128::from(unix_ms & ((1 << 48) - 1)) << 80;
+    // Leave headroom so incrementing never overflows into the timestamp.
+    let mut random = rng.gen::<u128>() & (RANDOM_MASK >> 1);
+    (0..count)
+        .map(|_| {
+            let value = timestamp | random;
+            random += 1;
+            encode_ulid(value)
+        })
+        .collect()
+}
+
+/// Generate a password with at least one character from every enabled class.
+pub fn password<R: Rng + ?Sized>(rng: &mut R, options: &PasswordOptions) -> Result<String, String> {
+    let classes: Vec<Vec<char>> = [
+        (options.lowercase, LOWERCASE),
+        (options.uppercase, UPPERCASE),
+        (options.digits, DIGITS),
+        (options.symbols, SYMBOLS),
+    ]
+    .into_iter()
+    .filter(|(enabled, _)| *enabled)
+    .map(|(_, chars)| {
+        chars
+            .chars()
+            .filter(|c| !options.exclude_ambiguous || !AMBIGUOUS.contains(*c))
+            .collect()
+    })
+    .collect();
+
+    if classes.is_empty() {
+        return Err("At least one character class must be enabled".to_string());
+    }
+    if options.length < classes.len() {
+        return Err(format!(
+            "Length {} is too short to include all {} character classes",
+            options.length,
+            classes.len()
+        ));
+    }
+
+    let all: Vec<char> = classes.iter().flatten().copied().collect();
+    let mut chars: Vec<char> = classes
+        .iter()
+        .map(|class| class[rng.gen_range(0..class.len())])
+        .collect();
+    chars.extend((classes.len()..options.length).map(|_| all[rng.gen_range(0..all.len())]));
+    chars.shuffle(rng);
+    Ok(chars.into_iter().collect())
+}
+
+/// What to generate, with its format options.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GenerateKind {
+    Uuid {
+        version: UuidVersion,
+        format: UuidFormat,
+        uppercase: bool,
+    },
+    Ulid {
+        lowercase: bool,
+    },
+    Password(PasswordOptions),
+}
+
+impl GenerateKind {
+    pub fn name(&self) -> &'static str {
+        match self {
+            GenerateKind::Uuid { .. } => "uuid",
+            GenerateKind::Ulid { .. } => "ulid",
+            GenerateKind::Password(_) => "password",
+        }
+    }
+}
+
+/// Generate `count` values of `kind`. `unix_ms` timestamps UUIDv7s and ULIDs.
+pub fn generate_values<R: Rng + ?Sized>(
+    rng: &mut R,
+    kind: &GenerateKind,
+    count: usize,
+    unix_ms: u64,
+) -> Result<Vec<String>, String> {
+    let count = validate_count(count)?;
+    match kind {
+        GenerateKind::Uuid {
+            version,
+            format,
+            uppercase,
+        } => Ok((0..count)
+            .map(|_| format_uuid(&uuid_bytes(rng, *version, unix_ms), *format, *uppercase))
+            .collect()),
+        GenerateKind::Ulid { lowercase } => Ok(ulids(rng, unix_ms, count)
+            .into_iter()
+            .map(|v| if *lowercase { v.to_lowercase() } else { v })
+            .collect()),
+        GenerateKind::Password(options) => (0..count).map(|_| password(rng, options)).collect(),
+    }
+}
+
+/// Reject counts outside `1..=MAX_COUNT`.
+pub fn validate_count(count: usize) -> Result<usize, String> {
+    if (1..=MAX_COUNT).contains(&count) {
+        Ok(count)
+    } else {
+        Err(format!("Count must be between 1 and {}", MAX_COUNT))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    #[test]
+    fn test_uuid_v4_bits_and_formats() {
+        let bytes = uuid_bytes(&mut StdRng::seed_from_u64(1), UuidVersion::V4, 0);
+        let text = format_uuid(&bytes, UuidFormat::Hyphenated, false);
+
+        assert_eq!(text.len(), 36);
+        assert_eq!(&text[14..15], "4");
+        assert!("89ab".contains(&text[19..20]));
+        assert_eq!(
+            format_uuid(&bytes, UuidFormat::Simple, false),
+            text.replace('-', "")
+        );
+        assert_eq!(
+            format_uuid(&bytes, UuidFormat::Braced, true),
+            format!("{{{}}}", text.to_uppercase())
+        );
+        assert_eq!(
+            format_uuid(&bytes, UuidFormat::Urn, true),
+            format!("urn:uuid:{}", text)
+        );
+    }
+
+    #[test]
+    fn test_uuid_v7_embeds_timestamp() {
+        let unix_ms = 0x0189_1234_5678;
+        let bytes = uuid_bytes(&mut StdRng::seed_from_u64(1), UuidVersion::V7, unix_ms);
+        let text = format_uuid(&bytes, UuidFormat::Hyphenated, false);
+        assert!(text.starts_with("01891234-5678-7"), "{}", text);
+    }
+
+    #[test]
+    fn test_seeded_output_is_reproducible() {
+        let generate = |seed| {
+            let mut rng = StdRng::seed_from_u64(seed);
+            (
+                uuid_bytes(&mut rng, UuidVersion::V4, 0),
+                password(&mut rng, &PasswordOptions::default()).unwrap(),
+            )
+        };
+        assert_eq!(generate(42), generate(42));
+        assert_ne!(generate(42), generate(43));
+    }
+
+    #[test]
+    fn test_ulids_are_sorted_and_encode_timestamp() {
+        let values = ulids(&mut StdRng::seed_from_u64(7), 1_700_000_000_000, 5);
+
+        assert_eq!(values.len(), 5);
+        assert!(values.iter().all(|v| v.len() == 26));
+        assert!(values.windows(2).all(|w| w[0] < w[1]));
+        // Same millisecond, same 10-character timestamp prefix
+        assert!(values.iter().all(|v| v[..10] == values[0][..10]));
+        assert_eq!(encode_ulid(0), "0".repeat(26));
+        assert_eq!(&encode_ulid(u128::MAX)[..1], "7");
+    }
+
+    #[test]
+    fn test_password_includes_every_class() {
+        let mut rng = StdRng::seed_from_u64(3);
+        for _ in 0..20 {
+            let value = password(&mut rng, &PasswordOptions::default()).unwrap();
+            assert_eq!(value.chars().count(), 20);
+            assert!(value.chars().any(|c| c.is_ascii_lowercase()));
+            assert!(value.chars().any(|c| c.is_ascii_uppercase()));
+            assert!(value.chars().any(|c| c.is_ascii_digit()));
+            assert!(value.chars().any(|c| SYMBOLS.contains(c)));
+        }
+    }
+
+    #[test]
+    fn test_password_options() {
+        let mut rng = StdRng::seed_from_u64(3);
+        let options = PasswordOptions {
+            length: 200,
+            symbols: false,
+            exclude_ambiguous: true,
+            ..Default::default()
+        };
+        let value = password(&mut rng, &options).unwrap();
+        assert!(value.chars().all(|c| c.is_ascii_alphanumeric()));
+        assert!(!value.chars().any(|c| AMBIGUOUS.contains(c)));
+
+        let none = PasswordOptions {
+            lowercase: false,
+            uppercase: false,
+            digits: false,
+            symbols: false,
+            ..Default::default()
+        };
+        assert!(password(&mut rng, &none).is_err());
+        let short = PasswordOptions {
+            length: 3,
+            ..Default::default()
+        };
+        assert!(password(&mut rng, &short).is_err());
+    }
+
+    #[test]
+    fn test_parse_and_validate() {
+        assert_eq!("v7".parse(), Ok(UuidVersion::V7));
+        assert!("5".parse::<UuidVersion>().is_err());
+        assert_eq!("URN".parse(), Ok(UuidFormat::Urn));
+        assert!(validate_count(0).is_err());
+        assert!(validate_count(MAX_COUNT + 1).is_err());
+    }
+
+    #[test]
+    fn test_generate_values() {
+        let kind = GenerateKind::Ulid { lowercase: true };
+        let values = generate_values(&mut StdRng::seed_from_u64(1), &kind, 3, 0).unwrap();
+        assert_eq!(values.len(), 3);
+        assert!(values
+            .iter()
+            .all(|v| v.starts_with("0000000000") && v == &v.to_lowercase()));
+
+        let kind = GenerateKind::Password(PasswordOptions::default());
+        assert!(generate_values(&mut StdRng::seed_from_u64(1), &kind, 0, 0).is_err());
+    }
+}
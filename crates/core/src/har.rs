@@ -0,0 +1,405 @@
+//! HAR 1.2 capture for `md fetch --har`
+//!
+//! The shell forwards DevTools Network events as [`NetworkEvent`]s; this
+//! module pairs them into requests and renders the HTTP Archive document.
+
+use serde_json::{json, Value};
+
+/// Response details shared by `responseReceived` and redirect hops.
+#[derive(Debug, Clone, PartialEq)]
+pub struct NetworkResponse {
+    pub status: u16,
+    pub status_text: String,
+    /// Negotiated protocol (`http/1.1`, `h2`, ...), when Chrome reports it
+    pub protocol: Option<String>,
+    pub headers: Vec<(String, String)>,
+    pub mime_type: String,
+    pub remote_ip: Option<String>,
+    /// Bytes received so far, headers included
+    pub encoded_data_length: f64,
+}
+
+/// The DevTools Network events a HAR is built from. Timestamps are Chrome's
+/// monotonic seconds; `wall_time` is seconds since the Unix epoch.
+#[derive(Debug, Clone, PartialEq)]
+pub enum NetworkEvent {
+    RequestWillBeSent {
+        request_id: String,
+        url: String,
+        method: String,
+        headers: Vec<(String, String)>,
+        post_data: Option<String>,
+        resource_type: Option<String>,
+        timestamp: f64,
+        wall_time: f64,
+        /// Set when this request follows a redirect; completes the previous
+        /// hop under the same id
+        redirect_response: Option<NetworkResponse>,
+    },
+    ResponseReceived {
+        request_id: String,
+        timestamp: f64,
+        response: NetworkResponse,
+    },
+    LoadingFinished {
+        request_id: String,
+        timestamp: f64,
+        encoded_data_length: f64,
+    },
+    LoadingFailed {
+        request_id: String,
+        timestamp: f64,
+        error_text: String,
+    },
+}
+
+/// One request and whatever is known about its outcome.
+#[derive(Debug, Clone, PartialEq)]
+pub struct NetworkRecord {
+    pub request_id: String,
+    pub url: String,
+    pub method: String,
+    pub headers: Vec<(String, String)>,
+    pub post_data: Option<String>,
+    pub resource_type: Option<String>,
+    pub started: f64,
+    pub wall_time: f64,
+    pub response: Option<NetworkResponse>,
+    pub response_time: Option<f64>,
+    pub finished: Option<f64>,
+    pub error: Option<String>,
+}
+
+/// Requests seen during a page load, in the order they started.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct NetworkLog {
+    pub records: Vec<NetworkRecord>,
+}
+
+impl NetworkLog {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The open record for `request_id`; redirects reuse ids, so the latest wins.
+    fn open(&mut self, request_id: &str) -> Option<&mut NetworkRecord> {
+        self.records
+            .iter_mut()
+            .rev()
+            .find(|r| r.request_id == request_id)
+    }
+
+    /// Fold one event into the log. Events for unknown requests (sent before
+    /// capture started) are ignored.
+    pub fn apply(&mut self, event: NetworkEvent) {
+        match event {
+            NetworkEvent::RequestWillBeSent {
+                request_id,
+                url,
+                method,
+                headers,
+                post_data,
+                resource_type,
+                timestamp,
+                wall_time,
+                redirect_response,
+            } => {
+                if let Some(response) = redirect_response {
+                    if let Some(previous) = self.open(&request_id) {
+                        previous.response = Some(response);
+                        previous.response_time = Some(timestamp);
+                        previous.finished = Some(timestamp);
+                    }
+                }
+                self.records.push(NetworkRecord {
+                    request_id,
+                    url,
+                    method,
+                    headers,
+                    post_data,
+                    resource_type,
+                    started: timestamp,
+                    wall_time,
+                    response: None,
+                    response_time: None,
+                    finished: None,
+                    error: None,
+                });
+            }
+            NetworkEvent::ResponseReceived {
+                request_id,
+                timestamp,
+                response,
+            } => {
+                if let Some(record) = self.open(&request_id) {
+                    record.response = Some(response);
+                    record.response_time = Some(timestamp);
+                }
+            }
+            NetworkEvent::LoadingFinished {
+                request_id,
+                timestamp,
+                encoded_data_length,
+            } => {
+                if let Some(record) = self.open(&request_id) {
+                    record.finished = Some(timestamp);
+                    if let Some(response) = &mut record.response {
+                        response.encoded_data_length = encoded_data_length;
+                    }
+                }
+            }
+            NetworkEvent::LoadingFailed {
+                request_id,
+                timestamp,
+                error_text,
+            } => {
+                if let Some(record) = self.open(&request_id) {
+                    record.finished = Some(timestamp);
+                    record.error = Some(error_text);
+                }
+            }
+        }
+    }
+}
+
+/// The page a HAR describes.
+#[derive(Debug, Clone, PartialEq)]
+pub struct HarPage {
+    pub url: String,
+    pub title: Option<String>,
+}
+
+fn rfc3339(wall_time: f64) -> String {
+    chrono::DateTime::from_timestamp_millis((wall_time * 1000.0).round() as i64)
+        .unwrap_or_default()
+        .to_rfc3339_opts(chrono::SecondsFormat::Millis, true)
+}
+
+fn name_values(pairs: &[(String, String)]) -> Value {
+    pairs
+        .iter()
+        .map(|(name, value)| json!({"name": name, "value": value}))
+        .collect()
+}
+
+fn query_string(url: &str) -> Value {
+    url::Url::parse(url)
+        .map(|u| {
+            u.query_pairs()
+                .map(|(name, value)| json!({"name": name, "value": value}))
+                .collect()
+        })
+        .unwrap_or_else(|_| json!([]))
+}
+
+fn milliseconds(from: f64, to: Option<f64>) -> f64 {
+    to.map_or(-1.0, |to| ((to - from) * 1000.0).max(0.0))
+}
+
+/// Render the log as a HAR 1.2 document with a single page. Requests that
+/// never got a response have status 0 and keep Chrome's error text in
+/// `_error`; timings not observed are -1 as the spec requires.
+pub fn to_har(log: &NetworkLog, page: &HarPage, creator_version: &str) -> Value {
+    let started = log
+        .records
+        .first()
+        .map_or_else(|| rfc3339(0.0), |r| rfc3339(r.wall_time));
+    let entries: Vec<Value> = log
+        .records
+        .iter()
+        .map(|record| {
+            let response = record.response.as_ref();
+            let http_version = response
+                .and_then(|r| r.protocol.clone())
+                .unwrap_or_else(|| "HTTP/1.1".to_string());
+            let wait = milliseconds(record.started, record.response_time);
+            let receive = record
+                .response_time
+                .map_or(-1.0, |t| milliseconds(t, record.finished));
+            let total = milliseconds(record.started, record.finished.or(record.response_time));
+            let redirect_url = response
+                .and_then(|r| {
+                    r.headers
+                        .iter()
+                        .find(|(name, _)| name.eq_ignore_ascii_case("location"))
+                })
+                .map_or("", |(_, value)| value.as_str());
+
+            let mut request = json!({
+                "method": record.method,
+                "url": record.url,
+                "httpVersion": http_version,
+                "cookies": [],
+                "headers": name_values(&record.headers),
+                "queryString": query_string(&record.url),
+                "headersSize": -1,
+                "bodySize": record.post_data.as_ref().map_or(0, |d| d.len() as i64),
+            });
+            if let Some(data) = &record.post_data {
+                let mime = record
+                    .headers
+                    .iter()
+                    .find(|(name, _)| name.eq_ignore_ascii_case("content-type"))
+                    .map_or("", |(_, value)| value.as_str());
+                request["postData"] = json!({"mimeType": mime, "text": data});
+            }
+
+            let mut entry = json!({
+                "pageref": "page_1",
+                "startedDateTime": rfc3339(record.wall_time),
+                "time": total.max(0.0),
+                "request": request,
+                "response": {
+                    "status": response.map_or(0, |r| r.status),
+                    "statusText": response.map_or("", |r| r.status_text.as_str()),
+                    "httpVersion": http_version,
+                    "cookies": [],
+                    "headers": response.map_or(json!([]), |r| name_values(&r.headers)),
+                    "content": {
+                        "size": response.map_or(0, |r| r.encoded_data_length as i64),
+                        "mimeType": response.map_or("x-unknown", |r| r.mime_type.as_str()),
+                    },
+                    "redirectURL": redirect_url,
+                    "headersSize": -1,
+                    "bodySize": response.map_or(-1, |r| r.encoded_data_length as i64),
+                },
+                "cache": {},
+                "timings": {"send": 0, "wait": wait, "receive": receive},
+            });
+            if let Some(ip) = response.and_then(|r| r.remote_ip.as_ref()) {
+                entry["serverIPAddress"] = json!(ip);
+            }
+            if let Some(kind) = &record.resource_type {
+                entry["_resourceType"] = json!(kind.to_lowercase());
+            }
+            if let Some(error) = &record.error {
+                entry["_error"] = json!(error);
+            }
+            entry
+        })
+        .collect();
+
+    json!({
+        "log": {
+            "version": "1.2",
+            "creator": {"name": "mcptools", "version": creator_version},
+            "pages": [{
+                "startedDateTime": started,
+                "id": "page_1",
+                "title": page.title.clone().unwrap_or_else(|| page.url.clone()),
+                "pageTimings": {},
+            }],
+            "entries": entries,
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn response(status: u16, headers: Vec<(&str, &str)>) -> NetworkResponse {
+        NetworkResponse {
+            status,
+            status_text: String::new(),
+            protocol: Some("h2".to_string()),
+            headers: headers
+                .into_iter()
+                .map(|(n, v)| (n.to_string(), v.to_string()))
+                .collect(),
+            mime_type: "text/html".to_string(),
+            remote_ip: Some("203.0.113.7".to_string()),
+            encoded_data_length: 100.0,
+        }
+    }
+
+    fn request(
+        id: &str,
+        url: &str,
+        timestamp: f64,
+        redirect: Option<NetworkResponse>,
+    ) -> NetworkEvent {
+        NetworkEvent::RequestWillBeSent {
+            request_id: id.to_string(),
+            url: url.to_string(),
+            method: "GET".to_string(),
+            headers: vec![("Accept".to_string(), "*/*".to_string())],
+            post_data: None,
+            resource_type: Some("Document".to_string()),
+            timestamp,
+            wall_time: 1_700_000_000.0 + timestamp,
+            redirect_response: redirect,
+        }
+    }
+
+    #[test]
+    fn test_network_log_to_har() {
+        let mut log = NetworkLog::new();
+        log.apply(request("1", "http://a.example/?q=1", 10.0, None));
+        log.apply(request(
+            "1",
+            "https://a.example/",
+            10.1,
+            Some(response(301, vec![("Location", "https://a.example/")])),
+        ));
+        log.apply(NetworkEvent::ResponseReceived {
+            request_id: "1".to_string(),
+            timestamp: 10.3,
+            response: response(200, vec![]),
+        });
+        log.apply(NetworkEvent::LoadingFinished {
+            request_id: "1".to_string(),
+            timestamp: 10.4,
+            encoded_data_length: 5120.0,
+        });
+        log.apply(request("2", "https://cdn.example/app.js", 10.5, None));
+        log.apply(NetworkEvent::LoadingFailed {
+            request_id: "2".to_string(),
+            timestamp: 10.6,
+            error_text: "net::ERR_BLOCKED_BY_CLIENT".to_string(),
+        });
+        // Finished before capture started: ignored.
+        log.apply(NetworkEvent::LoadingFinished {
+            request_id: "0".to_string(),
+            timestamp: 9.0,
+            encoded_data_length: 1.0,
+        });
+
+        let har = to_har(
+            &log,
+            &HarPage {
+                url: "https://a.example/".to_string(),
+                title: Some("A".to_string()),
+            },
+            "1.0.0",
+        );
+        let log = &har["log"];
+        assert_eq!(log["version"], "1.2");
+        assert_eq!(log["pages"][0]["title"], "A");
+        assert_eq!(
+            log["pages"][0]["startedDateTime"],
+            "2023-11-14T22:13:30.000Z"
+        );
+
+        let entries = log["entries"].as_array().unwrap();
+        assert_eq!(entries.len(), 3);
+        assert_eq!(entries[0]["response"]["status"], 301);
+        assert_eq!(entries[0]["response"]["redirectURL"], "https://a.example/");
+        assert_eq!(entries[0]["request"]["queryString"][0]["name"], "q");
+
+        let main = &entries[1];
+        assert_eq!(main["response"]["status"], 200);
+        assert_eq!(main["response"]["bodySize"], 5120);
+        assert_eq!(main["response"]["httpVersion"], "h2");
+        assert_eq!(main["serverIPAddress"], "203.0.113.7");
+        assert_eq!(main["_resourceType"], "document");
+        let wait = main["timings"]["wait"].as_f64().unwrap();
+        assert!((wait - 200.0).abs() < 1e-6, "{}", wait);
+        assert!((main["time"].as_f64().unwrap() - 300.0).abs() < 1e-6);
+
+        let failed = &entries[2];
+        assert_eq!(failed["response"]["status"], 0);
+        assert_eq!(failed["_error"], "net::ERR_BLOCKED_BY_CLIENT");
+        assert_eq!(failed["timings"]["wait"], -1.0);
+    }
+}
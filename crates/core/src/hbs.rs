@@ -0,0 +1,430 @@
+//! Sandboxed Handlebars templates over a JSON context
+//!
+//! Templates render with the `handlebars` crate and its built-in helpers
+//! (`if`, `unless`, `each`, `with`, `lookup`, `eq`/`ne`/`gt`/`lt`, `and`/`or`/
+//! `not`, `len`, ...), inline partials, and HTML escaping of `{{...}}`
+//! (`{{{...}}}` renders raw). The registry has no template directory, file
+//! loader, or script helpers, so rendering only reads the context it is
+//! given. Output size, block nesting, and partial recursion are bounded.
+
+use std::collections::HashMap;
+
+use handlebars::template::{Parameter, Template, TemplateElement};
+use handlebars::{Handlebars, RenderError, RenderErrorReason, TemplateError};
+use serde_json::Value;
+
+/// Largest rendered output, in bytes.
+pub const MAX_OUTPUT_LEN: usize = 1024 * 1024;
+
+/// Deepest block and subexpression nesting accepted.
+const MAX_DEPTH: usize = 32;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum HbsError {
+    /// Malformed or unsupported template
+    Syntax(String),
+    /// Strict mode: a referenced value is missing
+    Missing(String),
+    /// A helper or partial failed while rendering
+    Render(String),
+    OutputTooLarge,
+}
+
+impl std::fmt::Display for HbsError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            HbsError::Syntax(message) => write!(f, "Template syntax error: {}", message),
+            HbsError::Missing(path) => write!(f, "Missing value for '{}'", path),
+            HbsError::Render(message) => write!(f, "Template error: {}", message),
+            HbsError::OutputTooLarge => {
+                write!(f, "Rendered output exceeds {} bytes", MAX_OUTPUT_LEN)
+            }
+        }
+    }
+}
+
+impl std::error::Error for HbsError {}
+
+fn at(line: Option<usize>, column: Option<usize>, message: String) -> String {
+    match (line, column) {
+        (Some(line), Some(column)) => format!("line {}, column {}: {}", line, column, message),
+        _ => message,
+    }
+}
+
+fn syntax_error(error: &TemplateError) -> HbsError {
+    let (line, column) = error.pos().unzip();
+    HbsError::Syntax(at(line, column, error.reason().to_string()))
+}
+
+fn render_error(error: RenderError) -> HbsError {
+    match error.reason() {
+        RenderErrorReason::TemplateError(inner) => syntax_error(inner),
+        RenderErrorReason::MissingVariable(path) => {
+            HbsError::Missing(path.clone().unwrap_or_default())
+        }
+        reason => HbsError::Render(at(error.line_no, error.column_no, reason.to_string())),
+    }
+}
+
+/// Collects inline partial calls so recursive partials can be rejected
+/// before rendering; the renderer itself would recurse until the stack
+/// overflows.
+#[derive(Default)]
+struct Scan {
+    /// Partials called from each inline partial body, by partial name
+    calls: HashMap<String, Vec<String>>,
+}
+
+impl Scan {
+    fn template(
+        &mut self,
+        template: &Template,
+        depth: usize,
+        scope: Option<&str>,
+    ) -> Result<(), HbsError> {
+        if depth > MAX_DEPTH {
+            return Err(HbsError::Syntax(format!(
+                "blocks nest deeper than {}",
+                MAX_DEPTH
+            )));
+        }
+        for element in &template.elements {
+            self.element(element, depth, scope)?;
+        }
+        Ok(())
+    }
+
+    fn element(
+        &mut self,
+        element: &TemplateElement,
+        depth: usize,
+        scope: Option<&str>,
+    ) -> Result<(), HbsError> {
+        match element {
+            TemplateElement::Expression(helper)
+            | TemplateElement::HtmlExpression(helper)
+            | TemplateElement::HelperBlock(helper) => {
+                let parameters = std::iter::once(&helper.name)
+                    .chain(&helper.params)
+                    .chain(helper.hash.values());
+                for parameter in parameters {
+                    self.parameter(parameter, depth, scope)?;
+                }
+                for body in helper.template.iter().chain(&helper.inverse) {
+                    self.template(body, depth + 1, scope)?;
+                }
+            }
+            TemplateElement::DecoratorExpression(decorator)
+            | TemplateElement::DecoratorBlock(decorator) => {
+                if decorator.name.as_name() == Some("inline") {
+                    let name = match decorator.params.first() {
+                        Some(Parameter::Literal(Value::String(name))) => name.clone(),
+                        _ => {
+                            return Err(HbsError::Syntax(
+                                "inline partial names must be string literals".to_string(),
+                            ))
+                        }
+                    };
+                    self.calls.entry(name.clone()).or_default();
+                    if let Some(body) = &decorator.template {
+                        self.template(body, depth + 1, Some(&name))?;
+                    }
+                } else if let Some(body) = &decorator.template {
+                    self.template(body, depth + 1, scope)?;
+                }
+            }
+            TemplateElement::PartialExpression(partial)
+            | TemplateElement::PartialBlock(partial) => {
+                let name = partial.name.as_name().map(str::to_string).ok_or_else(|| {
+                    HbsError::Syntax("partial names must be written out, not computed".to_string())
+                })?;
+                if let Some(scope) = scope {
+                    self.calls.entry(scope.to_string()).or_default().push(name);
+                }
+                if let Some(body) = &partial.template {
+                    self.template(body, depth + 1, scope)?;
+                }
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
+    fn parameter(
+        &mut self,
+        parameter: &Parameter,
+        depth: usize,
+        scope: Option<&str>,
+    ) -> Result<(), HbsError> {
+        match parameter {
+            Parameter::Subexpression(subexpression) => {
+                if depth + 1 > MAX_DEPTH {
+                    return Err(HbsError::Syntax(format!(
+                        "subexpressions nest deeper than {}",
+                        MAX_DEPTH
+                    )));
+                }
+                self.element(&subexpression.element, depth + 1, scope)
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// The first inline partial that can reach itself through partial calls.
+    fn recursive_partial(&self) -> Option<&str> {
+        let mut names: Vec<&String> = self.calls.keys().collect();
+        names.sort();
+        names.into_iter().map(String::as_str).find(|start| {
+            let mut stack = vec![*start];
+            let mut seen = std::collections::HashSet::new();
+            while let Some(name) = stack.pop() {
+                for callee in self.calls.get(name).into_iter().flatten() {
+                    if callee == start {
+                        return true;
+                    }
+                    if seen.insert(callee.as_str()) {
+                        stack.push(callee);
+                    }
+                }
+            }
+            false
+        })
+    }
+}
+
+/// Validate a template without rendering it.
+pub fn check_template(template: &str) -> Result<(), HbsError> {
+    let compiled = Template::compile(template).map_err(|e| syntax_error(&e))?;
+    let mut scan = Scan::default();
+    scan.template(&compiled, 0, None)?;
+    match scan.recursive_partial() {
+        Some(name) => Err(HbsError::Syntax(format!(
+            "partial '{}' includes itself",
+            name
+        ))),
+        None => Ok(()),
+    }
+}
+
+/// Writer that refuses to grow past [`MAX_OUTPUT_LEN`].
+#[derive(Default)]
+struct CappedOutput {
+    buffer: Vec<u8>,
+    overflowed: bool,
+}
+
+impl std::io::Write for CappedOutput {
+    fn write(&mut self, data: &[u8]) -> std::io::Result<usize> {
+        if self.buffer.len() + data.len() > MAX_OUTPUT_LEN {
+            self.overflowed = true;
+            return Err(std::io::Error::other("output too large"));
+        }
+        self.buffer.extend_from_slice(data);
+        Ok(data.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+/// Render `template` against `context`. Missing values render as empty
+/// text unless `strict`, in which case they are an error.
+pub fn render_template(template: &str, context: &Value, strict: bool) -> Result<String, HbsError> {
+    check_template(template)?;
+
+    let mut registry = Handlebars::new();
+    registry.set_strict_mode(strict);
+
+    let mut output = CappedOutput::default();
+    match registry.render_template_to_write(template, context, &mut output) {
+        Ok(()) => {}
+        Err(_) if output.overflowed => return Err(HbsError::OutputTooLarge),
+        Err(e) => return Err(render_error(e)),
+    }
+    String::from_utf8(output.buffer).map_err(|e| HbsError::Render(e.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn render(template: &str, context: Value) -> String {
+        render_template(template, &context, false).unwrap()
+    }
+
+    #[test]
+    fn test_values_and_paths() {
+        let context = json!({"name": "Ada", "stats": {"count": 3, "ok": true}, "tags": ["a", "b"]});
+        assert_eq!(
+            render(
+                "{{name}}: {{stats.count}} {{stats.ok}} {{tags.1}} {{{name}}} [{{missing}}]",
+                context
+            ),
+            "Ada: 3 true b Ada []"
+        );
+    }
+
+    #[test]
+    fn test_html_escaping() {
+        let context = json!({"html": "<b>&</b>"});
+        assert_eq!(
+            render("{{html}} {{{html}}}", context),
+            "&lt;b&gt;&amp;&lt;/b&gt; <b>&</b>"
+        );
+    }
+
+    #[test]
+    fn test_each_with_data_and_parent() {
+        let context = json!({"sep": ", ", "items": [{"n": "x"}, {"n": "y"}, {"n": "z"}]});
+        assert_eq!(
+            render(
+                "{{#each items}}{{@index}}={{n}}{{#unless @last}}{{../sep}}{{/unless}}{{/each}}",
+                context
+            ),
+            "0=x, 1=y, 2=z"
+        );
+        assert_eq!(
+            render(
+                "{{#each m}}{{@key}}:{{this}};{{/each}}",
+                json!({"m": {"a": 1, "b": 2}})
+            ),
+            "a:1;b:2;"
+        );
+        assert_eq!(
+            render("{{#each xs}}x{{else}}none{{/each}}", json!({"xs": []})),
+            "none"
+        );
+    }
+
+    #[test]
+    fn test_if_unless_with_truthiness() {
+        let template = "{{#if v}}yes{{else}}no{{/if}}";
+        for (value, expected) in [
+            (json!(0), "no"),
+            (json!(""), "no"),
+            (json!([]), "no"),
+            (json!(null), "no"),
+            (json!({}), "no"),
+            (json!({"k": 0}), "yes"),
+            (json!("x"), "yes"),
+            (json!(2), "yes"),
+        ] {
+            assert_eq!(render(template, json!({"v": value})), expected);
+        }
+        assert_eq!(
+            render(
+                "{{#with user}}{{name}} of {{../org}}{{/with}}",
+                json!({"user": {"name": "Ada"}, "org": "ACME"})
+            ),
+            "Ada of ACME"
+        );
+    }
+
+    #[test]
+    fn test_builtin_helpers() {
+        let context =
+            json!({"status": "done", "items": [1, 2, 3], "names": {"a": "Ada"}, "key": "a"});
+        assert_eq!(
+            render(
+                "{{#if (eq status \"done\")}}ok{{/if}} {{len items}} {{lookup names key}} {{#if (and (gt (len items) 2) (not false))}}many{{/if}}",
+                context
+            ),
+            "ok 3 Ada many"
+        );
+    }
+
+    #[test]
+    fn test_inline_partials() {
+        let template =
+            "{{#*inline \"row\"}}- {{name}}\n{{/inline}}{{#each people}}{{> row}}{{/each}}";
+        assert_eq!(
+            render(
+                template,
+                json!({"people": [{"name": "Ada"}, {"name": "Bob"}]})
+            ),
+            "- Ada\n- Bob\n"
+        );
+        assert!(matches!(
+            render_template("{{> nope}}", &json!({}), false),
+            Err(HbsError::Render(_))
+        ));
+    }
+
+    #[test]
+    fn test_standalone_lines_and_whitespace_control() {
+        let template = "Items:\n{{#each items}}\n- {{this}}\n{{/each}}\nDone {{! note }}\n";
+        assert_eq!(
+            render(template, json!({"items": ["a", "b"]})),
+            "Items:\n- a\n- b\nDone \n"
+        );
+        assert_eq!(
+            render("a  {{~x~}}  b \\{{y}}", json!({"x": "-"})),
+            "a-b {{y}}"
+        );
+        assert_eq!(render("{{!-- has }} inside --}}ok", json!({})), "ok");
+
+        let nested =
+            "{{#each xs}}\n  {{#if this}}\n- {{this}}\n  {{else}}\n- none\n  {{/if}}\n{{/each}}\n";
+        assert_eq!(
+            render(nested, json!({"xs": ["a", "", "b"]})),
+            "- a\n- none\n- b\n"
+        );
+    }
+
+    #[test]
+    fn test_strict_mode() {
+        let err = render_template("{{a.b}}", &json!({"a": {}}), true).unwrap_err();
+        assert_eq!(err, HbsError::Missing("a.b".to_string()));
+        assert!(render_template("{{a}}", &json!({"a": null}), true).is_ok());
+    }
+
+    #[test]
+    fn test_syntax_errors() {
+        for template in [
+            "{{#if x}}",
+            "{{/if}}",
+            "{{#if x}}{{/each}}",
+            "{{x",
+            "{{> (dynamic)}}",
+            "{{#*inline name}}x{{/inline}}",
+        ] {
+            assert!(
+                matches!(check_template(template), Err(HbsError::Syntax(..))),
+                "{}",
+                template
+            );
+        }
+    }
+
+    #[test]
+    fn test_limits() {
+        let nested = "{{#if x}}".repeat(MAX_DEPTH + 1) + &"{{/if}}".repeat(MAX_DEPTH + 1);
+        assert!(matches!(check_template(&nested), Err(HbsError::Syntax(..))));
+        let nested = format!(
+            "{{{{len {}x{}}}}}",
+            "(len ".repeat(MAX_DEPTH + 1),
+            ")".repeat(MAX_DEPTH + 1)
+        );
+        assert!(matches!(check_template(&nested), Err(HbsError::Syntax(..))));
+
+        for template in [
+            "{{#*inline \"a\"}}x{{> a}}{{/inline}}{{> a}}",
+            "{{#*inline \"a\"}}{{> b}}{{/inline}}{{#*inline \"b\"}}{{#if x}}{{> a}}{{/if}}{{/inline}}{{> a}}",
+        ] {
+            assert_eq!(
+                render_template(template, &json!({"x": true}), false),
+                Err(HbsError::Syntax("partial 'a' includes itself".to_string()))
+            );
+        }
+
+        let context = json!({"xs": vec![0; 2000]});
+        let template = format!("{{{{#each xs}}}}{}{{{{/each}}}}", "x".repeat(1000));
+        assert_eq!(
+            render_template(&template, &context, false),
+            Err(HbsError::OutputTooLarge)
+        );
+    }
+}
@@ -19,6 +19,8 @@ pub struct HnItem {
     pub score: Option<u64>,
     pub title: Option<String>,
     pub descendants: Option<u64>,
+    /// Poll option IDs, in display order (polls only).
+    pub parts: Option<Vec<u64>>,
 }
 
 /// Individual list item output
@@ -42,6 +44,10 @@ pub struct ListPaginationInfo {
     pub limit: usize,
     pub next_page_command: Option<String>,
     pub prev_page_command: Option<String>,
+    /// Opaque token for the next page that stays valid as the list shifts;
+    /// absent on the last page
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next_cursor: Option<String>,
 }
 
 /// Complete list output with items and pagination
@@ -64,9 +70,61 @@ pub struct PostOutput {
     pub text: Option<String>,
     pub total_comments: Option<u64>,
     pub comments: Vec<CommentOutput>,
+    /// Dead or deleted comments on this page left out of `comments`.
+    pub hidden_comments: usize,
+    /// Options of a poll, in display order; empty for other posts.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub poll_options: Vec<PollOption>,
+    /// The linked article, when requested
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub article: Option<PostArticle>,
     pub pagination: PaginationInfo,
 }
 
+/// A story's linked article converted to Markdown by the md fetch pipeline
+#[derive(Debug, Serialize, Clone)]
+pub struct PostArticle {
+    pub url: String,
+    pub title: Option<String>,
+    /// Requested page of the converted Markdown; absent when the fetch failed
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub content: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pagination: Option<crate::md::MdPaginationInfo>,
+    /// Why the article could not be fetched; the post is still returned
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+impl PostArticle {
+    pub fn from_fetch(url: &str, result: Result<crate::md::FetchOutput, String>) -> Self {
+        match result {
+            Ok(fetched) => Self {
+                url: url.to_string(),
+                title: fetched.title,
+                content: Some(fetched.content),
+                pagination: Some(fetched.pagination),
+                error: None,
+            },
+            Err(error) => Self {
+                url: url.to_string(),
+                title: None,
+                content: None,
+                pagination: None,
+                error: Some(error),
+            },
+        }
+    }
+}
+
+/// One option of a poll with its current vote count
+#[derive(Debug, Serialize, Clone)]
+pub struct PollOption {
+    pub id: u64,
+    pub text: Option<String>,
+    pub score: Option<u64>,
+}
+
 /// Individual comment output
 #[derive(Debug, Serialize, Clone)]
 pub struct CommentOutput {
@@ -74,7 +132,15 @@ pub struct CommentOutput {
     pub author: Option<String>,
     pub time: Option<String>,
     pub text: Option<String>,
+    /// Direct replies; dead and deleted ones are left out of the count
+    /// wherever the replies were fetched
     pub replies_count: usize,
+    /// Deleted by its author; `text` is a placeholder
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub deleted: bool,
+    /// Killed by moderators or flags
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub dead: bool,
 }
 
 /// Threaded comment output with nested replies
@@ -88,6 +154,29 @@ pub struct ThreadedCommentOutput {
     pub depth: usize,
 }
 
+/// A reply in a comment thread, with its depth below the thread root
+#[derive(Debug, Serialize, Clone)]
+pub struct ThreadReply {
+    #[serde(flatten)]
+    pub comment: CommentOutput,
+    /// 1 for direct replies to the thread root
+    pub depth: usize,
+}
+
+/// A comment thread: the root comment, one page of its replies, and
+/// pagination over the root's direct replies
+#[derive(Debug, Serialize, Clone)]
+pub struct ThreadOutput {
+    pub comment: CommentOutput,
+    pub replies: Vec<ThreadReply>,
+    /// Dead or deleted replies on this page left out of `replies`.
+    pub hidden_replies: usize,
+    /// Deepest reply level included; replies below it are left out
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_depth: Option<usize>,
+    pub pagination: PaginationInfo,
+}
+
 /// Pagination metadata for post reading
 #[derive(Debug, Serialize, Clone)]
 pub struct PaginationInfo {
@@ -97,6 +186,10 @@ pub struct PaginationInfo {
     pub limit: usize,
     pub next_page_command: Option<String>,
     pub prev_page_command: Option<String>,
+    /// Opaque token for the next page that stays valid as the list shifts;
+    /// absent on the last page
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next_cursor: Option<String>,
 }
 
 /// Convert Unix timestamp to formatted string
@@ -133,6 +226,199 @@ pub fn calculate_pagination(
     Ok((start, end))
 }
 
+/// Order of a filtered story list
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ListSort {
+    /// HN's own ranking for the list (default)
+    #[default]
+    #[serde(alias = "relevance")]
+    Rank,
+    /// Newest first
+    Date,
+    /// Most points first
+    Points,
+}
+
+impl ListSort {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ListSort::Rank => "rank",
+            ListSort::Date => "date",
+            ListSort::Points => "points",
+        }
+    }
+}
+
+/// Parse a `--since` / `--until` bound into Unix seconds
+///
+/// Accepts a duration back from `now` (`30m`, `24h`, `7d`, `2w`), a date
+/// (`2024-05-01`, midnight UTC), or an RFC 3339 timestamp.
+pub fn parse_time_bound(value: &str, now: i64) -> Result<i64, String> {
+    let value = value.trim();
+    let invalid = || {
+        format!(
+            "Invalid time '{}': use a duration like 24h or 7d, a date like 2024-05-01, or an RFC 3339 timestamp",
+            value
+        )
+    };
+    if let Some(unit) = value.chars().last().filter(|c| c.is_ascii_alphabetic()) {
+        if let Ok(n) = value[..value.len() - 1].parse::<i64>() {
+            let secs = match unit {
+                'm' => 60,
+                'h' => 60 * 60,
+                'd' => 24 * 60 * 60,
+                'w' => 7 * 24 * 60 * 60,
+                _ => return Err(invalid()),
+            };
+            return Ok(now - n * secs);
+        }
+    }
+    if let Ok(date) = chrono::NaiveDate::parse_from_str(value, "%Y-%m-%d") {
+        return Ok(date
+            .and_hms_opt(0, 0, 0)
+            .ok_or_else(invalid)?
+            .and_utc()
+            .timestamp());
+    }
+    DateTime::parse_from_rfc3339(value)
+        .map(|t| t.timestamp())
+        .map_err(|_| invalid())
+}
+
+/// Client-side filters and ordering for story lists, applied after fetching
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ListFilters {
+    /// Keep stories with at least this many points
+    pub min_score: Option<u64>,
+    /// Keep stories linking to this host or one of its subdomains
+    pub domain: Option<String>,
+    /// Keep stories submitted by this user (case-insensitive)
+    pub author: Option<String>,
+    /// Keep stories whose title or text contains this (case-insensitive)
+    pub keyword: Option<String>,
+    /// Keep stories posted at or after this time (Unix seconds)
+    pub since: Option<i64>,
+    /// Keep stories posted before this time (Unix seconds)
+    pub until: Option<i64>,
+    pub sort: ListSort,
+}
+
+impl ListFilters {
+    pub fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
+
+    /// The filters as `hn list` flags, with a leading space, for building
+    /// page navigation commands. Empty when no filter is set.
+    pub fn command_args(&self) -> String {
+        let quote = |value: &str| {
+            if value.contains(char::is_whitespace) || value.contains('"') {
+                format!("\"{}\"", value.replace('"', "\\\""))
+            } else {
+                value.to_string()
+            }
+        };
+        let mut args = String::new();
+        if let Some(min_score) = self.min_score {
+            args.push_str(&format!(" --min-score {min_score}"));
+        }
+        if let Some(domain) = &self.domain {
+            args.push_str(&format!(" --domain {}", quote(domain)));
+        }
+        if let Some(author) = &self.author {
+            args.push_str(&format!(" --author {}", quote(author)));
+        }
+        if let Some(keyword) = &self.keyword {
+            args.push_str(&format!(" --keyword {}", quote(keyword)));
+        }
+        // Relative bounds are pinned so every page sees the same window.
+        let bound = |secs: i64| {
+            DateTime::<Utc>::from_timestamp(secs, 0)
+                .map(|t| t.format("%Y-%m-%dT%H:%M:%SZ").to_string())
+                .unwrap_or_default()
+        };
+        if let Some(since) = self.since {
+            args.push_str(&format!(" --since {}", bound(since)));
+        }
+        if let Some(until) = self.until {
+            args.push_str(&format!(" --until {}", bound(until)));
+        }
+        if self.sort != ListSort::Rank {
+            args.push_str(&format!(" --sort {}", self.sort.as_str()));
+        }
+        args
+    }
+}
+
+/// Host of `url` in lowercase without a leading `www.`.
+fn url_domain(url: &str) -> Option<String> {
+    let host = url::Url::parse(url).ok()?.host_str()?.to_lowercase();
+    Some(host.strip_prefix("www.").unwrap_or(&host).to_string())
+}
+
+/// Keep the items matching every filter in `filters`, ordered by its `sort`
+///
+/// The domain filter matches the story URL's host or any subdomain of it, so
+/// `github.com` also keeps `gist.github.com`; text posts have no domain.
+/// Stories without a time never match a time bound. Sorting is stable, so
+/// ties keep HN's ranking.
+pub fn filter_hn_items(items: Vec<HnItem>, filters: &ListFilters) -> Vec<HnItem> {
+    let domain = filters.domain.as_deref().map(|d| {
+        let d = d.trim().to_lowercase();
+        d.strip_prefix("www.").unwrap_or(&d).to_string()
+    });
+    let keyword = filters.keyword.as_deref().map(str::to_lowercase);
+
+    let mut items: Vec<HnItem> = items
+        .into_iter()
+        .filter(|item| {
+            filters
+                .min_score
+                .is_none_or(|min| item.score.unwrap_or(0) >= min)
+        })
+        .filter(|item| {
+            domain.as_deref().is_none_or(|domain| {
+                item.url
+                    .as_deref()
+                    .and_then(url_domain)
+                    .is_some_and(|host| host == domain || host.ends_with(&format!(".{domain}")))
+            })
+        })
+        .filter(|item| {
+            filters.author.as_deref().is_none_or(|author| {
+                item.by
+                    .as_deref()
+                    .is_some_and(|by| by.eq_ignore_ascii_case(author))
+            })
+        })
+        .filter(|item| {
+            keyword.as_deref().is_none_or(|keyword| {
+                [item.title.as_deref(), item.text.as_deref()]
+                    .into_iter()
+                    .flatten()
+                    .any(|text| strip_html(text).to_lowercase().contains(keyword))
+            })
+        })
+        .filter(|item| {
+            let time = item.time.map(|t| t as i64);
+            filters
+                .since
+                .is_none_or(|since| time.is_some_and(|t| t >= since))
+                && filters
+                    .until
+                    .is_none_or(|until| time.is_some_and(|t| t < until))
+        })
+        .collect();
+
+    match filters.sort {
+        ListSort::Rank => {}
+        ListSort::Date => items.sort_by_key(|item| std::cmp::Reverse(item.time.unwrap_or(0))),
+        ListSort::Points => items.sort_by_key(|item| std::cmp::Reverse(item.score.unwrap_or(0))),
+    }
+    items
+}
+
 /// Transform HackerNews items into list output with pagination
 ///
 /// Takes raw HN API items and constructs a complete ListOutput with:
@@ -191,6 +477,7 @@ pub fn transform_hn_items(
             limit,
             next_page_command: next_page,
             prev_page_command: prev_page,
+            next_cursor: None,
         },
     }
 }
@@ -212,21 +499,121 @@ pub fn strip_html(text: &str) -> String {
         .replace("<p>", "\n")
 }
 
+/// Convert an HN item body to Markdown
+///
+/// HN bodies use a small subset of HTML: `<p>` between paragraphs, `<i>`,
+/// `<a href>`, and `<pre><code>` for code. These become blank lines,
+/// `*emphasis*`, links (`<url>` when the label is just the URL, possibly
+/// shortened with `...`), and fenced code blocks kept verbatim. Other tags
+/// are dropped and entities decoded; `&gt;` quote lines become Markdown
+/// block quotes as a side effect.
+pub fn html_to_markdown(html: &str) -> String {
+    let code_re = Regex::new(r"(?s)<pre><code>(.*?)</code></pre>").unwrap();
+    let mut blocks: Vec<String> = Vec::new();
+    fn push_paragraphs(html: &str, blocks: &mut Vec<String>) {
+        blocks.extend(
+            html.split("<p>")
+                .map(|paragraph| inline_html_to_markdown(paragraph).trim().to_string())
+                .filter(|paragraph| !paragraph.is_empty()),
+        );
+    }
+
+    let mut last = 0;
+    for caps in code_re.captures_iter(html) {
+        let whole = caps.get(0).unwrap();
+        push_paragraphs(&html[last..whole.start()], &mut blocks);
+        let code = decode_html_entities(&caps[1]);
+        blocks.push(format!("```\n{}\n```", code.trim_end_matches('\n')));
+        last = whole.end();
+    }
+    push_paragraphs(&html[last..], &mut blocks);
+
+    blocks.join("\n\n")
+}
+
+/// Links and italics of one paragraph to Markdown, other tags dropped
+fn inline_html_to_markdown(html: &str) -> String {
+    let other_tag_re = Regex::new(r"<(/?)([a-zA-Z][a-zA-Z0-9]*)\b[^>]*>").unwrap();
+    let link_re = Regex::new(r#"(?s)<a\s[^>]*?href="([^"]*)"[^>]*>(.*?)</a>"#).unwrap();
+    let italic_re = Regex::new(r"(?s)<i>(.*?)</i>").unwrap();
+    let tag_re = Regex::new(r"<[^>]*>").unwrap();
+
+    // Drop everything but links and italics first, so the `<url>` links
+    // produced below aren't mistaken for tags
+    let text = other_tag_re.replace_all(html, |caps: &regex::Captures| {
+        match caps[2].to_ascii_lowercase().as_str() {
+            "a" | "i" => caps[0].to_string(),
+            _ => String::new(),
+        }
+    });
+    let text = link_re.replace_all(&text, |caps: &regex::Captures| {
+        let href = &caps[1];
+        let label = tag_re.replace_all(&caps[2], "");
+        let shortened = label
+            .strip_suffix("...")
+            .is_some_and(|prefix| href.starts_with(prefix));
+        if label == href || shortened {
+            format!("<{}>", href)
+        } else {
+            format!("[{}]({})", label, href)
+        }
+    });
+    let text = italic_re.replace_all(&text, |caps: &regex::Captures| {
+        let inner = caps[1].trim();
+        if inner.is_empty() {
+            String::new()
+        } else {
+            format!("*{}*", inner)
+        }
+    });
+    decode_html_entities(&text)
+}
+
+/// Decode named and numeric HTML entities in one pass
+fn decode_html_entities(text: &str) -> String {
+    let entity_re = Regex::new(r"&(#[xX][0-9a-fA-F]+|#[0-9]+|amp|lt|gt|quot|apos|nbsp);").unwrap();
+    entity_re
+        .replace_all(text, |caps: &regex::Captures| {
+            let entity = &caps[1];
+            let decoded = match entity {
+                "amp" => Some('&'),
+                "lt" => Some('<'),
+                "gt" => Some('>'),
+                "quot" => Some('"'),
+                "apos" => Some('\''),
+                "nbsp" => Some(' '),
+                _ => entity
+                    .strip_prefix("#x")
+                    .or_else(|| entity.strip_prefix("#X"))
+                    .map(|hex| u32::from_str_radix(hex, 16))
+                    .unwrap_or_else(|| entity[1..].parse())
+                    .ok()
+                    .and_then(char::from_u32),
+            };
+            decoded.map_or_else(|| caps[0].to_string(), String::from)
+        })
+        .into_owned()
+}
+
 /// Transform HN items to comment outputs
 ///
 /// Converts raw HN API items into structured comment outputs with
 /// formatted timestamps and cleaned text.
 pub fn transform_comments(comments: Vec<HnItem>) -> Vec<CommentOutput> {
-    comments
-        .iter()
-        .map(|c| CommentOutput {
-            id: c.id,
-            author: c.by.clone(),
-            time: format_timestamp(c.time),
-            text: c.text.as_ref().map(|t| strip_html(t)),
-            replies_count: c.kids.as_ref().map(|k| k.len()).unwrap_or(0),
-        })
-        .collect()
+    comments.iter().map(comment_output).collect()
+}
+
+/// Output form of a single comment, with HTML stripped
+pub fn comment_output(c: &HnItem) -> CommentOutput {
+    CommentOutput {
+        id: c.id,
+        author: c.by.clone(),
+        time: format_timestamp(c.time),
+        text: c.text.as_deref().map(html_to_markdown),
+        replies_count: c.kids.as_ref().map(|k| k.len()).unwrap_or(0),
+        deleted: is_deleted(c),
+        dead: is_dead(c),
+    }
 }
 
 /// Build post output with pagination metadata
@@ -261,9 +648,12 @@ pub fn build_post_output(
         author: item.by.clone(),
         score: item.score,
         time: format_timestamp(item.time),
-        text: item.text.as_ref().map(|t| strip_html(t)),
+        text: item.text.as_deref().map(html_to_markdown),
         total_comments: item.descendants,
         comments,
+        hidden_comments: 0,
+        poll_options: Vec::new(),
+        article: None,
         pagination: PaginationInfo {
             current_page: page,
             total_pages,
@@ -271,10 +661,205 @@ pub fn build_post_output(
             limit,
             next_page_command: next_page,
             prev_page_command: prev_page,
+            next_cursor: None,
+        },
+    }
+}
+
+/// Build a comment thread from its root and one page of replies
+///
+/// `replies` are the fetched replies below the root's direct replies
+/// `page_kids`, ordered depth-first (see [`order_thread_items`]), and are
+/// filtered with [`apply_dead_filter`]; hidden replies are left out of their
+/// parents' `replies_count`. The pagination counts the root's
+/// direct replies, `limit` per page, and its navigation commands keep
+/// `max_depth` and the thread limit.
+#[allow(clippy::too_many_arguments)]
+pub fn build_thread_output(
+    post_id: u64,
+    root: &HnItem,
+    page_kids: &[u64],
+    replies: Vec<HnItem>,
+    show_dead: bool,
+    page: usize,
+    limit: usize,
+    max_depth: Option<usize>,
+) -> ThreadOutput {
+    use std::collections::{HashMap, HashSet};
+
+    let mut depths: HashMap<u64, usize> = page_kids.iter().map(|id| (*id, 1)).collect();
+    for item in &replies {
+        if let Some(depth) = depths.get(&item.id).copied() {
+            for kid in item.kids.iter().flatten() {
+                depths.insert(*kid, depth + 1);
+            }
+        }
+    }
+    let fetched: Vec<u64> = replies.iter().map(|item| item.id).collect();
+    let (replies, hidden_replies) = apply_dead_filter(replies, show_dead);
+    let visible: HashSet<u64> = replies.iter().map(|item| item.id).collect();
+    let hidden: HashSet<u64> = fetched
+        .into_iter()
+        .filter(|id| !visible.contains(id))
+        .collect();
+    let replies = replies
+        .iter()
+        .filter_map(|item| {
+            let mut comment = comment_output(item);
+            comment.replies_count = item
+                .kids
+                .iter()
+                .flatten()
+                .filter(|kid| !hidden.contains(kid))
+                .count();
+            Some(ThreadReply {
+                comment,
+                depth: *depths.get(&item.id)?,
+            })
+        })
+        .collect();
+
+    let total_replies = root.kids.as_ref().map_or(0, Vec::len);
+    let total_pages = total_replies.div_ceil(limit.max(1));
+    let command = |page: usize| {
+        let mut command = format!(
+            "mcptools hn read {} --thread {} --thread-page {} --thread-limit {}",
+            post_id, root.id, page, limit
+        );
+        if let Some(depth) = max_depth {
+            command.push_str(&format!(" --depth {}", depth));
+        }
+        command
+    };
+
+    ThreadOutput {
+        comment: comment_output(root),
+        replies,
+        hidden_replies,
+        max_depth,
+        pagination: PaginationInfo {
+            current_page: page,
+            total_pages,
+            total_comments: total_replies,
+            limit,
+            next_page_command: (page < total_pages).then(|| command(page + 1)),
+            prev_page_command: (page > 1).then(|| command(page - 1)),
+            next_cursor: None,
         },
     }
 }
 
+/// Whether `hn read` can show the item as a post: stories and polls.
+pub fn is_readable_post(item: &HnItem) -> bool {
+    matches!(item.item_type.as_str(), "story" | "poll")
+}
+
+/// Transform fetched `pollopt` items into poll options
+///
+/// Keeps the order of `parts` and skips anything that is not a live
+/// `pollopt`.
+pub fn build_poll_options(parts: Vec<HnItem>) -> Vec<PollOption> {
+    parts
+        .into_iter()
+        .filter(|part| part.item_type == "pollopt" && !is_deleted(part) && !is_dead(part))
+        .map(|part| PollOption {
+            id: part.id,
+            text: part.text.as_ref().map(|t| strip_html(t)),
+            score: part.score,
+        })
+        .collect()
+}
+
+/// Text shown in place of a deleted item.
+pub const DELETED_PLACEHOLDER: &str = "[deleted]";
+
+/// Text shown in place of (or in front of) a dead item.
+pub const DEAD_PLACEHOLDER: &str = "[dead]";
+
+/// Whether the item was deleted by its author.
+pub fn is_deleted(item: &HnItem) -> bool {
+    item.deleted.unwrap_or(false)
+}
+
+/// Whether the item was killed by moderators or flags.
+pub fn is_dead(item: &HnItem) -> bool {
+    item.dead.unwrap_or(false)
+}
+
+/// Apply dead/deleted visibility rules to a list of items.
+///
+/// The API returns deleted items with no author or text and dead items with
+/// their original content. This keeps the output readable:
+///
+/// - Deleted items get [`DELETED_PLACEHOLDER`] as their text.
+/// - Dead items are prefixed with [`DEAD_PLACEHOLDER`] when `show_dead` is
+///   set, and reduced to the bare placeholder otherwise.
+/// - Unless `show_dead` is set, dead or deleted items without replies are
+///   dropped entirely; ones with replies stay as placeholders so their
+///   children still have a parent.
+///
+/// Returns the visible items and the number of items dropped.
+pub fn apply_dead_filter(items: Vec<HnItem>, show_dead: bool) -> (Vec<HnItem>, usize) {
+    let mut hidden = 0;
+    let visible = items
+        .into_iter()
+        .filter_map(|mut item| {
+            let deleted = is_deleted(&item);
+            let dead = is_dead(&item);
+            if !deleted && !dead {
+                return Some(item);
+            }
+
+            let has_replies = item.kids.as_ref().is_some_and(|k| !k.is_empty());
+            if !show_dead && !has_replies {
+                hidden += 1;
+                return None;
+            }
+
+            if deleted {
+                item.by = None;
+                item.text = Some(DELETED_PLACEHOLDER.to_string());
+            } else if show_dead {
+                item.text = Some(match item.text.take() {
+                    Some(text) => format!("{DEAD_PLACEHOLDER} {text}"),
+                    None => DEAD_PLACEHOLDER.to_string(),
+                });
+            } else {
+                item.by = None;
+                item.text = Some(DEAD_PLACEHOLDER.to_string());
+            }
+            Some(item)
+        })
+        .collect();
+    (visible, hidden)
+}
+
+/// Order fetched thread items depth-first, the way they appear on HN
+///
+/// Items may arrive in any order (e.g. fetched level by level); this walks
+/// `root_kids` and each item's `kids` in their original order, so every
+/// comment is followed by its replies before its next sibling. Items not
+/// reachable from `root_kids` are dropped.
+pub fn order_thread_items(root_kids: &[u64], items: Vec<HnItem>) -> Vec<HnItem> {
+    use std::collections::HashMap;
+
+    let mut by_id: HashMap<u64, HnItem> = items.into_iter().map(|item| (item.id, item)).collect();
+    let mut ordered = Vec::with_capacity(by_id.len());
+    let mut stack: Vec<u64> = root_kids.iter().rev().copied().collect();
+
+    while let Some(id) = stack.pop() {
+        let Some(item) = by_id.remove(&id) else {
+            continue;
+        };
+        if let Some(kids) = &item.kids {
+            stack.extend(kids.iter().rev());
+        }
+        ordered.push(item);
+    }
+
+    ordered
+}
+
 /// Build a threaded comment tree from a flat list of HnItems
 ///
 /// Takes a flat list of comments and organizes them into a hierarchical tree
@@ -326,7 +911,7 @@ pub fn build_comment_tree(items: Vec<HnItem>, depth: usize) -> Vec<ThreadedComme
             id: item.id,
             author: item.by.clone(),
             time: format_timestamp(item.time),
-            text: item.text.as_ref().map(|t| strip_html(t)),
+            text: item.text.as_deref().map(html_to_markdown),
             replies,
             depth: current_depth,
         })
@@ -378,6 +963,939 @@ pub fn count_tree_comments(tree: &[ThreadedCommentOutput]) -> usize {
         .sum()
 }
 
+/// HackerNews user from the `/user/<id>.json` API
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct HnUser {
+    pub id: String,
+    pub created: u64,
+    pub karma: u64,
+    pub about: Option<String>,
+    pub submitted: Option<Vec<u64>>,
+}
+
+/// One of a user's recent submissions, resolved from its item
+#[derive(Debug, Serialize, Clone)]
+pub struct UserSubmission {
+    pub id: u64,
+    #[serde(rename = "type")]
+    pub item_type: String,
+    /// Story, job, or poll title
+    pub title: Option<String>,
+    pub url: Option<String>,
+    /// Plain-text comment excerpt
+    pub text: Option<String>,
+    pub score: Option<u64>,
+    pub time: Option<String>,
+}
+
+/// User profile output
+#[derive(Debug, Serialize, Clone)]
+pub struct UserOutput {
+    pub id: String,
+    pub karma: u64,
+    pub created: Option<String>,
+    /// About text with HTML stripped
+    pub about: Option<String>,
+    pub total_submissions: usize,
+    pub recent_submissions: Vec<UserSubmission>,
+}
+
+/// Length of the comment excerpts in [`UserSubmission::text`].
+pub const SUBMISSION_EXCERPT_CHARS: usize = 200;
+
+/// Plain-text excerpt of HTML item text, cut to [`SUBMISSION_EXCERPT_CHARS`]
+fn text_excerpt(html: &str) -> String {
+    let text = strip_html(&html.replace("<p>", " "));
+    match text.char_indices().nth(SUBMISSION_EXCERPT_CHARS) {
+        Some((end, _)) => format!("{}...", text[..end].trim_end()),
+        None => text,
+    }
+}
+
+/// Build a user profile from the API user and their fetched recent items
+///
+/// `items` are the user's most recent submissions in `submitted` order;
+/// deleted and dead ones are skipped. Paragraph breaks in the about text
+/// are kept as blank lines.
+pub fn build_user_output(user: HnUser, items: Vec<HnItem>) -> UserOutput {
+    let about = user
+        .about
+        .as_deref()
+        .map(|about| strip_html(&about.replace("<p>", "\n\n")).trim().to_string())
+        .filter(|about| !about.is_empty());
+
+    let recent_submissions = items
+        .into_iter()
+        .filter(|item| !is_deleted(item) && !is_dead(item))
+        .map(|item| UserSubmission {
+            id: item.id,
+            text: item
+                .text
+                .as_deref()
+                .filter(|_| item.title.is_none())
+                .map(text_excerpt),
+            item_type: item.item_type,
+            title: item.title,
+            url: item.url,
+            score: item.score,
+            time: format_timestamp(item.time),
+        })
+        .collect();
+
+    UserOutput {
+        id: user.id,
+        karma: user.karma,
+        created: format_timestamp(Some(user.created)),
+        about,
+        total_submissions: user.submitted.as_ref().map_or(0, Vec::len),
+        recent_submissions,
+    }
+}
+
+/// HN discussion page of an item
+pub fn item_page_url(id: u64) -> String {
+    format!("https://news.ycombinator.com/item?id={}", id)
+}
+
+/// Render a story and its comments as one Markdown document for `hn export`
+///
+/// Starts with YAML front matter (strings as double-quoted scalars, like
+/// `md fetch --frontmatter`), then the title, article link, and post text.
+/// `comments` are the story's comments ordered depth-first (see
+/// [`order_thread_items`]); each becomes a block quote nested one level per
+/// reply depth, with its body converted by [`html_to_markdown`].
+pub fn render_story_markdown(story: &HnItem, comments: &[HnItem], exported_at: &str) -> String {
+    use std::collections::HashMap;
+
+    let quote = |value: &str| serde_json::to_string(value).unwrap_or_else(|_| "\"\"".to_string());
+    let title = story.title.as_deref().unwrap_or("(No title)");
+    let time = format_timestamp(story.time);
+    let discussion = item_page_url(story.id);
+
+    let mut lines = vec!["---".to_string(), format!("id: {}", story.id)];
+    lines.push(format!("title: {}", quote(title)));
+    if let Some(url) = &story.url {
+        lines.push(format!("url: {}", quote(url)));
+    }
+    if let Some(author) = &story.by {
+        lines.push(format!("author: {}", quote(author)));
+    }
+    if let Some(score) = story.score {
+        lines.push(format!("score: {}", score));
+    }
+    if let Some(time) = &time {
+        lines.push(format!("time: {}", quote(time)));
+    }
+    lines.push(format!("comments: {}", comments.len()));
+    lines.push(format!("hn_url: {}", quote(&discussion)));
+    lines.push(format!("exported_at: {}", quote(exported_at)));
+    lines.push("---".to_string());
+    let mut doc = lines.join("\n") + "\n\n";
+
+    doc.push_str(&format!("# {}\n\n", title));
+    if let Some(url) = &story.url {
+        doc.push_str(&format!("**Article:** <{}>\n\n", url));
+    }
+    let mut byline = Vec::new();
+    if let Some(author) = &story.by {
+        byline.push(format!("By **{}**", author));
+    }
+    if let Some(score) = story.score {
+        byline.push(format!("{} points", score));
+    }
+    if let Some(time) = &time {
+        byline.push(time.clone());
+    }
+    byline.push(format!("[Discussion]({})", discussion));
+    doc.push_str(&format!("{}\n\n", byline.join(" · ")));
+    if let Some(text) = &story.text {
+        doc.push_str(&format!("{}\n\n", html_to_markdown(text)));
+    }
+
+    doc.push_str(&format!("## Comments ({})\n", comments.len()));
+    if comments.is_empty() {
+        doc.push_str("\n_No comments._\n");
+    }
+
+    let mut depths: HashMap<u64, usize> = HashMap::from([(story.id, 0)]);
+    for comment in comments {
+        let depth = comment
+            .parent
+            .and_then(|parent| depths.get(&parent))
+            .map_or(1, |depth| depth + 1);
+        depths.insert(comment.id, depth);
+
+        let prefix = ">".repeat(depth);
+        let mut header = vec![format!(
+            "**{}**",
+            comment.by.as_deref().unwrap_or("(unknown)")
+        )];
+        if let Some(time) = format_timestamp(comment.time) {
+            header.push(time);
+        }
+        header.push(format!("[#{}]({})", comment.id, item_page_url(comment.id)));
+
+        doc.push_str(&format!(
+            "\n{} {}\n{}\n",
+            prefix,
+            header.join(" · "),
+            prefix
+        ));
+        let body = comment
+            .text
+            .as_deref()
+            .map(html_to_markdown)
+            .unwrap_or_default();
+        for line in body.lines() {
+            if line.is_empty() {
+                doc.push_str(&format!("{}\n", prefix));
+            } else {
+                doc.push_str(&format!("{} {}\n", prefix, line));
+            }
+        }
+    }
+
+    doc
+}
+
+/// Recently changed items and profiles from the `/updates.json` API
+#[derive(Debug, Deserialize, Serialize, Clone, Default)]
+pub struct HnUpdates {
+    #[serde(default)]
+    pub items: Vec<u64>,
+    #[serde(default)]
+    pub profiles: Vec<String>,
+}
+
+/// A recently changed item, resolved from its current version
+#[derive(Debug, Serialize, Clone)]
+pub struct UpdatedItem {
+    pub id: u64,
+    #[serde(rename = "type")]
+    pub item_type: String,
+    pub title: Option<String>,
+    pub url: Option<String>,
+    pub author: Option<String>,
+    /// Plain-text excerpt of the item text
+    pub text: Option<String>,
+    pub score: Option<u64>,
+    pub comments: Option<u64>,
+    pub time: Option<String>,
+    /// Item a comment replies to
+    pub parent: Option<u64>,
+}
+
+/// A recently changed user profile
+#[derive(Debug, Serialize, Clone)]
+pub struct UpdatedProfile {
+    pub id: String,
+    pub karma: u64,
+    pub created: Option<String>,
+    pub total_submissions: usize,
+}
+
+/// Updates output: the resolved changes and how many were reported
+#[derive(Debug, Serialize, Clone)]
+pub struct UpdatesOutput {
+    pub items: Vec<UpdatedItem>,
+    pub profiles: Vec<UpdatedProfile>,
+    /// Changed items reported by the API, resolved or not
+    pub total_items: usize,
+    /// Changed profiles reported by the API, resolved or not
+    pub total_profiles: usize,
+}
+
+/// Build updates output from the API's change list and the resolved items
+/// and users
+///
+/// Deleted items are skipped; `items` and `users` keep their fetch order.
+pub fn build_updates_output(
+    updates: &HnUpdates,
+    items: Vec<HnItem>,
+    users: Vec<HnUser>,
+) -> UpdatesOutput {
+    let items = items
+        .into_iter()
+        .filter(|item| !is_deleted(item))
+        .map(|item| UpdatedItem {
+            id: item.id,
+            text: item.text.as_deref().map(text_excerpt),
+            item_type: item.item_type,
+            title: item.title,
+            url: item.url,
+            author: item.by,
+            score: item.score,
+            comments: item.descendants,
+            time: format_timestamp(item.time),
+            parent: item.parent,
+        })
+        .collect();
+    let profiles = users
+        .into_iter()
+        .map(|user| UpdatedProfile {
+            created: format_timestamp(Some(user.created)),
+            total_submissions: user.submitted.as_ref().map_or(0, Vec::len),
+            id: user.id,
+            karma: user.karma,
+        })
+        .collect();
+
+    UpdatesOutput {
+        items,
+        profiles,
+        total_items: updates.items.len(),
+        total_profiles: updates.profiles.len(),
+    }
+}
+
+/// Response of the HN Algolia search API (`/api/v1/search`)
+#[derive(Debug, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct HnSearchResponse {
+    pub hits: Vec<HnSearchHit>,
+    #[serde(default)]
+    pub nb_hits: usize,
+    #[serde(default)]
+    pub nb_pages: usize,
+}
+
+/// One hit of an HN Algolia search; stories and comments share the shape
+#[derive(Debug, Deserialize, Clone)]
+pub struct HnSearchHit {
+    #[serde(rename = "objectID")]
+    pub object_id: String,
+    #[serde(rename = "_tags", default)]
+    pub tags: Vec<String>,
+    pub title: Option<String>,
+    pub url: Option<String>,
+    pub author: Option<String>,
+    pub points: Option<u64>,
+    pub num_comments: Option<u64>,
+    pub created_at_i: Option<u64>,
+    pub story_text: Option<String>,
+    pub comment_text: Option<String>,
+    pub story_id: Option<u64>,
+    pub story_title: Option<String>,
+}
+
+/// Individual search result output
+#[derive(Debug, Serialize, Clone)]
+pub struct SearchItem {
+    pub id: u64,
+    #[serde(rename = "type")]
+    pub item_type: String,
+    /// Story title, or the title of the story a comment belongs to
+    pub title: Option<String>,
+    pub url: Option<String>,
+    pub author: Option<String>,
+    pub score: Option<u64>,
+    pub time: Option<String>,
+    pub comments: Option<u64>,
+    /// Plain-text excerpt of the story or comment text
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub text: Option<String>,
+    /// Story a comment belongs to
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub story_id: Option<u64>,
+}
+
+/// Search output with matching items and pagination
+#[derive(Debug, Serialize, Clone)]
+pub struct SearchOutput {
+    pub query: String,
+    pub tags: Option<String>,
+    pub items: Vec<SearchItem>,
+    pub pagination: ListPaginationInfo,
+}
+
+/// Algolia `numericFilters` value for a `created_at_i` window, if any
+pub fn search_numeric_filters(since: Option<i64>, until: Option<i64>) -> Option<String> {
+    let filters: Vec<String> = [
+        since.map(|t| format!("created_at_i>={}", t)),
+        until.map(|t| format!("created_at_i<{}", t)),
+    ]
+    .into_iter()
+    .flatten()
+    .collect();
+    (!filters.is_empty()).then(|| filters.join(","))
+}
+
+/// Build search output from an Algolia response
+///
+/// `page` is 1-indexed; `command_args` are appended to the navigation
+/// commands so the next page keeps the same tags and date range.
+pub fn build_search_output(
+    response: HnSearchResponse,
+    query: &str,
+    tags: Option<String>,
+    page: usize,
+    limit: usize,
+    command_args: &str,
+) -> SearchOutput {
+    let items = response
+        .hits
+        .into_iter()
+        .filter_map(|hit| {
+            let id = hit.object_id.parse::<u64>().ok()?;
+            let item_type = ["story", "comment", "poll", "pollopt", "job"]
+                .into_iter()
+                .find(|t| hit.tags.iter().any(|tag| tag == t))
+                .unwrap_or("story")
+                .to_string();
+            let text = hit
+                .comment_text
+                .as_deref()
+                .or(hit.story_text.as_deref())
+                .map(text_excerpt)
+                .filter(|text| !text.is_empty());
+            Some(SearchItem {
+                id,
+                item_type,
+                title: hit.title.filter(|t| !t.is_empty()).or(hit.story_title),
+                url: hit.url.filter(|u| !u.is_empty()),
+                author: hit.author,
+                score: hit.points,
+                time: format_timestamp(hit.created_at_i),
+                comments: hit.num_comments,
+                text,
+                story_id: hit.story_id.filter(|story| *story != id),
+            })
+        })
+        .collect();
+
+    let total_pages = response.nb_pages;
+    let command = |page: usize| {
+        format!(
+            "mcptools hn search {:?} --page {}{}",
+            query, page, command_args
+        )
+    };
+    SearchOutput {
+        query: query.to_string(),
+        tags,
+        items,
+        pagination: ListPaginationInfo {
+            current_page: page,
+            total_pages,
+            total_items: response.nb_hits,
+            limit,
+            next_page_command: (page < total_pages).then(|| command(page + 1)),
+            prev_page_command: (page > 1).then(|| command(page - 1)),
+            next_cursor: None,
+        },
+    }
+}
+
+/// Public HN page listing `story_type` stories.
+fn story_type_page(story_type: &str) -> String {
+    let path = match story_type {
+        "new" => "newest",
+        "job" => "jobs",
+        "top" => "news",
+        other => other,
+    };
+    format!("https://news.ycombinator.com/{path}")
+}
+
+/// Escape text for an XML element or attribute value.
+fn xml_escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&apos;")
+}
+
+/// Parse a time produced by [`format_timestamp`] back into a `DateTime`.
+fn parse_list_time(time: Option<&str>) -> Option<DateTime<Utc>> {
+    let naive = chrono::NaiveDateTime::parse_from_str(time?, "%Y-%m-%d %H:%M:%S UTC").ok()?;
+    Some(naive.and_utc())
+}
+
+/// Column headers of [`list_table_rows`].
+pub const LIST_TABLE_HEADERS: [&str; 5] = ["#", "Title", "Score", "Comments", "Age"];
+
+/// Compact age of something `secs` old: `45s`, `12m`, `5h`, `3d`, `2mo`, `4y`.
+pub fn format_age(secs: i64) -> String {
+    let secs = secs.max(0);
+    match secs {
+        s if s < 60 => format!("{s}s"),
+        s if s < 60 * 60 => format!("{}m", s / 60),
+        s if s < 24 * 60 * 60 => format!("{}h", s / (60 * 60)),
+        s if s < 30 * 24 * 60 * 60 => format!("{}d", s / (24 * 60 * 60)),
+        s if s < 365 * 24 * 60 * 60 => format!("{}mo", s / (30 * 24 * 60 * 60)),
+        s => format!("{}y", s / (365 * 24 * 60 * 60)),
+    }
+}
+
+/// One row per listed story with its overall rank, title, score, comment
+/// count, and age at `now` (Unix seconds), in [`LIST_TABLE_HEADERS`] order.
+/// Shared by the table and Markdown renderings of `hn list`.
+pub fn list_table_rows(output: &ListOutput, now: i64) -> Vec<[String; 5]> {
+    let offset = (output.pagination.current_page.max(1) - 1) * output.pagination.limit;
+    output
+        .items
+        .iter()
+        .enumerate()
+        .map(|(idx, item)| {
+            [
+                (offset + idx + 1).to_string(),
+                item.title
+                    .clone()
+                    .unwrap_or_else(|| "(No title)".to_string()),
+                item.score.unwrap_or(0).to_string(),
+                item.comments.unwrap_or(0).to_string(),
+                parse_list_time(item.time.as_deref())
+                    .map(|time| format_age(now - time.timestamp()))
+                    .unwrap_or_else(|| "?".to_string()),
+            ]
+        })
+        .collect()
+}
+
+/// Render a story list as a Markdown table, titles linking to the story
+/// (or its HN page for text posts)
+pub fn render_list_markdown(output: &ListOutput, now: i64) -> String {
+    let mut rows = vec![LIST_TABLE_HEADERS.map(String::from).to_vec()];
+    for (row, item) in list_table_rows(output, now).into_iter().zip(&output.items) {
+        let [rank, title, score, comments, age] = row;
+        let link = item
+            .url
+            .clone()
+            .unwrap_or_else(|| format!("https://news.ycombinator.com/item?id={}", item.id));
+        let title = title.replace('[', "\\[").replace(']', "\\]");
+        rows.push(vec![
+            rank,
+            format!("[{title}]({link})"),
+            score,
+            comments,
+            age,
+        ]);
+    }
+    let heading = format!(
+        "## Hacker News: {} stories (page {} of {})",
+        output.story_type, output.pagination.current_page, output.pagination.total_pages
+    );
+    match crate::atlassian::convert::markdown_table(rows) {
+        Some(table) if !output.items.is_empty() => format!("{heading}\n\n{table}\n"),
+        _ => format!("{heading}\n\nNo stories on this page.\n"),
+    }
+}
+
+/// Render a story list as an RSS 2.0 or Atom feed
+///
+/// Each story links to its URL (or its HN page for text posts) and carries
+/// the HN discussion page as its stable ID. `generated_at` stamps the feed
+/// and stands in for stories without a time.
+pub fn render_list_feed(
+    output: &ListOutput,
+    kind: crate::md::FeedKind,
+    generated_at: DateTime<Utc>,
+) -> String {
+    let title = xml_escape(&format!("Hacker News: {} stories", output.story_type));
+    let home = xml_escape(&story_type_page(&output.story_type));
+    let mut xml = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+
+    let stories = output.items.iter().map(|item| {
+        let discussion = format!("https://news.ycombinator.com/item?id={}", item.id);
+        let link = xml_escape(item.url.as_deref().unwrap_or(&discussion));
+        let summary = xml_escape(&format!(
+            "{} points by {} | {} comments: {}",
+            item.score.unwrap_or(0),
+            item.author.as_deref().unwrap_or("unknown"),
+            item.comments.unwrap_or(0),
+            discussion
+        ));
+        let title = xml_escape(item.title.as_deref().unwrap_or("(No title)"));
+        let time = parse_list_time(item.time.as_deref()).unwrap_or(generated_at);
+        (title, link, xml_escape(&discussion), summary, time, item)
+    });
+
+    match kind {
+        crate::md::FeedKind::Rss => {
+            xml.push_str("<rss version=\"2.0\">\n<channel>\n");
+            xml.push_str(&format!("<title>{title}</title>\n<link>{home}</link>\n"));
+            xml.push_str(&format!(
+                "<description>{title}, page {}</description>\n<lastBuildDate>{}</lastBuildDate>\n",
+                output.pagination.current_page,
+                generated_at.to_rfc2822()
+            ));
+            for (title, link, discussion, summary, time, _) in stories {
+                xml.push_str(&format!(
+                    "<item>\n<title>{title}</title>\n<link>{link}</link>\n\
+                     <guid isPermaLink=\"true\">{discussion}</guid>\n\
+                     <comments>{discussion}</comments>\n<pubDate>{}</pubDate>\n\
+                     <description>{summary}</description>\n</item>\n",
+                    time.to_rfc2822()
+                ));
+            }
+            xml.push_str("</channel>\n</rss>\n");
+        }
+        crate::md::FeedKind::Atom => {
+            xml.push_str("<feed xmlns=\"http://www.w3.org/2005/Atom\">\n");
+            xml.push_str(&format!(
+                "<title>{title}</title>\n<id>{home}</id>\n<link href=\"{home}\"/>\n\
+                 <updated>{}</updated>\n<author><name>Hacker News</name></author>\n",
+                generated_at.to_rfc3339()
+            ));
+            for (title, link, discussion, summary, time, item) in stories {
+                let author = item
+                    .author
+                    .as_deref()
+                    .map(|a| format!("<author><name>{}</name></author>\n", xml_escape(a)))
+                    .unwrap_or_default();
+                xml.push_str(&format!(
+                    "<entry>\n<title>{title}</title>\n<id>{discussion}</id>\n\
+                     <link href=\"{link}\"/>\n<link rel=\"replies\" href=\"{discussion}\"/>\n\
+                     <updated>{}</updated>\n{author}<summary>{summary}</summary>\n</entry>\n",
+                    time.to_rfc3339()
+                ));
+            }
+            xml.push_str("</feed>\n");
+        }
+    }
+
+    xml
+}
+
+/// What `hn watch` remembers about a story between polls
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct StoryWatchState {
+    pub score: Option<u64>,
+    pub descendants: Option<u64>,
+    /// Every comment ID seen in the thread so far
+    pub seen: std::collections::HashSet<u64>,
+}
+
+/// A comment that appeared since the previous poll
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct NewComment {
+    pub id: u64,
+    pub parent: Option<u64>,
+    pub author: Option<String>,
+    pub time: Option<String>,
+    pub text: Option<String>,
+}
+
+/// One line of `hn watch --json` output. Polls that find no change emit
+/// nothing.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(tag = "event", rename_all = "lowercase")]
+pub enum StoryWatchEvent {
+    /// The story as first seen, which later polls compare against
+    Baseline {
+        id: u64,
+        at: Option<String>,
+        title: Option<String>,
+        score: Option<u64>,
+        comments: Option<u64>,
+    },
+    /// The score moved or new comments appeared
+    Update {
+        id: u64,
+        at: Option<String>,
+        score: Option<u64>,
+        /// Change since the previous poll
+        score_delta: i64,
+        comments: Option<u64>,
+        /// New live comments, in thread order
+        new_comments: Vec<NewComment>,
+    },
+    /// A poll that failed; the watch keeps going
+    Error {
+        id: u64,
+        at: Option<String>,
+        error: String,
+    },
+}
+
+impl StoryWatchEvent {
+    pub fn error(id: u64, at: i64, error: String) -> Self {
+        Self::Error {
+            id,
+            at: format_timestamp(Some(at as u64)),
+            error,
+        }
+    }
+}
+
+/// Start watching `story`, whose whole thread is `thread`
+pub fn start_story_watch(
+    story: &HnItem,
+    thread: &[HnItem],
+    at: i64,
+) -> (StoryWatchState, StoryWatchEvent) {
+    let state = StoryWatchState {
+        score: story.score,
+        descendants: story.descendants,
+        seen: thread.iter().map(|item| item.id).collect(),
+    };
+    let event = StoryWatchEvent::Baseline {
+        id: story.id,
+        at: format_timestamp(Some(at as u64)),
+        title: story.title.clone(),
+        score: story.score,
+        comments: story.descendants,
+    };
+    (state, event)
+}
+
+/// Compare a poll of `story` against `state` and advance it
+///
+/// `thread` is the story's whole thread when it was refetched, or `None`
+/// when the comment count did not move and the walk was skipped. New
+/// comments that are already dead or deleted are remembered but not
+/// reported.
+pub fn poll_story_watch(
+    state: &mut StoryWatchState,
+    story: &HnItem,
+    thread: Option<&[HnItem]>,
+    at: i64,
+) -> Option<StoryWatchEvent> {
+    let new_comments: Vec<NewComment> = thread
+        .unwrap_or_default()
+        .iter()
+        .filter(|item| state.seen.insert(item.id))
+        .filter(|item| !is_deleted(item) && !is_dead(item))
+        .map(|item| NewComment {
+            id: item.id,
+            parent: item.parent,
+            author: item.by.clone(),
+            time: format_timestamp(item.time),
+            text: item.text.as_deref().map(html_to_markdown),
+        })
+        .collect();
+    let score_delta = story.score.unwrap_or(0) as i64 - state.score.unwrap_or(0) as i64;
+    state.score = story.score;
+    state.descendants = story.descendants;
+
+    (score_delta != 0 || !new_comments.is_empty()).then(|| StoryWatchEvent::Update {
+        id: story.id,
+        at: format_timestamp(Some(at as u64)),
+        score: story.score,
+        score_delta,
+        comments: story.descendants,
+        new_comments,
+    })
+}
+
+/// One server-sent event from the Firebase streaming API
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FirebaseEvent {
+    /// `put`, `patch`, `keep-alive`, `cancel`, or `auth_revoked`
+    pub event: String,
+    pub data: String,
+}
+
+/// Split the complete events off the front of `buffer`, leaving any
+/// partially received event in place for the next chunk
+pub fn drain_firebase_events(buffer: &mut Vec<u8>) -> Vec<FirebaseEvent> {
+    let mut events = Vec::new();
+    while let Some(end) = buffer.windows(2).position(|pair| pair == b"\n\n") {
+        let block: Vec<u8> = buffer.drain(..end + 2).collect();
+        let block = String::from_utf8_lossy(&block);
+        let mut event = FirebaseEvent {
+            event: String::new(),
+            data: String::new(),
+        };
+        for line in block.lines() {
+            if let Some(name) = line.strip_prefix("event:") {
+                event.event = name.trim().to_string();
+            } else if let Some(data) = line.strip_prefix("data:") {
+                if !event.data.is_empty() {
+                    event.data.push('\n');
+                }
+                event.data.push_str(data.trim_start());
+            }
+        }
+        if !event.event.is_empty() {
+            events.push(event);
+        }
+    }
+    events
+}
+
+/// Apply a `put` or `patch` event to a story list kept by the stream
+///
+/// A `put` at `/` replaces the whole list and a `put` at `/<index>` sets or
+/// (with `null`) removes one rank; a `patch` sets several ranks at once.
+/// Other events leave the list alone.
+pub fn apply_firebase_event(ids: &mut Vec<u64>, event: &FirebaseEvent) -> Result<(), String> {
+    #[derive(Deserialize)]
+    struct Payload {
+        path: String,
+        data: serde_json::Value,
+    }
+
+    if event.event != "put" && event.event != "patch" {
+        return Ok(());
+    }
+    let payload: Payload = serde_json::from_str(&event.data)
+        .map_err(|e| format!("Invalid {} event: {}", event.event, e))?;
+
+    let mut slots: Vec<Option<u64>> = ids.iter().copied().map(Some).collect();
+    let mut set = |index: &str, value: &serde_json::Value| -> Result<(), String> {
+        let index: usize = index
+            .parse()
+            .map_err(|_| format!("Invalid list index: {}", index))?;
+        if slots.len() <= index {
+            slots.resize(index + 1, None);
+        }
+        slots[index] = value.as_u64();
+        Ok(())
+    };
+    let path = payload.path.trim_matches('/');
+    match (event.event.as_str(), path, &payload.data) {
+        ("put", "", serde_json::Value::Array(values)) => {
+            slots = values.iter().map(|value| value.as_u64()).collect();
+        }
+        ("put", "", serde_json::Value::Null) => slots.clear(),
+        ("put", index, value) if !index.is_empty() => set(index, value)?,
+        ("patch", "", serde_json::Value::Object(values)) => {
+            for (index, value) in values {
+                set(index, value)?;
+            }
+        }
+        _ => {
+            return Err(format!(
+                "Unexpected {} event at {}",
+                event.event, payload.path
+            ))
+        }
+    }
+
+    *ids = slots.into_iter().flatten().collect();
+    Ok(())
+}
+
+/// One line of `hn live --json` output
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(tag = "event", rename_all = "lowercase")]
+pub enum LiveRankEvent {
+    /// The watched ranks when the stream opened
+    Snapshot {
+        list: String,
+        at: Option<String>,
+        /// Story IDs in rank order, rank 1 first
+        ids: Vec<u64>,
+    },
+    /// A story moved into the watched ranks
+    Entered {
+        list: String,
+        at: Option<String>,
+        id: u64,
+        rank: usize,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        title: Option<String>,
+    },
+    /// A story changed rank within the watched ranks
+    Moved {
+        list: String,
+        at: Option<String>,
+        id: u64,
+        from: usize,
+        to: usize,
+    },
+    /// A story dropped out of the watched ranks
+    Left {
+        list: String,
+        at: Option<String>,
+        id: u64,
+        from: usize,
+    },
+}
+
+/// Rank changes between two versions of a story list, looking only at the
+/// first `limit` ranks
+///
+/// Events come in new rank order (entries and moves), followed by the
+/// stories that left. Ranks are 1-indexed.
+pub fn rank_changes(
+    list: &str,
+    old: &[u64],
+    new: &[u64],
+    limit: usize,
+    at: i64,
+) -> Vec<LiveRankEvent> {
+    let old = &old[..old.len().min(limit)];
+    let new = &new[..new.len().min(limit)];
+    let at = format_timestamp(Some(at as u64));
+    let rank_in = |ids: &[u64], id: u64| ids.iter().position(|&other| other == id);
+
+    let mut events: Vec<LiveRankEvent> = new
+        .iter()
+        .enumerate()
+        .filter_map(|(index, &id)| match rank_in(old, id) {
+            None => Some(LiveRankEvent::Entered {
+                list: list.to_string(),
+                at: at.clone(),
+                id,
+                rank: index + 1,
+                title: None,
+            }),
+            Some(from) if from != index => Some(LiveRankEvent::Moved {
+                list: list.to_string(),
+                at: at.clone(),
+                id,
+                from: from + 1,
+                to: index + 1,
+            }),
+            Some(_) => None,
+        })
+        .collect();
+    events.extend(
+        old.iter()
+            .enumerate()
+            .filter(|(_, &id)| rank_in(new, id).is_none())
+            .map(|(index, &id)| LiveRankEvent::Left {
+                list: list.to_string(),
+                at: at.clone(),
+                id,
+                from: index + 1,
+            }),
+    );
+    events
+}
+
+/// Items younger than this are still collecting votes, replies, and edits.
+pub const ITEM_SETTLE_SECS: i64 = 24 * 60 * 60;
+
+/// Cache lifetime of an item fetched before it settled.
+pub const FRESH_ITEM_CACHE_TTL_SECS: u64 = 10 * 60;
+
+/// Cache lifetime of an item fetched after it settled.
+pub const SETTLED_ITEM_CACHE_TTL_SECS: u64 = 7 * 24 * 60 * 60;
+
+/// An item as kept by the on-disk item cache
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CachedItem {
+    /// Unix seconds when the item was fetched
+    pub fetched_at: i64,
+    pub item: HnItem,
+}
+
+impl CachedItem {
+    /// How long this entry may be served: short while the item was still
+    /// settling when fetched, long once it had settled. Items without a
+    /// timestamp count as unsettled.
+    pub fn ttl(&self) -> u64 {
+        let settled = self
+            .item
+            .time
+            .is_some_and(|time| self.fetched_at - time as i64 >= ITEM_SETTLE_SECS);
+        if settled {
+            SETTLED_ITEM_CACHE_TTL_SECS
+        } else {
+            FRESH_ITEM_CACHE_TTL_SECS
+        }
+    }
+
+    /// Whether the entry can still be served at `now` (Unix seconds).
+    pub fn is_fresh(&self, now: i64) -> bool {
+        crate::md::is_cache_fresh(self.fetched_at, now, self.ttl())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -460,6 +1978,7 @@ mod tests {
             score: Some(100),
             title: Some("Test Story".to_string()),
             descendants: Some(42),
+            parts: None,
         }];
 
         let output = transform_hn_items(items, "top".to_string(), 1, 10, 1);
@@ -495,6 +2014,7 @@ mod tests {
                 score: Some(50),
                 title: Some("Story 1".to_string()),
                 descendants: Some(10),
+                parts: None,
             },
             HnItem {
                 id: 2,
@@ -510,6 +2030,7 @@ mod tests {
                 score: Some(75),
                 title: Some("Story 2".to_string()),
                 descendants: Some(20),
+                parts: None,
             },
         ];
 
@@ -546,6 +2067,7 @@ mod tests {
             score: None,
             title: None,
             descendants: None,
+            parts: None,
         }];
 
         let output = transform_hn_items(items, "ask".to_string(), 1, 10, 1);
@@ -575,6 +2097,7 @@ mod tests {
             score: Some(10),
             title: Some("Story".to_string()),
             descendants: None,
+            parts: None,
         }];
 
         let output = transform_hn_items(items, "top".to_string(), 1, 10, 50);
@@ -604,6 +2127,7 @@ mod tests {
             score: Some(10),
             title: Some("Story".to_string()),
             descendants: None,
+            parts: None,
         }];
 
         let output = transform_hn_items(items, "show".to_string(), 5, 10, 50);
@@ -633,6 +2157,7 @@ mod tests {
             score: Some(10),
             title: Some("Story".to_string()),
             descendants: None,
+            parts: None,
         }];
 
         let output = transform_hn_items(items, "job".to_string(), 3, 10, 100);
@@ -667,6 +2192,7 @@ mod tests {
             score: Some(10),
             title: Some("Story".to_string()),
             descendants: None,
+            parts: None,
         }];
 
         let story_types = vec!["top", "new", "best", "ask", "show", "job"];
@@ -701,6 +2227,37 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_html_to_markdown_paragraphs_and_italics() {
+        let html = "First &amp; <i>only</i> point.<p>&gt; quoted &#x27;text&#x27;<p>Third";
+        assert_eq!(
+            html_to_markdown(html),
+            "First & *only* point.\n\n> quoted 'text'\n\nThird"
+        );
+    }
+
+    #[test]
+    fn test_html_to_markdown_links() {
+        let html = concat!(
+            "See <a href=\"https:&#x2F;&#x2F;example.com&#x2F;a\" rel=\"nofollow\">https:&#x2F;&#x2F;example.com&#x2F;a</a>",
+            " and <a href=\"https:&#x2F;&#x2F;example.com&#x2F;very&#x2F;long&#x2F;path\" rel=\"nofollow\">https:&#x2F;&#x2F;example.com&#x2F;very&#x2F;...</a>",
+            " or <a href=\"https:&#x2F;&#x2F;docs.rs\">the docs</a>"
+        );
+        assert_eq!(
+            html_to_markdown(html),
+            "See <https://example.com/a> and <https://example.com/very/long/path> or [the docs](https://docs.rs)"
+        );
+    }
+
+    #[test]
+    fn test_html_to_markdown_code_blocks() {
+        let html = "Try this:<p><pre><code>  fn main() {\n      println!(&quot;&lt;hi&gt;&quot;);\n\n\n  }\n</code></pre>Then <i>run</i> it.";
+        assert_eq!(
+            html_to_markdown(html),
+            "Try this:\n\n```\n  fn main() {\n      println!(\"<hi>\");\n\n\n  }\n```\n\nThen *run* it."
+        );
+    }
+
     #[test]
     fn test_transform_comments_single() {
         let comments = vec![HnItem {
@@ -717,6 +2274,7 @@ mod tests {
             score: None,
             title: None,
             descendants: None,
+            parts: None,
         }];
 
         let outputs = transform_comments(comments);
@@ -746,6 +2304,7 @@ mod tests {
                 score: None,
                 title: None,
                 descendants: None,
+                parts: None,
             },
             HnItem {
                 id: 102,
@@ -761,6 +2320,7 @@ mod tests {
                 score: None,
                 title: None,
                 descendants: None,
+                parts: None,
             },
         ];
 
@@ -789,6 +2349,7 @@ mod tests {
             score: None,
             title: None,
             descendants: None,
+            parts: None,
         }];
 
         let outputs = transform_comments(comments);
@@ -817,6 +2378,7 @@ mod tests {
             score: Some(250),
             title: Some("Test Story".to_string()),
             descendants: Some(50),
+            parts: None,
         };
 
         let comments = vec![
@@ -826,6 +2388,8 @@ mod tests {
                 time: Some("2021-01-01 00:10:00 UTC".to_string()),
                 text: Some("First comment".to_string()),
                 replies_count: 2,
+                deleted: false,
+                dead: false,
             },
             CommentOutput {
                 id: 101,
@@ -833,6 +2397,8 @@ mod tests {
                 time: Some("2021-01-01 00:20:00 UTC".to_string()),
                 text: Some("Second comment".to_string()),
                 replies_count: 0,
+                deleted: false,
+                dead: false,
             },
         ];
 
@@ -869,6 +2435,7 @@ mod tests {
             score: None,
             title: None,
             descendants: None,
+            parts: None,
         };
 
         let output = build_post_output(item, vec![], 1, 10, 0);
@@ -902,6 +2469,7 @@ mod tests {
             score: Some(100),
             title: Some("Test".to_string()),
             descendants: Some(50),
+            parts: None,
         };
 
         let output = build_post_output(item, vec![], 1, 10, 50);
@@ -932,6 +2500,7 @@ mod tests {
             score: Some(100),
             title: Some("Test".to_string()),
             descendants: Some(50),
+            parts: None,
         };
 
         let output = build_post_output(item, vec![], 5, 10, 50);
@@ -962,6 +2531,7 @@ mod tests {
             score: Some(100),
             title: Some("Test".to_string()),
             descendants: Some(50),
+            parts: None,
         };
 
         let output = build_post_output(item, vec![], 3, 10, 50);
@@ -996,6 +2566,7 @@ mod tests {
             score: Some(100),
             title: Some("Test".to_string()),
             descendants: Some(5),
+            parts: None,
         };
 
         let output = build_post_output(item, vec![], 1, 10, 5);
@@ -1022,6 +2593,7 @@ mod tests {
             score: Some(100),
             title: Some("Test".to_string()),
             descendants: Some(0),
+            parts: None,
         };
 
         let output = build_post_output(item, vec![], 1, 10, 0);
@@ -1049,6 +2621,7 @@ mod tests {
             score: None,
             title: None,
             descendants: None,
+            parts: None,
         }];
 
         let tree = build_comment_tree(items, 0);
@@ -1078,6 +2651,7 @@ mod tests {
                 score: None,
                 title: None,
                 descendants: None,
+                parts: None,
             },
             HnItem {
                 id: 101,
@@ -1093,6 +2667,7 @@ mod tests {
                 score: None,
                 title: None,
                 descendants: None,
+                parts: None,
             },
             HnItem {
                 id: 102,
@@ -1108,6 +2683,7 @@ mod tests {
                 score: None,
                 title: None,
                 descendants: None,
+                parts: None,
             },
         ];
 
@@ -1139,6 +2715,7 @@ mod tests {
                 score: None,
                 title: None,
                 descendants: None,
+                parts: None,
             },
             HnItem {
                 id: 101,
@@ -1154,6 +2731,7 @@ mod tests {
                 score: None,
                 title: None,
                 descendants: None,
+                parts: None,
             },
             HnItem {
                 id: 102,
@@ -1169,6 +2747,7 @@ mod tests {
                 score: None,
                 title: None,
                 descendants: None,
+                parts: None,
             },
         ];
 
@@ -1202,6 +2781,7 @@ mod tests {
                 score: None,
                 title: None,
                 descendants: None,
+                parts: None,
             },
             HnItem {
                 id: 200,
@@ -1217,6 +2797,7 @@ mod tests {
                 score: None,
                 title: None,
                 descendants: None,
+                parts: None,
             },
         ];
 
@@ -1246,6 +2827,7 @@ mod tests {
             score: None,
             title: None,
             descendants: None,
+            parts: None,
         }];
 
         let tree = build_comment_tree(items, 0);
@@ -1445,4 +3027,816 @@ mod tests {
         let count = count_tree_comments(&tree);
         assert_eq!(count, 3); // 100, 101, 200
     }
+
+    fn flagged_comment(id: u64, deleted: bool, dead: bool, kids: Option<Vec<u64>>) -> HnItem {
+        HnItem {
+            id,
+            item_type: "comment".to_string(),
+            by: if deleted {
+                None
+            } else {
+                Some("someone".to_string())
+            },
+            time: Some(1609459200),
+            text: if deleted {
+                None
+            } else {
+                Some("original".to_string())
+            },
+            dead: dead.then_some(true),
+            deleted: deleted.then_some(true),
+            parent: Some(1),
+            kids,
+            url: None,
+            score: None,
+            title: None,
+            descendants: None,
+            parts: None,
+        }
+    }
+
+    #[test]
+    fn test_apply_dead_filter_hides_leaf_dead_and_deleted() {
+        let items = vec![
+            flagged_comment(1, false, false, None),
+            flagged_comment(2, true, false, None),
+            flagged_comment(3, false, true, None),
+        ];
+        let (visible, hidden) = apply_dead_filter(items, false);
+        assert_eq!(visible.len(), 1);
+        assert_eq!(visible[0].id, 1);
+        assert_eq!(hidden, 2);
+    }
+
+    #[test]
+    fn test_apply_dead_filter_keeps_placeholders_with_replies() {
+        let items = vec![
+            flagged_comment(2, true, false, Some(vec![10])),
+            flagged_comment(3, false, true, Some(vec![11])),
+        ];
+        let (visible, hidden) = apply_dead_filter(items, false);
+        assert_eq!(hidden, 0);
+        assert_eq!(visible[0].text.as_deref(), Some(DELETED_PLACEHOLDER));
+        assert_eq!(visible[1].text.as_deref(), Some(DEAD_PLACEHOLDER));
+        assert!(visible[1].by.is_none());
+    }
+
+    #[test]
+    fn test_apply_dead_filter_show_dead() {
+        let items = vec![
+            flagged_comment(2, true, false, None),
+            flagged_comment(3, false, true, None),
+        ];
+        let (visible, hidden) = apply_dead_filter(items, true);
+        assert_eq!(hidden, 0);
+        assert_eq!(visible[0].text.as_deref(), Some(DELETED_PLACEHOLDER));
+        assert_eq!(visible[1].text.as_deref(), Some("[dead] original"));
+        assert_eq!(visible[1].by.as_deref(), Some("someone"));
+    }
+
+    #[test]
+    fn test_story_watch() {
+        let story = |score, descendants| HnItem {
+            item_type: "story".to_string(),
+            title: Some("Launch HN".to_string()),
+            score: Some(score),
+            descendants: Some(descendants),
+            ..flagged_comment(1, false, false, Some(vec![10]))
+        };
+        let reply = |id, parent| HnItem {
+            parent: Some(parent),
+            ..flagged_comment(id, false, false, None)
+        };
+
+        let (mut state, baseline) = start_story_watch(&story(5, 1), &[reply(10, 1)], 0);
+        assert!(matches!(
+            baseline,
+            StoryWatchEvent::Baseline {
+                score: Some(5),
+                comments: Some(1),
+                ..
+            }
+        ));
+
+        // Nothing moved.
+        assert_eq!(poll_story_watch(&mut state, &story(5, 1), None, 60), None);
+
+        // Score only.
+        let Some(StoryWatchEvent::Update {
+            score_delta,
+            new_comments,
+            ..
+        }) = poll_story_watch(&mut state, &story(8, 1), None, 120)
+        else {
+            panic!("expected an update");
+        };
+        assert_eq!(score_delta, 3);
+        assert!(new_comments.is_empty());
+
+        // Two new comments, one of them already dead.
+        let thread = [
+            reply(10, 1),
+            reply(11, 10),
+            HnItem {
+                parent: Some(1),
+                ..flagged_comment(12, false, true, None)
+            },
+        ];
+        let Some(StoryWatchEvent::Update {
+            score_delta,
+            new_comments,
+            comments,
+            ..
+        }) = poll_story_watch(&mut state, &story(8, 3), Some(&thread), 180)
+        else {
+            panic!("expected an update");
+        };
+        assert_eq!(score_delta, 0);
+        assert_eq!(comments, Some(3));
+        assert_eq!(new_comments.len(), 1);
+        assert_eq!(new_comments[0].id, 11);
+        assert_eq!(new_comments[0].parent, Some(10));
+
+        // The same thread again reports nothing.
+        assert_eq!(
+            poll_story_watch(&mut state, &story(8, 3), Some(&thread), 240),
+            None
+        );
+    }
+
+    #[test]
+    fn test_firebase_list_events() {
+        let mut buffer = b"event: put\ndata: {\"path\":\"/\",\"data\":[1,2,3,4]}\n\nevent: keep-alive\ndata: null\n\nevent: pa".to_vec();
+        let events = drain_firebase_events(&mut buffer);
+        assert_eq!(events.len(), 2);
+        assert_eq!(events[1].event, "keep-alive");
+        assert_eq!(buffer, b"event: pa");
+
+        let mut ids = Vec::new();
+        for event in &events {
+            apply_firebase_event(&mut ids, event).unwrap();
+        }
+        assert_eq!(ids, vec![1, 2, 3, 4]);
+
+        buffer.extend_from_slice(b"tch\ndata: {\"path\":\"/\",\"data\":{\"0\":3,\"2\":1}}\n\n");
+        buffer.extend_from_slice(b"event: put\ndata: {\"path\":\"/3\",\"data\":null}\n\n");
+        for event in drain_firebase_events(&mut buffer) {
+            apply_firebase_event(&mut ids, &event).unwrap();
+        }
+        assert_eq!(ids, vec![3, 2, 1]);
+        assert!(buffer.is_empty());
+
+        let bad = FirebaseEvent {
+            event: "put".to_string(),
+            data: "not json".to_string(),
+        };
+        assert!(apply_firebase_event(&mut ids, &bad).is_err());
+    }
+
+    #[test]
+    fn test_rank_changes() {
+        assert!(rank_changes("top", &[1, 2, 3], &[1, 2, 3], 3, 0).is_empty());
+
+        // 4 climbs into the top 3, 3 drops out, and 9 below the cutoff is
+        // ignored.
+        let events = rank_changes("top", &[1, 2, 3, 4], &[4, 1, 2, 3, 9], 3, 0);
+        let summary: Vec<String> = events
+            .iter()
+            .map(|event| match event {
+                LiveRankEvent::Entered { id, rank, .. } => format!("+{id}@{rank}"),
+                LiveRankEvent::Moved { id, from, to, .. } => format!("{id}:{from}->{to}"),
+                LiveRankEvent::Left { id, from, .. } => format!("-{id}@{from}"),
+                LiveRankEvent::Snapshot { .. } => unreachable!(),
+            })
+            .collect();
+        assert_eq!(summary, vec!["+4@1", "1:1->2", "2:2->3", "-3@3"]);
+
+        let json = serde_json::to_string(&events[1]).unwrap();
+        assert!(json.starts_with(r#"{"event":"moved","list":"top""#));
+    }
+
+    #[test]
+    fn test_filter_hn_items() {
+        let story = |id, title: &str, url: Option<&str>, by: &str, score| HnItem {
+            item_type: "story".to_string(),
+            title: Some(title.to_string()),
+            url: url.map(str::to_string),
+            by: Some(by.to_string()),
+            score: Some(score),
+            text: None,
+            ..flagged_comment(id, false, false, None)
+        };
+        let items = vec![
+            story(
+                1,
+                "Rust 2.0 released",
+                Some("https://www.rust-lang.org/news"),
+                "alice",
+                300,
+            ),
+            story(2, "A gist", Some("https://gist.github.com/x"), "Bob", 50),
+            story(3, "Ask HN: Rust jobs?", None, "carol", 10),
+            story(4, "Notgithub", Some("https://notgithub.com/"), "bob", 500),
+        ];
+        let ids = |filters: ListFilters| -> Vec<u64> {
+            filter_hn_items(items.clone(), &filters)
+                .iter()
+                .map(|item| item.id)
+                .collect()
+        };
+
+        assert_eq!(ids(ListFilters::default()), vec![1, 2, 3, 4]);
+        assert_eq!(
+            ids(ListFilters {
+                min_score: Some(100),
+                ..Default::default()
+            }),
+            vec![1, 4]
+        );
+        assert_eq!(
+            ids(ListFilters {
+                domain: Some("github.com".to_string()),
+                ..Default::default()
+            }),
+            vec![2]
+        );
+        assert_eq!(
+            ids(ListFilters {
+                domain: Some("www.Rust-Lang.org".to_string()),
+                ..Default::default()
+            }),
+            vec![1]
+        );
+        assert_eq!(
+            ids(ListFilters {
+                author: Some("bob".to_string()),
+                ..Default::default()
+            }),
+            vec![2, 4]
+        );
+        assert_eq!(
+            ids(ListFilters {
+                keyword: Some("RUST".to_string()),
+                min_score: Some(20),
+                ..Default::default()
+            }),
+            vec![1]
+        );
+    }
+
+    #[test]
+    fn test_list_filters_command_args() {
+        assert_eq!(ListFilters::default().command_args(), "");
+        let filters = ListFilters {
+            min_score: Some(100),
+            domain: Some("github.com".to_string()),
+            keyword: Some("open source".to_string()),
+            since: Some(1_700_000_000),
+            sort: ListSort::Points,
+            ..Default::default()
+        };
+        assert_eq!(
+            filters.command_args(),
+            " --min-score 100 --domain github.com --keyword \"open source\" --since 2023-11-14T22:13:20Z --sort points"
+        );
+    }
+
+    #[test]
+    fn test_parse_time_bound() {
+        let now = 1_700_000_000;
+        assert_eq!(parse_time_bound("24h", now), Ok(now - 86_400));
+        assert_eq!(parse_time_bound("30m", now), Ok(now - 1_800));
+        assert_eq!(parse_time_bound("2w", now), Ok(now - 14 * 86_400));
+        assert_eq!(parse_time_bound("2024-05-01", now), Ok(1_714_521_600));
+        assert_eq!(
+            parse_time_bound("2023-11-14T22:13:20Z", 0),
+            Ok(1_700_000_000)
+        );
+        assert!(parse_time_bound("3y", now).is_err());
+        assert!(parse_time_bound("yesterday", now).is_err());
+    }
+
+    #[test]
+    fn test_filter_hn_items_time_and_sort() {
+        let story = |id, time: Option<u64>, score| HnItem {
+            item_type: "story".to_string(),
+            time,
+            score: Some(score),
+            ..flagged_comment(id, false, false, None)
+        };
+        let items = vec![
+            story(1, Some(1_000), 5),
+            story(2, Some(3_000), 50),
+            story(3, Some(2_000), 50),
+            story(4, None, 500),
+        ];
+        let ids = |filters: ListFilters| -> Vec<u64> {
+            filter_hn_items(items.clone(), &filters)
+                .iter()
+                .map(|item| item.id)
+                .collect()
+        };
+
+        assert_eq!(
+            ids(ListFilters {
+                since: Some(2_000),
+                ..Default::default()
+            }),
+            vec![2, 3]
+        );
+        assert_eq!(
+            ids(ListFilters {
+                until: Some(2_000),
+                ..Default::default()
+            }),
+            vec![1]
+        );
+        assert_eq!(
+            ids(ListFilters {
+                sort: ListSort::Date,
+                ..Default::default()
+            }),
+            vec![2, 3, 1, 4]
+        );
+        // Ties keep the list's own order.
+        assert_eq!(
+            ids(ListFilters {
+                sort: ListSort::Points,
+                ..Default::default()
+            }),
+            vec![4, 2, 3, 1]
+        );
+    }
+
+    #[test]
+    fn test_format_age() {
+        assert_eq!(format_age(5), "5s");
+        assert_eq!(format_age(150), "2m");
+        assert_eq!(format_age(5 * 3600 + 59), "5h");
+        assert_eq!(format_age(3 * 86_400), "3d");
+        assert_eq!(format_age(65 * 86_400), "2mo");
+        assert_eq!(format_age(800 * 86_400), "2y");
+        assert_eq!(format_age(-10), "0s");
+    }
+
+    #[test]
+    fn test_list_table_rows_and_markdown() {
+        let story = |id, title: &str, url: Option<&str>, time| HnItem {
+            item_type: "story".to_string(),
+            title: Some(title.to_string()),
+            url: url.map(str::to_string),
+            score: Some(10),
+            descendants: Some(4),
+            time: Some(time),
+            ..flagged_comment(id, false, false, None)
+        };
+        let items = vec![
+            story(7, "A | B [beta]", Some("https://example.com"), 1_000),
+            story(8, "Ask HN: x", None, 4_600),
+        ];
+        // Page 2 of 10 per page: ranks continue from 11.
+        let output = transform_hn_items(items, "top".to_string(), 2, 10, 12);
+
+        let rows = list_table_rows(&output, 8_200);
+        assert_eq!(
+            rows[0],
+            ["11", "A | B [beta]", "10", "4", "2h"].map(String::from)
+        );
+        assert_eq!(rows[1][0], "12");
+        assert_eq!(rows[1][4], "1h");
+
+        let markdown = render_list_markdown(&output, 8_200);
+        assert!(markdown.starts_with("## Hacker News: top stories (page 2 of 2)"));
+        assert!(markdown.contains("| # | Title | Score | Comments | Age |"));
+        assert!(
+            markdown.contains("| 11 | [A \\| B \\[beta\\]](https://example.com) | 10 | 4 | 2h |")
+        );
+        assert!(markdown.contains("[Ask HN: x](https://news.ycombinator.com/item?id=8)"));
+    }
+
+    #[test]
+    fn test_render_list_feed() {
+        let story = HnItem {
+            item_type: "story".to_string(),
+            title: Some("Rust & <XML>".to_string()),
+            url: Some("https://example.com/?a=1&b=2".to_string()),
+            score: Some(120),
+            descendants: Some(45),
+            time: Some(1_700_000_000),
+            ..flagged_comment(42, false, false, None)
+        };
+        let ask = HnItem {
+            item_type: "story".to_string(),
+            title: Some("Ask HN: Feeds?".to_string()),
+            time: None,
+            ..flagged_comment(43, false, false, None)
+        };
+        let output = transform_hn_items(vec![story, ask], "new".to_string(), 1, 30, 2);
+        let now = DateTime::<Utc>::from_timestamp(1_700_000_600, 0).unwrap();
+
+        for kind in [crate::md::FeedKind::Rss, crate::md::FeedKind::Atom] {
+            let xml = render_list_feed(&output, kind, now);
+            assert!(xml.contains("Rust &amp; &lt;XML&gt;"));
+
+            let feed = crate::md::parse_feed(&xml).unwrap();
+            assert_eq!(feed.kind, kind);
+            assert_eq!(feed.title.as_deref(), Some("Hacker News: new stories"));
+            assert_eq!(feed.entries.len(), 2);
+            assert_eq!(feed.entries[0].title.as_deref(), Some("Rust & <XML>"));
+            assert_eq!(
+                feed.entries[0].link.as_deref(),
+                Some("https://example.com/?a=1&b=2")
+            );
+            assert_eq!(
+                feed.entries[0].date.as_deref(),
+                Some("2023-11-14T22:13:20Z")
+            );
+            // Text posts link to their discussion; undated ones use `now`.
+            assert_eq!(
+                feed.entries[1].link.as_deref(),
+                Some("https://news.ycombinator.com/item?id=43")
+            );
+            assert_eq!(
+                feed.entries[1].date.as_deref(),
+                Some("2023-11-14T22:23:20Z")
+            );
+        }
+    }
+
+    #[test]
+    fn test_cached_item_freshness() {
+        let posted = 1_700_000_000;
+        let entry = |fetched_at: i64| CachedItem {
+            fetched_at,
+            item: HnItem {
+                time: Some(posted as u64),
+                ..flagged_comment(1, false, false, None)
+            },
+        };
+
+        // Fetched an hour after posting: only a short TTL.
+        let fresh = entry(posted + 3_600);
+        assert_eq!(fresh.ttl(), FRESH_ITEM_CACHE_TTL_SECS);
+        assert!(fresh.is_fresh(posted + 3_600 + 60));
+        assert!(!fresh.is_fresh(posted + 3_600 + FRESH_ITEM_CACHE_TTL_SECS as i64));
+
+        // Fetched after settling: served for days.
+        let settled = entry(posted + ITEM_SETTLE_SECS);
+        assert_eq!(settled.ttl(), SETTLED_ITEM_CACHE_TTL_SECS);
+        assert!(settled.is_fresh(posted + ITEM_SETTLE_SECS + 3 * 86_400));
+
+        let undated = CachedItem {
+            fetched_at: posted,
+            item: HnItem {
+                time: None,
+                ..flagged_comment(2, false, false, None)
+            },
+        };
+        assert_eq!(undated.ttl(), FRESH_ITEM_CACHE_TTL_SECS);
+    }
+
+    #[test]
+    fn test_order_thread_items() {
+        // Fetched breadth-first: 1 and 2 first, then their replies.
+        let items = vec![
+            flagged_comment(2, false, false, Some(vec![21])),
+            flagged_comment(1, false, false, Some(vec![11, 12])),
+            flagged_comment(12, false, false, None),
+            flagged_comment(21, false, false, None),
+            flagged_comment(11, false, false, Some(vec![111])),
+            flagged_comment(111, false, false, None),
+            flagged_comment(99, false, false, None),
+        ];
+
+        let ordered = order_thread_items(&[1, 2, 3], items);
+        let ids: Vec<u64> = ordered.iter().map(|item| item.id).collect();
+        assert_eq!(ids, vec![1, 11, 111, 12, 2, 21]);
+    }
+
+    #[test]
+    fn test_build_thread_output() {
+        let root = HnItem {
+            text: Some("<p>root</p>".to_string()),
+            ..flagged_comment(1, false, false, Some(vec![11, 12, 13, 14, 15]))
+        };
+        // Second page of two direct replies, depth-first as fetched
+        let replies = vec![
+            flagged_comment(13, false, false, Some(vec![131])),
+            flagged_comment(131, false, false, Some(vec![1311])),
+            flagged_comment(14, true, false, None),
+            flagged_comment(99, false, false, None),
+        ];
+
+        let output =
+            build_thread_output(500, &root, &[13, 14], replies.clone(), false, 2, 2, Some(2));
+        assert_eq!(output.comment.id, 1);
+        assert_eq!(output.comment.replies_count, 5);
+        let depths: Vec<(u64, usize)> = output
+            .replies
+            .iter()
+            .map(|reply| (reply.comment.id, reply.depth))
+            .collect();
+        assert_eq!(depths, vec![(13, 1), (131, 2)]);
+        assert_eq!(output.hidden_replies, 1);
+        // 1311 is below the depth limit and unfetched, so it still counts
+        assert_eq!(output.replies[1].comment.replies_count, 1);
+        let shown = build_thread_output(500, &root, &[13, 14], replies, true, 2, 2, Some(2));
+        assert_eq!(shown.replies.len(), 3);
+        assert_eq!(shown.replies[2].depth, 1);
+
+        assert_eq!(output.pagination.total_pages, 3);
+        assert_eq!(output.pagination.total_comments, 5);
+        assert_eq!(
+            output.pagination.next_page_command.as_deref(),
+            Some("mcptools hn read 500 --thread 1 --thread-page 3 --thread-limit 2 --depth 2")
+        );
+        assert_eq!(
+            output.pagination.prev_page_command.as_deref(),
+            Some("mcptools hn read 500 --thread 1 --thread-page 1 --thread-limit 2 --depth 2")
+        );
+
+        let json = serde_json::to_value(&output).unwrap();
+        assert_eq!(json["replies"][1]["id"], 131);
+        assert_eq!(json["replies"][1]["depth"], 2);
+        assert_eq!(json["max_depth"], 2);
+    }
+
+    #[test]
+    fn test_thread_reply_counts_skip_dead() {
+        let root = flagged_comment(1, false, false, Some(vec![11]));
+        let replies = vec![
+            flagged_comment(11, false, false, Some(vec![111, 112, 113])),
+            flagged_comment(111, false, false, None),
+            flagged_comment(112, false, true, None),
+            flagged_comment(113, true, false, None),
+        ];
+
+        let output = build_thread_output(500, &root, &[11], replies.clone(), false, 1, 10, None);
+        assert_eq!(output.hidden_replies, 2);
+        assert_eq!(output.replies[0].comment.replies_count, 1);
+
+        let shown = build_thread_output(500, &root, &[11], replies, true, 1, 10, None);
+        assert_eq!(shown.replies[0].comment.replies_count, 3);
+        let flags: Vec<(bool, bool)> = shown
+            .replies
+            .iter()
+            .map(|reply| (reply.comment.deleted, reply.comment.dead))
+            .collect();
+        assert_eq!(
+            flags,
+            vec![(false, false), (false, false), (false, true), (true, false)]
+        );
+
+        let json = serde_json::to_value(&shown).unwrap();
+        assert!(json["replies"][0].get("dead").is_none());
+        assert_eq!(json["replies"][2]["dead"], true);
+        assert_eq!(json["replies"][3]["deleted"], true);
+    }
+
+    #[test]
+    fn test_build_poll_options() {
+        let poll = HnItem {
+            item_type: "poll".to_string(),
+            parts: Some(vec![11, 12, 13]),
+            ..flagged_comment(10, false, false, None)
+        };
+        assert!(is_readable_post(&poll));
+        assert!(!is_readable_post(&flagged_comment(1, false, false, None)));
+
+        let option = |id, text: &str, score| HnItem {
+            item_type: "pollopt".to_string(),
+            text: Some(text.to_string()),
+            score: Some(score),
+            parent: Some(10),
+            ..flagged_comment(id, false, false, None)
+        };
+        let removed = HnItem {
+            item_type: "pollopt".to_string(),
+            ..flagged_comment(13, true, false, None)
+        };
+
+        let options = build_poll_options(vec![
+            option(11, "Rust &amp; C", 40),
+            option(12, "<i>Neither</i>", 2),
+            removed,
+        ]);
+        assert_eq!(options.len(), 2);
+        assert_eq!(options[0].id, 11);
+        assert_eq!(options[0].text.as_deref(), Some("Rust & C"));
+        assert_eq!(options[0].score, Some(40));
+        assert_eq!(options[1].text.as_deref(), Some("Neither"));
+
+        // Other posts leave the field out of the JSON entirely.
+        let output = build_post_output(poll, vec![], 1, 10, 0);
+        let json = serde_json::to_value(&output).unwrap();
+        assert!(json.get("poll_options").is_none());
+    }
+
+    #[test]
+    fn test_build_user_output() {
+        let user = HnUser {
+            id: "pg".to_string(),
+            created: 1160418092,
+            karma: 155000,
+            about: Some(
+                "Bug fixer.<p>Essays at <a href=\"http://paulgraham.com\">paulgraham.com</a>"
+                    .to_string(),
+            ),
+            submitted: Some(vec![3, 2, 1]),
+        };
+        let story = HnItem {
+            title: Some("An essay".to_string()),
+            url: Some("http://paulgraham.com/x.html".to_string()),
+            score: Some(300),
+            item_type: "story".to_string(),
+            ..flagged_comment(3, false, false, None)
+        };
+        let comment = HnItem {
+            text: Some(format!("Long &quot;reply&quot;<p>{}", "x".repeat(300))),
+            ..flagged_comment(2, false, false, None)
+        };
+        let deleted = flagged_comment(1, true, false, None);
+
+        let output = build_user_output(user, vec![story, comment, deleted]);
+        assert_eq!(output.id, "pg");
+        assert_eq!(output.created.as_deref(), Some("2006-10-09 18:21:32 UTC"));
+        assert_eq!(
+            output.about.as_deref(),
+            Some("Bug fixer.\n\nEssays at paulgraham.com")
+        );
+        assert_eq!(output.total_submissions, 3);
+        assert_eq!(output.recent_submissions.len(), 2);
+
+        let story = &output.recent_submissions[0];
+        assert_eq!(story.title.as_deref(), Some("An essay"));
+        assert_eq!(story.text, None);
+
+        let comment = &output.recent_submissions[1];
+        assert_eq!(comment.item_type, "comment");
+        let text = comment.text.as_deref().unwrap();
+        assert!(text.starts_with("Long \"reply\" xxx"));
+        assert!(text.ends_with("..."));
+        assert_eq!(text.chars().count(), SUBMISSION_EXCERPT_CHARS + 3);
+    }
+
+    #[test]
+    fn test_render_story_markdown() {
+        let story = HnItem {
+            id: 1,
+            item_type: "story".to_string(),
+            title: Some("Show HN: \"Quotes\": a test".to_string()),
+            url: Some("https://example.com".to_string()),
+            score: Some(42),
+            text: None,
+            parent: None,
+            ..flagged_comment(1, false, false, Some(vec![10, 20]))
+        };
+        let comment = |id, parent, text: &str| HnItem {
+            text: Some(text.to_string()),
+            parent: Some(parent),
+            ..flagged_comment(id, false, false, None)
+        };
+        let comments = vec![
+            comment(10, 1, "Top <i>level</i><p>Second paragraph"),
+            comment(11, 10, "A reply<p><pre><code>  code()\n</code></pre>"),
+            comment(20, 1, "Another"),
+        ];
+
+        let doc = render_story_markdown(&story, &comments, "2024-01-01T00:00:00Z");
+        assert!(doc.starts_with("---\nid: 1\ntitle: \"Show HN: \\\"Quotes\\\": a test\"\n"));
+        assert!(doc.contains("comments: 3\n"));
+        assert!(doc.contains("exported_at: \"2024-01-01T00:00:00Z\"\n---\n"));
+        assert!(doc.contains("**Article:** <https://example.com>"));
+        assert!(doc.contains("## Comments (3)"));
+        assert!(doc.contains(
+            "> **someone** · 2021-01-01 00:00:00 UTC · [#10](https://news.ycombinator.com/item?id=10)\n>\n> Top *level*\n>\n> Second paragraph\n"
+        ));
+        assert!(doc.contains(">> A reply\n>>\n>> ```\n>>   code()\n>> ```\n"));
+        assert!(doc.contains("\n> Another\n"));
+
+        let empty = render_story_markdown(&story, &[], "now");
+        assert!(empty.contains("_No comments._"));
+    }
+
+    #[test]
+    fn test_build_updates_output() {
+        let updates: HnUpdates = serde_json::from_value(serde_json::json!({
+            "items": [3, 2, 1],
+            "profiles": ["pg", "dang"]
+        }))
+        .unwrap();
+        let story = HnItem {
+            title: Some("An essay".to_string()),
+            score: Some(300),
+            descendants: Some(12),
+            item_type: "story".to_string(),
+            parent: None,
+            ..flagged_comment(3, false, false, None)
+        };
+        let comment = HnItem {
+            text: Some("Nice<p>essay".to_string()),
+            ..flagged_comment(2, false, false, None)
+        };
+        let deleted = flagged_comment(1, true, false, None);
+        let user = HnUser {
+            id: "pg".to_string(),
+            created: 1160418092,
+            karma: 155000,
+            about: None,
+            submitted: Some(vec![1, 2]),
+        };
+
+        let output = build_updates_output(&updates, vec![story, comment, deleted], vec![user]);
+        assert_eq!(output.total_items, 3);
+        assert_eq!(output.total_profiles, 2);
+        assert_eq!(output.items.len(), 2);
+        assert_eq!(output.items[0].comments, Some(12));
+        assert_eq!(output.items[1].text.as_deref(), Some("Nice essay"));
+        assert_eq!(output.items[1].parent, Some(1));
+        assert_eq!(output.profiles[0].id, "pg");
+        assert_eq!(output.profiles[0].total_submissions, 2);
+    }
+
+    #[test]
+    fn test_search_numeric_filters() {
+        assert_eq!(search_numeric_filters(None, None), None);
+        assert_eq!(
+            search_numeric_filters(Some(100), None).as_deref(),
+            Some("created_at_i>=100")
+        );
+        assert_eq!(
+            search_numeric_filters(Some(100), Some(200)).as_deref(),
+            Some("created_at_i>=100,created_at_i<200")
+        );
+    }
+
+    #[test]
+    fn test_build_search_output() {
+        let response: HnSearchResponse = serde_json::from_value(serde_json::json!({
+            "hits": [
+                {
+                    "objectID": "8863",
+                    "_tags": ["story", "author_dhouston", "story_8863"],
+                    "title": "My YC app: Dropbox",
+                    "url": "http://www.getdropbox.com/u/2/screencast.html",
+                    "author": "dhouston",
+                    "points": 104,
+                    "num_comments": 71,
+                    "created_at_i": 1175714200,
+                    "story_id": 8863
+                },
+                {
+                    "objectID": "9224",
+                    "_tags": ["comment", "author_BrandonM", "story_8863"],
+                    "title": null,
+                    "url": null,
+                    "author": "BrandonM",
+                    "comment_text": "I have a few <i>qualms</i> with this app",
+                    "story_id": 8863,
+                    "story_title": "My YC app: Dropbox"
+                },
+                { "objectID": "not-a-number", "_tags": ["story"] }
+            ],
+            "nbHits": 45,
+            "nbPages": 3,
+            "page": 1
+        }))
+        .unwrap();
+
+        let output = build_search_output(
+            response,
+            "dropbox",
+            Some("story".to_string()),
+            2,
+            20,
+            " --tags story",
+        );
+        assert_eq!(output.items.len(), 2);
+
+        let story = &output.items[0];
+        assert_eq!(story.item_type, "story");
+        assert_eq!(story.score, Some(104));
+        assert_eq!(story.story_id, None);
+        assert_eq!(story.time.as_deref(), Some("2007-04-04 19:16:40 UTC"));
+
+        let comment = &output.items[1];
+        assert_eq!(comment.item_type, "comment");
+        assert_eq!(comment.title.as_deref(), Some("My YC app: Dropbox"));
+        assert_eq!(
+            comment.text.as_deref(),
+            Some("I have a few qualms with this app")
+        );
+        assert_eq!(comment.story_id, Some(8863));
+
+        assert_eq!(output.pagination.total_items, 45);
+        assert_eq!(output.pagination.total_pages, 3);
+        assert_eq!(
+            output.pagination.next_page_command.as_deref(),
+            Some("mcptools hn search \"dropbox\" --page 3 --tags story")
+        );
+        assert_eq!(
+            output.pagination.prev_page_command.as_deref(),
+            Some("mcptools hn search \"dropbox\" --page 1 --tags story")
+        );
+    }
 }
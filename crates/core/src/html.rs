@@ -0,0 +1,615 @@
+//! CSS and XPath selection over HTML documents
+//!
+//! Pure functions that run a CSS selector against an HTML document and
+//! render the matched fragments as text, Markdown, HTML, or JSON. XPath is
+//! supported for the location-path subset that maps onto CSS: `/` and `//`
+//! steps, `*`, and `[n]`, `[last()]`, `[@a]`, `[@a='v']`,
+//! `[contains(@a,'v')]`, and `[starts-with(@a,'v')]` predicates, with an
+//! optional trailing `/@attr` or `/text()`.
+
+use scraper::{ElementRef, Html, Selector};
+use serde::Serialize;
+use std::collections::BTreeMap;
+
+/// How matched fragments are rendered.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SelectFormat {
+    /// Whitespace-normalized text (or the attribute value), one per line
+    Text,
+    /// Each fragment converted to Markdown, separated by blank lines
+    Markdown,
+    /// Each fragment's outer HTML, one per line
+    Html,
+    /// The matches as a JSON array
+    Json,
+}
+
+/// One matched element.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct HtmlMatch {
+    pub tag: String,
+    pub text: String,
+    /// Value of the requested attribute, when one was asked for
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub value: Option<String>,
+    pub attributes: BTreeMap<String, String>,
+    pub html: String,
+}
+
+/// A `<table>` flattened to text cells. `colspan` and `rowspan` cells are
+/// repeated into every position they cover, and rows are padded to a
+/// common width.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct HtmlTable {
+    /// Position among the tables in the document, from 0
+    pub index: usize,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caption: Option<String>,
+    /// Column headers; empty when the table has none
+    pub headers: Vec<String>,
+    pub rows: Vec<Vec<String>>,
+}
+
+/// An XPath expression translated to CSS.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct XPathSelector {
+    pub css: String,
+    /// Set by a trailing `/@name` step
+    pub attribute: Option<String>,
+    /// Set by a trailing `/text()` step
+    pub text: bool,
+}
+
+fn css_string(value: &str) -> String {
+    format!("\"{}\"", value.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+fn is_name_char(c: char) -> bool {
+    c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | ':' | '.')
+}
+
+/// Read a quoted XPath literal at the start of `s`, returning it and the rest.
+fn xpath_literal(s: &str) -> Option<(&str, &str)> {
+    let quote = s.chars().next().filter(|c| *c == '\'' || *c == '"')?;
+    let end = s[1..].find(quote)? + 1;
+    Some((&s[1..end], &s[end + 1..]))
+}
+
+fn xpath_predicate(predicate: &str) -> Result<String, String> {
+    let p = predicate.trim();
+    if let Ok(n) = p.parse::<usize>() {
+        if n == 0 {
+            return Err("XPath positions start at 1".to_string());
+        }
+        return Ok(format!(":nth-of-type({})", n));
+    }
+    if p == "last()" {
+        return Ok(":last-of-type".to_string());
+    }
+    if let Some(rest) = p.strip_prefix('@') {
+        let name_len = rest.find(|c| !is_name_char(c)).unwrap_or(rest.len());
+        let (name, rest) = rest.split_at(name_len);
+        let rest = rest.trim_start();
+        if name.is_empty() {
+            return Err(format!("Unsupported XPath predicate [{}]", predicate));
+        }
+        if rest.is_empty() {
+            return Ok(format!("[{}]", name));
+        }
+        if let Some(value) = rest.strip_prefix('=') {
+            if let Some((literal, "")) = xpath_literal(value.trim()).map(|(l, r)| (l, r.trim())) {
+                return Ok(format!("[{}={}]", name, css_string(literal)));
+            }
+        }
+        return Err(format!("Unsupported XPath predicate [{}]", predicate));
+    }
+    for (function, operator) in [("contains", "*="), ("starts-with", "^=")] {
+        let Some(args) = p
+            .strip_prefix(function)
+            .and_then(|r| r.trim_start().strip_prefix('('))
+            .and_then(|r| r.trim_end().strip_suffix(')'))
+        else {
+            continue;
+        };
+        let Some((attribute, literal)) = args.split_once(',') else {
+            break;
+        };
+        let attribute = attribute.trim().strip_prefix('@');
+        let literal = xpath_literal(literal.trim()).filter(|(_, rest)| rest.trim().is_empty());
+        if let (Some(attribute), Some((literal, _))) = (attribute, literal) {
+            if !attribute.is_empty() && attribute.chars().all(is_name_char) {
+                return Ok(format!(
+                    "[{}{}{}]",
+                    attribute,
+                    operator,
+                    css_string(literal)
+                ));
+            }
+        }
+        break;
+    }
+    Err(format!("Unsupported XPath predicate [{}]", predicate))
+}
+
+/// Translate the supported XPath subset (see the module docs) to CSS.
+pub fn xpath_to_css(xpath: &str) -> Result<XPathSelector, String> {
+    let mut rest = xpath.trim();
+    if rest.is_empty() {
+        return Err("Empty XPath expression".to_string());
+    }
+    let mut css = String::new();
+    let mut attribute = None;
+    let mut text = false;
+    let mut first = true;
+
+    while !rest.is_empty() {
+        let descendant = if let Some(r) = rest.strip_prefix("//") {
+            rest = r;
+            true
+        } else if let Some(r) = rest.strip_prefix('/') {
+            rest = r;
+            false
+        } else if first {
+            // A relative path is read as searching the whole document.
+            true
+        } else {
+            return Err(format!("Unexpected '{}' in XPath", rest));
+        };
+
+        if let Some(name) = rest.strip_prefix('@') {
+            if css.is_empty() || !name.chars().all(is_name_char) || name.is_empty() {
+                return Err(format!("Unsupported XPath attribute step '@{}'", name));
+            }
+            attribute = Some(name.to_string());
+            break;
+        }
+        if rest == "text()" {
+            if css.is_empty() {
+                return Err("text() needs an element step before it".to_string());
+            }
+            text = true;
+            break;
+        }
+
+        let name_len = rest
+            .find(|c: char| !(is_name_char(c) || c == '*'))
+            .unwrap_or(rest.len());
+        let (name, after) = rest.split_at(name_len);
+        if name.is_empty() || (name.contains('*') && name != "*") || name.starts_with('.') {
+            return Err(format!(
+                "Unsupported XPath step at '{}' (only element names and * are supported)",
+                rest
+            ));
+        }
+        rest = after;
+
+        if !first {
+            css.push_str(if descendant { " " } else { " > " });
+        }
+        css.push_str(name);
+        if first && !descendant {
+            css.push_str(":root");
+        }
+
+        while let Some(r) = rest.strip_prefix('[') {
+            let end = r
+                .find(']')
+                .ok_or_else(|| "Unterminated XPath predicate".to_string())?;
+            let mut predicate = xpath_predicate(&r[..end])?;
+            if name == "*" && predicate.starts_with(":nth-of-type") {
+                predicate = predicate.replace(":nth-of-type", ":nth-child");
+            } else if name == "*" && predicate == ":last-of-type" {
+                predicate = ":last-child".to_string();
+            }
+            css.push_str(&predicate);
+            rest = &r[end + 1..];
+        }
+        first = false;
+    }
+
+    Ok(XPathSelector {
+        css,
+        attribute,
+        text,
+    })
+}
+
+fn normalize_text(element: &ElementRef) -> String {
+    element
+        .text()
+        .flat_map(str::split_whitespace)
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Run a CSS selector against an HTML document (or fragment). With
+/// `attribute`, elements without that attribute are skipped. At most
+/// `limit` matches are returned.
+pub fn select_html(
+    html: &str,
+    css: &str,
+    attribute: Option<&str>,
+    limit: usize,
+) -> Result<Vec<HtmlMatch>, String> {
+    let document = Html::parse_document(html);
+    let selector =
+        Selector::parse(css).map_err(|e| format!("Invalid CSS selector '{css}': {e:?}"))?;
+
+    Ok(document
+        .select(&selector)
+        .filter_map(|element| {
+            let value = match attribute {
+                Some(name) => Some(element.value().attr(name)?.to_string()),
+                None => None,
+            };
+            Some(HtmlMatch {
+                tag: element.value().name().to_string(),
+                text: normalize_text(&element),
+                value,
+                attributes: element
+                    .value()
+                    .attrs()
+                    .map(|(k, v)| (k.to_string(), v.to_string()))
+                    .collect(),
+                html: element.html(),
+            })
+        })
+        .take(limit)
+        .collect())
+}
+
+/// Largest `colspan` or `rowspan` honored, so a malformed attribute can't
+/// blow up the grid.
+const MAX_SPAN: usize = 1000;
+
+fn span_attr(cell: &ElementRef, name: &str) -> usize {
+    cell.value()
+        .attr(name)
+        .and_then(|v| v.trim().parse::<usize>().ok())
+        .unwrap_or(1)
+        .clamp(1, MAX_SPAN)
+}
+
+/// Nearest ancestor of `node` with one of `names`, as an element.
+fn nearest_ancestor<'a>(node: &ElementRef<'a>, names: &[&str]) -> Option<ElementRef<'a>> {
+    node.ancestors()
+        .filter_map(ElementRef::wrap)
+        .find(|e| names.contains(&e.value().name()))
+}
+
+/// Lay out rows of `(text, colspan, rowspan)` cells on a grid, carrying
+/// `rowspan` cells down into the rows below.
+fn layout_rows(rows: Vec<Vec<(String, usize, usize)>>) -> Vec<Vec<String>> {
+    // Per column: the spanning cell's text and how many more rows it covers.
+    let mut pending: Vec<Option<(String, usize)>> = Vec::new();
+    let mut grid = Vec::with_capacity(rows.len());
+
+    for cells in rows {
+        let mut row = Vec::new();
+        let mut cells = cells.into_iter();
+        loop {
+            let col = row.len();
+            if let Some(Some((text, remaining))) = pending.get_mut(col) {
+                row.push(text.clone());
+                *remaining -= 1;
+                if *remaining == 0 {
+                    pending[col] = None;
+                }
+                continue;
+            }
+            match cells.next() {
+                Some((text, colspan, rowspan)) => {
+                    for _ in 0..colspan {
+                        let col = row.len();
+                        if pending.len() <= col {
+                            pending.resize(col + 1, None);
+                        }
+                        if rowspan > 1 {
+                            pending[col] = Some((text.clone(), rowspan - 1));
+                        }
+                        row.push(text.clone());
+                    }
+                }
+                // Out of cells: fill gaps only up to the last carried cell.
+                None if pending[col.min(pending.len())..]
+                    .iter()
+                    .any(Option::is_some) =>
+                {
+                    row.push(String::new());
+                }
+                None => break,
+            }
+        }
+        grid.push(row);
+    }
+    grid
+}
+
+/// Parse every `<table>` in an HTML document or fragment. Header rows come
+/// from `<thead>` (several rows are joined per column with " / "), or else
+/// from a first row made only of `<th>` cells. Rows of nested tables belong
+/// to the nested table only.
+pub fn extract_tables(html: &str) -> Vec<HtmlTable> {
+    let document = Html::parse_document(html);
+    let table_selector = Selector::parse("table").expect("valid selector");
+    let row_selector = Selector::parse("tr").expect("valid selector");
+    let caption_selector = Selector::parse("caption").expect("valid selector");
+
+    document
+        .select(&table_selector)
+        .enumerate()
+        .map(|(index, table)| {
+            let mut head = Vec::new();
+            let mut body = Vec::new();
+            let mut first_row_all_th = false;
+            for tr in table.select(&row_selector) {
+                if nearest_ancestor(&tr, &["table"]).map(|t| t.id()) != Some(table.id()) {
+                    continue;
+                }
+                let cells: Vec<ElementRef> = tr
+                    .children()
+                    .filter_map(ElementRef::wrap)
+                    .filter(|c| matches!(c.value().name(), "th" | "td"))
+                    .collect();
+                if head.is_empty() && body.is_empty() {
+                    first_row_all_th =
+                        !cells.is_empty() && cells.iter().all(|c| c.value().name() == "th");
+                }
+                let cells = cells
+                    .iter()
+                    .map(|c| {
+                        (
+                            normalize_text(c),
+                            span_attr(c, "colspan"),
+                            span_attr(c, "rowspan"),
+                        )
+                    })
+                    .collect();
+                let section = nearest_ancestor(&tr, &["thead", "table"]);
+                if section.is_some_and(|s| s.value().name() == "thead") {
+                    head.push(cells);
+                } else {
+                    body.push(cells);
+                }
+            }
+
+            let head_rows = head.len();
+            let mut grid = layout_rows(head.into_iter().chain(body).collect());
+            let header_rows: Vec<Vec<String>> = if head_rows > 0 {
+                grid.drain(..head_rows).collect()
+            } else if first_row_all_th && !grid.is_empty() {
+                vec![grid.remove(0)]
+            } else {
+                Vec::new()
+            };
+
+            let width = header_rows
+                .iter()
+                .chain(&grid)
+                .map(Vec::len)
+                .max()
+                .unwrap_or(0);
+            let headers = if header_rows.is_empty() {
+                Vec::new()
+            } else {
+                (0..width)
+                    .map(|col| {
+                        let mut parts: Vec<&str> = Vec::new();
+                        for row in &header_rows {
+                            match row.get(col).map(String::as_str) {
+                                Some(text) if !text.is_empty() && !parts.contains(&text) => {
+                                    parts.push(text)
+                                }
+                                _ => {}
+                            }
+                        }
+                        parts.join(" / ")
+                    })
+                    .collect()
+            };
+            for row in &mut grid {
+                row.resize(width, String::new());
+            }
+
+            HtmlTable {
+                index,
+                caption: table
+                    .select(&caption_selector)
+                    .next()
+                    .map(|c| normalize_text(&c))
+                    .filter(|c| !c.is_empty()),
+                headers,
+                rows: grid,
+            }
+        })
+        .collect()
+}
+
+/// Render a table as RFC 4180 CSV, with a header line when it has headers.
+pub fn table_to_csv(table: &HtmlTable) -> String {
+    let field = |value: &str| {
+        if value.contains([',', '"', '\n', '\r']) {
+            format!("\"{}\"", value.replace('"', "\"\""))
+        } else {
+            value.to_string()
+        }
+    };
+    let headers = (!table.headers.is_empty()).then_some(&table.headers);
+    headers
+        .into_iter()
+        .chain(&table.rows)
+        .map(|row| row.iter().map(|v| field(v)).collect::<Vec<_>>().join(",") + "\n")
+        .collect()
+}
+
+/// Render matches in the given format.
+pub fn format_matches(matches: &[HtmlMatch], format: SelectFormat) -> String {
+    match format {
+        SelectFormat::Text => matches
+            .iter()
+            .map(|m| m.value.clone().unwrap_or_else(|| m.text.clone()))
+            .collect::<Vec<_>>()
+            .join("\n"),
+        SelectFormat::Markdown => matches
+            .iter()
+            .map(|m| match &m.value {
+                Some(value) => value.clone(),
+                None => html2md::parse_html(&crate::md::clean_html(&m.html))
+                    .trim()
+                    .to_string(),
+            })
+            .collect::<Vec<_>>()
+            .join("\n\n"),
+        SelectFormat::Html => matches
+            .iter()
+            .map(|m| m.html.as_str())
+            .collect::<Vec<_>>()
+            .join("\n"),
+        SelectFormat::Json => serde_json::to_string_pretty(matches).unwrap_or_default(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_extract_tables() {
+        let html = r#"
+            <table>
+              <caption> Plans </caption>
+              <thead>
+                <tr><th rowspan="2">Plan</th><th colspan="2">Price</th></tr>
+                <tr><th>Monthly</th><th>Yearly</th></tr>
+              </thead>
+              <tbody>
+                <tr><td>Free</td><td colspan="2">$0</td></tr>
+                <tr><td rowspan="2">Pro, "team"</td><td>$10</td><td>$100</td></tr>
+                <tr><td>$12</td></tr>
+              </tbody>
+            </table>
+            <table>
+              <tr><td>a</td><td><table><tr><th>inner</th></tr></table></td></tr>
+              <tr><td>b</td></tr>
+            </table>"#;
+        let tables = extract_tables(html);
+        assert_eq!(tables.len(), 3);
+
+        let plans = &tables[0];
+        assert_eq!(plans.caption.as_deref(), Some("Plans"));
+        assert_eq!(plans.headers, ["Plan", "Price / Monthly", "Price / Yearly"]);
+        assert_eq!(
+            plans.rows,
+            [
+                ["Free", "$0", "$0"],
+                ["Pro, \"team\"", "$10", "$100"],
+                ["Pro, \"team\"", "$12", ""],
+            ]
+        );
+        assert_eq!(
+            table_to_csv(plans),
+            "Plan,Price / Monthly,Price / Yearly\nFree,$0,$0\n\"Pro, \"\"team\"\"\",$10,$100\n\"Pro, \"\"team\"\"\",$12,\n"
+        );
+
+        // The outer table has no header row and keeps only its own rows.
+        let outer = &tables[1];
+        assert!(outer.headers.is_empty());
+        assert_eq!(outer.rows, [["a", "inner"], ["b", ""]]);
+        assert_eq!(tables[2].headers, ["inner"]);
+        assert!(tables[2].rows.is_empty());
+    }
+
+    const PAGE: &str = r#"<html><body>
+        <table class="results">
+          <tr><td>Alpha</td><td><a href="/a" class="link primary">A  link</a></td></tr>
+          <tr><td>Beta</td><td><a href="/b">B</a></td></tr>
+        </table>
+        <div id="note"><p>First <b>bold</b></p><p>Second</p></div>
+    </body></html>"#;
+
+    #[test]
+    fn test_select_css() {
+        let matches = select_html(PAGE, "table.results td", None, 100).unwrap();
+        assert_eq!(matches.len(), 4);
+        assert_eq!(matches[1].text, "A link");
+        assert_eq!(matches[1].tag, "td");
+
+        let links = select_html(PAGE, "a", Some("href"), 100).unwrap();
+        assert_eq!(format_matches(&links, SelectFormat::Text), "/a\n/b");
+        assert_eq!(links[0].attributes["class"], "link primary");
+
+        assert_eq!(select_html(PAGE, "td", None, 1).unwrap().len(), 1);
+        assert!(select_html(PAGE, "a", Some("title"), 100)
+            .unwrap()
+            .is_empty());
+        assert!(select_html(PAGE, "td[", None, 100).is_err());
+    }
+
+    #[test]
+    fn test_format_matches() {
+        let matches = select_html(PAGE, "#note p", None, 100).unwrap();
+        assert_eq!(
+            format_matches(&matches, SelectFormat::Text),
+            "First bold\nSecond"
+        );
+        assert_eq!(
+            format_matches(&matches, SelectFormat::Markdown),
+            "First **bold**\n\nSecond"
+        );
+        assert_eq!(
+            format_matches(&matches, SelectFormat::Html),
+            "<p>First <b>bold</b></p>\n<p>Second</p>"
+        );
+        let json: serde_json::Value =
+            serde_json::from_str(&format_matches(&matches, SelectFormat::Json)).unwrap();
+        assert_eq!(json[0]["tag"], "p");
+        assert_eq!(json[1]["text"], "Second");
+        assert!(json[0].get("value").is_none());
+    }
+
+    #[test]
+    fn test_xpath_to_css() {
+        let cases = [
+            (
+                "//table[@class='results']//td",
+                "table[class=\"results\"] td",
+            ),
+            ("/html/body/div", "html:root > body > div"),
+            ("//tr[2]/td[last()]", "tr:nth-of-type(2) > td:last-of-type"),
+            ("//*[1]", "*:nth-child(1)"),
+            ("div/p", "div > p"),
+            (
+                "//a[contains(@class, 'link')][starts-with(@href,\"/\")]",
+                "a[class*=\"link\"][href^=\"/\"]",
+            ),
+            ("//a[@href]", "a[href]"),
+        ];
+        for (xpath, css) in cases {
+            assert_eq!(xpath_to_css(xpath).unwrap().css, css, "{}", xpath);
+        }
+
+        let selector = xpath_to_css("//a/@href").unwrap();
+        assert_eq!(selector.css, "a");
+        assert_eq!(selector.attribute.as_deref(), Some("href"));
+        assert!(xpath_to_css("//p/text()").unwrap().text);
+
+        for bad in [
+            "",
+            "//a[position()>1]",
+            "//a/..",
+            "//a[0]",
+            "//@href",
+            "//a[@x='1'",
+        ] {
+            assert!(xpath_to_css(bad).is_err(), "{}", bad);
+        }
+    }
+
+    #[test]
+    fn test_xpath_selects_like_css() {
+        let selector = xpath_to_css("//table[@class='results']//tr[2]/td[1]").unwrap();
+        let matches = select_html(PAGE, &selector.css, None, 100).unwrap();
+        assert_eq!(format_matches(&matches, SelectFormat::Text), "Beta");
+    }
+}
@@ -0,0 +1,1183 @@
+//! jq and JSONPath queries over JSON values
+//!
+//! jq queries run on the embedded jaq engine (`jaq-core`, `jaq-std`, and
+//! `jaq-json`) with its standard library: paths, variables and
+//! destructuring, `reduce`/`foreach`, `def`, `try`/`catch`, regex functions,
+//! formats, and so on. Nothing reads files, the environment, or further
+//! inputs: modules cannot be loaded, `env` is not defined, `input` and
+//! `inputs` are unknown, and `halt` ends the query with an error.
+//!
+//! Every run has a [`Budget`]. The query and the standard library are
+//! compiled with a check before each definition body, pipe stage, `,`
+//! operand, and `reduce`/`foreach` update, and after each value `range`
+//! yields. The check stops the run once the step count or the deadline runs
+//! out, or when the native stack that jaq recurses on is nearly exhausted.
+//! Loops such as
+//! `until(false; .+1)` and runaway recursion therefore end with
+//! [`JqError::Aborted`] instead of running forever or aborting the
+//! process.
+//!
+//! A query starting with `$` is read as JSONPath instead (`$.store.book[0]`,
+//! `$..author`, `$.items[*]`, `$.items[?(@.price < 10)]`), translated to jq,
+//! and yields the matched values.
+//!
+//! Queries are limited to [`MAX_QUERY_LEN`] bytes and [`MAX_DEPTH`] levels of
+//! nesting, and a query may yield at most [`MAX_RESULTS`] values per input.
+
+use std::cell::RefCell;
+use std::time::{Duration, Instant};
+
+use jaq_core::box_iter::box_once;
+use jaq_core::load::parse::{BinaryOp, Def, Pattern, Term};
+use jaq_core::load::{Arena, File, Loader};
+use jaq_core::path::{Part, Path};
+use jaq_core::{data, Ctx, Exn, Native, Vars};
+use jaq_json::Val;
+use serde::Deserialize;
+use serde_json::Value;
+
+/// Most values a query may yield for one input.
+pub const MAX_RESULTS: usize = 100_000;
+
+/// Longest query accepted, in bytes.
+pub const MAX_QUERY_LEN: usize = 2048;
+
+/// Deepest parenthesis/bracket/brace nesting accepted; the jaq parser and
+/// evaluator recurse once per level.
+pub const MAX_DEPTH: usize = 64;
+
+/// Evaluation steps a run may take by default (see [`Budget`]).
+pub const MAX_STEPS: u64 = 100_000_000;
+
+/// Native stack kept free for jaq: a run stops once less than this remains.
+const STACK_RESERVE: usize = 768 * 1024;
+
+/// Native filter that charges one step of the current run's [`Budget`].
+const TICK: &str = "_tick";
+
+/// `range/3` over jaq's native generator, so every value it yields is
+/// charged; the native is registered as `_range`.
+const RANGE_DEF: &str = "def range($from; $upto; $by): _range($from; $upto; $by) | .;";
+
+/// jq formats that the jaq standard library leaves to its command-line tool.
+const FORMAT_DEFS: &str = r#"
+def @csv: if type == "array" then . else error("\(type) cannot be csv-formatted, only an array can be") end
+  | map(if type == "string" then "\"" + gsub("\""; "\"\"") + "\"" elif . == null then "" else tojson end)
+  | join(",");
+def @tsv: if type == "array" then . else error("\(type) cannot be tsv-formatted, only an array can be") end
+  | map(if type == "string" then gsub("\\\\"; "\\\\") | gsub("\t"; "\\t") | gsub("\n"; "\\n") | gsub("\r"; "\\r") elif . == null then "" else tojson end)
+  | join("\t");
+"#;
+
+/// Native jaq functions left out because they reach outside the query.
+const DISABLED_FUNCTIONS: &[&str] = &["env"];
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum JqError {
+    /// Malformed query; the offset is the byte position of the token
+    Syntax(usize, String),
+    /// Evaluation failed (bad index, type mismatch, `error` called)
+    Runtime(String),
+    TooManyResults,
+    /// The run used up its [`Budget`] or recursed too deeply
+    Aborted(String),
+}
+
+impl std::fmt::Display for JqError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            JqError::Syntax(pos, message) => {
+                write!(f, "Query syntax error at byte {}: {}", pos, message)
+            }
+            JqError::Runtime(message) => write!(f, "{}", message),
+            JqError::TooManyResults => {
+                write!(f, "Query produced more than {} values", MAX_RESULTS)
+            }
+            JqError::Aborted(message) => write!(f, "{}", message),
+        }
+    }
+}
+
+impl std::error::Error for JqError {}
+
+type JqResult<T> = Result<T, JqError>;
+
+fn syntax(pos: usize, message: impl Into<String>) -> JqError {
+    JqError::Syntax(pos, message.into())
+}
+
+fn runtime(message: impl Into<String>) -> JqError {
+    JqError::Runtime(message.into())
+}
+
+// ---------------------------------------------------------------------------
+// jq (jaq)
+// ---------------------------------------------------------------------------
+
+type Data = data::JustLut<Val>;
+type Filter = jaq_core::Filter<Data>;
+
+/// Byte offset of `part` within `code`, or 0 when it points elsewhere (an
+/// error in the standard library definitions).
+fn offset(code: &str, part: &str) -> usize {
+    let base = code.as_ptr() as usize;
+    let ptr = part.as_ptr() as usize;
+    if (base..=base + code.len()).contains(&ptr) {
+        ptr - base
+    } else {
+        0
+    }
+}
+
+/// Deepest nesting of `(`, `[`, `{`, and string interpolation in `code`.
+fn nesting_depth(code: &str) -> usize {
+    // `"` entries are strings, in which only `\(` opens nested code.
+    let mut stack = Vec::new();
+    let mut deepest = 0;
+    let mut chars = code.chars();
+    while let Some(c) = chars.next() {
+        let in_string = stack.last() == Some(&'"');
+        match c {
+            '\\' if in_string && chars.next() == Some('(') => stack.push('('),
+            '"' if in_string => {
+                stack.pop();
+            }
+            _ if in_string => {}
+            '#' => {
+                chars.by_ref().find(|&c| c == '\n');
+            }
+            '"' | '(' | '[' | '{' => stack.push(c),
+            ')' | ']' | '}' => {
+                stack.pop();
+            }
+            _ => {}
+        }
+        deepest = deepest.max(stack.len());
+    }
+    deepest
+}
+
+fn load_error(code: &str, error: jaq_core::load::Error<&str>) -> JqError {
+    use jaq_core::load::Error;
+    match error {
+        Error::Io(errors) => {
+            let (path, _) = errors.into_iter().next().unwrap_or(("", String::new()));
+            syntax(
+                offset(code, path),
+                format!("cannot load module {}: modules are not available", path),
+            )
+        }
+        Error::Lex(errors) => match errors.into_iter().next() {
+            Some((expect, found)) => {
+                syntax(offset(code, found), format!("expected {}", expect.as_str()))
+            }
+            None => syntax(0, "invalid query"),
+        },
+        Error::Parse(errors) => match errors.into_iter().next() {
+            Some((expect, "")) => syntax(
+                code.len(),
+                format!("unexpected end of query, expected {}", expect.as_str()),
+            ),
+            Some((expect, found)) => syntax(
+                offset(code, found),
+                format!("expected {}, found '{}'", expect.as_str(), found),
+            ),
+            None => syntax(0, "invalid query"),
+        },
+    }
+}
+
+fn compile_error(code: &str, name: &str, undefined: jaq_core::compile::Undefined) -> JqError {
+    use jaq_core::compile::Undefined;
+    let message = match undefined {
+        Undefined::Filter(arity) => format!("unknown function {}/{}", name, arity),
+        Undefined::Var => format!("undefined variable {}", name),
+        Undefined::Label => format!("undefined label {}", name),
+        Undefined::Mod => format!("unknown module {}", name),
+        _ => format!("undefined symbol {}", name),
+    };
+    syntax(offset(code, name), message)
+}
+
+/// Limits on a single [`Query`] run.
+#[derive(Debug, Clone, Copy)]
+pub struct Budget {
+    /// Evaluation steps the run may take
+    pub steps: u64,
+    /// Instant after which the run stops
+    pub deadline: Option<Instant>,
+}
+
+impl Default for Budget {
+    fn default() -> Self {
+        Budget {
+            steps: MAX_STEPS,
+            deadline: None,
+        }
+    }
+}
+
+impl Budget {
+    /// The default step budget, stopping `timeout` from now.
+    pub fn with_timeout(timeout: Duration) -> Self {
+        Budget {
+            deadline: Some(Instant::now() + timeout),
+            ..Budget::default()
+        }
+    }
+}
+
+/// What is left of the current run's [`Budget`]. Once a check fails the
+/// run stays aborted, so a `try` in the query cannot swallow it.
+struct Meter {
+    steps: u64,
+    deadline: Option<Instant>,
+    aborted: Option<String>,
+}
+
+impl Meter {
+    fn charge(&mut self) -> Result<(), String> {
+        if let Some(message) = &self.aborted {
+            return Err(message.clone());
+        }
+        let message = if self.steps == 0 {
+            "Query exceeded its evaluation budget"
+        } else if self.steps.is_multiple_of(1024)
+            && self.deadline.is_some_and(|d| Instant::now() >= d)
+        {
+            "Query timed out"
+        } else if stacker::remaining_stack().is_some_and(|left| left < STACK_RESERVE) {
+            "Query recursed too deeply"
+        } else {
+            self.steps -= 1;
+            return Ok(());
+        };
+        self.aborted = Some(message.to_string());
+        Err(message.to_string())
+    }
+}
+
+thread_local! {
+    static METER: RefCell<Option<Meter>> = const { RefCell::new(None) };
+}
+
+/// Clears the thread's [`Meter`] when a run ends, however it ends.
+struct MeterGuard;
+
+impl MeterGuard {
+    fn install(budget: Budget) -> Self {
+        METER.with(|meter| {
+            *meter.borrow_mut() = Some(Meter {
+                steps: budget.steps,
+                deadline: budget.deadline,
+                aborted: None,
+            })
+        });
+        MeterGuard
+    }
+
+    /// Why the run was aborted, if it was.
+    fn aborted(&self) -> Option<String> {
+        METER.with(|meter| meter.borrow().as_ref().and_then(|m| m.aborted.clone()))
+    }
+}
+
+impl Drop for MeterGuard {
+    fn drop(&mut self) {
+        METER.with(|meter| *meter.borrow_mut() = None);
+    }
+}
+
+/// Charge one step to the current run; outside a run this always passes.
+fn tick<'a>() -> Result<(), Exn<'a, Val>> {
+    METER
+        .with(|meter| match meter.borrow_mut().as_mut() {
+            Some(meter) => meter.charge(),
+            None => Ok(()),
+        })
+        .map_err(|message| Exn::from(jaq_core::Error::str(message)))
+}
+
+/// `_tick | term`
+fn metered(term: Term<&str>) -> Term<&str> {
+    Term::BinOp(
+        Box::new(Term::Call(TICK, Vec::new())),
+        BinaryOp::Pipe(None),
+        Box::new(meter(term)),
+    )
+}
+
+fn meter_def(def: Def<&str>) -> Def<&str> {
+    Def {
+        body: metered(def.body),
+        ..def
+    }
+}
+
+fn meter_pattern(pattern: Pattern<&str>) -> Pattern<&str> {
+    match pattern {
+        Pattern::Var(name) => Pattern::Var(name),
+        Pattern::Arr(items) => Pattern::Arr(items.into_iter().map(meter_pattern).collect()),
+        Pattern::Obj(entries) => Pattern::Obj(
+            entries
+                .into_iter()
+                .map(|(key, value)| (meter(key), meter_pattern(value)))
+                .collect(),
+        ),
+    }
+}
+
+/// Insert [`TICK`] before every definition body, pipe stage, `,` operand,
+/// and `reduce`/`foreach` update, so that every loop (which in jq is either
+/// recursion or a generator feeding a pipe) is charged per iteration.
+fn meter(term: Term<&str>) -> Term<&str> {
+    use jaq_core::load::lex::StrPart;
+    fn boxed(term: Term<&str>) -> Box<Term<&str>> {
+        Box::new(meter(term))
+    }
+    match term {
+        Term::Str(format, parts) => Term::Str(
+            format,
+            parts
+                .into_iter()
+                .map(|part| match part {
+                    StrPart::Term(term) => StrPart::Term(meter(term)),
+                    other => other,
+                })
+                .collect(),
+        ),
+        Term::Arr(items) => Term::Arr(items.map(|term| boxed(*term))),
+        Term::Obj(entries) => Term::Obj(
+            entries
+                .into_iter()
+                .map(|(key, value)| (meter(key), value.map(meter)))
+                .collect(),
+        ),
+        Term::Neg(term) => Term::Neg(boxed(*term)),
+        Term::BinOp(lhs, op @ BinaryOp::Pipe(_), rhs) => {
+            Term::BinOp(boxed(*lhs), op, Box::new(metered(*rhs)))
+        }
+        Term::BinOp(lhs, op @ BinaryOp::Comma, rhs) => {
+            Term::BinOp(Box::new(metered(*lhs)), op, Box::new(metered(*rhs)))
+        }
+        Term::BinOp(lhs, op, rhs) => Term::BinOp(boxed(*lhs), op, boxed(*rhs)),
+        Term::Label(name, body) => Term::Label(name, boxed(*body)),
+        Term::Fold(name, xs, pattern, args) => Term::Fold(
+            name,
+            boxed(*xs),
+            meter_pattern(pattern),
+            args.into_iter().map(metered).collect(),
+        ),
+        Term::TryCatch(body, catch) => Term::TryCatch(boxed(*body), catch.map(|term| boxed(*term))),
+        Term::IfThenElse(branches, otherwise) => Term::IfThenElse(
+            branches
+                .into_iter()
+                .map(|(cond, then)| (meter(cond), meter(then)))
+                .collect(),
+            otherwise.map(|term| boxed(*term)),
+        ),
+        Term::Def(defs, body) => Term::Def(defs.into_iter().map(meter_def).collect(), boxed(*body)),
+        Term::Call(name, args) => Term::Call(name, args.into_iter().map(meter).collect()),
+        Term::Path(head, Path(parts)) => Term::Path(
+            boxed(*head),
+            Path(
+                parts
+                    .into_iter()
+                    .map(|(part, opt)| {
+                        let part = match part {
+                            Part::Index(index) => Part::Index(meter(index)),
+                            Part::Range(from, upto) => {
+                                Part::Range(from.map(meter), upto.map(meter))
+                            }
+                        };
+                        (part, opt)
+                    })
+                    .collect(),
+            ),
+        ),
+        other @ (Term::Id | Term::Recurse | Term::Num(_) | Term::Break(_) | Term::Var(_)) => other,
+    }
+}
+
+fn compile_jq(code: &str) -> JqResult<Filter> {
+    let parse_defs = |code| jaq_core::load::parse(code, |p| p.defs()).unwrap_or_default();
+    let std_defs = || {
+        parse_defs(RANGE_DEF)
+            .into_iter()
+            .chain(jaq_core::defs())
+            .chain(jaq_std::defs())
+            .chain(jaq_json::defs())
+            .chain(parse_defs(FORMAT_DEFS))
+    };
+
+    // Load the query as written for its lex, parse, and module errors, then
+    // run it as a metered definition at the end of the prelude.
+    let arena = Arena::default();
+    Loader::new(std_defs())
+        .load(&arena, File { code, path: () })
+        .map_err(|errors| match errors.into_iter().next() {
+            Some((_, error)) => load_error(code, error),
+            None => syntax(0, "invalid query"),
+        })?;
+    let term =
+        jaq_core::load::parse(code, |p| p.term()).ok_or_else(|| syntax(0, "invalid query"))?;
+    let main = Def {
+        name: "_main",
+        args: Vec::new(),
+        body: term,
+    };
+
+    let arena = Arena::default();
+    let modules = Loader::new(
+        std_defs()
+            .map(|def| meter_def(def))
+            .chain([meter_def(main)]),
+    )
+    .load(
+        &arena,
+        File {
+            code: "_main",
+            path: (),
+        },
+    )
+    .map_err(|_| syntax(0, "invalid query"))?;
+
+    jaq_core::Compiler::default()
+        .with_funs(funs())
+        .compile(modules)
+        .map_err(|errors| {
+            match errors
+                .into_iter()
+                .flat_map(|(_, undefined)| undefined)
+                .next()
+            {
+                Some((name, undefined)) => compile_error(code, name, undefined),
+                None => syntax(0, "invalid query"),
+            }
+        })
+}
+
+/// Native functions: jaq's, minus [`DISABLED_FUNCTIONS`], with `range`
+/// renamed for [`RANGE_DEF`], plus [`TICK`].
+fn funs() -> impl Iterator<Item = jaq_core::native::Fun<Data>> {
+    let tick = Native::new(|cv| box_once(tick().map(|()| cv.1)))
+        .with_paths(|cvp| box_once(tick().map(|()| cvp.1)))
+        .with_update(|cv, f| match tick() {
+            Ok(()) => f(cv.1),
+            Err(e) => box_once(Err(e)),
+        });
+    jaq_core::funs()
+        .chain(jaq_std::funs())
+        .chain(jaq_json::funs())
+        .filter(|(name, _, _)| !DISABLED_FUNCTIONS.contains(name))
+        .map(|(name, args, native)| match name {
+            "range" => ("_range", args, native),
+            _ => (name, args, native),
+        })
+        .chain([(TICK, jaq_core::native::v(0), tick)])
+}
+
+/// Convert a jaq exception into an error, unquoting `error("message")`.
+fn exception(exn: Exn<Val>) -> JqError {
+    match exn.get_err() {
+        Ok(error) => match error.into_val() {
+            Val::TStr(bytes) => runtime(String::from_utf8_lossy(&bytes)),
+            value => runtime(value.to_string()),
+        },
+        Err(_) => runtime("halt is not supported"),
+    }
+}
+
+fn to_value(value: &Val) -> JqResult<Value> {
+    serde_json::from_str(&value.to_string())
+        .map_err(|e| runtime(format!("Query result is not valid JSON: {}", e)))
+}
+
+// ---------------------------------------------------------------------------
+// JSONPath
+// ---------------------------------------------------------------------------
+
+struct PathScanner<'a> {
+    src: &'a str,
+    pos: usize,
+}
+
+impl PathScanner<'_> {
+    fn peek(&self) -> Option<char> {
+        self.src[self.pos..].chars().next()
+    }
+
+    fn eat(&mut self, prefix: &str) -> bool {
+        let found = self.src[self.pos..].starts_with(prefix);
+        if found {
+            self.pos += prefix.len();
+        }
+        found
+    }
+
+    fn skip_space(&mut self) {
+        while self.peek().is_some_and(char::is_whitespace) {
+            self.pos += 1;
+        }
+    }
+
+    fn name(&mut self) -> JqResult<String> {
+        let start = self.pos;
+        while self
+            .peek()
+            .is_some_and(|c| c.is_alphanumeric() || c == '_' || c == '-' || c == '$')
+        {
+            self.pos += self.peek().map_or(1, char::len_utf8);
+        }
+        if self.pos == start {
+            return Err(syntax(start, "expected a member name"));
+        }
+        Ok(self.src[start..self.pos].to_string())
+    }
+
+    fn quoted(&mut self) -> JqResult<String> {
+        let start = self.pos;
+        let quote = self.peek().unwrap_or('\'');
+        self.pos += 1;
+        let mut out = String::new();
+        loop {
+            let c = self
+                .peek()
+                .ok_or_else(|| syntax(start, "unterminated string"))?;
+            self.pos += c.len_utf8();
+            match c {
+                '\\' => {
+                    let escaped = self
+                        .peek()
+                        .ok_or_else(|| syntax(start, "unterminated string"))?;
+                    self.pos += escaped.len_utf8();
+                    out.push(escaped);
+                }
+                c if c == quote => return Ok(out),
+                c => out.push(c),
+            }
+        }
+    }
+
+    fn integer(&mut self) -> JqResult<Option<i64>> {
+        self.skip_space();
+        let start = self.pos;
+        self.eat("-");
+        while self.peek().is_some_and(|c| c.is_ascii_digit()) {
+            self.pos += 1;
+        }
+        if self.pos == start {
+            return Ok(None);
+        }
+        self.src[start..self.pos]
+            .parse()
+            .map(Some)
+            .map_err(|_| syntax(start, "invalid integer"))
+    }
+
+    /// The text of a `(...)` group, with nested parentheses and quotes.
+    fn group(&mut self) -> JqResult<String> {
+        let start = self.pos;
+        if !self.eat("(") {
+            return Err(syntax(start, "expected '(' after '?'"));
+        }
+        let inner_start = self.pos;
+        let mut depth = 0usize;
+        while let Some(c) = self.peek() {
+            match c {
+                '\'' | '"' => {
+                    self.quoted()?;
+                    continue;
+                }
+                '(' => depth += 1,
+                ')' if depth == 0 => {
+                    let inner = self.src[inner_start..self.pos].to_string();
+                    self.pos += 1;
+                    return Ok(inner);
+                }
+                ')' => depth -= 1,
+                _ => {}
+            }
+            self.pos += c.len_utf8();
+        }
+        Err(syntax(start, "unterminated filter expression"))
+    }
+}
+
+/// `cur`'s member `name`, yielding nothing when it is missing.
+fn path_member(name: &str) -> String {
+    let key = Value::String(name.to_string());
+    format!("(objects | select(has({key})) | .[{key}])")
+}
+
+/// `cur`'s element `index`, yielding nothing when it is out of range.
+fn path_element(index: i64) -> String {
+    let bound = if index >= 0 { index + 1 } else { -index };
+    format!("(arrays | select(length >= {bound}) | .[{index}])")
+}
+
+const PATH_WILDCARD: &str = ".[]?";
+
+/// Rewrite a JSONPath filter into jq: `@` becomes `.`, `&&`/`||` become
+/// `and`/`or`, and single-quoted strings become JSON strings.
+fn rewrite_filter(filter: &str, offset: usize) -> JqResult<String> {
+    let mut scanner = PathScanner {
+        src: filter,
+        pos: 0,
+    };
+    let mut out = String::new();
+    while let Some(c) = scanner.peek() {
+        match c {
+            '\'' | '"' => {
+                let text = scanner
+                    .quoted()
+                    .map_err(|_| syntax(offset + scanner.pos, "unterminated string"))?;
+                out.push_str(&Value::String(text).to_string());
+            }
+            '@' => {
+                scanner.pos += 1;
+                if scanner.peek() != Some('.') {
+                    out.push('.');
+                }
+            }
+            _ if scanner.eat("&&") => out.push_str(" and "),
+            _ if scanner.eat("||") => out.push_str(" or "),
+            c => {
+                out.push(c);
+                scanner.pos += c.len_utf8();
+            }
+        }
+    }
+    Ok(out)
+}
+
+fn parse_bracket_selector(scanner: &mut PathScanner) -> JqResult<String> {
+    scanner.skip_space();
+    let start = scanner.pos;
+    if scanner.eat("*") {
+        scanner.skip_space();
+        if !scanner.eat("]") {
+            return Err(syntax(scanner.pos, "expected ']'"));
+        }
+        return Ok(PATH_WILDCARD.to_string());
+    }
+    if scanner.eat("?") {
+        let filter = scanner.group()?;
+        let condition = rewrite_filter(&filter, start)?;
+        // Check the filter on its own so errors point at the selector.
+        compile_jq(&condition).map_err(|e| match e {
+            JqError::Syntax(_, message) => syntax(start, message),
+            other => other,
+        })?;
+        scanner.skip_space();
+        if !scanner.eat("]") {
+            return Err(syntax(scanner.pos, "expected ']'"));
+        }
+        return Ok(format!("({PATH_WILDCARD} | select(({condition})?))"));
+    }
+
+    let mut selectors = Vec::new();
+    loop {
+        scanner.skip_space();
+        let selector = match scanner.peek() {
+            Some('\'' | '"') => path_member(&scanner.quoted()?),
+            _ => {
+                let from = scanner.integer()?;
+                scanner.skip_space();
+                if scanner.eat(":") {
+                    let to = scanner.integer()?;
+                    let bound = |n: Option<i64>| n.map_or_else(String::new, |n| n.to_string());
+                    match (from, to) {
+                        (None, None) => "(arrays | .[])".to_string(),
+                        _ => format!("(arrays | .[{}:{}][])", bound(from), bound(to)),
+                    }
+                } else {
+                    let index =
+                        from.ok_or_else(|| syntax(scanner.pos, "expected an index or name"))?;
+                    path_element(index)
+                }
+            }
+        };
+        selectors.push(selector);
+        scanner.skip_space();
+        if scanner.eat("]") {
+            break;
+        }
+        if !scanner.eat(",") {
+            return Err(syntax(scanner.pos, "expected ',' or ']'"));
+        }
+    }
+    Ok(if selectors.len() == 1 {
+        selectors.remove(0)
+    } else {
+        format!("({})", selectors.join(", "))
+    })
+}
+
+/// Translate a JSONPath expression into an equivalent jq program.
+fn jsonpath_to_jq(query: &str) -> JqResult<String> {
+    let mut scanner = PathScanner { src: query, pos: 0 };
+    scanner.skip_space();
+    if !scanner.eat("$") {
+        return Err(syntax(scanner.pos, "JSONPath must start with '$'"));
+    }
+    let mut steps = vec![".".to_string()];
+    loop {
+        scanner.skip_space();
+        if scanner.peek().is_none() {
+            return Ok(steps.join(" | "));
+        }
+        let step = if scanner.eat("..") {
+            let step = if scanner.eat("*") {
+                PATH_WILDCARD.to_string()
+            } else if scanner.eat("[") {
+                parse_bracket_selector(&mut scanner)?
+            } else {
+                path_member(&scanner.name()?)
+            };
+            format!("(.. | {step})")
+        } else if scanner.eat(".") {
+            if scanner.eat("*") {
+                PATH_WILDCARD.to_string()
+            } else {
+                path_member(&scanner.name()?)
+            }
+        } else if scanner.eat("[") {
+            parse_bracket_selector(&mut scanner)?
+        } else {
+            return Err(syntax(scanner.pos, "expected '.', '..', or '['"));
+        };
+        steps.push(step);
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Compiled queries
+// ---------------------------------------------------------------------------
+
+/// A compiled query, reusable across inputs.
+pub struct Query {
+    filter: Filter,
+}
+
+impl std::fmt::Debug for Query {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Query").finish_non_exhaustive()
+    }
+}
+
+impl Query {
+    /// Parse a jq expression, or a JSONPath expression when it starts with `$`.
+    pub fn parse(query: &str) -> JqResult<Self> {
+        if query.len() > MAX_QUERY_LEN {
+            return Err(syntax(
+                MAX_QUERY_LEN,
+                format!("query is longer than {} bytes", MAX_QUERY_LEN),
+            ));
+        }
+        if nesting_depth(query) > MAX_DEPTH {
+            return Err(syntax(0, "query nests too deeply"));
+        }
+        let filter = if query.trim_start().starts_with('$') {
+            let translated = jsonpath_to_jq(query)?;
+            compile_jq(&translated).map_err(|e| match e {
+                JqError::Syntax(_, message) => syntax(0, message),
+                other => other,
+            })?
+        } else if query.trim().is_empty() {
+            compile_jq(".")?
+        } else {
+            compile_jq(query)?
+        };
+        Ok(Query { filter })
+    }
+
+    /// Run the query against one input value with the default [`Budget`].
+    pub fn run(&self, input: &Value) -> JqResult<Vec<Value>> {
+        self.run_within(input, Budget::default())
+    }
+
+    /// Run the query against one input value, stopping with
+    /// [`JqError::Aborted`] once `budget` runs out.
+    pub fn run_within(&self, input: &Value, budget: Budget) -> JqResult<Vec<Value>> {
+        let input = Val::deserialize(input).map_err(|e| runtime(e.to_string()))?;
+        let ctx = Ctx::<Data>::new(&self.filter.lut, Vars::new([]));
+        let meter = MeterGuard::install(budget);
+        let mut results = Vec::new();
+        let mut outputs = self.filter.id.run((ctx, input));
+        let outcome = loop {
+            if results.len() == MAX_RESULTS {
+                break Err(JqError::TooManyResults);
+            }
+            match outputs.next() {
+                None => break Ok(()),
+                Some(output) => match output.map_err(exception).and_then(|v| to_value(&v)) {
+                    Ok(value) => results.push(value),
+                    Err(e) => break Err(e),
+                },
+            }
+        };
+        // An abort wins over whatever error (or `try`) it surfaced as.
+        match meter.aborted() {
+            Some(message) => Err(JqError::Aborted(message)),
+            None => outcome.map(|()| results),
+        }
+    }
+}
+
+/// Parse `query` and run it against each input in turn, concatenating the
+/// results.
+pub fn run_query(query: &str, inputs: &[Value]) -> JqResult<Vec<Value>> {
+    let query = Query::parse(query)?;
+    let mut results = Vec::new();
+    for input in inputs {
+        results.extend(query.run(input)?);
+        if results.len() > MAX_RESULTS {
+            return Err(JqError::TooManyResults);
+        }
+    }
+    Ok(results)
+}
+
+/// Parse a sequence of whitespace-separated JSON values (a single
+/// document, NDJSON, or concatenated JSON).
+pub fn parse_inputs(text: &str) -> Result<Vec<Value>, String> {
+    serde_json::Deserializer::from_str(text)
+        .into_iter::<Value>()
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|e| format!("Invalid JSON input: {}", e))
+}
+
+/// Render results one per line, like jq: pretty by default, `compact` for
+/// one line each, `raw` to print strings without quotes.
+pub fn format_results(results: &[Value], raw: bool, compact: bool) -> String {
+    let mut out = String::new();
+    for value in results {
+        match value {
+            Value::String(text) if raw => out.push_str(text),
+            _ if compact => out.push_str(&value.to_string()),
+            _ => out.push_str(
+                &serde_json::to_string_pretty(value).unwrap_or_else(|_| value.to_string()),
+            ),
+        }
+        out.push('\n');
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn q(query: &str, input: Value) -> Vec<Value> {
+        Query::parse(query)
+            .unwrap_or_else(|e| panic!("{}: {}", query, e))
+            .run(&input)
+            .unwrap_or_else(|e| panic!("{}: {}", query, e))
+    }
+
+    fn sample() -> Value {
+        json!({
+            "store": {
+                "book": [
+                    {"title": "A", "author": "Ann", "price": 8.5, "tags": ["x"]},
+                    {"title": "B", "author": "Bob", "price": 12, "tags": []},
+                    {"title": "C", "author": "Cy", "price": 30, "isbn": "123"}
+                ],
+                "bicycle": {"color": "red", "price": 19.95}
+            }
+        })
+    }
+
+    #[test]
+    fn test_paths_and_iteration() {
+        let data = sample();
+        assert_eq!(q(".store.bicycle.color", data.clone()), vec![json!("red")]);
+        assert_eq!(q(".store.book[-1].title", data.clone()), vec![json!("C")]);
+        assert_eq!(
+            q(".store.book[].title", data.clone()),
+            vec![json!("A"), json!("B"), json!("C")]
+        );
+        assert_eq!(q(".store.book[1:] | length", data.clone()), vec![json!(2)]);
+        assert_eq!(q(".missing.deeper", data.clone()), vec![Value::Null]);
+        assert_eq!(
+            q(r#"."store"["bicycle"].price"#, data.clone()),
+            vec![json!(19.95)]
+        );
+        assert_eq!(
+            q("[..|.price?|numbers] | length", data.clone()),
+            vec![json!(4)]
+        );
+        assert_eq!(q("", json!(1)), vec![json!(1)]);
+
+        let err = Query::parse(".a[]")
+            .unwrap()
+            .run(&json!({"a": 1}))
+            .unwrap_err();
+        assert!(matches!(err, JqError::Runtime(_)));
+        assert!(q(".a[]?", json!({"a": 1})).is_empty());
+    }
+
+    #[test]
+    fn test_filters_and_construction() {
+        let data = sample();
+        assert_eq!(
+            q(
+                r#".store.book[] | select(.price > 10 and (has("isbn") | not)) | {title, cost: .price}"#,
+                data.clone()
+            ),
+            vec![json!({"title": "B", "cost": 12})]
+        );
+        assert_eq!(
+            q(r#"[.store.book[] | "\(.author): \(.price)"]"#, data.clone()),
+            vec![json!(["Ann: 8.5", "Bob: 12", "Cy: 30"])]
+        );
+        assert_eq!(
+            q(".store.book | map(.tags // []) | flatten", data.clone()),
+            vec![json!(["x"])]
+        );
+        assert_eq!(
+            q(
+                ".store.book | sort_by(-.price) | map(.title) | join(\",\")",
+                data.clone()
+            ),
+            vec![json!("C,B,A")]
+        );
+        assert_eq!(
+            q(
+                r#".store.book[0] | if .price < 10 then "cheap" elif .price < 20 then "ok" else "dear" end"#,
+                data.clone()
+            ),
+            vec![json!("cheap")]
+        );
+        assert_eq!(q("1, 2 | . * 10", Value::Null), vec![json!(10), json!(20)]);
+        assert_eq!(
+            q("[.[] | tostring] | @csv", json!([1, "a\"b", null])),
+            vec![json!(r#""1","a""b","null""#)]
+        );
+        assert_eq!(
+            q("@tsv", json!(["a\tb", 1, null, "c\\d"])),
+            vec![json!("a\\tb\t1\t\tc\\\\d")]
+        );
+        assert_eq!(
+            q(
+                r#"to_entries | map(select(.value > 1)) | from_entries"#,
+                json!({"a": 1, "b": 2})
+            ),
+            vec![json!({"b": 2})]
+        );
+        assert_eq!(
+            q(r#"try error("boom") catch ("caught: " + .)"#, Value::Null),
+            vec![json!("caught: boom")]
+        );
+        assert_eq!(
+            q(r#"gsub("[aeiou]"; "_") | test("^H"; "i")"#, json!("hello")),
+            vec![json!(true)]
+        );
+        assert_eq!(
+            q(
+                "group_by(.k) | map(length)",
+                json!([{"k": 1}, {"k": 2}, {"k": 1}])
+            ),
+            vec![json!([2, 1])]
+        );
+    }
+
+    #[test]
+    fn test_del_and_paths() {
+        let data = json!({"a": {"b": 1, "c": 2}, "d": [1, 2, 3]});
+        assert_eq!(
+            q("del(.a.b, .d[0])", data.clone()),
+            vec![json!({"a": {"c": 2}, "d": [2, 3]})]
+        );
+        assert_eq!(
+            q("del(.d[] | select(. > 1))", data.clone()),
+            vec![json!({"a": {"b": 1, "c": 2}, "d": [1]})]
+        );
+        assert_eq!(q("[paths] | length", data.clone()), vec![json!(7)]);
+        assert_eq!(q("path(.a.b)", data), vec![json!(["a", "b"])]);
+    }
+
+    #[test]
+    fn test_jsonpath() {
+        let data = sample();
+        assert_eq!(q("$.store.bicycle.color", data.clone()), vec![json!("red")]);
+        assert_eq!(
+            q("$..author", data.clone()),
+            vec![json!("Ann"), json!("Bob"), json!("Cy")]
+        );
+        assert_eq!(
+            q(
+                "$.store.book[?(@.price < 10 || @.isbn)].title",
+                data.clone()
+            ),
+            vec![json!("A"), json!("C")]
+        );
+        assert_eq!(
+            q("$.store.book[0,2]['title']", data.clone()),
+            vec![json!("A"), json!("C")]
+        );
+        assert_eq!(
+            q("$.store.book[-1:].author", data.clone()),
+            vec![json!("Cy")]
+        );
+        assert_eq!(q("$.store.book[*].isbn", data.clone()), vec![json!("123")]);
+        assert!(q("$.store.book[5]", data.clone()).is_empty());
+        assert!(q("$.nothing", data).is_empty());
+    }
+
+    #[test]
+    fn test_full_jq() {
+        let data = sample();
+        assert_eq!(
+            q(".store.bicycle as $b | $b.color", data.clone()),
+            vec![json!("red")]
+        );
+        assert_eq!(
+            q("reduce .store.book[].price as $p (0; . + $p)", data.clone()),
+            vec![json!(50.5)]
+        );
+        assert_eq!(
+            q(r#"[splits(", *")]"#, json!("a, b,c")),
+            vec![json!(["a", "b", "c"])]
+        );
+        assert_eq!(
+            q("def double: . * 2; [.[] | double]", json!([1, 2])),
+            vec![json!([2, 4])]
+        );
+        assert_eq!(
+            q("[foreach .[] as $x (0; . + $x)]", json!([1, 2, 3])),
+            vec![json!([1, 3, 6])]
+        );
+        assert_eq!(
+            q("(.a = 1) | (.c |= . + 3)", json!({"c": 2})),
+            vec![json!({"a": 1, "c": 5})]
+        );
+        assert_eq!(
+            q(
+                ". as [$first, {k: $k}] | [$first, $k]",
+                json!([1, {"k": 2}])
+            ),
+            vec![json!([1, 2])]
+        );
+
+        assert_eq!(
+            q(
+                r#"def f: def g: "f"; g + "!"; def h(f): f; h(f)"#,
+                Value::Null
+            ),
+            vec![json!("f!")]
+        );
+        assert_eq!(
+            q(
+                "def fact: if . <= 1 then 1 else . * (. - 1 | fact) end; fact",
+                json!(10)
+            ),
+            vec![json!(3628800)]
+        );
+        assert_eq!(
+            q("[limit(3; recurse(. * 2))]", json!(1)),
+            vec![json!([1, 2, 4])]
+        );
+        assert_eq!(q("until(. > 100; . * 2)", json!(1)), vec![json!(128)]);
+
+        let err = Query::parse("halt").unwrap().run(&Value::Null).unwrap_err();
+        assert_eq!(err.to_string(), "halt is not supported");
+    }
+
+    #[test]
+    fn test_syntax_errors() {
+        for (query, message) in [
+            (".a |", "unexpected end of query"),
+            ("$__loc__", "expected '.', '..', or '['"),
+            ("frobnicate(1)", "unknown function frobnicate/1"),
+            ("input", "unknown function input/0"),
+            ("env", "unknown function env/0"),
+            ("[$ENV]", "undefined variable $ENV"),
+            (r#"include "lib"; ."#, "modules are not available"),
+            (r#""\(.a""#, "expected closing"),
+        ] {
+            let err = Query::parse(query).unwrap_err();
+            assert!(
+                err.to_string().contains(message),
+                "{}: expected '{}', got '{}'",
+                query,
+                message,
+                err
+            );
+        }
+
+        let err = Query::parse(".a | frobnicate").unwrap_err();
+        assert_eq!(err, syntax(5, "unknown function frobnicate/0"));
+    }
+
+    #[test]
+    fn test_limits() {
+        let err = run_query("range(1000000)", &[Value::Null]).unwrap_err();
+        assert_eq!(err, JqError::TooManyResults);
+        assert_eq!(
+            run_query("[range(1000000)] | length", &[Value::Null]).unwrap(),
+            vec![json!(1000000)]
+        );
+
+        let budget = Budget {
+            steps: 100_000,
+            deadline: None,
+        };
+        for query in [
+            "until(false; . + 1)",
+            "repeat(.) | empty",
+            "range(1e18) | empty",
+            "[range(1e18)] | length",
+            "reduce range(1e18) as $x (0; . + 1)",
+            "def f: def g: f; g; f",
+            "def f(x): [x, f(x)]; f(1)",
+            "try until(false; . + 1) catch 0",
+        ] {
+            let err = Query::parse(query)
+                .unwrap()
+                .run_within(&json!(0), budget)
+                .unwrap_err();
+            assert!(matches!(err, JqError::Aborted(_)), "{query}: {err}");
+        }
+        let err = Query::parse("until(false; . + 1)")
+            .unwrap()
+            .run_within(&json!(0), Budget::with_timeout(Duration::ZERO))
+            .unwrap_err();
+        assert_eq!(err, JqError::Aborted("Query timed out".into()));
+        let err = Query::parse("def f: 1 + f; f")
+            .unwrap()
+            .run(&Value::Null)
+            .unwrap_err();
+        assert_eq!(err, JqError::Aborted("Query recursed too deeply".into()));
+
+        let chain = ".a".repeat(MAX_QUERY_LEN / 2);
+        assert_eq!(q(&chain, json!({})), vec![Value::Null]);
+        assert!(Query::parse(&" ".repeat(MAX_QUERY_LEN + 1)).is_err());
+
+        let nested = format!(
+            "{}1{}",
+            "(".repeat(MAX_DEPTH + 1),
+            ")".repeat(MAX_DEPTH + 1)
+        );
+        assert!(Query::parse(&nested).is_err());
+        let in_string = format!("\"{}\"", "(".repeat(MAX_DEPTH + 1));
+        assert!(Query::parse(&in_string).is_ok());
+        assert_eq!(nesting_depth(r#"[("\(.a)") # ((("#), 4);
+    }
+
+    #[test]
+    fn test_inputs_and_formatting() {
+        let inputs = parse_inputs("{\"a\":1}\n{\"a\":2}\n").unwrap();
+        assert_eq!(run_query(".a", &inputs).unwrap(), vec![json!(1), json!(2)]);
+        assert!(parse_inputs("{").is_err());
+
+        let results = vec![json!("x"), json!({"k": [1]})];
+        assert_eq!(format_results(&results, true, true), "x\n{\"k\":[1]}\n");
+        assert_eq!(
+            format_results(&results, false, false),
+            "\"x\"\n{\n  \"k\": [\n    1\n  ]\n}\n"
+        );
+    }
+}
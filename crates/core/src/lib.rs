@@ -33,9 +33,29 @@
 //! The core crate is organized by domain:
 //!
 //! - [`atlassian`]: Transformations for Atlassian services (Jira, Confluence)
+//! - [`cal`]: iCalendar parsing and recurrence expansion
+//! - [`chunk`]: Token-bounded chunking for embedding pipelines and lossless splitting of streamed results
+//! - [`codec`]: Checksums and base64, URL, and hex encodings
+//! - [`crawl`]: Link discovery, scoping, and page naming for site crawls
+//! - [`demo`]: Scenarios, fixtures, and transcript formatting for scripted tool walkthroughs
+//! - [`diff`]: Line diffs and unified patch application
+//! - [`extract`]: Regex extraction with size limits
+//! - [`har`]: HTTP Archive (HAR 1.2) capture from DevTools network events
+//! - [`hbs`]: Sandboxed Handlebars templates over JSON
 //! - [`hn`]: Transformations for HackerNews API data
+//! - [`html`]: CSS and XPath-subset selection over HTML documents
+//! - [`jq`]: jq-subset and JSONPath queries over JSON values
+//! - [`mail`]: IMAP response parsing and MIME message to Markdown conversion
 //! - [`md`]: Transformations for web page to Markdown conversion
+//! - [`meta`]: Canonical URL, OpenGraph, Twitter card, and JSON-LD extraction
+//! - [`normalize`]: Markdown reflow, heading repair, and list and link normalization
+//! - [`search`]: Query building, result normalization, and rank fusion for unified search
+//! - [`state`]: Layout and migration planning for the versioned state directory
+//! - [`template`]: `{name}` placeholder rendering for templated bulk operations
+//! - [`transcribe`]: Merging and rendering of speech-to-text segments
 //! - [`upgrade`]: Transformations for version comparison and upgrade logic
+//! - [`vision`]: Prompts and Ollama request shapes for describing images
+//! - [`youtube`]: YouTube caption track discovery and transcript rendering
 //!
 //! Each module contains:
 //!
@@ -76,10 +96,32 @@
 pub mod annotations;
 pub mod atlas;
 pub mod atlassian;
+pub mod cal;
+pub mod chunk;
+pub mod codec;
+pub mod crawl;
+pub mod demo;
+pub mod diff;
+pub mod extract;
+pub mod generate;
 pub mod greprag;
+pub mod har;
+pub mod hbs;
 pub mod hn;
+pub mod html;
+pub mod jq;
+pub mod mail;
 pub mod md;
+pub mod meta;
+pub mod normalize;
 pub mod pagination;
 pub mod queries;
+pub mod search;
+pub mod sitemap;
+pub mod state;
 pub mod strand;
+pub mod template;
+pub mod transcribe;
 pub mod upgrade;
+pub mod vision;
+pub mod youtube;
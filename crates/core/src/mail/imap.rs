@@ -0,0 +1,506 @@
+//! IMAP4rev1 protocol helpers
+//!
+//! Command building and response parsing for the small, read-only subset of
+//! IMAP that `mail` uses: `UID SEARCH` and `UID FETCH`. The shell owns the
+//! connection and hands complete response bytes (literals included) to these
+//! functions.
+
+use base64::Engine;
+use chrono::NaiveDate;
+
+/// Quote a string for use in an IMAP command.
+///
+/// CR, LF, and NUL are rejected: a quoted string cannot carry them, and a
+/// line break would end the command and start another one.
+pub fn quote(value: &str) -> Result<String, String> {
+    check_string(value)?;
+    Ok(format!(
+        "\"{}\"",
+        value.replace('\\', "\\\\").replace('"', "\\\"")
+    ))
+}
+
+/// Encode a string argument: a quoted string when it is ASCII, otherwise a
+/// literal (`{n}` CRLF, then the UTF-8 bytes), since quoted strings cannot
+/// carry 8-bit data. Commands with literals are sent with
+/// [`command_segments`].
+pub fn astring(value: &str) -> Result<String, String> {
+    if value.is_ascii() {
+        return quote(value);
+    }
+    check_string(value)?;
+    Ok(format!("{{{}}}\r\n{}", value.len(), value))
+}
+
+/// Split a command into the pieces sent before and after each literal.
+///
+/// Every piece but the last ends with a `{n}` marker; the client sends it
+/// with CRLF and waits for the server's `+` continuation before sending the
+/// next one. Strings are checked by [`quote`] and [`astring`], so the only
+/// CRLFs in a command are the ones after literal markers.
+pub fn command_segments(command: &str) -> Vec<&str> {
+    command.split("\r\n").collect()
+}
+
+fn check_string(value: &str) -> Result<(), String> {
+    match value.chars().find(|c| matches!(c, '\r' | '\n' | '\0')) {
+        Some(c) => Err(format!(
+            "IMAP strings cannot contain {}",
+            c.escape_default()
+        )),
+        None => Ok(()),
+    }
+}
+
+/// Format a date for IMAP `SINCE`/`BEFORE` criteria (`01-Jan-2024`).
+pub fn format_imap_date(date: NaiveDate) -> String {
+    date.format("%d-%b-%Y").to_string()
+}
+
+/// SASL `XOAUTH2` initial response for `AUTHENTICATE XOAUTH2`.
+pub fn xoauth2_response(user: &str, access_token: &str) -> String {
+    base64::engine::general_purpose::STANDARD.encode(format!(
+        "user={}\x01auth=Bearer {}\x01\x01",
+        user, access_token
+    ))
+}
+
+/// Mail search filters. Empty filters match every message.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SearchQuery {
+    pub from: Option<String>,
+    pub to: Option<String>,
+    pub subject: Option<String>,
+    /// Full-text search over headers and body.
+    pub text: Option<String>,
+    /// Messages on or after this date.
+    pub since: Option<NaiveDate>,
+    /// Messages before this date.
+    pub before: Option<NaiveDate>,
+    pub unseen: bool,
+}
+
+/// Build the search criteria for `UID SEARCH` (e.g. `FROM "alice" UNSEEN`).
+///
+/// Non-ASCII values are sent as literals and need the `CHARSET UTF-8`
+/// prefix, which is added when any string criterion contains non-ASCII
+/// characters. Values containing CR, LF, or NUL are rejected.
+pub fn build_search_criteria(query: &SearchQuery) -> Result<String, String> {
+    let mut criteria = Vec::new();
+    let strings = [
+        ("FROM", &query.from),
+        ("TO", &query.to),
+        ("SUBJECT", &query.subject),
+        ("TEXT", &query.text),
+    ];
+    let mut non_ascii = false;
+    for (key, value) in strings {
+        if let Some(value) = value.as_deref().filter(|v| !v.is_empty()) {
+            non_ascii |= !value.is_ascii();
+            criteria.push(format!("{} {}", key, astring(value)?));
+        }
+    }
+    if let Some(since) = query.since {
+        criteria.push(format!("SINCE {}", format_imap_date(since)));
+    }
+    if let Some(before) = query.before {
+        criteria.push(format!("BEFORE {}", format_imap_date(before)));
+    }
+    if query.unseen {
+        criteria.push("UNSEEN".to_string());
+    }
+    if criteria.is_empty() {
+        criteria.push("ALL".to_string());
+    }
+
+    let criteria = criteria.join(" ");
+    if non_ascii {
+        Ok(format!("CHARSET UTF-8 {}", criteria))
+    } else {
+        Ok(criteria)
+    }
+}
+
+/// Extract UIDs from untagged `* SEARCH` responses.
+pub fn parse_search_response(response: &[u8]) -> Vec<u32> {
+    String::from_utf8_lossy(response)
+        .lines()
+        .filter_map(|line| line.strip_prefix("* SEARCH"))
+        .flat_map(|rest| {
+            rest.split_whitespace()
+                .filter_map(|n| n.parse::<u32>().ok())
+                .collect::<Vec<_>>()
+        })
+        .collect()
+}
+
+/// Format UIDs as an IMAP sequence set (`1,5,9`).
+pub fn uid_set(uids: &[u32]) -> String {
+    uids.iter()
+        .map(|u| u.to_string())
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+/// One message from an untagged `FETCH` response.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct FetchedMessage {
+    pub uid: u32,
+    pub size: Option<u64>,
+    pub flags: Vec<String>,
+    /// Contents of the first `BODY[...]` item (headers or full message).
+    pub body: Vec<u8>,
+}
+
+impl FetchedMessage {
+    pub fn is_seen(&self) -> bool {
+        self.flags.iter().any(|f| f.eq_ignore_ascii_case("\\Seen"))
+    }
+}
+
+/// A value in an IMAP response.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Token {
+    Atom(String),
+    String(Vec<u8>),
+    List(Vec<Token>),
+    Nil,
+}
+
+/// Parse every untagged `FETCH` response in a complete response buffer.
+///
+/// Messages without a `UID` item are skipped.
+pub fn parse_fetch_response(response: &[u8]) -> Vec<FetchedMessage> {
+    let mut messages = Vec::new();
+    let mut pos = 0;
+
+    while let Some(offset) = find(&response[pos..], b"* ") {
+        let start = pos + offset;
+        // Must be at the start of a line.
+        if start > 0 && response[start - 1] != b'\n' {
+            pos = start + 2;
+            continue;
+        }
+        let mut cursor = start + 2;
+        let seq_end = cursor
+            + response[cursor..]
+                .iter()
+                .take_while(|b| b.is_ascii_digit())
+                .count();
+        if seq_end == cursor || !response[seq_end..].starts_with(b" FETCH ") {
+            pos = start + 2;
+            continue;
+        }
+        cursor = seq_end + b" FETCH ".len();
+
+        if let Some(Token::List(items)) = parse_token(response, &mut cursor) {
+            messages.extend(fetched_from_items(items));
+        }
+        pos = cursor.max(start + 2);
+    }
+
+    messages
+}
+
+fn fetched_from_items(items: Vec<Token>) -> Option<FetchedMessage> {
+    let mut message = FetchedMessage::default();
+    let mut has_uid = false;
+    let mut has_body = false;
+    let mut iter = items.into_iter();
+
+    while let Some(key) = iter.next() {
+        let Token::Atom(key) = key else {
+            continue;
+        };
+        let Some(value) = iter.next() else {
+            break;
+        };
+        let key = key.to_uppercase();
+        match (key.as_str(), value) {
+            ("UID", Token::Atom(uid)) => {
+                message.uid = uid.parse().ok()?;
+                has_uid = true;
+            }
+            ("RFC822.SIZE", Token::Atom(size)) => message.size = size.parse().ok(),
+            ("FLAGS", Token::List(flags)) => {
+                message.flags = flags
+                    .into_iter()
+                    .filter_map(|f| match f {
+                        Token::Atom(a) => Some(a),
+                        _ => None,
+                    })
+                    .collect();
+            }
+            (k, Token::String(bytes))
+                if !has_body && (k.starts_with("BODY[") || k.starts_with("RFC822")) =>
+            {
+                message.body = bytes;
+                has_body = true;
+            }
+            _ => {}
+        }
+    }
+
+    has_uid.then_some(message)
+}
+
+fn find(haystack: &[u8], needle: &[u8]) -> Option<usize> {
+    haystack.windows(needle.len()).position(|w| w == needle)
+}
+
+fn skip_spaces(buf: &[u8], pos: &mut usize) {
+    while *pos < buf.len() && buf[*pos] == b' ' {
+        *pos += 1;
+    }
+}
+
+/// Parse one token at `pos`: a list, quoted string, literal, `NIL`, or atom.
+///
+/// Atoms may contain a bracketed section (`BODY[HEADER.FIELDS (FROM)]`),
+/// which is kept as part of the atom.
+fn parse_token(buf: &[u8], pos: &mut usize) -> Option<Token> {
+    skip_spaces(buf, pos);
+    let first = *buf.get(*pos)?;
+
+    match first {
+        b'(' => {
+            *pos += 1;
+            let mut items = Vec::new();
+            loop {
+                skip_spaces(buf, pos);
+                match buf.get(*pos)? {
+                    b')' => {
+                        *pos += 1;
+                        return Some(Token::List(items));
+                    }
+                    b'\r' | b'\n' => *pos += 1,
+                    _ => items.push(parse_token(buf, pos)?),
+                }
+            }
+        }
+        b'"' => {
+            *pos += 1;
+            let mut out = Vec::new();
+            while let Some(&b) = buf.get(*pos) {
+                *pos += 1;
+                match b {
+                    b'\\' => {
+                        out.push(*buf.get(*pos)?);
+                        *pos += 1;
+                    }
+                    b'"' => return Some(Token::String(out)),
+                    _ => out.push(b),
+                }
+            }
+            None
+        }
+        b'{' => {
+            let close = *pos + find(&buf[*pos..], b"}")?;
+            let len: usize = std::str::from_utf8(&buf[*pos + 1..close])
+                .ok()?
+                .trim_end_matches('+')
+                .parse()
+                .ok()?;
+            let mut start = close + 1;
+            if buf[start..].starts_with(b"\r\n") {
+                start += 2;
+            } else if buf[start..].starts_with(b"\n") {
+                start += 1;
+            }
+            let end = start.checked_add(len)?;
+            let bytes = buf.get(start..end)?.to_vec();
+            *pos = end;
+            Some(Token::String(bytes))
+        }
+        _ => {
+            let start = *pos;
+            let mut depth = 0usize;
+            while let Some(&b) = buf.get(*pos) {
+                match b {
+                    b'[' => depth += 1,
+                    b']' => depth = depth.saturating_sub(1),
+                    b' ' | b')' | b'(' | b'\r' | b'\n' if depth == 0 => break,
+                    _ => {}
+                }
+                *pos += 1;
+            }
+            if *pos == start {
+                return None;
+            }
+            let atom = String::from_utf8_lossy(&buf[start..*pos]).to_string();
+            if atom.eq_ignore_ascii_case("NIL") {
+                Some(Token::Nil)
+            } else {
+                Some(Token::Atom(atom))
+            }
+        }
+    }
+}
+
+/// Status of a tagged completion line (`a1 OK ...`).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Completion {
+    Ok,
+    No(String),
+    Bad(String),
+}
+
+/// Parse a tagged completion line for `tag`; `None` if the line is not one.
+pub fn parse_completion(line: &str, tag: &str) -> Option<Completion> {
+    let rest = line.strip_prefix(tag)?.strip_prefix(' ')?;
+    let (status, text) = rest.split_once(' ').unwrap_or((rest, ""));
+    let text = text.trim().to_string();
+    match status.to_uppercase().as_str() {
+        "OK" => Some(Completion::Ok),
+        "NO" => Some(Completion::No(text)),
+        "BAD" => Some(Completion::Bad(text)),
+        _ => None,
+    }
+}
+
+/// Length of a literal announced at the end of a response line (`{123}`).
+pub fn literal_length(line: &[u8]) -> Option<usize> {
+    let line = line
+        .strip_suffix(b"\r\n")
+        .or_else(|| line.strip_suffix(b"\n"))
+        .unwrap_or(line);
+    let line = line.strip_suffix(b"}")?;
+    let open = line.iter().rposition(|&b| b == b'{')?;
+    std::str::from_utf8(&line[open + 1..])
+        .ok()?
+        .trim_end_matches('+')
+        .parse()
+        .ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_quote_escapes() {
+        assert_eq!(quote(r#"say "hi" \o/"#).unwrap(), r#""say \"hi\" \\o/""#);
+    }
+
+    #[test]
+    fn test_quote_rejects_line_breaks_and_nul() {
+        let injection = "x\"\r\na9 SELECT INBOX\r\na10 UID STORE 1:* +FLAGS (\\Deleted)";
+        assert!(quote(injection).is_err());
+        assert!(quote("a\nb").is_err());
+        assert!(quote("a\0b").is_err());
+        assert!(astring("café\r\nx").is_err());
+
+        let query = SearchQuery {
+            subject: Some(injection.to_string()),
+            ..Default::default()
+        };
+        assert!(build_search_criteria(&query).is_err());
+    }
+
+    #[test]
+    fn test_astring_uses_literals_for_non_ascii() {
+        assert_eq!(astring("plain").unwrap(), "\"plain\"");
+        assert_eq!(astring("café").unwrap(), "{5}\r\ncafé");
+
+        let command = format!("UID SEARCH SUBJECT {} UNSEEN", astring("café").unwrap());
+        assert_eq!(
+            command_segments(&command),
+            vec!["UID SEARCH SUBJECT {5}", "café UNSEEN"]
+        );
+        assert_eq!(
+            command_segments("EXAMINE \"INBOX\""),
+            vec!["EXAMINE \"INBOX\""]
+        );
+    }
+
+    #[test]
+    fn test_build_search_criteria() {
+        assert_eq!(
+            build_search_criteria(&SearchQuery::default()).unwrap(),
+            "ALL"
+        );
+
+        let query = SearchQuery {
+            from: Some("alice@example.com".to_string()),
+            subject: Some("".to_string()),
+            since: NaiveDate::from_ymd_opt(2024, 1, 5),
+            unseen: true,
+            ..Default::default()
+        };
+        assert_eq!(
+            build_search_criteria(&query).unwrap(),
+            "FROM \"alice@example.com\" SINCE 05-Jan-2024 UNSEEN"
+        );
+
+        let query = SearchQuery {
+            subject: Some("café".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(
+            build_search_criteria(&query).unwrap(),
+            "CHARSET UTF-8 SUBJECT {5}\r\ncafé"
+        );
+    }
+
+    #[test]
+    fn test_parse_search_response() {
+        let response = b"* SEARCH 3 7 12\r\n* SEARCH 20\r\na2 OK SEARCH completed\r\n";
+        assert_eq!(parse_search_response(response), vec![3, 7, 12, 20]);
+        assert!(parse_search_response(b"* SEARCH\r\na2 OK\r\n").is_empty());
+    }
+
+    #[test]
+    fn test_parse_fetch_response_with_literals() {
+        let response = b"* 1 FETCH (UID 10 FLAGS (\\Seen \\Answered) RFC822.SIZE 1234 \
+BODY[HEADER.FIELDS (FROM SUBJECT)] {29}\r\nFrom: a@b.c\r\nSubject: Hi)\r\n\r\n)\r\n\
+* 2 FETCH (FLAGS () UID 11 BODY[] {5}\r\nhello)\r\n\
+* 3 EXISTS\r\n\
+a3 OK FETCH completed\r\n";
+        let messages = parse_fetch_response(response);
+
+        assert_eq!(messages.len(), 2);
+        assert_eq!(messages[0].uid, 10);
+        assert_eq!(messages[0].size, Some(1234));
+        assert_eq!(messages[0].flags, vec!["\\Seen", "\\Answered"]);
+        assert!(messages[0].is_seen());
+        assert_eq!(messages[0].body, b"From: a@b.c\r\nSubject: Hi)\r\n\r\n");
+        assert_eq!(messages[1].uid, 11);
+        assert!(!messages[1].is_seen());
+        assert_eq!(messages[1].body, b"hello");
+    }
+
+    #[test]
+    fn test_parse_fetch_response_quoted_and_nil() {
+        let response =
+            b"* 4 FETCH (UID 5 BODY[TEXT] \"a \\\"b\\\"\")\r\n* 5 FETCH (UID 6 BODY[] NIL)\r\n";
+        let messages = parse_fetch_response(response);
+        assert_eq!(messages[0].body, b"a \"b\"");
+        assert!(messages[1].body.is_empty());
+    }
+
+    #[test]
+    fn test_parse_completion() {
+        assert_eq!(
+            parse_completion("a1 OK LOGIN done", "a1"),
+            Some(Completion::Ok)
+        );
+        assert_eq!(
+            parse_completion("a1 NO [AUTHENTICATIONFAILED] Invalid", "a1"),
+            Some(Completion::No("[AUTHENTICATIONFAILED] Invalid".to_string()))
+        );
+        assert_eq!(parse_completion("* OK ready", "a1"), None);
+        assert_eq!(parse_completion("a10 OK", "a1"), None);
+    }
+
+    #[test]
+    fn test_literal_length_and_xoauth2() {
+        assert_eq!(literal_length(b"* 1 FETCH (BODY[] {42}\r\n"), Some(42));
+        assert_eq!(literal_length(b"* 1 FETCH (UID 1)\r\n"), None);
+        assert_eq!(
+            xoauth2_response("u@x.com", "tok"),
+            base64::engine::general_purpose::STANDARD
+                .encode("user=u@x.com\x01auth=Bearer tok\x01\x01")
+        );
+        assert_eq!(uid_set(&[1, 5, 9]), "1,5,9");
+    }
+}
@@ -0,0 +1,598 @@
+//! RFC 5322 / MIME message parsing
+//!
+//! Enough of MIME to read mail: header unfolding, RFC 2047 encoded words,
+//! quoted-printable and base64 transfer encodings, charsets, and multipart
+//! bodies. Parsing never fails; malformed parts degrade to raw text.
+
+use base64::Engine;
+use serde::Serialize;
+
+/// A header name and its unfolded, decoded value.
+pub type Header = (String, String);
+
+/// Body text and attachment listing of a parsed message.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct MessageBody {
+    /// Concatenated `text/plain` parts.
+    pub text: Option<String>,
+    /// Concatenated `text/html` parts.
+    pub html: Option<String>,
+    pub attachments: Vec<AttachmentInfo>,
+}
+
+/// An attachment, listed by name and size (contents are not kept).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct AttachmentInfo {
+    pub filename: String,
+    pub content_type: String,
+    /// Decoded size in bytes.
+    pub size: usize,
+}
+
+/// Split a raw message into decoded headers and the body bytes.
+pub fn split_message(raw: &[u8]) -> (Vec<Header>, &[u8]) {
+    let (header_end, body_start) = find_header_end(raw);
+    let header_text = String::from_utf8_lossy(&raw[..header_end]);
+    (parse_headers(&header_text), &raw[body_start..])
+}
+
+/// Locate the blank line that ends the header block.
+///
+/// Returns the end of the headers and the start of the body.
+fn find_header_end(raw: &[u8]) -> (usize, usize) {
+    if raw.starts_with(b"\r\n") {
+        return (0, 2);
+    }
+    if raw.starts_with(b"\n") {
+        return (0, 1);
+    }
+    let crlf = find_bytes(raw, b"\r\n\r\n").map(|i| (i, i + 4));
+    let lf = find_bytes(raw, b"\n\n").map(|i| (i, i + 2));
+    match (crlf, lf) {
+        (Some(a), Some(b)) => {
+            if a.0 <= b.0 {
+                a
+            } else {
+                b
+            }
+        }
+        (Some(a), None) => a,
+        (None, Some(b)) => b,
+        (None, None) => (raw.len(), raw.len()),
+    }
+}
+
+fn find_bytes(haystack: &[u8], needle: &[u8]) -> Option<usize> {
+    haystack.windows(needle.len()).position(|w| w == needle)
+}
+
+/// Parse a header block: unfold continuation lines and decode encoded words.
+pub fn parse_headers(text: &str) -> Vec<Header> {
+    let mut headers: Vec<Header> = Vec::new();
+    for line in text.lines() {
+        if line.starts_with([' ', '\t']) {
+            if let Some((_, value)) = headers.last_mut() {
+                value.push(' ');
+                value.push_str(line.trim());
+            }
+            continue;
+        }
+        if let Some((name, value)) = line.split_once(':') {
+            headers.push((name.trim().to_string(), value.trim().to_string()));
+        }
+    }
+    headers
+        .into_iter()
+        .map(|(name, value)| {
+            let value = decode_encoded_words(&value);
+            (name, value)
+        })
+        .collect()
+}
+
+/// First value of a header, matched case-insensitively.
+pub fn header<'a>(headers: &'a [Header], name: &str) -> Option<&'a str> {
+    headers
+        .iter()
+        .find(|(n, _)| n.eq_ignore_ascii_case(name))
+        .map(|(_, v)| v.as_str())
+}
+
+/// Decode RFC 2047 encoded words (`=?utf-8?B?...?=`, `=?iso-8859-1?Q?...?=`).
+///
+/// Whitespace between adjacent encoded words is dropped, as the RFC requires.
+pub fn decode_encoded_words(value: &str) -> String {
+    let mut out = String::new();
+    let mut rest = value;
+    let mut pending_space = String::new();
+    let mut last_was_encoded = false;
+
+    while !rest.is_empty() {
+        let Some(start) = rest.find("=?") else {
+            out.push_str(&pending_space);
+            out.push_str(rest);
+            return out;
+        };
+        let (before, candidate) = rest.split_at(start);
+
+        match parse_encoded_word(candidate) {
+            Some((decoded, consumed)) => {
+                if !(last_was_encoded && before.trim().is_empty()) {
+                    out.push_str(&pending_space);
+                    out.push_str(before);
+                }
+                pending_space.clear();
+                out.push_str(&decoded);
+                last_was_encoded = true;
+                rest = &candidate[consumed..];
+                // Hold trailing whitespace until we know what follows it.
+                let trimmed = rest.trim_start();
+                pending_space.push_str(&rest[..rest.len() - trimmed.len()]);
+                rest = trimmed;
+            }
+            None => {
+                out.push_str(&pending_space);
+                pending_space.clear();
+                out.push_str(before);
+                out.push_str("=?");
+                last_was_encoded = false;
+                rest = &candidate[2..];
+            }
+        }
+    }
+    out.push_str(&pending_space);
+    out
+}
+
+/// Parse one encoded word at the start of `s`; returns the decoded text and
+/// the number of bytes consumed.
+fn parse_encoded_word(s: &str) -> Option<(String, usize)> {
+    let inner = s.strip_prefix("=?")?;
+    let (charset, inner) = inner.split_once('?')?;
+    let (encoding, inner) = inner.split_once('?')?;
+    let end = inner.find("?=")?;
+    let text = &inner[..end];
+    if text.contains(' ') {
+        return None;
+    }
+    let consumed = 2 + charset.len() + 1 + encoding.len() + 1 + end + 2;
+    // RFC 2231 language suffix: charset*lang
+    let charset = charset.split('*').next().unwrap_or(charset);
+
+    let bytes = match encoding {
+        "B" | "b" => base64::engine::general_purpose::STANDARD
+            .decode(text)
+            .or_else(|_| base64::engine::general_purpose::STANDARD_NO_PAD.decode(text))
+            .ok()?,
+        "Q" | "q" => decode_quoted_printable(text.replace('_', " ").as_bytes()),
+        _ => return None,
+    };
+
+    Some((decode_charset(&bytes, charset), consumed))
+}
+
+/// Decode quoted-printable bytes, including soft line breaks.
+pub fn decode_quoted_printable(input: &[u8]) -> Vec<u8> {
+    let mut out = Vec::with_capacity(input.len());
+    let mut i = 0;
+    while i < input.len() {
+        let b = input[i];
+        if b != b'=' {
+            out.push(b);
+            i += 1;
+            continue;
+        }
+        // Soft line break: "=\r\n" or "=\n"
+        if input[i + 1..].starts_with(b"\r\n") {
+            i += 3;
+            continue;
+        }
+        if input[i + 1..].starts_with(b"\n") {
+            i += 2;
+            continue;
+        }
+        let hex = input
+            .get(i + 1..i + 3)
+            .and_then(|h| std::str::from_utf8(h).ok())
+            .and_then(|h| u8::from_str_radix(h, 16).ok());
+        match hex {
+            Some(byte) => {
+                out.push(byte);
+                i += 3;
+            }
+            None => {
+                out.push(b'=');
+                i += 1;
+            }
+        }
+    }
+    out
+}
+
+/// Decode bytes in the given charset, falling back to lossy UTF-8.
+pub fn decode_charset(bytes: &[u8], charset: &str) -> String {
+    let label = charset.trim().trim_matches('"');
+    let encoding = encoding_rs::Encoding::for_label(label.as_bytes());
+    match encoding {
+        Some(encoding) => encoding.decode(bytes).0.into_owned(),
+        None => String::from_utf8_lossy(bytes).into_owned(),
+    }
+}
+
+/// A parsed `Content-Type` or `Content-Disposition` value.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HeaderValue {
+    /// Lowercased main value (`text/plain`, `attachment`).
+    pub value: String,
+    /// Parameters with lowercased names and unquoted values.
+    pub params: Vec<(String, String)>,
+}
+
+impl HeaderValue {
+    pub fn param(&self, name: &str) -> Option<&str> {
+        self.params
+            .iter()
+            .find(|(n, _)| n.eq_ignore_ascii_case(name))
+            .map(|(_, v)| v.as_str())
+    }
+}
+
+/// Parse a structured header value with `; name=value` parameters.
+///
+/// Handles quoted values and RFC 2231 `name*=charset''percent-encoded`
+/// parameters (continuations `name*0*`, `name*1*` are joined).
+pub fn parse_header_value(value: &str) -> HeaderValue {
+    let mut parts = split_params(value).into_iter();
+    let main = parts.next().unwrap_or_default().trim().to_lowercase();
+
+    let mut params: Vec<(String, String)> = Vec::new();
+    let mut extended: Vec<(String, usize, bool, String)> = Vec::new();
+
+    for part in parts {
+        let Some((name, val)) = part.split_once('=') else {
+            continue;
+        };
+        let name = name.trim().to_lowercase();
+        let val = unquote(val.trim());
+
+        if let Some(base) = name.strip_suffix('*') {
+            // name*=... or name*N*=...
+            let (base, index) = match base.rsplit_once('*') {
+                Some((b, n)) => (b.to_string(), n.parse().unwrap_or(0)),
+                None => (base.to_string(), 0),
+            };
+            extended.push((base, index, true, val));
+        } else if let Some((base, n)) = name.rsplit_once('*') {
+            extended.push((base.to_string(), n.parse().unwrap_or(0), false, val));
+        } else {
+            params.push((name, val));
+        }
+    }
+
+    extended.sort_by(|a, b| a.0.cmp(&b.0).then(a.1.cmp(&b.1)));
+    let mut i = 0;
+    while i < extended.len() {
+        let base = extended[i].0.clone();
+        let mut charset = "utf-8".to_string();
+        let mut bytes = Vec::new();
+        while i < extended.len() && extended[i].0 == base {
+            let (_, index, encoded, val) = &extended[i];
+            if *encoded {
+                let mut text = val.as_str();
+                if *index == 0 {
+                    if let Some((cs, rest)) = text.split_once('\'') {
+                        charset = cs.to_string();
+                        text = rest.split_once('\'').map(|(_, t)| t).unwrap_or(rest);
+                    }
+                }
+                bytes.extend(percent_decode(text));
+            } else {
+                bytes.extend_from_slice(val.as_bytes());
+            }
+            i += 1;
+        }
+        params.retain(|(n, _)| n != &base);
+        params.push((base, decode_charset(&bytes, &charset)));
+    }
+
+    HeaderValue {
+        value: main,
+        params,
+    }
+}
+
+/// Split on `;` outside of quotes.
+fn split_params(value: &str) -> Vec<String> {
+    let mut parts = Vec::new();
+    let mut current = String::new();
+    let mut in_quotes = false;
+    let mut escaped = false;
+    for c in value.chars() {
+        match c {
+            _ if escaped => {
+                current.push(c);
+                escaped = false;
+            }
+            '\\' if in_quotes => {
+                current.push(c);
+                escaped = true;
+            }
+            '"' => {
+                in_quotes = !in_quotes;
+                current.push(c);
+            }
+            ';' if !in_quotes => parts.push(std::mem::take(&mut current)),
+            _ => current.push(c),
+        }
+    }
+    parts.push(current);
+    parts
+}
+
+fn unquote(value: &str) -> String {
+    match value.strip_prefix('"').and_then(|v| v.strip_suffix('"')) {
+        Some(inner) => inner.replace("\\\"", "\"").replace("\\\\", "\\"),
+        None => value.to_string(),
+    }
+}
+
+fn percent_decode(text: &str) -> Vec<u8> {
+    let bytes = text.as_bytes();
+    let mut out = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        if bytes[i] == b'%' {
+            if let Some(byte) = bytes
+                .get(i + 1..i + 3)
+                .and_then(|h| std::str::from_utf8(h).ok())
+                .and_then(|h| u8::from_str_radix(h, 16).ok())
+            {
+                out.push(byte);
+                i += 3;
+                continue;
+            }
+        }
+        out.push(bytes[i]);
+        i += 1;
+    }
+    out
+}
+
+/// Decode a part body according to its `Content-Transfer-Encoding`.
+pub fn decode_transfer_encoding(body: &[u8], encoding: Option<&str>) -> Vec<u8> {
+    match encoding.map(|e| e.trim().to_lowercase()).as_deref() {
+        Some("base64") => {
+            let cleaned: Vec<u8> = body
+                .iter()
+                .copied()
+                .filter(|b| !b.is_ascii_whitespace())
+                .collect();
+            base64::engine::general_purpose::STANDARD
+                .decode(&cleaned)
+                .or_else(|_| base64::engine::general_purpose::STANDARD_NO_PAD.decode(&cleaned))
+                .unwrap_or_else(|_| body.to_vec())
+        }
+        Some("quoted-printable") => decode_quoted_printable(body),
+        _ => body.to_vec(),
+    }
+}
+
+/// Parse a message body (the part after the top-level headers).
+pub fn parse_body(headers: &[Header], body: &[u8]) -> MessageBody {
+    let mut result = MessageBody::default();
+    walk_part(headers, body, &mut result, 0);
+    result
+}
+
+/// Maximum multipart nesting depth; deeper parts are ignored.
+const MAX_DEPTH: usize = 16;
+
+fn walk_part(headers: &[Header], body: &[u8], out: &mut MessageBody, depth: usize) {
+    if depth > MAX_DEPTH {
+        return;
+    }
+
+    let content_type = header(headers, "Content-Type")
+        .map(parse_header_value)
+        .unwrap_or_else(|| HeaderValue {
+            value: "text/plain".to_string(),
+            params: vec![],
+        });
+
+    if content_type.value.starts_with("multipart/") {
+        let Some(boundary) = content_type.param("boundary") else {
+            return;
+        };
+        let parts = split_multipart(body, boundary);
+
+        if content_type.value == "multipart/alternative" {
+            // Alternatives render the same content: keep the last (richest)
+            // plain-text and HTML versions instead of concatenating them.
+            let mut text = None;
+            let mut html = None;
+            for part in parts {
+                let (part_headers, part_body) = split_message(part);
+                let mut alt = MessageBody::default();
+                walk_part(&part_headers, part_body, &mut alt, depth + 1);
+                text = alt.text.or(text);
+                html = alt.html.or(html);
+                out.attachments.extend(alt.attachments);
+            }
+            append_text(&mut out.text, text);
+            append_text(&mut out.html, html);
+        } else {
+            for part in parts {
+                let (part_headers, part_body) = split_message(part);
+                walk_part(&part_headers, part_body, out, depth + 1);
+            }
+        }
+        return;
+    }
+
+    let disposition = header(headers, "Content-Disposition").map(parse_header_value);
+    let filename = disposition
+        .as_ref()
+        .and_then(|d| d.param("filename"))
+        .or_else(|| content_type.param("name"))
+        .map(str::to_string);
+    let is_attachment = disposition
+        .as_ref()
+        .is_some_and(|d| d.value == "attachment")
+        || filename.is_some();
+    let is_text = content_type.value == "text/plain" || content_type.value == "text/html";
+
+    let decoded = decode_transfer_encoding(body, header(headers, "Content-Transfer-Encoding"));
+
+    if is_attachment || !is_text {
+        let filename = filename.unwrap_or_else(|| match content_type.value.as_str() {
+            "message/rfc822" => "message.eml".to_string(),
+            other => format!("unnamed.{}", other.rsplit('/').next().unwrap_or("bin")),
+        });
+        out.attachments.push(AttachmentInfo {
+            filename,
+            content_type: content_type.value,
+            size: decoded.len(),
+        });
+        return;
+    }
+
+    let charset = content_type.param("charset").unwrap_or("utf-8");
+    let text = Some(decode_charset(&decoded, charset));
+    if content_type.value == "text/html" {
+        append_text(&mut out.html, text);
+    } else {
+        append_text(&mut out.text, text);
+    }
+}
+
+fn append_text(slot: &mut Option<String>, text: Option<String>) {
+    let Some(text) = text else {
+        return;
+    };
+    match slot {
+        Some(existing) => {
+            existing.push_str("\n\n");
+            existing.push_str(&text);
+        }
+        None => *slot = Some(text),
+    }
+}
+
+/// Split a multipart body into its parts (headers and body of each).
+pub fn split_multipart<'a>(body: &'a [u8], boundary: &str) -> Vec<&'a [u8]> {
+    let delimiter = format!("--{}", boundary);
+    let delimiter = delimiter.as_bytes();
+
+    let mut parts = Vec::new();
+    let mut start: Option<usize> = None;
+    let mut pos = 0;
+
+    while pos <= body.len() {
+        let line_end = body[pos..]
+            .iter()
+            .position(|&b| b == b'\n')
+            .map(|i| pos + i + 1)
+            .unwrap_or(body.len());
+        let line = &body[pos..line_end];
+        let trimmed = trim_line_end(line);
+
+        if trimmed.starts_with(delimiter) {
+            let rest = &trimmed[delimiter.len()..];
+            if let Some(s) = start {
+                // Exclude the line break that precedes the delimiter.
+                parts.push(trim_trailing_newline(&body[s..pos]));
+            }
+            if rest.starts_with(b"--") {
+                return parts;
+            }
+            start = Some(line_end);
+        }
+
+        if line_end == body.len() {
+            break;
+        }
+        pos = line_end;
+    }
+
+    // Missing closing delimiter: keep what we have.
+    if let Some(s) = start {
+        if s < body.len() {
+            parts.push(trim_trailing_newline(&body[s..]));
+        }
+    }
+    parts
+}
+
+fn trim_line_end(line: &[u8]) -> &[u8] {
+    let mut end = line.len();
+    while end > 0 && matches!(line[end - 1], b'\n' | b'\r' | b' ' | b'\t') {
+        end -= 1;
+    }
+    &line[..end]
+}
+
+fn trim_trailing_newline(part: &[u8]) -> &[u8] {
+    part.strip_suffix(b"\r\n")
+        .or_else(|| part.strip_suffix(b"\n"))
+        .unwrap_or(part)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_headers_unfolds() {
+        let headers = parse_headers("Subject: a long\r\n  subject\r\nX-Empty:\r\nFrom: x@y.z\r\n");
+        assert_eq!(header(&headers, "subject"), Some("a long subject"));
+        assert_eq!(header(&headers, "X-Empty"), Some(""));
+        assert_eq!(header(&headers, "FROM"), Some("x@y.z"));
+    }
+
+    #[test]
+    fn test_decode_encoded_words() {
+        assert_eq!(
+            decode_encoded_words("=?UTF-8?B?SGVsbG8=?= world"),
+            "Hello world"
+        );
+        assert_eq!(
+            decode_encoded_words("=?ISO-8859-1?Q?caf=E9_cr=E8me?="),
+            "café crème"
+        );
+        // Whitespace between adjacent encoded words is dropped.
+        assert_eq!(decode_encoded_words("=?UTF-8?Q?a?= =?UTF-8?Q?b?="), "ab");
+        assert_eq!(decode_encoded_words("plain =?bogus"), "plain =?bogus");
+    }
+
+    #[test]
+    fn test_decode_quoted_printable() {
+        assert_eq!(decode_quoted_printable(b"a=3Db=\r\nc"), b"a=bc");
+        assert_eq!(decode_quoted_printable(b"bad=ZZ"), b"bad=ZZ");
+    }
+
+    #[test]
+    fn test_parse_header_value_params() {
+        let value = parse_header_value("attachment; filename=\"my file.txt\"; size=10");
+        assert_eq!(value.value, "attachment");
+        assert_eq!(value.param("FILENAME"), Some("my file.txt"));
+        assert_eq!(value.param("size"), Some("10"));
+
+        let value =
+            parse_header_value("attachment; filename*0*=UTF-8''r%C3%A9sum; filename*1=\"e.pdf\"");
+        assert_eq!(value.param("filename"), Some("résume.pdf"));
+    }
+
+    #[test]
+    fn test_split_multipart() {
+        let body = b"pre\r\n--b\r\nA\r\n--b\r\nB\r\n--b--\r\nepilogue";
+        assert_eq!(split_multipart(body, "b"), vec![&b"A"[..], &b"B"[..]]);
+    }
+
+    #[test]
+    fn test_parse_body_plain_default() {
+        let (headers, body) = split_message(b"Subject: x\n\nhello\n");
+        let parsed = parse_body(&headers, body);
+        assert_eq!(parsed.text.as_deref(), Some("hello\n"));
+        assert!(parsed.attachments.is_empty());
+    }
+}
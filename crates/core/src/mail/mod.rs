@@ -0,0 +1,239 @@
+/// Read-only mail transformations (IMAP responses, MIME messages)
+///
+/// This module contains pure functions for building IMAP commands, parsing
+/// IMAP responses, and converting RFC 5322 messages to Markdown.
+/// All functions are free of I/O operations and testable with fixture data.
+pub mod imap;
+pub mod mime;
+
+use serde::Serialize;
+
+use imap::FetchedMessage;
+use mime::{header, parse_body, split_message, AttachmentInfo};
+
+/// One row of `mail search` output.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct MailSummary {
+    pub uid: u32,
+    pub from: String,
+    pub to: String,
+    pub subject: String,
+    pub date: String,
+    pub size: Option<u64>,
+    pub seen: bool,
+}
+
+/// Output of `mail search`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct MailSearchOutput {
+    pub mailbox: String,
+    /// Number of messages matching the query (before `limit`).
+    pub total: usize,
+    /// Newest matches first.
+    pub messages: Vec<MailSummary>,
+}
+
+/// A full message, decoded for display.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct MailMessage {
+    pub uid: u32,
+    pub mailbox: String,
+    pub message_id: Option<String>,
+    pub from: String,
+    pub to: String,
+    pub cc: Option<String>,
+    pub subject: String,
+    pub date: String,
+    /// Body as Markdown (plain text preferred, HTML converted otherwise).
+    pub body: String,
+    pub attachments: Vec<AttachmentInfo>,
+}
+
+/// Build a search row from a `FETCH` of `FLAGS RFC822.SIZE BODY.PEEK[HEADER...]`.
+pub fn summarize_fetched(message: &FetchedMessage) -> MailSummary {
+    let (headers, _) = split_message(&message.body);
+    let get = |name: &str| header(&headers, name).unwrap_or_default().to_string();
+
+    MailSummary {
+        uid: message.uid,
+        from: get("From"),
+        to: get("To"),
+        subject: get("Subject"),
+        date: get("Date"),
+        size: message.size,
+        seen: message.is_seen(),
+    }
+}
+
+/// Decode a raw RFC 5322 message into a [`MailMessage`].
+pub fn parse_message(uid: u32, mailbox: &str, raw: &[u8]) -> MailMessage {
+    let (headers, body) = split_message(raw);
+    let get = |name: &str| header(&headers, name).map(str::to_string);
+    let parsed = parse_body(&headers, body);
+
+    let body = match (parsed.text, parsed.html) {
+        (Some(text), _) if !text.trim().is_empty() => text.replace("\r\n", "\n"),
+        (_, Some(html)) => html2md::parse_html(&html),
+        (text, None) => text.unwrap_or_default(),
+    };
+
+    MailMessage {
+        uid,
+        mailbox: mailbox.to_string(),
+        message_id: get("Message-ID"),
+        from: get("From").unwrap_or_default(),
+        to: get("To").unwrap_or_default(),
+        cc: get("Cc"),
+        subject: get("Subject").unwrap_or_default(),
+        date: get("Date").unwrap_or_default(),
+        body: body.trim().to_string(),
+        attachments: parsed.attachments,
+    }
+}
+
+/// Format a byte count for display (`512 B`, `1.5 KB`, `2.0 MB`).
+pub fn format_size(bytes: usize) -> String {
+    const KB: f64 = 1024.0;
+    let b = bytes as f64;
+    if b < KB {
+        format!("{} B", bytes)
+    } else if b < KB * KB {
+        format!("{:.1} KB", b / KB)
+    } else {
+        format!("{:.1} MB", b / (KB * KB))
+    }
+}
+
+/// Render a message as Markdown: subject heading, header list, body, attachments.
+pub fn message_to_markdown(message: &MailMessage) -> String {
+    let subject = if message.subject.is_empty() {
+        "(no subject)"
+    } else {
+        &message.subject
+    };
+
+    let mut out = format!("# {}\n\n", subject);
+    out.push_str(&format!("- **From:** {}\n", message.from));
+    out.push_str(&format!("- **To:** {}\n", message.to));
+    if let Some(cc) = message.cc.as_deref().filter(|c| !c.is_empty()) {
+        out.push_str(&format!("- **Cc:** {}\n", cc));
+    }
+    out.push_str(&format!("- **Date:** {}\n", message.date));
+    out.push_str(&format!("- **UID:** {}\n", message.uid));
+
+    if !message.body.is_empty() {
+        out.push('\n');
+        out.push_str(&message.body);
+        out.push('\n');
+    }
+
+    if !message.attachments.is_empty() {
+        out.push_str("\n## Attachments\n\n");
+        for attachment in &message.attachments {
+            out.push_str(&format!(
+                "- {} ({}, {})\n",
+                attachment.filename,
+                attachment.content_type,
+                format_size(attachment.size)
+            ));
+        }
+    }
+
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MULTIPART: &[u8] = b"From: =?UTF-8?Q?Jos=C3=A9?= <jose@example.com>\r\n\
+To: team@example.com\r\n\
+Subject: =?UTF-8?B?UmVwb3J0IMOp?=\r\n\
+Date: Mon, 1 Jan 2024 10:00:00 +0000\r\n\
+Message-ID: <abc@example.com>\r\n\
+Content-Type: multipart/mixed; boundary=\"outer\"\r\n\
+\r\n\
+preamble\r\n\
+--outer\r\n\
+Content-Type: multipart/alternative; boundary=inner\r\n\
+\r\n\
+--inner\r\n\
+Content-Type: text/plain; charset=iso-8859-1\r\n\
+Content-Transfer-Encoding: quoted-printable\r\n\
+\r\n\
+Caf=E9 at noon=\r\n\
+, see attached.\r\n\
+--inner\r\n\
+Content-Type: text/html\r\n\
+\r\n\
+<p>Caf&eacute; at noon</p>\r\n\
+--inner--\r\n\
+--outer\r\n\
+Content-Type: application/pdf; name=\"report.pdf\"\r\n\
+Content-Disposition: attachment; filename=\"report.pdf\"\r\n\
+Content-Transfer-Encoding: base64\r\n\
+\r\n\
+SGVsbG8gd29ybGQ=\r\n\
+--outer--\r\n";
+
+    #[test]
+    fn test_parse_message_multipart() {
+        let message = parse_message(42, "INBOX", MULTIPART);
+
+        assert_eq!(message.from, "José <jose@example.com>");
+        assert_eq!(message.subject, "Report é");
+        assert_eq!(message.message_id.as_deref(), Some("<abc@example.com>"));
+        assert_eq!(message.body, "Café at noon, see attached.");
+        assert_eq!(
+            message.attachments,
+            vec![AttachmentInfo {
+                filename: "report.pdf".to_string(),
+                content_type: "application/pdf".to_string(),
+                size: 11,
+            }]
+        );
+    }
+
+    #[test]
+    fn test_parse_message_html_only() {
+        let raw = b"Subject: Hi\r\nContent-Type: text/html\r\n\r\n<h1>Title</h1><p>Body</p>";
+        let message = parse_message(1, "INBOX", raw);
+        assert!(message.body.contains("Title"));
+        assert!(message.body.contains("Body"));
+        assert!(!message.body.contains("<p>"));
+    }
+
+    #[test]
+    fn test_message_to_markdown() {
+        let message = parse_message(42, "INBOX", MULTIPART);
+        let markdown = message_to_markdown(&message);
+
+        assert!(markdown.starts_with("# Report é\n\n- **From:** José <jose@example.com>\n"));
+        assert!(markdown.contains("- **UID:** 42\n\nCafé at noon"));
+        assert!(markdown.ends_with("## Attachments\n\n- report.pdf (application/pdf, 11 B)\n"));
+        assert!(!markdown.contains("**Cc:**"));
+    }
+
+    #[test]
+    fn test_summarize_fetched() {
+        let fetched = FetchedMessage {
+            uid: 7,
+            size: Some(2048),
+            flags: vec!["\\Seen".to_string()],
+            body: b"From: a@example.com\r\nSubject: Hello\r\n\r\n".to_vec(),
+        };
+        let summary = summarize_fetched(&fetched);
+        assert_eq!(summary.uid, 7);
+        assert_eq!(summary.from, "a@example.com");
+        assert_eq!(summary.subject, "Hello");
+        assert_eq!(summary.to, "");
+        assert!(summary.seen);
+    }
+
+    #[test]
+    fn test_format_size() {
+        assert_eq!(format_size(512), "512 B");
+        assert_eq!(format_size(1536), "1.5 KB");
+        assert_eq!(format_size(2 * 1024 * 1024), "2.0 MB");
+    }
+}
@@ -0,0 +1,301 @@
+//! Page metadata extraction
+//!
+//! Pure functions that read the canonical URL, meta description, OpenGraph
+//! and Twitter card properties, and JSON-LD entities out of a page's HTML.
+
+use scraper::{Html, Selector};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::BTreeMap;
+
+/// Property prefixes collected under `open_graph` (OpenGraph and its
+/// object-type namespaces).
+const OPEN_GRAPH_PREFIXES: &[&str] = &["og:", "article:", "book:", "profile:", "music:", "video:"];
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PageMetadata {
+    /// `<link rel="canonical">`, resolved against the page URL
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub canonical_url: Option<String>,
+    /// `<meta name="description">`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// `og:*` (and `article:*`, ...) properties; repeated ones become arrays
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    pub open_graph: BTreeMap<String, Value>,
+    /// `twitter:*` properties; repeated ones become arrays
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    pub twitter: BTreeMap<String, Value>,
+    /// Entities from `<script type="application/ld+json">` blocks, with
+    /// top-level arrays and `@graph` lists flattened
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub json_ld: Vec<Value>,
+}
+
+impl PageMetadata {
+    pub fn is_empty(&self) -> bool {
+        self == &PageMetadata::default()
+    }
+}
+
+/// Output of `md meta`.
+#[derive(Debug, Clone, Serialize)]
+pub struct MetaOutput {
+    pub url: String,
+    pub title: Option<String>,
+    pub metadata: PageMetadata,
+}
+
+fn insert_property(map: &mut BTreeMap<String, Value>, key: String, value: String) {
+    let value = Value::String(value);
+    match map.get_mut(&key) {
+        None => {
+            map.insert(key, value);
+        }
+        Some(Value::Array(values)) => values.push(value),
+        Some(existing) => {
+            let first = existing.take();
+            *existing = Value::Array(vec![first, value]);
+        }
+    }
+}
+
+/// Parse one JSON-LD block, tolerating the HTML comment and CDATA wrappers
+/// some sites put around it. Invalid blocks yield nothing.
+fn parse_json_ld(text: &str) -> Vec<Value> {
+    let text = text.trim();
+    let text = text
+        .strip_prefix("<!--")
+        .and_then(|t| t.strip_suffix("-->"))
+        .unwrap_or(text)
+        .trim();
+    let text = text
+        .strip_prefix("//<![CDATA[")
+        .and_then(|t| t.strip_suffix("//]]>"))
+        .unwrap_or(text)
+        .trim();
+
+    let mut entities = Vec::new();
+    let mut pending = match serde_json::from_str::<Value>(text) {
+        Ok(value) => vec![value],
+        Err(_) => return entities,
+    };
+    while let Some(value) = pending.pop() {
+        match value {
+            Value::Array(items) => pending.extend(items.into_iter().rev()),
+            Value::Object(mut map) => match map.remove("@graph") {
+                Some(Value::Array(graph)) => pending.extend(graph.into_iter().rev()),
+                Some(graph) => {
+                    map.insert("@graph".to_string(), graph);
+                    entities.push(Value::Object(map));
+                }
+                None => entities.push(Value::Object(map)),
+            },
+            _ => {}
+        }
+    }
+    entities
+}
+
+/// Extract metadata from a page's HTML. `page_url` resolves a relative
+/// canonical link.
+pub fn extract_metadata(html: &str, page_url: &str) -> PageMetadata {
+    let document = Html::parse_document(html);
+    let mut metadata = PageMetadata::default();
+
+    let link = Selector::parse("link[rel][href]").expect("valid selector");
+    metadata.canonical_url = document
+        .select(&link)
+        .find(|el| {
+            el.value().attr("rel").is_some_and(|rel| {
+                rel.split_whitespace()
+                    .any(|r| r.eq_ignore_ascii_case("canonical"))
+            })
+        })
+        .and_then(|el| el.value().attr("href"))
+        .map(str::trim)
+        .filter(|href| !href.is_empty())
+        .map(|href| {
+            url::Url::parse(page_url)
+                .and_then(|base| base.join(href))
+                .map(|url| url.to_string())
+                .unwrap_or_else(|_| href.to_string())
+        });
+
+    let meta = Selector::parse("meta[content]").expect("valid selector");
+    for el in document.select(&meta) {
+        let Some(content) = el.value().attr("content").map(str::trim) else {
+            continue;
+        };
+        // OpenGraph uses `property`, Twitter `name`, but sites mix them up.
+        let Some(key) = el
+            .value()
+            .attr("property")
+            .or_else(|| el.value().attr("name"))
+            .map(|k| k.trim().to_ascii_lowercase())
+        else {
+            continue;
+        };
+        if content.is_empty() {
+            continue;
+        }
+        if key == "description" {
+            metadata
+                .description
+                .get_or_insert_with(|| content.to_string());
+        } else if key.starts_with("twitter:") {
+            insert_property(&mut metadata.twitter, key, content.to_string());
+        } else if OPEN_GRAPH_PREFIXES.iter().any(|p| key.starts_with(p)) {
+            insert_property(&mut metadata.open_graph, key, content.to_string());
+        }
+    }
+
+    let script = Selector::parse("script[type]").expect("valid selector");
+    for el in document.select(&script) {
+        let is_json_ld = el
+            .value()
+            .attr("type")
+            .is_some_and(|t| t.trim().eq_ignore_ascii_case("application/ld+json"));
+        if is_json_ld {
+            metadata
+                .json_ld
+                .extend(parse_json_ld(&el.text().collect::<String>()));
+        }
+    }
+
+    metadata
+}
+
+fn value_text(value: &Value) -> String {
+    match value {
+        Value::String(text) => text.clone(),
+        Value::Array(items) => items.iter().map(value_text).collect::<Vec<_>>().join(", "),
+        other => other.to_string(),
+    }
+}
+
+/// Render `md meta` output as plain text: one `key: value` line per field,
+/// and a one-line summary (`@type` plus name or headline) per JSON-LD entity.
+pub fn format_meta_text(output: &MetaOutput) -> String {
+    let mut lines = vec![format!("url: {}", output.url)];
+    if let Some(title) = &output.title {
+        lines.push(format!("title: {}", title));
+    }
+    let metadata = &output.metadata;
+    if let Some(canonical) = &metadata.canonical_url {
+        lines.push(format!("canonical: {}", canonical));
+    }
+    if let Some(description) = &metadata.description {
+        lines.push(format!("description: {}", description));
+    }
+    for (key, value) in metadata.open_graph.iter().chain(&metadata.twitter) {
+        lines.push(format!("{}: {}", key, value_text(value)));
+    }
+    for entity in &metadata.json_ld {
+        let kind = entity.get("@type").map_or("Thing".to_string(), value_text);
+        let label = ["name", "headline"]
+            .iter()
+            .find_map(|key| entity.get(*key).and_then(Value::as_str));
+        match label {
+            Some(label) => lines.push(format!("json-ld: {} ({})", kind, label)),
+            None => lines.push(format!("json-ld: {}", kind)),
+        }
+    }
+    lines.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    const PAGE: &str = r#"<!doctype html>
+<html><head>
+  <title>Post</title>
+  <link rel="stylesheet" href="/style.css">
+  <link rel="Canonical" href="/posts/1">
+  <meta name="description" content=" A short post. ">
+  <meta property="og:title" content="Post">
+  <meta property="og:image" content="https://example.com/a.png">
+  <meta property="og:image" content="https://example.com/b.png">
+  <meta property="article:published_time" content="2024-05-01">
+  <meta name="twitter:card" content="summary">
+  <meta property="twitter:site" content="@example">
+  <meta name="og:type" content="article">
+  <meta name="viewport" content="width=device-width">
+  <meta property="og:empty" content="">
+  <script type="application/ld+json">
+    {"@context": "https://schema.org", "@type": "Article", "headline": "Post"}
+  </script>
+  <script type="application/ld+json"><!--
+    {"@context": "https://schema.org", "@graph": [
+      {"@type": "WebSite", "name": "Example"},
+      {"@type": "Organization", "name": "Example Inc"}
+    ]}
+  --></script>
+  <script type="application/ld+json">{not json</script>
+  <script>var x = {"@type": "Ignored"};</script>
+</head><body><p>Hi</p></body></html>"#;
+
+    #[test]
+    fn test_extract_metadata() {
+        let metadata = extract_metadata(PAGE, "https://example.com/posts/1?ref=feed");
+
+        assert_eq!(
+            metadata.canonical_url.as_deref(),
+            Some("https://example.com/posts/1")
+        );
+        assert_eq!(metadata.description.as_deref(), Some("A short post."));
+        assert_eq!(metadata.open_graph["og:title"], json!("Post"));
+        assert_eq!(metadata.open_graph["og:type"], json!("article"));
+        assert_eq!(
+            metadata.open_graph["og:image"],
+            json!(["https://example.com/a.png", "https://example.com/b.png"])
+        );
+        assert_eq!(
+            metadata.open_graph["article:published_time"],
+            json!("2024-05-01")
+        );
+        assert!(!metadata.open_graph.contains_key("og:empty"));
+        assert_eq!(metadata.twitter["twitter:card"], json!("summary"));
+        assert_eq!(metadata.twitter["twitter:site"], json!("@example"));
+
+        let types: Vec<&str> = metadata
+            .json_ld
+            .iter()
+            .filter_map(|e| e["@type"].as_str())
+            .collect();
+        assert_eq!(types, vec!["Article", "WebSite", "Organization"]);
+    }
+
+    #[test]
+    fn test_extract_metadata_empty_page() {
+        let metadata = extract_metadata("<html><head><title>x</title></head></html>", "not a url");
+        assert!(metadata.is_empty());
+        assert_eq!(
+            serde_json::to_value(&metadata).unwrap(),
+            json!({}),
+            "empty fields are omitted"
+        );
+
+        // A relative canonical with an unparseable page URL is kept as written.
+        let metadata = extract_metadata(r#"<link rel="canonical" href="/a">"#, "not a url");
+        assert_eq!(metadata.canonical_url.as_deref(), Some("/a"));
+    }
+
+    #[test]
+    fn test_format_meta_text() {
+        let output = MetaOutput {
+            url: "https://example.com/posts/1".to_string(),
+            title: Some("Post".to_string()),
+            metadata: extract_metadata(PAGE, "https://example.com/posts/1"),
+        };
+        let text = format_meta_text(&output);
+        assert!(text.starts_with("url: https://example.com/posts/1\ntitle: Post\n"));
+        assert!(text.contains("\ncanonical: https://example.com/posts/1\n"));
+        assert!(text.contains("\nog:image: https://example.com/a.png, https://example.com/b.png\n"));
+        assert!(text.contains("\ntwitter:card: summary\n"));
+        assert!(text.ends_with("json-ld: Article (Post)\njson-ld: WebSite (Example)\njson-ld: Organization (Example Inc)"));
+    }
+}
@@ -0,0 +1,1093 @@
+//! Markdown normalization
+//!
+//! Pure, line-based rewrites for cleaning up generated Markdown before it is
+//! published: paragraph reflow, heading level repair, list marker
+//! normalization, and link style conversion. Fenced and indented code,
+//! tables, block quotes, HTML blocks, and front matter are copied through
+//! unchanged.
+
+use std::collections::{BTreeMap, BTreeSet};
+
+/// How links are written in the output.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum LinkStyle {
+    /// Leave links and reference definitions as written
+    Keep,
+    /// Resolve reference links to `[text](url)` and drop the definitions they used
+    #[default]
+    Inline,
+    /// Turn inline links into `[text][n]` with definitions at the end
+    Reference,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NormalizeOptions {
+    /// Reflow paragraphs and list items to this width; 0 puts each on one
+    /// line. `None` keeps the original line breaks.
+    pub width: Option<usize>,
+    /// Marker for unordered list items (`-`, `*`, or `+`)
+    pub bullet: char,
+    pub links: LinkStyle,
+}
+
+impl Default for NormalizeOptions {
+    fn default() -> Self {
+        Self {
+            width: Some(0),
+            bullet: '-',
+            links: LinkStyle::Inline,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Marker {
+    Bullet,
+    Ordered(u64),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Block {
+    Blank,
+    Heading {
+        level: usize,
+        text: String,
+    },
+    /// Lines keep trailing whitespace so hard breaks can be detected.
+    Paragraph {
+        indent: usize,
+        lines: Vec<String>,
+    },
+    Item {
+        indent: usize,
+        marker: Marker,
+        lines: Vec<String>,
+    },
+    Definition {
+        label: String,
+        destination: String,
+    },
+    Verbatim(Vec<String>),
+}
+
+fn indent_width(line: &str) -> usize {
+    line.chars()
+        .take_while(|c| c.is_whitespace())
+        .map(|c| if c == '\t' { 4 } else { 1 })
+        .sum()
+}
+
+/// Fence character and length when `trimmed` opens a fenced code block.
+fn fence_marker(trimmed: &str) -> Option<(char, usize)> {
+    let c = trimmed.chars().next().filter(|c| *c == '`' || *c == '~')?;
+    let len = trimmed.chars().take_while(|x| *x == c).count();
+    (len >= 3 && !(c == '`' && trimmed[len..].contains('`'))).then_some((c, len))
+}
+
+fn is_fence_close(trimmed: &str, (c, len): (char, usize)) -> bool {
+    let run = trimmed.chars().take_while(|x| *x == c).count();
+    run >= len && trimmed[run..].trim().is_empty()
+}
+
+fn parse_atx(trimmed: &str) -> Option<(usize, String)> {
+    let level = trimmed.chars().take_while(|c| *c == '#').count();
+    let rest = &trimmed[level..];
+    if !(1..=6).contains(&level) || !(rest.is_empty() || rest.starts_with([' ', '\t'])) {
+        return None;
+    }
+    let mut text = rest.trim();
+    // Optional closing sequence: `## Title ##`
+    let without_hashes = text.trim_end_matches('#');
+    if without_hashes.is_empty() || without_hashes.ends_with([' ', '\t']) {
+        text = without_hashes.trim_end();
+    }
+    Some((level, text.to_string()))
+}
+
+fn is_setext_underline(trimmed: &str) -> Option<usize> {
+    let line = trimmed.trim_end();
+    if !line.is_empty() && line.chars().all(|c| c == '=') {
+        Some(1)
+    } else if !line.is_empty() && line.chars().all(|c| c == '-') {
+        Some(2)
+    } else {
+        None
+    }
+}
+
+fn is_thematic_break(trimmed: &str) -> bool {
+    let chars: Vec<char> = trimmed.chars().filter(|c| !c.is_whitespace()).collect();
+    chars.len() >= 3 && matches!(chars[0], '-' | '*' | '_') && chars.iter().all(|c| *c == chars[0])
+}
+
+/// Marker, marker width including the following spaces, and content.
+fn parse_item(trimmed: &str) -> Option<(Marker, usize, &str)> {
+    let (marker, len) = if trimmed.starts_with(['-', '*', '+']) {
+        (Marker::Bullet, 1)
+    } else {
+        let digits = trimmed.chars().take_while(char::is_ascii_digit).count();
+        if !(1..=9).contains(&digits) || !trimmed[digits..].starts_with(['.', ')']) {
+            return None;
+        }
+        (Marker::Ordered(trimmed[..digits].parse().ok()?), digits + 1)
+    };
+    let rest = &trimmed[len..];
+    if rest.is_empty() {
+        return Some((marker, len + 1, ""));
+    }
+    if !rest.starts_with([' ', '\t']) {
+        return None;
+    }
+    let spaces = rest.len() - rest.trim_start().len();
+    // Five or more spaces start indented code inside the item; count one.
+    let width = if spaces > 4 { len + 1 } else { len + spaces };
+    Some((marker, width, rest.trim_start()))
+}
+
+fn is_table_delimiter(line: &str) -> bool {
+    let line = line.trim();
+    line.contains('-')
+        && line.contains(['|', ':'])
+        && line
+            .chars()
+            .all(|c| matches!(c, '|' | '-' | ':' | ' ' | '\t'))
+}
+
+fn is_html_start(trimmed: &str) -> bool {
+    let mut chars = trimmed.chars();
+    if chars.next() != Some('<') {
+        return false;
+    }
+    match chars.next() {
+        Some('/' | '!' | '?') => true,
+        Some(c) if c.is_ascii_alphabetic() => {
+            // `<https://...>` is an autolink, not HTML.
+            let name: String = trimmed[1..]
+                .chars()
+                .take_while(|c| c.is_ascii_alphanumeric() || matches!(c, '+' | '.' | '-'))
+                .collect();
+            !trimmed[1 + name.len()..].starts_with(':') && !trimmed.contains('@')
+        }
+        _ => false,
+    }
+}
+
+fn parse_definition(trimmed: &str) -> Option<(String, String)> {
+    let rest = trimmed.strip_prefix('[')?;
+    let end = rest.find(']')?;
+    let label = &rest[..end];
+    let destination = rest[end + 1..].strip_prefix(':')?.trim();
+    if label.trim().is_empty() || label.starts_with('^') || destination.is_empty() {
+        return None;
+    }
+    Some((label.to_string(), destination.to_string()))
+}
+
+fn parse_blocks(text: &str) -> Vec<Block> {
+    let lines: Vec<&str> = text.lines().collect();
+    let mut blocks = Vec::new();
+    // Content column of the most recent list item, while inside a list.
+    let mut list_column: Option<usize> = None;
+    let mut i = 0;
+
+    if lines.first().map(|l| l.trim_end()) == Some("---") {
+        if let Some(end) = lines[1..]
+            .iter()
+            .position(|l| matches!(l.trim_end(), "---" | "..."))
+        {
+            blocks.push(Block::Verbatim(
+                lines[..end + 2].iter().map(|l| l.to_string()).collect(),
+            ));
+            i = end + 2;
+        }
+    }
+
+    // Collect lines from `start` up to (not including) the next blank line.
+    let until_blank = |start: usize| {
+        let end = lines[start..]
+            .iter()
+            .position(|l| l.trim().is_empty())
+            .map_or(lines.len(), |p| start + p);
+        (
+            lines[start..end].iter().map(|l| l.to_string()).collect(),
+            end,
+        )
+    };
+
+    while i < lines.len() {
+        let line = lines[i];
+        let trimmed = line.trim_start();
+        let indent = indent_width(line);
+        let open = matches!(
+            blocks.last(),
+            Some(Block::Paragraph { .. } | Block::Item { .. })
+        );
+
+        if trimmed.is_empty() {
+            blocks.push(Block::Blank);
+            i += 1;
+            continue;
+        }
+
+        if indent < 2 && parse_item(trimmed).is_none() {
+            list_column = None;
+        }
+
+        if let Some(fence) = fence_marker(trimmed).filter(|_| indent < 4 || list_column.is_some()) {
+            let end = lines[i + 1..]
+                .iter()
+                .position(|l| is_fence_close(l.trim_start(), fence))
+                .map_or(lines.len() - 1, |p| i + 1 + p);
+            blocks.push(Block::Verbatim(
+                lines[i..=end].iter().map(|l| l.to_string()).collect(),
+            ));
+            i = end + 1;
+            continue;
+        }
+
+        if indent >= 4 && !open && list_column.is_none() {
+            let mut end = i;
+            while end < lines.len()
+                && (lines[end].trim().is_empty() || indent_width(lines[end]) >= 4)
+            {
+                end += 1;
+            }
+            while lines[end - 1].trim().is_empty() {
+                end -= 1;
+            }
+            blocks.push(Block::Verbatim(
+                lines[i..end].iter().map(|l| l.to_string()).collect(),
+            ));
+            i = end;
+            continue;
+        }
+
+        if indent < 4 {
+            if let Some((level, text)) = parse_atx(trimmed) {
+                blocks.push(Block::Heading { level, text });
+                i += 1;
+                continue;
+            }
+
+            if let (Some(level), Some(Block::Paragraph { indent: 0, lines })) =
+                (is_setext_underline(trimmed), blocks.last())
+            {
+                let text = lines.iter().map(|l| l.trim()).collect::<Vec<_>>().join(" ");
+                blocks.pop();
+                blocks.push(Block::Heading { level, text });
+                i += 1;
+                continue;
+            }
+
+            if is_thematic_break(trimmed) {
+                blocks.push(Block::Verbatim(vec![line.trim().to_string()]));
+                i += 1;
+                continue;
+            }
+        }
+
+        if let Some((marker, width, content)) =
+            parse_item(trimmed).filter(|_| indent < 4 || list_column.is_some())
+        {
+            // Only a bullet or `1.` with content may interrupt a paragraph.
+            let interrupts = !matches!(blocks.last(), Some(Block::Paragraph { .. }))
+                || (!content.is_empty() && matches!(marker, Marker::Bullet | Marker::Ordered(1)));
+            if !open || interrupts {
+                blocks.push(Block::Item {
+                    indent,
+                    marker,
+                    lines: vec![content.to_string()],
+                });
+                list_column = Some(indent + width);
+                i += 1;
+                continue;
+            }
+        }
+
+        if indent < 4 && trimmed.starts_with('>') {
+            let (quote, end) = until_blank(i);
+            blocks.push(Block::Verbatim(quote));
+            i = end;
+            continue;
+        }
+
+        // Reflowing would fold a pipe row into the paragraph above it, so
+        // runs of such rows are always kept as written.
+        if indent < 4 && trimmed.starts_with('|') {
+            let end = lines[i..]
+                .iter()
+                .position(|l| !l.trim_start().starts_with('|'))
+                .map_or(lines.len(), |p| i + p);
+            blocks.push(Block::Verbatim(
+                lines[i..end].iter().map(|l| l.to_string()).collect(),
+            ));
+            i = end;
+            continue;
+        }
+
+        if !open && indent < 4 {
+            let table =
+                trimmed.contains('|') && lines.get(i + 1).is_some_and(|l| is_table_delimiter(l));
+            if table || is_html_start(trimmed) {
+                let (verbatim, end) = until_blank(i);
+                blocks.push(Block::Verbatim(verbatim));
+                i = end;
+                continue;
+            }
+            if let Some((label, destination)) = parse_definition(trimmed) {
+                blocks.push(Block::Definition { label, destination });
+                i += 1;
+                continue;
+            }
+        }
+
+        match blocks.last_mut() {
+            Some(Block::Paragraph { lines, .. } | Block::Item { lines, .. }) => {
+                lines.push(trimmed.to_string());
+            }
+            _ => blocks.push(Block::Paragraph {
+                indent: if list_column.is_some() { indent } else { 0 },
+                lines: vec![trimmed.to_string()],
+            }),
+        }
+        i += 1;
+    }
+
+    blocks
+}
+
+fn label_key(label: &str) -> String {
+    label
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+        .to_lowercase()
+}
+
+/// Link rewriting state shared across the document.
+struct Links {
+    style: LinkStyle,
+    /// Definitions in document order
+    definitions: Vec<(String, String)>,
+    by_key: BTreeMap<String, String>,
+    used: BTreeSet<String>,
+    /// Definitions created for inline links in reference mode
+    added: Vec<(String, String)>,
+}
+
+impl Links {
+    fn new(style: LinkStyle, definitions: Vec<(String, String)>) -> Self {
+        let mut by_key = BTreeMap::new();
+        for (label, destination) in &definitions {
+            // The first definition of a label wins.
+            by_key
+                .entry(label_key(label))
+                .or_insert_with(|| destination.clone());
+        }
+        Self {
+            style,
+            definitions,
+            by_key,
+            used: BTreeSet::new(),
+            added: Vec::new(),
+        }
+    }
+
+    fn resolve(&mut self, label: &str) -> Option<String> {
+        let key = label_key(label);
+        let destination = self.by_key.get(&key)?.clone();
+        self.used.insert(key);
+        Some(destination)
+    }
+
+    /// Label for a destination in reference mode, reusing an existing one.
+    fn label_for(&mut self, destination: &str) -> String {
+        if let Some((label, _)) = self
+            .definitions
+            .iter()
+            .chain(&self.added)
+            .find(|(_, d)| d == destination)
+        {
+            return label.clone();
+        }
+        let label = (1..)
+            .map(|n| n.to_string())
+            .find(|n| !self.by_key.contains_key(n))
+            .expect("unbounded range");
+        self.by_key.insert(label.clone(), destination.to_string());
+        self.added.push((label.clone(), destination.to_string()));
+        label
+    }
+
+    /// Definitions written at the end of the document.
+    fn trailing(&self) -> Vec<(String, String)> {
+        match self.style {
+            LinkStyle::Keep => Vec::new(),
+            LinkStyle::Inline => self
+                .definitions
+                .iter()
+                .filter(|(label, _)| !self.used.contains(&label_key(label)))
+                .cloned()
+                .collect(),
+            LinkStyle::Reference => self
+                .definitions
+                .iter()
+                .chain(&self.added)
+                .cloned()
+                .collect(),
+        }
+    }
+
+    fn rewrite(&mut self, text: &str) -> String {
+        if self.style == LinkStyle::Keep {
+            return text.to_string();
+        }
+        let chars: Vec<char> = text.chars().collect();
+        let slice = |from: usize, to: usize| chars[from..to].iter().collect::<String>();
+        let mut out = String::with_capacity(text.len());
+        let mut i = 0;
+
+        while i < chars.len() {
+            match chars[i] {
+                '\\' => {
+                    out.extend(&chars[i..(i + 2).min(chars.len())]);
+                    i += 2;
+                }
+                '`' => {
+                    let run = chars[i..].iter().take_while(|c| **c == '`').count();
+                    let close = (i + run..chars.len()).find(|&j| {
+                        chars[j..].iter().take_while(|c| **c == '`').count() == run
+                            && (j == 0 || chars[j - 1] != '`')
+                    });
+                    let end = close.map_or(i + run, |j| j + run);
+                    out.push_str(&slice(i, end));
+                    i = end;
+                }
+                '[' => {
+                    let Some(close) = matching(&chars, i, '[', ']') else {
+                        out.push('[');
+                        i += 1;
+                        continue;
+                    };
+                    let inner = slice(i + 1, close);
+                    if inner.starts_with('^') {
+                        out.push_str(&slice(i, close + 1));
+                        i = close + 1;
+                        continue;
+                    }
+                    let text = self.rewrite(&inner);
+                    let after = close + 1;
+                    match chars.get(after) {
+                        Some('(') => {
+                            if let Some(end) = matching(&chars, after, '(', ')') {
+                                let destination = slice(after + 1, end).trim().to_string();
+                                if self.style == LinkStyle::Reference && !destination.is_empty() {
+                                    let label = self.label_for(&destination);
+                                    out.push_str(&format!("[{}][{}]", text, label));
+                                } else {
+                                    out.push_str(&format!("[{}]({})", text, destination));
+                                }
+                                i = end + 1;
+                                continue;
+                            }
+                        }
+                        Some('[') => {
+                            if let Some(end) = matching(&chars, after, '[', ']') {
+                                let label = slice(after + 1, end);
+                                let label = if label.trim().is_empty() {
+                                    &inner
+                                } else {
+                                    &label
+                                };
+                                match self
+                                    .resolve(label)
+                                    .filter(|_| self.style == LinkStyle::Inline)
+                                {
+                                    Some(destination) => {
+                                        out.push_str(&format!("[{}]({})", text, destination))
+                                    }
+                                    None => out.push_str(&format!(
+                                        "[{}]{}",
+                                        text,
+                                        slice(after, end + 1)
+                                    )),
+                                }
+                                i = end + 1;
+                                continue;
+                            }
+                        }
+                        _ => {
+                            if self.style == LinkStyle::Inline {
+                                if let Some(destination) = self.resolve(&inner) {
+                                    out.push_str(&format!("[{}]({})", text, destination));
+                                    i = after;
+                                    continue;
+                                }
+                            }
+                        }
+                    }
+                    out.push_str(&format!("[{}]", text));
+                    i = after;
+                }
+                c => {
+                    out.push(c);
+                    i += 1;
+                }
+            }
+        }
+        out
+    }
+}
+
+/// Index of the bracket closing the one at `open`, skipping escapes and
+/// nested pairs.
+fn matching(chars: &[char], open: usize, left: char, right: char) -> Option<usize> {
+    let mut depth = 0;
+    let mut i = open;
+    while i < chars.len() {
+        match chars[i] {
+            '\\' => i += 1,
+            c if c == left => depth += 1,
+            c if c == right => {
+                depth -= 1;
+                if depth == 0 {
+                    return Some(i);
+                }
+            }
+            _ => {}
+        }
+        i += 1;
+    }
+    None
+}
+
+/// Words that would start a new block if a wrap put them first on a line.
+fn starts_block(word: &str) -> bool {
+    let digits = word.chars().take_while(char::is_ascii_digit).count();
+    word.starts_with(['#', '>', '|'])
+        || matches!(word, "-" | "*" | "+")
+        || word.chars().all(|c| c == '=' || c == '-')
+        || word.starts_with("```")
+        || word.starts_with("~~~")
+        || (digits > 0 && matches!(&word[digits..], "." | ")"))
+}
+
+fn ends_with_hard_break(line: &str) -> bool {
+    let trailing_backslashes = line.chars().rev().take_while(|c| *c == '\\').count();
+    line.ends_with("  ") || trailing_backslashes % 2 == 1
+}
+
+/// Reflow text lines. Hard breaks are kept and written as a trailing `\`.
+fn reflow(lines: &[String], width: Option<usize>) -> Vec<String> {
+    let mut segments: Vec<Vec<&str>> = vec![Vec::new()];
+    for (index, line) in lines.iter().enumerate() {
+        let last = index + 1 == lines.len();
+        let hard = !last && ends_with_hard_break(line);
+        let text = line.trim_end();
+        let text = if hard && !line.ends_with(' ') {
+            &text[..text.len() - 1]
+        } else {
+            text
+        };
+        segments
+            .last_mut()
+            .expect("non-empty")
+            .push(text.trim_end());
+        if hard {
+            segments.push(Vec::new());
+        }
+    }
+
+    let mut out = Vec::new();
+    let count = segments.len();
+    for (index, segment) in segments.into_iter().enumerate() {
+        let mut wrapped = match width {
+            None => segment.iter().map(|l| l.to_string()).collect(),
+            Some(width) => wrap_words(&segment, width),
+        };
+        if index + 1 < count {
+            if let Some(last) = wrapped.last_mut() {
+                last.push('\\');
+            }
+        }
+        out.extend(wrapped);
+    }
+    out
+}
+
+fn wrap_words(lines: &[&str], width: usize) -> Vec<String> {
+    let mut out: Vec<String> = Vec::new();
+    let mut current = String::new();
+    for word in lines.iter().flat_map(|l| l.split_whitespace()) {
+        let fits = width == 0 || current.chars().count() + 1 + word.chars().count() <= width;
+        if current.is_empty() {
+            current.push_str(word);
+        } else if fits || starts_block(word) {
+            current.push(' ');
+            current.push_str(word);
+        } else {
+            out.push(std::mem::take(&mut current));
+            current.push_str(word);
+        }
+    }
+    if !current.is_empty() || out.is_empty() {
+        out.push(current);
+    }
+    out
+}
+
+fn rewrite_lines(links: &mut Links, lines: &[String]) -> Vec<String> {
+    links
+        .rewrite(&lines.join("\n"))
+        .split('\n')
+        .map(str::to_string)
+        .collect()
+}
+
+/// Normalize a Markdown document.
+///
+/// Headings become ATX (`#`) headings whose levels never skip one (an `###`
+/// under an `#` becomes `##`, and its children shift with it). Bullets use
+/// `options.bullet`, ordered items are renumbered from their list's first
+/// number with a `.` delimiter, and runs of blank lines collapse to one.
+/// The result ends with a single newline and is stable under a second pass.
+pub fn normalize_markdown(text: &str, options: &NormalizeOptions) -> String {
+    let blocks = parse_blocks(text);
+    let definitions = blocks
+        .iter()
+        .filter_map(|b| match b {
+            Block::Definition { label, destination } => Some((label.clone(), destination.clone())),
+            _ => None,
+        })
+        .collect();
+    let mut links = Links::new(options.links, definitions);
+
+    let mut out: Vec<String> = Vec::new();
+    let mut blank = false;
+    let push = |out: &mut Vec<String>, blank: &mut bool, lines: Vec<String>| {
+        if *blank && !out.is_empty() {
+            out.push(String::new());
+        }
+        *blank = false;
+        out.extend(lines);
+    };
+
+    // (input level, output level) of the enclosing headings
+    let mut headings: Vec<(usize, usize)> = Vec::new();
+    // (indent, next number) of the enclosing ordered lists
+    let mut counters: Vec<(usize, u64)> = Vec::new();
+
+    for block in &blocks {
+        match block {
+            Block::Blank => blank = true,
+            Block::Heading { level, text } => {
+                while headings.last().is_some_and(|(l, _)| l >= level) {
+                    headings.pop();
+                }
+                let output = headings.last().map_or(*level, |(_, o)| (o + 1).min(6));
+                headings.push((*level, output));
+                counters.clear();
+
+                let text = links.rewrite(&text.split_whitespace().collect::<Vec<_>>().join(" "));
+                let line = if text.is_empty() {
+                    "#".repeat(output)
+                } else {
+                    format!("{} {}", "#".repeat(output), text)
+                };
+                blank = true;
+                push(&mut out, &mut blank, vec![line]);
+                blank = true;
+            }
+            Block::Paragraph { indent, lines } => {
+                if *indent < 2 {
+                    counters.clear();
+                }
+                let prefix = " ".repeat(*indent);
+                let width = options.width.map(|w| match w {
+                    0 => 0,
+                    w => w.saturating_sub(*indent).max(1),
+                });
+                let lines = reflow(&rewrite_lines(&mut links, lines), width)
+                    .into_iter()
+                    .map(|l| format!("{}{}", prefix, l))
+                    .collect();
+                push(&mut out, &mut blank, lines);
+            }
+            Block::Item {
+                indent,
+                marker,
+                lines,
+            } => {
+                counters.retain(|(i, _)| i <= indent);
+                let marker = match marker {
+                    Marker::Bullet => {
+                        counters.retain(|(i, _)| i < indent);
+                        options.bullet.to_string()
+                    }
+                    Marker::Ordered(n) => {
+                        let number = match counters.last_mut() {
+                            Some((i, next)) if i == indent => {
+                                *next += 1;
+                                *next - 1
+                            }
+                            _ => {
+                                counters.push((*indent, n + 1));
+                                *n
+                            }
+                        };
+                        format!("{}.", number)
+                    }
+                };
+                let first = format!("{}{} ", " ".repeat(*indent), marker);
+                let continuation = " ".repeat(first.len());
+                let width = options.width.map(|w| match w {
+                    0 => 0,
+                    w => w.saturating_sub(first.len()).max(1),
+                });
+                let lines = reflow(&rewrite_lines(&mut links, lines), width)
+                    .into_iter()
+                    .enumerate()
+                    .map(|(index, l)| {
+                        let prefix = if index == 0 { &first } else { &continuation };
+                        format!("{}{}", prefix, l).trim_end().to_string()
+                    })
+                    .collect();
+                push(&mut out, &mut blank, lines);
+            }
+            Block::Definition { label, destination } => {
+                if options.links == LinkStyle::Keep {
+                    push(
+                        &mut out,
+                        &mut blank,
+                        vec![format!("[{}]: {}", label, destination)],
+                    );
+                }
+            }
+            Block::Verbatim(lines) => {
+                if lines.first().is_some_and(|l| indent_width(l) < 2) {
+                    counters.clear();
+                }
+                push(&mut out, &mut blank, lines.clone());
+            }
+        }
+    }
+
+    let trailing = links.trailing();
+    if !trailing.is_empty() {
+        blank = true;
+        push(
+            &mut out,
+            &mut blank,
+            trailing
+                .into_iter()
+                .map(|(label, destination)| format!("[{}]: {}", label, destination))
+                .collect(),
+        );
+    }
+
+    if out.is_empty() {
+        return String::new();
+    }
+    out.join("\n") + "\n"
+}
+
+/// Apply `rewrite` to every line outside fenced code, keeping the input's
+/// trailing newline.
+fn map_prose_lines(
+    text: &str,
+    mut rewrite: impl FnMut(&[&str], usize) -> (String, usize),
+) -> String {
+    let lines: Vec<&str> = text.lines().collect();
+    let mut out = Vec::with_capacity(lines.len());
+    let mut fence = None;
+    let mut i = 0;
+    while i < lines.len() {
+        let trimmed = lines[i].trim_start();
+        if let Some(marker) = fence {
+            if is_fence_close(trimmed, marker) {
+                fence = None;
+            }
+            out.push(lines[i].to_string());
+            i += 1;
+        } else if let Some(marker) = fence_marker(trimmed) {
+            fence = Some(marker);
+            out.push(lines[i].to_string());
+            i += 1;
+        } else {
+            let (line, consumed) = rewrite(&lines, i);
+            out.push(line);
+            i += consumed;
+        }
+    }
+    let mut result = out.join("\n");
+    if text.ends_with('\n') {
+        result.push('\n');
+    }
+    result
+}
+
+/// Rewrite setext headings (a single line over `===` or `---`) and closed
+/// ATX headings (`## Title ##`) as plain `#` headings, leaving everything
+/// else as written.
+pub fn atx_headings(text: &str) -> String {
+    let heading = |level: usize, title: &str| {
+        let title = title.trim();
+        if title.is_empty() {
+            "#".repeat(level)
+        } else {
+            format!("{} {}", "#".repeat(level), title)
+        }
+    };
+    map_prose_lines(text, |lines, i| {
+        let line = lines[i];
+        let trimmed = line.trim_start();
+        if indent_width(line) < 4 {
+            if let Some((level, title)) = parse_atx(trimmed) {
+                return (heading(level, &title), 1);
+            }
+            // Only a one-line paragraph: a longer one would be a
+            // multi-line heading, which generated Markdown never has.
+            let starts_paragraph = i == 0 || lines[i - 1].trim().is_empty();
+            let underline = lines.get(i + 1).and_then(|l| {
+                (indent_width(l) < 4)
+                    .then(|| is_setext_underline(l.trim_start()))
+                    .flatten()
+            });
+            if let Some(level) = underline {
+                if starts_paragraph
+                    && !trimmed.is_empty()
+                    && !trimmed.starts_with(['>', '|', '<'])
+                    && parse_item(trimmed).is_none()
+                {
+                    return (heading(level, trimmed), 2);
+                }
+            }
+        }
+        (line.to_string(), 1)
+    })
+}
+
+/// Rewrite inline links and images as numbered references (`[text][1]`)
+/// with their definitions at the end, leaving everything else as written.
+/// Links sharing a destination share a number; code is left alone.
+pub fn reference_links(text: &str) -> String {
+    let mut links = Links::new(LinkStyle::Reference, Vec::new());
+    let mut result = map_prose_lines(text, |lines, i| (links.rewrite(lines[i]), 1));
+    let trailing = links.trailing();
+    if !trailing.is_empty() {
+        let definitions = trailing
+            .into_iter()
+            .map(|(label, destination)| format!("[{}]: {}", label, destination))
+            .collect::<Vec<_>>()
+            .join("\n");
+        result = format!("{}\n\n{}\n", result.trim_end(), definitions);
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_atx_headings_and_reference_links() {
+        let input = "Title\n==========\n\nSub\n----------\n\n### Three ###\n\n- item\n---\n\n```\nCode\n===\n```\n";
+        assert_eq!(
+            atx_headings(input),
+            "# Title\n\n## Sub\n\n### Three\n\n- item\n---\n\n```\nCode\n===\n```\n"
+        );
+
+        let input = "See [A](https://a.com), ![pic](x.png), and [again](https://a.com).\n\n```\n[not](a-link)\n```\n";
+        assert_eq!(
+            reference_links(input),
+            "See [A][1], ![pic][2], and [again][1].\n\n```\n[not](a-link)\n```\n\n[1]: https://a.com\n[2]: x.png\n"
+        );
+        assert_eq!(reference_links("No links.\n"), "No links.\n");
+    }
+
+    const INPUT: &str = "\
+---
+title: Notes
+---
+Release   notes
+=============
+
+Some text that was
+| not a table row
+wrapped by a model   at odd
+places.  
+After a hard break.
+
+
+### Details ###
+
+* first item
+  continued here
++ second [docs][Docs]
+    * nested
+
+1) one
+1) two
+7) three
+
+#### Deep
+
+Setup
+-----
+
+```sh
+*  not a list
+#not touched
+```
+
+| a | b |
+|---|---|
+| * | 1 |
+
+> quoted *  text
+> kept
+
+    indented code
+
+See [the guide] and ![logo](/logo.png \"Logo\").
+
+[docs]: https://example.com/docs
+[the guide]: https://example.com/guide
+[unused]: https://example.com/unused
+";
+
+    const GOLDEN: &str = "\
+---
+title: Notes
+---
+
+# Release notes
+
+Some text that was
+| not a table row
+wrapped by a model at odd places.\\
+After a hard break.
+
+## Details
+
+- first item continued here
+- second [docs](https://example.com/docs)
+    - nested
+
+1. one
+2. two
+3. three
+
+### Deep
+
+## Setup
+
+```sh
+*  not a list
+#not touched
+```
+
+| a | b |
+|---|---|
+| * | 1 |
+
+> quoted *  text
+> kept
+
+    indented code
+
+See [the guide](https://example.com/guide) and ![logo](/logo.png \"Logo\").
+
+[unused]: https://example.com/unused
+";
+
+    #[test]
+    fn test_normalize_golden() {
+        let output = normalize_markdown(INPUT, &NormalizeOptions::default());
+        assert_eq!(output, GOLDEN);
+        // A second pass changes nothing.
+        assert_eq!(
+            normalize_markdown(&output, &NormalizeOptions::default()),
+            output
+        );
+    }
+
+    #[test]
+    fn test_normalize_wrap_width() {
+        let options = NormalizeOptions {
+            width: Some(20),
+            ..NormalizeOptions::default()
+        };
+        let input = "A paragraph with several words and 1. a number\n\n* an item long enough to wrap around\n";
+        let expected = "\
+A paragraph with
+several words and 1.
+a number
+
+- an item long
+  enough to wrap
+  around
+";
+        assert_eq!(normalize_markdown(input, &options), expected);
+        assert_eq!(normalize_markdown(expected, &options), expected);
+
+        let keep = NormalizeOptions {
+            width: None,
+            ..NormalizeOptions::default()
+        };
+        assert_eq!(
+            normalize_markdown("line one   \nline two\nline three", &keep),
+            "line one\\\nline two\nline three\n"
+        );
+    }
+
+    #[test]
+    fn test_normalize_heading_levels() {
+        let input = "## A\n#### B\n###### C\n### D\n# E\n### F\n";
+        let output = normalize_markdown(input, &NormalizeOptions::default());
+        assert_eq!(output, "## A\n\n### B\n\n#### C\n\n### D\n\n# E\n\n## F\n");
+    }
+
+    #[test]
+    fn test_normalize_reference_links() {
+        let options = NormalizeOptions {
+            links: LinkStyle::Reference,
+            bullet: '*',
+            ..NormalizeOptions::default()
+        };
+        let input = "\
+- [a](https://a.example) and [b](https://b.example) and [a again](https://a.example)
+- [c][1] and `[code](not a link)` and \\[escaped](x)
+
+[1]: https://c.example
+";
+        let expected = "\
+* [a][2] and [b][3] and [a again][2]
+* [c][1] and `[code](not a link)` and \\[escaped](x)
+
+[1]: https://c.example
+[2]: https://a.example
+[3]: https://b.example
+";
+        assert_eq!(normalize_markdown(input, &options), expected);
+
+        let keep = NormalizeOptions {
+            links: LinkStyle::Keep,
+            ..NormalizeOptions::default()
+        };
+        assert_eq!(
+            normalize_markdown(input, &keep).lines().last(),
+            Some("[1]: https://c.example")
+        );
+        assert!(normalize_markdown(input, &keep).contains("[c][1]"));
+    }
+
+    #[test]
+    fn test_normalize_empty() {
+        assert_eq!(normalize_markdown("", &NormalizeOptions::default()), "");
+        assert_eq!(
+            normalize_markdown("\n\n  \n", &NormalizeOptions::default()),
+            ""
+        );
+    }
+}
@@ -4,7 +4,13 @@
 //! This module provides the functional core for pagination token persistence.
 //! Tokens are stored using MD5 hashes for space efficiency, with an 8-character
 //! hash prefix shown to users.
+//!
+//! It also provides [`IdCursor`], an opaque continuation token for lists of
+//! ids that shift between requests (HN story lists and comment pages).
 
+use base64::Engine;
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
 use std::fs;
 use std::path::Path;
 
@@ -15,6 +21,7 @@ pub enum PaginationError {
     TokenNotFound(String),
     InvalidTokenHash(String),
     HashError(String),
+    InvalidCursor(String),
 }
 
 impl std::fmt::Display for PaginationError {
@@ -28,6 +35,7 @@ impl std::fmt::Display for PaginationError {
                 write!(f, "Invalid token hash format: {}", hash)
             }
             PaginationError::HashError(msg) => write!(f, "Hash error: {}", msg),
+            PaginationError::InvalidCursor(msg) => write!(f, "Invalid cursor: {}", msg),
         }
     }
 }
@@ -110,6 +118,82 @@ pub fn load_token(pagination_dir: &Path, hash_prefix: &str) -> Result<String, Pa
     Err(PaginationError::TokenNotFound(hash_prefix.to_string()))
 }
 
+/// Continuation token for a list of ids whose order shifts between requests
+///
+/// Page numbers go stale when items move up or down a list between two
+/// calls: the same id can show up twice, or never. A cursor instead
+/// remembers every id already returned, and the next page is the first
+/// `limit` ids of the current list that haven't been, so no id repeats and
+/// none is skipped, wherever it moved.
+///
+/// The encoded token grows with every page, so callers keep it with
+/// [`save_token`] and hand out the 8-character hash instead.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct IdCursor {
+    /// What the ids belong to (e.g. `hn:list:top`); a token is only
+    /// accepted for the same scope
+    pub scope: String,
+    /// Ids already returned, in the order they were returned
+    pub seen: Vec<u64>,
+}
+
+impl IdCursor {
+    pub fn new(scope: &str, seen: Vec<u64>) -> Self {
+        Self {
+            scope: scope.to_string(),
+            seen,
+        }
+    }
+
+    /// Opaque URL-safe token for this cursor
+    pub fn encode(&self) -> String {
+        let json = serde_json::to_vec(self).unwrap_or_default();
+        base64::engine::general_purpose::URL_SAFE_NO_PAD.encode(json)
+    }
+
+    /// Parse a token made by [`IdCursor::encode`], checking it belongs to
+    /// `scope`
+    pub fn decode(token: &str, scope: &str) -> Result<Self, PaginationError> {
+        let json = base64::engine::general_purpose::URL_SAFE_NO_PAD
+            .decode(token.trim())
+            .map_err(|_| PaginationError::InvalidCursor("not a cursor token".to_string()))?;
+        let cursor: Self = serde_json::from_slice(&json)
+            .map_err(|_| PaginationError::InvalidCursor("not a cursor token".to_string()))?;
+        if cursor.scope != scope {
+            return Err(PaginationError::InvalidCursor(format!(
+                "token is for {}, not {}",
+                cursor.scope, scope
+            )));
+        }
+        Ok(cursor)
+    }
+
+    /// The next `limit` ids of `ids` that haven't been returned yet, in
+    /// list order
+    pub fn next_page(&self, ids: &[u64], limit: usize) -> Vec<u64> {
+        let seen: HashSet<u64> = self.seen.iter().copied().collect();
+        ids.iter()
+            .filter(|id| !seen.contains(id))
+            .take(limit)
+            .copied()
+            .collect()
+    }
+
+    /// Page number the next page stands for, at `limit` ids per page
+    pub fn page_number(&self, limit: usize) -> usize {
+        self.seen.len() / limit.max(1) + 1
+    }
+
+    /// Token for the page after `page`, or `None` once every id in `ids`
+    /// has been returned
+    pub fn next_token(&self, ids: &[u64], page: &[u64]) -> Option<String> {
+        let mut seen = self.seen.clone();
+        seen.extend(page.iter().filter(|id| !self.seen.contains(id)));
+        let next = Self::new(&self.scope, seen);
+        (!next.next_page(ids, 1).is_empty()).then(|| next.encode())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -184,4 +268,57 @@ mod tests {
         assert_eq!(loaded1, token1);
         assert_eq!(loaded2, token2);
     }
+
+    #[test]
+    fn test_id_cursor_round_trip() {
+        let cursor = IdCursor::new("hn:list:top", vec![3, 1, 2]);
+        let token = cursor.encode();
+        assert!(token
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_'));
+        assert_eq!(IdCursor::decode(&token, "hn:list:top").unwrap(), cursor);
+
+        assert!(matches!(
+            IdCursor::decode(&token, "hn:list:new"),
+            Err(PaginationError::InvalidCursor(_))
+        ));
+        assert!(matches!(
+            IdCursor::decode("not a token!", "hn:list:top"),
+            Err(PaginationError::InvalidCursor(_))
+        ));
+    }
+
+    #[test]
+    fn test_id_cursor_survives_shifting_lists() {
+        let first = IdCursor::new("scope", Vec::new());
+        let ids = [1, 2, 3, 4, 5, 6];
+        let page = first.next_page(&ids, 2);
+        assert_eq!(page, vec![1, 2]);
+        let token = first.next_token(&ids, &page).unwrap();
+
+        // 2 dropped below 3 and a new 7 entered at the top
+        let shifted = [7, 1, 3, 2, 4, 5, 6];
+        let cursor = IdCursor::decode(&token, "scope").unwrap();
+        assert_eq!(cursor.page_number(2), 2);
+        let page = cursor.next_page(&shifted, 2);
+        assert_eq!(page, vec![7, 3]);
+
+        let last = IdCursor::new("scope", vec![7, 1, 3, 2, 4]);
+        assert_eq!(last.next_page(&shifted, 2), vec![5, 6]);
+        assert_eq!(last.next_token(&shifted, &[5, 6]), None);
+    }
+
+    #[test]
+    fn test_id_cursor_stored_by_hash() {
+        let temp_dir = TempDir::new().unwrap();
+        let ids: Vec<u64> = (40_000_000..40_001_000).collect();
+        let cursor = IdCursor::new("hn:item:1", ids[..900].to_vec());
+        let token = cursor.next_token(&ids, &ids[900..930]).unwrap();
+
+        let hash = save_token(temp_dir.path(), &token).unwrap();
+        assert_eq!(hash.len(), 8);
+        let loaded = load_token(temp_dir.path(), &hash).unwrap();
+        let cursor = IdCursor::decode(&loaded, "hn:item:1").unwrap();
+        assert_eq!(cursor.next_page(&ids, 30), ids[930..960].to_vec());
+    }
 }
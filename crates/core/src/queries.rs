@@ -2,10 +2,15 @@
 //!
 //! Pure functions for managing saved JQL queries in the filesystem.
 //! This module provides the functional core for query persistence.
+//!
+//! Saved queries may contain `{name}` placeholders (see [`crate::template`]),
+//! filled in with [`render_query`] when the query is run.
 
 use std::fs;
 use std::path::Path;
 
+use crate::template::{self, TemplateVars};
+
 /// Error type for query operations
 #[derive(Debug)]
 pub enum QueryError {
@@ -13,6 +18,7 @@ pub enum QueryError {
     QueryNotFound(String),
     QueryAlreadyExists(String),
     InvalidQueryName(String),
+    InvalidTemplate(String),
 }
 
 impl std::fmt::Display for QueryError {
@@ -28,6 +34,7 @@ impl std::fmt::Display for QueryError {
                 )
             }
             QueryError::InvalidQueryName(name) => write!(f, "Invalid query name: {}", name),
+            QueryError::InvalidTemplate(msg) => write!(f, "Invalid query template: {}", msg),
         }
     }
 }
@@ -136,6 +143,37 @@ pub fn delete_query(queries_dir: &Path, name: &str) -> Result<(), QueryError> {
     Ok(())
 }
 
+/// List the `{name}` variables a query expects, in order of first use
+///
+/// Queries that are not valid templates (e.g. JQL with literal braces) have
+/// no variables.
+pub fn query_variables(query: &str) -> Vec<String> {
+    template::placeholders(query).unwrap_or_default()
+}
+
+/// Fill in a query's `{name}` placeholders from `vars`
+///
+/// Queries without placeholders are returned unchanged, so saved JQL with
+/// literal braces keeps working as long as no variables are passed.
+///
+/// # Returns
+/// * `Ok(String)` - The query with every placeholder replaced
+/// * `Err(QueryError::InvalidTemplate)` - A placeholder has no value, or
+///   variables were passed to a query that is not a valid template
+pub fn render_query(query: &str, vars: &TemplateVars) -> Result<String, QueryError> {
+    match template::placeholders(query) {
+        Ok(names) if names.is_empty() => Ok(query.to_string()),
+        Ok(_) => template::render(query, vars).map_err(|e| match e {
+            template::TemplateError::UnknownVariable(name) => QueryError::InvalidTemplate(format!(
+                "missing value for {{{name}}}; pass --var {name}=VALUE"
+            )),
+            other => QueryError::InvalidTemplate(other.to_string()),
+        }),
+        Err(_) if vars.is_empty() => Ok(query.to_string()),
+        Err(e) => Err(QueryError::InvalidTemplate(e.to_string())),
+    }
+}
+
 /// Validate query name for security and usability
 ///
 /// Query names must:
@@ -235,6 +273,31 @@ mod tests {
         assert!(validate_query_name("invalid@query").is_err());
     }
 
+    #[test]
+    fn test_render_query() {
+        let query = "project = {project} AND type = Bug AND assignee = {who}";
+        assert_eq!(query_variables(query), vec!["project", "who"]);
+
+        let vars = template::parse_vars(&["project=PROJ", "who=currentUser()"]).unwrap();
+        assert_eq!(
+            render_query(query, &vars).unwrap(),
+            "project = PROJ AND type = Bug AND assignee = currentUser()"
+        );
+
+        let missing = template::parse_vars(&["project=PROJ"]).unwrap();
+        let err = render_query(query, &missing).unwrap_err().to_string();
+        assert!(err.contains("--var who=VALUE"));
+
+        // Queries without placeholders are left alone, braces included
+        let plain = "text ~ \"{code\"";
+        assert_eq!(render_query(plain, &TemplateVars::new()).unwrap(), plain);
+        assert_eq!(
+            render_query("status = Open", &vars).unwrap(),
+            "status = Open"
+        );
+        assert!(render_query(plain, &vars).is_err());
+    }
+
     #[test]
     fn test_list_empty_directory() {
         let temp_dir = TempDir::new().unwrap();
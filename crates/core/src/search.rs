@@ -0,0 +1,886 @@
+//! Unified search across Jira, Confluence, Bitbucket code, HackerNews, and
+//! the local Atlas index
+//!
+//! The shell runs one query per source; this module builds those queries,
+//! turns each source's response into [`SearchHit`]s, and merges the lists
+//! into one ranking. Sources score on incompatible scales (or not at all),
+//! so ranking uses each hit's position within its own source (reciprocal
+//! rank fusion) and boosts hits whose title contains the query terms.
+//! [`RankingConfig`] adds per-source weights and recency decay on top.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::HashMap;
+
+use crate::atlassian::{confluence, jira};
+
+/// Constant in the reciprocal rank fusion term `1 / (K + rank)`. Larger
+/// values flatten the gap between the first and later hits of a source.
+pub const RRF_K: f64 = 60.0;
+
+/// Longest snippet kept per hit, in characters.
+pub const SNIPPET_CHARS: usize = 200;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SearchSource {
+    Jira,
+    Confluence,
+    Bitbucket,
+    Hn,
+    Local,
+}
+
+impl SearchSource {
+    pub const ALL: [SearchSource; 5] = [
+        SearchSource::Jira,
+        SearchSource::Confluence,
+        SearchSource::Bitbucket,
+        SearchSource::Hn,
+        SearchSource::Local,
+    ];
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            SearchSource::Jira => "jira",
+            SearchSource::Confluence => "confluence",
+            SearchSource::Bitbucket => "bitbucket",
+            SearchSource::Hn => "hn",
+            SearchSource::Local => "local",
+        }
+    }
+}
+
+/// One result from one source, in that source's own order.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct SearchHit {
+    pub source: SearchSource,
+    pub title: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub snippet: Option<String>,
+    /// Creation or last-update time (RFC 3339) when the source reports one
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub updated: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct RankedHit {
+    #[serde(flatten)]
+    pub hit: SearchHit,
+    pub score: f64,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SourceStatus {
+    Ok,
+    /// Not configured (missing workspace, no local index)
+    Skipped,
+    Error,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct SourceReport {
+    pub source: SearchSource,
+    pub status: SourceStatus,
+    pub count: usize,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct UnifiedSearchOutput {
+    pub query: String,
+    pub results: Vec<RankedHit>,
+    pub sources: Vec<SourceReport>,
+}
+
+/// A candidate from the local Atlas index: a file matched by path or
+/// description, or a symbol inside it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LocalMatch {
+    pub path: String,
+    pub description: Option<String>,
+    pub symbol: Option<String>,
+    pub line: Option<usize>,
+}
+
+/// Lowercased words of the query, without duplicates.
+pub fn query_terms(query: &str) -> Vec<String> {
+    let mut terms: Vec<String> = Vec::new();
+    for term in query
+        .split(|c: char| !c.is_alphanumeric() && c != '_')
+        .filter(|t| !t.is_empty())
+        .map(str::to_lowercase)
+    {
+        if !terms.contains(&term) {
+            terms.push(term);
+        }
+    }
+    terms
+}
+
+/// Fraction of `terms` that occur in `text` (case-insensitive), 0.0 to 1.0.
+pub fn term_coverage(terms: &[String], text: &str) -> f64 {
+    if terms.is_empty() {
+        return 0.0;
+    }
+    let text = text.to_lowercase();
+    let found = terms.iter().filter(|t| text.contains(t.as_str())).count();
+    found as f64 / terms.len() as f64
+}
+
+/// Tuning for [`rank_hits`], read from the `[search]` table of
+/// `~/.config/mcptools/config.toml`:
+///
+/// ```toml
+/// [search]
+/// title_boost = 1.0
+/// recency_half_life_days = 30
+///
+/// [search.weights]
+/// jira = 2.0
+/// hn = 0.5
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct RankingConfig {
+    /// Multiplier per source; sources not listed weigh 1.0
+    pub weights: HashMap<SearchSource, f64>,
+    /// How much a title containing every query term adds (1.0 doubles the score)
+    pub title_boost: f64,
+    /// Age at which a dated hit's score halves; `None` disables recency decay
+    pub recency_half_life_days: Option<f64>,
+}
+
+impl Default for RankingConfig {
+    fn default() -> Self {
+        Self {
+            weights: HashMap::new(),
+            title_boost: 1.0,
+            recency_half_life_days: None,
+        }
+    }
+}
+
+impl RankingConfig {
+    pub fn weight(&self, source: SearchSource) -> f64 {
+        self.weights.get(&source).copied().unwrap_or(1.0)
+    }
+}
+
+/// Parse the ranking settings from a config file's contents. A missing
+/// file or `[search]` table gives the defaults; other tables are ignored.
+pub fn parse_ranking_config(toml_content: Option<&str>) -> Result<RankingConfig, String> {
+    #[derive(Deserialize, Default)]
+    struct RawFile {
+        #[serde(default)]
+        search: RawSearch,
+    }
+
+    #[derive(Deserialize, Default)]
+    #[serde(deny_unknown_fields)]
+    struct RawSearch {
+        #[serde(default)]
+        weights: HashMap<SearchSource, f64>,
+        title_boost: Option<f64>,
+        recency_half_life_days: Option<f64>,
+    }
+
+    let raw: RawFile = match toml_content {
+        Some(content) => toml::from_str(content).map_err(|e| format!("invalid config: {e}"))?,
+        None => RawFile::default(),
+    };
+    let search = raw.search;
+
+    for (source, weight) in &search.weights {
+        if !weight.is_finite() || *weight < 0.0 {
+            return Err(format!(
+                "search.weights.{} must be a non-negative number, got {}",
+                source.as_str(),
+                weight
+            ));
+        }
+    }
+    let title_boost = search.title_boost.unwrap_or(1.0);
+    if !title_boost.is_finite() || title_boost < 0.0 {
+        return Err(format!(
+            "search.title_boost must be a non-negative number, got {title_boost}"
+        ));
+    }
+    if let Some(days) = search.recency_half_life_days {
+        if !days.is_finite() || days <= 0.0 {
+            return Err(format!(
+                "search.recency_half_life_days must be positive, got {days}"
+            ));
+        }
+    }
+
+    Ok(RankingConfig {
+        weights: search.weights,
+        title_boost,
+        recency_half_life_days: search.recency_half_life_days,
+    })
+}
+
+/// Parse a source timestamp: RFC 3339, or Jira's `+0000` offset form.
+fn parse_timestamp(value: &str) -> Option<DateTime<Utc>> {
+    DateTime::parse_from_rfc3339(value)
+        .or_else(|_| DateTime::parse_from_str(value, "%Y-%m-%dT%H:%M:%S%.f%z"))
+        .ok()
+        .map(|d| d.with_timezone(&Utc))
+}
+
+/// `0.5 ^ (age / half_life)` for a dated hit; 1.0 when decay is off, the
+/// hit has no (parseable) date, or the date is in the future.
+pub fn recency_factor(
+    updated: Option<&str>,
+    now: DateTime<Utc>,
+    half_life_days: Option<f64>,
+) -> f64 {
+    let (Some(half_life), Some(updated)) = (half_life_days, updated.and_then(parse_timestamp))
+    else {
+        return 1.0;
+    };
+    let age_days = (now - updated).num_seconds().max(0) as f64 / 86_400.0;
+    0.5_f64.powf(age_days / half_life)
+}
+
+/// Score of the hit at `rank` (0-based) within its source: the reciprocal
+/// rank fusion term, boosted by the share of query terms in the title,
+/// times the source weight and the recency factor.
+pub fn score_hit(
+    terms: &[String],
+    hit: &SearchHit,
+    rank: usize,
+    config: &RankingConfig,
+    now: DateTime<Utc>,
+) -> f64 {
+    let fusion = 1.0 / (RRF_K + rank as f64 + 1.0);
+    fusion
+        * (1.0 + config.title_boost * term_coverage(terms, &hit.title))
+        * config.weight(hit.source)
+        * recency_factor(hit.updated.as_deref(), now, config.recency_half_life_days)
+}
+
+fn dedup_key(hit: &SearchHit) -> String {
+    match &hit.url {
+        Some(url) => url.trim_end_matches('/').to_lowercase(),
+        None => format!("{}:{}", hit.source.as_str(), hit.title),
+    }
+}
+
+/// Merge per-source result lists (each in its source's order) into one
+/// ranking, best first, keeping at most `limit` hits. Hits with the same
+/// URL keep the higher score. Ties keep source order, then rank. `now` is
+/// the reference time for recency decay.
+pub fn rank_hits(
+    query: &str,
+    batches: Vec<Vec<SearchHit>>,
+    limit: usize,
+    config: &RankingConfig,
+    now: DateTime<Utc>,
+) -> Vec<RankedHit> {
+    let terms = query_terms(query);
+    let mut ranked: Vec<(usize, RankedHit)> = Vec::new();
+    let mut seen: HashMap<String, usize> = HashMap::new();
+
+    for hit in batches
+        .into_iter()
+        .flat_map(|batch| batch.into_iter().enumerate())
+        .map(|(rank, hit)| RankedHit {
+            score: score_hit(&terms, &hit, rank, config, now),
+            hit,
+        })
+    {
+        let key = dedup_key(&hit.hit);
+        match seen.get(&key) {
+            Some(&i) if ranked[i].1.score >= hit.score => {}
+            Some(&i) => ranked[i].1 = hit,
+            None => {
+                seen.insert(key, ranked.len());
+                ranked.push((ranked.len(), hit));
+            }
+        }
+    }
+
+    ranked.sort_by(|(a_order, a), (b_order, b)| {
+        b.score
+            .partial_cmp(&a.score)
+            .unwrap_or(std::cmp::Ordering::Equal)
+            .then(a_order.cmp(b_order))
+    });
+    ranked.into_iter().take(limit).map(|(_, hit)| hit).collect()
+}
+
+/// Collapse whitespace and cut `text` to [`SNIPPET_CHARS`] characters.
+pub fn snippet(text: &str) -> Option<String> {
+    let text = text.split_whitespace().collect::<Vec<_>>().join(" ");
+    if text.is_empty() {
+        return None;
+    }
+    if text.chars().count() <= SNIPPET_CHARS {
+        return Some(text);
+    }
+    let cut: String = text.chars().take(SNIPPET_CHARS).collect();
+    Some(format!("{}…", cut.trim_end()))
+}
+
+fn quote_query(query: &str) -> String {
+    query.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+/// JQL for a free-text search, most recently updated first.
+pub fn jira_text_jql(query: &str) -> String {
+    format!("text ~ \"{}\" ORDER BY updated DESC", quote_query(query))
+}
+
+/// CQL for a free-text search.
+pub fn confluence_text_cql(query: &str) -> String {
+    format!("text ~ \"{}\"", quote_query(query))
+}
+
+pub fn hits_from_jira(output: &jira::SearchOutput, base_url: &str) -> Vec<SearchHit> {
+    let base_url = base_url.trim_end_matches('/');
+    output
+        .issues
+        .iter()
+        .map(|issue| SearchHit {
+            source: SearchSource::Jira,
+            title: format!("{}: {}", issue.key, issue.summary),
+            url: Some(format!("{}/browse/{}", base_url, issue.key)),
+            snippet: issue.description.as_deref().and_then(snippet),
+            updated: issue.updated.clone(),
+        })
+        .collect()
+}
+
+/// Absolute URL for a page's `webui` link. Cloud serves pages under `/wiki`,
+/// which the link omits.
+pub fn confluence_page_url(base_url: &str, webui: &str, cloud: bool) -> String {
+    if webui.starts_with("http://") || webui.starts_with("https://") {
+        return webui.to_string();
+    }
+    let base_url = base_url.trim_end_matches('/');
+    if cloud && !base_url.ends_with("/wiki") && !webui.starts_with("/wiki/") {
+        format!("{}/wiki{}", base_url, webui)
+    } else {
+        format!("{}{}", base_url, webui)
+    }
+}
+
+pub fn hits_from_confluence(
+    output: &confluence::SearchOutput,
+    base_url: &str,
+    cloud: bool,
+) -> Vec<SearchHit> {
+    output
+        .pages
+        .iter()
+        .map(|page| SearchHit {
+            source: SearchSource::Confluence,
+            title: page.title.clone(),
+            url: page
+                .url
+                .as_deref()
+                .map(|webui| confluence_page_url(base_url, webui, cloud)),
+            snippet: page.content.as_deref().and_then(snippet),
+            updated: None,
+        })
+        .collect()
+}
+
+/// Browser URL for a Bitbucket API `src` link
+/// (`https://api.bitbucket.org/2.0/repositories/ws/repo/src/<hash>/path`).
+fn bitbucket_web_url(api_href: &str) -> Option<String> {
+    let mut url = url::Url::parse(api_href).ok()?;
+    let path = url.path().to_string();
+    let rest = path.split_once("/repositories/")?.1.to_string();
+    if let Some(host) = url.host_str().and_then(|h| h.strip_prefix("api.")) {
+        let host = host.to_string();
+        url.set_host(Some(&host)).ok()?;
+    }
+    url.set_path(&rest);
+    Some(url.to_string())
+}
+
+/// Hits from a Bitbucket Cloud code search response
+/// (`GET /workspaces/{workspace}/search/code`).
+pub fn hits_from_bitbucket_code(response: &Value) -> Vec<SearchHit> {
+    let Some(values) = response.get("values").and_then(Value::as_array) else {
+        return Vec::new();
+    };
+    values
+        .iter()
+        .filter_map(|value| {
+            let file = value.get("file")?;
+            let path = file.get("path").and_then(Value::as_str)?;
+            let href = file
+                .pointer("/links/self/href")
+                .and_then(Value::as_str)
+                .unwrap_or_default();
+            let url = bitbucket_web_url(href);
+            let repository = href
+                .split_once("/repositories/")
+                .and_then(|(_, rest)| rest.split_once("/src/"))
+                .map(|(repo, _)| repo.to_string());
+            let line = value
+                .pointer("/content_matches/0/lines")
+                .and_then(Value::as_array)
+                .and_then(|lines| {
+                    lines.iter().find(|l| {
+                        l["segments"]
+                            .as_array()
+                            .is_some_and(|s| s.iter().any(|s| s["match"] == true))
+                    })
+                })
+                .map(|line| {
+                    line["segments"]
+                        .as_array()
+                        .into_iter()
+                        .flatten()
+                        .filter_map(|s| s["text"].as_str())
+                        .collect::<String>()
+                });
+            Some(SearchHit {
+                source: SearchSource::Bitbucket,
+                title: match repository {
+                    Some(repository) => format!("{}: {}", repository, path),
+                    None => path.to_string(),
+                },
+                url,
+                snippet: line.as_deref().and_then(snippet),
+                updated: None,
+            })
+        })
+        .collect()
+}
+
+/// Hits from an HN Algolia search response (`GET /api/v1/search`).
+pub fn hits_from_hn(response: &Value) -> Vec<SearchHit> {
+    let Some(hits) = response.get("hits").and_then(Value::as_array) else {
+        return Vec::new();
+    };
+    hits.iter()
+        .filter_map(|hit| {
+            let id = hit.get("objectID").and_then(Value::as_str)?;
+            let title = hit
+                .get("title")
+                .or_else(|| hit.get("story_title"))
+                .and_then(Value::as_str)
+                .filter(|t| !t.is_empty())?;
+            let points = hit.get("points").and_then(Value::as_u64).unwrap_or(0);
+            let comments = hit.get("num_comments").and_then(Value::as_u64).unwrap_or(0);
+            let mut summary = format!("{} points, {} comments", points, comments);
+            if let Some(link) = hit.get("url").and_then(Value::as_str) {
+                summary.push_str(" · ");
+                summary.push_str(link);
+            }
+            Some(SearchHit {
+                source: SearchSource::Hn,
+                title: title.to_string(),
+                url: Some(format!("https://news.ycombinator.com/item?id={}", id)),
+                snippet: snippet(&summary),
+                updated: hit
+                    .get("created_at")
+                    .and_then(Value::as_str)
+                    .map(str::to_string),
+            })
+        })
+        .collect()
+}
+
+/// Order local index candidates by how many query terms their path,
+/// symbol, and description contain, keeping at most `limit`.
+pub fn hits_from_local(query: &str, matches: Vec<LocalMatch>, limit: usize) -> Vec<SearchHit> {
+    let terms = query_terms(query);
+    let mut scored: Vec<(f64, LocalMatch)> = matches
+        .into_iter()
+        .map(|m| {
+            let text = format!(
+                "{} {} {}",
+                m.path,
+                m.symbol.as_deref().unwrap_or_default(),
+                m.description.as_deref().unwrap_or_default()
+            );
+            (term_coverage(&terms, &text), m)
+        })
+        .filter(|(coverage, _)| *coverage > 0.0)
+        .collect();
+    scored.sort_by(|a, b| b.0.partial_cmp(&a.0).unwrap_or(std::cmp::Ordering::Equal));
+    scored
+        .into_iter()
+        .take(limit)
+        .map(|(_, m)| {
+            let location = match m.line {
+                Some(line) => format!("{}:{}", m.path, line),
+                None => m.path.clone(),
+            };
+            SearchHit {
+                source: SearchSource::Local,
+                title: match &m.symbol {
+                    Some(symbol) => format!("{} ({})", symbol, location),
+                    None => location,
+                },
+                url: None,
+                snippet: m.description.as_deref().and_then(snippet),
+                updated: None,
+            }
+        })
+        .collect()
+}
+
+/// Numbered list of results followed by a per-source status line.
+pub fn format_unified_text(output: &UnifiedSearchOutput) -> String {
+    let mut text = String::new();
+    if output.results.is_empty() {
+        text.push_str(&format!("No results for \"{}\".\n", output.query));
+    }
+    for (i, result) in output.results.iter().enumerate() {
+        let hit = &result.hit;
+        text.push_str(&format!(
+            "{}. [{}] {}\n",
+            i + 1,
+            hit.source.as_str(),
+            hit.title
+        ));
+        if let Some(url) = &hit.url {
+            text.push_str(&format!("   {}\n", url));
+        }
+        if let Some(snippet) = &hit.snippet {
+            text.push_str(&format!("   {}\n", snippet));
+        }
+    }
+    let sources: Vec<String> = output
+        .sources
+        .iter()
+        .map(|report| match report.status {
+            SourceStatus::Ok => format!("{} {}", report.source.as_str(), report.count),
+            SourceStatus::Skipped => format!("{} skipped", report.source.as_str()),
+            SourceStatus::Error => format!("{} failed", report.source.as_str()),
+        })
+        .collect();
+    text.push_str(&format!("\nSources: {}\n", sources.join(", ")));
+    for report in &output.sources {
+        if let Some(message) = &report.message {
+            text.push_str(&format!("  {}: {}\n", report.source.as_str(), message));
+        }
+    }
+    text
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn hit(source: SearchSource, title: &str, url: Option<&str>) -> SearchHit {
+        SearchHit {
+            source,
+            title: title.to_string(),
+            url: url.map(str::to_string),
+            snippet: None,
+            updated: None,
+        }
+    }
+
+    #[test]
+    fn test_rank_hits_fuses_sources_and_boosts_title_matches() {
+        let jira = vec![
+            hit(
+                SearchSource::Jira,
+                "PROJ-1: Unrelated",
+                Some("https://j/browse/PROJ-1"),
+            ),
+            hit(
+                SearchSource::Jira,
+                "PROJ-2: Login timeout",
+                Some("https://j/browse/PROJ-2"),
+            ),
+        ];
+        let hn = vec![
+            hit(
+                SearchSource::Hn,
+                "Login timeouts explained",
+                Some("https://hn/1"),
+            ),
+            // Same page as the second Jira hit, lower score: dropped
+            hit(SearchSource::Hn, "dup", Some("https://j/browse/PROJ-2/")),
+        ];
+        let ranked = rank_hits("login timeout", vec![jira, hn], 10, &defaults(), now());
+        let titles: Vec<&str> = ranked.iter().map(|r| r.hit.title.as_str()).collect();
+        assert_eq!(
+            titles,
+            vec![
+                "Login timeouts explained",
+                "PROJ-2: Login timeout",
+                "PROJ-1: Unrelated"
+            ]
+        );
+        assert!((ranked[0].score - 2.0 / 61.0).abs() < 1e-12);
+        assert_eq!(
+            rank_hits("login", vec![vec![], vec![]], 10, &defaults(), now()),
+            vec![]
+        );
+        assert_eq!(
+            rank_hits(
+                "x",
+                vec![vec![hit(SearchSource::Local, "a", None)]; 3],
+                2,
+                &defaults(),
+                now()
+            )
+            .len(),
+            1
+        );
+    }
+
+    fn defaults() -> RankingConfig {
+        RankingConfig::default()
+    }
+
+    fn now() -> DateTime<Utc> {
+        DateTime::parse_from_rfc3339("2024-03-01T00:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc)
+    }
+
+    #[test]
+    fn test_parse_ranking_config() {
+        assert_eq!(parse_ranking_config(None).unwrap(), defaults());
+        assert_eq!(
+            parse_ranking_config(Some("[other]\nkey = 1\n")).unwrap(),
+            defaults()
+        );
+
+        let config = parse_ranking_config(Some(
+            "[search]\ntitle_boost = 0.5\nrecency_half_life_days = 14\n\n[search.weights]\njira = 2.0\nhn = 0\n",
+        ))
+        .unwrap();
+        assert_eq!(config.weight(SearchSource::Jira), 2.0);
+        assert_eq!(config.weight(SearchSource::Hn), 0.0);
+        assert_eq!(config.weight(SearchSource::Local), 1.0);
+        assert_eq!(config.title_boost, 0.5);
+        assert_eq!(config.recency_half_life_days, Some(14.0));
+
+        for bad in [
+            "[search.weights]\njira = -1\n",
+            "[search.weights]\ngithub = 1\n",
+            "[search]\nrecency_half_life_days = 0\n",
+            "[search]\ntitle_bost = 1\n",
+            "[search",
+        ] {
+            assert!(parse_ranking_config(Some(bad)).is_err(), "{bad}");
+        }
+    }
+
+    #[test]
+    fn test_recency_factor() {
+        let half_life = Some(10.0);
+        assert_eq!(
+            recency_factor(Some("2024-02-20T00:00:00Z"), now(), half_life),
+            0.5
+        );
+        // Jira's offset format, 20 days old
+        assert_eq!(
+            recency_factor(Some("2024-02-10T00:00:00.000+0000"), now(), half_life),
+            0.25
+        );
+        assert_eq!(
+            recency_factor(Some("2024-03-05T00:00:00Z"), now(), half_life),
+            1.0
+        );
+        assert_eq!(recency_factor(None, now(), half_life), 1.0);
+        assert_eq!(recency_factor(Some("yesterday"), now(), half_life), 1.0);
+        assert_eq!(
+            recency_factor(Some("2020-01-01T00:00:00Z"), now(), None),
+            1.0
+        );
+    }
+
+    #[test]
+    fn test_rank_hits_applies_weights_and_recency() {
+        let dated = |source, title: &str, url: &str, updated: &str| SearchHit {
+            updated: Some(updated.to_string()),
+            ..hit(source, title, Some(url))
+        };
+        let batches = vec![
+            vec![dated(
+                SearchSource::Hn,
+                "old",
+                "https://hn/1",
+                "2023-03-01T00:00:00Z",
+            )],
+            vec![dated(
+                SearchSource::Jira,
+                "new",
+                "https://j/1",
+                "2024-02-29T00:00:00Z",
+            )],
+            vec![hit(SearchSource::Local, "undated", None)],
+        ];
+        let order = |config: &RankingConfig| -> Vec<String> {
+            rank_hits("q", batches.clone(), 10, config, now())
+                .into_iter()
+                .map(|r| r.hit.title)
+                .collect()
+        };
+        // Equal first-rank scores: input order
+        assert_eq!(order(&defaults()), vec!["old", "new", "undated"]);
+
+        let decayed = RankingConfig {
+            recency_half_life_days: Some(30.0),
+            ..defaults()
+        };
+        assert_eq!(order(&decayed), vec!["undated", "new", "old"]);
+
+        let weighted = RankingConfig {
+            weights: HashMap::from([(SearchSource::Hn, 3.0), (SearchSource::Local, 0.5)]),
+            ..defaults()
+        };
+        assert_eq!(order(&weighted), vec!["old", "new", "undated"]);
+        let ranked = rank_hits("q", batches.clone(), 10, &weighted, now());
+        assert!((ranked[0].score - 3.0 / 61.0).abs() < 1e-12);
+        assert!((ranked[2].score - 0.5 / 61.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_query_builders_escape_quotes() {
+        assert_eq!(
+            jira_text_jql(r#"say "hi" \o/"#),
+            r#"text ~ "say \"hi\" \\o/" ORDER BY updated DESC"#
+        );
+        assert_eq!(confluence_text_cql("deploy"), r#"text ~ "deploy""#);
+        assert_eq!(
+            query_terms("Login, login TIMEOUT"),
+            vec!["login", "timeout"]
+        );
+    }
+
+    #[test]
+    fn test_confluence_page_url() {
+        let webui = "/spaces/ENG/pages/1/Runbook";
+        assert_eq!(
+            confluence_page_url("https://acme.atlassian.net", webui, true),
+            "https://acme.atlassian.net/wiki/spaces/ENG/pages/1/Runbook"
+        );
+        assert_eq!(
+            confluence_page_url("https://wiki.corp/", webui, false),
+            "https://wiki.corp/spaces/ENG/pages/1/Runbook"
+        );
+    }
+
+    #[test]
+    fn test_hits_from_bitbucket_and_hn() {
+        let code = json!({"values": [{
+            "file": {
+                "path": "src/auth.rs",
+                "links": {"self": {"href": "https://api.bitbucket.org/2.0/repositories/acme/api/src/abc123/src/auth.rs"}}
+            },
+            "content_matches": [{"lines": [
+                {"line": 9, "segments": [{"text": "// header"}]},
+                {"line": 10, "segments": [{"text": "fn "}, {"text": "login", "match": true}, {"text": "() {}"}]}
+            ]}]
+        }]});
+        assert_eq!(
+            hits_from_bitbucket_code(&code),
+            vec![SearchHit {
+                source: SearchSource::Bitbucket,
+                title: "acme/api: src/auth.rs".to_string(),
+                url: Some("https://bitbucket.org/acme/api/src/abc123/src/auth.rs".to_string()),
+                snippet: Some("fn login() {}".to_string()),
+                updated: None,
+            }]
+        );
+
+        let hn = json!({"hits": [
+            {"objectID": "42", "title": "Show HN: Login", "url": "https://x.dev", "points": 10, "num_comments": 3, "created_at": "2024-01-02T03:04:05Z"},
+            {"objectID": "43", "title": null}
+        ]});
+        let hits = hits_from_hn(&hn);
+        assert_eq!(hits.len(), 1);
+        assert_eq!(
+            hits[0].url.as_deref(),
+            Some("https://news.ycombinator.com/item?id=42")
+        );
+        assert_eq!(
+            hits[0].snippet.as_deref(),
+            Some("10 points, 3 comments · https://x.dev")
+        );
+        assert_eq!(hits[0].updated.as_deref(), Some("2024-01-02T03:04:05Z"));
+    }
+
+    #[test]
+    fn test_hits_from_local_orders_by_coverage() {
+        let matches = vec![
+            LocalMatch {
+                path: "src/db.rs".to_string(),
+                description: Some("Database access for sessions".to_string()),
+                symbol: None,
+                line: None,
+            },
+            LocalMatch {
+                path: "src/auth.rs".to_string(),
+                description: None,
+                symbol: Some("refresh_session".to_string()),
+                line: Some(12),
+            },
+            LocalMatch {
+                path: "README.md".to_string(),
+                description: None,
+                symbol: None,
+                line: None,
+            },
+        ];
+        let hits = hits_from_local("refresh session", matches, 10);
+        let titles: Vec<&str> = hits.iter().map(|h| h.title.as_str()).collect();
+        assert_eq!(
+            titles,
+            vec!["refresh_session (src/auth.rs:12)", "src/db.rs"]
+        );
+        assert_eq!(
+            hits[1].snippet.as_deref(),
+            Some("Database access for sessions")
+        );
+    }
+
+    #[test]
+    fn test_format_unified_text() {
+        let output = UnifiedSearchOutput {
+            query: "login".to_string(),
+            results: vec![RankedHit {
+                hit: SearchHit {
+                    snippet: Some("Fails after 30s".to_string()),
+                    ..hit(
+                        SearchSource::Jira,
+                        "PROJ-2: Login",
+                        Some("https://j/browse/PROJ-2"),
+                    )
+                },
+                score: 0.03,
+            }],
+            sources: vec![
+                SourceReport {
+                    source: SearchSource::Jira,
+                    status: SourceStatus::Ok,
+                    count: 1,
+                    message: None,
+                },
+                SourceReport {
+                    source: SearchSource::Bitbucket,
+                    status: SourceStatus::Skipped,
+                    count: 0,
+                    message: Some("no workspace".to_string()),
+                },
+            ],
+        };
+        assert_eq!(
+            format_unified_text(&output),
+            "1. [jira] PROJ-2: Login\n   https://j/browse/PROJ-2\n   Fails after 30s\n\nSources: jira 1, bitbucket skipped\n  bitbucket: no workspace\n"
+        );
+        assert!(snippet(&"word ".repeat(100)).unwrap().ends_with("word…"));
+        assert_eq!(snippet("  \n "), None);
+    }
+}
@@ -0,0 +1,270 @@
+//! Sitemap parsing and filtering for `md sitemap`
+//!
+//! Handles the `<urlset>` and `<sitemapindex>` formats from sitemaps.org with
+//! a tag scanner rather than a full XML parser; sitemaps are flat enough that
+//! only `<loc>` and the optional metadata children matter.
+
+use chrono::NaiveDate;
+use regex::Regex;
+use serde::Serialize;
+use std::sync::LazyLock;
+
+static URL_RE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"(?s)<(?:\w+:)?url\b[^>]*>(.*?)</(?:\w+:)?url>").unwrap());
+static SITEMAP_RE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"(?s)<(?:\w+:)?sitemap\b[^>]*>(.*?)</(?:\w+:)?sitemap>").unwrap());
+static INDEX_RE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"<(?:\w+:)?sitemapindex\b").unwrap());
+/// Entry children we read. The closing tag is not matched by name, which is
+/// fine since these elements hold only text.
+static CHILD_RE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"(?s)<(?:\w+:)?(loc|lastmod|changefreq|priority)\b[^>]*>(.*?)</").unwrap()
+});
+
+/// One `<url>` or `<sitemap>` entry.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct SitemapEntry {
+    pub loc: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub lastmod: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub changefreq: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub priority: Option<f32>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Sitemap {
+    /// A `<urlset>` listing pages
+    UrlSet(Vec<SitemapEntry>),
+    /// A `<sitemapindex>` listing other sitemaps
+    Index(Vec<SitemapEntry>),
+}
+
+/// Filters applied to page entries.
+#[derive(Debug, Clone, Default)]
+pub struct SitemapFilter {
+    /// Keep URLs whose path starts with this (or, for a full URL, whose URL does)
+    pub path_prefix: Option<String>,
+    /// Keep URLs with a `<lastmod>` on or after this date
+    pub lastmod_after: Option<NaiveDate>,
+}
+
+pub(crate) fn element_text(raw: &str) -> Option<String> {
+    let raw = raw.trim();
+    let raw = raw
+        .strip_prefix("<![CDATA[")
+        .and_then(|s| s.strip_suffix("]]>"))
+        .unwrap_or(raw);
+    let text = html_escape::decode_html_entities(raw.trim()).to_string();
+    (!text.is_empty()).then_some(text)
+}
+
+fn parse_entries(xml: &str, re: &Regex) -> Vec<SitemapEntry> {
+    re.captures_iter(xml)
+        .filter_map(|captures| {
+            let block = captures.get(1)?.as_str();
+            let mut entry = SitemapEntry {
+                loc: String::new(),
+                lastmod: None,
+                changefreq: None,
+                priority: None,
+            };
+            for child in CHILD_RE.captures_iter(block) {
+                let text = element_text(&child[2]);
+                match &child[1] {
+                    "loc" => entry.loc = text.unwrap_or_default(),
+                    "lastmod" => entry.lastmod = text,
+                    "changefreq" => entry.changefreq = text,
+                    _ => entry.priority = text.and_then(|p| p.parse().ok()),
+                }
+            }
+            (!entry.loc.is_empty()).then_some(entry)
+        })
+        .collect()
+}
+
+/// Parse a sitemap or sitemap index. A document with no XML markup is read
+/// as a plain-text sitemap, one URL per line.
+pub fn parse_sitemap(content: &str) -> Result<Sitemap, String> {
+    let content = content.trim_start_matches('\u{feff}').trim();
+    if content.is_empty() {
+        return Err("Sitemap is empty".to_string());
+    }
+
+    if !content.starts_with('<') {
+        let entries = content
+            .lines()
+            .map(str::trim)
+            .filter(|l| l.starts_with("http://") || l.starts_with("https://"))
+            .map(|loc| SitemapEntry {
+                loc: loc.to_string(),
+                lastmod: None,
+                changefreq: None,
+                priority: None,
+            })
+            .collect();
+        return Ok(Sitemap::UrlSet(entries));
+    }
+
+    if INDEX_RE.is_match(content) {
+        return Ok(Sitemap::Index(parse_entries(content, &SITEMAP_RE)));
+    }
+    if content.contains("urlset") {
+        return Ok(Sitemap::UrlSet(parse_entries(content, &URL_RE)));
+    }
+    Err("Not a sitemap: expected <urlset> or <sitemapindex>".to_string())
+}
+
+/// Date part of a W3C datetime `<lastmod>` (`YYYY`, `YYYY-MM`, `YYYY-MM-DD`,
+/// or a full timestamp). Partial dates resolve to their first day.
+pub fn lastmod_date(value: &str) -> Option<NaiveDate> {
+    let date = value.trim().get(..10).unwrap_or(value.trim());
+    let mut parts = date.split('-').map(|p| p.parse::<u32>().ok());
+    let year = parts.next()??;
+    let month = parts.next().flatten().unwrap_or(1);
+    let day = parts.next().flatten().unwrap_or(1);
+    NaiveDate::from_ymd_opt(year as i32, month, day)
+}
+
+fn url_path(loc: &str) -> &str {
+    let after_scheme = loc.split_once("://").map_or(loc, |(_, rest)| rest);
+    after_scheme.find('/').map_or("/", |i| &after_scheme[i..])
+}
+
+/// Whether an entry passes `filter`. Entries without a parseable `<lastmod>`
+/// fail a date filter.
+pub fn matches_filter(entry: &SitemapEntry, filter: &SitemapFilter) -> bool {
+    if let Some(prefix) = &filter.path_prefix {
+        let matched = if prefix.contains("://") {
+            entry.loc.starts_with(prefix.as_str())
+        } else if prefix.starts_with('/') {
+            url_path(&entry.loc).starts_with(prefix.as_str())
+        } else {
+            url_path(&entry.loc)[1..].starts_with(prefix.as_str())
+        };
+        if !matched {
+            return false;
+        }
+    }
+    if let Some(after) = filter.lastmod_after {
+        match entry.lastmod.as_deref().and_then(lastmod_date) {
+            Some(date) if date >= after => {}
+            _ => return false,
+        }
+    }
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const URLSET: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<urlset xmlns="http://www.sitemaps.org/schemas/sitemap/0.9">
+  <url>
+    <loc>https://example.com/docs/intro?a=1&amp;b=2</loc>
+    <lastmod>2024-03-05T10:00:00+00:00</lastmod>
+    <changefreq>weekly</changefreq>
+    <priority>0.8</priority>
+  </url>
+  <url><loc><![CDATA[https://example.com/blog/post]]></loc><lastmod>2023-12</lastmod></url>
+  <url><loc>https://example.com/about</loc></url>
+  <url><lastmod>2024-01-01</lastmod></url>
+</urlset>"#;
+
+    fn entries() -> Vec<SitemapEntry> {
+        match parse_sitemap(URLSET).unwrap() {
+            Sitemap::UrlSet(entries) => entries,
+            other => panic!("expected urlset, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_parse_urlset() {
+        let entries = entries();
+        assert_eq!(entries.len(), 3);
+        assert_eq!(entries[0].loc, "https://example.com/docs/intro?a=1&b=2");
+        assert_eq!(entries[0].changefreq.as_deref(), Some("weekly"));
+        assert_eq!(entries[0].priority, Some(0.8));
+        assert_eq!(entries[1].loc, "https://example.com/blog/post");
+        assert_eq!(entries[2].lastmod, None);
+    }
+
+    #[test]
+    fn test_parse_index() {
+        let xml = r#"<sitemapindex xmlns="http://www.sitemaps.org/schemas/sitemap/0.9">
+            <sitemap><loc>https://example.com/sitemap-docs.xml</loc><lastmod>2024-01-01</lastmod></sitemap>
+            <sitemap><loc>https://example.com/sitemap-blog.xml</loc></sitemap>
+        </sitemapindex>"#;
+        let Sitemap::Index(sitemaps) = parse_sitemap(xml).unwrap() else {
+            panic!("expected index");
+        };
+        assert_eq!(sitemaps.len(), 2);
+        assert_eq!(sitemaps[1].loc, "https://example.com/sitemap-blog.xml");
+    }
+
+    #[test]
+    fn test_parse_plain_text_and_errors() {
+        let Sitemap::UrlSet(entries) =
+            parse_sitemap("https://a.example/\n\n# note\nhttps://b.example/x\n").unwrap()
+        else {
+            panic!("expected urlset");
+        };
+        assert_eq!(entries.len(), 2);
+        assert!(parse_sitemap("").is_err());
+        assert!(parse_sitemap("<html><body>Not found</body></html>").is_err());
+    }
+
+    #[test]
+    fn test_lastmod_date() {
+        let date = |y, m, d| NaiveDate::from_ymd_opt(y, m, d);
+        assert_eq!(lastmod_date("2024-03-05T10:00:00+00:00"), date(2024, 3, 5));
+        assert_eq!(lastmod_date("2023-12"), date(2023, 12, 1));
+        assert_eq!(lastmod_date("2022"), date(2022, 1, 1));
+        assert_eq!(lastmod_date("yesterday"), None);
+    }
+
+    #[test]
+    fn test_matches_filter() {
+        let entries = entries();
+        let keep = |filter: SitemapFilter| -> Vec<&str> {
+            entries
+                .iter()
+                .filter(|e| matches_filter(e, &filter))
+                .map(|e| e.loc.as_str())
+                .collect()
+        };
+
+        assert_eq!(keep(SitemapFilter::default()).len(), 3);
+        assert_eq!(
+            keep(SitemapFilter {
+                path_prefix: Some("/blog".to_string()),
+                ..Default::default()
+            }),
+            vec!["https://example.com/blog/post"]
+        );
+        assert_eq!(
+            keep(SitemapFilter {
+                path_prefix: Some("docs/".to_string()),
+                ..Default::default()
+            })
+            .len(),
+            1
+        );
+        assert_eq!(
+            keep(SitemapFilter {
+                path_prefix: Some("https://example.com/a".to_string()),
+                ..Default::default()
+            }),
+            vec!["https://example.com/about"]
+        );
+        assert_eq!(
+            keep(SitemapFilter {
+                lastmod_after: NaiveDate::from_ymd_opt(2024, 1, 1),
+                ..Default::default()
+            }),
+            vec!["https://example.com/docs/intro?a=1&b=2"]
+        );
+    }
+}
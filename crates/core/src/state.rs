@@ -0,0 +1,213 @@
+//! Layout and migration planning for the versioned state directory
+//!
+//! Runtime state (caches, seen-story state, sessions, notes, the audit log,
+//! and pagination tokens) lives under `$XDG_STATE_HOME/mcptools/`, falling
+//! back to `~/.local/state/mcptools/`. A `VERSION` file records the layout
+//! version so later releases can migrate it. This module only computes paths
+//! and plans; the shell performs the filesystem operations.
+
+use serde::Serialize;
+use std::path::{Path, PathBuf};
+
+/// Layout version written by this release
+pub const STATE_VERSION: u32 = 1;
+
+/// Name of the file holding the layout version, relative to the state root
+pub const VERSION_FILE: &str = "VERSION";
+
+/// A named subdirectory of the state directory
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum StateComponent {
+    Cache,
+    Seen,
+    Sessions,
+    Notes,
+    Audit,
+    Pagination,
+    Snapshots,
+}
+
+impl StateComponent {
+    pub const ALL: [StateComponent; 7] = [
+        StateComponent::Cache,
+        StateComponent::Seen,
+        StateComponent::Sessions,
+        StateComponent::Notes,
+        StateComponent::Audit,
+        StateComponent::Pagination,
+        StateComponent::Snapshots,
+    ];
+
+    /// Directory name under the state root
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            StateComponent::Cache => "cache",
+            StateComponent::Seen => "seen",
+            StateComponent::Sessions => "sessions",
+            StateComponent::Notes => "notes",
+            StateComponent::Audit => "audit",
+            StateComponent::Pagination => "pagination",
+            StateComponent::Snapshots => "snapshots",
+        }
+    }
+}
+
+/// A pre-state-directory location whose contents belong to a component
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct LegacyLocation {
+    pub component: StateComponent,
+    pub path: PathBuf,
+}
+
+/// One filesystem operation of a migration
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(tag = "action", rename_all = "snake_case")]
+pub enum MigrationStep {
+    /// Create a component directory
+    CreateDir { path: PathBuf },
+    /// Move every entry of `from` into `to`, keeping entries already in `to`,
+    /// then remove `from` when it is empty
+    MoveContents {
+        component: StateComponent,
+        from: PathBuf,
+        to: PathBuf,
+    },
+    /// Record the layout version
+    WriteVersion { path: PathBuf, version: u32 },
+}
+
+/// Resolve the state root from `XDG_STATE_HOME` and `HOME`
+///
+/// Per the XDG spec, a relative or empty `XDG_STATE_HOME` is ignored.
+pub fn state_root(xdg_state_home: Option<&str>, home: &Path) -> PathBuf {
+    let base = match xdg_state_home {
+        Some(dir) if Path::new(dir).is_absolute() => PathBuf::from(dir),
+        _ => home.join(".local/state"),
+    };
+    base.join("mcptools")
+}
+
+/// Directory of `component` under `root`
+pub fn component_dir(root: &Path, component: StateComponent) -> PathBuf {
+    root.join(component.as_str())
+}
+
+/// Parse the contents of the `VERSION` file; a missing file is version 0
+pub fn parse_state_version(content: Option<&str>) -> Result<u32, String> {
+    match content {
+        None => Ok(0),
+        Some(text) => text
+            .trim()
+            .parse::<u32>()
+            .map_err(|_| format!("Invalid state version: {:?}", text.trim())),
+    }
+}
+
+/// Locations used before the state directory existed
+pub fn legacy_locations(home: &Path) -> Vec<LegacyLocation> {
+    vec![LegacyLocation {
+        component: StateComponent::Pagination,
+        path: home.join(".config/mcptools/pagination"),
+    }]
+}
+
+/// Plan the steps that bring `root` to [`STATE_VERSION`]
+///
+/// `existing_legacy` holds the legacy locations that exist on disk. An
+/// up-to-date root with nothing left to import yields an empty plan; a root
+/// written by a newer release is an error rather than a downgrade.
+pub fn plan_migration(
+    root: &Path,
+    current_version: u32,
+    existing_legacy: &[LegacyLocation],
+) -> Result<Vec<MigrationStep>, String> {
+    if current_version > STATE_VERSION {
+        return Err(format!(
+            "State directory {} has version {}, but this release supports up to {}; upgrade mcptools",
+            root.display(),
+            current_version,
+            STATE_VERSION
+        ));
+    }
+    if current_version == STATE_VERSION && existing_legacy.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let mut steps: Vec<MigrationStep> = StateComponent::ALL
+        .iter()
+        .map(|&component| MigrationStep::CreateDir {
+            path: component_dir(root, component),
+        })
+        .collect();
+    steps.extend(
+        existing_legacy
+            .iter()
+            .map(|legacy| MigrationStep::MoveContents {
+                component: legacy.component,
+                from: legacy.path.clone(),
+                to: component_dir(root, legacy.component),
+            }),
+    );
+    steps.push(MigrationStep::WriteVersion {
+        path: root.join(VERSION_FILE),
+        version: STATE_VERSION,
+    });
+    Ok(steps)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_state_root_prefers_absolute_xdg_state_home() {
+        let home = Path::new("/home/u");
+        assert_eq!(
+            state_root(Some("/var/state"), home),
+            PathBuf::from("/var/state/mcptools")
+        );
+        assert_eq!(
+            state_root(Some("relative"), home),
+            PathBuf::from("/home/u/.local/state/mcptools")
+        );
+        assert_eq!(
+            state_root(None, home),
+            PathBuf::from("/home/u/.local/state/mcptools")
+        );
+    }
+
+    #[test]
+    fn test_parse_state_version() {
+        assert_eq!(parse_state_version(None), Ok(0));
+        assert_eq!(parse_state_version(Some("1\n")), Ok(1));
+        assert!(parse_state_version(Some("one")).is_err());
+    }
+
+    #[test]
+    fn test_plan_migration() {
+        let root = Path::new("/s/mcptools");
+        let legacy = legacy_locations(Path::new("/home/u"));
+
+        let fresh = plan_migration(root, 0, &legacy).unwrap();
+        assert_eq!(fresh.len(), StateComponent::ALL.len() + 2);
+        assert_eq!(
+            fresh[StateComponent::ALL.len()],
+            MigrationStep::MoveContents {
+                component: StateComponent::Pagination,
+                from: PathBuf::from("/home/u/.config/mcptools/pagination"),
+                to: PathBuf::from("/s/mcptools/pagination"),
+            }
+        );
+        assert_eq!(
+            fresh.last(),
+            Some(&MigrationStep::WriteVersion {
+                path: PathBuf::from("/s/mcptools/VERSION"),
+                version: STATE_VERSION,
+            })
+        );
+
+        assert!(plan_migration(root, STATE_VERSION, &[]).unwrap().is_empty());
+        assert!(plan_migration(root, STATE_VERSION + 1, &[]).is_err());
+    }
+}
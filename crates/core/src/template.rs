@@ -0,0 +1,208 @@
+//! Simple `{name}` placeholder templating
+//!
+//! Pure functions for rendering text templates with named variables, used
+//! for bulk operations such as templated Jira comments. Placeholders are
+//! `{name}` where `name` is made of letters, digits, `_`, `-`, or `.`.
+//! Literal braces are written as `{{` and `}}`.
+
+use std::collections::BTreeMap;
+
+/// Variables available to a template
+pub type TemplateVars = BTreeMap<String, String>;
+
+/// Error type for template operations
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TemplateError {
+    UnknownVariable(String),
+    UnclosedPlaceholder(usize),
+    InvalidPlaceholder(String),
+    InvalidVariable(String),
+}
+
+impl std::fmt::Display for TemplateError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TemplateError::UnknownVariable(name) => {
+                write!(f, "Unknown template variable: {{{}}}", name)
+            }
+            TemplateError::UnclosedPlaceholder(pos) => {
+                write!(f, "Unclosed '{{' at position {}", pos)
+            }
+            TemplateError::InvalidPlaceholder(name) => {
+                write!(f, "Invalid placeholder name: {{{}}}", name)
+            }
+            TemplateError::InvalidVariable(arg) => {
+                write!(f, "Invalid variable '{}' (expected NAME=VALUE)", arg)
+            }
+        }
+    }
+}
+
+impl std::error::Error for TemplateError {}
+
+fn is_name_char(c: char) -> bool {
+    c.is_ascii_alphanumeric() || matches!(c, '_' | '-' | '.')
+}
+
+/// Parse `NAME=VALUE` arguments into template variables
+///
+/// The value may contain `=`; only the first one separates name and value.
+/// Later definitions of the same name win.
+pub fn parse_vars<S: AsRef<str>>(args: &[S]) -> Result<TemplateVars, TemplateError> {
+    let mut vars = TemplateVars::new();
+    for arg in args {
+        let arg = arg.as_ref();
+        let (name, value) = arg
+            .split_once('=')
+            .ok_or_else(|| TemplateError::InvalidVariable(arg.to_string()))?;
+        let name = name.trim();
+        if name.is_empty() || !name.chars().all(is_name_char) {
+            return Err(TemplateError::InvalidVariable(arg.to_string()));
+        }
+        vars.insert(name.to_string(), value.to_string());
+    }
+    Ok(vars)
+}
+
+/// List the placeholder names used in a template, in order of first use
+pub fn placeholders(template: &str) -> Result<Vec<String>, TemplateError> {
+    let mut names = Vec::new();
+    walk(template, |name| {
+        if !names.iter().any(|n| n == name) {
+            names.push(name.to_string());
+        }
+        Ok(String::new())
+    })?;
+    Ok(names)
+}
+
+/// Render a template, replacing every `{name}` with its value
+///
+/// Returns an error if a placeholder has no matching variable, so typos are
+/// caught before anything is sent.
+pub fn render(template: &str, vars: &TemplateVars) -> Result<String, TemplateError> {
+    walk(template, |name| {
+        vars.get(name)
+            .cloned()
+            .ok_or_else(|| TemplateError::UnknownVariable(name.to_string()))
+    })
+}
+
+/// Scan a template, emitting literal text and resolving placeholders via `resolve`.
+fn walk<F>(template: &str, mut resolve: F) -> Result<String, TemplateError>
+where
+    F: FnMut(&str) -> Result<String, TemplateError>,
+{
+    let mut out = String::with_capacity(template.len());
+    let mut chars = template.char_indices().peekable();
+
+    while let Some((pos, c)) = chars.next() {
+        match c {
+            '{' if chars.peek().map(|&(_, n)| n) == Some('{') => {
+                chars.next();
+                out.push('{');
+            }
+            '}' if chars.peek().map(|&(_, n)| n) == Some('}') => {
+                chars.next();
+                out.push('}');
+            }
+            '{' => {
+                let mut name = String::new();
+                let mut closed = false;
+                for (_, n) in chars.by_ref() {
+                    if n == '}' {
+                        closed = true;
+                        break;
+                    }
+                    name.push(n);
+                }
+                if !closed {
+                    return Err(TemplateError::UnclosedPlaceholder(pos));
+                }
+                let name = name.trim();
+                if name.is_empty() || !name.chars().all(is_name_char) {
+                    return Err(TemplateError::InvalidPlaceholder(name.to_string()));
+                }
+                out.push_str(&resolve(name)?);
+            }
+            _ => out.push(c),
+        }
+    }
+
+    Ok(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn vars(pairs: &[(&str, &str)]) -> TemplateVars {
+        pairs
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn test_render_substitutes_variables() {
+        let v = vars(&[("version", "1.2.0"), ("key", "PROJ-1")]);
+        assert_eq!(
+            render("{key} released in {version}", &v).unwrap(),
+            "PROJ-1 released in 1.2.0"
+        );
+    }
+
+    #[test]
+    fn test_render_escaped_braces() {
+        let v = vars(&[("x", "1")]);
+        assert_eq!(render("{{x}} = {x}", &v).unwrap(), "{x} = 1");
+    }
+
+    #[test]
+    fn test_render_unknown_variable() {
+        let err = render("Hi {name}", &TemplateVars::new()).unwrap_err();
+        assert_eq!(err, TemplateError::UnknownVariable("name".to_string()));
+    }
+
+    #[test]
+    fn test_render_unclosed_placeholder() {
+        let err = render("Hi {name", &vars(&[("name", "x")])).unwrap_err();
+        assert_eq!(err, TemplateError::UnclosedPlaceholder(3));
+    }
+
+    #[test]
+    fn test_render_invalid_placeholder() {
+        assert!(matches!(
+            render("{not valid}", &TemplateVars::new()),
+            Err(TemplateError::InvalidPlaceholder(_))
+        ));
+    }
+
+    #[test]
+    fn test_render_multibyte_text() {
+        let v = vars(&[("who", "José")]);
+        assert_eq!(render("¡Hola {who}! ✓", &v).unwrap(), "¡Hola José! ✓");
+    }
+
+    #[test]
+    fn test_parse_vars() {
+        let parsed = parse_vars(&["version=1.2.0", "url=https://x.io/?a=b"]).unwrap();
+        assert_eq!(parsed["version"], "1.2.0");
+        assert_eq!(parsed["url"], "https://x.io/?a=b");
+    }
+
+    #[test]
+    fn test_parse_vars_invalid() {
+        assert!(parse_vars(&["novalue"]).is_err());
+        assert!(parse_vars(&["=x"]).is_err());
+        assert!(parse_vars(&["bad name=x"]).is_err());
+    }
+
+    #[test]
+    fn test_placeholders() {
+        assert_eq!(
+            placeholders("{a} {b} {a} {{c}}").unwrap(),
+            vec!["a".to_string(), "b".to_string()]
+        );
+    }
+}
@@ -0,0 +1,448 @@
+//! Speech-to-text transcript shaping
+//!
+//! Pure functions for turning speech-to-text responses into readable,
+//! timestamped transcripts. Both whisper.cpp's server and OpenAI-compatible
+//! `/v1/audio/transcriptions` endpoints return the same `verbose_json` shape:
+//! a full `text` plus short `segments` with start/end times in seconds.
+//! Segments are usually a few seconds long, so they are merged into
+//! paragraphs before rendering.
+
+use serde::{Deserialize, Serialize};
+
+/// Which speech-to-text API to call.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum TranscribeApi {
+    /// whisper.cpp `server` (`POST /inference`).
+    #[default]
+    WhisperCpp,
+    /// OpenAI-compatible endpoint (`POST /v1/audio/transcriptions`).
+    Openai,
+}
+
+impl std::str::FromStr for TranscribeApi {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_lowercase().as_str() {
+            "whisper-cpp" | "whisper.cpp" | "whispercpp" => Ok(TranscribeApi::WhisperCpp),
+            "openai" => Ok(TranscribeApi::Openai),
+            other => Err(format!(
+                "Invalid API '{}' (expected 'whisper-cpp' or 'openai')",
+                other
+            )),
+        }
+    }
+}
+
+/// Build the transcription endpoint URL for an API and base URL.
+///
+/// A base URL that already ends with the endpoint path is used as-is.
+pub fn transcription_url(api: TranscribeApi, base_url: &str) -> String {
+    let base = base_url.trim_end_matches('/');
+    let path = match api {
+        TranscribeApi::WhisperCpp => "/inference",
+        TranscribeApi::Openai => "/v1/audio/transcriptions",
+    };
+    if base.ends_with(path) {
+        base.to_string()
+    } else if api == TranscribeApi::Openai && base.ends_with("/v1") {
+        format!("{}/audio/transcriptions", base)
+    } else {
+        format!("{}{}", base, path)
+    }
+}
+
+/// A `verbose_json` transcription response.
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct TranscriptionResponse {
+    #[serde(default)]
+    pub text: String,
+    #[serde(default)]
+    pub language: Option<String>,
+    #[serde(default)]
+    pub duration: Option<f64>,
+    #[serde(default)]
+    pub segments: Vec<TranscriptSegment>,
+}
+
+/// A timed span of transcribed speech.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TranscriptSegment {
+    /// Start time in seconds.
+    pub start: f64,
+    /// End time in seconds.
+    pub end: f64,
+    pub text: String,
+}
+
+/// Limits for merging segments into paragraphs.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MergeOptions {
+    /// Silence (seconds) that always starts a new paragraph.
+    pub max_gap: f64,
+    /// Longest paragraph, in seconds.
+    pub max_duration: f64,
+}
+
+impl Default for MergeOptions {
+    fn default() -> Self {
+        Self {
+            max_gap: 2.0,
+            max_duration: 60.0,
+        }
+    }
+}
+
+/// A finished transcript, ready for JSON output or Markdown rendering.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TranscriptOutput {
+    /// Audio file the transcript came from.
+    pub source: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub language: Option<String>,
+    /// Audio duration in seconds, when known.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub duration: Option<f64>,
+    pub text: String,
+    /// Merged, timestamped paragraphs.
+    pub segments: Vec<TranscriptSegment>,
+}
+
+/// Whether a segment is a non-speech annotation such as `[BLANK_AUDIO]`
+/// or `(music)`, which whisper emits for silence and background noise.
+pub fn is_noise(text: &str) -> bool {
+    let text = text.trim();
+    text.is_empty()
+        || (text.starts_with('[') && text.ends_with(']'))
+        || (text.starts_with('(') && text.ends_with(')'))
+        || text.chars().all(|c| c == '♪' || c.is_whitespace())
+}
+
+/// Merge consecutive segments into paragraphs.
+///
+/// Noise segments are dropped and whitespace is normalized. A new paragraph
+/// starts after a silence longer than `max_gap`, or when adding the next
+/// segment would make the paragraph longer than `max_duration`; in the latter
+/// case, the break is moved back to the last segment that ends a sentence if
+/// there is one, so paragraphs do not stop mid-sentence.
+pub fn merge_segments(
+    segments: &[TranscriptSegment],
+    options: &MergeOptions,
+) -> Vec<TranscriptSegment> {
+    let cleaned: Vec<TranscriptSegment> = segments
+        .iter()
+        .filter(|s| !is_noise(&s.text))
+        .map(|s| TranscriptSegment {
+            start: s.start,
+            end: s.end.max(s.start),
+            text: normalize_whitespace(&s.text),
+        })
+        .collect();
+
+    let mut paragraphs: Vec<Vec<TranscriptSegment>> = Vec::new();
+    let mut current: Vec<TranscriptSegment> = Vec::new();
+
+    for segment in cleaned {
+        let Some(first) = current.first() else {
+            current.push(segment);
+            continue;
+        };
+        let last = current.last().expect("current is not empty");
+        let gap = segment.start - last.end;
+
+        if gap > options.max_gap {
+            paragraphs.push(std::mem::take(&mut current));
+        } else if segment.end - first.start > options.max_duration {
+            // Carry the unfinished sentence over to the next paragraph.
+            let split = current
+                .iter()
+                .rposition(|s| ends_sentence(&s.text))
+                .map(|i| i + 1)
+                .unwrap_or(current.len());
+            let carry = current.split_off(split);
+            paragraphs.push(std::mem::replace(&mut current, carry));
+        }
+        current.push(segment);
+    }
+    if !current.is_empty() {
+        paragraphs.push(current);
+    }
+
+    paragraphs
+        .into_iter()
+        .filter(|p| !p.is_empty())
+        .map(|p| TranscriptSegment {
+            start: p[0].start,
+            end: p[p.len() - 1].end,
+            text: p
+                .iter()
+                .map(|s| s.text.as_str())
+                .collect::<Vec<_>>()
+                .join(" "),
+        })
+        .collect()
+}
+
+/// Build a transcript from a transcription response.
+///
+/// Responses without segments (plain `json` format) become a single
+/// untimed paragraph spanning the whole duration.
+pub fn build_transcript(
+    source: &str,
+    response: TranscriptionResponse,
+    options: &MergeOptions,
+) -> TranscriptOutput {
+    let segments = if response.segments.is_empty() {
+        let text = normalize_whitespace(&response.text);
+        if text.is_empty() {
+            Vec::new()
+        } else {
+            vec![TranscriptSegment {
+                start: 0.0,
+                end: response.duration.unwrap_or(0.0),
+                text,
+            }]
+        }
+    } else {
+        merge_segments(&response.segments, options)
+    };
+
+    let duration = response.duration.or_else(|| segments.last().map(|s| s.end));
+
+    TranscriptOutput {
+        source: source.to_string(),
+        language: response.language.filter(|l| !l.is_empty()),
+        duration,
+        text: normalize_whitespace(&response.text),
+        segments,
+    }
+}
+
+/// Format seconds as `MM:SS`, or `H:MM:SS` from one hour on.
+pub fn format_timestamp(seconds: f64) -> String {
+    let total = seconds.max(0.0).floor() as u64;
+    let (h, m, s) = (total / 3600, (total % 3600) / 60, total % 60);
+    if h > 0 {
+        format!("{}:{:02}:{:02}", h, m, s)
+    } else {
+        format!("{:02}:{:02}", m, s)
+    }
+}
+
+/// Render a transcript as Markdown with one timestamped paragraph per segment.
+pub fn render_markdown(transcript: &TranscriptOutput) -> String {
+    let mut out = format!("# Transcript: {}\n\n", transcript.source);
+
+    let mut meta = Vec::new();
+    if let Some(language) = &transcript.language {
+        meta.push(format!("- **Language:** {}", language));
+    }
+    if let Some(duration) = transcript.duration {
+        meta.push(format!("- **Duration:** {}", format_timestamp(duration)));
+    }
+    if !meta.is_empty() {
+        out.push_str(&meta.join("\n"));
+        out.push_str("\n\n");
+    }
+
+    for segment in &transcript.segments {
+        out.push_str(&format!(
+            "**[{}]** {}\n\n",
+            format_timestamp(segment.start),
+            segment.text
+        ));
+    }
+
+    out.trim_end().to_string() + "\n"
+}
+
+fn normalize_whitespace(text: &str) -> String {
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+fn ends_sentence(text: &str) -> bool {
+    text.trim_end()
+        .trim_end_matches(['"', '\'', ')', '”'])
+        .ends_with(['.', '!', '?', '…'])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn seg(start: f64, end: f64, text: &str) -> TranscriptSegment {
+        TranscriptSegment {
+            start,
+            end,
+            text: text.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_transcription_url() {
+        assert_eq!(
+            transcription_url(TranscribeApi::WhisperCpp, "http://localhost:8080/"),
+            "http://localhost:8080/inference"
+        );
+        assert_eq!(
+            transcription_url(TranscribeApi::Openai, "https://api.openai.com"),
+            "https://api.openai.com/v1/audio/transcriptions"
+        );
+        assert_eq!(
+            transcription_url(TranscribeApi::Openai, "http://localhost:8000/v1"),
+            "http://localhost:8000/v1/audio/transcriptions"
+        );
+        assert_eq!(
+            transcription_url(TranscribeApi::WhisperCpp, "http://host/inference"),
+            "http://host/inference"
+        );
+    }
+
+    #[test]
+    fn test_api_from_str() {
+        assert_eq!("openai".parse(), Ok(TranscribeApi::Openai));
+        assert_eq!("whisper.cpp".parse(), Ok(TranscribeApi::WhisperCpp));
+        assert!("azure".parse::<TranscribeApi>().is_err());
+    }
+
+    #[test]
+    fn test_is_noise() {
+        assert!(is_noise(" [BLANK_AUDIO] "));
+        assert!(is_noise("(music)"));
+        assert!(is_noise("♪ ♪"));
+        assert!(is_noise(""));
+        assert!(!is_noise("Hello (everyone)."));
+    }
+
+    #[test]
+    fn test_merge_short_segments() {
+        let segments = [
+            seg(0.0, 2.0, " Hello everyone."),
+            seg(2.2, 4.0, " Let's  get started."),
+        ];
+        let merged = merge_segments(&segments, &MergeOptions::default());
+
+        assert_eq!(merged.len(), 1);
+        assert_eq!(
+            merged[0],
+            seg(0.0, 4.0, "Hello everyone. Let's get started.")
+        );
+    }
+
+    #[test]
+    fn test_merge_splits_on_gap() {
+        let segments = [seg(0.0, 2.0, "First."), seg(10.0, 12.0, "Second.")];
+        let merged = merge_segments(&segments, &MergeOptions::default());
+
+        assert_eq!(merged.len(), 2);
+        assert_eq!(merged[1].start, 10.0);
+    }
+
+    #[test]
+    fn test_merge_splits_at_sentence_end_when_too_long() {
+        let options = MergeOptions {
+            max_gap: 5.0,
+            max_duration: 10.0,
+        };
+        let segments = [
+            seg(0.0, 3.0, "One sentence."),
+            seg(3.0, 6.0, "Another that"),
+            seg(6.0, 9.0, "keeps going"),
+            seg(9.0, 12.0, "and ends."),
+        ];
+        let merged = merge_segments(&segments, &options);
+
+        assert_eq!(merged.len(), 2);
+        assert_eq!(merged[0], seg(0.0, 3.0, "One sentence."));
+        assert_eq!(
+            merged[1],
+            seg(3.0, 12.0, "Another that keeps going and ends.")
+        );
+    }
+
+    #[test]
+    fn test_merge_hard_split_without_sentence_end() {
+        let options = MergeOptions {
+            max_gap: 5.0,
+            max_duration: 5.0,
+        };
+        let segments = [
+            seg(0.0, 3.0, "no punctuation"),
+            seg(3.0, 6.0, "at all"),
+            seg(6.0, 9.0, "here"),
+        ];
+        let merged = merge_segments(&segments, &options);
+
+        assert_eq!(merged.len(), 3);
+        assert!(merged.iter().all(|p| p.end - p.start <= 5.0));
+    }
+
+    #[test]
+    fn test_merge_drops_noise() {
+        let segments = [
+            seg(0.0, 1.0, "[BLANK_AUDIO]"),
+            seg(1.0, 2.0, "Hi."),
+            seg(2.0, 3.0, "(applause)"),
+        ];
+        let merged = merge_segments(&segments, &MergeOptions::default());
+        assert_eq!(merged, vec![seg(1.0, 2.0, "Hi.")]);
+    }
+
+    #[test]
+    fn test_build_transcript_from_verbose_json() {
+        let json = r#"{
+            "task": "transcribe",
+            "language": "english",
+            "duration": 4.5,
+            "text": " Hello everyone. Let's get started.",
+            "segments": [
+                {"id": 0, "start": 0.0, "end": 2.0, "text": " Hello everyone.", "tokens": [1, 2]},
+                {"id": 1, "start": 2.1, "end": 4.5, "text": " Let's get started."}
+            ]
+        }"#;
+        let response: TranscriptionResponse = serde_json::from_str(json).unwrap();
+        let transcript = build_transcript("meeting.wav", response, &MergeOptions::default());
+
+        assert_eq!(transcript.language.as_deref(), Some("english"));
+        assert_eq!(transcript.duration, Some(4.5));
+        assert_eq!(transcript.text, "Hello everyone. Let's get started.");
+        assert_eq!(transcript.segments.len(), 1);
+    }
+
+    #[test]
+    fn test_build_transcript_without_segments() {
+        let response: TranscriptionResponse =
+            serde_json::from_str(r#"{"text": "Just text."}"#).unwrap();
+        let transcript = build_transcript("a.mp3", response, &MergeOptions::default());
+
+        assert_eq!(transcript.segments, vec![seg(0.0, 0.0, "Just text.")]);
+        assert_eq!(transcript.duration, Some(0.0));
+        assert_eq!(transcript.language, None);
+    }
+
+    #[test]
+    fn test_format_timestamp() {
+        assert_eq!(format_timestamp(0.0), "00:00");
+        assert_eq!(format_timestamp(65.9), "01:05");
+        assert_eq!(format_timestamp(3725.0), "1:02:05");
+        assert_eq!(format_timestamp(-3.0), "00:00");
+    }
+
+    #[test]
+    fn test_render_markdown() {
+        let transcript = TranscriptOutput {
+            source: "standup.m4a".to_string(),
+            language: Some("en".to_string()),
+            duration: Some(95.0),
+            text: String::new(),
+            segments: vec![seg(0.0, 5.0, "Morning."), seg(62.0, 70.0, "Blockers?")],
+        };
+        let md = render_markdown(&transcript);
+
+        assert!(md.starts_with("# Transcript: standup.m4a\n\n"));
+        assert!(md.contains("- **Language:** en\n- **Duration:** 01:35"));
+        assert!(md.contains("**[00:00]** Morning.\n\n**[01:02]** Blockers?\n"));
+        assert!(md.ends_with("Blockers?\n"));
+    }
+}
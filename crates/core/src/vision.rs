@@ -0,0 +1,248 @@
+//! Image description via a local vision model
+//!
+//! Pure helpers for describing images with an Ollama vision model (llava,
+//! qwen-vl, ...): prompt construction, request/response shapes for Ollama's
+//! `/api/generate` endpoint, and cleanup of model output into alt text.
+
+use serde::{Deserialize, Serialize};
+
+/// Default Ollama vision model.
+pub const DEFAULT_VISION_MODEL: &str = "llava";
+
+/// Maximum length of generated alt text, in characters.
+pub const MAX_ALT_TEXT_CHARS: usize = 125;
+
+/// What kind of text to ask the model for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DescribeMode {
+    /// A detailed description of the image.
+    #[default]
+    Description,
+    /// A single short sentence suitable for an image's alt text.
+    AltText,
+}
+
+/// Image container formats that vision models accept.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum VisionImageFormat {
+    Png,
+    Jpeg,
+    Gif,
+    Webp,
+    Bmp,
+}
+
+/// Request body for Ollama's `/api/generate` endpoint.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct GenerateRequest {
+    pub model: String,
+    pub prompt: String,
+    /// Base64-encoded images.
+    pub images: Vec<String>,
+    pub stream: bool,
+}
+
+/// The subset of Ollama's `/api/generate` response that we use.
+#[derive(Debug, Clone, Deserialize)]
+pub struct GenerateResponse {
+    #[serde(default)]
+    pub response: String,
+    #[serde(default)]
+    pub error: Option<String>,
+}
+
+/// Output of a describe-image request.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ImageDescription {
+    /// File path, or `path#image-id` for images embedded in a PDF.
+    pub source: String,
+    pub model: String,
+    pub description: String,
+}
+
+/// Detect an image format from its magic bytes.
+pub fn detect_image_format(bytes: &[u8]) -> Option<VisionImageFormat> {
+    if bytes.starts_with(&[0x89, b'P', b'N', b'G', 0x0D, 0x0A, 0x1A, 0x0A]) {
+        Some(VisionImageFormat::Png)
+    } else if bytes.starts_with(&[0xFF, 0xD8, 0xFF]) {
+        Some(VisionImageFormat::Jpeg)
+    } else if bytes.starts_with(b"GIF87a") || bytes.starts_with(b"GIF89a") {
+        Some(VisionImageFormat::Gif)
+    } else if bytes.len() >= 12 && &bytes[..4] == b"RIFF" && &bytes[8..12] == b"WEBP" {
+        Some(VisionImageFormat::Webp)
+    } else if bytes.starts_with(b"BM") {
+        Some(VisionImageFormat::Bmp)
+    } else {
+        None
+    }
+}
+
+/// Build the prompt for the given mode.
+///
+/// A custom prompt replaces the built-in description prompt; in alt-text mode
+/// it is appended as extra guidance so the output stays short.
+pub fn build_prompt(mode: DescribeMode, custom: Option<&str>) -> String {
+    let custom = custom.map(str::trim).filter(|c| !c.is_empty());
+    match (mode, custom) {
+        (DescribeMode::Description, Some(custom)) => custom.to_string(),
+        (DescribeMode::Description, None) => "Describe this image in detail. Mention any visible \
+             text, the main subjects, and, for charts or diagrams, what they show."
+            .to_string(),
+        (DescribeMode::AltText, custom) => {
+            let mut prompt = format!(
+                "Write alt text for this image: one plain sentence under {} characters. \
+                 Do not start with \"Image of\" or \"Picture of\". Reply with the alt text only.",
+                MAX_ALT_TEXT_CHARS
+            );
+            if let Some(custom) = custom {
+                prompt.push(' ');
+                prompt.push_str(custom);
+            }
+            prompt
+        }
+    }
+}
+
+/// Build a non-streaming `/api/generate` request for one image.
+pub fn build_generate_request(model: &str, prompt: &str, image_base64: String) -> GenerateRequest {
+    GenerateRequest {
+        model: model.to_string(),
+        prompt: prompt.to_string(),
+        images: vec![image_base64],
+        stream: false,
+    }
+}
+
+/// Extract the generated text from an Ollama response.
+pub fn parse_generate_response(response: GenerateResponse) -> Result<String, String> {
+    if let Some(error) = response.error {
+        return Err(error);
+    }
+    let text = response.response.trim();
+    if text.is_empty() {
+        return Err("Model returned an empty description".to_string());
+    }
+    Ok(text.to_string())
+}
+
+/// Clean model output into a single line of alt text.
+///
+/// Collapses whitespace, strips wrapping quotes and "Alt text:" style
+/// prefixes, escapes characters that would break Markdown image syntax, and
+/// truncates at a word boundary to [`MAX_ALT_TEXT_CHARS`].
+pub fn clean_alt_text(text: &str) -> String {
+    let mut text = text.split_whitespace().collect::<Vec<_>>().join(" ");
+
+    for prefix in ["alt text:", "alt-text:", "alt:"] {
+        if text
+            .get(..prefix.len())
+            .is_some_and(|p| p.eq_ignore_ascii_case(prefix))
+        {
+            text = text[prefix.len()..].trim_start().to_string();
+        }
+    }
+
+    let text = text
+        .trim_matches(|c| matches!(c, '"' | '\'' | '`' | '“' | '”'))
+        .replace('[', "(")
+        .replace(']', ")");
+
+    if text.chars().count() <= MAX_ALT_TEXT_CHARS {
+        return text;
+    }
+
+    let truncated: String = text.chars().take(MAX_ALT_TEXT_CHARS).collect();
+    let cut = truncated
+        .rfind(' ')
+        .map(|i| &truncated[..i])
+        .unwrap_or(&truncated);
+    format!("{}…", cut.trim_end_matches([',', ';', ':', '.']))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_detect_image_format() {
+        assert_eq!(
+            detect_image_format(&[0x89, b'P', b'N', b'G', 0x0D, 0x0A, 0x1A, 0x0A, 0]),
+            Some(VisionImageFormat::Png)
+        );
+        assert_eq!(
+            detect_image_format(&[0xFF, 0xD8, 0xFF, 0xE0]),
+            Some(VisionImageFormat::Jpeg)
+        );
+        assert_eq!(
+            detect_image_format(b"GIF89a..."),
+            Some(VisionImageFormat::Gif)
+        );
+        assert_eq!(
+            detect_image_format(b"RIFF\0\0\0\0WEBPVP8 "),
+            Some(VisionImageFormat::Webp)
+        );
+        assert_eq!(detect_image_format(b"%PDF-1.7"), None);
+        assert_eq!(detect_image_format(&[]), None);
+    }
+
+    #[test]
+    fn test_build_prompt_modes() {
+        assert!(build_prompt(DescribeMode::Description, None).contains("in detail"));
+        assert_eq!(
+            build_prompt(DescribeMode::Description, Some("What color is the car?")),
+            "What color is the car?"
+        );
+
+        let alt = build_prompt(DescribeMode::AltText, Some("Focus on the chart."));
+        assert!(alt.contains("alt text"));
+        assert!(alt.ends_with("Focus on the chart."));
+        assert_eq!(
+            build_prompt(DescribeMode::AltText, Some("  ")),
+            build_prompt(DescribeMode::AltText, None)
+        );
+    }
+
+    #[test]
+    fn test_build_generate_request() {
+        let req = build_generate_request("llava", "Describe", "aGVsbG8=".to_string());
+        let json = serde_json::to_value(&req).unwrap();
+        assert_eq!(json["model"], "llava");
+        assert_eq!(json["images"][0], "aGVsbG8=");
+        assert_eq!(json["stream"], false);
+    }
+
+    #[test]
+    fn test_parse_generate_response() {
+        let ok: GenerateResponse =
+            serde_json::from_str(r#"{"model":"llava","response":"  A cat.\n","done":true}"#)
+                .unwrap();
+        assert_eq!(parse_generate_response(ok).unwrap(), "A cat.");
+
+        let err: GenerateResponse =
+            serde_json::from_str(r#"{"error":"model 'llava' not found"}"#).unwrap();
+        assert!(parse_generate_response(err)
+            .unwrap_err()
+            .contains("not found"));
+
+        let empty: GenerateResponse = serde_json::from_str(r#"{"response":" "}"#).unwrap();
+        assert!(parse_generate_response(empty).is_err());
+    }
+
+    #[test]
+    fn test_clean_alt_text() {
+        assert_eq!(
+            clean_alt_text("Alt text: \"A bar chart\n of sales\""),
+            "A bar chart of sales"
+        );
+        assert_eq!(clean_alt_text("Diagram [v2]"), "Diagram (v2)");
+    }
+
+    #[test]
+    fn test_clean_alt_text_truncates_at_word() {
+        let long = "word ".repeat(60);
+        let alt = clean_alt_text(&long);
+        assert!(alt.chars().count() <= MAX_ALT_TEXT_CHARS + 1);
+        assert!(alt.ends_with("word…"));
+    }
+}
@@ -0,0 +1,541 @@
+//! YouTube caption transcripts
+//!
+//! Pure functions for turning a YouTube watch page into a timestamped
+//! Markdown transcript: video ID extraction, caption track discovery from the
+//! page's `ytInitialPlayerResponse`, parsing of the `json3` caption format,
+//! and rendering. Caption cues are merged into paragraphs with
+//! [`crate::transcribe::merge_segments`].
+
+use serde::{Deserialize, Serialize};
+
+use crate::md::MdPaginationInfo;
+use crate::transcribe::{format_timestamp, merge_segments, MergeOptions, TranscriptSegment};
+
+/// Length of a YouTube video ID.
+const VIDEO_ID_LEN: usize = 11;
+
+/// A caption track listed in the player response.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct CaptionTrack {
+    /// Timed-text URL for this track.
+    #[serde(skip)]
+    pub base_url: String,
+    pub language_code: String,
+    pub name: String,
+    /// Whether the track is auto-generated (speech recognition).
+    pub is_generated: bool,
+}
+
+/// Video details and caption tracks from a watch page.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct VideoInfo {
+    pub video_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub author: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub length_seconds: Option<u64>,
+    pub tracks: Vec<CaptionTrack>,
+}
+
+/// Output of a transcript fetch.
+#[derive(Debug, Clone, Serialize)]
+pub struct YoutubeTranscriptOutput {
+    pub video_id: String,
+    pub url: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub author: Option<String>,
+    pub language: String,
+    pub is_generated: bool,
+    pub content: String,
+    pub pagination: MdPaginationInfo,
+}
+
+/// Extract a video ID from a YouTube URL or a bare ID.
+///
+/// Supports `watch?v=`, `youtu.be/`, `/shorts/`, `/embed/`, `/live/`, and
+/// `/v/` URLs.
+pub fn extract_video_id(input: &str) -> Option<String> {
+    let input = input.trim();
+    if is_video_id(input) {
+        return Some(input.to_string());
+    }
+
+    let without_scheme = input
+        .strip_prefix("https://")
+        .or_else(|| input.strip_prefix("http://"))
+        .unwrap_or(input);
+    let (host, rest) = without_scheme.split_once('/')?;
+    let host = host.trim_start_matches("www.").trim_start_matches("m.");
+
+    let candidate = if host == "youtu.be" {
+        rest.split(['?', '#', '/']).next()
+    } else if host.ends_with("youtube.com") || host.ends_with("youtube-nocookie.com") {
+        let (path, query) = rest.split_once('?').unwrap_or((rest, ""));
+        if path == "watch" {
+            query
+                .split(['&', '#'])
+                .find_map(|pair| pair.strip_prefix("v="))
+        } else {
+            ["shorts/", "embed/", "live/", "v/"]
+                .iter()
+                .find_map(|prefix| path.strip_prefix(prefix))
+                .and_then(|id| id.split(['/', '#']).next())
+        }
+    } else {
+        None
+    }?;
+
+    is_video_id(candidate).then(|| candidate.to_string())
+}
+
+fn is_video_id(s: &str) -> bool {
+    s.len() == VIDEO_ID_LEN
+        && s.chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+}
+
+/// Canonical watch URL for a video ID.
+pub fn watch_url(video_id: &str) -> String {
+    format!("https://www.youtube.com/watch?v={}", video_id)
+}
+
+/// Parse video details and caption tracks from a watch page's HTML.
+pub fn parse_watch_page(video_id: &str, html: &str) -> Result<VideoInfo, String> {
+    let player = extract_player_response(html)?;
+
+    if let Some(status) = player
+        .pointer("/playabilityStatus/status")
+        .and_then(|s| s.as_str())
+    {
+        if status != "OK" {
+            let reason = player
+                .pointer("/playabilityStatus/reason")
+                .and_then(|s| s.as_str())
+                .unwrap_or(status);
+            return Err(format!("Video {} is not playable: {}", video_id, reason));
+        }
+    }
+
+    let details = player.get("videoDetails");
+    let detail = |key: &str| {
+        details
+            .and_then(|d| d.get(key))
+            .and_then(|v| v.as_str())
+            .map(str::to_string)
+    };
+
+    let tracks = player
+        .pointer("/captions/playerCaptionsTracklistRenderer/captionTracks")
+        .and_then(|t| t.as_array())
+        .map(|tracks| tracks.iter().filter_map(parse_caption_track).collect())
+        .unwrap_or_default();
+
+    Ok(VideoInfo {
+        video_id: video_id.to_string(),
+        title: detail("title"),
+        author: detail("author"),
+        length_seconds: detail("lengthSeconds").and_then(|s| s.parse().ok()),
+        tracks,
+    })
+}
+
+/// Find and parse the `ytInitialPlayerResponse` object embedded in the page.
+fn extract_player_response(html: &str) -> Result<serde_json::Value, String> {
+    const MARKER: &str = "ytInitialPlayerResponse";
+
+    let mut search = html;
+    while let Some(pos) = search.find(MARKER) {
+        let after = &search[pos + MARKER.len()..];
+        let trimmed = after.trim_start();
+        if let Some(json) = trimmed.strip_prefix('=') {
+            // The object is followed by more script; parse only the first value.
+            let mut stream = serde_json::Deserializer::from_str(json.trim_start())
+                .into_iter::<serde_json::Value>();
+            if let Some(Ok(value)) = stream.next() {
+                if value.is_object() {
+                    return Ok(value);
+                }
+            }
+        }
+        search = after;
+    }
+
+    Err("Could not find player data in the YouTube page".to_string())
+}
+
+fn parse_caption_track(track: &serde_json::Value) -> Option<CaptionTrack> {
+    let base_url = track.get("baseUrl")?.as_str()?.to_string();
+    let language_code = track.get("languageCode")?.as_str()?.to_string();
+    let name = track
+        .pointer("/name/simpleText")
+        .and_then(|n| n.as_str())
+        .map(str::to_string)
+        .or_else(|| {
+            track.pointer("/name/runs").and_then(|runs| {
+                runs.as_array().map(|runs| {
+                    runs.iter()
+                        .filter_map(|r| r.get("text").and_then(|t| t.as_str()))
+                        .collect::<String>()
+                })
+            })
+        })
+        .unwrap_or_else(|| language_code.clone());
+    let is_generated = track.get("kind").and_then(|k| k.as_str()) == Some("asr");
+
+    Some(CaptionTrack {
+        base_url,
+        language_code,
+        name,
+        is_generated,
+    })
+}
+
+/// Pick a caption track.
+///
+/// With a language, an exact code match wins over a prefix match (`en`
+/// matches `en-US`); without one, the first track is used. Manually created
+/// tracks are preferred over auto-generated ones in both cases.
+pub fn select_track<'a>(
+    tracks: &'a [CaptionTrack],
+    language: Option<&str>,
+) -> Option<&'a CaptionTrack> {
+    let best = |candidates: Vec<&'a CaptionTrack>| {
+        candidates
+            .iter()
+            .find(|t| !t.is_generated)
+            .or_else(|| candidates.first())
+            .copied()
+    };
+
+    match language {
+        Some(lang) => {
+            let lang = lang.to_lowercase();
+            let exact: Vec<_> = tracks
+                .iter()
+                .filter(|t| t.language_code.to_lowercase() == lang)
+                .collect();
+            if !exact.is_empty() {
+                return best(exact);
+            }
+            best(
+                tracks
+                    .iter()
+                    .filter(|t| {
+                        let code = t.language_code.to_lowercase();
+                        code.split('-').next() == lang.split('-').next()
+                    })
+                    .collect(),
+            )
+        }
+        None => best(tracks.iter().collect()),
+    }
+}
+
+/// Timed-text URL for a track in `json3` format.
+pub fn json3_url(track: &CaptionTrack) -> String {
+    let base = track
+        .base_url
+        .split('&')
+        .filter(|p| !p.starts_with("fmt="))
+        .collect::<Vec<_>>()
+        .join("&");
+    format!("{}&fmt=json3", base)
+}
+
+#[derive(Debug, Deserialize)]
+struct Json3 {
+    #[serde(default)]
+    events: Vec<Json3Event>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct Json3Event {
+    #[serde(default)]
+    t_start_ms: u64,
+    #[serde(default)]
+    d_duration_ms: u64,
+    #[serde(default)]
+    segs: Vec<Json3Seg>,
+}
+
+#[derive(Debug, Deserialize)]
+struct Json3Seg {
+    #[serde(default)]
+    utf8: String,
+}
+
+/// Parse `json3` captions into timed segments.
+///
+/// Events without text (window styling, line breaks) are skipped.
+pub fn parse_json3(json: &str) -> Result<Vec<TranscriptSegment>, String> {
+    let captions: Json3 =
+        serde_json::from_str(json).map_err(|e| format!("Invalid caption data: {}", e))?;
+
+    Ok(captions
+        .events
+        .into_iter()
+        .filter_map(|event| {
+            let text: String = event.segs.iter().map(|s| s.utf8.as_str()).collect();
+            let text = decode_entities(&text.replace('\n', " "));
+            let text = text.split_whitespace().collect::<Vec<_>>().join(" ");
+            if text.is_empty() {
+                return None;
+            }
+            let start = event.t_start_ms as f64 / 1000.0;
+            Some(TranscriptSegment {
+                start,
+                end: start + event.d_duration_ms as f64 / 1000.0,
+                text,
+            })
+        })
+        .collect())
+}
+
+fn decode_entities(text: &str) -> String {
+    text.replace("&#39;", "'")
+        .replace("&quot;", "\"")
+        .replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&amp;", "&")
+}
+
+/// Render captions as Markdown paragraphs, each starting with a timestamp
+/// that links to that point in the video.
+///
+/// Auto-generated cues overlap, so their end times are clamped to the next
+/// cue's start before merging.
+pub fn render_transcript_markdown(
+    info: &VideoInfo,
+    track: &CaptionTrack,
+    segments: &[TranscriptSegment],
+    options: &MergeOptions,
+) -> String {
+    let mut clamped = segments.to_vec();
+    for i in 1..clamped.len() {
+        let next_start = clamped[i].start;
+        if clamped[i - 1].end > next_start {
+            clamped[i - 1].end = next_start;
+        }
+    }
+
+    let mut out = String::new();
+    if let Some(title) = &info.title {
+        out.push_str(&format!("# {}\n\n", title));
+    }
+    let mut meta = Vec::new();
+    if let Some(author) = &info.author {
+        meta.push(format!("- **Channel:** {}", author));
+    }
+    if let Some(length) = info.length_seconds {
+        meta.push(format!(
+            "- **Duration:** {}",
+            format_timestamp(length as f64)
+        ));
+    }
+    meta.push(format!(
+        "- **Captions:** {}{}",
+        track.name,
+        if track.is_generated {
+            " (auto-generated)"
+        } else {
+            ""
+        }
+    ));
+    meta.push(format!("- **URL:** {}", watch_url(&info.video_id)));
+    out.push_str(&meta.join("\n"));
+    out.push_str("\n\n");
+
+    for paragraph in merge_segments(&clamped, options) {
+        out.push_str(&format!(
+            "**[{}](https://youtu.be/{}?t={})** {}\n\n",
+            format_timestamp(paragraph.start),
+            info.video_id,
+            paragraph.start.floor() as u64,
+            paragraph.text
+        ));
+    }
+
+    out.trim_end().to_string() + "\n"
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn track(code: &str, generated: bool) -> CaptionTrack {
+        CaptionTrack {
+            base_url: format!("https://www.youtube.com/api/timedtext?v=x&lang={}", code),
+            language_code: code.to_string(),
+            name: code.to_string(),
+            is_generated: generated,
+        }
+    }
+
+    #[test]
+    fn test_extract_video_id() {
+        let id = Some("dQw4w9WgXcQ".to_string());
+        assert_eq!(extract_video_id("dQw4w9WgXcQ"), id);
+        assert_eq!(
+            extract_video_id("https://www.youtube.com/watch?v=dQw4w9WgXcQ&t=42s"),
+            id
+        );
+        assert_eq!(
+            extract_video_id("https://m.youtube.com/watch?feature=share&v=dQw4w9WgXcQ"),
+            id
+        );
+        assert_eq!(extract_video_id("https://youtu.be/dQw4w9WgXcQ?si=abc"), id);
+        assert_eq!(
+            extract_video_id("https://www.youtube.com/shorts/dQw4w9WgXcQ"),
+            id
+        );
+        assert_eq!(
+            extract_video_id("https://www.youtube-nocookie.com/embed/dQw4w9WgXcQ"),
+            id
+        );
+        assert_eq!(
+            extract_video_id("https://example.com/watch?v=dQw4w9WgXcQ"),
+            None
+        );
+        assert_eq!(extract_video_id("https://youtu.be/short"), None);
+    }
+
+    #[test]
+    fn test_parse_watch_page() {
+        let html = r#"<script>var ytInitialPlayerResponse = {
+            "playabilityStatus": {"status": "OK"},
+            "videoDetails": {"title": "Talk", "author": "Conf", "lengthSeconds": "125"},
+            "captions": {"playerCaptionsTracklistRenderer": {"captionTracks": [
+                {"baseUrl": "https://www.youtube.com/api/timedtext?v=abc&lang=en", "languageCode": "en",
+                 "name": {"simpleText": "English"}},
+                {"baseUrl": "https://www.youtube.com/api/timedtext?v=abc&lang=en&kind=asr", "languageCode": "en",
+                 "kind": "asr", "name": {"runs": [{"text": "English (auto-generated)"}]}}
+            ]}}
+        };var meta = {};</script>"#;
+        let info = parse_watch_page("abcdefghijk", html).unwrap();
+
+        assert_eq!(info.title.as_deref(), Some("Talk"));
+        assert_eq!(info.author.as_deref(), Some("Conf"));
+        assert_eq!(info.length_seconds, Some(125));
+        assert_eq!(info.tracks.len(), 2);
+        assert_eq!(info.tracks[0].name, "English");
+        assert!(!info.tracks[0].is_generated);
+        assert_eq!(info.tracks[1].name, "English (auto-generated)");
+        assert!(info.tracks[1].is_generated);
+    }
+
+    #[test]
+    fn test_parse_watch_page_errors() {
+        assert!(parse_watch_page("abcdefghijk", "<html></html>").is_err());
+
+        let unplayable = r#"ytInitialPlayerResponse = {"playabilityStatus":
+            {"status": "ERROR", "reason": "Video unavailable"}};"#;
+        let err = parse_watch_page("abcdefghijk", unplayable).unwrap_err();
+        assert!(err.contains("Video unavailable"));
+
+        let no_captions = r#"ytInitialPlayerResponse = {"videoDetails": {"title": "T"}};"#;
+        assert!(parse_watch_page("abcdefghijk", no_captions)
+            .unwrap()
+            .tracks
+            .is_empty());
+    }
+
+    #[test]
+    fn test_select_track() {
+        let tracks = vec![
+            track("en", true),
+            track("en-GB", false),
+            track("es", false),
+            track("de", true),
+        ];
+
+        assert_eq!(select_track(&tracks, None).unwrap().language_code, "en-GB");
+        assert_eq!(
+            select_track(&tracks, Some("es")).unwrap().language_code,
+            "es"
+        );
+        // Exact match wins even when auto-generated.
+        assert_eq!(
+            select_track(&tracks, Some("en")).unwrap().language_code,
+            "en"
+        );
+        assert_eq!(
+            select_track(&tracks, Some("EN-gb")).unwrap().language_code,
+            "en-GB"
+        );
+        assert_eq!(
+            select_track(&tracks, Some("de-AT")).unwrap().language_code,
+            "de"
+        );
+        assert!(select_track(&tracks, Some("fr")).is_none());
+        assert!(select_track(&[], None).is_none());
+    }
+
+    #[test]
+    fn test_json3_url() {
+        let mut t = track("en", false);
+        t.base_url = "https://www.youtube.com/api/timedtext?v=x&fmt=srv3&lang=en".to_string();
+        assert_eq!(
+            json3_url(&t),
+            "https://www.youtube.com/api/timedtext?v=x&lang=en&fmt=json3"
+        );
+    }
+
+    #[test]
+    fn test_parse_json3() {
+        let json = r#"{"events": [
+            {"tStartMs": 0, "dDurationMs": 5000, "id": 1, "wWinId": 1},
+            {"tStartMs": 120, "dDurationMs": 2000, "segs": [{"utf8": "Hello"}, {"utf8": " world"}]},
+            {"tStartMs": 2100, "dDurationMs": 10, "segs": [{"utf8": "\n"}]},
+            {"tStartMs": 2500, "dDurationMs": 1500, "segs": [{"utf8": "it&#39;s\nme"}]}
+        ]}"#;
+        let segments = parse_json3(json).unwrap();
+
+        assert_eq!(segments.len(), 2);
+        assert_eq!(segments[0].text, "Hello world");
+        assert_eq!(segments[0].start, 0.12);
+        assert_eq!(segments[1].text, "it's me");
+        assert_eq!(segments[1].end, 4.0);
+        assert!(parse_json3("not json").is_err());
+    }
+
+    #[test]
+    fn test_render_transcript_markdown() {
+        let info = VideoInfo {
+            video_id: "abcdefghijk".to_string(),
+            title: Some("Talk".to_string()),
+            author: Some("Conf".to_string()),
+            length_seconds: Some(95),
+            tracks: vec![],
+        };
+        let t = track("en", true);
+        let segments = vec![
+            TranscriptSegment {
+                start: 0.0,
+                end: 4.0,
+                text: "Hello".to_string(),
+            },
+            // Overlaps the previous cue, as auto-generated captions do.
+            TranscriptSegment {
+                start: 2.0,
+                end: 5.0,
+                text: "everyone.".to_string(),
+            },
+            TranscriptSegment {
+                start: 65.0,
+                end: 67.0,
+                text: "Questions?".to_string(),
+            },
+        ];
+        let md = render_transcript_markdown(&info, &t, &segments, &MergeOptions::default());
+
+        assert!(md.starts_with("# Talk\n\n- **Channel:** Conf\n- **Duration:** 01:35\n"));
+        assert!(md.contains("- **Captions:** en (auto-generated)\n"));
+        assert!(md.contains("**[00:00](https://youtu.be/abcdefghijk?t=0)** Hello everyone.\n\n"));
+        assert!(md.ends_with("**[01:05](https://youtu.be/abcdefghijk?t=65)** Questions?\n"));
+    }
+}
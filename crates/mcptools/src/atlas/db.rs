@@ -6,6 +6,7 @@ use mcptools_core::atlas::{
     extract_parent_paths, sort_tree_entries, ContentHash, DirectoryEntry, DirectoryPeekView,
     FileEntry, PeekView, Symbol, SymbolKind, TreeEntry, Visibility,
 };
+use mcptools_core::search::LocalMatch;
 use rusqlite::{params, Connection};
 
 pub struct Database {
@@ -163,6 +164,64 @@ impl Database {
         Ok(())
     }
 
+    /// Candidates for `search-all`: files whose path or short description,
+    /// and symbols whose name, contain one of the lowercase `terms`. At most
+    /// `limit` of each kind per term; ranking happens in the core.
+    pub fn search(&self, terms: &[String], limit: usize) -> Result<Vec<LocalMatch>> {
+        let mut files = self
+            .conn
+            .prepare(
+                "SELECT path, short_description FROM files
+                 WHERE lower(path) LIKE ?1 OR lower(coalesce(short_description, '')) LIKE ?1
+                 LIMIT ?2",
+            )
+            .wrap_err("preparing file search")?;
+        let mut symbols = self
+            .conn
+            .prepare(
+                "SELECT s.file_path, s.name, s.start_line, f.short_description
+                 FROM symbols s LEFT JOIN files f ON f.path = s.file_path
+                 WHERE lower(s.name) LIKE ?1
+                 LIMIT ?2",
+            )
+            .wrap_err("preparing symbol search")?;
+
+        let mut matches: Vec<LocalMatch> = Vec::new();
+        for term in terms {
+            let pattern = format!("%{term}%");
+            let found = files
+                .query_map(params![pattern, limit as i64], |row| {
+                    Ok(LocalMatch {
+                        path: row.get(0)?,
+                        description: row.get(1)?,
+                        symbol: None,
+                        line: None,
+                    })
+                })
+                .wrap_err("searching files")?
+                .chain(
+                    symbols
+                        .query_map(params![pattern, limit as i64], |row| {
+                            Ok(LocalMatch {
+                                path: row.get(0)?,
+                                symbol: Some(row.get(1)?),
+                                line: Some(row.get::<_, i64>(2)? as usize),
+                                description: row.get(3)?,
+                            })
+                        })
+                        .wrap_err("searching symbols")?,
+                )
+                .collect::<std::result::Result<Vec<_>, _>>()
+                .wrap_err("reading search results")?;
+            for m in found {
+                if !matches.contains(&m) {
+                    matches.push(m);
+                }
+            }
+        }
+        Ok(matches)
+    }
+
     /// Delete all data from every table.
     pub fn clear_all(&self) -> Result<()> {
         self.conn
@@ -7,6 +7,7 @@ use mcptools_core::atlassian::confluence::transform_search_results;
 pub use mcptools_core::atlassian::confluence::{
     ConfluenceSearchResponse, PageOutput, SearchOutput,
 };
+use mcptools_core::atlassian::deployment::{confluence_search_path, Deployment};
 
 /// Confluence commands
 #[derive(Debug, clap::Subcommand)]
@@ -39,23 +40,24 @@ pub struct SearchOptions {
 /// to the pure function in the core crate.
 pub async fn search_pages_data(query: String, limit: usize) -> Result<SearchOutput> {
     // Configure HTTP client (I/O setup)
-    let config = ConfluenceConfig::from_env()?;
+    let config = ConfluenceConfig::from_env().await?;
     let client = create_confluence_client(&config)?;
 
     // Build API URL (I/O configuration)
     let base_url = config.base_url.trim_end_matches('/');
-    let url = format!("{base_url}/wiki/api/v2/pages/search");
+    let url = format!("{base_url}{}", confluence_search_path(config.deployment));
 
     let limit_str = limit.to_string();
+    // v2 (Cloud) selects the body format directly; v1 (Data Center) expands it
+    let body_param = match config.deployment {
+        Deployment::Cloud => ("bodyFormat", "view"),
+        Deployment::DataCenter => ("expand", "body.view"),
+    };
 
     // Perform HTTP request (I/O operation)
     let response = client
         .get(&url)
-        .query(&[
-            ("cql", query.as_str()),
-            ("limit", &limit_str),
-            ("bodyFormat", "view"),
-        ])
+        .query(&[("cql", query.as_str()), ("limit", &limit_str), body_param])
         .send()
         .await
         .map_err(|e| eyre!("Failed to send request to Confluence: {}", e))?;
@@ -1,9 +1,9 @@
 use std::path::PathBuf;
 
-use base64::Engine;
 use colored::Colorize;
 use mcptools_core::atlassian::jira::{
-    transform_attachment_response, AttachmentOutput, JiraAttachmentResponse,
+    attachment_file_name, attachment_file_names, transform_attachment_response, AttachmentOutput,
+    JiraAttachmentResponse,
 };
 use serde::Deserialize;
 
@@ -58,6 +58,36 @@ pub enum AttachmentCommands {
     },
 }
 
+/// Options for `jira attach`
+#[derive(Debug, clap::Args, Clone)]
+pub struct AttachOptions {
+    /// Issue key (e.g., PROJ-123)
+    pub issue_key: String,
+
+    /// Files to upload
+    #[arg(required = true)]
+    pub files: Vec<PathBuf>,
+
+    /// Output as JSON
+    #[arg(long)]
+    pub json: bool,
+}
+
+/// Options for `jira attachments`
+#[derive(Debug, clap::Args, Clone)]
+pub struct AttachmentsOptions {
+    /// Issue key (e.g., PROJ-123)
+    pub issue_key: String,
+
+    /// Download every attachment into this directory (created if missing)
+    #[arg(long, value_name = "DIR")]
+    pub download: Option<PathBuf>,
+
+    /// Output as JSON
+    #[arg(long)]
+    pub json: bool,
+}
+
 // --- Local deserialization structs for the issue-with-attachments response ---
 
 #[derive(Debug, Deserialize)]
@@ -76,10 +106,10 @@ struct AttachmentFields {
 /// Fetch all raw attachment metadata from a Jira issue.
 async fn fetch_issue_attachments(
     client: &reqwest::Client,
-    base_url: &str,
+    api_base: &str,
     issue_key: &str,
 ) -> Result<Vec<JiraAttachmentResponse>> {
-    let url = format!("{base_url}/rest/api/3/issue/{issue_key}?fields=attachment");
+    let url = format!("{api_base}/issue/{issue_key}?fields=attachment");
 
     let response = client
         .get(&url)
@@ -99,11 +129,11 @@ async fn fetch_issue_attachments(
 
 /// List all attachments on a Jira ticket.
 pub async fn list_attachments_data(issue_key: String) -> Result<Vec<AttachmentOutput>> {
-    let config = JiraConfig::from_env()?;
+    let config = JiraConfig::from_env().await?;
     let client = create_jira_client(&config)?;
-    let base_url = config.base_url.trim_end_matches('/');
+    let api_base = config.api_base();
 
-    let raw = fetch_issue_attachments(&client, base_url, &issue_key).await?;
+    let raw = fetch_issue_attachments(&client, &api_base, &issue_key).await?;
     Ok(transform_attachment_response(raw))
 }
 
@@ -113,16 +143,53 @@ pub async fn download_attachment_data(
     attachment_id: String,
     output: Option<PathBuf>,
 ) -> Result<PathBuf> {
-    let config = JiraConfig::from_env()?;
+    let config = JiraConfig::from_env().await?;
     let client = create_jira_client(&config)?;
-    let base_url = config.base_url.trim_end_matches('/');
+    let api_base = config.api_base();
 
-    let all = fetch_issue_attachments(&client, base_url, &issue_key).await?;
+    let all = fetch_issue_attachments(&client, &api_base, &issue_key).await?;
     let attachment = all
         .into_iter()
         .find(|a| a.id == attachment_id)
         .ok_or_else(|| eyre!("Attachment {attachment_id} not found on {issue_key}"))?;
 
+    let out_path =
+        output.unwrap_or_else(|| std::env::temp_dir().join(attachment_file_name(&attachment)));
+    save_attachment(&client, &attachment, &out_path).await?;
+
+    Ok(out_path)
+}
+
+/// Download every attachment on a ticket into `dir`. Returns the saved
+/// paths in attachment order.
+pub async fn download_all_attachments_data(
+    issue_key: String,
+    dir: PathBuf,
+) -> Result<Vec<PathBuf>> {
+    let config = JiraConfig::from_env().await?;
+    let client = create_jira_client(&config)?;
+    let api_base = config.api_base();
+
+    let all = fetch_issue_attachments(&client, &api_base, &issue_key).await?;
+    tokio::fs::create_dir_all(&dir)
+        .await
+        .map_err(|e| eyre!("Failed to create {}: {e}", dir.display()))?;
+
+    let mut paths = Vec::with_capacity(all.len());
+    for (attachment, name) in all.iter().zip(attachment_file_names(&all)) {
+        let out_path = dir.join(name);
+        save_attachment(&client, attachment, &out_path).await?;
+        paths.push(out_path);
+    }
+    Ok(paths)
+}
+
+/// Fetch an attachment's content and write it to `out_path`.
+async fn save_attachment(
+    client: &reqwest::Client,
+    attachment: &JiraAttachmentResponse,
+    out_path: &std::path::Path,
+) -> Result<()> {
     let response = client
         .get(&attachment.content)
         .send()
@@ -136,13 +203,9 @@ pub async fn download_attachment_data(
         .await
         .map_err(|e| eyre!("Failed to read attachment content: {e}"))?;
 
-    let out_path = output.unwrap_or_else(|| std::env::temp_dir().join(&attachment.filename));
-
-    tokio::fs::write(&out_path, &bytes)
+    tokio::fs::write(out_path, &bytes)
         .await
-        .map_err(|e| eyre!("Failed to write file to {}: {e}", out_path.display()))?;
-
-    Ok(out_path)
+        .map_err(|e| eyre!("Failed to write file to {}: {e}", out_path.display()))
 }
 
 /// Upload files as attachments to a Jira ticket.
@@ -150,8 +213,8 @@ pub async fn upload_attachment_data(
     issue_key: String,
     files: Vec<PathBuf>,
 ) -> Result<Vec<AttachmentOutput>> {
-    let config = JiraConfig::from_env()?;
-    let base_url = config.base_url.trim_end_matches('/');
+    let config = JiraConfig::from_env().await?;
+    let api_base = config.api_base();
 
     if files.is_empty() {
         return Err(eyre!("At least one file path is required for upload"));
@@ -166,12 +229,10 @@ pub async fn upload_attachment_data(
 
     // Build a client WITHOUT Content-Type: application/json
     // (multipart sets its own Content-Type boundary)
-    let auth_value = base64::engine::general_purpose::STANDARD
-        .encode(format!("{}:{}", config.email, config.api_token));
     let mut headers = reqwest::header::HeaderMap::new();
     headers.insert(
         reqwest::header::AUTHORIZATION,
-        reqwest::header::HeaderValue::from_str(&format!("Basic {auth_value}"))
+        reqwest::header::HeaderValue::from_str(&config.authorization())
             .map_err(|e| eyre!("Invalid auth header: {e}"))?,
     );
     // Required by Jira to bypass XSRF protection
@@ -208,7 +269,7 @@ pub async fn upload_attachment_data(
         form = form.part("file", part);
     }
 
-    let url = format!("{base_url}/rest/api/3/issue/{issue_key}/attachments");
+    let url = format!("{api_base}/issue/{issue_key}/attachments");
 
     let response = client
         .post(&url)
@@ -263,31 +324,7 @@ pub async fn handler(cmd: AttachmentCommands) -> Result<()> {
     match cmd {
         AttachmentCommands::List { issue_key, json } => {
             let attachments = list_attachments_data(issue_key).await?;
-
-            if json {
-                std::println!("{}", serde_json::to_string_pretty(&attachments)?);
-            } else if attachments.is_empty() {
-                std::println!("No attachments found.");
-            } else {
-                let mut table = crate::prelude::new_table();
-                table.add_row(prettytable::row![
-                    "ID".bold().cyan(),
-                    "Filename".bold().cyan(),
-                    "Size".bold().cyan(),
-                    "Type".bold().cyan(),
-                    "Created".bold().cyan()
-                ]);
-                for att in &attachments {
-                    table.add_row(prettytable::row![
-                        att.id.green().to_string(),
-                        att.filename.bright_white().to_string(),
-                        att.size_human.bright_yellow().to_string(),
-                        att.mime_type.bright_blue().to_string(),
-                        att.created.bright_black().to_string()
-                    ]);
-                }
-                table.printstd();
-            }
+            display_attachments(&attachments, json)?;
         }
 
         AttachmentCommands::Download {
@@ -316,22 +353,92 @@ pub async fn handler(cmd: AttachmentCommands) -> Result<()> {
             json,
         } => {
             let uploads = upload_attachment_data(issue_key, files).await?;
+            display_uploads(&uploads, json)?;
+        }
+    }
 
-            if json {
-                std::println!("{}", serde_json::to_string_pretty(&uploads)?);
-            } else {
-                std::println!(
-                    "{}",
-                    format!("Uploaded {} attachment(s):", uploads.len())
-                        .green()
-                        .bold()
-                );
-                for att in &uploads {
-                    std::println!("  - {} ({})", att.filename.bright_white(), att.size_human);
-                }
-            }
+    Ok(())
+}
+
+/// Handle `jira attach`.
+pub async fn attach_handler(options: AttachOptions) -> Result<()> {
+    let uploads = upload_attachment_data(options.issue_key, options.files).await?;
+    display_uploads(&uploads, options.json)
+}
+
+/// Handle `jira attachments`, downloading them all when `--download` is set.
+pub async fn attachments_handler(options: AttachmentsOptions) -> Result<()> {
+    let Some(dir) = options.download else {
+        let attachments = list_attachments_data(options.issue_key).await?;
+        return display_attachments(&attachments, options.json);
+    };
+
+    let paths = download_all_attachments_data(options.issue_key, dir).await?;
+    if options.json {
+        let paths: Vec<String> = paths.iter().map(|p| p.display().to_string()).collect();
+        std::println!(
+            "{}",
+            serde_json::to_string_pretty(&serde_json::json!({ "paths": paths }))?
+        );
+    } else if paths.is_empty() {
+        std::println!("No attachments found.");
+    } else {
+        std::println!(
+            "{}",
+            format!("Downloaded {} attachment(s):", paths.len())
+                .green()
+                .bold()
+        );
+        for path in &paths {
+            std::println!("  - {}", path.display());
+        }
+    }
+    Ok(())
+}
+
+/// Print attachment metadata as a table, or as JSON.
+fn display_attachments(attachments: &[AttachmentOutput], json: bool) -> Result<()> {
+    if json {
+        std::println!("{}", serde_json::to_string_pretty(attachments)?);
+    } else if attachments.is_empty() {
+        std::println!("No attachments found.");
+    } else {
+        let mut table = crate::prelude::new_table();
+        table.add_row(prettytable::row![
+            "ID".bold().cyan(),
+            "Filename".bold().cyan(),
+            "Size".bold().cyan(),
+            "Type".bold().cyan(),
+            "Created".bold().cyan()
+        ]);
+        for att in attachments {
+            table.add_row(prettytable::row![
+                att.id.green().to_string(),
+                att.filename.bright_white().to_string(),
+                att.size_human.bright_yellow().to_string(),
+                att.mime_type.bright_blue().to_string(),
+                att.created.bright_black().to_string()
+            ]);
         }
+        table.printstd();
     }
+    Ok(())
+}
 
+/// Print the attachments an upload created, or them as JSON.
+fn display_uploads(uploads: &[AttachmentOutput], json: bool) -> Result<()> {
+    if json {
+        std::println!("{}", serde_json::to_string_pretty(uploads)?);
+    } else {
+        std::println!(
+            "{}",
+            format!("Uploaded {} attachment(s):", uploads.len())
+                .green()
+                .bold()
+        );
+        for att in uploads {
+            std::println!("  - {} ({})", att.filename.bright_white(), att.size_human);
+        }
+    }
     Ok(())
 }
@@ -0,0 +1,118 @@
+//! Board listing via the Jira Agile API
+
+use clap::Args;
+use colored::Colorize;
+use mcptools_core::atlassian::jira::{
+    transform_board_list_response, BoardListOutput, JiraBoardListResponse,
+};
+
+use crate::atlassian::{create_jira_client, JiraConfig};
+use crate::prelude::*;
+
+/// Options for listing boards
+#[derive(Args, Debug, Clone)]
+pub struct BoardsOptions {
+    /// Only boards for this project key or ID
+    #[arg(long)]
+    pub project: Option<String>,
+
+    /// Only boards of this type: scrum, kanban, or simple
+    #[arg(long = "type", value_name = "TYPE")]
+    pub board_type: Option<String>,
+
+    /// Only boards whose name contains this text
+    #[arg(long)]
+    pub name: Option<String>,
+
+    /// Output as JSON
+    #[arg(long)]
+    pub json: bool,
+}
+
+// --- Data functions (public, used by CLI and MCP) ---
+
+/// List the Jira boards visible to the user, following every page.
+pub async fn list_boards_data(
+    project: Option<String>,
+    board_type: Option<String>,
+    name: Option<String>,
+) -> Result<BoardListOutput> {
+    let config = JiraConfig::from_env().await?;
+    let client = create_jira_client(&config)?;
+    let base_url = config.base_url.trim_end_matches('/');
+
+    let mut filters = Vec::new();
+    if let Some(project) = project {
+        filters.push(("projectKeyOrId", project));
+    }
+    if let Some(board_type) = board_type {
+        filters.push(("type", board_type));
+    }
+    if let Some(name) = name {
+        filters.push(("name", name));
+    }
+
+    let mut pages = Vec::new();
+    let mut start_at = 0;
+    loop {
+        let response = client
+            .get(format!("{base_url}/rest/agile/1.0/board"))
+            .query(&filters)
+            .query(&[("startAt", start_at)])
+            .send()
+            .await
+            .map_err(|e| eyre!("Failed to fetch boards: {e}"))?;
+
+        if !response.status().is_success() {
+            let status = response.status();
+            let body = response.text().await.unwrap_or_default();
+            return Err(eyre!("Failed to list boards [{}]: {}", status, body));
+        }
+
+        let page: JiraBoardListResponse = response
+            .json()
+            .await
+            .map_err(|e| eyre!("Failed to parse board response: {e}"))?;
+
+        start_at = page.start_at + page.values.len() as u64;
+        let done = page.is_last || page.values.is_empty();
+        pages.push(page);
+        if done {
+            break;
+        }
+    }
+
+    Ok(transform_board_list_response(pages))
+}
+
+// --- CLI handler ---
+
+/// Handle the boards command.
+pub async fn handler(options: BoardsOptions) -> Result<()> {
+    let boards = list_boards_data(options.project, options.board_type, options.name).await?;
+
+    if options.json {
+        std::println!("{}", serde_json::to_string_pretty(&boards)?);
+    } else if boards.boards.is_empty() {
+        std::println!("No boards found.");
+    } else {
+        let mut table = new_table();
+        table.add_row(prettytable::row![
+            "ID".bold().cyan(),
+            "Name".bold().cyan(),
+            "Type".bold().cyan(),
+            "Project".bold().cyan()
+        ]);
+        for b in &boards.boards {
+            table.add_row(prettytable::row![
+                b.id.to_string().green(),
+                b.name.bright_white(),
+                b.board_type.bright_yellow(),
+                b.project_key.as_deref().unwrap_or("-").bright_black()
+            ]);
+        }
+        table.printstd();
+    }
+
+    Ok(())
+}
@@ -0,0 +1,188 @@
+//! Post templated comments to every issue matching a JQL query
+
+use colored::Colorize;
+use mcptools_core::atlassian::jira::{
+    render_bulk_comments, BulkCommentOutput, BulkCommentResult, IssueOutput,
+};
+use mcptools_core::template::parse_vars;
+use serde::Deserialize;
+
+use super::{add_comment_data, search_issues_data};
+use crate::prelude::*;
+
+/// Options for bulk commenting
+#[derive(Debug, clap::Args, Deserialize, Clone)]
+pub struct BulkCommentOptions {
+    /// JQL query selecting the issues to comment on
+    #[arg(long)]
+    pub jql: String,
+
+    /// Comment template (markdown). Placeholders: {key}, {summary}, {status}, {assignee}, and any --var
+    #[arg(long, short = 't')]
+    pub template: String,
+
+    /// Template variable as NAME=VALUE (repeatable)
+    #[arg(long = "var", value_name = "NAME=VALUE")]
+    #[serde(default)]
+    pub vars: Vec<String>,
+
+    /// Maximum number of issues to comment on
+    #[arg(long, default_value = "50")]
+    pub limit: usize,
+
+    /// Delay between comments in milliseconds
+    #[arg(long, default_value = "500")]
+    pub delay_ms: u64,
+
+    /// Render comments without posting them
+    #[arg(long)]
+    #[serde(default)]
+    pub dry_run: bool,
+
+    /// Output as JSON
+    #[arg(long)]
+    #[serde(default)]
+    pub json: bool,
+}
+
+/// Fetch up to `limit` issues matching `jql`, following pagination tokens.
+async fn fetch_matching_issues(jql: &str, limit: usize) -> Result<Vec<IssueOutput>> {
+    let mut issues: Vec<IssueOutput> = Vec::new();
+    let mut next_page = None;
+
+    while issues.len() < limit {
+        let page = search_issues_data(jql.to_string(), limit - issues.len(), next_page).await?;
+        let fetched = page.issues.len();
+        for issue in page.issues {
+            if !issues.iter().any(|i| i.key == issue.key) {
+                issues.push(issue);
+            }
+        }
+        next_page = page.next_page_token;
+        if fetched == 0 || next_page.is_none() {
+            break;
+        }
+    }
+
+    issues.truncate(limit);
+    Ok(issues)
+}
+
+/// Render and post one comment per matching issue.
+///
+/// All comments are rendered before anything is posted, so a template error
+/// aborts the run without side effects. Posting continues past individual
+/// failures; each issue's outcome is reported in the results.
+pub async fn bulk_comment_data(options: BulkCommentOptions) -> Result<BulkCommentOutput> {
+    let vars = parse_vars(&options.vars).map_err(|e| eyre!(e))?;
+    let issues = fetch_matching_issues(&options.jql, options.limit).await?;
+    let rendered = render_bulk_comments(&issues, &options.template, &vars).map_err(|e| eyre!(e))?;
+
+    let mut results = Vec::with_capacity(rendered.len());
+    for (index, (ticket_key, body)) in rendered.into_iter().enumerate() {
+        if options.dry_run {
+            results.push(BulkCommentResult {
+                ticket_key,
+                success: true,
+                body,
+                comment_id: None,
+                error: None,
+            });
+            continue;
+        }
+
+        if index > 0 && options.delay_ms > 0 {
+            tokio::time::sleep(std::time::Duration::from_millis(options.delay_ms)).await;
+        }
+
+        let result = match add_comment_data(ticket_key.clone(), body.clone()).await {
+            Ok(comment) => BulkCommentResult {
+                ticket_key,
+                success: true,
+                body,
+                comment_id: Some(comment.comment_id),
+                error: None,
+            },
+            Err(e) => BulkCommentResult {
+                ticket_key,
+                success: false,
+                body,
+                comment_id: None,
+                error: Some(e.to_string()),
+            },
+        };
+        results.push(result);
+    }
+
+    Ok(BulkCommentOutput::new(
+        options.jql,
+        options.dry_run,
+        results,
+    ))
+}
+
+/// Display bulk comment results as a formatted CLI table.
+fn display_results(output: &BulkCommentOutput) {
+    if output.results.is_empty() {
+        std::println!("No issues matched the query.");
+        return;
+    }
+
+    let mut table = new_table();
+    table.add_row(prettytable::row![
+        "Issue".bold().cyan(),
+        "Status".bold().cyan(),
+        "Comment".bold().cyan()
+    ]);
+
+    for r in &output.results {
+        let status = if output.dry_run {
+            "preview".bright_black().to_string()
+        } else if r.success {
+            "✓".green().bold().to_string()
+        } else {
+            "✗".red().bold().to_string()
+        };
+        let detail = match (&r.error, output.dry_run) {
+            (Some(error), _) => error.red().to_string(),
+            (None, true) => r.body.chars().take(60).collect::<String>(),
+            (None, false) => r
+                .comment_id
+                .clone()
+                .unwrap_or_default()
+                .bright_black()
+                .to_string(),
+        };
+        table.add_row(prettytable::row![r.ticket_key.cyan(), status, detail]);
+    }
+
+    table.printstd();
+
+    if output.dry_run {
+        std::println!(
+            "\n{} {} comments rendered (dry run, nothing posted)",
+            "ℹ".bright_blue(),
+            output.results.len()
+        );
+    } else {
+        std::println!(
+            "\n{} posted, {} failed",
+            output.succeeded.to_string().green().bold(),
+            output.failed.to_string().red().bold()
+        );
+    }
+}
+
+/// Handle the bulk-comment command.
+pub async fn handler(options: BulkCommentOptions) -> Result<()> {
+    let json = options.json;
+    let output = bulk_comment_data(options).await?;
+
+    if json {
+        std::println!("{}", serde_json::to_string_pretty(&output)?);
+    } else {
+        display_results(&output);
+    }
+
+    Ok(())
+}
@@ -0,0 +1,225 @@
+//! Update many tickets from a CSV or JSON file
+
+use std::path::PathBuf;
+
+use colored::Colorize;
+use futures::StreamExt;
+use mcptools_core::atlassian::jira::{
+    parse_bulk_update_csv, parse_bulk_update_json, BulkUpdateOutput, BulkUpdateResult,
+    BulkUpdateRow, UpdateOutput,
+};
+
+use super::update::{update_ticket_data, UpdateOptions};
+use crate::prelude::*;
+
+/// Options for bulk updating tickets
+#[derive(Debug, clap::Args, Clone)]
+#[command(after_help = "FILE FORMAT:
+  CSV with a header row, or a JSON array of objects with the same keys.
+  Columns: key (required), status, priority, type, assignee, summary,
+  description, due_date, components, add_labels, remove_labels.
+  Empty cells are left unchanged; list columns separate values with ';'.
+
+  key,status,assignee,add_labels
+  PROJ-1,In Progress,me,backend;urgent
+  PROJ-2,Done,,")]
+pub struct BulkUpdateOptions {
+    /// CSV or JSON file of updates, one row per ticket
+    #[arg(long, short)]
+    pub file: PathBuf,
+
+    /// Input format (default: from the file extension, else CSV)
+    #[arg(long, value_parser = ["csv", "json"])]
+    pub format: Option<String>,
+
+    /// Maximum number of tickets updated at once
+    #[arg(long, default_value = "4")]
+    pub concurrency: usize,
+
+    /// Validate the file and show the planned updates without applying them
+    #[arg(long)]
+    pub dry_run: bool,
+
+    /// Output as JSON
+    #[arg(long)]
+    pub json: bool,
+}
+
+impl BulkUpdateOptions {
+    /// Whether the file is JSON, by --format or by its extension
+    fn is_json(&self) -> bool {
+        match self.format.as_deref() {
+            Some(format) => format == "json",
+            None => self
+                .file
+                .extension()
+                .is_some_and(|ext| ext.eq_ignore_ascii_case("json")),
+        }
+    }
+}
+
+/// Read and validate a bulk update file. Fails listing every invalid row.
+pub fn read_bulk_update_file(options: &BulkUpdateOptions) -> Result<Vec<BulkUpdateRow>> {
+    let content = std::fs::read_to_string(&options.file)
+        .map_err(|e| eyre!("Failed to read {}: {e}", options.file.display()))?;
+
+    if options.is_json() {
+        let value: serde_json::Value = serde_json::from_str(&content)
+            .map_err(|e| eyre!("Failed to parse {}: {e}", options.file.display()))?;
+        parse_bulk_update_json(&value)
+    } else {
+        parse_bulk_update_csv(&content)
+    }
+    .map_err(|e| eyre!("Invalid bulk update file:\n{e}"))
+}
+
+/// The single-ticket update options for one row
+fn row_update_options(row: &BulkUpdateRow) -> UpdateOptions {
+    UpdateOptions {
+        ticket_key: row.key.clone(),
+        status: row.status.clone(),
+        priority: row.priority.clone(),
+        issue_type: row.issue_type.clone(),
+        assignee: row.assignee.clone(),
+        description: row.description.clone(),
+        summary: row.summary.clone(),
+        due_date: row.due_date.clone(),
+        components: row.components.clone(),
+        add_labels: row.add_labels.clone(),
+        remove_labels: row.remove_labels.clone(),
+        fields: Vec::new(),
+        sprint: None,
+        board: None,
+        json: false,
+    }
+}
+
+// --- Data functions (public, used by CLI and MCP) ---
+
+/// Apply validated rows, at most `concurrency` tickets at a time.
+///
+/// Every row runs even when others fail; results keep the input order. In a
+/// dry run nothing is sent and every row is reported as planned.
+pub async fn bulk_update_data(
+    rows: Vec<BulkUpdateRow>,
+    concurrency: usize,
+    dry_run: bool,
+) -> Result<BulkUpdateOutput> {
+    if dry_run {
+        let results = rows
+            .into_iter()
+            .map(|row| BulkUpdateResult {
+                row: row.row,
+                success: true,
+                update: UpdateOutput {
+                    fields_updated: row.planned_fields(),
+                    ticket_key: row.key,
+                    partial_failure: false,
+                },
+                error: None,
+            })
+            .collect();
+        return Ok(BulkUpdateOutput::new(true, results));
+    }
+
+    let results = futures::stream::iter(rows)
+        .map(|row| async move {
+            match update_ticket_data(row_update_options(&row)).await {
+                Ok(update) => BulkUpdateResult {
+                    row: row.row,
+                    success: !update.partial_failure,
+                    update,
+                    error: None,
+                },
+                Err(e) => BulkUpdateResult {
+                    row: row.row,
+                    success: false,
+                    update: UpdateOutput {
+                        ticket_key: row.key,
+                        fields_updated: Vec::new(),
+                        partial_failure: true,
+                    },
+                    error: Some(e.to_string()),
+                },
+            }
+        })
+        .buffered(concurrency.max(1))
+        .collect()
+        .await;
+
+    Ok(BulkUpdateOutput::new(false, results))
+}
+
+// --- Display functions ---
+
+/// Display per-row results as a formatted CLI table.
+fn display_results(output: &BulkUpdateOutput) {
+    let mut table = new_table();
+    table.add_row(prettytable::row![
+        "Row".bold().cyan(),
+        "Issue".bold().cyan(),
+        "Status".bold().cyan(),
+        "Details".bold().cyan()
+    ]);
+
+    for r in &output.results {
+        let status = if output.dry_run {
+            "planned".bright_black().to_string()
+        } else if r.success {
+            "✓".green().bold().to_string()
+        } else {
+            "✗".red().bold().to_string()
+        };
+        let detail = match &r.error {
+            Some(error) => error.red().to_string(),
+            None => r
+                .update
+                .fields_updated
+                .iter()
+                .map(|f| match &f.error {
+                    Some(error) => format!("{}: {}", f.field, error).red().to_string(),
+                    None => format!("{}={}", f.field, f.value.as_deref().unwrap_or("")),
+                })
+                .collect::<Vec<_>>()
+                .join(", "),
+        };
+        table.add_row(prettytable::row![
+            r.row.to_string().bright_black(),
+            r.update.ticket_key.cyan(),
+            status,
+            detail
+        ]);
+    }
+
+    table.printstd();
+
+    if output.dry_run {
+        std::println!(
+            "\n{} {} rows validated (dry run, nothing updated)",
+            "ℹ".bright_blue(),
+            output.results.len()
+        );
+    } else {
+        std::println!(
+            "\n{} updated, {} failed",
+            output.succeeded.to_string().green().bold(),
+            output.failed.to_string().red().bold()
+        );
+    }
+}
+
+// --- CLI handler ---
+
+/// Handle the bulk-update command.
+pub async fn handler(options: BulkUpdateOptions) -> Result<()> {
+    let rows = read_bulk_update_file(&options)?;
+    let output = bulk_update_data(rows, options.concurrency, options.dry_run).await?;
+
+    if options.json {
+        std::println!("{}", serde_json::to_string_pretty(&output)?);
+    } else {
+        display_results(&output);
+    }
+
+    Ok(())
+}
@@ -2,7 +2,7 @@
 
 use colored::Colorize;
 use mcptools_core::atlassian::jira::{
-    markdown_to_adf, transform_comment_list_response, transform_comment_response, CommentOutput,
+    rich_text_payload, transform_comment_list_response, transform_comment_response, CommentOutput,
     JiraComment,
 };
 use serde::Deserialize;
@@ -83,13 +83,12 @@ struct JiraCommentListResponse {
 
 /// Add a new comment to a Jira ticket.
 pub async fn add_comment_data(issue_key: String, body: String) -> Result<CommentOutput> {
-    let config = JiraConfig::from_env()?;
+    let config = JiraConfig::from_env().await?;
     let client = create_jira_client(&config)?;
-    let base_url = config.base_url.trim_end_matches('/');
+    let api_base = config.api_base();
 
-    let adf_body = markdown_to_adf(&body);
-    let payload = serde_json::json!({ "body": adf_body });
-    let url = format!("{base_url}/rest/api/3/issue/{issue_key}/comment");
+    let payload = serde_json::json!({ "body": rich_text_payload(config.flavor(), &body) });
+    let url = format!("{api_base}/issue/{issue_key}/comment");
 
     let response = client
         .post(&url)
@@ -110,11 +109,11 @@ pub async fn add_comment_data(issue_key: String, body: String) -> Result<Comment
 
 /// List all comments on a Jira ticket.
 pub async fn list_comments_data(issue_key: String) -> Result<Vec<CommentOutput>> {
-    let config = JiraConfig::from_env()?;
+    let config = JiraConfig::from_env().await?;
     let client = create_jira_client(&config)?;
-    let base_url = config.base_url.trim_end_matches('/');
+    let api_base = config.api_base();
 
-    let url = format!("{base_url}/rest/api/3/issue/{issue_key}/comment");
+    let url = format!("{api_base}/issue/{issue_key}/comment");
 
     let response = client
         .get(&url)
@@ -138,13 +137,12 @@ pub async fn update_comment_data(
     comment_id: String,
     body: String,
 ) -> Result<CommentOutput> {
-    let config = JiraConfig::from_env()?;
+    let config = JiraConfig::from_env().await?;
     let client = create_jira_client(&config)?;
-    let base_url = config.base_url.trim_end_matches('/');
+    let api_base = config.api_base();
 
-    let adf_body = markdown_to_adf(&body);
-    let payload = serde_json::json!({ "body": adf_body });
-    let url = format!("{base_url}/rest/api/3/issue/{issue_key}/comment/{comment_id}");
+    let payload = serde_json::json!({ "body": rich_text_payload(config.flavor(), &body) });
+    let url = format!("{api_base}/issue/{issue_key}/comment/{comment_id}");
 
     let response = client
         .put(&url)
@@ -165,11 +163,11 @@ pub async fn update_comment_data(
 
 /// Delete a comment from a Jira ticket.
 pub async fn delete_comment_data(issue_key: String, comment_id: String) -> Result<()> {
-    let config = JiraConfig::from_env()?;
+    let config = JiraConfig::from_env().await?;
     let client = create_jira_client(&config)?;
-    let base_url = config.base_url.trim_end_matches('/');
+    let api_base = config.api_base();
 
-    let url = format!("{base_url}/rest/api/3/issue/{issue_key}/comment/{comment_id}");
+    let url = format!("{api_base}/issue/{issue_key}/comment/{comment_id}");
 
     let response = client
         .delete(&url)
@@ -2,11 +2,10 @@
 
 use clap::Args;
 use colored::Colorize;
-use mcptools_core::atlassian::jira::{
-    markdown_to_adf, parse_assignee_identifier, AssigneeIdentifier, TicketOutput,
-};
+use mcptools_core::atlassian::jira::{build_create_payload, NewIssue, TicketOutput};
 use serde::Deserialize;
 
+use super::update::lookup_assignee;
 use crate::atlassian::{create_jira_client, JiraConfig};
 use crate::prelude::*;
 
@@ -14,9 +13,10 @@ use crate::prelude::*;
 #[derive(Args, Debug, Clone)]
 pub struct CreateOptions {
     /// Summary/title of the ticket (required)
+    #[arg(long)]
     pub summary: String,
 
-    /// Description of the ticket
+    /// Description of the ticket, in markdown
     #[arg(long)]
     pub description: Option<String>,
 
@@ -24,10 +24,19 @@ pub struct CreateOptions {
     #[arg(long, default_value = "PROD")]
     pub project: String,
 
-    /// Issue type (defaults to Task)
-    #[arg(long, default_value = "Task")]
+    /// Issue type, e.g. Story, Bug, Epic (defaults to Task)
+    #[arg(
+        long = "type",
+        alias = "issue-type",
+        value_name = "TYPE",
+        default_value = "Task"
+    )]
     pub issue_type: String,
 
+    /// Label to add to the ticket (repeatable; labels cannot contain spaces)
+    #[arg(long = "label", value_name = "LABEL")]
+    pub labels: Vec<String>,
+
     /// Priority (e.g., Highest, High, Medium, Low, Lowest)
     #[arg(long)]
     pub priority: Option<String>,
@@ -65,14 +74,14 @@ pub async fn create_ticket_data(options: CreateOptions) -> Result<CreateOutput>
         ));
     }
 
-    let config = JiraConfig::from_env()?;
+    let config = JiraConfig::from_env().await?;
     let client = create_jira_client(&config)?;
-    let base_url = config.base_url.trim_end_matches('/');
+    let api_base = config.api_base();
 
     // Handle assignee lookup if provided
-    let assignee_account_id = if let Some(assignee_input) = &options.assignee {
-        match lookup_assignee(&client, base_url, assignee_input).await {
-            Ok(account_id) => Some(account_id),
+    let assignee = if let Some(assignee_input) = &options.assignee {
+        match lookup_assignee(&client, &api_base, config.deployment, assignee_input).await {
+            Ok(user_ref) => Some(user_ref),
             Err(e) => {
                 return Err(eyre!("Failed to resolve assignee: {}", e));
             }
@@ -81,33 +90,22 @@ pub async fn create_ticket_data(options: CreateOptions) -> Result<CreateOutput>
         None
     };
 
-    // Build create payload
-    let mut fields = serde_json::json!({
-        "summary": options.summary,
-        "project": {
-            "key": options.project
-        }
-    });
-
-    // Add description if provided (convert markdown to ADF format)
-    if let Some(description) = &options.description {
-        fields["description"] = markdown_to_adf(description);
-    }
-
-    // Add issue type (defaults to Task)
-    fields["issuetype"] = serde_json::json!({ "name": options.issue_type });
-
-    if let Some(priority) = &options.priority {
-        fields["priority"] = serde_json::json!({ "name": priority });
-    }
-
-    if let Some(assignee_id) = assignee_account_id {
-        fields["assignee"] = serde_json::json!({ "id": assignee_id });
-    }
+    let payload = build_create_payload(
+        &NewIssue {
+            project: options.project.clone(),
+            issue_type: options.issue_type.clone(),
+            summary: options.summary.clone(),
+            description: options.description.clone(),
+            priority: options.priority.clone(),
+            assignee,
+            labels: options.labels.clone(),
+        },
+        config.flavor(),
+    )
+    .map_err(|e| eyre!(e))?;
 
     // Send create request
-    let url = format!("{base_url}/rest/api/3/issue");
-    let payload = serde_json::json!({ "fields": fields });
+    let url = format!("{api_base}/issue");
 
     let response = client
         .post(&url)
@@ -175,7 +173,7 @@ pub async fn create_ticket_data(options: CreateOptions) -> Result<CreateOutput>
         .map_err(|e| eyre!("Failed to parse create response: {}", e))?;
 
     // Fetch the full ticket details using the get_ticket_data function
-    let ticket = super::get::get_ticket_data(create_response.key).await?;
+    let ticket = super::get::get_ticket_data(create_response.key, false).await?;
 
     // Assign to sprint if requested (post-creation, graceful degradation)
     if let Some(sprint_name) = &options.sprint {
@@ -195,177 +193,6 @@ pub async fn create_ticket_data(options: CreateOptions) -> Result<CreateOutput>
     Ok(ticket)
 }
 
-/// Look up assignee account ID from email, display name, account ID, or special "me" keyword
-async fn lookup_assignee(
-    client: &reqwest::Client,
-    base_url: &str,
-    assignee_input: &str,
-) -> Result<String> {
-    let identifier = parse_assignee_identifier(assignee_input);
-
-    match identifier {
-        AssigneeIdentifier::AccountId(id) => {
-            // Already an account ID, return as-is
-            Ok(id)
-        }
-        AssigneeIdentifier::Email(email) => {
-            // Search for user by email
-            search_user_by_email(client, base_url, &email).await
-        }
-        AssigneeIdentifier::DisplayName(name) => {
-            // Search for user by display name
-            search_user_by_name(client, base_url, &name).await
-        }
-        AssigneeIdentifier::CurrentUser => {
-            // Get current user's account ID
-            get_current_user_account_id(client, base_url).await
-        }
-    }
-}
-
-/// Search for user by email address
-async fn search_user_by_email(
-    client: &reqwest::Client,
-    base_url: &str,
-    email: &str,
-) -> Result<String> {
-    let url = format!(
-        "{base_url}/rest/api/3/users/search?query={}",
-        urlencoding::encode(email)
-    );
-
-    let response = client
-        .get(&url)
-        .send()
-        .await
-        .map_err(|e| eyre!("Failed to search for user by email: {}", e))?;
-
-    if !response.status().is_success() {
-        let status = response.status();
-        let body = response.text().await.unwrap_or_default();
-        return Err(eyre!("Jira user search error [{}]: {}", status, body));
-    }
-
-    let body_text = response
-        .text()
-        .await
-        .map_err(|e| eyre!("Failed to read user search response: {}", e))?;
-
-    #[derive(Deserialize)]
-    struct UserSearchResponse {
-        #[serde(default)]
-        users: Option<Vec<User>>,
-    }
-
-    #[derive(Deserialize)]
-    struct User {
-        #[serde(rename = "accountId")]
-        account_id: String,
-    }
-
-    let users: UserSearchResponse = serde_json::from_str(&body_text)
-        .map_err(|e| eyre!("Failed to parse user search response: {}", e))?;
-
-    users
-        .users
-        .and_then(|mut u| {
-            if !u.is_empty() {
-                Some(u.remove(0).account_id)
-            } else {
-                None
-            }
-        })
-        .ok_or_else(|| eyre!("No user found with email: {}", email))
-}
-
-/// Search for user by display name
-async fn search_user_by_name(
-    client: &reqwest::Client,
-    base_url: &str,
-    name: &str,
-) -> Result<String> {
-    let url = format!(
-        "{base_url}/rest/api/3/users/search?query={}",
-        urlencoding::encode(name)
-    );
-
-    let response = client
-        .get(&url)
-        .send()
-        .await
-        .map_err(|e| eyre!("Failed to search for user by name: {}", e))?;
-
-    if !response.status().is_success() {
-        let status = response.status();
-        let body = response.text().await.unwrap_or_default();
-        return Err(eyre!("Jira user search error [{}]: {}", status, body));
-    }
-
-    let body_text = response
-        .text()
-        .await
-        .map_err(|e| eyre!("Failed to read user search response: {}", e))?;
-
-    #[derive(Deserialize)]
-    struct UserSearchResponse {
-        #[serde(default)]
-        users: Option<Vec<User>>,
-    }
-
-    #[derive(Deserialize)]
-    struct User {
-        #[serde(rename = "accountId")]
-        account_id: String,
-    }
-
-    let users: UserSearchResponse = serde_json::from_str(&body_text)
-        .map_err(|e| eyre!("Failed to parse user search response: {}", e))?;
-
-    users
-        .users
-        .and_then(|mut u| {
-            if !u.is_empty() {
-                Some(u.remove(0).account_id)
-            } else {
-                None
-            }
-        })
-        .ok_or_else(|| eyre!("No user found with name: {}", name))
-}
-
-/// Get the current user's account ID from Jira
-async fn get_current_user_account_id(client: &reqwest::Client, base_url: &str) -> Result<String> {
-    let url = format!("{base_url}/rest/api/3/myself");
-
-    let response = client
-        .get(&url)
-        .send()
-        .await
-        .map_err(|e| eyre!("Failed to fetch current user: {}", e))?;
-
-    if !response.status().is_success() {
-        let status = response.status();
-        let body = response.text().await.unwrap_or_default();
-        return Err(eyre!("Failed to get current user [{}]: {}", status, body));
-    }
-
-    let body_text = response
-        .text()
-        .await
-        .map_err(|e| eyre!("Failed to read current user response: {}", e))?;
-
-    #[derive(Deserialize)]
-    struct CurrentUser {
-        #[serde(rename = "accountId")]
-        account_id: String,
-    }
-
-    let user: CurrentUser = serde_json::from_str(&body_text)
-        .map_err(|e| eyre!("Failed to parse current user response: {}", e))?;
-
-    Ok(user.account_id)
-}
-
 /// CLI handler for create command
 pub async fn handler(options: CreateOptions) -> Result<()> {
     let ticket = create_ticket_data(options.clone()).await?;
@@ -0,0 +1,205 @@
+//! Epic progress: an epic's details with its child issues and rollup
+
+use colored::Colorize;
+use mcptools_core::atlassian::deployment::jira_search_path;
+use mcptools_core::atlassian::jira::{
+    epic_rollup, story_points_field, transform_epic_children, EpicOutput, JiraChildSearchResponse,
+};
+
+use super::check_response;
+use crate::atlassian::{create_jira_client, JiraConfig};
+use crate::prelude::*;
+
+/// Options for showing an epic
+#[derive(Debug, clap::Args, Clone)]
+pub struct EpicOptions {
+    /// Epic issue key (e.g., PROJ-100)
+    pub epic_key: String,
+
+    /// Custom field that holds story points, by ID or [jira.fields] alias
+    /// (default: the story_points alias, else customfield_10016)
+    #[arg(long, env = "JIRA_STORY_POINTS_FIELD")]
+    pub points_field: Option<String>,
+
+    /// Output as JSON
+    #[arg(long)]
+    pub json: bool,
+}
+
+// --- Data functions (public, used by CLI and MCP) ---
+
+/// Get an epic's details with all of its child issues and their rollup.
+/// `points_field` is a field ID or alias; see [`story_points_field`].
+pub async fn get_epic_data(epic_key: String, points_field: Option<String>) -> Result<EpicOutput> {
+    let points_field = story_points_field(
+        &super::field::load_field_aliases()?,
+        points_field.as_deref(),
+    );
+    let epic = super::get_ticket_data(epic_key.clone(), false).await?;
+
+    let config = JiraConfig::from_env().await?;
+    let client = create_jira_client(&config)?;
+    let url = format!(
+        "{}{}",
+        config.api_base(),
+        jira_search_path(config.deployment)
+    );
+
+    let jql = format!("parent = \"{epic_key}\" ORDER BY created ASC");
+    let fields = format!("summary,status,assignee,issuetype,{points_field}");
+
+    let mut issues = Vec::new();
+    let mut next_page_token: Option<String> = None;
+    loop {
+        let mut query = vec![
+            ("jql", jql.clone()),
+            ("fields", fields.clone()),
+            ("maxResults", "100".to_string()),
+        ];
+        // Cloud pages with a token; Data Center pages with an offset
+        match next_page_token.take() {
+            Some(token) => query.push(("nextPageToken", token)),
+            None if !issues.is_empty() => query.push(("startAt", issues.len().to_string())),
+            None => {}
+        }
+
+        let response = client
+            .get(&url)
+            .query(&query)
+            .send()
+            .await
+            .map_err(|e| eyre!("Failed to fetch epic children: {e}"))?;
+
+        let response = check_response(response, "Failed to fetch epic children").await?;
+
+        let page: JiraChildSearchResponse = response
+            .json()
+            .await
+            .map_err(|e| eyre!("Failed to parse epic children: {e}"))?;
+
+        let fetched = page.issues.len();
+        issues.extend(page.issues);
+        next_page_token = page.next_page_token;
+
+        let more_by_offset = page
+            .total
+            .is_some_and(|total| (issues.len() as u64) < total);
+        if fetched == 0 || (next_page_token.is_none() && !more_by_offset) {
+            break;
+        }
+    }
+
+    let children = transform_epic_children(issues, &points_field);
+    let rollup = epic_rollup(&children);
+
+    Ok(EpicOutput {
+        epic,
+        children,
+        rollup,
+    })
+}
+
+// --- Display functions ---
+
+/// Format story points without a trailing `.0` for whole numbers.
+fn format_points(points: f64) -> String {
+    if points.fract() == 0.0 {
+        format!("{points:.0}")
+    } else {
+        format!("{points:.1}")
+    }
+}
+
+/// Display an epic's children and progress rollup.
+fn display_epic(output: &EpicOutput) {
+    super::display_ticket(&output.epic);
+
+    if output.children.is_empty() {
+        std::println!("\nNo child issues found.");
+        return;
+    }
+
+    std::println!("\n{}:", "Child Issues".bold().cyan());
+    let mut table = new_table();
+    table.add_row(prettytable::row![
+        "Key".bold().cyan(),
+        "Type".bold().cyan(),
+        "Status".bold().cyan(),
+        "Points".bold().cyan(),
+        "Assignee".bold().cyan(),
+        "Summary".bold().cyan()
+    ]);
+    for child in &output.children {
+        let status = if child.done {
+            child.status.green().to_string()
+        } else {
+            child.status.bright_yellow().to_string()
+        };
+        table.add_row(prettytable::row![
+            child.key.green().to_string(),
+            child.issue_type.as_deref().unwrap_or("-").to_string(),
+            status,
+            child
+                .story_points
+                .map(format_points)
+                .unwrap_or_else(|| "-".to_string()),
+            child
+                .assignee
+                .as_deref()
+                .unwrap_or("Unassigned")
+                .bright_magenta()
+                .to_string(),
+            child.summary.bright_white().to_string()
+        ]);
+    }
+    table.printstd();
+
+    let rollup = &output.rollup;
+    std::println!("\n{}:", "Progress".bold().cyan());
+    let mut table = new_table();
+    table.add_row(prettytable::row![
+        "Status".bold().cyan(),
+        "Issues".bold().cyan(),
+        "Points".bold().cyan()
+    ]);
+    for status in &rollup.by_status {
+        table.add_row(prettytable::row![
+            status.status.bright_white().to_string(),
+            status.issues.to_string(),
+            format_points(status.story_points)
+        ]);
+    }
+    table.printstd();
+
+    std::println!(
+        "\n{} {}/{} issues, {}/{} points ({:.0}%)",
+        "Done:".bold().cyan(),
+        rollup.done_issues,
+        rollup.total_issues,
+        format_points(rollup.done_points),
+        format_points(rollup.total_points),
+        rollup.percent_done
+    );
+    if rollup.unestimated_issues > 0 {
+        std::println!(
+            "{} {} issue(s) without story points",
+            "Unestimated:".bold().cyan(),
+            rollup.unestimated_issues
+        );
+    }
+}
+
+// --- CLI handler ---
+
+/// Handle the epic command.
+pub async fn handler(options: EpicOptions) -> Result<()> {
+    let output = get_epic_data(options.epic_key, options.points_field).await?;
+
+    if options.json {
+        std::println!("{}", serde_json::to_string_pretty(&output)?);
+    } else {
+        display_epic(&output);
+    }
+
+    Ok(())
+}
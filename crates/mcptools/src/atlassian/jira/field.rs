@@ -0,0 +1,143 @@
+//! Field discovery: map field names to the site's field IDs
+
+use std::collections::HashMap;
+use std::sync::{Mutex, OnceLock};
+
+use colored::Colorize;
+use mcptools_core::atlassian::jira::{
+    parse_field_aliases, transform_fields_response, FieldAliases, FieldOutput, JiraField,
+};
+
+use super::check_response;
+use crate::atlassian::{create_jira_client, JiraConfig};
+use crate::prelude::*;
+
+/// Options for listing fields
+#[derive(Debug, clap::Args, Clone)]
+pub struct FieldsOptions {
+    /// Only list custom fields
+    #[arg(long)]
+    pub custom: bool,
+
+    /// Only list fields whose name, ID, or alias contains this text
+    /// (case-insensitive)
+    #[arg(long)]
+    pub search: Option<String>,
+
+    /// Output as JSON
+    #[arg(long)]
+    pub json: bool,
+}
+
+/// Field aliases from the `[jira.fields]` table of
+/// `~/.config/mcptools/config.toml`; none when the file is missing.
+pub fn load_field_aliases() -> Result<FieldAliases> {
+    let path = std::env::var("HOME")
+        .ok()
+        .map(|home| std::path::PathBuf::from(home).join(".config/mcptools/config.toml"));
+    let content = match path {
+        Some(path) if path.exists() => Some(
+            std::fs::read_to_string(&path)
+                .map_err(|e| eyre!("Failed to read {}: {}", path.display(), e))?,
+        ),
+        _ => None,
+    };
+    parse_field_aliases(content.as_deref())
+        .map_err(|e| eyre!("~/.config/mcptools/config.toml: {}", e))
+}
+
+/// Field catalogs, cached per API base for the lifetime of the process (the
+/// MCP server reuses them across tool calls).
+static FIELD_CATALOGS: OnceLock<Mutex<HashMap<String, Vec<JiraField>>>> = OnceLock::new();
+
+/// Fetch every field defined on the site, once per process.
+pub(super) async fn field_catalog(
+    client: &reqwest::Client,
+    api_base: &str,
+) -> Result<Vec<JiraField>> {
+    let cache = FIELD_CATALOGS.get_or_init(Default::default);
+    if let Some(fields) = cache.lock().ok().and_then(|c| c.get(api_base).cloned()) {
+        return Ok(fields);
+    }
+
+    let response = client
+        .get(format!("{api_base}/field"))
+        .send()
+        .await
+        .map_err(|e| eyre!("Failed to fetch fields: {e}"))?;
+
+    let response = check_response(response, "Failed to fetch fields").await?;
+
+    let fields: Vec<JiraField> = response
+        .json()
+        .await
+        .map_err(|e| eyre!("Failed to parse fields response: {e}"))?;
+
+    if let Ok(mut cache) = cache.lock() {
+        cache.insert(api_base.to_string(), fields.clone());
+    }
+    Ok(fields)
+}
+
+// --- Data functions (public, used by CLI and MCP) ---
+
+/// List the fields defined on the Jira site.
+pub async fn list_fields_data(
+    custom_only: bool,
+    search: Option<String>,
+) -> Result<Vec<FieldOutput>> {
+    let aliases = load_field_aliases()?;
+    let config = JiraConfig::from_env().await?;
+    let client = create_jira_client(&config)?;
+
+    let fields = field_catalog(&client, &config.api_base()).await?;
+    let search = search.map(|s| s.to_lowercase());
+
+    Ok(transform_fields_response(fields, &aliases)
+        .into_iter()
+        .filter(|f| !custom_only || f.custom)
+        .filter(|f| {
+            search.as_ref().is_none_or(|s| {
+                f.name.to_lowercase().contains(s)
+                    || f.id.to_lowercase().contains(s)
+                    || f.alias
+                        .as_ref()
+                        .is_some_and(|a| a.to_lowercase().contains(s))
+            })
+        })
+        .collect())
+}
+
+// --- CLI handler ---
+
+/// Handle the fields command.
+pub async fn handler(options: FieldsOptions) -> Result<()> {
+    let fields = list_fields_data(options.custom, options.search).await?;
+
+    if options.json {
+        std::println!("{}", serde_json::to_string_pretty(&fields)?);
+    } else if fields.is_empty() {
+        std::println!("No fields found.");
+    } else {
+        let mut table = new_table();
+        table.add_row(prettytable::row![
+            "ID".bold().cyan(),
+            "Name".bold().cyan(),
+            "Type".bold().cyan(),
+            "Custom".bold().cyan(),
+            "Alias".bold().cyan()
+        ]);
+        for f in &fields {
+            table.add_row(prettytable::row![
+                f.id.green(),
+                f.name.bright_white(),
+                f.field_type.as_deref().unwrap_or("-").bright_black(),
+                if f.custom { "yes" } else { "" },
+                f.alias.as_deref().unwrap_or("").bright_yellow()
+            ]);
+        }
+        table.printstd();
+    }
+
+    Ok(())
+}
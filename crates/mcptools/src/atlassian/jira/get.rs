@@ -1,5 +1,5 @@
 use mcptools_core::atlassian::jira::{
-    transform_ticket_response, JiraExtendedIssueResponse, TicketOutput,
+    name_custom_fields, transform_ticket_response, JiraExtendedIssueResponse, TicketOutput,
 };
 use serde::Deserialize;
 
@@ -13,19 +13,26 @@ pub struct GetOptions {
     #[arg(env = "JIRA_ISSUE_KEY")]
     pub issue_key: String,
 
+    /// Include extra sections in the output (changelog: field change history)
+    #[arg(long, value_parser = ["changelog"])]
+    #[serde(default)]
+    pub expand: Option<String>,
+
     /// Output as JSON
     #[arg(long)]
     pub json: bool,
 }
 
-/// Get detailed ticket information from Jira
-pub async fn get_ticket_data(issue_key: String) -> Result<TicketOutput> {
-    let config = JiraConfig::from_env()?;
+/// Get detailed ticket information from Jira. With `expand_changelog`, the
+/// ticket's field change history is included as well.
+pub async fn get_ticket_data(issue_key: String, expand_changelog: bool) -> Result<TicketOutput> {
+    let aliases = super::field::load_field_aliases()?;
+    let config = JiraConfig::from_env().await?;
     let client = create_jira_client(&config)?;
 
     let ticket_url = format!(
-        "{}/rest/api/3/issue/{}?expand=changelog",
-        config.base_url,
+        "{}/issue/{}",
+        config.api_base(),
         urlencoding::encode(&issue_key)
     );
 
@@ -50,8 +57,8 @@ pub async fn get_ticket_data(issue_key: String) -> Result<TicketOutput> {
         .map_err(|e| eyre!("Failed to parse Jira response: {}", e))?;
 
     let comments_url = format!(
-        "{}/rest/api/3/issue/{}/comment",
-        config.base_url,
+        "{}/issue/{}/comment",
+        config.api_base(),
         urlencoding::encode(&issue_key)
     );
 
@@ -76,16 +83,34 @@ pub async fn get_ticket_data(issue_key: String) -> Result<TicketOutput> {
     };
 
     // Fetch attachments (gracefully degrade to empty if it fails)
-    let attachments = super::attachment::list_attachments_data(issue_key)
+    let attachments = super::attachment::list_attachments_data(issue_key.clone())
         .await
         .unwrap_or_default();
 
-    Ok(transform_ticket_response(issue, comments, attachments))
+    let mut ticket = transform_ticket_response(issue, comments, attachments, &aliases);
+
+    // Name custom fields (keep their IDs if the field catalog is unavailable)
+    if !ticket.custom_fields.is_empty() {
+        if let Ok(fields) = super::field::field_catalog(&client, &config.api_base()).await {
+            ticket.custom_fields = name_custom_fields(ticket.custom_fields, &fields);
+        }
+    }
+
+    if expand_changelog {
+        let history = super::history::get_changelog_data(issue_key, None).await?;
+        ticket.changelog = Some(history.entries);
+    }
+
+    Ok(ticket)
 }
 
 /// Handle the get command
 pub async fn handler(options: GetOptions) -> Result<()> {
-    let ticket = get_ticket_data(options.issue_key).await?;
+    let ticket = get_ticket_data(
+        options.issue_key,
+        options.expand.as_deref() == Some("changelog"),
+    )
+    .await?;
 
     if options.json {
         println!("{}", serde_json::to_string_pretty(&ticket)?);
@@ -0,0 +1,157 @@
+//! Field change history of Jira tickets
+
+use colored::Colorize;
+use mcptools_core::atlassian::deployment::Deployment;
+use mcptools_core::atlassian::jira::{transform_changelog, ChangelogOutput, JiraChangelogPage};
+use serde::Deserialize;
+
+use super::check_response;
+use crate::atlassian::{create_jira_client, JiraConfig};
+use crate::prelude::*;
+
+/// Options for showing a ticket's history
+#[derive(Debug, clap::Args, Clone)]
+pub struct HistoryOptions {
+    /// Issue key (e.g., PROJ-123)
+    pub issue_key: String,
+
+    /// Only show changes to this field, by name or ID (e.g., status)
+    #[arg(long)]
+    pub field: Option<String>,
+
+    /// Output as JSON
+    #[arg(long)]
+    pub json: bool,
+}
+
+// --- Local deserialization struct for the issue-with-changelog response ---
+
+#[derive(Debug, Deserialize)]
+struct IssueWithChangelog {
+    changelog: JiraChangelogPage,
+}
+
+// --- Data functions (public, used by CLI and MCP) ---
+
+/// Get the field change history of a Jira ticket, oldest first.
+pub async fn get_changelog_data(
+    issue_key: String,
+    field: Option<String>,
+) -> Result<ChangelogOutput> {
+    let config = JiraConfig::from_env().await?;
+    let client = create_jira_client(&config)?;
+    let api_base = config.api_base();
+
+    let histories = match config.deployment {
+        // Cloud pages through the dedicated changelog endpoint
+        Deployment::Cloud => {
+            let mut histories = Vec::new();
+            loop {
+                let response = client
+                    .get(format!("{api_base}/issue/{issue_key}/changelog"))
+                    .query(&[("startAt", histories.len()), ("maxResults", 100)])
+                    .send()
+                    .await
+                    .map_err(|e| eyre!("Failed to fetch changelog: {e}"))?;
+
+                let response = check_response(response, "Failed to fetch changelog").await?;
+
+                let page: JiraChangelogPage = response
+                    .json()
+                    .await
+                    .map_err(|e| eyre!("Failed to parse changelog response: {e}"))?;
+
+                let done = page.values.is_empty()
+                    || page.is_last.unwrap_or_else(|| {
+                        page.start_at + page.values.len() as u64 >= page.total.unwrap_or(0)
+                    });
+                histories.extend(page.values);
+                if done {
+                    break histories;
+                }
+            }
+        }
+        // Data Center returns the whole history with the issue
+        Deployment::DataCenter => {
+            let response = client
+                .get(format!("{api_base}/issue/{issue_key}"))
+                .query(&[("expand", "changelog"), ("fields", "summary")])
+                .send()
+                .await
+                .map_err(|e| eyre!("Failed to fetch changelog: {e}"))?;
+
+            let response = check_response(response, "Failed to fetch changelog").await?;
+
+            let issue: IssueWithChangelog = response
+                .json()
+                .await
+                .map_err(|e| eyre!("Failed to parse changelog response: {e}"))?;
+
+            issue.changelog.values
+        }
+    };
+
+    Ok(transform_changelog(&issue_key, histories, field.as_deref()))
+}
+
+// --- Display functions ---
+
+/// Display a ticket's history as a formatted CLI table.
+fn display_changelog(output: &ChangelogOutput) {
+    if output.entries.is_empty() {
+        std::println!("No changes found on {}.", output.ticket_key.bold().cyan());
+        return;
+    }
+
+    std::println!(
+        "\n{} {}\n",
+        "History of".green().bold(),
+        output.ticket_key.bold().cyan()
+    );
+
+    let mut table = new_table();
+    table.add_row(prettytable::row![
+        "When".bold().cyan(),
+        "Author".bold().cyan(),
+        "Field".bold().cyan(),
+        "From".bold().cyan(),
+        "To".bold().cyan()
+    ]);
+
+    for entry in &output.entries {
+        table.add_row(prettytable::row![
+            entry.created.bright_black().to_string(),
+            entry
+                .author
+                .as_deref()
+                .unwrap_or("Unknown")
+                .bright_magenta()
+                .to_string(),
+            entry.field.bright_yellow().to_string(),
+            entry.from.as_deref().unwrap_or("-").to_string(),
+            entry
+                .to
+                .as_deref()
+                .unwrap_or("-")
+                .bright_white()
+                .to_string()
+        ]);
+    }
+
+    table.printstd();
+}
+
+// --- CLI handler ---
+
+/// Handle the history command.
+pub async fn handler(options: HistoryOptions) -> Result<()> {
+    let output = get_changelog_data(options.issue_key, options.field).await?;
+
+    if options.json {
+        std::println!("{}", serde_json::to_string_pretty(&output)?);
+    } else {
+        display_changelog(&output);
+    }
+
+    Ok(())
+}
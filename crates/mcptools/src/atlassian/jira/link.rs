@@ -0,0 +1,212 @@
+//! Create and list links between Jira issues
+
+use colored::Colorize;
+use mcptools_core::atlassian::jira::{
+    build_issue_link_payload, transform_issue_links, IssueLinkOutput, JiraIssueLink,
+    JiraIssueLinkTypesResponse,
+};
+use serde::Deserialize;
+
+use super::check_response;
+use crate::atlassian::{create_jira_client, JiraConfig};
+use crate::prelude::*;
+
+/// Options for linking issues. Without a link flag, lists the ticket's links.
+#[derive(Debug, clap::Args, Clone)]
+pub struct LinkOptions {
+    /// Issue key (e.g., PROJ-123)
+    pub issue_key: String,
+
+    /// Mark the ticket as blocking this issue (repeatable)
+    #[arg(long, value_name = "KEY")]
+    pub blocks: Vec<String>,
+
+    /// Mark the ticket as relating to this issue (repeatable)
+    #[arg(long, value_name = "KEY")]
+    pub relates_to: Vec<String>,
+
+    /// Mark the ticket as a duplicate of this issue (repeatable)
+    #[arg(long, value_name = "KEY")]
+    pub duplicates: Vec<String>,
+
+    /// Output as JSON
+    #[arg(long)]
+    pub json: bool,
+}
+
+impl LinkOptions {
+    /// `(relationship, other issue key)` pairs requested by the flags
+    fn requested_links(&self) -> Vec<(String, String)> {
+        [
+            ("blocks", &self.blocks),
+            ("relates to", &self.relates_to),
+            ("duplicates", &self.duplicates),
+        ]
+        .into_iter()
+        .flat_map(|(relationship, keys)| {
+            keys.iter()
+                .map(move |key| (relationship.to_string(), key.clone()))
+        })
+        .collect()
+    }
+}
+
+// --- Local deserialization structs for the issue-with-links response ---
+
+#[derive(Debug, Deserialize)]
+struct IssueWithLinks {
+    fields: LinkFields,
+}
+
+#[derive(Debug, Deserialize)]
+struct LinkFields {
+    #[serde(default)]
+    issuelinks: Vec<JiraIssueLink>,
+}
+
+// --- Data functions (public, used by CLI and MCP) ---
+
+/// List the links from a Jira ticket to other issues.
+pub async fn list_issue_links_data(issue_key: String) -> Result<Vec<IssueLinkOutput>> {
+    let config = JiraConfig::from_env().await?;
+    let client = create_jira_client(&config)?;
+    let api_base = config.api_base();
+
+    let url = format!("{api_base}/issue/{issue_key}?fields=issuelinks");
+
+    let response = client
+        .get(&url)
+        .send()
+        .await
+        .map_err(|e| eyre!("Failed to fetch issue links: {e}"))?;
+
+    let response = check_response(response, "Failed to fetch issue links").await?;
+
+    let issue: IssueWithLinks = response
+        .json()
+        .await
+        .map_err(|e| eyre!("Failed to parse issue links response: {e}"))?;
+
+    Ok(transform_issue_links(issue.fields.issuelinks))
+}
+
+/// Link a Jira ticket to other issues, each as a `(relationship, other key)`
+/// pair such as `("blocks", "PROJ-2")`. Link types are looked up on the
+/// server, so every relationship is checked before any link is created.
+/// Returns all of the ticket's links afterwards.
+pub async fn create_issue_links_data(
+    issue_key: String,
+    links: Vec<(String, String)>,
+) -> Result<Vec<IssueLinkOutput>> {
+    if links.is_empty() {
+        return Err(eyre!(
+            "At least one link is required (--blocks, --relates-to, or --duplicates)"
+        ));
+    }
+
+    let config = JiraConfig::from_env().await?;
+    let client = create_jira_client(&config)?;
+    let api_base = config.api_base();
+
+    let response = client
+        .get(format!("{api_base}/issueLinkType"))
+        .send()
+        .await
+        .map_err(|e| eyre!("Failed to fetch issue link types: {e}"))?;
+
+    let response = check_response(response, "Failed to fetch issue link types").await?;
+
+    let link_types: JiraIssueLinkTypesResponse = response
+        .json()
+        .await
+        .map_err(|e| eyre!("Failed to parse issue link types: {e}"))?;
+
+    let payloads = links
+        .iter()
+        .map(|(relationship, other_key)| {
+            build_issue_link_payload(
+                &link_types.issue_link_types,
+                &issue_key,
+                relationship,
+                other_key,
+            )
+        })
+        .collect::<std::result::Result<Vec<_>, String>>()
+        .map_err(|e| eyre!(e))?;
+
+    for payload in payloads {
+        let response = client
+            .post(format!("{api_base}/issueLink"))
+            .json(&payload)
+            .send()
+            .await
+            .map_err(|e| eyre!("Failed to create issue link: {e}"))?;
+
+        check_response(response, "Failed to create issue link").await?;
+    }
+
+    list_issue_links_data(issue_key).await
+}
+
+// --- Display functions ---
+
+/// Display a ticket's links as a formatted CLI table.
+fn display_links(issue_key: &str, links: &[IssueLinkOutput]) {
+    if links.is_empty() {
+        std::println!("No links found on {}.", issue_key.bold().cyan());
+        return;
+    }
+
+    std::println!(
+        "\n{} {}\n",
+        "Links for".green().bold(),
+        issue_key.bold().cyan()
+    );
+
+    let mut table = new_table();
+    table.add_row(prettytable::row![
+        "Relationship".bold().cyan(),
+        "Issue".bold().cyan(),
+        "Status".bold().cyan(),
+        "Summary".bold().cyan()
+    ]);
+
+    for link in links {
+        table.add_row(prettytable::row![
+            link.relationship.bright_yellow().to_string(),
+            link.issue_key.green().to_string(),
+            link.status
+                .as_deref()
+                .unwrap_or("-")
+                .bright_blue()
+                .to_string(),
+            link.summary
+                .as_deref()
+                .unwrap_or("")
+                .bright_white()
+                .to_string()
+        ]);
+    }
+
+    table.printstd();
+}
+
+// --- CLI handler ---
+
+/// Handle the link command.
+pub async fn handler(options: LinkOptions) -> Result<()> {
+    let requested = options.requested_links();
+    let links = if requested.is_empty() {
+        list_issue_links_data(options.issue_key.clone()).await?
+    } else {
+        create_issue_links_data(options.issue_key.clone(), requested).await?
+    };
+
+    if options.json {
+        std::println!("{}", serde_json::to_string_pretty(&links)?);
+    } else {
+        display_links(&options.issue_key, &links);
+    }
+
+    Ok(())
+}
@@ -1,10 +1,20 @@
 pub mod attachment;
+pub mod board;
+pub mod bulk_comment;
+pub mod bulk_update;
 pub mod comment;
 pub mod create;
+pub mod epic;
+pub mod field;
 pub mod get;
+pub mod history;
+pub mod link;
 pub mod search;
 pub mod sprint;
+pub mod transition;
 pub mod update;
+pub mod watch;
+pub mod worklog;
 
 use colored::Colorize;
 use mcptools_core::atlassian::jira::TicketOutput;
@@ -24,6 +34,19 @@ pub(super) async fn check_response(
     Err(eyre!("{context} [{status}]: {body}"))
 }
 
+/// Jira operations, with options shared by every subcommand
+#[derive(Debug, clap::Args)]
+pub struct App {
+    /// REST API version: v3 (Cloud) or v2 (Server / Data Center). Overrides
+    /// JIRA_DEPLOYMENT and ATLASSIAN_DEPLOYMENT; detected from the site when
+    /// unset
+    #[arg(long, global = true, value_name = "VERSION", value_parser = ["v2", "v3"])]
+    pub api_version: Option<String>,
+
+    #[command(subcommand)]
+    pub command: Commands,
+}
+
 /// Jira commands
 #[derive(Debug, clap::Subcommand)]
 pub enum Commands {
@@ -39,18 +62,70 @@ pub enum Commands {
     #[clap(name = "get", alias = "read")]
     Get(get::GetOptions),
 
+    /// Show a ticket's field change history
+    #[clap(name = "history")]
+    History(history::HistoryOptions),
+
+    /// Show an epic with its child issues and progress rollup
+    #[clap(name = "epic")]
+    Epic(epic::EpicOptions),
+
+    /// List the fields defined on the site, with their IDs
+    #[clap(name = "fields")]
+    Fields(field::FieldsOptions),
+
     /// Update Jira ticket fields
     #[clap(name = "update")]
     Update(update::UpdateOptions),
 
+    /// List the transitions available from a ticket's current status
+    #[clap(name = "transitions")]
+    Transitions(transition::TransitionsOptions),
+
     /// Manage comments on Jira tickets
     #[command(subcommand)]
     Comment(comment::CommentCommands),
 
+    /// Link a ticket to other issues, or list its links
+    #[clap(name = "link")]
+    Link(link::LinkOptions),
+
+    /// Add, remove, or list the watchers of a ticket
+    #[clap(name = "watch")]
+    Watch(watch::WatchOptions),
+
+    /// Log and list time spent on Jira tickets
+    #[command(subcommand)]
+    Worklog(worklog::WorklogCommands),
+
+    /// Post a templated comment on every issue matching a JQL query
+    #[clap(name = "bulk-comment")]
+    BulkComment(bulk_comment::BulkCommentOptions),
+
+    /// Update many tickets from a CSV or JSON file
+    #[clap(name = "bulk-update")]
+    BulkUpdate(bulk_update::BulkUpdateOptions),
+
+    /// Upload files as attachments to a ticket
+    #[clap(name = "attach")]
+    Attach(attachment::AttachOptions),
+
+    /// List a ticket's attachments, or download them all with --download
+    #[clap(name = "attachments")]
+    Attachments(attachment::AttachmentsOptions),
+
     /// Manage attachments on Jira tickets
     #[command(subcommand)]
     Attachment(attachment::AttachmentCommands),
 
+    /// List the boards visible to you
+    #[clap(name = "boards")]
+    Boards(board::BoardsOptions),
+
+    /// List the sprints on a board (same as `sprint list`)
+    #[clap(name = "sprints")]
+    Sprints(sprint::SprintListOptions),
+
     /// Manage sprints on a Jira board
     #[command(subcommand)]
     Sprint(sprint::SprintCommands),
@@ -66,9 +141,22 @@ pub async fn run(cmd: Commands, global: crate::Global) -> Result<()> {
         Commands::Create(options) => create::handler(options).await,
         Commands::Search(options) => search::handler(options).await,
         Commands::Get(options) => get::handler(options).await,
+        Commands::History(options) => history::handler(options).await,
+        Commands::Epic(options) => epic::handler(options).await,
+        Commands::Fields(options) => field::handler(options).await,
         Commands::Update(options) => update::handler(options).await,
+        Commands::Transitions(options) => transition::handler(options).await,
         Commands::Comment(cmd) => comment::handler(cmd).await,
+        Commands::Link(options) => link::handler(options).await,
+        Commands::Watch(options) => watch::handler(options).await,
+        Commands::Worklog(cmd) => worklog::handler(cmd).await,
+        Commands::BulkComment(options) => bulk_comment::handler(options).await,
+        Commands::BulkUpdate(options) => bulk_update::handler(options).await,
+        Commands::Attach(options) => attachment::attach_handler(options).await,
+        Commands::Attachments(options) => attachment::attachments_handler(options).await,
         Commands::Attachment(cmd) => attachment::handler(cmd).await,
+        Commands::Boards(options) => board::handler(options).await,
+        Commands::Sprints(options) => sprint::list_handler(options).await,
         Commands::Sprint(cmd) => sprint::handler(cmd).await,
     }
 }
@@ -76,7 +164,7 @@ pub async fn run(cmd: Commands, global: crate::Global) -> Result<()> {
 /// Display a ticket's details as a formatted CLI table.
 ///
 /// Renders the standard ticket view used by the get, create, and update handlers:
-/// header line, metadata table, description, labels, components, attachments, and comments.
+/// header line, metadata table, description, labels, components, links, attachments, history, and comments.
 fn display_ticket(ticket: &TicketOutput) {
     std::println!(
         "\n{} - {}\n",
@@ -159,6 +247,34 @@ fn display_ticket(ticket: &TicketOutput) {
         );
     }
 
+    if !ticket.custom_fields.is_empty() {
+        std::println!("\n{}:", "Custom Fields".bold().cyan());
+        for (name, value) in &ticket.custom_fields {
+            let value = match value {
+                serde_json::Value::String(s) => s.clone(),
+                serde_json::Value::Array(items) => items
+                    .iter()
+                    .map(|item| item.as_str().map_or_else(|| item.to_string(), String::from))
+                    .collect::<Vec<_>>()
+                    .join(", "),
+                other => other.to_string(),
+            };
+            std::println!("  {}: {}", name.bright_yellow(), value.bright_white());
+        }
+    }
+
+    if !ticket.links.is_empty() {
+        std::println!("\n{}:", "Links".bold().cyan());
+        for link in &ticket.links {
+            std::println!(
+                "  {} {} {}",
+                link.relationship.bright_yellow(),
+                link.issue_key.green(),
+                link.summary.as_deref().unwrap_or("").bright_white()
+            );
+        }
+    }
+
     if !ticket.attachments.is_empty() {
         std::println!("\n{}:", "Attachments".bold().cyan());
         for att in &ticket.attachments {
@@ -172,6 +288,20 @@ fn display_ticket(ticket: &TicketOutput) {
         }
     }
 
+    if let Some(changelog) = ticket.changelog.as_ref().filter(|c| !c.is_empty()) {
+        std::println!("\n{}:", "History".bold().cyan());
+        for entry in changelog {
+            std::println!(
+                "  {} {} {}: {} -> {}",
+                entry.created.bright_black(),
+                entry.author.as_deref().unwrap_or("Unknown").magenta(),
+                entry.field.bright_yellow(),
+                entry.from.as_deref().unwrap_or("-"),
+                entry.to.as_deref().unwrap_or("-").bright_white()
+            );
+        }
+    }
+
     if !ticket.comments.is_empty() {
         std::println!("\n{}", "Comments:".bold().cyan());
         for (index, comment) in ticket.comments.iter().enumerate() {
@@ -243,9 +373,19 @@ fn display_ticket(ticket: &TicketOutput) {
 
 // Re-export public data functions for external use (e.g., MCP)
 pub use attachment::{download_attachment_data, list_attachments_data, upload_attachment_data};
+pub use board::list_boards_data;
+pub use bulk_comment::bulk_comment_data;
+pub use bulk_update::bulk_update_data;
 pub use comment::{add_comment_data, delete_comment_data, list_comments_data, update_comment_data};
 pub use create::create_ticket_data;
+pub use epic::get_epic_data;
+pub use field::list_fields_data;
 pub use get::get_ticket_data;
+pub use history::get_changelog_data;
+pub use link::{create_issue_links_data, list_issue_links_data};
 pub use search::search_issues_data;
 pub use sprint::{list_sprints_data, move_issue_to_sprint, resolve_sprint_name};
+pub use transition::list_transitions_data;
 pub use update::update_ticket_data;
+pub use watch::{list_watchers_data, update_watchers_data};
+pub use worklog::{add_worklog_data, list_worklogs_data};
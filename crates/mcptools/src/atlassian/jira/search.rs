@@ -3,8 +3,9 @@ use serde::Deserialize;
 use std::path::PathBuf;
 
 // Import domain models and pure functions from core crate
-use mcptools_core::atlassian::jira::transform_search_response;
+use mcptools_core::atlassian::jira::{transform_search_response, with_sprint_filter};
 pub use mcptools_core::atlassian::jira::{IssueOutput, JiraSearchResponse, SearchOutput};
+use mcptools_core::template::parse_vars;
 
 /// Options for searching Jira issues
 #[derive(Debug, clap::Args, Deserialize, Clone)]
@@ -24,12 +25,15 @@ pub use mcptools_core::atlassian::jira::{IssueOutput, JiraSearchResponse, Search
   # Combine criteria: active tickets with specific text in summary:
   mcptools atlassian jira search \"assignee = currentUser() AND status NOT IN (Done, Closed) AND summary ~ \\\"api\\\"\"
 
+  # Only tickets in the active sprint (or a sprint ID or name):
+  mcptools atlassian jira search \"project = PROJ\" --sprint active
+
   # Fetch next page using pagination token:
   mcptools atlassian jira search \"assignee = currentUser()\" --limit 50 --next-page <token>
 
 SAVED QUERIES:
   # Save a query:
-  mcptools atlassian jira search 'project = \"PM\" AND \"Assigned Guild[Dropdown]\" = DevOps' --save --query devops
+  mcptools atlassian jira search 'project = \"PM\" AND \"Team[Dropdown]\" = DevOps' --save --query devops
 
   # Execute a saved query:
   mcptools atlassian jira search --query devops
@@ -37,6 +41,10 @@ SAVED QUERIES:
   # Execute with custom limit:
   mcptools atlassian jira search --query devops --limit 20
 
+  # Save a query with {name} placeholders and fill them in when running it:
+  mcptools atlassian jira search 'project = {project} AND type = Bug AND status != Done' --save --query my-open-bugs
+  mcptools atlassian jira search --saved my-open-bugs --var project=PROJ
+
   # Update existing query:
   mcptools atlassian jira search 'project = \"PM\" AND status = Open' --save --query devops --update
 
@@ -57,7 +65,8 @@ NOTES:
   - Results are limited to 10 per page by default; use --limit to change
   - Use --next-page with the token from the previous response to fetch additional pages
   - Pagination tokens expire after 7 days
-  - Saved queries are stored in ~/.config/mcptools/queries/")]
+  - Saved queries are stored in ~/.config/mcptools/queries/
+  - --saved is an alias for --query; placeholders without a --var value are an error")]
 pub struct SearchOptions {
     /// JQL query (e.g., "project = PROJ AND status = Open"), optional when using --query, --list, --load, or --delete
     #[arg(env = "JIRA_QUERY")]
@@ -71,6 +80,10 @@ pub struct SearchOptions {
     #[arg(long)]
     pub next_page: Option<String>,
 
+    /// Only issues in this sprint: active, future, closed, a sprint ID, or a sprint name
+    #[arg(long)]
+    pub sprint: Option<String>,
+
     /// Output as JSON
     #[arg(long)]
     pub json: bool,
@@ -80,9 +93,14 @@ pub struct SearchOptions {
     pub save: bool,
 
     /// Name of saved query to use, save, delete, or load
-    #[arg(long)]
+    #[arg(long, visible_alias = "saved")]
     pub query: Option<String>,
 
+    /// Value for a {name} placeholder in the query, as NAME=VALUE (repeatable)
+    #[arg(long = "var", value_name = "NAME=VALUE")]
+    #[serde(default)]
+    pub vars: Vec<String>,
+
     /// Update an existing saved query (used with --save)
     #[arg(long)]
     pub update: bool,
@@ -109,19 +127,22 @@ pub async fn search_issues_data(
     next_page: Option<String>,
 ) -> Result<SearchOutput> {
     use crate::atlassian::{create_jira_client, JiraConfig};
+    use mcptools_core::atlassian::deployment::{jira_search_path, Deployment};
     use mcptools_core::pagination;
 
-    let config = JiraConfig::from_env()?;
+    let config = JiraConfig::from_env().await?;
     let client = create_jira_client(&config)?;
 
-    // Handle base_url that may or may not have trailing slash
-    let base_url = config.base_url.trim_end_matches('/');
-    let url = format!("{base_url}/rest/api/3/search/jql");
+    let url = format!(
+        "{}{}",
+        config.api_base(),
+        jira_search_path(config.deployment)
+    );
 
     // Build query parameters for GET request
     let max_results = std::cmp::min(limit, 100); // Jira API max is 100
     let max_results_str = max_results.to_string();
-    let fields_str = "key,summary,description,status,assignee";
+    let fields_str = "key,summary,description,status,assignee,updated";
 
     // Strip invalid JQL backslash escapes that Claude Code's Bash tool may insert
     // (e.g., `!` becomes `\!` to prevent history expansion, but `\!` is not valid JQL)
@@ -155,7 +176,12 @@ pub async fn search_issues_data(
             };
 
         next_page_str_owned = actual_token;
-        query_params.push(("nextPageToken", next_page_str_owned.as_str()));
+        // Data Center pages by offset; its next page token is the next offset
+        let param = match config.deployment {
+            Deployment::Cloud => "nextPageToken",
+            Deployment::DataCenter => "startAt",
+        };
+        query_params.push((param, next_page_str_owned.as_str()));
     }
 
     let response = client
@@ -180,9 +206,10 @@ pub async fn search_issues_data(
         .map_err(|e| eyre!("Failed to parse Jira response: {}", e))?;
 
     // Transform response and store pagination token if present
-    let mut output = transform_search_response(search_response);
+    let mut output = transform_search_response(search_response, config.flavor());
 
-    if let Some(ref token) = output.next_page_token {
+    // Data Center "tokens" are short offsets and are shown as they are
+    if let (Deployment::Cloud, Some(token)) = (config.deployment, &output.next_page_token) {
         let pagination_dir = get_pagination_dir()?;
         match pagination::save_token(&pagination_dir, token) {
             Ok(hash) => {
@@ -216,7 +243,14 @@ pub async fn handler(options: SearchOptions) -> Result<()> {
         } else {
             println!("Saved queries:");
             for query_name in queries_list {
-                println!("  - {}", query_name);
+                let variables = queries::load_query(&queries_dir, &query_name)
+                    .map(|query| queries::query_variables(&query))
+                    .unwrap_or_default();
+                if variables.is_empty() {
+                    println!("  - {}", query_name);
+                } else {
+                    println!("  - {} (--var {})", query_name, variables.join(", --var "));
+                }
             }
         }
         return Ok(());
@@ -324,6 +358,30 @@ pub async fn handler(options: SearchOptions) -> Result<()> {
             .clone()
     };
 
+    // A template saved without values is stored, not run
+    let variables = queries::query_variables(&search_query);
+    if (options.save || options.update) && options.vars.is_empty() && !variables.is_empty() {
+        println!(
+            "Run it with: mcptools atlassian jira search --saved {} --var {}=VALUE",
+            options.query.as_deref().unwrap_or_default(),
+            variables.join("=VALUE --var ")
+        );
+        return Ok(());
+    }
+
+    // Fill in {name} placeholders for saved queries, or whenever --var is given
+    let search_query = if options.query.is_some() || !options.vars.is_empty() {
+        let vars = parse_vars(&options.vars).map_err(|e| eyre!(e))?;
+        queries::render_query(&search_query, &vars).map_err(|e| eyre!("{}", e))?
+    } else {
+        search_query
+    };
+
+    let search_query = match &options.sprint {
+        Some(sprint) => with_sprint_filter(&search_query, sprint),
+        None => search_query,
+    };
+
     // Execute search
     let data = search_issues_data(search_query.clone(), options.limit, options.next_page).await?;
 
@@ -361,9 +419,21 @@ pub async fn handler(options: SearchOptions) -> Result<()> {
         if let Some(next_token) = &data.next_page_token {
             let pagination_command = if use_saved_query_in_footer {
                 // Use --query for saved queries
+                let sprint_arg = options
+                    .sprint
+                    .as_ref()
+                    .map(|sprint| format!(" --sprint '{sprint}'"))
+                    .unwrap_or_default();
+                let var_args: String = options
+                    .vars
+                    .iter()
+                    .map(|var| format!(" --var '{var}'"))
+                    .collect();
                 format!(
-                    "mcptools atlassian jira search --query {} --limit {} --next-page {}",
+                    "mcptools atlassian jira search --query {}{}{} --limit {} --next-page {}",
                     options.query.as_ref().unwrap(),
+                    var_args,
+                    sprint_arg,
                     options.limit,
                     next_token
                 )
@@ -396,17 +466,7 @@ fn get_queries_dir() -> Result<PathBuf> {
     Ok(queries_dir)
 }
 
-/// Get the pagination directory, creating it if necessary
+/// Get the pagination directory in the state directory, creating it if necessary
 fn get_pagination_dir() -> Result<PathBuf> {
-    let home = std::env::var("HOME")
-        .ok()
-        .map(PathBuf::from)
-        .ok_or_else(|| eyre!("Could not determine home directory (HOME env var not set)"))?;
-
-    let pagination_dir = home.join(".config/mcptools/pagination");
-
-    // Create directory if it doesn't exist
-    std::fs::create_dir_all(&pagination_dir)?;
-
-    Ok(pagination_dir)
+    crate::state::component_dir(mcptools_core::state::StateComponent::Pagination)
 }
@@ -24,7 +24,7 @@ pub struct SprintListOptions {
     #[arg(long, env = "JIRA_BOARD_ID")]
     pub board: u64,
 
-    /// Filter by sprint state(s), comma-separated
+    /// Filter by sprint state(s), comma-separated: active, future, closed
     #[arg(long, default_value = "active,future")]
     pub state: String,
 
@@ -66,7 +66,7 @@ async fn fetch_board_sprints(
 
 /// List all sprints on a Jira board.
 pub async fn list_sprints_data(board_id: u64, state_filter: &str) -> Result<SprintListOutput> {
-    let config = JiraConfig::from_env()?;
+    let config = JiraConfig::from_env().await?;
     let client = create_jira_client(&config)?;
     let base_url = config.base_url.trim_end_matches('/');
 
@@ -76,7 +76,7 @@ pub async fn list_sprints_data(board_id: u64, state_filter: &str) -> Result<Spri
 
 /// Resolve a sprint name to its ID by searching active+future sprints on the board.
 pub async fn resolve_sprint_name(board_id: u64, sprint_name: &str) -> Result<u64> {
-    let config = JiraConfig::from_env()?;
+    let config = JiraConfig::from_env().await?;
     let client = create_jira_client(&config)?;
     let base_url = config.base_url.trim_end_matches('/');
 
@@ -96,7 +96,7 @@ pub async fn resolve_sprint_name(board_id: u64, sprint_name: &str) -> Result<u64
 
 /// Move an issue to a sprint via the Agile API.
 pub async fn move_issue_to_sprint(issue_key: &str, sprint_id: u64) -> Result<()> {
-    let config = JiraConfig::from_env()?;
+    let config = JiraConfig::from_env().await?;
     let client = create_jira_client(&config)?;
     let base_url = config.base_url.trim_end_matches('/');
 
@@ -128,34 +128,37 @@ pub async fn move_issue_to_sprint(issue_key: &str, sprint_id: u64) -> Result<()>
 /// Handle sprint subcommands.
 pub async fn handler(cmd: SprintCommands) -> Result<()> {
     match cmd {
-        SprintCommands::List(options) => {
-            let sprints = list_sprints_data(options.board, &options.state).await?;
-
-            if options.json {
-                std::println!("{}", serde_json::to_string_pretty(&sprints)?);
-            } else if sprints.sprints.is_empty() {
-                std::println!("No sprints found.");
-            } else {
-                let mut table = new_table();
-                table.add_row(prettytable::row![
-                    "ID".bold().cyan(),
-                    "Name".bold().cyan(),
-                    "State".bold().cyan(),
-                    "Start Date".bold().cyan(),
-                    "End Date".bold().cyan()
-                ]);
-                for s in &sprints.sprints {
-                    table.add_row(prettytable::row![
-                        s.id.to_string().green(),
-                        s.name.bright_white(),
-                        s.state.bright_yellow(),
-                        s.start_date.as_deref().unwrap_or("-").bright_black(),
-                        s.end_date.as_deref().unwrap_or("-").bright_black()
-                    ]);
-                }
-                table.printstd();
-            }
+        SprintCommands::List(options) => list_handler(options).await,
+    }
+}
+
+/// List the sprints on a board, for `sprint list` and `sprints`.
+pub async fn list_handler(options: SprintListOptions) -> Result<()> {
+    let sprints = list_sprints_data(options.board, &options.state).await?;
+
+    if options.json {
+        std::println!("{}", serde_json::to_string_pretty(&sprints)?);
+    } else if sprints.sprints.is_empty() {
+        std::println!("No sprints found.");
+    } else {
+        let mut table = new_table();
+        table.add_row(prettytable::row![
+            "ID".bold().cyan(),
+            "Name".bold().cyan(),
+            "State".bold().cyan(),
+            "Start Date".bold().cyan(),
+            "End Date".bold().cyan()
+        ]);
+        for s in &sprints.sprints {
+            table.add_row(prettytable::row![
+                s.id.to_string().green(),
+                s.name.bright_white(),
+                s.state.bright_yellow(),
+                s.start_date.as_deref().unwrap_or("-").bright_black(),
+                s.end_date.as_deref().unwrap_or("-").bright_black()
+            ]);
         }
+        table.printstd();
     }
 
     Ok(())
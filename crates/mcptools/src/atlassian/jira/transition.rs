@@ -0,0 +1,105 @@
+//! List the workflow transitions available on a Jira ticket
+
+use colored::Colorize;
+use mcptools_core::atlassian::jira::{
+    transform_transitions_response, JiraTransitionsResponse, TransitionListOutput,
+};
+use serde::Deserialize;
+
+use super::check_response;
+use crate::atlassian::{create_jira_client, JiraConfig};
+use crate::prelude::*;
+
+/// Options for listing transitions
+#[derive(Debug, clap::Args, Deserialize, Clone)]
+pub struct TransitionsOptions {
+    /// Issue key (e.g., "PROJ-123")
+    #[arg(env = "JIRA_ISSUE_KEY")]
+    pub issue_key: String,
+
+    /// Output as JSON
+    #[arg(long)]
+    pub json: bool,
+}
+
+/// List transitions reachable from the ticket's current status, including
+/// the fields each transition requires.
+pub async fn list_transitions_data(issue_key: String) -> Result<TransitionListOutput> {
+    let config = JiraConfig::from_env().await?;
+    let client = create_jira_client(&config)?;
+
+    let url = format!(
+        "{}/issue/{}/transitions?expand=transitions.fields",
+        config.api_base(),
+        urlencoding::encode(&issue_key)
+    );
+
+    let response = client
+        .get(&url)
+        .send()
+        .await
+        .map_err(|e| eyre!("Failed to fetch transitions: {e}"))?;
+
+    let response = check_response(response, "Failed to fetch transitions").await?;
+
+    let transitions: JiraTransitionsResponse = response
+        .json()
+        .await
+        .map_err(|e| eyre!("Failed to parse transitions response: {e}"))?;
+
+    Ok(transform_transitions_response(&issue_key, transitions))
+}
+
+/// Display available transitions as a formatted CLI table.
+fn display_transitions(output: &TransitionListOutput) {
+    if output.transitions.is_empty() {
+        std::println!(
+            "No transitions available for {}.",
+            output.ticket_key.bold().cyan()
+        );
+        return;
+    }
+
+    std::println!(
+        "\n{} {}\n",
+        "Transitions for".green().bold(),
+        output.ticket_key.bold().cyan()
+    );
+
+    let mut table = new_table();
+    table.add_row(prettytable::row![
+        "ID".bold().cyan(),
+        "Name".bold().cyan(),
+        "Target Status".bold().cyan(),
+        "Required Fields".bold().cyan()
+    ]);
+
+    for t in &output.transitions {
+        let required = if t.required_fields.is_empty() {
+            "-".bright_black().to_string()
+        } else {
+            t.required_fields.join(", ").yellow().to_string()
+        };
+        table.add_row(prettytable::row![
+            t.id.bright_black().to_string(),
+            t.name.bright_white().to_string(),
+            t.to_status.green().to_string(),
+            required
+        ]);
+    }
+
+    table.printstd();
+}
+
+/// Handle the transitions command.
+pub async fn handler(options: TransitionsOptions) -> Result<()> {
+    let output = list_transitions_data(options.issue_key).await?;
+
+    if options.json {
+        std::println!("{}", serde_json::to_string_pretty(&output)?);
+    } else {
+        display_transitions(&output);
+    }
+
+    Ok(())
+}
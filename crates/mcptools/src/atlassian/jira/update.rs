@@ -4,10 +4,12 @@ use crate::atlassian::{create_jira_client, JiraConfig};
 use crate::prelude::*;
 use clap::Args;
 use colored::Colorize;
+use mcptools_core::atlassian::deployment::{Deployment, JiraFlavor};
 use mcptools_core::atlassian::jira::{
-    build_update_payload, find_transition_by_status, markdown_to_adf, parse_assignee_identifier,
-    AssigneeIdentifier, FieldUpdateResult, JiraTransitionsResponse, JiraUserSearchResponse,
-    UpdateOutput,
+    build_field_value, build_update_payload, build_update_request, find_transition_by_status,
+    parse_assignee_identifier, parse_due_date, resolve_field_id, rich_text_payload,
+    AssigneeIdentifier, FieldEdits, FieldUpdateResult, JiraTransitionsResponse, JiraUser,
+    JiraUserSearchResponse, UpdateOutput,
 };
 use prettytable::row;
 
@@ -37,6 +39,31 @@ pub struct UpdateOptions {
     #[arg(long, short = 'd')]
     pub description: Option<String>,
 
+    /// New summary (title)
+    #[arg(long)]
+    pub summary: Option<String>,
+
+    /// New due date (YYYY-MM-DD, or "none" to clear)
+    #[arg(long, value_name = "DATE")]
+    pub due_date: Option<String>,
+
+    /// Replace the ticket's components (repeatable)
+    #[arg(long = "component", value_name = "NAME")]
+    pub components: Vec<String>,
+
+    /// Add a label, keeping existing ones (repeatable)
+    #[arg(long = "add-label", value_name = "LABEL")]
+    pub add_labels: Vec<String>,
+
+    /// Remove a label (repeatable)
+    #[arg(long = "remove-label", value_name = "LABEL")]
+    pub remove_labels: Vec<String>,
+
+    /// Set a field by its [jira.fields] alias, name, or ID; an empty value
+    /// clears it (repeatable)
+    #[arg(long = "field", value_name = "NAME=VALUE")]
+    pub fields: Vec<String>,
+
     /// Sprint name to assign the ticket to
     #[arg(long)]
     pub sprint: Option<String>,
@@ -58,9 +85,22 @@ pub struct UpdateOptions {
 /// - Building and sending update requests
 /// - Tracking partial failures
 pub async fn update_ticket_data(options: UpdateOptions) -> Result<UpdateOutput> {
-    let config = JiraConfig::from_env()?;
+    let config = JiraConfig::from_env().await?;
     let client = create_jira_client(&config)?;
-    let base_url = config.base_url.trim_end_matches('/');
+    let api_base = config.api_base();
+
+    let edits = FieldEdits {
+        summary: options.summary.clone(),
+        due_date: options
+            .due_date
+            .as_deref()
+            .map(parse_due_date)
+            .transpose()
+            .map_err(|e| eyre!(e))?,
+        components: (!options.components.is_empty()).then(|| options.components.clone()),
+        add_labels: options.add_labels.clone(),
+        remove_labels: options.remove_labels.clone(),
+    };
 
     // Validate that at least one field is provided
     if options.status.is_none()
@@ -69,17 +109,23 @@ pub async fn update_ticket_data(options: UpdateOptions) -> Result<UpdateOutput>
         && options.assignee.is_none()
         && options.description.is_none()
         && options.sprint.is_none()
+        && options.fields.is_empty()
+        && edits.is_empty()
     {
         return Err(eyre!(
-            "At least one field must be provided for update (--status, --priority, --type, --assignee, --description, or --sprint)"
+            "At least one field must be provided for update (--status, --priority, --type, --assignee, --description, --summary, --due-date, --component, --add-label, --remove-label, --field, or --sprint)"
         ));
     }
 
+    // Resolve custom fields before changing anything, so a typo fails fast
+    let custom_fields =
+        resolve_custom_fields(&client, &api_base, config.flavor(), &options.fields).await?;
+
     let mut results = Vec::new();
 
     // Handle assignee lookup if provided
     let assignee_account_id = if let Some(assignee_input) = &options.assignee {
-        match lookup_assignee(&client, base_url, assignee_input).await {
+        match lookup_assignee(&client, &api_base, config.deployment, assignee_input).await {
             Ok(account_id) => {
                 results.push(FieldUpdateResult {
                     field: "assignee".to_string(),
@@ -105,7 +151,7 @@ pub async fn update_ticket_data(options: UpdateOptions) -> Result<UpdateOutput>
 
     // Handle status transition if provided
     if let Some(new_status) = &options.status {
-        match handle_status_transition(&client, base_url, &options.ticket_key, new_status).await {
+        match handle_status_transition(&client, &api_base, &options.ticket_key, new_status).await {
             Ok(()) => {
                 results.push(FieldUpdateResult {
                     field: "status".to_string(),
@@ -150,22 +196,28 @@ pub async fn update_ticket_data(options: UpdateOptions) -> Result<UpdateOutput>
         }
     }
 
-    // Convert description to ADF if provided
-    let adf_description = options.description.as_deref().map(markdown_to_adf);
+    // Convert description to ADF (Cloud) if provided
+    let description = options
+        .description
+        .as_deref()
+        .map(|d| rich_text_payload(config.flavor(), d));
 
     // Build payload for other fields (status is handled separately via transitions)
     let payload = build_update_payload(
+        config.flavor(),
         options.priority.as_deref(),
         options.issue_type.as_deref(),
         assignee_account_id.as_deref(),
-        adf_description.as_ref(),
+        description.as_ref(),
+        &custom_fields,
     );
 
     // Only send update request if the payload has fields (status is handled separately via transitions)
     let has_fields = payload.as_object().map(|o| !o.is_empty()).unwrap_or(false);
+    let request = build_update_request(payload, &edits).map_err(|e| eyre!(e))?;
 
-    if has_fields {
-        match update_issue_fields(&client, base_url, &options.ticket_key, payload).await {
+    if has_fields || !edits.is_empty() {
+        match update_issue_fields(&client, &api_base, &options.ticket_key, request).await {
             Ok(updated_fields) => {
                 results.extend(updated_fields);
             }
@@ -175,8 +227,14 @@ pub async fn update_ticket_data(options: UpdateOptions) -> Result<UpdateOutput>
                     options.priority.as_ref().map(|_| "priority"),
                     options.issue_type.as_ref().map(|_| "issue_type"),
                     options.description.as_ref().map(|_| "description"),
+                    edits.summary.as_ref().map(|_| "summary"),
+                    edits.due_date.as_ref().map(|_| "duedate"),
+                    edits.components.as_ref().map(|_| "components"),
+                    (!edits.add_labels.is_empty() || !edits.remove_labels.is_empty())
+                        .then_some("labels"),
                 ];
-                for field in failed_fields.into_iter().flatten() {
+                let failed_custom = custom_fields.iter().map(|(id, _)| Some(id.as_str()));
+                for field in failed_fields.into_iter().chain(failed_custom).flatten() {
                     results.push(FieldUpdateResult {
                         field: field.to_string(),
                         success: false,
@@ -198,38 +256,108 @@ pub async fn update_ticket_data(options: UpdateOptions) -> Result<UpdateOutput>
     })
 }
 
-/// Look up assignee account ID from email, display name, account ID, or special "me" keyword
-async fn lookup_assignee(
+/// Resolve `NAME=VALUE` field arguments to `(field ID, request value)` pairs
+async fn resolve_custom_fields(
+    client: &reqwest::Client,
+    api_base: &str,
+    flavor: JiraFlavor,
+    args: &[String],
+) -> Result<Vec<(String, serde_json::Value)>> {
+    if args.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let aliases = super::field::load_field_aliases()?;
+    let catalog = super::field::field_catalog(client, api_base).await?;
+
+    args.iter()
+        .map(|arg| {
+            let (name, value) = arg
+                .split_once('=')
+                .ok_or_else(|| eyre!("Invalid --field '{arg}' (expected NAME=VALUE)"))?;
+            let id = resolve_field_id(&catalog, &aliases, name).ok_or_else(|| {
+                eyre!(
+                    "Unknown field '{}' (see `mcptools atlassian jira fields`)",
+                    name.trim()
+                )
+            })?;
+            let field = catalog.iter().find(|f| f.id == id);
+            let value = build_field_value(field, value, flavor).map_err(|e| eyre!(e))?;
+            Ok((id, value))
+        })
+        .collect()
+}
+
+/// Look up a user reference from email, display name, account ID, or special "me" keyword
+///
+/// Returns the account ID on Cloud and the username on Server / Data Center.
+pub(super) async fn lookup_assignee(
     client: &reqwest::Client,
-    base_url: &str,
+    api_base: &str,
+    deployment: Deployment,
     assignee_input: &str,
 ) -> Result<String> {
     let identifier = parse_assignee_identifier(assignee_input);
 
     match identifier {
         AssigneeIdentifier::AccountId(id) => Ok(id),
-        AssigneeIdentifier::Email(email) => search_user_by_email(client, base_url, &email).await,
-        AssigneeIdentifier::DisplayName(name) => search_user_by_name(client, base_url, &name).await,
-        AssigneeIdentifier::CurrentUser => get_current_user_account_id(client, base_url).await,
+        AssigneeIdentifier::Email(email) => {
+            search_user_by_email(client, api_base, deployment, &email).await
+        }
+        AssigneeIdentifier::DisplayName(name) => {
+            search_user_by_name(client, api_base, deployment, &name).await
+        }
+        AssigneeIdentifier::CurrentUser => get_current_user_ref(client, api_base, deployment).await,
     }
 }
 
+/// User search URL: `/users/search?query=` on Cloud, `/user/search?username=`
+/// (which also matches names and emails) on Server / Data Center
+fn user_search_url(
+    api_base: &str,
+    deployment: Deployment,
+    query: &str,
+    start_at: usize,
+    max_results: usize,
+) -> String {
+    let (path, param) = match deployment {
+        Deployment::Cloud => ("users/search", "query"),
+        Deployment::DataCenter => ("user/search", "username"),
+    };
+    format!(
+        "{api_base}/{path}?{param}={}&startAt={start_at}&maxResults={max_results}",
+        urlencoding::encode(query)
+    )
+}
+
+/// The user's reference for this deployment, or an error naming the user
+fn user_ref(user: &JiraUser, deployment: Deployment) -> Result<String> {
+    user.user_ref(deployment.into())
+        .map(String::from)
+        .ok_or_else(|| {
+            eyre!(
+                "User '{}' has no {} on this Jira deployment",
+                user.display_name.as_deref().unwrap_or("unknown"),
+                match deployment {
+                    Deployment::Cloud => "account ID",
+                    Deployment::DataCenter => "username",
+                }
+            )
+        })
+}
+
 /// Search for user by email address
 async fn search_user_by_email(
     client: &reqwest::Client,
-    base_url: &str,
+    api_base: &str,
+    deployment: Deployment,
     email: &str,
 ) -> Result<String> {
     let mut start_at = 0;
     const MAX_RESULTS: usize = 50;
 
     loop {
-        let url = format!(
-            "{base_url}/rest/api/3/users/search?query={}&startAt={}&maxResults={}",
-            urlencoding::encode(email),
-            start_at,
-            MAX_RESULTS
-        );
+        let url = user_search_url(api_base, deployment, email, start_at, MAX_RESULTS);
 
         let response = client
             .get(&url)
@@ -265,7 +393,7 @@ async fn search_user_by_email(
         });
 
         if let Some(user) = exact_match {
-            return Ok(user.account_id.clone());
+            return user_ref(user, deployment);
         }
 
         // If no exact match, try partial match
@@ -277,7 +405,7 @@ async fn search_user_by_email(
         });
 
         if let Some(user) = partial_match {
-            return Ok(user.account_id.clone());
+            return user_ref(user, deployment);
         }
 
         // Move to next page
@@ -290,19 +418,15 @@ async fn search_user_by_email(
 /// Search for user by display name
 async fn search_user_by_name(
     client: &reqwest::Client,
-    base_url: &str,
+    api_base: &str,
+    deployment: Deployment,
     name: &str,
 ) -> Result<String> {
     let mut start_at = 0;
     const MAX_RESULTS: usize = 50;
 
     loop {
-        let url = format!(
-            "{base_url}/rest/api/3/users/search?query={}&startAt={}&maxResults={}",
-            urlencoding::encode(name),
-            start_at,
-            MAX_RESULTS
-        );
+        let url = user_search_url(api_base, deployment, name, start_at, MAX_RESULTS);
 
         let response = client
             .get(&url)
@@ -329,16 +453,16 @@ async fn search_user_by_name(
             break;
         }
 
-        // First try to find an exact match on display name (case-insensitive)
+        // First try to find an exact match on display name or username (case-insensitive)
         let exact_match = users.iter().find(|u| {
             u.display_name
-                .as_ref()
-                .map(|dn| dn.eq_ignore_ascii_case(name))
-                .unwrap_or(false)
+                .iter()
+                .chain(&u.name)
+                .any(|n| n.eq_ignore_ascii_case(name))
         });
 
         if let Some(user) = exact_match {
-            return Ok(user.account_id.clone());
+            return user_ref(user, deployment);
         }
 
         // If no exact match, try case-insensitive word matching (handles "luis ramirez" vs "Luis Ramirez")
@@ -357,7 +481,7 @@ async fn search_user_by_name(
         });
 
         if let Some(user) = word_match {
-            return Ok(user.account_id.clone());
+            return user_ref(user, deployment);
         }
 
         // Move to next page
@@ -371,9 +495,13 @@ async fn search_user_by_name(
     ))
 }
 
-/// Get the current user's account ID from Jira
-async fn get_current_user_account_id(client: &reqwest::Client, base_url: &str) -> Result<String> {
-    let url = format!("{base_url}/rest/api/3/myself");
+/// Get the current user's account ID (Cloud) or username (Data Center) from Jira
+async fn get_current_user_ref(
+    client: &reqwest::Client,
+    api_base: &str,
+    deployment: Deployment,
+) -> Result<String> {
+    let url = format!("{api_base}/myself");
 
     let response = client
         .get(&url)
@@ -392,27 +520,21 @@ async fn get_current_user_account_id(client: &reqwest::Client, base_url: &str) -
         .await
         .map_err(|e| eyre!("Failed to read current user response: {}", e))?;
 
-    #[derive(serde::Deserialize)]
-    struct CurrentUser {
-        #[serde(rename = "accountId")]
-        account_id: String,
-    }
-
-    let user: CurrentUser = serde_json::from_str(&body_text)
+    let user: JiraUser = serde_json::from_str(&body_text)
         .map_err(|e| eyre!("Failed to parse current user response: {}", e))?;
 
-    Ok(user.account_id)
+    user_ref(&user, deployment)
 }
 
 /// Handle status transition via transitions API
 async fn handle_status_transition(
     client: &reqwest::Client,
-    base_url: &str,
+    api_base: &str,
     ticket_key: &str,
     target_status: &str,
 ) -> Result<()> {
     // Fetch available transitions
-    let url = format!("{base_url}/rest/api/3/issue/{ticket_key}/transitions");
+    let url = format!("{api_base}/issue/{ticket_key}/transitions");
 
     let response = client
         .get(&url)
@@ -438,14 +560,15 @@ async fn handle_status_transition(
     let transition_id = find_transition_by_status(&transitions_response.transitions, target_status)
         .ok_or_else(|| {
             eyre!(
-                "No valid transition to status '{}'. Available statuses: {}",
+                "No valid transition to status '{}'. Available statuses: {}. Run `mcptools atlassian jira transitions {}` for details",
                 target_status,
                 transitions_response
                     .transitions
                     .iter()
                     .map(|t| format!("'{}'", t.to.name))
                     .collect::<Vec<_>>()
-                    .join(", ")
+                    .join(", "),
+                ticket_key
             )
         })?;
 
@@ -480,12 +603,11 @@ async fn handle_status_transition(
 /// Update issue fields via PUT request
 async fn update_issue_fields(
     client: &reqwest::Client,
-    base_url: &str,
+    api_base: &str,
     ticket_key: &str,
-    fields: serde_json::Value,
+    payload: serde_json::Value,
 ) -> Result<Vec<FieldUpdateResult>> {
-    let url = format!("{base_url}/rest/api/3/issue/{ticket_key}");
-    let payload = serde_json::json!({ "fields": fields });
+    let url = format!("{api_base}/issue/{ticket_key}");
 
     let response = client
         .put(&url)
@@ -504,20 +626,29 @@ async fn update_issue_fields(
     let mut results = Vec::new();
 
     if let Some(fields_obj) = payload["fields"].as_object() {
-        for key in fields_obj.keys() {
+        for (key, field) in fields_obj {
             let field_name = match key.as_str() {
                 "priority" => "priority",
                 "issuetype" => "issue_type",
                 _ => key,
             };
 
-            let value = if key == "description" {
-                Some("(updated)".to_string())
-            } else {
-                fields_obj[key]["name"]
+            let value = match key.as_str() {
+                "description" => Some("(updated)".to_string()),
+                "duedate" if field.is_null() => Some("(cleared)".to_string()),
+                "components" => field.as_array().map(|components| {
+                    components
+                        .iter()
+                        .filter_map(|c| c["name"].as_str())
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                }),
+                _ => field
                     .as_str()
-                    .or_else(|| fields_obj[key]["value"].as_str())
+                    .or_else(|| field["name"].as_str())
+                    .or_else(|| field["value"].as_str())
                     .map(|s| s.to_string())
+                    .or_else(|| field.is_number().then(|| field.to_string())),
             };
 
             results.push(FieldUpdateResult {
@@ -529,6 +660,25 @@ async fn update_issue_fields(
         }
     }
 
+    if let Some(label_ops) = payload["update"]["labels"].as_array() {
+        let summary = label_ops
+            .iter()
+            .filter_map(|op| {
+                op["add"]
+                    .as_str()
+                    .map(|l| format!("+{l}"))
+                    .or_else(|| op["remove"].as_str().map(|l| format!("-{l}")))
+            })
+            .collect::<Vec<_>>()
+            .join(", ");
+        results.push(FieldUpdateResult {
+            field: "labels".to_string(),
+            success: true,
+            value: Some(summary),
+            error: None,
+        });
+    }
+
     Ok(results)
 }
 
@@ -548,7 +698,7 @@ pub async fn handler(options: UpdateOptions) -> Result<()> {
 
     if options.json {
         // For JSON output, fetch and return the full ticket details
-        let ticket = super::get::get_ticket_data(update_output.ticket_key.clone()).await?;
+        let ticket = super::get::get_ticket_data(update_output.ticket_key.clone(), false).await?;
         std::println!("{}", serde_json::to_string_pretty(&ticket)?);
     } else {
         // Display update summary
@@ -608,7 +758,7 @@ pub async fn handler(options: UpdateOptions) -> Result<()> {
         std::println!();
         std::println!("{}", "Current ticket state:".bold().cyan());
 
-        let ticket = super::get::get_ticket_data(update_output.ticket_key.clone()).await?;
+        let ticket = super::get::get_ticket_data(update_output.ticket_key.clone(), false).await?;
         super::display_ticket(&ticket);
     }
 
@@ -0,0 +1,198 @@
+//! Add, remove, and list watchers on Jira tickets
+
+use colored::Colorize;
+use mcptools_core::atlassian::deployment::Deployment;
+use mcptools_core::atlassian::jira::{
+    transform_watchers_response, JiraWatchersResponse, WatchersOutput,
+};
+
+use super::check_response;
+use super::update::lookup_assignee;
+use crate::atlassian::{create_jira_client, JiraConfig};
+use crate::prelude::*;
+
+/// Options for managing watchers. Without a flag, lists the ticket's watchers.
+#[derive(Debug, clap::Args, Clone)]
+pub struct WatchOptions {
+    /// Issue key (e.g., PROJ-123)
+    pub issue_key: String,
+
+    /// Add a watcher: email, display name, account ID, or "me" (repeatable)
+    #[arg(long, value_name = "USER")]
+    pub add: Vec<String>,
+
+    /// Remove a watcher: email, display name, account ID, or "me" (repeatable)
+    #[arg(long, value_name = "USER")]
+    pub remove: Vec<String>,
+
+    /// List the watchers after any changes
+    #[arg(long)]
+    pub list: bool,
+
+    /// Output as JSON
+    #[arg(long)]
+    pub json: bool,
+}
+
+// --- Data functions (public, used by CLI and MCP) ---
+
+/// List the watchers of a Jira ticket.
+pub async fn list_watchers_data(issue_key: String) -> Result<WatchersOutput> {
+    let config = JiraConfig::from_env().await?;
+    let client = create_jira_client(&config)?;
+    let api_base = config.api_base();
+
+    let response = client
+        .get(format!("{api_base}/issue/{issue_key}/watchers"))
+        .send()
+        .await
+        .map_err(|e| eyre!("Failed to fetch watchers: {e}"))?;
+
+    let response = check_response(response, "Failed to fetch watchers").await?;
+
+    let watchers: JiraWatchersResponse = response
+        .json()
+        .await
+        .map_err(|e| eyre!("Failed to parse watchers response: {e}"))?;
+
+    Ok(transform_watchers_response(&issue_key, watchers))
+}
+
+/// Add and remove watchers on a Jira ticket. Users are resolved the same way
+/// as assignees, and every user is resolved before any watcher changes.
+/// Returns the ticket's watchers afterwards.
+pub async fn update_watchers_data(
+    issue_key: String,
+    add: Vec<String>,
+    remove: Vec<String>,
+) -> Result<WatchersOutput> {
+    if add.is_empty() && remove.is_empty() {
+        return Err(eyre!("At least one watcher to add or remove is required"));
+    }
+
+    let config = JiraConfig::from_env().await?;
+    let client = create_jira_client(&config)?;
+    let api_base = config.api_base();
+
+    let mut to_add = Vec::new();
+    for user in &add {
+        to_add.push(lookup_assignee(&client, &api_base, config.deployment, user).await?);
+    }
+    let mut to_remove = Vec::new();
+    for user in &remove {
+        to_remove.push(lookup_assignee(&client, &api_base, config.deployment, user).await?);
+    }
+
+    let url = format!("{api_base}/issue/{issue_key}/watchers");
+
+    // Cloud refers to users by account ID, Server / Data Center by username
+    let user_param = match config.deployment {
+        Deployment::Cloud => "accountId",
+        Deployment::DataCenter => "username",
+    };
+
+    for account_id in to_add {
+        // The API takes the bare account ID (or username) as a JSON string
+        let response = client
+            .post(&url)
+            .json(&account_id)
+            .send()
+            .await
+            .map_err(|e| eyre!("Failed to add watcher: {e}"))?;
+
+        check_response(response, "Failed to add watcher").await?;
+    }
+
+    for account_id in to_remove {
+        let response = client
+            .delete(&url)
+            .query(&[(user_param, &account_id)])
+            .send()
+            .await
+            .map_err(|e| eyre!("Failed to remove watcher: {e}"))?;
+
+        check_response(response, "Failed to remove watcher").await?;
+    }
+
+    list_watchers_data(issue_key).await
+}
+
+// --- Display functions ---
+
+/// Display a ticket's watchers as a formatted CLI table.
+fn display_watchers(output: &WatchersOutput) {
+    if output.watchers.is_empty() {
+        std::println!("No watchers on {}.", output.ticket_key.bold().cyan());
+        return;
+    }
+
+    std::println!(
+        "\n{} {} ({})\n",
+        "Watchers of".green().bold(),
+        output.ticket_key.bold().cyan(),
+        output.watch_count
+    );
+
+    let mut table = new_table();
+    table.add_row(prettytable::row![
+        "Name".bold().cyan(),
+        "Email".bold().cyan(),
+        "Account ID".bold().cyan()
+    ]);
+
+    for watcher in &output.watchers {
+        table.add_row(prettytable::row![
+            watcher
+                .display_name
+                .as_deref()
+                .unwrap_or("Unknown")
+                .bright_magenta()
+                .to_string(),
+            watcher
+                .email
+                .as_deref()
+                .unwrap_or("-")
+                .bright_white()
+                .to_string(),
+            watcher.account_id.bright_black().to_string()
+        ]);
+    }
+
+    table.printstd();
+}
+
+// --- CLI handler ---
+
+/// Handle the watch command.
+pub async fn handler(options: WatchOptions) -> Result<()> {
+    let changed = !options.add.is_empty() || !options.remove.is_empty();
+    let output = if changed {
+        update_watchers_data(
+            options.issue_key.clone(),
+            options.add.clone(),
+            options.remove.clone(),
+        )
+        .await?
+    } else {
+        list_watchers_data(options.issue_key.clone()).await?
+    };
+
+    if options.json {
+        std::println!("{}", serde_json::to_string_pretty(&output)?);
+        return Ok(());
+    }
+
+    if changed {
+        for user in &options.add {
+            std::println!("{} {}", "Added watcher:".green().bold(), user);
+        }
+        for user in &options.remove {
+            std::println!("{} {}", "Removed watcher:".green().bold(), user);
+        }
+    }
+    if !changed || options.list {
+        display_watchers(&output);
+    }
+
+    Ok(())
+}
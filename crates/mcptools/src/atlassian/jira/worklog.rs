@@ -0,0 +1,220 @@
+//! Log and list time spent on Jira tickets
+
+use colored::Colorize;
+use mcptools_core::atlassian::jira::{
+    build_worklog_payload, parse_worklog_duration, transform_worklog_list_response,
+    transform_worklog_response, JiraWorklog, JiraWorklogListResponse, WorklogListOutput,
+    WorklogOutput,
+};
+
+use super::check_response;
+use crate::atlassian::{create_jira_client, JiraConfig};
+use crate::prelude::*;
+
+/// Worklog subcommands
+#[derive(Debug, clap::Subcommand)]
+pub enum WorklogCommands {
+    /// Log time spent on a Jira ticket
+    #[clap(name = "add")]
+    Add {
+        /// Issue key (e.g., PROJ-123)
+        issue_key: String,
+
+        /// Time spent, e.g. 1h30m, 2d, 45m (1d = 8h, 1w = 5d)
+        #[arg(long, value_name = "DURATION")]
+        time: String,
+
+        /// Work description (supports markdown)
+        #[arg(long)]
+        comment: Option<String>,
+
+        /// Output as JSON
+        #[arg(long)]
+        json: bool,
+    },
+
+    /// List the time logged on a Jira ticket
+    #[clap(name = "list")]
+    List {
+        /// Issue key (e.g., PROJ-123)
+        issue_key: String,
+
+        /// Output as JSON
+        #[arg(long)]
+        json: bool,
+    },
+}
+
+// --- Data functions (public, used by CLI and MCP) ---
+
+/// Log time on a Jira ticket. `time` uses Jira's duration notation.
+pub async fn add_worklog_data(
+    issue_key: String,
+    time: String,
+    comment: Option<String>,
+) -> Result<WorklogOutput> {
+    let seconds = parse_worklog_duration(&time).map_err(|e| eyre!(e))?;
+
+    let config = JiraConfig::from_env().await?;
+    let client = create_jira_client(&config)?;
+    let api_base = config.api_base();
+
+    let payload = build_worklog_payload(seconds, comment.as_deref(), config.flavor());
+    let url = format!("{api_base}/issue/{issue_key}/worklog");
+
+    let response = client
+        .post(&url)
+        .json(&payload)
+        .send()
+        .await
+        .map_err(|e| eyre!("Failed to add worklog: {e}"))?;
+
+    let response = check_response(response, "Failed to add worklog").await?;
+
+    let worklog: JiraWorklog = response
+        .json()
+        .await
+        .map_err(|e| eyre!("Failed to parse worklog response: {e}"))?;
+
+    Ok(transform_worklog_response(&issue_key, worklog))
+}
+
+/// List all worklogs on a Jira ticket.
+pub async fn list_worklogs_data(issue_key: String) -> Result<WorklogListOutput> {
+    let config = JiraConfig::from_env().await?;
+    let client = create_jira_client(&config)?;
+    let api_base = config.api_base();
+
+    let url = format!("{api_base}/issue/{issue_key}/worklog");
+
+    let response = client
+        .get(&url)
+        .send()
+        .await
+        .map_err(|e| eyre!("Failed to fetch worklogs: {e}"))?;
+
+    let response = check_response(response, "Failed to fetch worklogs").await?;
+
+    let list: JiraWorklogListResponse = response
+        .json()
+        .await
+        .map_err(|e| eyre!("Failed to parse worklog list response: {e}"))?;
+
+    Ok(transform_worklog_list_response(&issue_key, list))
+}
+
+// --- Display functions ---
+
+/// Display a newly logged worklog.
+fn display_worklog(output: &WorklogOutput) {
+    std::println!(
+        "\n{} {} {} {}",
+        "Logged".green().bold(),
+        output.time_spent.bright_yellow(),
+        "on".green().bold(),
+        output.ticket_key.bold().cyan()
+    );
+
+    let mut table = new_table();
+    table.add_row(prettytable::row![
+        "ID".bold().cyan(),
+        output.worklog_id.bright_white().to_string()
+    ]);
+    table.add_row(prettytable::row![
+        "Author".bold().cyan(),
+        output
+            .author
+            .as_deref()
+            .unwrap_or("Unknown")
+            .bright_magenta()
+            .to_string()
+    ]);
+    table.add_row(prettytable::row![
+        "Started".bold().cyan(),
+        output.started.bright_black().to_string()
+    ]);
+    table.printstd();
+
+    if let Some(comment) = &output.comment {
+        std::println!("\n{}:", "Comment".bold().cyan());
+        std::println!("{}\n", comment);
+    }
+}
+
+/// Display a ticket's worklogs as a formatted CLI table with the total.
+fn display_worklog_list(output: &WorklogListOutput) {
+    if output.worklogs.is_empty() {
+        std::println!("No worklogs found.");
+        return;
+    }
+
+    let mut table = new_table();
+    table.add_row(prettytable::row![
+        "ID".bold().cyan(),
+        "Author".bold().cyan(),
+        "Started".bold().cyan(),
+        "Time".bold().cyan(),
+        "Comment".bold().cyan()
+    ]);
+
+    for worklog in &output.worklogs {
+        let comment_snippet = worklog
+            .comment
+            .as_deref()
+            .unwrap_or("")
+            .chars()
+            .take(60)
+            .collect::<String>();
+        table.add_row(prettytable::row![
+            worklog.worklog_id.green().to_string(),
+            worklog
+                .author
+                .as_deref()
+                .unwrap_or("Unknown")
+                .bright_magenta()
+                .to_string(),
+            worklog.started.bright_black().to_string(),
+            worklog.time_spent.bright_yellow().to_string(),
+            comment_snippet.bright_white().to_string()
+        ]);
+    }
+
+    table.printstd();
+    std::println!(
+        "\n{} {}",
+        "Total logged:".bold().cyan(),
+        output.total_time_spent.bright_yellow()
+    );
+}
+
+// --- CLI handler ---
+
+/// Handle worklog subcommands.
+pub async fn handler(cmd: WorklogCommands) -> Result<()> {
+    match cmd {
+        WorklogCommands::Add {
+            issue_key,
+            time,
+            comment,
+            json,
+        } => {
+            let output = add_worklog_data(issue_key, time, comment).await?;
+            if json {
+                std::println!("{}", serde_json::to_string_pretty(&output)?);
+            } else {
+                display_worklog(&output);
+            }
+        }
+
+        WorklogCommands::List { issue_key, json } => {
+            let output = list_worklogs_data(issue_key).await?;
+            if json {
+                std::println!("{}", serde_json::to_string_pretty(&output)?);
+            } else {
+                display_worklog_list(&output);
+            }
+        }
+    }
+
+    Ok(())
+}
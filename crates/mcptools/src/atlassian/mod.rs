@@ -1,5 +1,12 @@
 use crate::prelude::{println, *};
+use mcptools_core::atlassian::deployment::{
+    authorization_header, deployment_from_url, jira_api_path, resolve_deployment,
+    server_info_from_manifest, ApplinksManifest, Deployment, DeploymentSetting, JiraFlavor,
+    ServerInfo, CONFLUENCE_MANIFEST_PATH,
+};
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::{Mutex, OnceLock};
 
 pub mod bitbucket;
 pub mod confluence;
@@ -17,8 +24,7 @@ pub struct App {
 #[derive(Debug, clap::Subcommand)]
 pub enum Commands {
     /// Jira operations
-    #[clap(subcommand)]
-    Jira(jira::Commands),
+    Jira(jira::App),
 
     /// Confluence operations
     #[clap(subcommand)]
@@ -55,80 +61,301 @@ impl AtlassianConfig {
 #[derive(Debug, Clone)]
 pub struct JiraConfig {
     pub base_url: String,
-    pub email: String,
+    /// Account email. Required for Cloud (Basic auth); unused on Data Center.
+    pub email: Option<String>,
     pub api_token: String,
+    pub deployment: Deployment,
 }
 
 impl JiraConfig {
     /// Load configuration from environment variables
     /// Tries JIRA_* first, falls back to ATLASSIAN_*
-    pub fn from_env() -> Result<Self> {
-        let base_url = std::env::var("JIRA_BASE_URL")
-            .or_else(|_| std::env::var("ATLASSIAN_BASE_URL"))
-            .map_err(|_| {
-                eyre!("Neither JIRA_BASE_URL nor ATLASSIAN_BASE_URL environment variable is set")
-            })?;
-
-        let email = std::env::var("JIRA_EMAIL")
-            .or_else(|_| std::env::var("ATLASSIAN_EMAIL"))
-            .map_err(|_| {
-                eyre!("Neither JIRA_EMAIL nor ATLASSIAN_EMAIL environment variable is set")
-            })?;
-
-        let api_token = std::env::var("JIRA_API_TOKEN")
-            .or_else(|_| std::env::var("ATLASSIAN_API_TOKEN"))
-            .map_err(|_| {
-                eyre!("Neither JIRA_API_TOKEN nor ATLASSIAN_API_TOKEN environment variable is set")
-            })?;
+    ///
+    /// The deployment comes from JIRA_DEPLOYMENT / ATLASSIAN_DEPLOYMENT and is
+    /// detected from the base URL or the `serverInfo` endpoint when unset.
+    ///
+    /// Credential overrides from the current MCP tool call take precedence.
+    pub async fn from_env() -> Result<Self> {
+        let overrides = CredentialOverrides::current();
+        let credentials = Credentials::resolve(&overrides, "JIRA", env_var)?;
+
+        let setting = deployment_setting(overrides.deployment, "JIRA_DEPLOYMENT", Product::Jira)?;
+        let deployment = detect_deployment(&credentials.base_url, setting, Product::Jira).await;
+        credentials.check_email(deployment, "JIRA")?;
 
         Ok(Self {
-            base_url,
-            email,
-            api_token,
+            base_url: credentials.base_url,
+            email: credentials.email,
+            api_token: credentials.api_token,
+            deployment,
         })
     }
+
+    /// Base URL of the Jira REST API for this deployment, e.g.
+    /// `https://acme.atlassian.net/rest/api/3`.
+    pub fn api_base(&self) -> String {
+        format!(
+            "{}{}",
+            self.base_url.trim_end_matches('/'),
+            jira_api_path(self.deployment)
+        )
+    }
+
+    /// Value of the `Authorization` header for this deployment.
+    pub fn authorization(&self) -> String {
+        authorization_header(self.deployment, self.email.as_deref(), &self.api_token)
+    }
+
+    /// REST dialect spoken to this deployment.
+    pub fn flavor(&self) -> JiraFlavor {
+        self.deployment.into()
+    }
 }
 
 /// Confluence-specific configuration with fallback to shared Atlassian credentials
 #[derive(Debug, Clone)]
 pub struct ConfluenceConfig {
     pub base_url: String,
-    pub email: String,
+    /// Account email. Required for Cloud (Basic auth); unused on Data Center.
+    pub email: Option<String>,
     pub api_token: String,
+    pub deployment: Deployment,
 }
 
 impl ConfluenceConfig {
     /// Load configuration from environment variables
     /// Tries CONFLUENCE_* first, falls back to ATLASSIAN_*
-    pub fn from_env() -> Result<Self> {
-        let base_url = std::env::var("CONFLUENCE_BASE_URL")
-            .or_else(|_| std::env::var("ATLASSIAN_BASE_URL"))
-            .map_err(|_| {
-                eyre!(
-                    "Neither CONFLUENCE_BASE_URL nor ATLASSIAN_BASE_URL environment variable is set"
-                )
-            })?;
-
-        let email = std::env::var("CONFLUENCE_EMAIL")
-            .or_else(|_| std::env::var("ATLASSIAN_EMAIL"))
-            .map_err(|_| {
-                eyre!("Neither CONFLUENCE_EMAIL nor ATLASSIAN_EMAIL environment variable is set")
-            })?;
-
-        let api_token = std::env::var("CONFLUENCE_API_TOKEN")
-            .or_else(|_| std::env::var("ATLASSIAN_API_TOKEN"))
-            .map_err(|_| {
-                eyre!(
-                    "Neither CONFLUENCE_API_TOKEN nor ATLASSIAN_API_TOKEN environment variable is set"
-                )
-            })?;
+    ///
+    /// The deployment comes from CONFLUENCE_DEPLOYMENT / ATLASSIAN_DEPLOYMENT
+    /// and is detected from the base URL or the `serverInfo` endpoint when unset.
+    ///
+    /// Credential overrides from the current MCP tool call take precedence.
+    pub async fn from_env() -> Result<Self> {
+        let overrides = CredentialOverrides::current();
+        let credentials = Credentials::resolve(&overrides, "CONFLUENCE", env_var)?;
+
+        let setting = deployment_setting(
+            overrides.deployment,
+            "CONFLUENCE_DEPLOYMENT",
+            Product::Confluence,
+        )?;
+        let deployment =
+            detect_deployment(&credentials.base_url, setting, Product::Confluence).await;
+        credentials.check_email(deployment, "CONFLUENCE")?;
+
+        Ok(Self {
+            base_url: credentials.base_url,
+            email: credentials.email,
+            api_token: credentials.api_token,
+            deployment,
+        })
+    }
+
+    /// Value of the `Authorization` header for this deployment.
+    pub fn authorization(&self) -> String {
+        authorization_header(self.deployment, self.email.as_deref(), &self.api_token)
+    }
+}
+
+/// Per-call Atlassian credentials supplied by an MCP client in
+/// `arguments._credentials`.
+///
+/// An override that sets `base_url` must bring its own `api_token` (and
+/// `email` for Cloud): the environment's credentials are never sent to a
+/// site the caller picked. Without `base_url`, fields left out fall back to
+/// the environment.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct CredentialOverrides {
+    pub base_url: Option<String>,
+    pub email: Option<String>,
+    pub api_token: Option<String>,
+    pub deployment: Option<String>,
+}
+
+tokio::task_local! {
+    static CREDENTIAL_OVERRIDES: CredentialOverrides;
+}
+
+impl CredentialOverrides {
+    /// Overrides in scope for the current task, or empty outside of one.
+    fn current() -> Self {
+        CREDENTIAL_OVERRIDES
+            .try_with(Clone::clone)
+            .unwrap_or_default()
+    }
+}
+
+/// Run `fut` with `overrides` visible to `JiraConfig::from_env` and
+/// `ConfluenceConfig::from_env`.
+pub async fn with_credential_overrides<F: std::future::Future>(
+    overrides: CredentialOverrides,
+    fut: F,
+) -> F::Output {
+    CREDENTIAL_OVERRIDES.scope(overrides, fut).await
+}
 
+/// Connection settings for one product, before the deployment is known
+#[derive(Debug)]
+struct Credentials {
+    base_url: String,
+    email: Option<String>,
+    api_token: String,
+    /// The base URL came from `_credentials`, so nothing was read from the
+    /// environment
+    overridden: bool,
+}
+
+impl Credentials {
+    /// Read `{service}_*` settings (falling back to `ATLASSIAN_*`) through
+    /// `env`, with `overrides` on top. An overridden base URL takes the email
+    /// and token from the override only.
+    fn resolve(
+        overrides: &CredentialOverrides,
+        service: &str,
+        env: impl Fn(&str) -> Option<String>,
+    ) -> Result<Self> {
+        if let Some(base_url) = overrides.base_url.clone() {
+            // The server's own token must never reach a site the caller chose
+            let api_token = overrides
+                .api_token
+                .clone()
+                .ok_or_else(|| eyre!("_credentials.baseUrl requires _credentials.apiToken"))?;
+            return Ok(Self {
+                base_url,
+                email: overrides.email.clone(),
+                api_token,
+                overridden: true,
+            });
+        }
+
+        let lookup = |value: Option<String>, name: &str| {
+            value
+                .or_else(|| env(&format!("{service}_{name}")))
+                .or_else(|| env(&format!("ATLASSIAN_{name}")))
+        };
+        let base_url = lookup(None, "BASE_URL").ok_or_else(|| {
+            eyre!("Neither {service}_BASE_URL nor ATLASSIAN_BASE_URL environment variable is set")
+        })?;
+        let api_token = lookup(overrides.api_token.clone(), "API_TOKEN").ok_or_else(|| {
+            eyre!("Neither {service}_API_TOKEN nor ATLASSIAN_API_TOKEN environment variable is set")
+        })?;
         Ok(Self {
             base_url,
-            email,
+            email: lookup(overrides.email.clone(), "EMAIL"),
             api_token,
+            overridden: false,
         })
     }
+
+    /// Cloud authenticates with email + token, so the email is required there.
+    fn check_email(&self, deployment: Deployment, service: &str) -> Result<()> {
+        match (deployment, &self.email) {
+            (Deployment::Cloud, None) if self.overridden => Err(eyre!(
+                "_credentials.email is required for a Cloud _credentials.baseUrl"
+            )),
+            (Deployment::Cloud, None) => Err(eyre!(
+                "Neither {service}_EMAIL nor ATLASSIAN_EMAIL environment variable is set"
+            )),
+            _ => Ok(()),
+        }
+    }
+}
+
+fn env_var(name: &str) -> Option<String> {
+    std::env::var(name).ok()
+}
+
+/// Pick an override value, then the service variable, then the shared one.
+fn env_or_override(value: Option<String>, var: &str, fallback: &str) -> Option<String> {
+    value
+        .or_else(|| std::env::var(var).ok())
+        .or_else(|| std::env::var(fallback).ok())
+}
+
+/// Read `product`'s deployment setting from `var`, falling back to
+/// ATLASSIAN_DEPLOYMENT. Only Jira accepts API versions (`v2` / `v3`).
+fn deployment_setting(
+    value: Option<String>,
+    var: &str,
+    product: Product,
+) -> Result<DeploymentSetting> {
+    let Some(value) = env_or_override(value, var, "ATLASSIAN_DEPLOYMENT") else {
+        return Ok(DeploymentSetting::Auto);
+    };
+    match product {
+        Product::Jira => DeploymentSetting::parse_jira(&value),
+        Product::Confluence => value.parse(),
+    }
+    .map_err(|e| eyre!("{e}"))
+}
+
+/// Atlassian product whose deployment is being detected; each one answers
+/// a different anonymous probe.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum Product {
+    Jira,
+    Confluence,
+}
+
+/// Deployments detected by probing, cached per product and base URL for the
+/// lifetime of the process (the MCP server reuses them across tool calls).
+static DETECTED_DEPLOYMENTS: OnceLock<Mutex<HashMap<(Product, String), Deployment>>> =
+    OnceLock::new();
+
+/// Resolve the deployment for `base_url`, probing the server only when the
+/// setting is `auto` and the URL is not recognizably Cloud.
+async fn detect_deployment(
+    base_url: &str,
+    setting: DeploymentSetting,
+    product: Product,
+) -> Deployment {
+    if setting != DeploymentSetting::Auto || deployment_from_url(base_url).is_some() {
+        return resolve_deployment(setting, base_url, None);
+    }
+
+    let key = (product, base_url.trim_end_matches('/').to_string());
+    let cache = DETECTED_DEPLOYMENTS.get_or_init(Default::default);
+    if let Some(deployment) = cache.lock().ok().and_then(|c| c.get(&key).copied()) {
+        return deployment;
+    }
+
+    let server_info = fetch_server_info(&key.1, product).await;
+    let deployment = resolve_deployment(setting, &key.1, server_info.as_ref());
+    if let Ok(mut cache) = cache.lock() {
+        cache.insert(key, deployment);
+    }
+    deployment
+}
+
+/// Probe the product's anonymous server description: Jira's `serverInfo`,
+/// or Confluence's applinks manifest. Returns `None` on any failure.
+async fn fetch_server_info(base_url: &str, product: Product) -> Option<ServerInfo> {
+    let client = reqwest::Client::builder()
+        .timeout(std::time::Duration::from_secs(10))
+        .build()
+        .ok()?;
+
+    let path = match product {
+        Product::Jira => "/rest/api/2/serverInfo",
+        Product::Confluence => CONFLUENCE_MANIFEST_PATH,
+    };
+    let response = client
+        .get(format!("{base_url}{path}"))
+        .header(reqwest::header::ACCEPT, "application/json")
+        .send()
+        .await
+        .ok()?
+        .error_for_status()
+        .ok()?;
+
+    match product {
+        Product::Jira => response.json().await.ok(),
+        Product::Confluence => {
+            let manifest: ApplinksManifest = response.json().await.ok()?;
+            server_info_from_manifest(&manifest)
+        }
+    }
 }
 
 /// Create an authenticated HTTP client with Basic Auth headers
@@ -138,27 +365,31 @@ pub fn create_authenticated_client(config: &AtlassianConfig) -> Result<reqwest::
 
 /// Create an authenticated HTTP client for Jira API
 pub fn create_jira_client(config: &JiraConfig) -> Result<reqwest::Client> {
-    create_basic_auth_client(&config.email, &config.api_token)
+    create_auth_header_client(&config.authorization())
 }
 
 /// Create an authenticated HTTP client for Confluence API
 pub fn create_confluence_client(config: &ConfluenceConfig) -> Result<reqwest::Client> {
-    create_basic_auth_client(&config.email, &config.api_token)
+    create_auth_header_client(&config.authorization())
 }
 
 /// Internal helper to create Basic Auth HTTP client
 fn create_basic_auth_client(email: &str, api_token: &str) -> Result<reqwest::Client> {
-    use base64::Engine;
-    use reqwest::header::{HeaderMap, HeaderValue, AUTHORIZATION, CONTENT_TYPE};
+    create_auth_header_client(&authorization_header(
+        Deployment::Cloud,
+        Some(email),
+        api_token,
+    ))
+}
 
-    let auth_string = format!("{}:{}", email, api_token);
-    let auth_encoded = base64::engine::general_purpose::STANDARD.encode(&auth_string);
+/// Internal helper to create an HTTP client with a fixed Authorization header
+fn create_auth_header_client(authorization: &str) -> Result<reqwest::Client> {
+    use reqwest::header::{HeaderMap, HeaderValue, AUTHORIZATION, CONTENT_TYPE};
 
     let mut headers = HeaderMap::new();
     headers.insert(
         AUTHORIZATION,
-        HeaderValue::from_str(&format!("Basic {auth_encoded}"))
-            .map_err(|e| eyre!("Invalid header value: {}", e))?,
+        HeaderValue::from_str(authorization).map_err(|e| eyre!("Invalid header value: {}", e))?,
     );
     headers.insert(CONTENT_TYPE, HeaderValue::from_static("application/json"));
 
@@ -243,8 +474,64 @@ pub async fn run(app: App, global: crate::Global) -> Result<()> {
     }
 
     match app.command {
-        Commands::Jira(cmd) => jira::run(cmd, global).await,
+        Commands::Jira(jira) => {
+            let overrides = CredentialOverrides {
+                deployment: jira.api_version,
+                ..Default::default()
+            };
+            with_credential_overrides(overrides, jira::run(jira.command, global)).await
+        }
         Commands::Confluence(cmd) => confluence::run(cmd, global).await,
         Commands::Bitbucket(cmd) => bitbucket::run(cmd, global).await,
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn env(name: &str) -> Option<String> {
+        match name {
+            "JIRA_BASE_URL" => Some("https://acme.atlassian.net".to_string()),
+            "JIRA_EMAIL" => Some("ops@acme.example".to_string()),
+            "JIRA_API_TOKEN" => Some("server-token".to_string()),
+            _ => None,
+        }
+    }
+
+    #[test]
+    fn test_base_url_override_requires_its_own_token() {
+        let overrides = CredentialOverrides {
+            base_url: Some("https://attacker.example".to_string()),
+            ..Default::default()
+        };
+        let err = Credentials::resolve(&overrides, "JIRA", env).unwrap_err();
+        assert!(err.to_string().contains("apiToken"));
+
+        let overrides = CredentialOverrides {
+            base_url: Some("https://other.atlassian.net".to_string()),
+            api_token: Some("caller-token".to_string()),
+            ..Default::default()
+        };
+        let credentials = Credentials::resolve(&overrides, "JIRA", env).unwrap();
+        assert_eq!(credentials.api_token, "caller-token");
+        // The server's email is not paired with the caller's site either
+        assert_eq!(credentials.email, None);
+        assert!(credentials.check_email(Deployment::Cloud, "JIRA").is_err());
+        assert!(credentials
+            .check_email(Deployment::DataCenter, "JIRA")
+            .is_ok());
+    }
+
+    #[test]
+    fn test_credentials_fall_back_to_env_without_base_url() {
+        let overrides = CredentialOverrides {
+            api_token: Some("caller-token".to_string()),
+            ..Default::default()
+        };
+        let credentials = Credentials::resolve(&overrides, "JIRA", env).unwrap();
+        assert_eq!(credentials.base_url, "https://acme.atlassian.net");
+        assert_eq!(credentials.email.as_deref(), Some("ops@acme.example"));
+        assert_eq!(credentials.api_token, "caller-token");
+    }
+}
@@ -0,0 +1,175 @@
+use crate::prelude::{eprintln, println, *};
+
+use jiff::tz::TimeZone;
+use mcptools_core::cal::{
+    expand_events, parse_ics, render_events_markdown, resolve_range, windows_zone_to_iana,
+    CalendarEventsOutput,
+};
+
+#[derive(Debug, clap::Parser)]
+#[command(name = "cal")]
+#[command(about = "Parse iCalendar (.ics) files and list events")]
+pub struct App {
+    #[command(subcommand)]
+    pub command: Commands,
+}
+
+#[derive(Debug, clap::Subcommand)]
+pub enum Commands {
+    /// List events from an .ics file or URL in a date range
+    #[clap(name = "parse")]
+    Parse(ParseOptions),
+}
+
+#[derive(Debug, clap::Args, Clone)]
+pub struct ParseOptions {
+    /// Path to an .ics file, or an http(s)/webcal URL
+    pub source: String,
+
+    /// First day of the range (YYYY-MM-DD, default: today)
+    #[arg(long)]
+    pub from: Option<chrono::NaiveDate>,
+
+    /// Last day of the range (YYYY-MM-DD, default: from + days - 1)
+    #[arg(long)]
+    pub to: Option<chrono::NaiveDate>,
+
+    /// Number of days to list when --to is not given
+    #[arg(short, long, default_value = "7")]
+    pub days: u32,
+
+    /// IANA time zone for output (default: system time zone)
+    #[arg(long, env = "CAL_TIMEZONE")]
+    pub tz: Option<String>,
+
+    /// Output as JSON
+    #[arg(long)]
+    pub json: bool,
+
+    /// Timeout in seconds when fetching a URL
+    #[arg(short, long, default_value = "30")]
+    pub timeout: u64,
+}
+
+pub async fn run(app: App, global: crate::Global) -> Result<()> {
+    match app.command {
+        Commands::Parse(options) => parse(options, global).await,
+    }
+}
+
+async fn parse(options: ParseOptions, global: crate::Global) -> Result<()> {
+    let output = calendar_events_data(CalendarQuery {
+        source: options.source,
+        from: options.from,
+        to: options.to,
+        days: options.days,
+        timezone: options.tz,
+        timeout: options.timeout,
+        verbose: global.verbose,
+    })
+    .await?;
+
+    if options.json {
+        println!("{}", serde_json::to_string_pretty(&output)?);
+    } else {
+        println!("{}", render_events_markdown(&output).trim_end());
+    }
+
+    Ok(())
+}
+
+/// Parameters for [`calendar_events_data`].
+#[derive(Debug, Clone)]
+pub struct CalendarQuery {
+    pub source: String,
+    pub from: Option<chrono::NaiveDate>,
+    pub to: Option<chrono::NaiveDate>,
+    pub days: u32,
+    /// IANA zone name; `None` uses the system zone.
+    pub timezone: Option<String>,
+    pub timeout: u64,
+    pub verbose: bool,
+}
+
+/// Public function for MCP reuse - load a calendar and expand its events in a range.
+pub async fn calendar_events_data(query: CalendarQuery) -> Result<CalendarEventsOutput> {
+    let output_tz = match &query.timezone {
+        Some(name) => resolve_timezone(name).ok_or_else(|| eyre!("Unknown time zone: {}", name))?,
+        None => TimeZone::system(),
+    };
+    let timezone = output_tz.iana_name().unwrap_or("local").to_string();
+
+    let text = load_source(&query.source, query.timeout, query.verbose).await?;
+    let calendar = parse_ics(&text).map_err(|e| eyre!(e))?;
+
+    let today = chrono::NaiveDate::parse_from_str(
+        &jiff::Zoned::now()
+            .with_time_zone(output_tz.clone())
+            .strftime("%Y-%m-%d")
+            .to_string(),
+        "%Y-%m-%d",
+    )?;
+    let (from, to) =
+        resolve_range(today, query.from, query.to, query.days).map_err(|e| eyre!(e))?;
+
+    if query.verbose {
+        eprintln!(
+            "Expanding {} events from {} to {} ({})",
+            calendar.events.len(),
+            from,
+            to,
+            timezone
+        );
+    }
+
+    let (events, expand_warnings) =
+        expand_events(&calendar, from, to, &output_tz, &resolve_timezone);
+    let mut warnings = calendar.warnings;
+    warnings.extend(expand_warnings);
+
+    Ok(CalendarEventsOutput {
+        source: query.source,
+        calendar_name: calendar.name,
+        timezone,
+        from: from.to_string(),
+        to: to.to_string(),
+        events,
+        warnings,
+    })
+}
+
+/// Look up an IANA zone, falling back to the Windows zone names Outlook emits.
+fn resolve_timezone(name: &str) -> Option<TimeZone> {
+    TimeZone::get(name)
+        .ok()
+        .or_else(|| windows_zone_to_iana(name).and_then(|iana| TimeZone::get(iana).ok()))
+}
+
+async fn load_source(source: &str, timeout: u64, verbose: bool) -> Result<String> {
+    let url = match source.strip_prefix("webcal://") {
+        Some(rest) => format!("https://{}", rest),
+        None => source.to_string(),
+    };
+
+    if !(url.starts_with("http://") || url.starts_with("https://")) {
+        return std::fs::read_to_string(source)
+            .map_err(|e| eyre!("Failed to read {}: {}", source, e));
+    }
+
+    if verbose {
+        eprintln!("Fetching {}", url);
+    }
+
+    let client = reqwest::Client::builder()
+        .timeout(std::time::Duration::from_secs(timeout))
+        .build()?;
+    let response = client
+        .get(&url)
+        .send()
+        .await
+        .map_err(|e| eyre!("Failed to fetch {}: {}", url, e))?;
+    if !response.status().is_success() {
+        return Err(eyre!("Failed to fetch {}: HTTP {}", url, response.status()));
+    }
+    Ok(response.text().await?)
+}
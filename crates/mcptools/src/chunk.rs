@@ -0,0 +1,136 @@
+use crate::prelude::{println, *};
+
+use mcptools_core::chunk::{chunk_segments, markdown_segments, ChunkOptions, ChunkSegment};
+
+#[derive(Debug, Clone, Copy, clap::ValueEnum)]
+pub enum ChunkFormat {
+    /// One JSON chunk record per line
+    Jsonl,
+    /// A single pretty-printed JSON array
+    Json,
+}
+
+#[derive(Debug, clap::Parser)]
+#[command(name = "chunk")]
+#[command(about = "Split a PDF or web page into chunks for embedding pipelines")]
+pub struct App {
+    /// Path to a PDF file, or an http(s) URL to fetch and convert to Markdown
+    source: String,
+
+    /// Maximum estimated tokens per chunk
+    #[arg(long, default_value_t = 512)]
+    max_tokens: usize,
+
+    /// Estimated tokens repeated from the previous chunk
+    #[arg(long, default_value_t = 64)]
+    overlap: usize,
+
+    /// Output format
+    #[arg(long, value_enum, default_value = "jsonl")]
+    format: ChunkFormat,
+
+    /// Only chunk this PDF section (ID like "s-1-0" or slug)
+    #[arg(short, long)]
+    section: Option<String>,
+
+    /// Timeout in seconds when fetching a URL
+    #[arg(short, long, default_value = "30")]
+    timeout: u64,
+}
+
+/// Module entry point
+pub async fn run(app: App, _global: crate::Global) -> Result<()> {
+    let options = ChunkOptions {
+        max_tokens: app.max_tokens,
+        overlap: app.overlap,
+    };
+    options.validate().map_err(|e| eyre!(e))?;
+
+    let segments = if is_url(&app.source) {
+        if app.section.is_some() {
+            return Err(eyre!("--section is only supported for PDF sources"));
+        }
+        let url = app.source.clone();
+        let timeout = app.timeout;
+        tokio::task::spawn_blocking(move || url_segments(url, timeout)).await??
+    } else {
+        pdf_segments(&app.source, app.section.as_deref())?
+    };
+
+    let chunks = chunk_segments(&app.source, &segments, &options).map_err(|e| eyre!(e))?;
+
+    match app.format {
+        ChunkFormat::Jsonl => {
+            for chunk in &chunks {
+                println!("{}", serde_json::to_string(chunk)?);
+            }
+        }
+        ChunkFormat::Json => println!("{}", serde_json::to_string_pretty(&chunks)?),
+    }
+
+    Ok(())
+}
+
+fn is_url(source: &str) -> bool {
+    source.starts_with("http://") || source.starts_with("https://")
+}
+
+/// Fetch a web page as Markdown and split it into heading-attributed segments.
+fn url_segments(url: String, timeout: u64) -> Result<Vec<ChunkSegment>> {
+    let output = crate::md::fetch_and_convert_data(crate::md::FetchConfig {
+        url,
+        timeout,
+        limit: 0,
+        ..Default::default()
+    })?;
+    Ok(markdown_segments(&output.content))
+}
+
+/// Parse a PDF and turn each rendered block into a segment carrying its
+/// section and page. Image blocks carry no text and are skipped.
+fn pdf_segments(path: &str, section: Option<&str>) -> Result<Vec<ChunkSegment>> {
+    let doc = pdf::ParsedDocument::from_path(path).map_err(|e| eyre!(e))?;
+    let id = section
+        .map(|s| pdf::SectionId::parse_or_slug(s).map_err(|e| eyre!(e)))
+        .transpose()?
+        .map(|id| doc.resolve_section_id(&id).map_err(|e| eyre!(e)))
+        .transpose()?;
+
+    // The index lists sections in document order, so each subheading the
+    // walk meets is the next entry after the current one.
+    let entries = &doc.tree.index.entries;
+    let (mut current, mut next) = match &id {
+        Some(id) => {
+            let pos = entries
+                .iter()
+                .position(|e| &e.id == id)
+                .ok_or_else(|| eyre!("Section not found: {}", id))?;
+            (Some(&entries[pos]), pos + 1)
+        }
+        None => (None, 0),
+    };
+
+    let mut segments = Vec::new();
+    for chunk in doc.iter_section_blocks(id.as_ref()).map_err(|e| eyre!(e))? {
+        match &chunk.block {
+            pdf::ContentBlock::Image { .. } => continue,
+            pdf::ContentBlock::SubHeading { .. } => {
+                current = entries.get(next);
+                next += 1;
+            }
+            _ => {}
+        }
+        let text = chunk.markdown.trim();
+        if text.is_empty() {
+            continue;
+        }
+        segments.push(ChunkSegment {
+            text: text.to_string(),
+            section_id: current.map(|e| e.id.to_string()),
+            section_title: current.map(|e| e.title.clone()),
+            page: Some(chunk.page),
+        });
+    }
+
+    Ok(segments)
+}
@@ -0,0 +1,92 @@
+use crate::prelude::*;
+
+use mcptools_core::atlassian::convert::{
+    adf_to_markdown, markdown_to_storage, storage_to_markdown,
+};
+use mcptools_core::atlassian::jira::markdown_to_adf;
+
+#[derive(Debug, clap::Parser)]
+#[command(name = "convert")]
+#[command(about = "Convert between Markdown, Jira ADF, and Confluence storage format")]
+pub struct App {
+    #[command(subcommand)]
+    pub command: Commands,
+}
+
+#[derive(Debug, clap::Subcommand)]
+pub enum Commands {
+    /// Convert Markdown to an Atlassian Document Format (ADF) JSON document
+    #[clap(name = "md-to-adf")]
+    MdToAdf(AdfOutputOptions),
+
+    /// Convert an ADF JSON document to Markdown
+    #[clap(name = "adf-to-md")]
+    AdfToMd(InputOptions),
+
+    /// Convert Markdown to Confluence storage format (XHTML)
+    #[clap(name = "md-to-storage")]
+    MdToStorage(InputOptions),
+
+    /// Convert Confluence storage format (XHTML) to Markdown
+    #[clap(name = "storage-to-md")]
+    StorageToMd(InputOptions),
+}
+
+#[derive(Debug, clap::Args, Clone)]
+pub struct InputOptions {
+    /// Input file ('-' or omitted for stdin)
+    pub input: Option<String>,
+}
+
+#[derive(Debug, clap::Args, Clone)]
+pub struct AdfOutputOptions {
+    /// Input file ('-' or omitted for stdin)
+    pub input: Option<String>,
+
+    /// Print the ADF document on one line
+    #[arg(short, long)]
+    pub compact: bool,
+}
+
+fn read_text(input: Option<&str>) -> Result<String> {
+    let file = input.filter(|f| *f != "-");
+    let bytes = crate::hash::read_input(None, file)?;
+    String::from_utf8(bytes).map_err(|_| eyre!("Input is not valid UTF-8"))
+}
+
+fn with_newline(mut text: String) -> String {
+    if !text.is_empty() && !text.ends_with('\n') {
+        text.push('\n');
+    }
+    text
+}
+
+pub async fn run(app: App, _global: crate::Global) -> Result<()> {
+    let output = match app.command {
+        Commands::MdToAdf(options) => {
+            let adf = markdown_to_adf(&read_text(options.input.as_deref())?);
+            if options.compact {
+                serde_json::to_string(&adf)?
+            } else {
+                serde_json::to_string_pretty(&adf)?
+            }
+        }
+        Commands::AdfToMd(options) => {
+            let text = read_text(options.input.as_deref())?;
+            let adf: serde_json::Value =
+                serde_json::from_str(&text).map_err(|e| eyre!("Input is not valid JSON: {}", e))?;
+            // Accept a bare document or an API payload with the document under `body`.
+            let doc = adf.get("body").filter(|b| b.is_object()).unwrap_or(&adf);
+            adf_to_markdown(doc)
+        }
+        Commands::MdToStorage(options) => {
+            markdown_to_storage(&read_text(options.input.as_deref())?)
+        }
+        Commands::StorageToMd(options) => {
+            storage_to_markdown(&read_text(options.input.as_deref())?)
+        }
+    };
+
+    print!("{}", with_newline(output));
+    Ok(())
+}
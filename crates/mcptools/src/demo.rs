@@ -0,0 +1,464 @@
+use crate::prelude::{println, *};
+use colored::Colorize;
+use serde_json::{json, Value};
+use std::path::Path;
+
+use mcptools_core::demo::{
+    abbreviate_strings, excerpt, fixture_pdf, preview, result_text, section_body,
+    Scenario as CoreScenario, FIXTURE_PAGE_HTML, FIXTURE_TICKET_JSON, PAGE_SUMMARY_TEMPLATE,
+    PDF_SUMMARY_TEMPLATE, TRIAGE_TEMPLATE,
+};
+
+/// Lines of each tool result shown without `--full`.
+const PREVIEW_LINES: usize = 12;
+
+/// Longest argument string shown in a tool call line.
+const ARGUMENT_CHARS: usize = 60;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum Scenario {
+    /// Outline a documentation page, read one section, and summarize it
+    SummarizePage,
+    /// Inspect a PDF, walk its outline, and summarize its first section
+    SummarizePdf,
+    /// Read a Jira ticket, pull out error signals, and draft a triage note
+    TriageTicket,
+}
+
+impl From<Scenario> for CoreScenario {
+    fn from(scenario: Scenario) -> Self {
+        match scenario {
+            Scenario::SummarizePage => CoreScenario::SummarizePage,
+            Scenario::SummarizePdf => CoreScenario::SummarizePdf,
+            Scenario::TriageTicket => CoreScenario::TriageTicket,
+        }
+    }
+}
+
+#[derive(Debug, clap::Parser)]
+#[command(name = "demo")]
+#[command(about = "Run a scripted multi-tool workflow, printing each MCP tool call and its result")]
+pub struct App {
+    /// Scenario to run; omit to list them
+    #[arg(value_enum)]
+    pub scenario: Option<Scenario>,
+
+    /// Run against real services and files instead of the bundled fixtures
+    #[arg(long, requires = "input")]
+    pub live: bool,
+
+    /// What the scenario works on in live mode: a URL, PDF path, or issue key
+    #[arg(long, value_name = "VALUE")]
+    pub input: Option<String>,
+
+    /// Print tool results in full instead of the first lines
+    #[arg(long)]
+    pub full: bool,
+}
+
+/// Sends `tools/call` requests through the MCP request handler, exactly as a
+/// client would, and prints the transcript.
+struct Runner<'a> {
+    global: &'a crate::Global,
+    full: bool,
+    step: usize,
+}
+
+impl Runner<'_> {
+    async fn call(&mut self, purpose: &str, tool: &str, arguments: Value) -> Result<Value> {
+        self.step += 1;
+        println!("{} {}", format!("Step {}:", self.step).bold(), purpose);
+        println!(
+            "  {} {} {}",
+            "->".cyan(),
+            tool.bright_white().bold(),
+            abbreviate_strings(&arguments, ARGUMENT_CHARS)
+        );
+
+        let request = json!({
+            "jsonrpc": "2.0",
+            "id": self.step,
+            "method": "tools/call",
+            "params": { "name": tool, "arguments": arguments }
+        });
+        let response = crate::mcp::handle_request(&request.to_string(), self.global, None).await;
+        let mut response = serde_json::to_value(response)?;
+
+        if let Some(error) = response.get("error") {
+            let message = error["message"].as_str().unwrap_or("unknown error");
+            return Err(eyre!("{} failed: {}", tool, message));
+        }
+        let result = response["result"].take();
+        let text = result_text(&result);
+        if result["isError"] == json!(true) {
+            return Err(eyre!("{} failed: {}", tool, text));
+        }
+
+        let shown = if self.full {
+            text.trim_end().to_string()
+        } else {
+            preview(&text, PREVIEW_LINES)
+        };
+        for line in shown.lines() {
+            println!("  {} {}", "<-".green(), line.dimmed());
+        }
+        println!();
+        Ok(result)
+    }
+
+    /// Call `tool` and parse its text result as JSON.
+    async fn call_json(&mut self, purpose: &str, tool: &str, arguments: Value) -> Result<Value> {
+        let result = self.call(purpose, tool, arguments).await?;
+        serde_json::from_str(&result_text(&result))
+            .map_err(|e| eyre!("{} returned invalid JSON: {}", tool, e))
+    }
+}
+
+fn list_scenarios() {
+    println!("{}", "Scenarios".bold());
+    for scenario in CoreScenario::ALL {
+        println!(
+            "  {:<16} {}",
+            scenario.as_str().cyan(),
+            scenario.description()
+        );
+        println!("  {:<16} --live --input: {}", "", scenario.live_input());
+    }
+    println!();
+    println!("Run one with: mcptools demo <scenario> [--live --input <VALUE>] [--full]");
+}
+
+pub async fn run(app: App, global: crate::Global) -> Result<()> {
+    let Some(scenario) = app.scenario else {
+        list_scenarios();
+        return Ok(());
+    };
+
+    // Fixtures are written to a scratch directory so the tools read them
+    // the same way they read real files.
+    let fixtures = tempfile::tempdir()?;
+    let input = match app.input {
+        Some(input) if app.live => input,
+        _ => write_fixture(scenario.into(), fixtures.path())?,
+    };
+
+    let core: CoreScenario = scenario.into();
+    println!(
+        "{} {} ({})\n",
+        "Demo:".bold(),
+        core.as_str().cyan().bold(),
+        if app.live { "live" } else { "fixtures" }
+    );
+
+    let mut runner = Runner {
+        global: &global,
+        full: app.full,
+        step: 0,
+    };
+    let summary = run_scenario(core, &mut runner, &input, app.live).await?;
+
+    println!("{}", "Result".bold());
+    println!("{}", summary.trim_end());
+    Ok(())
+}
+
+/// Write the scenario's fixture into `dir` and return the scenario input.
+fn write_fixture(scenario: CoreScenario, dir: &Path) -> Result<String> {
+    Ok(match scenario {
+        CoreScenario::SummarizePage => {
+            let path = dir.join("widget-guide.html");
+            std::fs::write(&path, FIXTURE_PAGE_HTML)?;
+            format!("file://{}", path.display())
+        }
+        CoreScenario::SummarizePdf => {
+            let path = dir.join("operations-report.pdf");
+            std::fs::write(&path, fixture_pdf())?;
+            path.display().to_string()
+        }
+        CoreScenario::TriageTicket => {
+            let path = dir.join("DEMO-42.json");
+            std::fs::write(&path, FIXTURE_TICKET_JSON)?;
+            path.display().to_string()
+        }
+    })
+}
+
+/// Run every step of `scenario` and return the final rendered text.
+async fn run_scenario(
+    scenario: CoreScenario,
+    runner: &mut Runner<'_>,
+    input: &str,
+    live: bool,
+) -> Result<String> {
+    match scenario {
+        CoreScenario::SummarizePage => summarize_page(runner, input).await,
+        CoreScenario::SummarizePdf => summarize_pdf(runner, input).await,
+        CoreScenario::TriageTicket => triage_ticket(runner, input, live).await,
+    }
+}
+
+async fn summarize_page(runner: &mut Runner<'_>, url: &str) -> Result<String> {
+    let toc = runner
+        .call(
+            "Outline the page's headings",
+            "md_toc",
+            json!({ "url": url }),
+        )
+        .await?;
+
+    let sections = runner
+        .call_json(
+            "Keep the top-level sections",
+            "json_query",
+            json!({
+                "json": result_text(&toc),
+                "query": "[.entries[] | select(.level == 2) | {text, char_offset, char_limit}]",
+                "compact": true
+            }),
+        )
+        .await?;
+    let section = sections
+        .get(0)
+        .cloned()
+        .ok_or_else(|| eyre!("The page has no second-level headings to pick a section from"))?;
+
+    let page = runner
+        .call_json(
+            "Read only the first section, using its offset and length from the outline",
+            "md_fetch",
+            json!({
+                "url": url,
+                "offset": section["char_offset"],
+                "limit": section["char_limit"]
+            }),
+        )
+        .await?;
+    let content = page["content"].as_str().unwrap_or_default();
+
+    let links = runner
+        .call_json(
+            "Collect the links in that section",
+            "regex_extract",
+            json!({
+                "text": content,
+                "pattern": r"\[[^\]]*\]\((https?://[^)\s]+)\)"
+            }),
+        )
+        .await?;
+    let links: Vec<Value> = links["matches"]
+        .as_array()
+        .into_iter()
+        .flatten()
+        .filter_map(|m| m["groups"].get(0)?.get("text").cloned())
+        .collect();
+
+    let summary = runner
+        .call(
+            "Render the summary",
+            "render_template",
+            json!({
+                "template": PAGE_SUMMARY_TEMPLATE,
+                "context": {
+                    "title": page["title"].as_str().unwrap_or(url),
+                    "sections": sections,
+                    "section": section,
+                    "excerpt": excerpt(&section_body(content), 200),
+                    "links": links
+                }
+            }),
+        )
+        .await?;
+    Ok(result_text(&summary))
+}
+
+async fn summarize_pdf(runner: &mut Runner<'_>, path: &str) -> Result<String> {
+    let info = runner
+        .call_json(
+            "Read the document metadata",
+            "pdf_info",
+            json!({ "path": path }),
+        )
+        .await?;
+
+    let toc = runner
+        .call(
+            "Parse the document tree",
+            "pdf_toc",
+            json!({ "path": path }),
+        )
+        .await?;
+
+    let sections = runner
+        .call_json(
+            "List the section IDs and headings",
+            "json_query",
+            json!({
+                "json": result_text(&toc),
+                "query": "[.index.entries[] | {id, title}]",
+                "compact": true
+            }),
+        )
+        .await?;
+    let first = sections
+        .get(0)
+        .cloned()
+        .ok_or_else(|| eyre!("The document has no sections"))?;
+
+    let section = runner
+        .call_json(
+            "Read the first section as Markdown",
+            "pdf_read",
+            json!({ "path": path, "sectionId": first["id"] }),
+        )
+        .await?;
+
+    let titles: Vec<&Value> = sections
+        .as_array()
+        .into_iter()
+        .flatten()
+        .map(|s| &s["title"])
+        .collect();
+    let summary = runner
+        .call(
+            "Render the summary",
+            "render_template",
+            json!({
+                "template": PDF_SUMMARY_TEMPLATE,
+                "context": {
+                    "info": info,
+                    "sections": titles,
+                    "excerpt": excerpt(section["text"].as_str().unwrap_or_default(), 240)
+                }
+            }),
+        )
+        .await?;
+    Ok(result_text(&summary))
+}
+
+async fn triage_ticket(runner: &mut Runner<'_>, input: &str, live: bool) -> Result<String> {
+    let ticket_json = if live {
+        let ticket = runner
+            .call("Fetch the ticket", "jira_get", json!({ "issueKey": input }))
+            .await?;
+        result_text(&ticket)
+    } else {
+        println!(
+            "{}\n",
+            "(fixtures: DEMO-42 is read from disk; --live calls jira_get)".dimmed()
+        );
+        std::fs::read_to_string(input)?
+    };
+
+    let ticket = runner
+        .call_json(
+            "Pick the fields that matter for triage",
+            "json_query",
+            json!({
+                "json": ticket_json,
+                "query": "{key, summary, status, priority, labels, comments: (.comments | length)}",
+                "compact": true
+            }),
+        )
+        .await?;
+
+    let description: Value = serde_json::from_str(&ticket_json)?;
+    let description = description["description"].as_str().unwrap_or_default();
+    let signals = runner
+        .call_json(
+            "Find error lines in the description",
+            "regex_extract",
+            json!({
+                "text": description,
+                "pattern": r"^(?:ERROR|Error|error|panic|Caused by|Exception)\b.*$",
+                "flags": "m"
+            }),
+        )
+        .await?;
+    let versions = runner
+        .call_json(
+            "Find version numbers in the description",
+            "regex_extract",
+            json!({ "text": description, "pattern": r"\bv?\d+\.\d+\.\d+\b" }),
+        )
+        .await?;
+
+    let texts = |output: &Value| -> Vec<Value> {
+        output["matches"]
+            .as_array()
+            .into_iter()
+            .flatten()
+            .map(|m| m["text"].clone())
+            .collect()
+    };
+    let draft = runner
+        .call(
+            "Draft the triage note (not posted)",
+            "render_template",
+            json!({
+                "template": TRIAGE_TEMPLATE,
+                "context": {
+                    "ticket": ticket,
+                    "signals": texts(&signals),
+                    "versions": texts(&versions)
+                }
+            }),
+        )
+        .await?;
+    Ok(result_text(&draft))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_offline_scenarios_run_end_to_end() {
+        let global = crate::Global {
+            verbose: false,
+            atlassian_url: None,
+            atlassian_email: None,
+            atlassian_token: None,
+            bitbucket_app_password: None,
+        };
+        let dir = tempfile::tempdir().unwrap();
+
+        let mut runner = Runner {
+            global: &global,
+            full: false,
+            step: 0,
+        };
+        let input = write_fixture(CoreScenario::TriageTicket, dir.path()).unwrap();
+        let note = run_scenario(CoreScenario::TriageTicket, &mut runner, &input, false)
+            .await
+            .unwrap();
+        assert_eq!(runner.step, 4);
+        assert!(note.contains("## Triage: DEMO-42"));
+        assert!(note.contains("- `ERROR export::csv: row buffer exceeded limit (10240)`"));
+        assert!(note.contains("Versions mentioned: v2.14.0, v2.13.2"));
+
+        let mut runner = Runner {
+            global: &global,
+            full: false,
+            step: 0,
+        };
+        let input = write_fixture(CoreScenario::SummarizePage, dir.path()).unwrap();
+        let summary = run_scenario(CoreScenario::SummarizePage, &mut runner, &input, false)
+            .await
+            .unwrap();
+        assert_eq!(runner.step, 5);
+        assert!(summary.starts_with("# Widget CLI Guide"));
+        assert!(summary.contains("https://example.com/widget/releases"));
+
+        let mut runner = Runner {
+            global: &global,
+            full: false,
+            step: 0,
+        };
+        let input = write_fixture(CoreScenario::SummarizePdf, dir.path()).unwrap();
+        let summary = run_scenario(CoreScenario::SummarizePdf, &mut runner, &input, false)
+            .await
+            .unwrap();
+        assert_eq!(runner.step, 5);
+        assert!(summary.contains("Incident Review"));
+    }
+}
@@ -0,0 +1,38 @@
+use crate::prelude::{println, *};
+
+use mcptools_core::codec::{decode, encode, Encoding};
+use std::io::Write;
+
+#[derive(Debug, clap::Parser)]
+#[command(name = "encode")]
+#[command(about = "Encode or decode base64, base64url, URL (percent), or hex")]
+pub struct App {
+    /// Encoding: base64, base64url, url, or hex
+    pub encoding: Encoding,
+
+    /// Text to convert (reads stdin when neither TEXT nor --file is given)
+    pub text: Option<String>,
+
+    /// Convert the contents of a file instead
+    #[arg(short, long, conflicts_with = "text")]
+    pub file: Option<String>,
+
+    /// Decode instead of encode. Decoded bytes are written to stdout as-is
+    #[arg(short, long)]
+    pub decode: bool,
+}
+
+pub async fn run(app: App, _global: crate::Global) -> Result<()> {
+    let data = crate::hash::read_input(app.text, app.file.as_deref())?;
+
+    if app.decode {
+        let text = String::from_utf8(data).map_err(|_| eyre!("Encoded input is not UTF-8"))?;
+        let bytes =
+            decode(text.trim_end_matches(['\n', '\r']), app.encoding).map_err(|e| eyre!(e))?;
+        std::io::stdout().write_all(&bytes)?;
+    } else {
+        println!("{}", encode(&data, app.encoding));
+    }
+
+    Ok(())
+}
@@ -0,0 +1,166 @@
+use crate::prelude::{println, *};
+
+use mcptools_core::generate::{
+    generate_values, GenerateKind, GenerateOutput, PasswordOptions, UuidFormat, UuidVersion,
+};
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+
+#[derive(Debug, clap::Parser)]
+#[command(name = "gen")]
+#[command(about = "Generate UUIDs, ULIDs, and random passwords")]
+pub struct App {
+    #[command(subcommand)]
+    pub command: Commands,
+}
+
+#[derive(Debug, clap::Subcommand)]
+pub enum Commands {
+    /// Generate UUIDs (v4 random or v7 time-ordered)
+    #[clap(name = "uuid")]
+    Uuid(UuidOptions),
+
+    /// Generate ULIDs (time-ordered, Crockford base32)
+    #[clap(name = "ulid")]
+    Ulid(UlidOptions),
+
+    /// Generate random passwords
+    #[clap(name = "password")]
+    Password(PasswordArgs),
+}
+
+#[derive(Debug, clap::Args, Clone)]
+pub struct CommonOptions {
+    /// Number of values to generate
+    #[arg(short = 'n', long, default_value = "1")]
+    pub count: usize,
+
+    /// Seed the random generator for reproducible output
+    #[arg(long)]
+    pub seed: Option<u64>,
+
+    /// Output as JSON
+    #[arg(long)]
+    pub json: bool,
+}
+
+#[derive(Debug, clap::Args, Clone)]
+pub struct UuidOptions {
+    #[command(flatten)]
+    pub common: CommonOptions,
+
+    /// UUID version: 4 (random) or 7 (time-ordered)
+    #[arg(long, default_value = "4")]
+    pub version: UuidVersion,
+
+    /// Output format: hyphenated, simple, urn, or braced
+    #[arg(long, default_value = "hyphenated")]
+    pub format: UuidFormat,
+
+    /// Uppercase hex digits
+    #[arg(long)]
+    pub uppercase: bool,
+}
+
+#[derive(Debug, clap::Args, Clone)]
+pub struct UlidOptions {
+    #[command(flatten)]
+    pub common: CommonOptions,
+
+    /// Lowercase output
+    #[arg(long)]
+    pub lowercase: bool,
+}
+
+#[derive(Debug, clap::Args, Clone)]
+pub struct PasswordArgs {
+    #[command(flatten)]
+    pub common: CommonOptions,
+
+    /// Password length
+    #[arg(short, long, default_value = "20")]
+    pub length: usize,
+
+    /// Leave out lowercase letters
+    #[arg(long)]
+    pub no_lowercase: bool,
+
+    /// Leave out uppercase letters
+    #[arg(long)]
+    pub no_uppercase: bool,
+
+    /// Leave out digits
+    #[arg(long)]
+    pub no_digits: bool,
+
+    /// Leave out symbols
+    #[arg(long)]
+    pub no_symbols: bool,
+
+    /// Leave out look-alike characters (0 O 1 l I |)
+    #[arg(long)]
+    pub exclude_ambiguous: bool,
+}
+
+/// Generate values with a seeded RNG when `seed` is given, OS entropy
+/// otherwise. UUIDv7 and ULID timestamps always come from the clock.
+pub fn generate_data(
+    kind: &GenerateKind,
+    count: usize,
+    seed: Option<u64>,
+) -> Result<GenerateOutput> {
+    let mut rng = match seed {
+        Some(seed) => StdRng::seed_from_u64(seed),
+        None => StdRng::from_entropy(),
+    };
+    let unix_ms = chrono::Utc::now().timestamp_millis().max(0) as u64;
+    let values = generate_values(&mut rng, kind, count, unix_ms).map_err(|e| eyre!(e))?;
+
+    Ok(GenerateOutput {
+        kind: kind.name().to_string(),
+        values,
+        seed,
+    })
+}
+
+pub async fn run(app: App, _global: crate::Global) -> Result<()> {
+    let (kind, common) = match app.command {
+        Commands::Uuid(options) => (
+            GenerateKind::Uuid {
+                version: options.version,
+                format: options.format,
+                uppercase: options.uppercase,
+            },
+            options.common,
+        ),
+        Commands::Ulid(options) => (
+            GenerateKind::Ulid {
+                lowercase: options.lowercase,
+            },
+            options.common,
+        ),
+        Commands::Password(options) => (
+            GenerateKind::Password(PasswordOptions {
+                length: options.length,
+                lowercase: !options.no_lowercase,
+                uppercase: !options.no_uppercase,
+                digits: !options.no_digits,
+                symbols: !options.no_symbols,
+                exclude_ambiguous: options.exclude_ambiguous,
+            }),
+            options.common,
+        ),
+    };
+
+    let output = generate_data(&kind, common.count, common.seed)?;
+
+    if common.json {
+        println!("{}", serde_json::to_string_pretty(&output)?);
+    } else {
+        for value in &output.values {
+            println!("{}", value);
+        }
+    }
+
+    Ok(())
+}
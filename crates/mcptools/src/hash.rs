@@ -0,0 +1,53 @@
+use crate::prelude::{println, *};
+
+use mcptools_core::codec::{hash, HashAlgorithm};
+
+#[derive(Debug, clap::Parser)]
+#[command(name = "hash")]
+#[command(about = "Compute a SHA-256 or MD5 checksum of text, a file, or stdin")]
+pub struct App {
+    /// Text to hash (reads stdin when neither TEXT nor --file is given)
+    pub text: Option<String>,
+
+    /// Hash the contents of a file instead
+    #[arg(short, long, conflicts_with = "text")]
+    pub file: Option<String>,
+
+    /// Hash algorithm: sha256 or md5
+    #[arg(short, long, default_value = "sha256")]
+    pub algorithm: HashAlgorithm,
+
+    /// Output as JSON
+    #[arg(long)]
+    pub json: bool,
+}
+
+/// Read the command input as bytes: inline text, a file, or stdin.
+pub fn read_input(text: Option<String>, file: Option<&str>) -> Result<Vec<u8>> {
+    use std::io::Read;
+
+    match (text, file) {
+        (Some(text), _) => Ok(text.into_bytes()),
+        (None, Some(path)) => {
+            std::fs::read(path).map_err(|e| eyre!("Failed to read {}: {}", path, e))
+        }
+        (None, None) => {
+            let mut buffer = Vec::new();
+            std::io::stdin().read_to_end(&mut buffer)?;
+            Ok(buffer)
+        }
+    }
+}
+
+pub async fn run(app: App, _global: crate::Global) -> Result<()> {
+    let data = read_input(app.text, app.file.as_deref())?;
+    let output = hash(&data, app.algorithm);
+
+    if app.json {
+        println!("{}", serde_json::to_string_pretty(&output)?);
+    } else {
+        println!("{}", output.digest);
+    }
+
+    Ok(())
+}
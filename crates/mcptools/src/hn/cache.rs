@@ -0,0 +1,39 @@
+use crate::prelude::{eprintln, *};
+use std::path::PathBuf;
+
+use mcptools_core::hn::{CachedItem, HnItem};
+use mcptools_core::state::StateComponent;
+
+/// Cache file for item `id`.
+fn entry_path(id: u64) -> Result<PathBuf> {
+    let dir = crate::state::component_dir(StateComponent::Cache)?.join("hn");
+    Ok(dir.join(format!("{id}.json")))
+}
+
+/// Return the cached item if its entry is still fresh. Unreadable entries
+/// count as misses.
+pub(super) fn load(id: u64) -> Option<HnItem> {
+    let content = std::fs::read_to_string(entry_path(id).ok()?).ok()?;
+    let entry: CachedItem = serde_json::from_str(&content).ok()?;
+    entry
+        .is_fresh(chrono::Utc::now().timestamp())
+        .then_some(entry.item)
+}
+
+/// Write `item` to the cache. Failures only warn; the fetch already succeeded.
+pub(super) fn store(item: &HnItem) {
+    let entry = CachedItem {
+        fetched_at: chrono::Utc::now().timestamp(),
+        item: item.clone(),
+    };
+    let result = entry_path(item.id).and_then(|path| {
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        std::fs::write(&path, serde_json::to_vec(&entry)?)?;
+        Ok(())
+    });
+    if let Err(e) = result {
+        eprintln!("Warning: failed to write hn cache: {}", e);
+    }
+}
@@ -0,0 +1,75 @@
+use crate::prelude::{println, *};
+use mcptools_core::hn::{apply_dead_filter, is_deleted, is_readable_post, render_story_markdown};
+
+use super::read_item::fetch_comment_tree;
+use super::{extract_item_id, fetch_item};
+
+#[derive(Debug, clap::Args, serde::Deserialize, Clone)]
+pub struct ExportOptions {
+    /// HackerNews item ID or full URL of the story
+    #[arg(env = "HN_ITEM")]
+    pub item: String,
+
+    /// Write the document to this file instead of stdout
+    #[arg(short, long, value_name = "FILE")]
+    pub out: Option<std::path::PathBuf>,
+
+    /// Include dead comments instead of hiding them
+    #[arg(long)]
+    #[serde(default)]
+    pub show_dead: bool,
+
+    /// Bypass the item cache: always fetch, and don't store the result
+    #[arg(long, env = "HN_NO_CACHE")]
+    #[serde(default)]
+    pub no_cache: bool,
+}
+
+pub async fn run(options: ExportOptions, global: crate::Global) -> Result<()> {
+    let item_id = extract_item_id(&options.item)?;
+
+    if global.verbose {
+        println!("Exporting item ID: {}", item_id);
+    }
+
+    let document = export_data(item_id, options.show_dead, !options.no_cache).await?;
+
+    match &options.out {
+        Some(path) => {
+            std::fs::write(path, &document)
+                .map_err(|e| eyre!("Failed to write {}: {}", path.display(), e))?;
+            println!("Exported item {} to {}", item_id, path.display());
+        }
+        None => print!("{}", document),
+    }
+
+    Ok(())
+}
+
+/// Fetches a story with its whole comment tree and renders it as a single
+/// Markdown document
+pub async fn export_data(item_id: u64, show_dead: bool, use_cache: bool) -> Result<String> {
+    let client = reqwest::Client::new();
+    let story = fetch_item(&client, item_id, use_cache).await?;
+
+    if !is_readable_post(&story) {
+        return Err(eyre!(
+            "Item {} is not a story or poll (type: {})",
+            item_id,
+            story.item_type
+        ));
+    }
+
+    if is_deleted(&story) {
+        return Err(eyre!("Item {} has been deleted", item_id));
+    }
+
+    let comments = fetch_comment_tree(&client, &story, use_cache).await;
+    let (comments, _) = apply_dead_filter(comments, show_dead);
+
+    Ok(render_story_markdown(
+        &story,
+        &comments,
+        &chrono::Utc::now().to_rfc3339_opts(chrono::SecondsFormat::Secs, true),
+    ))
+}
@@ -1,11 +1,58 @@
 use crate::prelude::{println, *};
 use colored::Colorize;
-use futures::future::join_all;
 use mcptools_core::hn::{
-    calculate_pagination, transform_hn_items, HnItem, ListItem, ListOutput, ListPaginationInfo,
+    calculate_pagination, filter_hn_items, list_table_rows, parse_time_bound, render_list_feed,
+    render_list_markdown, transform_hn_items, HnItem, ListFilters, ListItem, ListOutput,
+    ListPaginationInfo, LIST_TABLE_HEADERS,
 };
+use mcptools_core::md::FeedKind;
+use mcptools_core::pagination::IdCursor;
+use std::collections::HashSet;
+
+use super::{fetch_items, get_api_base};
+
+/// Output format for `hn list`
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, clap::ValueEnum, serde::Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ListFormat {
+    /// Colored listing (default)
+    #[default]
+    Text,
+    /// Aligned table: rank, title, score, comments, age
+    Table,
+    /// Markdown table with the same columns, titles linked
+    Markdown,
+    /// Stories and pagination as JSON
+    Json,
+    /// RSS 2.0 feed
+    Rss,
+    /// Atom feed
+    Atom,
+}
 
-use super::{fetch_item, get_api_base};
+/// Order of a filtered `hn list`
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, clap::ValueEnum, serde::Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ListSort {
+    /// HN's own ranking for the list (default)
+    #[default]
+    #[value(alias = "relevance")]
+    Rank,
+    /// Newest first
+    Date,
+    /// Most points first
+    Points,
+}
+
+impl From<ListSort> for mcptools_core::hn::ListSort {
+    fn from(s: ListSort) -> Self {
+        match s {
+            ListSort::Rank => mcptools_core::hn::ListSort::Rank,
+            ListSort::Date => mcptools_core::hn::ListSort::Date,
+            ListSort::Points => mcptools_core::hn::ListSort::Points,
+        }
+    }
+}
 
 #[derive(Debug, clap::Args, serde::Deserialize, Clone)]
 pub struct ListOptions {
@@ -21,9 +68,78 @@ pub struct ListOptions {
     #[arg(short, long, default_value = "1")]
     pub page: usize,
 
-    /// Output as JSON
+    /// Output as JSON (same as --format json)
     #[arg(long)]
     pub json: bool,
+
+    /// Output format; rss and atom print a feed a feed reader can subscribe to
+    #[arg(
+        long,
+        visible_alias = "output",
+        value_enum,
+        default_value_t = ListFormat::Text,
+        conflicts_with = "json"
+    )]
+    #[serde(default)]
+    pub format: ListFormat,
+
+    /// Only show stories with at least this many points
+    #[arg(long, value_name = "POINTS")]
+    pub min_score: Option<u64>,
+
+    /// Only show stories linking to this domain or its subdomains
+    #[arg(long)]
+    pub domain: Option<String>,
+
+    /// Only show stories submitted by this user
+    #[arg(long)]
+    pub author: Option<String>,
+
+    /// Only show stories whose title or text contains this (case-insensitive)
+    #[arg(long)]
+    pub keyword: Option<String>,
+
+    /// Only show stories posted since this time: a duration back from now
+    /// (30m, 24h, 7d, 2w), a date (2024-05-01), or an RFC 3339 timestamp
+    #[arg(long, value_name = "TIME")]
+    pub since: Option<String>,
+
+    /// Only show stories posted before this time (same formats as --since)
+    #[arg(long, value_name = "TIME")]
+    pub until: Option<String>,
+
+    /// Order of the listed stories; anything but rank sorts the whole list
+    /// before paginating
+    #[arg(long, value_enum, default_value_t = ListSort::Rank)]
+    #[serde(default)]
+    pub sort: ListSort,
+
+    /// Bypass the item cache: always fetch, and don't store the result
+    #[arg(long, env = "HN_NO_CACHE")]
+    #[serde(default)]
+    pub no_cache: bool,
+}
+
+impl ListOptions {
+    fn filters(&self) -> Result<ListFilters> {
+        let now = chrono::Utc::now().timestamp();
+        let bound = |value: &Option<String>| {
+            value
+                .as_deref()
+                .map(|v| parse_time_bound(v, now))
+                .transpose()
+                .map_err(|e| eyre!(e))
+        };
+        Ok(ListFilters {
+            min_score: self.min_score,
+            domain: self.domain.clone(),
+            author: self.author.clone(),
+            keyword: self.keyword.clone(),
+            since: bound(&self.since)?,
+            until: bound(&self.until)?,
+            sort: self.sort.into(),
+        })
+    }
 }
 
 pub async fn run(options: ListOptions, global: crate::Global) -> Result<()> {
@@ -31,39 +147,80 @@ pub async fn run(options: ListOptions, global: crate::Global) -> Result<()> {
         println!("Fetching {} stories...", options.story_type);
     }
 
-    let list_output =
-        list_items_data(options.story_type.clone(), options.limit, options.page).await?;
-
-    if options.json {
-        output_json(&list_output)?;
+    let filters = options.filters()?;
+    let list_output = list_items_data(
+        options.story_type.clone(),
+        options.limit,
+        options.page,
+        &filters,
+        None,
+        !options.no_cache,
+    )
+    .await?;
+
+    let format = if options.json {
+        ListFormat::Json
     } else {
-        output_formatted(
+        options.format
+    };
+    match format {
+        ListFormat::Json => output_json(&list_output)?,
+        ListFormat::Rss => println!(
+            "{}",
+            render_list_feed(&list_output, FeedKind::Rss, chrono::Utc::now()).trim_end()
+        ),
+        ListFormat::Atom => println!(
+            "{}",
+            render_list_feed(&list_output, FeedKind::Atom, chrono::Utc::now()).trim_end()
+        ),
+        ListFormat::Table => print!(
+            "{}",
+            format_list_table(&list_output, chrono::Utc::now().timestamp())
+        ),
+        ListFormat::Markdown => print!(
+            "{}",
+            render_list_markdown(&list_output, chrono::Utc::now().timestamp())
+        ),
+        ListFormat::Text => output_formatted(
             &list_output.items,
             &options,
             list_output.pagination.total_items,
-        )?;
+            &filters,
+        )?,
     }
 
     Ok(())
 }
 
+/// Firebase endpoint holding the ranked story IDs of a story type
+pub(super) fn story_list_endpoint(story_type: &str) -> Result<&'static str> {
+    match story_type {
+        "top" => Ok("topstories"),
+        "new" => Ok("newstories"),
+        "best" => Ok("beststories"),
+        "ask" => Ok("askstories"),
+        "show" => Ok("showstories"),
+        "job" => Ok("jobstories"),
+        _ => Err(eyre!(
+            "Invalid story type: {}. Valid types: top, new, best, ask, show, job",
+            story_type
+        )),
+    }
+}
+
 /// Fetches HackerNews story list data and returns it as a structured ListOutput
-pub async fn list_items_data(story_type: String, limit: usize, page: usize) -> Result<ListOutput> {
-    // Determine API endpoint based on story type
-    let endpoint = match story_type.as_str() {
-        "top" => "topstories",
-        "new" => "newstories",
-        "best" => "beststories",
-        "ask" => "askstories",
-        "show" => "showstories",
-        "job" => "jobstories",
-        _ => {
-            return Err(eyre!(
-                "Invalid story type: {}. Valid types: top, new, best, ask, show, job",
-                story_type
-            ))
-        }
-    };
+///
+/// With a `cursor` from a previous call's `next_cursor`, `page` is ignored
+/// and the page holds the next stories not yet returned.
+pub async fn list_items_data(
+    story_type: String,
+    limit: usize,
+    page: usize,
+    filters: &ListFilters,
+    cursor: Option<&str>,
+    use_cache: bool,
+) -> Result<ListOutput> {
+    let endpoint = story_list_endpoint(&story_type)?;
 
     // Fetch story IDs
     let client = reqwest::Client::new();
@@ -74,29 +231,75 @@ pub async fn list_items_data(story_type: String, limit: usize, page: usize) -> R
         return Err(eyre!("No stories found"));
     }
 
-    // Calculate pagination
-    let total_items = story_ids.len();
-    let (start, end) =
-        calculate_pagination(total_items, page, limit).map_err(|e| eyre!("{}", e))?;
-
-    let paginated_ids: Vec<u64> = story_ids[start..end].to_vec();
+    let scope = format!("hn:list:{}", story_type);
+    let cursor = cursor
+        .map(|token| super::load_cursor(token, &scope))
+        .transpose()?;
 
-    // Fetch story details in parallel
-    let item_futures = paginated_ids.iter().map(|id| fetch_item(&client, *id));
-    let items: Vec<HnItem> = join_all(item_futures)
-        .await
-        .into_iter()
-        .filter_map(|r| r.ok())
-        .collect();
+    // Filters need every story's details before pages can be cut
+    let matching = if filters.is_empty() {
+        None
+    } else {
+        Some(filter_hn_items(
+            fetch_items(&client, &story_ids, use_cache).await,
+            filters,
+        ))
+    };
+    let pool: Vec<u64> = match &matching {
+        Some(matching) => matching.iter().map(|item| item.id).collect(),
+        None => story_ids,
+    };
+    let total_items = pool.len();
+
+    // A cursor picks up after the ids it has already returned; a page number
+    // counts from the top of the current list
+    let (page, seen, page_ids) = match cursor {
+        Some(cursor) => {
+            let page_ids = cursor.next_page(&pool, limit);
+            (cursor.page_number(limit), cursor, page_ids)
+        }
+        None if total_items == 0 => (page, IdCursor::new(&scope, Vec::new()), Vec::new()),
+        None => {
+            let (start, end) =
+                calculate_pagination(total_items, page, limit).map_err(|e| eyre!("{}", e))?;
+            (
+                page,
+                IdCursor::new(&scope, pool[..start].to_vec()),
+                pool[start..end].to_vec(),
+            )
+        }
+    };
+    let next_cursor = seen.next_token(&pool, &page_ids).map(super::store_cursor);
+
+    let items = match matching {
+        Some(matching) => {
+            let page_set: HashSet<u64> = page_ids.iter().copied().collect();
+            matching
+                .into_iter()
+                .filter(|item| page_set.contains(&item.id))
+                .collect()
+        }
+        // Fetch story details in parallel
+        None => fetch_items(&client, &page_ids, use_cache).await,
+    };
 
     // Transform to output format
-    Ok(transform_hn_items(
-        items,
-        story_type,
-        page,
-        limit,
-        total_items,
-    ))
+    let mut output = transform_hn_items(items, story_type, page, limit, total_items);
+    output.pagination.next_cursor = next_cursor;
+
+    // Keep the filters on the navigation commands
+    let args = filters.command_args();
+    let pagination = &mut output.pagination;
+    for command in [
+        &mut pagination.next_page_command,
+        &mut pagination.prev_page_command,
+    ]
+    .into_iter()
+    .flatten()
+    {
+        command.push_str(&args);
+    }
+    Ok(output)
 }
 
 /// Convert list output to JSON string
@@ -104,10 +307,40 @@ fn format_list_json(output: &ListOutput) -> Result<String> {
     serde_json::to_string_pretty(output).map_err(|e| eyre!("JSON serialization failed: {}", e))
 }
 
+/// Render list output as an aligned table
+fn format_list_table(output: &ListOutput, now: i64) -> String {
+    if output.items.is_empty() {
+        return "No stories on this page.\n".to_string();
+    }
+    let mut table = crate::prelude::new_table();
+    table.set_titles(prettytable::Row::new(
+        LIST_TABLE_HEADERS
+            .iter()
+            .map(|header| prettytable::Cell::new(&header.bold().cyan().to_string()))
+            .collect(),
+    ));
+    for [rank, title, score, comments, age] in list_table_rows(output, now) {
+        table.add_row(prettytable::row![
+            rank.yellow(),
+            title.bright_white(),
+            score.bright_yellow(),
+            comments.bright_magenta(),
+            age.bright_black()
+        ]);
+    }
+    table.to_string()
+}
+
 /// Convert list output to formatted text with colors
-fn format_list_text(items: &[ListItem], options: &ListOptions, total_items: usize) -> String {
+fn format_list_text(
+    items: &[ListItem],
+    options: &ListOptions,
+    total_items: usize,
+    filters: &ListFilters,
+) -> String {
     let mut result = String::new();
     let total_pages = total_items.div_ceil(options.limit);
+    let filter_args = filters.command_args();
 
     // Header
     result.push_str(&format!("\n{}\n", "=".repeat(80).bright_cyan()));
@@ -198,9 +431,10 @@ fn format_list_text(items: &[ListItem], options: &ListOptions, total_items: usiz
             "  {}: {}\n",
             "Next page".green(),
             format!(
-                "mcptools hn list {} --page {}",
+                "mcptools hn list {} --page {}{}",
                 options.story_type,
-                options.page + 1
+                options.page + 1,
+                filter_args
             )
             .cyan()
         ));
@@ -210,9 +444,10 @@ fn format_list_text(items: &[ListItem], options: &ListOptions, total_items: usiz
             "  {}: {}\n",
             "Previous page".green(),
             format!(
-                "mcptools hn list {} --page {}",
+                "mcptools hn list {} --page {}{}",
                 options.story_type,
-                options.page - 1
+                options.page - 1,
+                filter_args
             )
             .cyan()
         ));
@@ -221,7 +456,11 @@ fn format_list_text(items: &[ListItem], options: &ListOptions, total_items: usiz
         result.push_str(&format!(
             "  {}: {}\n",
             "First page".green(),
-            format!("mcptools hn list {} --page 1", options.story_type).cyan()
+            format!(
+                "mcptools hn list {} --page 1{}",
+                options.story_type, filter_args
+            )
+            .cyan()
         ));
     }
 
@@ -272,8 +511,13 @@ fn output_json(output: &ListOutput) -> Result<()> {
     Ok(())
 }
 
-fn output_formatted(items: &[ListItem], options: &ListOptions, total_items: usize) -> Result<()> {
-    let formatted = format_list_text(items, options, total_items);
+fn output_formatted(
+    items: &[ListItem],
+    options: &ListOptions,
+    total_items: usize,
+    filters: &ListFilters,
+) -> Result<()> {
+    let formatted = format_list_text(items, options, total_items, filters);
     print!("{}", formatted);
     Ok(())
 }
@@ -305,6 +549,7 @@ mod tests {
                 limit: 30,
                 next_page_command: None,
                 prev_page_command: None,
+                next_cursor: None,
             },
         }
     }
@@ -315,6 +560,15 @@ mod tests {
             limit,
             page,
             json: false,
+            format: ListFormat::Text,
+            min_score: None,
+            domain: None,
+            author: None,
+            keyword: None,
+            since: None,
+            until: None,
+            sort: ListSort::Rank,
+            no_cache: false,
         }
     }
 
@@ -370,6 +624,7 @@ mod tests {
             limit: 10,
             next_page_command: Some("mcptools hn list ask --page 3".to_string()),
             prev_page_command: Some("mcptools hn list ask --page 1".to_string()),
+            next_cursor: None,
         };
 
         let json = format_list_json(&output).unwrap();
@@ -412,12 +667,34 @@ mod tests {
         assert_eq!(parsed["items"].as_array().unwrap().len(), 1);
     }
 
+    #[test]
+    fn test_format_list_table() {
+        let output = create_test_output(
+            vec![
+                create_test_item(1, "First Story"),
+                create_test_item(2, "Second Story"),
+            ],
+            "top",
+        );
+
+        let table = format_list_table(&output, 0);
+        assert!(table.contains("Title"));
+        assert!(table.contains("Comments"));
+        assert!(table.contains("First Story"));
+        assert!(table.contains("42"));
+        // "2 hours ago" is not a timestamp, so the age is unknown.
+        assert!(table.contains('?'));
+
+        let empty = create_test_output(vec![], "top");
+        assert_eq!(format_list_table(&empty, 0), "No stories on this page.\n");
+    }
+
     #[test]
     fn test_format_list_text_basic() {
         let item = create_test_item(1, "Test Story");
         let options = create_test_options("top", 1, 30);
 
-        let formatted = format_list_text(&[item], &options, 1);
+        let formatted = format_list_text(&[item], &options, 1, &ListFilters::default());
 
         assert!(formatted.contains("HACKERNEWS TOP STORIES"));
         assert!(formatted.contains("Page 1 of 1"));
@@ -434,7 +711,7 @@ mod tests {
         ];
         let options = create_test_options("new", 1, 30);
 
-        let formatted = format_list_text(&items, &options, 3);
+        let formatted = format_list_text(&items, &options, 3, &ListFilters::default());
 
         assert!(formatted.contains("First Story"));
         assert!(formatted.contains("Second Story"));
@@ -448,7 +725,7 @@ mod tests {
     fn test_format_list_text_empty() {
         let options = create_test_options("top", 1, 30);
 
-        let formatted = format_list_text(&[], &options, 0);
+        let formatted = format_list_text(&[], &options, 0, &ListFilters::default());
 
         assert!(formatted.contains("No stories on this page"));
     }
@@ -458,18 +735,38 @@ mod tests {
         let item = create_test_item(1, "Test Story");
         let options = create_test_options("ask", 1, 30);
 
-        let formatted = format_list_text(&[item], &options, 1);
+        let formatted = format_list_text(&[item], &options, 1, &ListFilters::default());
 
         assert!(formatted.contains("HACKERNEWS ASK STORIES"));
         assert!(formatted.contains("=".repeat(80).as_str()));
     }
 
+    #[test]
+    fn test_format_list_text_keeps_filters_in_navigation() {
+        let item = create_test_item(1, "Test Story");
+        let options = ListOptions {
+            min_score: Some(100),
+            domain: Some("github.com".to_string()),
+            ..create_test_options("top", 2, 10)
+        };
+        let filters = options.filters().unwrap();
+
+        let formatted = format_list_text(&[item], &options, 50, &filters);
+
+        assert!(
+            formatted.contains("mcptools hn list top --page 3 --min-score 100 --domain github.com")
+        );
+        assert!(
+            formatted.contains("mcptools hn list top --page 1 --min-score 100 --domain github.com")
+        );
+    }
+
     #[test]
     fn test_format_list_text_includes_pagination() {
         let item = create_test_item(1, "Test Story");
         let options = create_test_options("top", 2, 10);
 
-        let formatted = format_list_text(&[item], &options, 50);
+        let formatted = format_list_text(&[item], &options, 50, &ListFilters::default());
 
         assert!(formatted.contains("Showing page"));
         assert!(formatted.contains("2"));
@@ -485,7 +782,7 @@ mod tests {
         let item = create_test_item(1, "Test Story");
         let options = create_test_options("show", 2, 10);
 
-        let formatted = format_list_text(&[item], &options, 50);
+        let formatted = format_list_text(&[item], &options, 50, &ListFilters::default());
 
         assert!(formatted.contains("NAVIGATION"));
         assert!(formatted.contains("To navigate"));
@@ -496,7 +793,7 @@ mod tests {
         let item = create_test_item(1, "Test Story");
         let options = create_test_options("top", 1, 10);
 
-        let formatted = format_list_text(&[item], &options, 50);
+        let formatted = format_list_text(&[item], &options, 50, &ListFilters::default());
 
         assert!(formatted.contains("Next page"));
         assert!(!formatted.contains("Previous page"));
@@ -507,7 +804,7 @@ mod tests {
         let item = create_test_item(1, "Test Story");
         let options = create_test_options("top", 5, 10);
 
-        let formatted = format_list_text(&[item], &options, 50);
+        let formatted = format_list_text(&[item], &options, 50, &ListFilters::default());
 
         assert!(!formatted.contains("Next page"));
         assert!(formatted.contains("Previous page"));
@@ -519,7 +816,7 @@ mod tests {
         let item = create_test_item(1, "Test Story");
         let options = create_test_options("top", 3, 10);
 
-        let formatted = format_list_text(&[item], &options, 50);
+        let formatted = format_list_text(&[item], &options, 50, &ListFilters::default());
 
         assert!(formatted.contains("Next page"));
         assert!(formatted.contains("Previous page"));
@@ -533,7 +830,7 @@ mod tests {
         for story_type in story_types {
             let item = create_test_item(1, "Test Story");
             let options = create_test_options(story_type, 1, 30);
-            let formatted = format_list_text(&[item], &options, 1);
+            let formatted = format_list_text(&[item], &options, 1, &ListFilters::default());
 
             assert!(
                 formatted.contains(&format!("HACKERNEWS {} STORIES", story_type.to_uppercase()))
@@ -554,7 +851,7 @@ mod tests {
         };
         let options = create_test_options("top", 1, 30);
 
-        let formatted = format_list_text(&[item], &options, 1);
+        let formatted = format_list_text(&[item], &options, 1, &ListFilters::default());
 
         assert!(formatted.contains("(No title)"));
         assert!(formatted.contains("unknown"));
@@ -566,7 +863,7 @@ mod tests {
         let item = create_test_item(42, "Test Story");
         let options = create_test_options("top", 1, 30);
 
-        let formatted = format_list_text(&[item], &options, 1);
+        let formatted = format_list_text(&[item], &options, 1, &ListFilters::default());
 
         assert!(formatted.contains("By"));
         assert!(formatted.contains("testuser"));
@@ -583,7 +880,7 @@ mod tests {
         let item = create_test_item(8863, "Test Story");
         let options = create_test_options("top", 1, 30);
 
-        let formatted = format_list_text(&[item], &options, 1);
+        let formatted = format_list_text(&[item], &options, 1, &ListFilters::default());
 
         assert!(formatted.contains("mcptools hn read 8863"));
         assert!(formatted.contains("Example"));
@@ -594,7 +891,7 @@ mod tests {
         let item = create_test_item(1, "Test Story");
         let options = create_test_options("top", 1, 30);
 
-        let formatted = format_list_text(&[item], &options, 1);
+        let formatted = format_list_text(&[item], &options, 1, &ListFilters::default());
 
         assert!(formatted.contains("To change page size"));
         assert!(formatted.contains("To list other story types"));
@@ -0,0 +1,236 @@
+use crate::prelude::{eprintln, println, *};
+use colored::Colorize;
+use std::collections::HashMap;
+
+use mcptools_core::hn::{
+    apply_firebase_event, drain_firebase_events, format_timestamp, rank_changes, LiveRankEvent,
+};
+
+use super::list_items::story_list_endpoint;
+use super::{fetch_items, get_api_base};
+
+#[derive(Debug, clap::Args, Clone)]
+pub struct LiveOptions {
+    /// Story list to follow: top, new, best, ask, show, job
+    #[arg(default_value = "top")]
+    pub story_type: String,
+
+    /// Number of ranks to follow, from the top of the list
+    #[arg(short, long, env = "HN_LIMIT", default_value = "30")]
+    pub limit: usize,
+
+    /// Stop after this many updates that changed the followed ranks
+    /// (default: run until interrupted)
+    #[arg(long, value_name = "N")]
+    pub count: Option<u64>,
+
+    /// Print one JSON event per line: snapshot, entered, moved, or left
+    #[arg(long)]
+    pub json: bool,
+}
+
+pub async fn run(options: LiveOptions, global: crate::Global) -> Result<()> {
+    if options.limit == 0 {
+        return Err(eyre!("--limit must be at least 1"));
+    }
+    let endpoint = story_list_endpoint(&options.story_type)?;
+    let url = format!("{}/{endpoint}.json", get_api_base());
+    if global.verbose {
+        println!("Streaming {}", url);
+    }
+
+    let client = reqwest::Client::new();
+    let mut ids: Option<Vec<u64>> = None;
+    let mut updates = 0;
+    loop {
+        let mut response = client
+            .get(&url)
+            .header(reqwest::header::ACCEPT, "text/event-stream")
+            .send()
+            .await
+            .map_err(|e| eyre!("Failed to open {} stream: {}", options.story_type, e))?;
+        if !response.status().is_success() {
+            return Err(eyre!(
+                "Failed to open {} stream: HTTP {}",
+                options.story_type,
+                response.status()
+            ));
+        }
+
+        let mut buffer = Vec::new();
+        while let Some(chunk) = response
+            .chunk()
+            .await
+            .map_err(|e| eyre!("Stream interrupted: {}", e))?
+        {
+            buffer.extend_from_slice(&chunk);
+            for event in drain_firebase_events(&mut buffer) {
+                match event.event.as_str() {
+                    "put" | "patch" => {}
+                    "cancel" | "auth_revoked" => {
+                        return Err(eyre!("Stream closed by the server: {}", event.event))
+                    }
+                    _ => continue,
+                }
+
+                let now = chrono::Utc::now().timestamp();
+                let Some(current) = &mut ids else {
+                    let mut initial = Vec::new();
+                    apply_firebase_event(&mut initial, &event).map_err(|e| eyre!(e))?;
+                    emit(
+                        &options,
+                        &LiveRankEvent::Snapshot {
+                            list: options.story_type.clone(),
+                            at: format_timestamp(Some(now as u64)),
+                            ids: initial.iter().take(options.limit).copied().collect(),
+                        },
+                    )?;
+                    ids = Some(initial);
+                    continue;
+                };
+
+                let previous = current.clone();
+                apply_firebase_event(current, &event).map_err(|e| eyre!(e))?;
+                let changes =
+                    rank_changes(&options.story_type, &previous, current, options.limit, now);
+                if changes.is_empty() {
+                    continue;
+                }
+                for change in with_titles(&client, changes).await {
+                    emit(&options, &change)?;
+                }
+
+                updates += 1;
+                if options.count == Some(updates) {
+                    return Ok(());
+                }
+            }
+        }
+
+        // Firebase closes long-lived streams now and then. Reconnecting
+        // replays the whole list as a `put`, which is compared against the
+        // list we already have like any other update.
+        if global.verbose {
+            eprintln!("Stream ended, reconnecting");
+        }
+    }
+}
+
+/// Fill in the titles of stories that entered the followed ranks
+async fn with_titles(client: &reqwest::Client, changes: Vec<LiveRankEvent>) -> Vec<LiveRankEvent> {
+    let entered: Vec<u64> = changes
+        .iter()
+        .filter_map(|change| match change {
+            LiveRankEvent::Entered { id, .. } => Some(*id),
+            _ => None,
+        })
+        .collect();
+    if entered.is_empty() {
+        return changes;
+    }
+
+    let titles: HashMap<u64, String> = fetch_items(client, &entered, true)
+        .await
+        .into_iter()
+        .filter_map(|item| Some((item.id, item.title?)))
+        .collect();
+    changes
+        .into_iter()
+        .map(|mut change| {
+            if let LiveRankEvent::Entered { id, title, .. } = &mut change {
+                *title = titles.get(id).cloned();
+            }
+            change
+        })
+        .collect()
+}
+
+fn emit(options: &LiveOptions, event: &LiveRankEvent) -> Result<()> {
+    if options.json {
+        println!("{}", serde_json::to_string(event)?);
+    } else {
+        print!("{}", format_event_text(event));
+    }
+    Ok(())
+}
+
+/// Render one live event for the terminal
+fn format_event_text(event: &LiveRankEvent) -> String {
+    match event {
+        LiveRankEvent::Snapshot { list, ids, .. } => format!(
+            "{} {} stories, ranks 1-{}; press Ctrl-C to stop\n",
+            "Following".bright_cyan().bold(),
+            list.to_uppercase().white().bold(),
+            ids.len()
+        ),
+        LiveRankEvent::Entered {
+            at,
+            id,
+            rank,
+            title,
+            ..
+        } => format!(
+            "{} {} {} {} {}\n",
+            at.as_deref().unwrap_or("unknown").bright_black(),
+            "+".green().bold(),
+            format!("#{rank}").yellow(),
+            title.as_deref().unwrap_or("(No title)").white().bold(),
+            format!("[{id}]").bright_black()
+        ),
+        LiveRankEvent::Moved {
+            at, id, from, to, ..
+        } => {
+            let arrow = if to < from {
+                "^".green().bold()
+            } else {
+                "v".red().bold()
+            };
+            format!(
+                "{} {} {} -> {} {}\n",
+                at.as_deref().unwrap_or("unknown").bright_black(),
+                arrow,
+                format!("#{from}").yellow(),
+                format!("#{to}").yellow(),
+                format!("[{id}]").bright_black()
+            )
+        }
+        LiveRankEvent::Left { at, id, from, .. } => format!(
+            "{} {} {} {}\n",
+            at.as_deref().unwrap_or("unknown").bright_black(),
+            "-".red().bold(),
+            format!("#{from}").yellow(),
+            format!("[{id}]").bright_black()
+        ),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_event_text() {
+        let entered = LiveRankEvent::Entered {
+            list: "top".to_string(),
+            at: Some("2024-01-01 00:00:00 UTC".to_string()),
+            id: 8863,
+            rank: 3,
+            title: Some("My YC app: Dropbox".to_string()),
+        };
+        let text = format_event_text(&entered);
+        assert!(text.contains("#3"));
+        assert!(text.contains("My YC app: Dropbox"));
+        assert!(text.contains("[8863]"));
+
+        let moved = LiveRankEvent::Moved {
+            list: "top".to_string(),
+            at: None,
+            id: 8863,
+            from: 5,
+            to: 2,
+        };
+        let text = format_event_text(&moved);
+        assert!(text.contains("#5"));
+        assert!(text.contains("#2"));
+    }
+}
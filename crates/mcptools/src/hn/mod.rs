@@ -1,19 +1,38 @@
-use crate::prelude::{println, *};
+use crate::prelude::{eprintln, println, *};
+use futures::stream::{self, StreamExt};
 use mcptools_core::hn::HnItem;
+use mcptools_core::pagination::{self, IdCursor};
+use mcptools_core::state::StateComponent;
 use regex::Regex;
 
+mod cache;
+pub mod export;
 pub mod list_items;
+pub mod live;
 pub mod read_item;
+pub mod search;
+pub mod updates;
+pub mod user;
+pub mod watch;
 
 // Re-export public data functions
 pub use list_items::list_items_data;
-pub use read_item::read_item_data;
+pub use read_item::{read_article_data, read_item_data, read_thread_data};
+pub use search::search_data;
+pub use updates::updates_data;
+pub use user::user_data;
 
 // Re-export domain types from core
 pub use mcptools_core::hn::{strip_html, CommentOutput, PaginationInfo, PostOutput};
 
 const HN_API_BASE: &str = "https://hacker-news.firebaseio.com/v0";
 
+/// Algolia-backed full-text search over HackerNews
+pub(crate) const HN_SEARCH_API_BASE: &str = "https://hn.algolia.com/api/v1";
+
+/// Item requests kept in flight at once when fetching many items
+const FETCH_CONCURRENCY: usize = 16;
+
 #[derive(Debug, clap::Parser)]
 #[command(name = "hn")]
 #[command(about = "HackerNews (news.ycombinator.com) operations")]
@@ -28,9 +47,33 @@ pub enum Commands {
     #[clap(name = "read")]
     Read(read_item::ReadOptions),
 
+    /// Export a story and its comments to a single Markdown document
+    #[clap(name = "export")]
+    Export(export::ExportOptions),
+
     /// List HackerNews stories (top, new, best, ask, show, job)
     #[clap(name = "list")]
     List(list_items::ListOptions),
+
+    /// Search HackerNews stories and comments by topic
+    #[clap(name = "search")]
+    Search(search::SearchOptions),
+
+    /// Show a HackerNews user's profile and recent submissions
+    #[clap(name = "user")]
+    User(user::UserOptions),
+
+    /// Show recently changed items and user profiles
+    #[clap(name = "updates")]
+    Updates(updates::UpdatesOptions),
+
+    /// Follow a story list over the Firebase stream and report rank changes
+    #[clap(name = "live")]
+    Live(live::LiveOptions),
+
+    /// Poll a story and report score changes and new comments
+    #[clap(name = "watch")]
+    Watch(watch::WatchOptions),
 }
 
 pub async fn run(app: App, global: crate::Global) -> Result<()> {
@@ -41,7 +84,13 @@ pub async fn run(app: App, global: crate::Global) -> Result<()> {
 
     match app.command {
         Commands::Read(options) => read_item::run(options, global).await,
+        Commands::Export(options) => export::run(options, global).await,
         Commands::List(options) => list_items::run(options, global).await,
+        Commands::Search(options) => search::run(options, global).await,
+        Commands::User(options) => user::run(options, global).await,
+        Commands::Updates(options) => updates::run(options, global).await,
+        Commands::Live(options) => live::run(options, global).await,
+        Commands::Watch(options) => watch::run(options, global).await,
     }
 }
 
@@ -70,7 +119,46 @@ pub fn extract_item_id(input: &str) -> Result<u64> {
     Err(eyre!("Invalid item ID or URL: {}", input))
 }
 
-pub async fn fetch_item(client: &reqwest::Client, id: u64) -> Result<HnItem> {
+/// Parse a cursor handed out by [`store_cursor`]: an 8-character hash is
+/// looked up in the pagination directory, anything else is decoded as the
+/// cursor token itself.
+pub(crate) fn load_cursor(token: &str, scope: &str) -> Result<IdCursor> {
+    let token = token.trim();
+    let stored = if token.len() == 8 && token.chars().all(|c| c.is_ascii_hexdigit()) {
+        crate::state::component_dir(StateComponent::Pagination)
+            .ok()
+            .and_then(|dir| pagination::load_token(&dir, token).ok())
+    } else {
+        None
+    };
+    IdCursor::decode(stored.as_deref().unwrap_or(token), scope).map_err(|e| eyre!("{}", e))
+}
+
+/// Keep a cursor token in the pagination directory and return its
+/// 8-character hash, so the handle stays short however many ids the cursor
+/// has seen. Falls back to the full token when it cannot be stored.
+pub(crate) fn store_cursor(token: String) -> String {
+    match crate::state::component_dir(StateComponent::Pagination)
+        .and_then(|dir| pagination::save_token(&dir, &token).map_err(|e| eyre!("{}", e)))
+    {
+        Ok(hash) => hash,
+        Err(e) => {
+            eprintln!("Warning: Failed to save pagination cursor: {}", e);
+            token
+        }
+    }
+}
+
+/// Fetch one item, serving it from the on-disk item cache when `use_cache`
+/// is set and the entry is fresh. Fetched items are stored back only when
+/// `use_cache` is set.
+pub async fn fetch_item(client: &reqwest::Client, id: u64, use_cache: bool) -> Result<HnItem> {
+    if use_cache {
+        if let Some(item) = cache::load(id) {
+            return Ok(item);
+        }
+    }
+
     let url = format!("{}/item/{id}.json", get_api_base());
     let response = client
         .get(&url)
@@ -91,9 +179,25 @@ pub async fn fetch_item(client: &reqwest::Client, id: u64) -> Result<HnItem> {
         .await
         .map_err(|e| eyre!("Failed to parse item {}: {}", id, e))?;
 
+    if use_cache {
+        cache::store(&item);
+    }
     Ok(item)
 }
 
+/// Fetch several items concurrently, returned in the order of `ids`
+///
+/// At most `FETCH_CONCURRENCY` requests run at once. Items that fail to
+/// fetch are skipped.
+pub async fn fetch_items(client: &reqwest::Client, ids: &[u64], use_cache: bool) -> Vec<HnItem> {
+    stream::iter(ids.iter().copied())
+        .map(|id| fetch_item(client, id, use_cache))
+        .buffered(FETCH_CONCURRENCY)
+        .filter_map(|result| async move { result.ok() })
+        .collect()
+        .await
+}
+
 pub fn truncate_text(text: &str, max_len: usize) -> String {
     if text.len() <= max_len {
         text.to_string()
@@ -1,14 +1,16 @@
 use crate::prelude::{println, *};
 use colored::Colorize;
-use futures::future::join_all;
 use mcptools_core::hn::{
-    build_comment_tree, build_post_output, count_tree_comments, flatten_comment_tree,
-    format_timestamp, strip_html, transform_comments, CommentOutput, HnItem, PaginationInfo,
-    PostOutput, ThreadedCommentOutput,
+    apply_dead_filter, build_comment_tree, build_poll_options, build_post_output,
+    build_thread_output, comment_output, count_tree_comments, flatten_comment_tree,
+    format_timestamp, html_to_markdown, is_deleted, is_readable_post, order_thread_items,
+    transform_comments, CommentOutput, HnItem, PaginationInfo, PollOption, PostArticle, PostOutput,
+    ThreadOutput, ThreadedCommentOutput,
 };
+use mcptools_core::pagination::IdCursor;
 use serde::Serialize;
 
-use super::{extract_item_id, fetch_item, truncate_text};
+use super::{extract_item_id, fetch_item, fetch_items, truncate_text};
 
 #[derive(Debug, clap::Args, serde::Deserialize, Clone)]
 pub struct ReadOptions {
@@ -31,6 +33,62 @@ pub struct ReadOptions {
     /// Read comment thread (provide comment ID)
     #[arg(short, long)]
     pub thread: Option<String>,
+
+    /// Levels of replies to include below the thread root (1 = direct
+    /// replies only); deeper replies can be read with their own --thread
+    #[arg(long, value_name = "LEVELS", requires = "thread")]
+    pub depth: Option<usize>,
+
+    /// Number of direct replies per thread page, each with its sub-thread
+    #[arg(long, value_name = "REPLIES", requires = "thread")]
+    pub thread_limit: Option<usize>,
+
+    /// Thread page to show (1-indexed)
+    #[arg(long, value_name = "PAGE", default_value = "1", requires = "thread")]
+    #[serde(default = "default_thread_page")]
+    pub thread_page: usize,
+
+    /// Show dead comments instead of hiding them
+    #[arg(long)]
+    #[serde(default)]
+    pub show_dead: bool,
+
+    /// Bypass the item cache: always fetch, and don't store the result
+    #[arg(long, env = "HN_NO_CACHE")]
+    #[serde(default)]
+    pub no_cache: bool,
+
+    /// Also fetch the story's linked article and convert it to Markdown
+    #[arg(long)]
+    #[serde(default)]
+    pub with_article: bool,
+
+    /// Characters of the article per page
+    #[arg(long, value_name = "CHARS", default_value = "5000")]
+    #[serde(default = "default_article_limit")]
+    pub article_limit: usize,
+
+    /// Page of the article to show (1-indexed)
+    #[arg(long, value_name = "PAGE", default_value = "1")]
+    #[serde(default = "default_article_page")]
+    pub article_page: usize,
+
+    /// Fetch the article with a plain HTTP request instead of headless Chrome
+    #[arg(long)]
+    #[serde(default)]
+    pub no_browser: bool,
+}
+
+fn default_thread_page() -> usize {
+    1
+}
+
+fn default_article_limit() -> usize {
+    5000
+}
+
+fn default_article_page() -> usize {
+    1
 }
 
 pub async fn run(options: ReadOptions, global: crate::Global) -> Result<()> {
@@ -47,17 +105,21 @@ pub async fn run(options: ReadOptions, global: crate::Global) -> Result<()> {
 
     // Fetch the main item
     let client = reqwest::Client::new();
-    let item = fetch_item(&client, item_id).await?;
+    let item = fetch_item(&client, item_id, !options.no_cache).await?;
 
-    // Validate it's a story
-    if item.item_type != "story" {
+    // Validate it's a story or poll
+    if !is_readable_post(&item) {
         return Err(eyre!(
-            "Item {} is not a story (type: {})",
+            "Item {} is not a story or poll (type: {})",
             item_id,
             item.item_type
         ));
     }
 
+    if is_deleted(&item) {
+        return Err(eyre!("Item {} has been deleted", item_id));
+    }
+
     // Get top-level comment IDs
     let comment_ids = item.kids.clone().unwrap_or_default();
     let total_comments = comment_ids.len();
@@ -73,24 +135,47 @@ pub async fn run(options: ReadOptions, global: crate::Global) -> Result<()> {
         .collect();
 
     // Fetch comments for this page
-    let comment_futures = paginated_ids.iter().map(|id| fetch_item(&client, *id));
-    let comments: Vec<HnItem> = join_all(comment_futures)
-        .await
-        .into_iter()
-        .filter_map(|r| r.ok())
-        .collect();
+    let comments = fetch_items(&client, &paginated_ids, !options.no_cache).await;
+    let (comments, hidden) = apply_dead_filter(comments, options.show_dead);
+    let mut extras = PostExtras {
+        poll_options: fetch_poll_options(&client, &item, !options.no_cache).await,
+        article: None,
+    };
+    if options.with_article {
+        if let Some(url) = &item.url {
+            extras.article = Some(
+                fetch_article(
+                    url,
+                    options.article_limit,
+                    options.article_page,
+                    options.no_browser,
+                )
+                .await,
+            );
+        }
+    }
 
     let total_pages = total_comments.div_ceil(options.limit);
 
     if options.json {
-        output_json(&item, &comments, &options, total_comments, total_pages)?;
+        output_json(
+            &item,
+            &comments,
+            &extras,
+            &options,
+            total_comments,
+            total_pages,
+            hidden,
+        )?;
     } else {
         output_formatted(
             &item,
             &comments,
+            &extras,
             &options,
             total_comments,
             total_pages,
+            hidden,
             &item_id.to_string(),
         )?;
     }
@@ -104,77 +189,166 @@ async fn read_thread(
     options: &ReadOptions,
     global: crate::Global,
 ) -> Result<()> {
-    let thread_item_id = thread_id
-        .parse::<u64>()
-        .map_err(|_| eyre!("Invalid thread ID: {}", thread_id))?;
-
     if global.verbose {
-        println!("Fetching comment thread: {}", thread_item_id);
+        println!("Fetching comment thread: {}", thread_id);
     }
 
-    let client = reqwest::Client::new();
-    let comment = fetch_item(&client, thread_item_id).await?;
-
-    if comment.item_type != "comment" {
-        return Err(eyre!("Item {} is not a comment", thread_item_id));
-    }
-
-    // Fetch all child comments recursively
-    let children = fetch_comment_tree(&client, &comment).await?;
+    let thread = read_thread_data(
+        post_id.to_string(),
+        thread_id,
+        options.depth,
+        options.thread_limit,
+        options.thread_page,
+        options.show_dead,
+        !options.no_cache,
+    )
+    .await?;
 
     if options.json {
-        output_thread_json(&comment, &children)?;
+        output_thread_json(&thread)?;
     } else {
-        output_thread_formatted(&comment, &children, post_id, options)?;
+        output_thread_formatted(&thread, post_id, options)?;
     }
 
     Ok(())
 }
 
-fn fetch_comment_tree<'a>(
-    client: &'a reqwest::Client,
-    parent: &'a HnItem,
-) -> std::pin::Pin<Box<dyn std::future::Future<Output = Result<Vec<HnItem>>> + 'a>> {
-    Box::pin(async move {
-        let mut all_comments = Vec::new();
-
-        if let Some(kids) = &parent.kids {
-            let comment_futures = kids.iter().map(|id| fetch_item(client, *id));
-            let comments: Vec<HnItem> = join_all(comment_futures)
-                .await
-                .into_iter()
-                .filter_map(|r| r.ok())
-                .collect();
+/// Parts of a post fetched alongside its comments
+#[derive(Debug, Default)]
+struct PostExtras {
+    poll_options: Vec<PollOption>,
+    article: Option<PostArticle>,
+}
 
-            for comment in comments {
-                let children = fetch_comment_tree(client, &comment).await?;
-                all_comments.push(comment);
-                all_comments.extend(children);
-            }
-        }
+/// Render the linked article section of `hn read --with-article`
+fn format_article_text(article: &PostArticle, item_id: u64) -> String {
+    let mut result = String::new();
+    result.push_str(&format!("\n{}\n", "=".repeat(80).bright_green()));
+    result.push_str(&format!(
+        "{}: {}\n",
+        "ARTICLE".bright_green().bold(),
+        article
+            .title
+            .as_deref()
+            .unwrap_or(&article.url)
+            .white()
+            .bold()
+    ));
+    result.push_str(&format!("{}\n", "=".repeat(80).bright_green()));
 
-        Ok(all_comments)
-    })
+    if let Some(error) = &article.error {
+        result.push_str(&format!(
+            "{} {}\n",
+            "Could not fetch the article:".red(),
+            error
+        ));
+        return result;
+    }
+    if let Some(content) = &article.content {
+        result.push_str(&format!("\n{}\n", content.trim_end()));
+    }
+    if let Some(pagination) = article.pagination.as_ref().filter(|p| p.has_more) {
+        result.push_str(&format!(
+            "\n{} ({} {} {}): {}\n",
+            "More of the article".green(),
+            "page".bright_white(),
+            pagination.current_page,
+            format!("of {}", pagination.total_pages).bright_white(),
+            format!(
+                "mcptools hn read {} --with-article --article-page {}",
+                item_id,
+                pagination.current_page + 1
+            )
+            .cyan()
+        ));
+    }
+    result
+}
+
+/// Fetch `url` with the md fetch pipeline, keeping page `page` of `limit`
+/// characters. Uses md fetch's response cache and falls back to plain HTTP
+/// when Chrome is not installed.
+async fn fetch_article(url: &str, limit: usize, page: usize, no_browser: bool) -> PostArticle {
+    let config = crate::md::FetchConfig {
+        url: url.to_string(),
+        limit,
+        page,
+        paginated: true,
+        cache_ttl: Some(3600),
+        no_browser,
+        ..Default::default()
+    };
+    let result = tokio::task::spawn_blocking(move || crate::md::fetch_and_convert_data(config))
+        .await
+        .map_err(|e| eyre!("Task join error: {}", e))
+        .and_then(|result| result)
+        .map_err(|e| e.to_string());
+    PostArticle::from_fetch(url, result)
+}
+
+/// Fetch the options of a poll in display order; empty for other items
+async fn fetch_poll_options(
+    client: &reqwest::Client,
+    item: &HnItem,
+    use_cache: bool,
+) -> Vec<PollOption> {
+    let Some(parts) = &item.parts else {
+        return Vec::new();
+    };
+    build_poll_options(fetch_items(client, parts, use_cache).await)
+}
+
+/// Fetch every reply below `parent`, ordered depth-first as on HN
+///
+/// Replies are fetched one level at a time, with a bounded number of each
+/// level's requests in flight, instead of one comment after another.
+pub(super) async fn fetch_comment_tree(
+    client: &reqwest::Client,
+    parent: &HnItem,
+    use_cache: bool,
+) -> Vec<HnItem> {
+    let root_kids = parent.kids.clone().unwrap_or_default();
+    fetch_reply_tree(client, &root_kids, None, use_cache).await
+}
+
+/// Fetch `root_kids` and the replies below them, ordered depth-first
+///
+/// With `max_depth`, only that many levels are fetched (1 = `root_kids`
+/// alone).
+async fn fetch_reply_tree(
+    client: &reqwest::Client,
+    root_kids: &[u64],
+    max_depth: Option<usize>,
+    use_cache: bool,
+) -> Vec<HnItem> {
+    let mut all_comments = Vec::new();
+    let mut level = root_kids.to_vec();
+    let mut depth = 0;
+
+    while !level.is_empty() && max_depth.is_none_or(|max| depth < max) {
+        depth += 1;
+        let comments = fetch_items(client, &level, use_cache).await;
+        level = comments
+            .iter()
+            .flat_map(|comment| comment.kids.iter().flatten().copied())
+            .collect();
+        all_comments.extend(comments);
+    }
+
+    order_thread_items(root_kids, all_comments)
 }
 
 /// Build JSON string for post with comments
 fn format_post_json(
     item: &HnItem,
     comments: &[HnItem],
+    extras: &PostExtras,
     options: &ReadOptions,
     total_comments: usize,
     total_pages: usize,
+    hidden_comments: usize,
 ) -> Result<String> {
-    let comment_outputs: Vec<CommentOutput> = comments
-        .iter()
-        .map(|c| CommentOutput {
-            id: c.id,
-            author: c.by.clone(),
-            time: format_timestamp(c.time),
-            text: c.text.as_ref().map(|t| strip_html(t)),
-            replies_count: c.kids.as_ref().map(|k| k.len()).unwrap_or(0),
-        })
-        .collect();
+    let comment_outputs: Vec<CommentOutput> = comments.iter().map(comment_output).collect();
 
     let next_page = if options.page < total_pages {
         Some(format!(
@@ -203,9 +377,12 @@ fn format_post_json(
         author: item.by.clone(),
         score: item.score,
         time: format_timestamp(item.time),
-        text: item.text.as_ref().map(|t| strip_html(t)),
+        text: item.text.as_deref().map(html_to_markdown),
         total_comments: item.descendants,
         comments: comment_outputs,
+        hidden_comments,
+        poll_options: extras.poll_options.clone(),
+        article: extras.article.clone(),
         pagination: PaginationInfo {
             current_page: options.page,
             total_pages,
@@ -213,6 +390,7 @@ fn format_post_json(
             limit: options.limit,
             next_page_command: next_page,
             prev_page_command: prev_page,
+            next_cursor: None,
         },
     };
 
@@ -222,22 +400,35 @@ fn format_post_json(
 fn output_json(
     item: &HnItem,
     comments: &[HnItem],
+    extras: &PostExtras,
     options: &ReadOptions,
     total_comments: usize,
     total_pages: usize,
+    hidden_comments: usize,
 ) -> Result<()> {
-    let json = format_post_json(item, comments, options, total_comments, total_pages)?;
+    let json = format_post_json(
+        item,
+        comments,
+        extras,
+        options,
+        total_comments,
+        total_pages,
+        hidden_comments,
+    )?;
     println!("{}", json);
     Ok(())
 }
 
 /// Build formatted text output for post with comments
+#[allow(clippy::too_many_arguments)]
 fn format_post_text(
     item: &HnItem,
     comments: &[HnItem],
+    extras: &PostExtras,
     options: &ReadOptions,
     total_comments: usize,
     total_pages: usize,
+    hidden_comments: usize,
     item_id: &str,
 ) -> String {
     let mut result = String::new();
@@ -291,7 +482,24 @@ fn format_post_text(
     ));
 
     if let Some(text) = &item.text {
-        result.push_str(&format!("\n{}\n", strip_html(text).bright_white()));
+        result.push_str(&format!("\n{}\n", html_to_markdown(text).bright_white()));
+    }
+
+    if !extras.poll_options.is_empty() {
+        result.push_str(&format!("\n{}:\n", "Poll".green()));
+        for option in &extras.poll_options {
+            let score = option.score.unwrap_or(0);
+            result.push_str(&format!(
+                "  {} {}\n",
+                format!("[{} {}]", score, if score == 1 { "vote" } else { "votes" })
+                    .bright_yellow(),
+                option.text.as_deref().unwrap_or("(no text)").bright_white()
+            ));
+        }
+    }
+
+    if let Some(article) = &extras.article {
+        result.push_str(&format_article_text(article, item.id));
     }
 
     // Comments section
@@ -332,8 +540,8 @@ fn format_post_text(
             ));
 
             if let Some(text) = &comment.text {
-                let stripped = strip_html(text);
-                let truncated = truncate_text(&stripped, 500);
+                let body = html_to_markdown(text);
+                let truncated = truncate_text(&body, 500);
                 result.push_str(&format!("{}\n", truncated.white()));
             }
 
@@ -347,6 +555,14 @@ fn format_post_text(
         }
     }
 
+    if hidden_comments > 0 {
+        result.push_str(&format!(
+            "\n{}\n",
+            format!("({hidden_comments} dead or deleted comments hidden; use --show-dead to include them)")
+                .bright_black()
+        ));
+    }
+
     // Navigation section
     result.push_str(&format!("\n{}\n", "=".repeat(80).bright_yellow()));
     result.push_str(&format!("{}\n", "NAVIGATION".bright_yellow().bold()));
@@ -425,75 +641,47 @@ fn format_post_text(
     result
 }
 
+#[allow(clippy::too_many_arguments)]
 fn output_formatted(
     item: &HnItem,
     comments: &[HnItem],
+    extras: &PostExtras,
     options: &ReadOptions,
     total_comments: usize,
     total_pages: usize,
+    hidden_comments: usize,
     item_id: &str,
 ) -> Result<()> {
     let formatted = format_post_text(
         item,
         comments,
+        extras,
         options,
         total_comments,
         total_pages,
+        hidden_comments,
         item_id,
     );
     print!("{}", formatted);
     Ok(())
 }
 
-#[derive(Serialize)]
-struct ThreadOutput {
-    comment: CommentOutput,
-    replies: Vec<CommentOutput>,
-}
-
 /// Build JSON string for comment thread
-fn format_thread_json(comment: &HnItem, children: &[HnItem]) -> Result<String> {
-    let comment_output = CommentOutput {
-        id: comment.id,
-        author: comment.by.clone(),
-        time: format_timestamp(comment.time),
-        text: comment.text.as_ref().map(|t| strip_html(t)),
-        replies_count: comment.kids.as_ref().map(|k| k.len()).unwrap_or(0),
-    };
-
-    let replies: Vec<CommentOutput> = children
-        .iter()
-        .map(|c| CommentOutput {
-            id: c.id,
-            author: c.by.clone(),
-            time: format_timestamp(c.time),
-            text: c.text.as_ref().map(|t| strip_html(t)),
-            replies_count: c.kids.as_ref().map(|k| k.len()).unwrap_or(0),
-        })
-        .collect();
-
-    let output = ThreadOutput {
-        comment: comment_output,
-        replies,
-    };
-
-    serde_json::to_string_pretty(&output).map_err(|e| eyre!("JSON serialization failed: {}", e))
+fn format_thread_json(thread: &ThreadOutput) -> Result<String> {
+    serde_json::to_string_pretty(thread).map_err(|e| eyre!("JSON serialization failed: {}", e))
 }
 
-fn output_thread_json(comment: &HnItem, children: &[HnItem]) -> Result<()> {
-    let json = format_thread_json(comment, children)?;
+fn output_thread_json(thread: &ThreadOutput) -> Result<()> {
+    let json = format_thread_json(thread)?;
     println!("{}", json);
     Ok(())
 }
 
 /// Build formatted text output for comment thread
-fn format_thread_text(
-    comment: &HnItem,
-    children: &[HnItem],
-    post_id: &str,
-    options: &ReadOptions,
-) -> String {
+fn format_thread_text(thread: &ThreadOutput, post_id: &str, options: &ReadOptions) -> String {
     let mut result = String::new();
+    let comment = &thread.comment;
+    let pagination = &thread.pagination;
 
     result.push_str(&format!("\n{}\n", "=".repeat(80).bright_cyan()));
     result.push_str(&format!("{}\n", "COMMENT THREAD".bright_cyan().bold()));
@@ -504,9 +692,9 @@ fn format_thread_text(
         "[Root Comment]".yellow().bold(),
         "by".bright_black(),
         comment
-            .by
-            .as_ref()
-            .unwrap_or(&"(unknown)".to_string())
+            .author
+            .as_deref()
+            .unwrap_or("(unknown)")
             .bright_white(),
         "ID".bright_black(),
         comment.id.to_string().bright_white()
@@ -514,60 +702,81 @@ fn format_thread_text(
     result.push_str(&format!(
         "{}: {}\n",
         "Time".green(),
-        format_timestamp(comment.time)
-            .unwrap_or("(unknown)".to_string())
+        comment
+            .time
+            .as_deref()
+            .unwrap_or("(unknown)")
             .bright_black()
     ));
 
     if let Some(text) = &comment.text {
-        result.push_str(&format!("\n{}\n", strip_html(text).bright_white()));
+        result.push_str(&format!("\n{}\n", text.bright_white()));
     }
 
-    if !children.is_empty() {
+    if !thread.replies.is_empty() {
         result.push_str(&format!("\n{}\n", "-".repeat(80).bright_magenta()));
-        result.push_str(&format!(
-            "{} ({} {})\n",
-            "REPLIES".bright_magenta().bold(),
-            children.len().to_string().bright_cyan().bold(),
-            "total".bright_white()
-        ));
+        if pagination.total_pages > 1 {
+            result.push_str(&format!(
+                "{} ({} {} {}, {} {})\n",
+                "REPLIES".bright_magenta().bold(),
+                "page".bright_white(),
+                pagination.current_page.to_string().bright_cyan().bold(),
+                format!("of {}", pagination.total_pages).bright_white(),
+                pagination.total_comments.to_string().bright_cyan().bold(),
+                "direct replies".bright_white()
+            ));
+        } else {
+            result.push_str(&format!(
+                "{} ({} {})\n",
+                "REPLIES".bright_magenta().bold(),
+                thread.replies.len().to_string().bright_cyan().bold(),
+                "total".bright_white()
+            ));
+        }
         result.push_str(&format!("{}\n", "-".repeat(80).bright_magenta()));
 
-        for (idx, child) in children.iter().enumerate() {
+        for (idx, reply) in thread.replies.iter().enumerate() {
+            let child = &reply.comment;
+            let indent = "  ".repeat(reply.depth);
             result.push_str(&format!(
-                "\n  {} {} {} ({}: {})\n",
+                "\n{}{} {} {} ({}: {})\n",
+                indent,
                 format!("[Reply #{}]", idx + 1).yellow().bold(),
                 "by".bright_black(),
                 child
-                    .by
-                    .as_ref()
-                    .unwrap_or(&"(unknown)".to_string())
+                    .author
+                    .as_deref()
+                    .unwrap_or("(unknown)")
                     .bright_white(),
                 "ID".bright_black(),
                 child.id.to_string().bright_white()
             ));
             result.push_str(&format!(
-                "  {}: {}\n",
+                "{}{}: {}\n",
+                indent,
                 "Time".green(),
-                format_timestamp(child.time)
-                    .unwrap_or("(unknown)".to_string())
-                    .bright_black()
+                child.time.as_deref().unwrap_or("(unknown)").bright_black()
             ));
 
             if let Some(text) = &child.text {
-                let stripped = strip_html(text);
-                let truncated = truncate_text(&stripped, 500);
+                let truncated = truncate_text(text, 500);
                 for line in truncated.lines() {
-                    result.push_str(&format!("  {}\n", line.white()));
+                    result.push_str(&format!("{}{}\n", indent, line.white()));
                 }
             }
 
-            if let Some(kids) = &child.kids {
-                if !kids.is_empty() {
+            if child.replies_count > 0 {
+                result.push_str(&format!(
+                    "{}{} {}\n",
+                    indent,
+                    "└─".bright_black(),
+                    format!("{} nested replies", child.replies_count).bright_magenta()
+                ));
+                if thread.max_depth == Some(reply.depth) {
                     result.push_str(&format!(
-                        "  {} {}\n",
-                        "└─".bright_black(),
-                        format!("{} nested replies", kids.len()).bright_magenta()
+                        "{}   {}\n",
+                        indent,
+                        format!("mcptools hn read {} --thread {}", post_id, child.id).cyan()
                     ));
                 }
             }
@@ -576,11 +785,35 @@ fn format_thread_text(
         result.push_str(&format!("\n{}\n", "No replies to this comment.".yellow()));
     }
 
+    if thread.hidden_replies > 0 {
+        result.push_str(&format!(
+            "\n{}\n",
+            format!(
+                "({} dead or deleted replies hidden; use --show-dead to include them)",
+                thread.hidden_replies
+            )
+            .bright_black()
+        ));
+    }
+
     // Navigation
     result.push_str(&format!("\n{}\n", "=".repeat(80).bright_yellow()));
     result.push_str(&format!("{}\n", "NAVIGATION".bright_yellow().bold()));
     result.push_str(&format!("{}\n", "=".repeat(80).bright_yellow()));
 
+    if pagination.next_page_command.is_some() || pagination.prev_page_command.is_some() {
+        result.push_str(&format!(
+            "\n{}:\n",
+            "To see more replies".bright_white().bold()
+        ));
+        if let Some(next) = &pagination.next_page_command {
+            result.push_str(&format!("  {}: {}\n", "Next page".green(), next.cyan()));
+        }
+        if let Some(prev) = &pagination.prev_page_command {
+            result.push_str(&format!("  {}: {}\n", "Previous page".green(), prev.cyan()));
+        }
+    }
+
     result.push_str(&format!(
         "\n{}:\n",
         "To go back to the post".bright_white().bold()
@@ -619,70 +852,170 @@ fn format_thread_text(
 }
 
 fn output_thread_formatted(
-    comment: &HnItem,
-    children: &[HnItem],
+    thread: &ThreadOutput,
     post_id: &str,
     options: &ReadOptions,
 ) -> Result<()> {
-    let formatted = format_thread_text(comment, children, post_id, options);
+    let formatted = format_thread_text(thread, post_id, options);
     print!("{}", formatted);
     Ok(())
 }
 
+/// Fetches one page of a comment thread: the root comment, `thread_limit`
+/// of its direct replies (all of them when unset), and their replies down
+/// to `depth` levels below the root
+pub async fn read_thread_data(
+    item: String,
+    thread_id: &str,
+    depth: Option<usize>,
+    thread_limit: Option<usize>,
+    thread_page: usize,
+    show_dead: bool,
+    use_cache: bool,
+) -> Result<ThreadOutput> {
+    let post_id = extract_item_id(&item)?;
+    let thread_item_id = thread_id
+        .parse::<u64>()
+        .map_err(|_| eyre!("Invalid thread ID: {}", thread_id))?;
+    if thread_page == 0 {
+        return Err(eyre!("Thread page must be at least 1"));
+    }
+    if thread_limit == Some(0) {
+        return Err(eyre!("Thread limit must be at least 1"));
+    }
+
+    let client = reqwest::Client::new();
+    let comment = fetch_item(&client, thread_item_id, use_cache).await?;
+
+    if comment.item_type != "comment" {
+        return Err(eyre!("Item {} is not a comment", thread_item_id));
+    }
+
+    // The thread root was requested explicitly, so always show it (with a
+    // placeholder if needed) and only filter its replies.
+    let (mut roots, _) = apply_dead_filter(vec![comment], true);
+    let comment = roots.remove(0);
+
+    let kids = comment.kids.clone().unwrap_or_default();
+    let limit = thread_limit.unwrap_or(kids.len().max(1));
+    let page_kids: Vec<u64> = kids
+        .iter()
+        .skip((thread_page - 1) * limit)
+        .take(limit)
+        .copied()
+        .collect();
+    if page_kids.is_empty() && thread_page > 1 {
+        return Err(eyre!(
+            "Thread page {} is out of range. Only {} pages available.",
+            thread_page,
+            kids.len().div_ceil(limit)
+        ));
+    }
+
+    let children = fetch_reply_tree(&client, &page_kids, depth, use_cache).await;
+
+    Ok(build_thread_output(
+        post_id,
+        &comment,
+        &page_kids,
+        children,
+        show_dead,
+        thread_page,
+        limit,
+        depth,
+    ))
+}
+
 /// Fetches HackerNews item data and returns it as a structured PostOutput
+///
+/// With a `cursor` from a previous call's `next_cursor`, `page` is ignored
+/// and the page holds the next top-level comments not yet returned.
 pub async fn read_item_data(
     item: String,
     limit: usize,
     page: usize,
-    thread: Option<String>,
+    cursor: Option<&str>,
+    show_dead: bool,
+    use_cache: bool,
 ) -> Result<PostOutput> {
     let item_id = extract_item_id(&item)?;
 
-    if thread.is_some() {
-        return Err(eyre!("Thread reading not supported in data mode yet"));
-    }
-
     // Fetch the main item (I/O)
     let client = reqwest::Client::new();
-    let hn_item = fetch_item(&client, item_id).await?;
+    let hn_item = fetch_item(&client, item_id, use_cache).await?;
 
-    // Validate it's a story
-    if hn_item.item_type != "story" {
+    // Validate it's a story or poll
+    if !is_readable_post(&hn_item) {
         return Err(eyre!(
-            "Item {} is not a story (type: {})",
+            "Item {} is not a story or poll (type: {})",
             item_id,
             hn_item.item_type
         ));
     }
 
-    // Get top-level comment IDs and calculate pagination bounds
+    if is_deleted(&hn_item) {
+        return Err(eyre!("Item {} has been deleted", item_id));
+    }
+
+    // Get top-level comment IDs and pick this page's, either after the ids
+    // a cursor has already returned or by page number
     let comment_ids = hn_item.kids.clone().unwrap_or_default();
     let total_comments = comment_ids.len();
-    let start = (page - 1) * limit;
-    let paginated_ids: Vec<u64> = comment_ids
-        .iter()
-        .skip(start)
-        .take(limit)
-        .copied()
-        .collect();
+    let scope = format!("hn:item:{}", item_id);
+    let (page, seen, paginated_ids) = match cursor {
+        Some(token) => {
+            let cursor = super::load_cursor(token, &scope)?;
+            let paginated_ids = cursor.next_page(&comment_ids, limit);
+            (cursor.page_number(limit), cursor, paginated_ids)
+        }
+        None => {
+            let start = ((page - 1) * limit).min(total_comments);
+            let paginated_ids: Vec<u64> = comment_ids
+                .iter()
+                .skip(start)
+                .take(limit)
+                .copied()
+                .collect();
+            (
+                page,
+                IdCursor::new(&scope, comment_ids[..start].to_vec()),
+                paginated_ids,
+            )
+        }
+    };
+    let next_cursor = seen
+        .next_token(&comment_ids, &paginated_ids)
+        .map(super::store_cursor);
 
     // Fetch comments for this page (I/O)
-    let comment_futures = paginated_ids.iter().map(|id| fetch_item(&client, *id));
-    let comments: Vec<HnItem> = join_all(comment_futures)
-        .await
-        .into_iter()
-        .filter_map(|r| r.ok())
-        .collect();
+    let comments = fetch_items(&client, &paginated_ids, use_cache).await;
 
     // Transform comments and build output using core functions
+    let (comments, hidden) = apply_dead_filter(comments, show_dead);
     let comment_outputs = transform_comments(comments);
-    Ok(build_post_output(
-        hn_item,
-        comment_outputs,
-        page,
-        limit,
-        total_comments,
-    ))
+    let poll_options = fetch_poll_options(&client, &hn_item, use_cache).await;
+    let mut output = build_post_output(hn_item, comment_outputs, page, limit, total_comments);
+    output.hidden_comments = hidden;
+    output.poll_options = poll_options;
+    output.pagination.next_cursor = next_cursor;
+    Ok(output)
+}
+
+/// Fetches a story with its top comments and its linked article converted
+/// to Markdown, for the `hn_read_article` tool
+pub async fn read_article_data(
+    item: String,
+    limit: usize,
+    article_limit: usize,
+    article_page: usize,
+    no_browser: bool,
+    use_cache: bool,
+) -> Result<PostOutput> {
+    let mut output = read_item_data(item, limit, 1, None, false, use_cache).await?;
+    if let Some(url) = output.url.clone() {
+        output.article = Some(fetch_article(&url, article_limit, article_page, no_browser).await);
+    }
+    Ok(output)
 }
 
 #[cfg(test)]
@@ -700,6 +1033,7 @@ mod tests {
             title: Some("Test Story".to_string()),
             score: Some(42),
             descendants: Some(10),
+            parts: None,
             kids: Some(vec![100, 200, 300]),
             parent: None,
             deleted: None,
@@ -718,6 +1052,7 @@ mod tests {
             title: None,
             score: None,
             descendants: None,
+            parts: None,
             kids: if has_kids { Some(vec![999]) } else { None },
             parent: Some(12345),
             deleted: None,
@@ -725,6 +1060,11 @@ mod tests {
         }
     }
 
+    fn create_test_thread(comment: HnItem, children: Vec<HnItem>) -> ThreadOutput {
+        let page_kids: Vec<u64> = children.iter().map(|c| c.id).collect();
+        build_thread_output(12345, &comment, &page_kids, children, false, 1, 10, None)
+    }
+
     fn create_test_options(page: usize, limit: usize) -> ReadOptions {
         ReadOptions {
             item: "12345".to_string(),
@@ -732,6 +1072,15 @@ mod tests {
             page,
             json: false,
             thread: None,
+            depth: None,
+            thread_limit: None,
+            thread_page: 1,
+            show_dead: false,
+            no_cache: false,
+            with_article: false,
+            article_limit: 5000,
+            article_page: 1,
+            no_browser: false,
         }
     }
 
@@ -742,7 +1091,7 @@ mod tests {
         let comments = vec![create_test_comment(100, "commenter1", false)];
         let options = create_test_options(1, 10);
 
-        let result = format_post_json(&item, &comments, &options, 3, 1);
+        let result = format_post_json(&item, &comments, &PostExtras::default(), &options, 3, 1, 0);
         assert!(result.is_ok());
 
         let json = result.unwrap();
@@ -758,7 +1107,7 @@ mod tests {
         let comments = vec![];
         let options = create_test_options(1, 10);
 
-        let result = format_post_json(&item, &comments, &options, 0, 1);
+        let result = format_post_json(&item, &comments, &PostExtras::default(), &options, 0, 1, 0);
         assert!(result.is_ok());
 
         let json = result.unwrap();
@@ -771,7 +1120,7 @@ mod tests {
         let comments = vec![create_test_comment(100, "commenter1", false)];
         let options = create_test_options(2, 10);
 
-        let result = format_post_json(&item, &comments, &options, 30, 3);
+        let result = format_post_json(&item, &comments, &PostExtras::default(), &options, 30, 3, 0);
         assert!(result.is_ok());
 
         let json = result.unwrap();
@@ -789,7 +1138,7 @@ mod tests {
         let comments = vec![create_test_comment(100, "commenter1", false)];
         let options = create_test_options(1, 10);
 
-        let result = format_post_json(&item, &comments, &options, 30, 3);
+        let result = format_post_json(&item, &comments, &PostExtras::default(), &options, 30, 3, 0);
         assert!(result.is_ok());
 
         let json = result.unwrap();
@@ -805,7 +1154,7 @@ mod tests {
         let comments = vec![create_test_comment(100, "commenter1", false)];
         let options = create_test_options(3, 10);
 
-        let result = format_post_json(&item, &comments, &options, 30, 3);
+        let result = format_post_json(&item, &comments, &PostExtras::default(), &options, 30, 3, 0);
         assert!(result.is_ok());
 
         let json = result.unwrap();
@@ -822,7 +1171,16 @@ mod tests {
         let comments = vec![create_test_comment(100, "commenter1", true)];
         let options = create_test_options(1, 10);
 
-        let result = format_post_text(&item, &comments, &options, 3, 1, "12345");
+        let result = format_post_text(
+            &item,
+            &comments,
+            &PostExtras::default(),
+            &options,
+            3,
+            1,
+            0,
+            "12345",
+        );
 
         // Check for main sections
         assert!(result.contains("POST"));
@@ -835,6 +1193,92 @@ mod tests {
         assert!(result.contains("NAVIGATION"));
     }
 
+    #[test]
+    fn test_format_post_with_poll_options() {
+        let item = HnItem {
+            item_type: "poll".to_string(),
+            parts: Some(vec![501, 502]),
+            ..create_test_item()
+        };
+        let extras = PostExtras {
+            poll_options: vec![
+                PollOption {
+                    id: 501,
+                    text: Some("Yes".to_string()),
+                    score: Some(12),
+                },
+                PollOption {
+                    id: 502,
+                    text: Some("No".to_string()),
+                    score: Some(1),
+                },
+            ],
+            article: None,
+        };
+        let options = create_test_options(1, 10);
+
+        let text = format_post_text(&item, &[], &extras, &options, 0, 1, 0, "12345");
+        assert!(text.contains("Poll"));
+        assert!(text.contains("[12 votes]"));
+        assert!(text.contains("[1 vote]"));
+        assert!(text.contains("No"));
+
+        let json = format_post_json(&item, &[], &extras, &options, 0, 1, 0).unwrap();
+        assert!(json.contains("\"poll_options\""));
+        assert!(json.contains("\"score\": 12"));
+        assert!(!json.contains("\"article\""));
+    }
+
+    #[test]
+    fn test_format_post_with_article() {
+        let item = create_test_item();
+        let options = create_test_options(1, 10);
+        let fetched = mcptools_core::md::FetchOutput {
+            url: "https://example.com".to_string(),
+            title: Some("Example Article".to_string()),
+            content: "# Example\n\nBody text.".to_string(),
+            html_length: 100,
+            fetch_time_ms: 5,
+            retries: 0,
+            selector_used: None,
+            elements_found: None,
+            strategy_applied: None,
+            heading: None,
+            pagination: mcptools_core::md::MdPaginationInfo {
+                current_page: 1,
+                total_pages: 3,
+                total_characters: 60,
+                limit: 20,
+                has_more: true,
+            },
+            metadata: None,
+        };
+        let extras = PostExtras {
+            poll_options: vec![],
+            article: Some(PostArticle::from_fetch("https://example.com", Ok(fetched))),
+        };
+
+        let text = format_post_text(&item, &[], &extras, &options, 0, 1, 0, "12345");
+        assert!(text.contains("ARTICLE"));
+        assert!(text.contains("Example Article"));
+        assert!(text.contains("Body text."));
+        assert!(text.contains("mcptools hn read 12345 --with-article --article-page 2"));
+
+        let json = format_post_json(&item, &[], &extras, &options, 0, 1, 0).unwrap();
+        assert!(json.contains("\"article\""));
+        assert!(json.contains("Body text."));
+
+        let failed = PostExtras {
+            poll_options: vec![],
+            article: Some(PostArticle::from_fetch(
+                "https://example.com",
+                Err("timed out".to_string()),
+            )),
+        };
+        let text = format_post_text(&item, &[], &failed, &options, 0, 1, 0, "12345");
+        assert!(text.contains("timed out"));
+    }
+
     #[test]
     fn test_format_post_text_with_comments() {
         let item = create_test_item();
@@ -844,7 +1288,16 @@ mod tests {
         ];
         let options = create_test_options(1, 10);
 
-        let result = format_post_text(&item, &comments, &options, 2, 1, "12345");
+        let result = format_post_text(
+            &item,
+            &comments,
+            &PostExtras::default(),
+            &options,
+            2,
+            1,
+            0,
+            "12345",
+        );
 
         assert!(result.contains("[Comment #1]"));
         assert!(result.contains("[Comment #2]"));
@@ -859,7 +1312,16 @@ mod tests {
         let comments = vec![];
         let options = create_test_options(1, 10);
 
-        let result = format_post_text(&item, &comments, &options, 0, 1, "12345");
+        let result = format_post_text(
+            &item,
+            &comments,
+            &PostExtras::default(),
+            &options,
+            0,
+            1,
+            0,
+            "12345",
+        );
 
         assert!(result.contains("No comments on this page"));
     }
@@ -870,7 +1332,16 @@ mod tests {
         let comments = vec![create_test_comment(100, "user1", false)];
         let options = create_test_options(2, 10);
 
-        let result = format_post_text(&item, &comments, &options, 30, 3, "12345");
+        let result = format_post_text(
+            &item,
+            &comments,
+            &PostExtras::default(),
+            &options,
+            30,
+            3,
+            0,
+            "12345",
+        );
 
         // Should have navigation commands
         assert!(result.contains("To view more comments"));
@@ -889,7 +1360,16 @@ mod tests {
         let comments = vec![create_test_comment(100, "user1", true)];
         let options = create_test_options(1, 10);
 
-        let result = format_post_text(&item, &comments, &options, 1, 1, "12345");
+        let result = format_post_text(
+            &item,
+            &comments,
+            &PostExtras::default(),
+            &options,
+            1,
+            1,
+            0,
+            "12345",
+        );
 
         // Should show reply indicator
         assert!(result.contains("└─"));
@@ -905,7 +1385,7 @@ mod tests {
             create_test_comment(300, "child2", false),
         ];
 
-        let result = format_thread_json(&comment, &children);
+        let result = format_thread_json(&create_test_thread(comment, children));
         assert!(result.is_ok());
 
         let json = result.unwrap();
@@ -922,7 +1402,7 @@ mod tests {
         let comment = create_test_comment(100, "rootuser", false);
         let children = vec![];
 
-        let result = format_thread_json(&comment, &children);
+        let result = format_thread_json(&create_test_thread(comment, children));
         assert!(result.is_ok());
 
         let json = result.unwrap();
@@ -939,7 +1419,7 @@ mod tests {
             create_test_comment(400, "child3", false),
         ];
 
-        let result = format_thread_json(&comment, &children);
+        let result = format_thread_json(&create_test_thread(comment, children));
         assert!(result.is_ok());
 
         let json = result.unwrap();
@@ -955,7 +1435,7 @@ mod tests {
         let children = vec![create_test_comment(200, "child1", false)];
         let options = create_test_options(1, 10);
 
-        let result = format_thread_text(&comment, &children, "12345", &options);
+        let result = format_thread_text(&create_test_thread(comment, children), "12345", &options);
 
         assert!(result.contains("COMMENT THREAD"));
         assert!(result.contains("[Root Comment]"));
@@ -973,7 +1453,7 @@ mod tests {
         ];
         let options = create_test_options(1, 10);
 
-        let result = format_thread_text(&comment, &children, "12345", &options);
+        let result = format_thread_text(&create_test_thread(comment, children), "12345", &options);
 
         assert!(result.contains("[Reply #1]"));
         assert!(result.contains("[Reply #2]"));
@@ -988,7 +1468,7 @@ mod tests {
         let children = vec![];
         let options = create_test_options(1, 10);
 
-        let result = format_thread_text(&comment, &children, "12345", &options);
+        let result = format_thread_text(&create_test_thread(comment, children), "12345", &options);
 
         assert!(result.contains("No replies to this comment"));
     }
@@ -999,7 +1479,7 @@ mod tests {
         let children = vec![];
         let options = create_test_options(2, 10);
 
-        let result = format_thread_text(&comment, &children, "12345", &options);
+        let result = format_thread_text(&create_test_thread(comment, children), "12345", &options);
 
         assert!(result.contains("To go back to the post"));
         assert!(result.contains("mcptools hn read 12345"));
@@ -1015,9 +1495,45 @@ mod tests {
         let children = vec![create_test_comment(200, "child1", true)];
         let options = create_test_options(1, 10);
 
-        let result = format_thread_text(&comment, &children, "12345", &options);
+        let result = format_thread_text(&create_test_thread(comment, children), "12345", &options);
 
         assert!(result.contains("└─"));
         assert!(result.contains("1 nested replies"));
     }
+
+    #[test]
+    fn test_format_thread_text_depth_and_pagination() {
+        let comment = HnItem {
+            kids: Some(vec![200, 300, 400]),
+            ..create_test_comment(100, "rootuser", false)
+        };
+        let children = vec![
+            HnItem {
+                kids: Some(vec![201]),
+                ..create_test_comment(200, "child1", false)
+            },
+            HnItem {
+                kids: Some(vec![202]),
+                ..create_test_comment(201, "grandchild", false)
+            },
+        ];
+        let thread = build_thread_output(12345, &comment, &[200], children, false, 1, 1, Some(2));
+        let options = create_test_options(1, 10);
+
+        let result = format_thread_text(&thread, "12345", &options);
+
+        assert!(result.contains("page"));
+        assert!(result.contains("direct replies"));
+        assert!(result.contains("grandchild"));
+        // The depth limit leaves 202 out; point at its own thread
+        assert!(result.contains("mcptools hn read 12345 --thread 201"));
+        assert!(result.contains("To see more replies"));
+        assert!(result.contains(
+            "mcptools hn read 12345 --thread 100 --thread-page 2 --thread-limit 1 --depth 2"
+        ));
+
+        let json = format_thread_json(&thread).unwrap();
+        assert!(json.contains("\"depth\": 2"));
+        assert!(json.contains("\"total_pages\": 3"));
+    }
 }
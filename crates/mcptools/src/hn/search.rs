@@ -0,0 +1,313 @@
+use crate::prelude::{println, *};
+use colored::Colorize;
+use mcptools_core::hn::{
+    build_search_output, parse_time_bound, search_numeric_filters, HnSearchResponse, SearchOutput,
+};
+
+use super::HN_SEARCH_API_BASE;
+
+#[derive(Debug, clap::Args, serde::Deserialize, Clone)]
+pub struct SearchOptions {
+    /// Words to search for in titles, URLs, and text
+    pub query: String,
+
+    /// Algolia tags to restrict results: story, comment, ask_hn, show_hn,
+    /// poll, author_<user>, story_<id>; join with commas to require all
+    #[arg(long, default_value = "story")]
+    #[serde(default = "default_tags")]
+    pub tags: String,
+
+    /// Only show items posted since this time: a duration back from now
+    /// (30m, 24h, 7d, 2w), a date (2024-05-01), or an RFC 3339 timestamp
+    #[arg(long, value_name = "TIME")]
+    pub since: Option<String>,
+
+    /// Only show items posted before this time (same formats as --since)
+    #[arg(long, value_name = "TIME")]
+    pub until: Option<String>,
+
+    /// Newest first instead of by relevance
+    #[arg(long)]
+    #[serde(default)]
+    pub by_date: bool,
+
+    /// Number of results per page
+    #[arg(short, long, env = "HN_LIMIT", default_value = "20")]
+    #[serde(default = "default_limit")]
+    pub limit: usize,
+
+    /// Page number (1-indexed)
+    #[arg(short, long, default_value = "1")]
+    #[serde(default = "default_page")]
+    pub page: usize,
+
+    /// Output as JSON
+    #[arg(long)]
+    #[serde(default)]
+    pub json: bool,
+}
+
+fn default_tags() -> String {
+    "story".to_string()
+}
+
+fn default_limit() -> usize {
+    20
+}
+
+fn default_page() -> usize {
+    1
+}
+
+impl SearchOptions {
+    /// Flags that keep this search's tags, window, and order on navigation
+    /// commands
+    fn command_args(&self) -> String {
+        let mut args = String::new();
+        if self.tags != default_tags() {
+            args.push_str(&format!(" --tags {}", self.tags));
+        }
+        if let Some(since) = &self.since {
+            args.push_str(&format!(" --since {}", since));
+        }
+        if let Some(until) = &self.until {
+            args.push_str(&format!(" --until {}", until));
+        }
+        if self.by_date {
+            args.push_str(" --by-date");
+        }
+        if self.limit != default_limit() {
+            args.push_str(&format!(" --limit {}", self.limit));
+        }
+        args
+    }
+}
+
+pub async fn run(options: SearchOptions, global: crate::Global) -> Result<()> {
+    if global.verbose {
+        println!("Searching HackerNews for: {}", options.query);
+    }
+
+    let output = search_data(&options).await?;
+
+    if options.json {
+        println!("{}", format_search_json(&output)?);
+    } else {
+        print!("{}", format_search_text(&output));
+    }
+
+    Ok(())
+}
+
+/// Searches HackerNews through the Algolia API and returns one page of results
+pub async fn search_data(options: &SearchOptions) -> Result<SearchOutput> {
+    if options.page == 0 {
+        return Err(eyre!("Page number must be at least 1"));
+    }
+    if options.limit == 0 {
+        return Err(eyre!("Limit must be at least 1"));
+    }
+
+    let now = chrono::Utc::now().timestamp();
+    let bound = |value: &Option<String>| {
+        value
+            .as_deref()
+            .map(|v| parse_time_bound(v, now))
+            .transpose()
+            .map_err(|e| eyre!(e))
+    };
+    let numeric_filters = search_numeric_filters(bound(&options.since)?, bound(&options.until)?);
+
+    let endpoint = if options.by_date {
+        "search_by_date"
+    } else {
+        "search"
+    };
+    let mut query = vec![
+        ("query", options.query.clone()),
+        ("hitsPerPage", options.limit.to_string()),
+        // Algolia pages are 0-indexed
+        ("page", (options.page - 1).to_string()),
+    ];
+    if !options.tags.is_empty() {
+        query.push(("tags", options.tags.clone()));
+    }
+    if let Some(filters) = numeric_filters {
+        query.push(("numericFilters", filters));
+    }
+
+    let response = reqwest::Client::new()
+        .get(format!("{}/{}", HN_SEARCH_API_BASE, endpoint))
+        .query(&query)
+        .send()
+        .await
+        .map_err(|e| eyre!("Failed to send request to HN search: {}", e))?;
+    if !response.status().is_success() {
+        return Err(eyre!("HN search failed [{}]", response.status()));
+    }
+    let body: HnSearchResponse = response
+        .json()
+        .await
+        .map_err(|e| eyre!("Failed to parse HN search response: {}", e))?;
+
+    Ok(build_search_output(
+        body,
+        &options.query,
+        Some(options.tags.clone()).filter(|tags| !tags.is_empty()),
+        options.page,
+        options.limit,
+        &options.command_args(),
+    ))
+}
+
+/// Convert search output to JSON string
+fn format_search_json(output: &SearchOutput) -> Result<String> {
+    serde_json::to_string_pretty(output).map_err(|e| eyre!("JSON serialization failed: {}", e))
+}
+
+/// Convert search output to formatted text with colors
+fn format_search_text(output: &SearchOutput) -> String {
+    let mut result = String::new();
+    let pagination = &output.pagination;
+
+    result.push_str(&format!("\n{}\n", "=".repeat(80).bright_cyan()));
+    result.push_str(&format!(
+        "{}\n",
+        format!(
+            "HACKERNEWS SEARCH: {} (Page {} of {})",
+            output.query,
+            pagination.current_page,
+            pagination.total_pages.max(1)
+        )
+        .bright_cyan()
+        .bold()
+    ));
+    result.push_str(&format!("{}\n", "=".repeat(80).bright_cyan()));
+
+    if output.items.is_empty() {
+        result.push_str(&format!("\n{}\n", "No results on this page.".yellow()));
+    }
+    for (idx, item) in output.items.iter().enumerate() {
+        let rank = (pagination.current_page - 1) * pagination.limit + idx + 1;
+        result.push_str(&format!(
+            "\n{} {} {}\n",
+            format!("[{rank}]").yellow().bold(),
+            format!("({})", item.item_type).bright_black(),
+            item.title.as_deref().unwrap_or("(No title)").white().bold()
+        ));
+        if let Some(url) = &item.url {
+            result.push_str(&format!(
+                "    {}: {}\n",
+                "URL".green(),
+                url.cyan().underline()
+            ));
+        }
+        if let Some(text) = &item.text {
+            result.push_str(&format!("    {}\n", text.bright_white()));
+        }
+        result.push_str(&format!(
+            "    {}: {} | {}: {} | {}: {} | {}: {}\n",
+            "By".green(),
+            item.author.as_deref().unwrap_or("unknown").bright_white(),
+            "Score".green(),
+            item.score.unwrap_or(0).to_string().bright_yellow(),
+            "Comments".green(),
+            item.comments.unwrap_or(0).to_string().bright_magenta(),
+            "Time".green(),
+            item.time.as_deref().unwrap_or("unknown").bright_black()
+        ));
+        result.push_str(&format!(
+            "    {}: {}\n",
+            "Read".green(),
+            format!("mcptools hn read {}", item.story_id.unwrap_or(item.id)).cyan()
+        ));
+    }
+
+    result.push_str(&format!(
+        "\n{} {} {}\n",
+        "Found".bright_white(),
+        pagination.total_items.to_string().bright_cyan().bold(),
+        "results".bright_white()
+    ));
+    if let Some(next) = &pagination.next_page_command {
+        result.push_str(&format!("  {}: {}\n", "Next page".green(), next.cyan()));
+    }
+    if let Some(prev) = &pagination.prev_page_command {
+        result.push_str(&format!("  {}: {}\n", "Previous page".green(), prev.cyan()));
+    }
+
+    result.push('\n');
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mcptools_core::hn::{ListPaginationInfo, SearchItem};
+
+    fn create_test_options() -> SearchOptions {
+        SearchOptions {
+            query: "rust".to_string(),
+            tags: default_tags(),
+            since: None,
+            until: None,
+            by_date: false,
+            limit: default_limit(),
+            page: 1,
+            json: false,
+        }
+    }
+
+    #[test]
+    fn test_command_args() {
+        assert_eq!(create_test_options().command_args(), "");
+
+        let options = SearchOptions {
+            tags: "comment,author_pg".to_string(),
+            since: Some("7d".to_string()),
+            by_date: true,
+            limit: 5,
+            ..create_test_options()
+        };
+        assert_eq!(
+            options.command_args(),
+            " --tags comment,author_pg --since 7d --by-date --limit 5"
+        );
+    }
+
+    #[test]
+    fn test_format_search_text() {
+        let output = SearchOutput {
+            query: "rust".to_string(),
+            tags: Some("story".to_string()),
+            items: vec![SearchItem {
+                id: 9224,
+                item_type: "comment".to_string(),
+                title: Some("Rust 1.0".to_string()),
+                url: None,
+                author: Some("steveklabnik".to_string()),
+                score: None,
+                time: None,
+                comments: None,
+                text: Some("Congrats to the team".to_string()),
+                story_id: Some(9000),
+            }],
+            pagination: ListPaginationInfo {
+                current_page: 1,
+                total_pages: 2,
+                total_items: 40,
+                limit: 20,
+                next_page_command: Some("mcptools hn search \"rust\" --page 2".to_string()),
+                prev_page_command: None,
+                next_cursor: None,
+            },
+        };
+
+        let text = format_search_text(&output);
+        assert!(text.contains("HACKERNEWS SEARCH: rust"));
+        assert!(text.contains("Rust 1.0"));
+        assert!(text.contains("Congrats to the team"));
+        assert!(text.contains("mcptools hn read 9000"));
+        assert!(text.contains("mcptools hn search \"rust\" --page 2"));
+    }
+}
@@ -0,0 +1,205 @@
+use crate::prelude::{println, *};
+use colored::Colorize;
+use futures::stream::{self, StreamExt};
+use mcptools_core::hn::{build_updates_output, HnUpdates, UpdatesOutput};
+
+use super::user::fetch_user;
+use super::{fetch_items, get_api_base, FETCH_CONCURRENCY};
+
+#[derive(Debug, clap::Args, serde::Deserialize, Clone)]
+pub struct UpdatesOptions {
+    /// Number of changed items to resolve
+    #[arg(short, long, env = "HN_LIMIT", default_value = "30")]
+    pub limit: usize,
+
+    /// Number of changed user profiles to resolve
+    #[arg(long, value_name = "COUNT", default_value = "10")]
+    pub profiles: usize,
+
+    /// Output as JSON
+    #[arg(long)]
+    pub json: bool,
+}
+
+pub async fn run(options: UpdatesOptions, global: crate::Global) -> Result<()> {
+    if global.verbose {
+        println!("Fetching recent updates...");
+    }
+
+    let output = updates_data(options.limit, options.profiles).await?;
+
+    if options.json {
+        println!("{}", format_updates_json(&output)?);
+    } else {
+        print!("{}", format_updates_text(&output));
+    }
+
+    Ok(())
+}
+
+/// Fetches the recently changed items and profiles and resolves the first
+/// `limit` items and `profiles` users
+///
+/// Changed items are always fetched fresh, bypassing the item cache, since
+/// a cached copy is by definition out of date.
+pub async fn updates_data(limit: usize, profiles: usize) -> Result<UpdatesOutput> {
+    let client = reqwest::Client::new();
+    let url = format!("{}/updates.json", get_api_base());
+    let response = client
+        .get(&url)
+        .send()
+        .await
+        .map_err(|e| eyre!("Failed to fetch updates: {}", e))?;
+
+    if !response.status().is_success() {
+        return Err(eyre!("Failed to fetch updates: HTTP {}", response.status()));
+    }
+
+    let updates: HnUpdates = response
+        .json()
+        .await
+        .map_err(|e| eyre!("Failed to parse updates: {}", e))?;
+
+    let item_ids: Vec<u64> = updates.items.iter().take(limit).copied().collect();
+    let items = fetch_items(&client, &item_ids, false).await;
+    let users = stream::iter(updates.profiles.iter().take(profiles))
+        .map(|username| fetch_user(&client, username))
+        .buffered(FETCH_CONCURRENCY)
+        .filter_map(|result| async move { result.ok() })
+        .collect()
+        .await;
+
+    Ok(build_updates_output(&updates, items, users))
+}
+
+/// Convert updates output to JSON string
+fn format_updates_json(output: &UpdatesOutput) -> Result<String> {
+    serde_json::to_string_pretty(output).map_err(|e| eyre!("JSON serialization failed: {}", e))
+}
+
+/// Convert updates output to formatted text with colors
+fn format_updates_text(output: &UpdatesOutput) -> String {
+    let mut result = String::new();
+
+    result.push_str(&format!("\n{}\n", "=".repeat(80).bright_cyan()));
+    result.push_str(&format!(
+        "{}\n",
+        format!(
+            "HACKERNEWS UPDATES ({} items, {} profiles changed)",
+            output.total_items, output.total_profiles
+        )
+        .bright_cyan()
+        .bold()
+    ));
+    result.push_str(&format!("{}\n", "=".repeat(80).bright_cyan()));
+
+    if output.items.is_empty() {
+        result.push_str(&format!("\n{}\n", "No changed items resolved.".yellow()));
+    }
+    for item in &output.items {
+        let heading = match (&item.title, &item.text) {
+            (Some(title), _) => title.white().bold().to_string(),
+            (None, Some(text)) => text.normal().to_string(),
+            (None, None) => "(no text)".bright_black().to_string(),
+        };
+        result.push_str(&format!(
+            "\n{} {}\n",
+            format!("[{}]", item.item_type).yellow().bold(),
+            heading
+        ));
+        let mut details = vec![format!(
+            "{}: {}",
+            "By".green(),
+            item.author.as_deref().unwrap_or("unknown").bright_white()
+        )];
+        if let Some(score) = item.score {
+            details.push(format!(
+                "{}: {}",
+                "Score".green(),
+                score.to_string().bright_yellow()
+            ));
+        }
+        if let Some(comments) = item.comments {
+            details.push(format!(
+                "{}: {}",
+                "Comments".green(),
+                comments.to_string().bright_magenta()
+            ));
+        }
+        details.push(format!(
+            "{}: {}",
+            "Time".green(),
+            item.time.as_deref().unwrap_or("unknown").bright_black()
+        ));
+        result.push_str(&format!("    {}\n", details.join(" | ")));
+        result.push_str(&format!(
+            "    {}: {}\n",
+            "Read".green(),
+            format!("mcptools hn read {}", item.id).cyan()
+        ));
+    }
+
+    if !output.profiles.is_empty() {
+        result.push_str(&format!("\n{}\n", "=".repeat(80).bright_yellow()));
+        result.push_str(&format!("{}\n", "CHANGED PROFILES".bright_yellow().bold()));
+        result.push_str(&format!("{}\n", "=".repeat(80).bright_yellow()));
+        for profile in &output.profiles {
+            result.push_str(&format!(
+                "\n{} | {}: {} | {}: {}\n",
+                profile.id.white().bold(),
+                "Karma".green(),
+                profile.karma.to_string().bright_yellow(),
+                "Profile".green(),
+                format!("mcptools hn user {}", profile.id).cyan()
+            ));
+        }
+    }
+
+    result.push('\n');
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mcptools_core::hn::{UpdatedItem, UpdatedProfile};
+
+    #[test]
+    fn test_format_updates_text() {
+        let mut output = UpdatesOutput {
+            items: vec![UpdatedItem {
+                id: 8863,
+                item_type: "story".to_string(),
+                title: Some("My YC app: Dropbox".to_string()),
+                url: None,
+                author: Some("dhouston".to_string()),
+                text: None,
+                score: Some(104),
+                comments: Some(71),
+                time: None,
+                parent: None,
+            }],
+            profiles: vec![UpdatedProfile {
+                id: "pg".to_string(),
+                karma: 155000,
+                created: None,
+                total_submissions: 0,
+            }],
+            total_items: 40,
+            total_profiles: 12,
+        };
+
+        let text = format_updates_text(&output);
+        assert!(text.contains("40 items, 12 profiles changed"));
+        assert!(text.contains("My YC app: Dropbox"));
+        assert!(text.contains("mcptools hn read 8863"));
+        assert!(text.contains("CHANGED PROFILES"));
+        assert!(text.contains("mcptools hn user pg"));
+
+        output.items.clear();
+        output.profiles.clear();
+        let text = format_updates_text(&output);
+        assert!(text.contains("No changed items resolved."));
+        assert!(!text.contains("CHANGED PROFILES"));
+    }
+}
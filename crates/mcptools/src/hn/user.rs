@@ -0,0 +1,260 @@
+use crate::prelude::{println, *};
+use colored::Colorize;
+use mcptools_core::hn::{build_user_output, HnItem, HnUser, UserOutput};
+
+use super::{fetch_items, get_api_base};
+
+#[derive(Debug, clap::Args, serde::Deserialize, Clone)]
+pub struct UserOptions {
+    /// HackerNews username (case-sensitive)
+    #[arg(env = "HN_USER")]
+    pub username: String,
+
+    /// Number of recent submissions to resolve
+    #[arg(short, long, env = "HN_LIMIT", default_value = "10")]
+    pub limit: usize,
+
+    /// Output as JSON
+    #[arg(long)]
+    pub json: bool,
+
+    /// Bypass the item cache: always fetch, and don't store the result
+    #[arg(long, env = "HN_NO_CACHE")]
+    #[serde(default)]
+    pub no_cache: bool,
+}
+
+pub async fn run(options: UserOptions, global: crate::Global) -> Result<()> {
+    if global.verbose {
+        println!("Fetching user: {}", options.username);
+    }
+
+    let output = user_data(options.username.clone(), options.limit, !options.no_cache).await?;
+
+    if options.json {
+        println!("{}", format_user_json(&output)?);
+    } else {
+        print!("{}", format_user_text(&output));
+    }
+
+    Ok(())
+}
+
+pub(super) async fn fetch_user(client: &reqwest::Client, username: &str) -> Result<HnUser> {
+    let url = format!(
+        "{}/user/{}.json",
+        get_api_base(),
+        urlencoding::encode(username)
+    );
+    let response = client
+        .get(&url)
+        .send()
+        .await
+        .map_err(|e| eyre!("Failed to fetch user {}: {}", username, e))?;
+
+    if !response.status().is_success() {
+        return Err(eyre!(
+            "Failed to fetch user {}: HTTP {}",
+            username,
+            response.status()
+        ));
+    }
+
+    // Unknown users come back as `null`
+    let user: Option<HnUser> = response
+        .json()
+        .await
+        .map_err(|e| eyre!("Failed to parse user {}: {}", username, e))?;
+    user.ok_or_else(|| {
+        eyre!(
+            "User not found: {} (usernames are case-sensitive)",
+            username
+        )
+    })
+}
+
+/// Fetches a HackerNews user profile and resolves their most recent submissions
+pub async fn user_data(username: String, limit: usize, use_cache: bool) -> Result<UserOutput> {
+    let client = reqwest::Client::new();
+    let user = fetch_user(&client, &username).await?;
+
+    // `submitted` is newest first
+    let recent_ids: Vec<u64> = user
+        .submitted
+        .iter()
+        .flatten()
+        .take(limit)
+        .copied()
+        .collect();
+    let items = fetch_items(&client, &recent_ids, use_cache).await;
+
+    Ok(build_user_output(user, items))
+}
+
+/// Convert user output to JSON string
+fn format_user_json(output: &UserOutput) -> Result<String> {
+    serde_json::to_string_pretty(output).map_err(|e| eyre!("JSON serialization failed: {}", e))
+}
+
+/// Convert user output to formatted text with colors
+fn format_user_text(output: &UserOutput) -> String {
+    let mut result = String::new();
+
+    result.push_str(&format!("\n{}\n", "=".repeat(80).bright_cyan()));
+    result.push_str(&format!(
+        "{}\n",
+        format!("HACKERNEWS USER: {}", output.id)
+            .bright_cyan()
+            .bold()
+    ));
+    result.push_str(&format!("{}\n", "=".repeat(80).bright_cyan()));
+
+    result.push_str(&format!(
+        "\n{}: {} | {}: {} | {}: {}\n",
+        "Karma".green(),
+        output.karma.to_string().bright_yellow(),
+        "Created".green(),
+        output
+            .created
+            .as_deref()
+            .unwrap_or("unknown")
+            .bright_black(),
+        "Submissions".green(),
+        output.total_submissions.to_string().bright_magenta()
+    ));
+    result.push_str(&format!(
+        "{}: {}\n",
+        "Profile".green(),
+        format!("https://news.ycombinator.com/user?id={}", output.id)
+            .cyan()
+            .underline()
+    ));
+
+    if let Some(about) = &output.about {
+        result.push_str(&format!("\n{}\n", "About:".bright_white().bold()));
+        for line in about.lines() {
+            result.push_str(&format!("  {}\n", line));
+        }
+    }
+
+    result.push_str(&format!("\n{}\n", "=".repeat(80).bright_yellow()));
+    result.push_str(&format!(
+        "{}\n",
+        "RECENT SUBMISSIONS".bright_yellow().bold()
+    ));
+    result.push_str(&format!("{}\n", "=".repeat(80).bright_yellow()));
+
+    if output.recent_submissions.is_empty() {
+        result.push_str(&format!("\n{}\n", "No recent submissions.".yellow()));
+    }
+    for item in &output.recent_submissions {
+        let heading = match (&item.title, &item.text) {
+            (Some(title), _) => title.white().bold().to_string(),
+            (None, Some(text)) => text.normal().to_string(),
+            (None, None) => "(no text)".bright_black().to_string(),
+        };
+        result.push_str(&format!(
+            "\n{} {}\n",
+            format!("[{}]", item.item_type).yellow().bold(),
+            heading
+        ));
+        if let Some(url) = &item.url {
+            result.push_str(&format!(
+                "    {}: {}\n",
+                "URL".green(),
+                url.cyan().underline()
+            ));
+        }
+        let mut details = Vec::new();
+        if let Some(score) = item.score {
+            details.push(format!(
+                "{}: {}",
+                "Score".green(),
+                score.to_string().bright_yellow()
+            ));
+        }
+        details.push(format!(
+            "{}: {}",
+            "Time".green(),
+            item.time.as_deref().unwrap_or("unknown").bright_black()
+        ));
+        details.push(format!(
+            "{}: {}",
+            "Read".green(),
+            format!("mcptools hn read {}", item.id).cyan()
+        ));
+        result.push_str(&format!("    {}\n", details.join(" | ")));
+    }
+
+    result.push('\n');
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mcptools_core::hn::UserSubmission;
+
+    fn create_test_output() -> UserOutput {
+        UserOutput {
+            id: "dang".to_string(),
+            karma: 1234,
+            created: Some("2007-01-01 00:00:00 UTC".to_string()),
+            about: Some("Moderator.\n\nhn@ycombinator.com".to_string()),
+            total_submissions: 2,
+            recent_submissions: vec![
+                UserSubmission {
+                    id: 1,
+                    item_type: "story".to_string(),
+                    title: Some("Launch HN".to_string()),
+                    url: Some("https://example.com".to_string()),
+                    text: None,
+                    score: Some(99),
+                    time: Some("2024-01-01 00:00:00 UTC".to_string()),
+                },
+                UserSubmission {
+                    id: 2,
+                    item_type: "comment".to_string(),
+                    title: None,
+                    url: None,
+                    text: Some("Please don't do this here.".to_string()),
+                    score: None,
+                    time: None,
+                },
+            ],
+        }
+    }
+
+    #[test]
+    fn test_format_user_json() {
+        let json = format_user_json(&create_test_output()).unwrap();
+        let parsed: serde_json::Value = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(parsed["id"], "dang");
+        assert_eq!(parsed["karma"], 1234);
+        assert_eq!(parsed["recent_submissions"][0]["type"], "story");
+        assert_eq!(
+            parsed["recent_submissions"][1]["title"],
+            serde_json::Value::Null
+        );
+    }
+
+    #[test]
+    fn test_format_user_text() {
+        let mut output = create_test_output();
+        let formatted = format_user_text(&output);
+
+        assert!(formatted.contains("HACKERNEWS USER: dang"));
+        assert!(formatted.contains("1234"));
+        assert!(formatted.contains("  hn@ycombinator.com"));
+        assert!(formatted.contains("Launch HN"));
+        assert!(formatted.contains("Please don't do this here."));
+        assert!(formatted.contains("mcptools hn read 2"));
+
+        output.about = None;
+        output.recent_submissions.clear();
+        let formatted = format_user_text(&output);
+        assert!(!formatted.contains("About:"));
+        assert!(formatted.contains("No recent submissions."));
+    }
+}
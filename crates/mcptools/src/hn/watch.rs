@@ -0,0 +1,223 @@
+use crate::prelude::{eprintln, println, *};
+use colored::Colorize;
+use std::time::Duration;
+
+use mcptools_core::hn::{
+    is_readable_post, poll_story_watch, start_story_watch, StoryWatchEvent, StoryWatchState,
+};
+
+use super::read_item::fetch_comment_tree;
+use super::{extract_item_id, fetch_item};
+
+/// Characters of each new comment shown in text output
+const COMMENT_PREVIEW_CHARS: usize = 200;
+
+#[derive(Debug, clap::Args, Clone)]
+pub struct WatchOptions {
+    /// HackerNews story ID or full URL
+    #[arg(env = "HN_ITEM")]
+    pub item: String,
+
+    /// Seconds between polls
+    #[arg(long, value_name = "SECONDS", default_value = "60")]
+    pub interval: u64,
+
+    /// Stop after this many polls (default: run until interrupted)
+    #[arg(long, value_name = "N")]
+    pub count: Option<u64>,
+
+    /// Print one JSON event per line: baseline, update, or error
+    #[arg(long)]
+    pub json: bool,
+}
+
+pub async fn run(options: WatchOptions, global: crate::Global) -> Result<()> {
+    if options.interval == 0 {
+        return Err(eyre!("--interval must be at least 1 second"));
+    }
+    let item_id = extract_item_id(&options.item)?;
+    if global.verbose {
+        println!("Watching item ID: {}", item_id);
+    }
+
+    let client = reqwest::Client::new();
+    let mut state: Option<StoryWatchState> = None;
+    let mut polls = 0;
+    loop {
+        polls += 1;
+        let now = chrono::Utc::now().timestamp();
+        // Watching needs live data, so the item cache is never used.
+        match fetch_item(&client, item_id, false).await {
+            Err(e) => emit(
+                &options,
+                StoryWatchEvent::error(item_id, now, e.to_string()),
+            )?,
+            Ok(story) if !is_readable_post(&story) => {
+                return Err(eyre!(
+                    "Item {} is not a story or poll (type: {})",
+                    item_id,
+                    story.item_type
+                ));
+            }
+            Ok(story) => match &mut state {
+                None => {
+                    let thread = fetch_comment_tree(&client, &story, false).await;
+                    let (initial, event) = start_story_watch(&story, &thread, now);
+                    state = Some(initial);
+                    emit(&options, event)?;
+                }
+                Some(state) => {
+                    // An unchanged comment count means no new comments, so
+                    // the thread walk is skipped.
+                    let thread = if story.descendants != state.descendants {
+                        Some(fetch_comment_tree(&client, &story, false).await)
+                    } else {
+                        None
+                    };
+                    match poll_story_watch(state, &story, thread.as_deref(), now) {
+                        Some(event) => emit(&options, event)?,
+                        None if !options.json => {
+                            eprintln!("{}", format!("No changes at {}", local_time(now)).dimmed())
+                        }
+                        None => {}
+                    }
+                }
+            },
+        }
+
+        if options.count == Some(polls) {
+            break;
+        }
+        tokio::time::sleep(Duration::from_secs(options.interval)).await;
+    }
+    Ok(())
+}
+
+fn emit(options: &WatchOptions, event: StoryWatchEvent) -> Result<()> {
+    if options.json {
+        println!("{}", serde_json::to_string(&event)?);
+    } else {
+        print!("{}", format_event_text(&event, options.interval));
+    }
+    Ok(())
+}
+
+/// Render one watch event for the terminal
+fn format_event_text(event: &StoryWatchEvent, interval: u64) -> String {
+    let mut result = String::new();
+    match event {
+        StoryWatchEvent::Baseline {
+            id,
+            title,
+            score,
+            comments,
+            ..
+        } => {
+            result.push_str(&format!(
+                "{} {} ({} points, {} comments); polling every {}s, press Ctrl-C to stop\n",
+                "Watching".bright_cyan().bold(),
+                title.as_deref().unwrap_or("(No title)").white().bold(),
+                score.unwrap_or(0),
+                comments.unwrap_or(0),
+                interval
+            ));
+            result.push_str(&format!(
+                "  {}\n",
+                format!("https://news.ycombinator.com/item?id={id}")
+                    .cyan()
+                    .underline()
+            ));
+        }
+        StoryWatchEvent::Update {
+            at,
+            score,
+            score_delta,
+            comments,
+            new_comments,
+            ..
+        } => {
+            let delta = match *score_delta {
+                0 => String::new(),
+                d if d > 0 => format!(" (+{d})").green().to_string(),
+                d => format!(" ({d})").red().to_string(),
+            };
+            result.push_str(&format!(
+                "{} {}: {} points{}, {} comments\n",
+                "Update".bright_yellow().bold(),
+                at.as_deref().unwrap_or("unknown").bright_black(),
+                score.unwrap_or(0).to_string().bright_yellow(),
+                delta,
+                comments.unwrap_or(0).to_string().bright_magenta()
+            ));
+            for comment in new_comments {
+                let text = comment.text.as_deref().unwrap_or_default();
+                let preview: String = text.chars().take(COMMENT_PREVIEW_CHARS).collect();
+                let ellipsis = if preview.len() < text.len() {
+                    "..."
+                } else {
+                    ""
+                };
+                result.push_str(&format!(
+                    "  {} {} {}: {}{}\n",
+                    "+".green().bold(),
+                    format!("[{}]", comment.id).bright_black(),
+                    comment
+                        .author
+                        .as_deref()
+                        .unwrap_or("unknown")
+                        .bright_white(),
+                    preview.replace('\n', " "),
+                    ellipsis
+                ));
+            }
+        }
+        StoryWatchEvent::Error { at, error, .. } => {
+            result.push_str(&format!(
+                "{} {}\n",
+                format!("Poll at {} failed:", at.as_deref().unwrap_or("unknown")).red(),
+                error
+            ));
+        }
+    }
+    result
+}
+
+fn local_time(secs: i64) -> String {
+    chrono::DateTime::from_timestamp(secs, 0)
+        .map(|t| {
+            t.with_timezone(&chrono::Local)
+                .format("%Y-%m-%d %H:%M:%S")
+                .to_string()
+        })
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mcptools_core::hn::NewComment;
+
+    #[test]
+    fn test_format_update_text() {
+        let event = StoryWatchEvent::Update {
+            id: 1,
+            at: Some("2024-01-01 00:00:00 UTC".to_string()),
+            score: Some(12),
+            score_delta: 4,
+            comments: Some(3),
+            new_comments: vec![NewComment {
+                id: 11,
+                parent: Some(1),
+                author: Some("alice".to_string()),
+                time: None,
+                text: Some(format!("Congrats!\n{}", "é".repeat(300))),
+            }],
+        };
+
+        let text = format_event_text(&event, 60);
+        assert!(text.contains("(+4)"));
+        assert!(text.contains("[11]"));
+        assert!(text.contains("alice: Congrats! "));
+        assert!(text.trim_end().ends_with("..."));
+    }
+}
@@ -0,0 +1,144 @@
+use crate::prelude::{eprintln, println, *};
+
+use mcptools_core::html::{format_matches, select_html, xpath_to_css, SelectFormat};
+
+#[derive(Debug, clap::Parser)]
+#[command(name = "html")]
+#[command(about = "Select elements from HTML with CSS or XPath selectors")]
+pub struct App {
+    #[command(subcommand)]
+    pub command: Commands,
+}
+
+#[derive(Debug, clap::Subcommand)]
+pub enum Commands {
+    /// Print the elements matching a CSS selector or XPath expression
+    #[clap(name = "select")]
+    Select(SelectOptions),
+}
+
+#[derive(Debug, Clone, Copy, clap::ValueEnum, serde::Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Format {
+    /// Whitespace-normalized text, one match per line
+    Text,
+    /// Each match converted to Markdown
+    Markdown,
+    /// Each match's outer HTML
+    Html,
+    /// Tag, text, attributes, and HTML of each match as JSON
+    Json,
+}
+
+impl From<Format> for SelectFormat {
+    fn from(format: Format) -> Self {
+        match format {
+            Format::Text => SelectFormat::Text,
+            Format::Markdown => SelectFormat::Markdown,
+            Format::Html => SelectFormat::Html,
+            Format::Json => SelectFormat::Json,
+        }
+    }
+}
+
+#[derive(Debug, clap::Args, Clone)]
+pub struct SelectOptions {
+    /// HTML file, http(s) URL (rendered with headless Chrome), or '-' for stdin
+    pub source: String,
+
+    /// CSS selector (e.g. 'table.results td')
+    #[arg(long, required_unless_present = "xpath", conflicts_with = "xpath")]
+    pub css: Option<String>,
+
+    /// XPath location path (e.g. '//table[@class="results"]//td', '//a/@href')
+    #[arg(long)]
+    pub xpath: Option<String>,
+
+    /// Print this attribute's value instead of the element (skips elements without it)
+    #[arg(long)]
+    pub attr: Option<String>,
+
+    /// Output format
+    #[arg(short, long, value_enum, default_value = "text")]
+    pub format: Format,
+
+    /// Maximum number of matches
+    #[arg(long, default_value = "1000")]
+    pub limit: usize,
+
+    /// Timeout in seconds when the source is a URL
+    #[arg(short, long, env = "MD_TIMEOUT", default_value = "30")]
+    pub timeout: u64,
+
+    /// Wait until an element matching this CSS selector appears (URL sources only)
+    #[arg(long, env = "MD_WAIT_FOR")]
+    pub wait_for: Option<String>,
+}
+
+pub async fn run(app: App, _global: crate::Global) -> Result<()> {
+    match app.command {
+        Commands::Select(options) => select(options).await,
+    }
+}
+
+/// Resolve `--css`/`--xpath`/`--attr` into a CSS selector and the attribute
+/// to read. An XPath `/@name` step and `attr` must agree when both are given.
+pub fn resolve_selector(
+    css: Option<String>,
+    xpath: Option<&str>,
+    attr: Option<String>,
+) -> std::result::Result<(String, Option<String>), String> {
+    match (css, xpath) {
+        (Some(css), None) => Ok((css, attr)),
+        (None, Some(xpath)) => {
+            let selector = xpath_to_css(xpath)?;
+            match (selector.attribute, attr) {
+                (Some(a), Some(b)) if a != b => Err(format!(
+                    "XPath selects @{a} but attribute '{b}' was also requested"
+                )),
+                (from_xpath, attr) => Ok((selector.css, from_xpath.or(attr))),
+            }
+        }
+        (Some(_), Some(_)) => Err("Provide either a CSS selector or an XPath, not both".into()),
+        (None, None) => Err("Provide a CSS selector or an XPath".into()),
+    }
+}
+
+/// Load HTML from a URL (rendered with headless Chrome), a file, or stdin.
+pub async fn load_html(source: &str, timeout: u64, wait_for: Option<String>) -> Result<String> {
+    if source.starts_with("http://") || source.starts_with("https://") {
+        let config = crate::md::FetchConfig {
+            url: source.to_string(),
+            timeout,
+            raw_html: true,
+            wait_for,
+            ..Default::default()
+        };
+        let fetched =
+            tokio::task::spawn_blocking(move || crate::md::fetch_and_convert_data(config))
+                .await??;
+        return Ok(fetched.content);
+    }
+
+    let file = (source != "-").then_some(source);
+    let bytes = crate::hash::read_input(None, file)?;
+    Ok(String::from_utf8_lossy(&bytes).into_owned())
+}
+
+async fn select(options: SelectOptions) -> Result<()> {
+    // Validate the selector before loading a page that may take a while.
+    let (css, attr) = resolve_selector(options.css, options.xpath.as_deref(), options.attr)
+        .map_err(|e| eyre!(e))?;
+    select_html("", &css, None, 0).map_err(|e| eyre!(e))?;
+
+    let html = load_html(&options.source, options.timeout, options.wait_for).await?;
+    let matches = select_html(&html, &css, attr.as_deref(), options.limit).map_err(|e| eyre!(e))?;
+
+    if matches.is_empty() && !matches!(options.format, Format::Json) {
+        eprintln!("No elements matched '{}'", css);
+        return Ok(());
+    }
+    println!("{}", format_matches(&matches, options.format.into()));
+
+    Ok(())
+}
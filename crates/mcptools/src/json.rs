@@ -0,0 +1,71 @@
+use crate::prelude::*;
+
+use mcptools_core::jq::{format_results, parse_inputs, Query};
+
+#[derive(Debug, clap::Parser)]
+#[command(name = "json")]
+#[command(about = "Query JSON with jq expressions or JSONPath")]
+pub struct App {
+    #[command(subcommand)]
+    pub command: Commands,
+}
+
+#[derive(Debug, clap::Subcommand)]
+pub enum Commands {
+    /// Run a jq expression (or JSONPath starting with '$') against JSON input
+    #[clap(name = "query")]
+    Query(QueryOptions),
+}
+
+#[derive(Debug, clap::Args, Clone)]
+pub struct QueryOptions {
+    /// JSON or NDJSON file ('-' for stdin)
+    pub input: String,
+
+    /// jq expression (e.g. '.items[] | {id, name}') or JSONPath (e.g. '$..id')
+    pub query: String,
+
+    /// Print string results without quotes
+    #[arg(short, long)]
+    pub raw_output: bool,
+
+    /// One line per result instead of pretty-printed JSON
+    #[arg(short, long)]
+    pub compact: bool,
+
+    /// Collect all input values into one array before querying
+    #[arg(short, long)]
+    pub slurp: bool,
+}
+
+pub async fn run(app: App, _global: crate::Global) -> Result<()> {
+    match app.command {
+        Commands::Query(options) => query(options),
+    }
+}
+
+fn query(options: QueryOptions) -> Result<()> {
+    // Parse the query first so a typo fails before reading a large input.
+    let query = Query::parse(&options.query).map_err(|e| eyre!(e))?;
+
+    let file = (options.input != "-").then_some(options.input.as_str());
+    let bytes = crate::hash::read_input(None, file)?;
+    let text = String::from_utf8(bytes).map_err(|_| eyre!("Input is not valid UTF-8"))?;
+
+    let mut inputs = parse_inputs(&text).map_err(|e| eyre!(e))?;
+    if options.slurp {
+        inputs = vec![serde_json::Value::Array(inputs)];
+    }
+
+    let mut results = Vec::new();
+    for input in &inputs {
+        results.extend(query.run(input).map_err(|e| eyre!(e))?);
+    }
+
+    print!(
+        "{}",
+        format_results(&results, options.raw_output, options.compact)
+    );
+
+    Ok(())
+}
@@ -0,0 +1,189 @@
+use crate::prelude::{eprintln, *};
+use tokio::io::{AsyncBufReadExt, AsyncReadExt, AsyncWriteExt, BufReader};
+use tokio::net::TcpStream;
+use tokio_native_tls::TlsStream;
+
+use mcptools_core::mail::imap::{
+    astring, command_segments, literal_length, parse_completion, quote, xoauth2_response,
+    Completion,
+};
+
+use super::{MailAuth, MailConfig};
+
+/// A minimal IMAP-over-TLS session: tagged commands, literal-aware reads.
+///
+/// Mailboxes are opened with `EXAMINE` and bodies fetched with `BODY.PEEK`,
+/// so a session never changes server state (not even the `\Seen` flag).
+pub struct ImapSession {
+    stream: BufReader<TlsStream<TcpStream>>,
+    next_tag: u32,
+    verbose: bool,
+}
+
+impl ImapSession {
+    /// Connect, read the greeting, and authenticate.
+    pub async fn connect(config: &MailConfig, verbose: bool) -> Result<Self> {
+        let tcp = tokio::time::timeout(
+            std::time::Duration::from_secs(config.timeout),
+            TcpStream::connect((config.host.as_str(), config.port)),
+        )
+        .await
+        .map_err(|_| eyre!("Timed out connecting to {}:{}", config.host, config.port))?
+        .map_err(|e| {
+            eyre!(
+                "Failed to connect to {}:{}: {}",
+                config.host,
+                config.port,
+                e
+            )
+        })?;
+
+        let connector = tokio_native_tls::native_tls::TlsConnector::new()
+            .map_err(|e| eyre!("Failed to initialize TLS: {}", e))?;
+        let tls = tokio_native_tls::TlsConnector::from(connector)
+            .connect(&config.host, tcp)
+            .await
+            .map_err(|e| eyre!("TLS handshake with {} failed: {}", config.host, e))?;
+
+        let mut session = Self {
+            stream: BufReader::new(tls),
+            next_tag: 1,
+            verbose,
+        };
+
+        let greeting = session.read_line().await?;
+        if !greeting.starts_with(b"* OK") && !greeting.starts_with(b"* PREAUTH") {
+            return Err(eyre!(
+                "Unexpected IMAP greeting: {}",
+                String::from_utf8_lossy(&greeting).trim_end()
+            ));
+        }
+
+        match &config.auth {
+            MailAuth::Password(password) => {
+                let login = astring(&config.username)
+                    .and_then(|user| Ok(format!("LOGIN {} {}", user, astring(password)?)))
+                    .map_err(|e| eyre!(e))?;
+                session.command(&login).await
+            }
+            MailAuth::OAuth(token) => {
+                session
+                    .command(&format!(
+                        "AUTHENTICATE XOAUTH2 {}",
+                        xoauth2_response(&config.username, token)
+                    ))
+                    .await
+            }
+        }
+        .map_err(|e| eyre!("IMAP authentication failed: {}", e))?;
+
+        Ok(session)
+    }
+
+    /// Open a mailbox read-only.
+    pub async fn examine(&mut self, mailbox: &str) -> Result<()> {
+        let mailbox_arg =
+            quote(mailbox).map_err(|e| eyre!("Invalid mailbox name '{}': {}", mailbox, e))?;
+        self.command(&format!("EXAMINE {}", mailbox_arg))
+            .await
+            .map_err(|e| eyre!("Failed to open mailbox '{}': {}", mailbox, e))?;
+        Ok(())
+    }
+
+    /// Send a tagged command and return every response byte before the
+    /// tagged completion, literals included.
+    ///
+    /// Literals in the command (from `astring`) are sent one at a time after
+    /// the server's `+` continuation request.
+    pub async fn command(&mut self, command: &str) -> Result<Vec<u8>> {
+        let tag = format!("a{}", self.next_tag);
+        self.next_tag += 1;
+
+        if self.verbose {
+            // Never echo credentials.
+            let shown = match command.split_once(' ') {
+                Some(("LOGIN", _)) | Some(("AUTHENTICATE", _)) => {
+                    command.split(' ').take(2).collect::<Vec<_>>().join(" ")
+                }
+                _ => command.replace("\r\n", " "),
+            };
+            eprintln!("IMAP > {} {}", tag, shown);
+        }
+
+        let line = format!("{} {}", tag, command);
+        let segments = command_segments(&line);
+        let (last, before_literals) = segments
+            .split_last()
+            .expect("split always yields at least one piece");
+
+        let mut response = Vec::new();
+        for segment in before_literals {
+            self.write_line(segment).await?;
+            loop {
+                let line = self.read_line().await?;
+                if line.starts_with(b"+") {
+                    break;
+                }
+                let text = String::from_utf8_lossy(&line);
+                if let Some(completion) = parse_completion(text.trim_end(), &tag) {
+                    return Err(match completion {
+                        Completion::Ok => eyre!("Server finished the command before its literal"),
+                        Completion::No(message) | Completion::Bad(message) => eyre!(message),
+                    });
+                }
+                response.extend_from_slice(&line);
+            }
+        }
+        self.write_line(last).await?;
+
+        loop {
+            let line = self.read_line().await?;
+
+            if line.starts_with(b"+") {
+                // A continuation request here is an authentication error
+                // challenge; an empty response makes the server finish with NO.
+                let stream = self.stream.get_mut();
+                stream.write_all(b"\r\n").await?;
+                stream.flush().await?;
+                continue;
+            }
+
+            let text = String::from_utf8_lossy(&line);
+            if let Some(completion) = parse_completion(text.trim_end(), &tag) {
+                return match completion {
+                    Completion::Ok => Ok(response),
+                    Completion::No(message) | Completion::Bad(message) => Err(eyre!(message)),
+                };
+            }
+
+            let literal = literal_length(&line);
+            response.extend_from_slice(&line);
+            if let Some(len) = literal {
+                let mut buf = vec![0; len];
+                self.stream.read_exact(&mut buf).await?;
+                response.extend_from_slice(&buf);
+            }
+        }
+    }
+
+    /// End the session. Errors are ignored; the server may just hang up.
+    pub async fn logout(mut self) {
+        let _ = self.command("LOGOUT").await;
+    }
+
+    async fn write_line(&mut self, line: &str) -> Result<()> {
+        let stream = self.stream.get_mut();
+        stream.write_all(format!("{}\r\n", line).as_bytes()).await?;
+        stream.flush().await?;
+        Ok(())
+    }
+
+    async fn read_line(&mut self) -> Result<Vec<u8>> {
+        let mut line = Vec::new();
+        let read = self.stream.read_until(b'\n', &mut line).await?;
+        if read == 0 {
+            return Err(eyre!("IMAP server closed the connection"));
+        }
+        Ok(line)
+    }
+}
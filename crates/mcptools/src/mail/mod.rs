@@ -0,0 +1,116 @@
+use crate::prelude::{println, *};
+
+pub mod client;
+pub mod read;
+pub mod search;
+
+// Re-export public data functions
+pub use read::read_message_data;
+pub use search::search_messages_data;
+
+const DEFAULT_IMAP_PORT: u16 = 993;
+const DEFAULT_MAILBOX: &str = "INBOX";
+
+#[derive(Debug, clap::Parser)]
+#[command(name = "mail")]
+#[command(about = "Read-only email access over IMAP")]
+pub struct App {
+    #[command(subcommand)]
+    pub command: Commands,
+}
+
+#[derive(Debug, clap::Subcommand)]
+pub enum Commands {
+    /// Search a mailbox, newest messages first
+    #[clap(name = "search")]
+    Search(search::SearchOptions),
+
+    /// Read a message as Markdown
+    #[clap(name = "read")]
+    Read(read::ReadOptions),
+}
+
+pub async fn run(app: App, global: crate::Global) -> Result<()> {
+    if global.verbose {
+        if let Ok(config) = MailConfig::from_env() {
+            println!("IMAP server: {}:{}", config.host, config.port);
+            println!();
+        }
+    }
+
+    match app.command {
+        Commands::Search(options) => search::run(options, global).await,
+        Commands::Read(options) => read::run(options, global).await,
+    }
+}
+
+/// How to authenticate with the IMAP server.
+#[derive(Clone)]
+pub enum MailAuth {
+    /// `LOGIN` with a password (use an app password for Gmail/Outlook).
+    Password(String),
+    /// `AUTHENTICATE XOAUTH2` with an OAuth access token.
+    OAuth(String),
+}
+
+impl std::fmt::Debug for MailAuth {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Password(_) => f.write_str("Password(..)"),
+            Self::OAuth(_) => f.write_str("OAuth(..)"),
+        }
+    }
+}
+
+/// IMAP connection configuration
+#[derive(Debug, Clone)]
+pub struct MailConfig {
+    pub host: String,
+    pub port: u16,
+    pub username: String,
+    pub auth: MailAuth,
+    pub timeout: u64,
+}
+
+impl MailConfig {
+    /// Load configuration from environment variables.
+    ///
+    /// MAIL_OAUTH_TOKEN takes precedence over MAIL_PASSWORD when both are set.
+    pub fn from_env() -> Result<Self> {
+        let host = std::env::var("MAIL_IMAP_HOST")
+            .map_err(|_| eyre!("MAIL_IMAP_HOST environment variable not set"))?;
+        let port = match std::env::var("MAIL_IMAP_PORT") {
+            Ok(port) => port
+                .parse()
+                .map_err(|_| eyre!("Invalid MAIL_IMAP_PORT: {}", port))?,
+            Err(_) => DEFAULT_IMAP_PORT,
+        };
+        let username = std::env::var("MAIL_USERNAME")
+            .map_err(|_| eyre!("MAIL_USERNAME environment variable not set"))?;
+        let auth = match (
+            std::env::var("MAIL_OAUTH_TOKEN"),
+            std::env::var("MAIL_PASSWORD"),
+        ) {
+            (Ok(token), _) => MailAuth::OAuth(token),
+            (_, Ok(password)) => MailAuth::Password(password),
+            _ => {
+                return Err(eyre!(
+                    "Neither MAIL_PASSWORD nor MAIL_OAUTH_TOKEN environment variable is set"
+                ))
+            }
+        };
+
+        Ok(Self {
+            host,
+            port,
+            username,
+            auth,
+            timeout: 30,
+        })
+    }
+}
+
+/// Mailbox from MAIL_MAILBOX, defaulting to INBOX.
+pub fn default_mailbox() -> String {
+    std::env::var("MAIL_MAILBOX").unwrap_or_else(|_| DEFAULT_MAILBOX.to_string())
+}
@@ -0,0 +1,64 @@
+use crate::prelude::{println, *};
+
+use mcptools_core::mail::imap::parse_fetch_response;
+use mcptools_core::mail::{message_to_markdown, parse_message, MailMessage};
+
+use super::client::ImapSession;
+use super::{default_mailbox, MailConfig};
+
+#[derive(Debug, clap::Args, Clone)]
+pub struct ReadOptions {
+    /// Message UID (from `mail search`)
+    pub uid: u32,
+
+    /// Mailbox containing the message (default: INBOX)
+    #[arg(short, long, env = "MAIL_MAILBOX")]
+    pub mailbox: Option<String>,
+
+    /// Output as JSON
+    #[arg(long)]
+    pub json: bool,
+}
+
+pub async fn run(options: ReadOptions, global: crate::Global) -> Result<()> {
+    let mailbox = options.mailbox.unwrap_or_else(default_mailbox);
+    let message = read_message_data(
+        MailConfig::from_env()?,
+        options.uid,
+        mailbox,
+        global.verbose,
+    )
+    .await?;
+
+    if options.json {
+        println!("{}", serde_json::to_string_pretty(&message)?);
+    } else {
+        println!("{}", message_to_markdown(&message).trim_end());
+    }
+
+    Ok(())
+}
+
+/// Public function for MCP reuse - fetch and decode one message without marking it read.
+pub async fn read_message_data(
+    config: MailConfig,
+    uid: u32,
+    mailbox: String,
+    verbose: bool,
+) -> Result<MailMessage> {
+    let mut session = ImapSession::connect(&config, verbose).await?;
+    session.examine(&mailbox).await?;
+
+    let response = session
+        .command(&format!("UID FETCH {} (UID BODY.PEEK[])", uid))
+        .await
+        .map_err(|e| eyre!("Fetch failed: {}", e))?;
+    session.logout().await;
+
+    let fetched = parse_fetch_response(&response)
+        .into_iter()
+        .find(|m| m.uid == uid)
+        .ok_or_else(|| eyre!("Message {} not found in {}", uid, mailbox))?;
+
+    Ok(parse_message(uid, &mailbox, &fetched.body))
+}
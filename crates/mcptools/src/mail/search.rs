@@ -0,0 +1,163 @@
+use crate::prelude::{println, *};
+use colored::Colorize;
+
+use mcptools_core::mail::imap::{
+    build_search_criteria, parse_fetch_response, parse_search_response, uid_set, SearchQuery,
+};
+use mcptools_core::mail::{summarize_fetched, MailSearchOutput};
+
+use super::client::ImapSession;
+use super::{default_mailbox, MailConfig};
+
+#[derive(Debug, clap::Args, Clone)]
+pub struct SearchOptions {
+    /// Match the From header
+    #[arg(long)]
+    pub from: Option<String>,
+
+    /// Match the To header
+    #[arg(long)]
+    pub to: Option<String>,
+
+    /// Match the Subject header
+    #[arg(long)]
+    pub subject: Option<String>,
+
+    /// Full-text search over headers and body
+    #[arg(long)]
+    pub text: Option<String>,
+
+    /// Messages on or after this date (YYYY-MM-DD)
+    #[arg(long)]
+    pub since: Option<chrono::NaiveDate>,
+
+    /// Messages before this date (YYYY-MM-DD)
+    #[arg(long)]
+    pub before: Option<chrono::NaiveDate>,
+
+    /// Only unread messages
+    #[arg(long)]
+    pub unseen: bool,
+
+    /// Mailbox to search (default: INBOX)
+    #[arg(short, long, env = "MAIL_MAILBOX")]
+    pub mailbox: Option<String>,
+
+    /// Maximum number of messages to return
+    #[arg(short, long, default_value = "20")]
+    pub limit: usize,
+
+    /// Output as JSON
+    #[arg(long)]
+    pub json: bool,
+}
+
+pub async fn run(options: SearchOptions, global: crate::Global) -> Result<()> {
+    let query = SearchQuery {
+        from: options.from,
+        to: options.to,
+        subject: options.subject,
+        text: options.text,
+        since: options.since,
+        before: options.before,
+        unseen: options.unseen,
+    };
+    let mailbox = options.mailbox.unwrap_or_else(default_mailbox);
+
+    let output = search_messages_data(
+        MailConfig::from_env()?,
+        query,
+        mailbox,
+        options.limit,
+        global.verbose,
+    )
+    .await?;
+
+    if options.json {
+        println!("{}", serde_json::to_string_pretty(&output)?);
+        return Ok(());
+    }
+
+    if output.messages.is_empty() {
+        println!("No messages found in {}", output.mailbox);
+        return Ok(());
+    }
+
+    let mut table = new_table();
+    table.add_row(prettytable::row!["UID", "DATE", "FROM", "SUBJECT"]);
+    for message in &output.messages {
+        let subject = if message.seen {
+            message.subject.clone()
+        } else {
+            message.subject.bold().to_string()
+        };
+        table.add_row(prettytable::row![
+            message.uid.to_string().cyan(),
+            message.date,
+            message.from,
+            subject
+        ]);
+    }
+    table.printstd();
+
+    if output.total > output.messages.len() {
+        println!(
+            "\n{}",
+            format!(
+                "Showing {} of {} messages. Use --limit for more.",
+                output.messages.len(),
+                output.total
+            )
+            .dimmed()
+        );
+    }
+
+    Ok(())
+}
+
+/// Public function for MCP reuse - search a mailbox and summarize the newest matches.
+pub async fn search_messages_data(
+    config: MailConfig,
+    query: SearchQuery,
+    mailbox: String,
+    limit: usize,
+    verbose: bool,
+) -> Result<MailSearchOutput> {
+    let criteria = build_search_criteria(&query).map_err(|e| eyre!("Invalid search: {}", e))?;
+
+    let mut session = ImapSession::connect(&config, verbose).await?;
+    session.examine(&mailbox).await?;
+
+    let response = session
+        .command(&format!("UID SEARCH {}", criteria))
+        .await
+        .map_err(|e| eyre!("Search failed: {}", e))?;
+    let mut uids = parse_search_response(&response);
+    uids.sort_unstable_by(|a, b| b.cmp(a));
+    let total = uids.len();
+    uids.truncate(limit);
+
+    let mut messages = Vec::new();
+    if !uids.is_empty() {
+        let response = session
+            .command(&format!(
+                "UID FETCH {} (UID FLAGS RFC822.SIZE BODY.PEEK[HEADER.FIELDS (FROM TO SUBJECT DATE)])",
+                uid_set(&uids)
+            ))
+            .await
+            .map_err(|e| eyre!("Fetch failed: {}", e))?;
+        messages = parse_fetch_response(&response)
+            .iter()
+            .map(summarize_fetched)
+            .collect();
+        messages.sort_by_key(|m| std::cmp::Reverse(m.uid));
+    }
+
+    session.logout().await;
+
+    Ok(MailSearchOutput {
+        mailbox,
+        total,
+        messages,
+    })
+}
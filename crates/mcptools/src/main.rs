@@ -5,15 +5,30 @@ use clap::Parser;
 
 mod atlas;
 mod atlassian;
+mod cal;
+mod chunk;
+mod convert;
+mod demo;
+mod encode;
 mod error;
+mod generate;
 mod greprag;
+mod hash;
 mod hn;
+mod html;
+mod json;
+mod mail;
 mod mcp;
 mod md;
 mod pdf;
 mod prelude;
+mod search;
+mod state;
 mod strand;
+mod template;
+mod transcribe;
 mod upgrade;
+mod vision;
 
 #[derive(Debug, clap::Parser)]
 #[command(
@@ -61,12 +76,46 @@ pub enum SubCommands {
     /// Atlassian (Jira, Confluence) operations
     Atlassian(crate::atlassian::App),
 
+    /// Parse iCalendar (.ics) files and list events
+    Cal(crate::cal::App),
+
+    /// Split a PDF or web page into chunks for embedding pipelines
+    Chunk(crate::chunk::App),
+
+    /// Convert between Markdown, Jira ADF, and Confluence storage format
+    Convert(crate::convert::App),
+
+    /// Run a scripted multi-tool workflow against fixtures or live config
+    Demo(crate::demo::App),
+
+    /// Describe an image using a local Ollama vision model
+    DescribeImage(crate::vision::App),
+
+    /// Encode or decode base64, base64url, URL (percent), or hex
+    Encode(crate::encode::App),
+
+    /// Generate UUIDs, ULIDs, and random passwords
+    #[command(name = "gen")]
+    Gen(crate::generate::App),
+
     /// Retrieve relevant code context from a repository using GrepRAG
     GrepRag(crate::greprag::App),
 
+    /// Compute a SHA-256 or MD5 checksum of text, a file, or stdin
+    Hash(crate::hash::App),
+
     /// HackerNews (news.ycombinator.com) operations
     HN(crate::hn::App),
 
+    /// Select elements from HTML with CSS or XPath selectors
+    Html(crate::html::App),
+
+    /// Query JSON with jq expressions or JSONPath
+    Json(crate::json::App),
+
+    /// Read-only email search and reading over IMAP
+    Mail(crate::mail::App),
+
     /// Model Context Protocol server
     MCP(crate::mcp::App),
 
@@ -76,9 +125,21 @@ pub enum SubCommands {
     /// PDF document navigation and extraction
     Pdf(crate::pdf::App),
 
+    /// Search Jira, Confluence, Bitbucket, HackerNews, and the local index at once
+    SearchAll(crate::search::App),
+
+    /// Manage the versioned state directory (caches, sessions, audit log)
+    State(crate::state::App),
+
     /// Local Rust code generation using Ollama
     Strand(crate::strand::App),
 
+    /// Render Handlebars templates against JSON data
+    Template(crate::template::App),
+
+    /// Transcribe audio into a timestamped Markdown transcript
+    Transcribe(crate::transcribe::App),
+
     /// Upgrade mcptools to the latest version
     Upgrade(crate::upgrade::App),
 }
@@ -93,12 +154,27 @@ async fn main() -> Result<()> {
     match app.command {
         SubCommands::Atlas(sub_app) => crate::atlas::run(sub_app, app.global).await,
         SubCommands::Atlassian(sub_app) => crate::atlassian::run(sub_app, app.global).await,
+        SubCommands::Cal(sub_app) => crate::cal::run(sub_app, app.global).await,
+        SubCommands::Chunk(sub_app) => crate::chunk::run(sub_app, app.global).await,
+        SubCommands::Convert(sub_app) => crate::convert::run(sub_app, app.global).await,
+        SubCommands::Demo(sub_app) => crate::demo::run(sub_app, app.global).await,
+        SubCommands::DescribeImage(sub_app) => crate::vision::run(sub_app, app.global).await,
+        SubCommands::Encode(sub_app) => crate::encode::run(sub_app, app.global).await,
+        SubCommands::Gen(sub_app) => crate::generate::run(sub_app, app.global).await,
         SubCommands::GrepRag(sub_app) => crate::greprag::run(sub_app, app.global).await,
+        SubCommands::Hash(sub_app) => crate::hash::run(sub_app, app.global).await,
         SubCommands::HN(sub_app) => crate::hn::run(sub_app, app.global).await,
+        SubCommands::Html(sub_app) => crate::html::run(sub_app, app.global).await,
+        SubCommands::Json(sub_app) => crate::json::run(sub_app, app.global).await,
+        SubCommands::Mail(sub_app) => crate::mail::run(sub_app, app.global).await,
         SubCommands::MCP(sub_app) => crate::mcp::run(sub_app, app.global).await,
         SubCommands::MD(sub_app) => crate::md::run(sub_app, app.global).await,
         SubCommands::Pdf(sub_app) => crate::pdf::run(sub_app, app.global).await,
+        SubCommands::SearchAll(sub_app) => crate::search::run(sub_app, app.global).await,
+        SubCommands::State(sub_app) => crate::state::run(sub_app, app.global).await,
         SubCommands::Strand(sub_app) => crate::strand::run(sub_app, app.global).await,
+        SubCommands::Template(sub_app) => crate::template::run(sub_app, app.global).await,
+        SubCommands::Transcribe(sub_app) => crate::transcribe::run(sub_app, app.global).await,
         SubCommands::Upgrade(sub_app) => crate::upgrade::run(sub_app, app.global).await,
     }
     .map_err(|err: color_eyre::eyre::Report| eyre!(err))
@@ -4,6 +4,25 @@
 pub struct App {
     #[command(subcommand)]
     pub command: Commands,
+
+    /// Accept per-call Atlassian credentials in `arguments._credentials`
+    /// (lets one server instance serve multiple Jira/Confluence sites)
+    #[arg(
+        long,
+        env = "MCPTOOLS_ALLOW_CREDENTIAL_OVERRIDES",
+        global = true,
+        default_value = "false"
+    )]
+    pub allow_credential_overrides: bool,
+
+    /// Maximum tool calls executed at once; further calls wait for a slot
+    #[arg(
+        long,
+        env = "MCPTOOLS_MAX_CONCURRENCY",
+        global = true,
+        default_value = "8"
+    )]
+    pub max_concurrency: usize,
 }
 
 #[derive(Debug, clap::Subcommand)]
@@ -26,4 +45,26 @@ pub struct SseOptions {
     /// Host to bind to
     #[arg(long, default_value = "127.0.0.1")]
     pub host: String,
+
+    /// Responses buffered per SSE session before the overflow policy applies
+    #[arg(long, env = "MCPTOOLS_SSE_QUEUE_CAPACITY", default_value = "64")]
+    pub queue_capacity: usize,
+
+    /// What to do when a session's queue is full
+    #[arg(
+        long,
+        value_enum,
+        env = "MCPTOOLS_SSE_OVERFLOW",
+        default_value = "disconnect"
+    )]
+    pub overflow: OverflowPolicy,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum, serde::Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum OverflowPolicy {
+    /// Discard the response that does not fit; the session stays open
+    Drop,
+    /// Close the session so the client reconnects and retries
+    Disconnect,
 }
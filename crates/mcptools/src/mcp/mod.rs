@@ -2,12 +2,16 @@ mod cli;
 mod resources;
 mod sse;
 mod stdio;
+mod stream;
 mod tools;
 
 pub use cli::App;
 
 use crate::prelude::*;
 use serde::{Deserialize, Serialize};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::OnceLock;
+use tokio::sync::{Semaphore, SemaphorePermit};
 
 // JSON-RPC 2.0 types
 #[derive(Debug, Deserialize)]
@@ -45,14 +49,49 @@ pub struct Tool {
     pub input_schema: serde_json::Value,
 }
 
+/// Set once at startup from `--allow-credential-overrides`.
+static CREDENTIAL_OVERRIDES_ALLOWED: AtomicBool = AtomicBool::new(false);
+
+/// Whether tool calls may carry `_credentials` overrides.
+pub fn credential_overrides_allowed() -> bool {
+    CREDENTIAL_OVERRIDES_ALLOWED.load(Ordering::Relaxed)
+}
+
+/// Tool calls allowed to run at once, across all clients of this server.
+const DEFAULT_MAX_CONCURRENCY: usize = 8;
+
+/// Set once at startup from `--max-concurrency`.
+static TOOL_CALL_LIMITER: OnceLock<Semaphore> = OnceLock::new();
+
+/// Wait for a tool-call slot; held for the duration of the call.
+async fn acquire_tool_call_slot() -> SemaphorePermit<'static> {
+    TOOL_CALL_LIMITER
+        .get_or_init(|| Semaphore::new(DEFAULT_MAX_CONCURRENCY))
+        .acquire()
+        .await
+        .expect("tool call limiter is never closed")
+}
+
 pub async fn run(app: App, global: crate::Global) -> Result<()> {
+    CREDENTIAL_OVERRIDES_ALLOWED.store(app.allow_credential_overrides, Ordering::Relaxed);
+    if app.max_concurrency == 0 {
+        return Err(eyre!("--max-concurrency must be at least 1"));
+    }
+    let _ = TOOL_CALL_LIMITER.set(Semaphore::new(app.max_concurrency));
+
     match app.command {
         cli::Commands::Stdio => stdio::run_stdio(global).await,
         cli::Commands::Sse(options) => sse::run_sse(options, global).await,
     }
 }
 
-pub async fn handle_request(request_str: &str, global: &crate::Global) -> JsonRpcResponse {
+/// Handle one JSON-RPC request. `notify` is the transport's channel for
+/// server-to-client messages; without it, streamed results are answered whole.
+pub async fn handle_request(
+    request_str: &str,
+    global: &crate::Global,
+    notify: Option<&stream::Notifier>,
+) -> JsonRpcResponse {
     let request: JsonRpcRequest = match serde_json::from_str(request_str) {
         Ok(req) => req,
         Err(e) => {
@@ -72,7 +111,17 @@ pub async fn handle_request(request_str: &str, global: &crate::Global) -> JsonRp
     let result = match request.method.as_str() {
         "initialize" => tools::handle_initialize(),
         "tools/list" => tools::handle_tools_list(),
-        "tools/call" => tools::handle_tools_call(request.params, global).await,
+        "tools/call" => {
+            let _slot = acquire_tool_call_slot().await;
+            let streaming = notify.zip(stream::stream_request(request.params.as_ref()));
+            let result = tools::handle_tools_call(request.params, global).await;
+            match (result, streaming) {
+                (Ok(value), Some((notify, request))) => {
+                    Ok(stream::stream_result(value, &request, notify).await)
+                }
+                (result, _) => result,
+            }
+        }
         "resources/list" => resources::handle_resources_list(),
         "resources/read" => resources::handle_resources_read(request.params),
         method => Err(JsonRpcError {
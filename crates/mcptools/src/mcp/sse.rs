@@ -1,15 +1,138 @@
 use crate::prelude::{eprintln, *};
 use axum::{
-    extract::State,
-    response::sse::{Event, Sse},
+    extract::{Query, State},
+    http::StatusCode,
+    response::{
+        sse::{Event, KeepAlive, Sse},
+        IntoResponse, Response,
+    },
     routing::{get, post},
     Json, Router,
 };
-use futures::stream::{self, Stream};
+use futures::stream::{self, Stream, StreamExt};
+use std::collections::HashMap;
 use std::convert::Infallible;
-use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tokio::sync::mpsc;
 use tower_http::cors::{Any, CorsLayer};
 
+use super::cli::OverflowPolicy;
+
+/// How long a message waits for room in a full session queue before the
+/// overflow policy applies.
+const QUEUE_GRACE: Duration = Duration::from_secs(5);
+
+/// One connected `GET /sse` client.
+struct Session {
+    tx: mpsc::Sender<String>,
+    /// Set when the overflow policy closes the session, so the stream stops
+    /// without draining what is still queued.
+    disconnected: Arc<AtomicBool>,
+}
+
+#[derive(Default)]
+struct Metrics {
+    sessions_opened: AtomicU64,
+    sessions_closed: AtomicU64,
+    messages_enqueued: AtomicU64,
+    messages_sent: AtomicU64,
+    messages_dropped: AtomicU64,
+    overflow_disconnects: AtomicU64,
+}
+
+struct ServerState {
+    global: crate::Global,
+    queue_capacity: usize,
+    overflow: OverflowPolicy,
+    sessions: Mutex<HashMap<String, Session>>,
+    metrics: Metrics,
+}
+
+impl ServerState {
+    /// Queue a message for `session_id`. A full queue gets [`QUEUE_GRACE`]
+    /// to drain before the overflow policy applies, so a client that is
+    /// merely slow is throttled rather than cut off.
+    async fn deliver(&self, session_id: &str, message: String) {
+        let tx = self
+            .sessions
+            .lock()
+            .expect("sessions lock poisoned")
+            .get(session_id)
+            .map(|session| session.tx.clone());
+        let Some(tx) = tx else {
+            // The client went away while the request ran.
+            self.metrics
+                .messages_dropped
+                .fetch_add(1, Ordering::Relaxed);
+            return;
+        };
+        let sent = match tx.try_send(message) {
+            Ok(()) => Ok(()),
+            Err(mpsc::error::TrySendError::Closed(_)) => Err(false),
+            Err(mpsc::error::TrySendError::Full(message)) => {
+                match tokio::time::timeout(QUEUE_GRACE, tx.send(message)).await {
+                    Ok(Ok(())) => Ok(()),
+                    Ok(Err(_)) => Err(false),
+                    Err(_) => Err(true),
+                }
+            }
+        };
+        match sent {
+            Ok(()) => {
+                self.metrics
+                    .messages_enqueued
+                    .fetch_add(1, Ordering::Relaxed);
+            }
+            Err(overflowed) => {
+                self.metrics
+                    .messages_dropped
+                    .fetch_add(1, Ordering::Relaxed);
+                if overflowed {
+                    self.overflow(session_id);
+                }
+            }
+        }
+    }
+
+    fn overflow(&self, session_id: &str) {
+        if self.overflow == OverflowPolicy::Disconnect {
+            let mut sessions = self.sessions.lock().expect("sessions lock poisoned");
+            if let Some(session) = sessions.remove(session_id) {
+                session.disconnected.store(true, Ordering::Relaxed);
+                self.metrics
+                    .overflow_disconnects
+                    .fetch_add(1, Ordering::Relaxed);
+            }
+        }
+        if self.global.verbose {
+            eprintln!(
+                "SSE session {} queue full ({} messages); {:?}",
+                session_id, self.queue_capacity, self.overflow
+            );
+        }
+    }
+}
+
+/// Removes the session when its SSE stream is dropped (client disconnect).
+struct SessionGuard {
+    state: Arc<ServerState>,
+    session_id: String,
+}
+
+impl Drop for SessionGuard {
+    fn drop(&mut self) {
+        if let Ok(mut sessions) = self.state.sessions.lock() {
+            sessions.remove(&self.session_id);
+        }
+        self.state
+            .metrics
+            .sessions_closed
+            .fetch_add(1, Ordering::Relaxed);
+    }
+}
+
 pub async fn run_sse(options: super::cli::SseOptions, global: crate::Global) -> Result<()> {
     if global.verbose {
         eprintln!(
@@ -17,6 +140,9 @@ pub async fn run_sse(options: super::cli::SseOptions, global: crate::Global) ->
             options.host, options.port
         );
     }
+    if options.queue_capacity == 0 {
+        return Err(eyre!("--queue-capacity must be at least 1"));
+    }
 
     let addr = format!("{}:{}", options.host, options.port);
 
@@ -25,18 +151,28 @@ pub async fn run_sse(options: super::cli::SseOptions, global: crate::Global) ->
         .allow_methods(Any)
         .allow_headers(Any);
 
-    let shared_global = Arc::new(global.clone());
+    let state = Arc::new(ServerState {
+        global: global.clone(),
+        queue_capacity: options.queue_capacity,
+        overflow: options.overflow,
+        sessions: Mutex::new(HashMap::new()),
+        metrics: Metrics::default(),
+    });
 
+    // `/metrics` is added after the CORS layer so browsers on other origins
+    // cannot read it.
     let app_router = Router::new()
         .route("/sse", get(sse_handler))
         .route("/message", post(message_handler))
         .layer(cors)
-        .with_state(shared_global);
+        .route("/metrics", get(metrics_handler))
+        .with_state(state);
 
     if global.verbose {
         eprintln!("MCP server listening on http://{}", addr);
         eprintln!("SSE endpoint: http://{}/sse", addr);
         eprintln!("Message endpoint: http://{}/message", addr);
+        eprintln!("Metrics endpoint: http://{}/metrics", addr);
     }
 
     let listener = tokio::net::TcpListener::bind(&addr)
@@ -50,18 +186,151 @@ pub async fn run_sse(options: super::cli::SseOptions, global: crate::Global) ->
     Ok(())
 }
 
+fn new_session_id() -> String {
+    use mcptools_core::generate::{format_uuid, uuid_bytes, UuidFormat, UuidVersion};
+
+    let bytes = uuid_bytes(&mut rand::thread_rng(), UuidVersion::V4, 0);
+    format_uuid(&bytes, UuidFormat::Simple, false)
+}
+
+/// Open a session: the first event names the endpoint to POST requests to,
+/// and every later event carries one JSON-RPC response.
 async fn sse_handler(
-    State(_global): State<Arc<crate::Global>>,
+    State(state): State<Arc<ServerState>>,
 ) -> Sse<impl Stream<Item = Result<Event, Infallible>>> {
-    let stream = stream::once(async { Ok(Event::default().data("MCP SSE endpoint ready")) });
-    Sse::new(stream)
+    let session_id = new_session_id();
+    let (tx, rx) = mpsc::channel(state.queue_capacity);
+    let disconnected = Arc::new(AtomicBool::new(false));
+    state
+        .sessions
+        .lock()
+        .expect("sessions lock poisoned")
+        .insert(
+            session_id.clone(),
+            Session {
+                tx,
+                disconnected: disconnected.clone(),
+            },
+        );
+    state
+        .metrics
+        .sessions_opened
+        .fetch_add(1, Ordering::Relaxed);
+
+    let endpoint = Event::default()
+        .event("endpoint")
+        .data(format!("/message?sessionId={}", session_id));
+    let guard = SessionGuard {
+        state: state.clone(),
+        session_id,
+    };
+    let messages = stream::unfold(
+        (rx, guard, disconnected),
+        |(mut rx, guard, disconnected)| async move {
+            let message = rx.recv().await?;
+            if disconnected.load(Ordering::Relaxed) {
+                return None;
+            }
+            guard
+                .state
+                .metrics
+                .messages_sent
+                .fetch_add(1, Ordering::Relaxed);
+            let event = Event::default().event("message").data(message);
+            Some((Ok(event), (rx, guard, disconnected)))
+        },
+    );
+
+    Sse::new(stream::once(async { Ok(endpoint) }).chain(messages)).keep_alive(KeepAlive::default())
+}
+
+#[derive(Debug, serde::Deserialize)]
+struct MessageQuery {
+    #[serde(rename = "sessionId")]
+    session_id: Option<String>,
 }
 
+/// With `?sessionId=`, accept the request and deliver the response on that
+/// session's stream; without it, answer inline in the HTTP response.
 async fn message_handler(
-    State(global): State<Arc<crate::Global>>,
+    State(state): State<Arc<ServerState>>,
+    Query(query): Query<MessageQuery>,
     Json(request): Json<serde_json::Value>,
-) -> Json<serde_json::Value> {
+) -> Response {
     let request_str = serde_json::to_string(&request).unwrap_or_default();
-    let response = super::handle_request(&request_str, &global).await;
-    Json(serde_json::to_value(response).unwrap_or(serde_json::Value::Null))
+
+    let Some(session_id) = query.session_id else {
+        let response = super::handle_request(&request_str, &state.global, None).await;
+        return Json(serde_json::to_value(response).unwrap_or(serde_json::Value::Null))
+            .into_response();
+    };
+
+    if !state
+        .sessions
+        .lock()
+        .expect("sessions lock poisoned")
+        .contains_key(&session_id)
+    {
+        return (StatusCode::NOT_FOUND, "Unknown or closed session").into_response();
+    }
+
+    tokio::spawn(async move {
+        let notify: super::stream::Notifier = {
+            let (state, session_id) = (state.clone(), session_id.clone());
+            Arc::new(move |message| {
+                let (state, session_id) = (state.clone(), session_id.clone());
+                Box::pin(async move { state.deliver(&session_id, message).await })
+            })
+        };
+        let response = super::handle_request(&request_str, &state.global, Some(&notify)).await;
+        match serde_json::to_string(&response) {
+            Ok(message) => state.deliver(&session_id, message).await,
+            Err(e) => eprintln!("Failed to serialize response: {e}"),
+        }
+    });
+    StatusCode::ACCEPTED.into_response()
+}
+
+/// Server-wide counters and queue depths. Session IDs are left out: they
+/// are the only credential `POST /message` checks.
+#[derive(Debug, serde::Serialize)]
+struct MetricsOutput {
+    queue_capacity: usize,
+    overflow: OverflowPolicy,
+    active_sessions: usize,
+    sessions_opened: u64,
+    sessions_closed: u64,
+    messages_enqueued: u64,
+    messages_sent: u64,
+    messages_dropped: u64,
+    overflow_disconnects: u64,
+    /// Messages waiting across all session queues
+    queued_messages: usize,
+    /// Depth of the fullest session queue
+    max_queue_depth: usize,
+}
+
+async fn metrics_handler(State(state): State<Arc<ServerState>>) -> Json<MetricsOutput> {
+    let depths: Vec<usize> = state
+        .sessions
+        .lock()
+        .expect("sessions lock poisoned")
+        .values()
+        .map(|session| state.queue_capacity - session.tx.capacity())
+        .collect();
+
+    let m = &state.metrics;
+    Json(MetricsOutput {
+        queue_capacity: state.queue_capacity,
+        overflow: state.overflow,
+        active_sessions: depths.len(),
+        sessions_opened: m.sessions_opened.load(Ordering::Relaxed),
+        sessions_closed: m.sessions_closed.load(Ordering::Relaxed),
+        messages_enqueued: m.messages_enqueued.load(Ordering::Relaxed),
+        messages_sent: m.messages_sent.load(Ordering::Relaxed),
+        messages_dropped: m.messages_dropped.load(Ordering::Relaxed),
+        overflow_disconnects: m.overflow_disconnects.load(Ordering::Relaxed),
+        queued_messages: depths.iter().sum(),
+        max_queue_depth: depths.iter().copied().max().unwrap_or(0),
+    })
 }
@@ -1,18 +1,59 @@
 use crate::prelude::{eprintln, *};
+use serde_json::Value;
+use std::collections::HashMap;
+use std::sync::Arc;
 use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
+use tokio::sync::{mpsc, Semaphore};
+use tokio::task::{self, JoinError, JoinSet};
 
+/// Requests read but not yet answered; at the cap, stdin is not read again
+/// until one of them finishes.
+const MAX_IN_FLIGHT: usize = 64;
+
+/// Responses and notifications waiting for stdout; senders wait when full.
+const OUTPUT_QUEUE: usize = 256;
+
+/// Serve JSON-RPC over stdin/stdout.
+///
+/// Each request runs on its own task, so a slow tool call does not hold up
+/// the ones behind it; responses are written as they complete and matched
+/// to requests by `id`, which JSON-RPC permits. At most [`MAX_IN_FLIGHT`]
+/// requests are read ahead, and tool calls among them are further bounded
+/// by the server's concurrency limiter. A request whose task fails is
+/// answered with an internal error instead of ending the session.
 pub async fn run_stdio(global: crate::Global) -> Result<()> {
     if global.verbose {
         eprintln!("Starting MCP server with stdio transport...");
         eprintln!();
     }
 
+    // A single writer keeps concurrent responses from interleaving on stdout.
+    let (tx, mut rx) = mpsc::channel::<String>(OUTPUT_QUEUE);
+    let writer = tokio::spawn(async move {
+        let mut stdout = tokio::io::stdout();
+        while let Some(response_json) = rx.recv().await {
+            stdout.write_all(response_json.as_bytes()).await?;
+            stdout.write_all(b"\n").await?;
+            stdout.flush().await?;
+        }
+        Ok::<(), std::io::Error>(())
+    });
+
     let stdin = tokio::io::stdin();
-    let mut stdout = tokio::io::stdout();
     let mut reader = BufReader::new(stdin);
     let mut line = String::new();
+    let slots = Arc::new(Semaphore::new(MAX_IN_FLIGHT));
+    let mut in_flight = JoinSet::new();
+    // Request ids by task, to answer a request whose task panicked.
+    let mut request_ids: HashMap<task::Id, Option<Value>> = HashMap::new();
 
     loop {
+        let slot = slots
+            .clone()
+            .acquire_owned()
+            .await
+            .expect("in-flight semaphore is never closed");
+
         line.clear();
         let bytes_read = reader.read_line(&mut line).await?;
 
@@ -20,7 +61,7 @@ pub async fn run_stdio(global: crate::Global) -> Result<()> {
             break; // EOF
         }
 
-        let trimmed = line.trim();
+        let trimmed = line.trim().to_string();
         if trimmed.is_empty() {
             continue;
         }
@@ -29,17 +70,81 @@ pub async fn run_stdio(global: crate::Global) -> Result<()> {
             eprintln!("Received: {trimmed}");
         }
 
-        let response = super::handle_request(trimmed, &global).await;
-        let response_json = serde_json::to_string(&response)?;
+        let request_id = serde_json::from_str::<Value>(&trimmed)
+            .ok()
+            .and_then(|request| request.get("id").cloned());
+        let global = global.clone();
+        let sender = tx.clone();
+        let notify: super::stream::Notifier = {
+            let tx = tx.clone();
+            Arc::new(move |message| {
+                let tx = tx.clone();
+                Box::pin(async move {
+                    let _ = tx.send(message).await;
+                })
+            })
+        };
+        let id = request_id.clone();
+        let handle = in_flight.spawn(async move {
+            let _slot = slot;
+            let response = super::handle_request(&trimmed, &global, Some(&notify)).await;
+            let response_json = serde_json::to_string(&response).unwrap_or_else(|e| {
+                eprintln!("Failed to serialize response: {e}");
+                internal_error(id, format!("Failed to serialize response: {e}"))
+            });
 
-        if global.verbose {
-            eprintln!("Sending: {response_json}");
+            if global.verbose {
+                eprintln!("Sending: {response_json}");
+            }
+
+            // The writer only stops when stdout fails; nothing left to report to.
+            let _ = sender.send(response_json).await;
+        });
+        request_ids.insert(handle.id(), request_id);
+
+        // Reap finished requests so the set does not grow with the session.
+        while let Some(result) = in_flight.try_join_next_with_id() {
+            reap(result, &mut request_ids, &tx).await;
         }
+    }
 
-        stdout.write_all(response_json.as_bytes()).await?;
-        stdout.write_all(b"\n").await?;
-        stdout.flush().await?;
+    // Answer everything already received before exiting.
+    while let Some(result) = in_flight.join_next_with_id().await {
+        reap(result, &mut request_ids, &tx).await;
     }
+    drop(tx);
+    writer.await??;
 
     Ok(())
 }
+
+/// Forget a finished request, answering it with an internal error if its
+/// task panicked before it could respond.
+async fn reap(
+    result: std::result::Result<(task::Id, ()), JoinError>,
+    request_ids: &mut HashMap<task::Id, Option<Value>>,
+    tx: &mpsc::Sender<String>,
+) {
+    let error = match result {
+        Ok((task_id, ())) => {
+            request_ids.remove(&task_id);
+            return;
+        }
+        Err(error) => error,
+    };
+    eprintln!("Request task failed: {error}");
+    let id = request_ids.remove(&error.id()).flatten();
+    let _ = tx
+        .send(internal_error(id, format!("Request failed: {error}")))
+        .await;
+}
+
+/// A serialized JSON-RPC internal error (-32603) response for `id`.
+fn internal_error(id: Option<Value>, message: String) -> String {
+    serde_json::json!({
+        "jsonrpc": "2.0",
+        "id": id,
+        "error": { "code": -32603, "message": message },
+    })
+    .to_string()
+}
@@ -0,0 +1,93 @@
+//! Opt-in chunked delivery of large tool results.
+//!
+//! A `tools/call` whose `params._meta` carries a `progressToken` and
+//! `streamChunks` (`true`, or a maximum piece size in characters) receives
+//! its text content as ordered `notifications/progress` messages, followed by
+//! the regular response with empty `content` and `_meta.streamed` as the
+//! final marker. Transports without a server-to-client channel (inline SSE
+//! POSTs) answer with the full result instead.
+
+use futures::future::BoxFuture;
+use mcptools_core::chunk::split_for_streaming;
+use serde_json::{json, Value};
+use std::sync::Arc;
+
+/// Characters per streamed piece when `streamChunks` is `true`.
+const DEFAULT_CHUNK_CHARS: usize = 16_000;
+
+/// Smallest piece size accepted, so a typo cannot produce millions of messages.
+const MIN_CHUNK_CHARS: usize = 256;
+
+/// Delivers one serialized JSON-RPC notification to the client, waiting for
+/// room in the transport's queue.
+pub type Notifier = Arc<dyn Fn(String) -> BoxFuture<'static, ()> + Send + Sync>;
+
+/// A streaming opt-in parsed from `params._meta`.
+pub struct StreamRequest {
+    token: Value,
+    chunk_chars: usize,
+}
+
+/// Read the streaming opt-in from `tools/call` params, if present.
+pub fn stream_request(params: Option<&Value>) -> Option<StreamRequest> {
+    let meta = params?.get("_meta")?;
+    let token = meta.get("progressToken")?.clone();
+    let chunk_chars = match meta.get("streamChunks")? {
+        Value::Bool(true) => DEFAULT_CHUNK_CHARS,
+        Value::Number(n) => (n.as_u64()? as usize).max(MIN_CHUNK_CHARS),
+        _ => return None,
+    };
+    Some(StreamRequest { token, chunk_chars })
+}
+
+/// Send `result`'s text content as progress notifications and return the
+/// final marker. Results that fit in one piece are returned unchanged.
+pub async fn stream_result(result: Value, request: &StreamRequest, notify: &Notifier) -> Value {
+    let Some(content) = result.get("content").and_then(Value::as_array) else {
+        return result;
+    };
+
+    // (content index, piece): text is split, anything else goes whole.
+    let mut pieces: Vec<(usize, Value)> = Vec::new();
+    for (index, item) in content.iter().enumerate() {
+        match item.get("text").and_then(Value::as_str) {
+            Some(text) if item.get("type") == Some(&json!("text")) => {
+                pieces.extend(
+                    split_for_streaming(text, request.chunk_chars)
+                        .into_iter()
+                        .map(|piece| (index, json!({ "type": "text", "text": piece }))),
+                );
+            }
+            _ => pieces.push((index, item.clone())),
+        }
+    }
+    if pieces.len() <= 1 {
+        return result;
+    }
+
+    let total = pieces.len();
+    for (sequence, (content_index, piece)) in pieces.into_iter().enumerate() {
+        let notification = json!({
+            "jsonrpc": "2.0",
+            "method": "notifications/progress",
+            "params": {
+                "progressToken": request.token,
+                "progress": sequence + 1,
+                "total": total,
+                "message": format!("chunk {} of {}", sequence + 1, total),
+                "contentIndex": content_index,
+                "content": [piece],
+            }
+        });
+        notify(notification.to_string()).await;
+    }
+
+    let mut marker = json!({
+        "content": [],
+        "_meta": { "streamed": true, "chunks": total }
+    });
+    if let Some(is_error) = result.get("isError") {
+        marker["isError"] = is_error.clone();
+    }
+    marker
+}
@@ -20,6 +20,9 @@ pub async fn handle_jira_search(
         limit: Option<usize>,
         #[serde(rename = "nextPageToken")]
         next_page_token: Option<String>,
+        sprint: Option<String>,
+        #[serde(default)]
+        vars: mcptools_core::template::TemplateVars,
     }
 
     let args: JiraSearchArgs = serde_json::from_value(arguments.unwrap_or(serde_json::Value::Null))
@@ -30,6 +33,7 @@ pub async fn handle_jira_search(
         })?;
 
     // Resolve query: either use provided query or load saved query
+    let from_saved = args.query_name.is_some();
     let resolved_query = if let Some(query_name) = args.query_name {
         // Load saved query
         let home = env::var("HOME")
@@ -55,6 +59,22 @@ pub async fn handle_jira_search(
         })?
     };
 
+    // Fill in {name} placeholders for saved queries, or whenever vars are given
+    let resolved_query = if from_saved || !args.vars.is_empty() {
+        queries::render_query(&resolved_query, &args.vars).map_err(|e| JsonRpcError {
+            code: -32602,
+            message: e.to_string(),
+            data: None,
+        })?
+    } else {
+        resolved_query
+    };
+
+    let resolved_query = match &args.sprint {
+        Some(sprint) => mcptools_core::atlassian::jira::with_sprint_filter(&resolved_query, sprint),
+        None => resolved_query,
+    };
+
     if global.verbose {
         eprintln!(
             "Calling jira_search: query={}, limit={:?}, nextPageToken={:?}",
@@ -163,6 +183,7 @@ pub async fn handle_jira_get(
     struct JiraGetArgs {
         #[serde(rename = "issueKey")]
         issue_key: String,
+        expand: Option<String>,
     }
 
     let args: JiraGetArgs = serde_json::from_value(arguments.unwrap_or(serde_json::Value::Null))
@@ -172,12 +193,29 @@ pub async fn handle_jira_get(
             data: None,
         })?;
 
+    let expand_changelog = match args.expand.as_deref() {
+        None => false,
+        Some("changelog") => true,
+        Some(other) => {
+            return Err(JsonRpcError {
+                code: -32602,
+                message: format!(
+                    "Invalid arguments: unknown expand '{other}' (expected 'changelog')"
+                ),
+                data: None,
+            })
+        }
+    };
+
     if global.verbose {
-        eprintln!("Calling jira_get: issueKey={}", args.issue_key);
+        eprintln!(
+            "Calling jira_get: issueKey={}, expand={:?}",
+            args.issue_key, args.expand
+        );
     }
 
     // Call the Jira module's data function
-    let ticket_data = crate::atlassian::jira::get_ticket_data(args.issue_key)
+    let ticket_data = crate::atlassian::jira::get_ticket_data(args.issue_key, expand_changelog)
         .await
         .map_err(|e| JsonRpcError {
             code: -32603,
@@ -204,6 +242,169 @@ pub async fn handle_jira_get(
     })
 }
 
+/// Handle Jira history command via MCP
+pub async fn handle_jira_history(
+    arguments: Option<serde_json::Value>,
+    global: &crate::Global,
+) -> Result<serde_json::Value, JsonRpcError> {
+    #[derive(Deserialize)]
+    struct Args {
+        #[serde(rename = "issueKey")]
+        issue_key: String,
+        field: Option<String>,
+    }
+
+    let args: Args =
+        serde_json::from_value(arguments.unwrap_or(serde_json::Value::Null)).map_err(|e| {
+            JsonRpcError {
+                code: -32602,
+                message: format!("Invalid arguments: {e}"),
+                data: None,
+            }
+        })?;
+
+    if global.verbose {
+        eprintln!(
+            "Calling jira_history: issueKey={}, field={:?}",
+            args.issue_key, args.field
+        );
+    }
+
+    let history = crate::atlassian::jira::get_changelog_data(args.issue_key, args.field)
+        .await
+        .map_err(|e| JsonRpcError {
+            code: -32603,
+            message: format!("Tool execution error: {e}"),
+            data: None,
+        })?;
+
+    let json_string = serde_json::to_string_pretty(&history).map_err(|e| JsonRpcError {
+        code: -32603,
+        message: format!("Serialization error: {e}"),
+        data: None,
+    })?;
+
+    let result = CallToolResult {
+        content: vec![Content::Text { text: json_string }],
+        is_error: None,
+    };
+
+    serde_json::to_value(result).map_err(|e| JsonRpcError {
+        code: -32603,
+        message: format!("Internal error: {e}"),
+        data: None,
+    })
+}
+
+/// Handle Jira field list command via MCP
+pub async fn handle_jira_field_list(
+    arguments: Option<serde_json::Value>,
+    global: &crate::Global,
+) -> Result<serde_json::Value, JsonRpcError> {
+    #[derive(Deserialize)]
+    struct JiraFieldListArgs {
+        #[serde(default, rename = "customOnly")]
+        custom_only: bool,
+        search: Option<String>,
+    }
+
+    let args: JiraFieldListArgs =
+        serde_json::from_value(arguments.unwrap_or(serde_json::json!({}))).map_err(|e| {
+            JsonRpcError {
+                code: -32602,
+                message: format!("Invalid arguments: {e}"),
+                data: None,
+            }
+        })?;
+
+    if global.verbose {
+        eprintln!(
+            "Calling jira_field_list: custom_only={}, search={:?}",
+            args.custom_only, args.search
+        );
+    }
+
+    let fields = crate::atlassian::jira::list_fields_data(args.custom_only, args.search)
+        .await
+        .map_err(|e| JsonRpcError {
+            code: -32603,
+            message: format!("Tool execution error: {e}"),
+            data: None,
+        })?;
+
+    let json_string = serde_json::to_string_pretty(&fields).map_err(|e| JsonRpcError {
+        code: -32603,
+        message: format!("Serialization error: {e}"),
+        data: None,
+    })?;
+
+    let result = CallToolResult {
+        content: vec![Content::Text { text: json_string }],
+        is_error: None,
+    };
+
+    serde_json::to_value(result).map_err(|e| JsonRpcError {
+        code: -32603,
+        message: format!("Internal error: {e}"),
+        data: None,
+    })
+}
+
+/// Handle Jira board list command via MCP
+pub async fn handle_jira_board_list(
+    arguments: Option<serde_json::Value>,
+    global: &crate::Global,
+) -> Result<serde_json::Value, JsonRpcError> {
+    #[derive(Deserialize)]
+    struct JiraBoardListArgs {
+        project: Option<String>,
+        #[serde(rename = "type")]
+        board_type: Option<String>,
+        name: Option<String>,
+    }
+
+    let args: JiraBoardListArgs =
+        serde_json::from_value(arguments.unwrap_or(serde_json::json!({}))).map_err(|e| {
+            JsonRpcError {
+                code: -32602,
+                message: format!("Invalid arguments: {e}"),
+                data: None,
+            }
+        })?;
+
+    if global.verbose {
+        eprintln!(
+            "Calling jira_board_list: project={:?}, type={:?}, name={:?}",
+            args.project, args.board_type, args.name
+        );
+    }
+
+    let boards = crate::atlassian::jira::list_boards_data(args.project, args.board_type, args.name)
+        .await
+        .map_err(|e| JsonRpcError {
+            code: -32603,
+            message: format!("Tool execution error: {e}"),
+            data: None,
+        })?;
+
+    let json_string = serde_json::to_string_pretty(&boards).map_err(|e| JsonRpcError {
+        code: -32603,
+        message: format!("Serialization error: {e}"),
+        data: None,
+    })?;
+
+    let result = CallToolResult {
+        content: vec![Content::Text { text: json_string }],
+        is_error: None,
+    };
+
+    serde_json::to_value(result).map_err(|e| JsonRpcError {
+        code: -32603,
+        message: format!("Internal error: {e}"),
+        data: None,
+    })
+}
+
 /// Handle Jira sprint list command via MCP
 pub async fn handle_jira_sprint_list(
     arguments: Option<serde_json::Value>,
@@ -276,6 +477,17 @@ pub async fn handle_jira_update(
         issue_type: Option<String>,
         assignee: Option<String>,
         description: Option<String>,
+        summary: Option<String>,
+        #[serde(rename = "dueDate")]
+        due_date: Option<String>,
+        #[serde(default)]
+        components: Vec<String>,
+        #[serde(rename = "addLabels", default)]
+        add_labels: Vec<String>,
+        #[serde(rename = "removeLabels", default)]
+        remove_labels: Vec<String>,
+        #[serde(default)]
+        fields: std::collections::BTreeMap<String, serde_json::Value>,
         sprint: Option<String>,
         #[serde(rename = "boardId")]
         board_id: Option<u64>,
@@ -310,6 +522,28 @@ pub async fn handle_jira_update(
         issue_type: args.issue_type,
         assignee: args.assignee,
         description: args.description,
+        summary: args.summary,
+        due_date: args.due_date,
+        components: args.components,
+        add_labels: args.add_labels,
+        remove_labels: args.remove_labels,
+        fields: args
+            .fields
+            .into_iter()
+            .map(|(name, value)| match value {
+                serde_json::Value::String(s) => format!("{name}={s}"),
+                serde_json::Value::Null => format!("{name}="),
+                serde_json::Value::Array(items) => format!(
+                    "{name}={}",
+                    items
+                        .iter()
+                        .map(|item| item.as_str().map_or_else(|| item.to_string(), String::from))
+                        .collect::<Vec<_>>()
+                        .join(",")
+                ),
+                other => format!("{name}={other}"),
+            })
+            .collect(),
         sprint: args.sprint,
         board: args.board_id,
         json: true, // MCP always returns JSON
@@ -357,6 +591,8 @@ pub async fn handle_jira_create(
         issue_type: Option<String>,
         priority: Option<String>,
         assignee: Option<String>,
+        #[serde(default)]
+        labels: Vec<String>,
         sprint: Option<String>,
         #[serde(rename = "boardId")]
         board_id: Option<u64>,
@@ -375,13 +611,14 @@ pub async fn handle_jira_create(
             &d[..std::cmp::min(50, len)]
         });
         eprintln!(
-            "Calling jira_create: summary={}, description={:?}, project={:?}, issueType={:?}, priority={:?}, assignee={:?}, sprint={:?}, boardId={:?}",
+            "Calling jira_create: summary={}, description={:?}, project={:?}, issueType={:?}, priority={:?}, assignee={:?}, labels={:?}, sprint={:?}, boardId={:?}",
             args.summary,
             desc_preview,
             args.project,
             args.issue_type,
             args.priority,
             args.assignee,
+            args.labels,
             args.sprint,
             args.board_id,
         );
@@ -393,6 +630,7 @@ pub async fn handle_jira_create(
         description: args.description,
         project: args.project.unwrap_or_else(|| "PROD".to_string()),
         issue_type: args.issue_type.unwrap_or_else(|| "Task".to_string()),
+        labels: args.labels,
         priority: args.priority,
         assignee: args.assignee,
         sprint: args.sprint,
@@ -665,6 +903,7 @@ pub async fn handle_jira_query_load(
 
     let json_string = serde_json::to_string_pretty(&serde_json::json!({
         "name": args.name,
+        "variables": queries::query_variables(&query),
         "query": query
     }))
     .map_err(|e| JsonRpcError {
@@ -1203,8 +1442,8 @@ pub async fn handle_jira_comment_list(
     })
 }
 
-/// Handle Jira comment update command via MCP
-pub async fn handle_jira_comment_update(
+/// Handle Jira transitions list command via MCP
+pub async fn handle_jira_transitions_list(
     arguments: Option<serde_json::Value>,
     global: &crate::Global,
 ) -> Result<serde_json::Value, JsonRpcError> {
@@ -1212,9 +1451,6 @@ pub async fn handle_jira_comment_update(
     struct Args {
         #[serde(rename = "issueKey")]
         issue_key: String,
-        #[serde(rename = "commentId")]
-        comment_id: String,
-        comment: String,
     }
 
     let args: Args =
@@ -1227,23 +1463,18 @@ pub async fn handle_jira_comment_update(
         })?;
 
     if global.verbose {
-        let comment_preview: String = args.comment.chars().take(50).collect();
-        eprintln!(
-            "Calling jira_comment_update: issueKey={}, commentId={}, comment={}",
-            args.issue_key, args.comment_id, comment_preview
-        );
+        eprintln!("Calling jira_transitions_list: issueKey={}", args.issue_key);
     }
 
-    let output =
-        crate::atlassian::jira::update_comment_data(args.issue_key, args.comment_id, args.comment)
-            .await
-            .map_err(|e| JsonRpcError {
-                code: -32603,
-                message: format!("Tool execution error: {e}"),
-                data: None,
-            })?;
+    let transitions = crate::atlassian::jira::list_transitions_data(args.issue_key)
+        .await
+        .map_err(|e| JsonRpcError {
+            code: -32603,
+            message: format!("Tool execution error: {e}"),
+            data: None,
+        })?;
 
-    let json_string = serde_json::to_string_pretty(&output).map_err(|e| JsonRpcError {
+    let json_string = serde_json::to_string_pretty(&transitions).map_err(|e| JsonRpcError {
         code: -32603,
         message: format!("Serialization error: {e}"),
         data: None,
@@ -1261,17 +1492,17 @@ pub async fn handle_jira_comment_update(
     })
 }
 
-/// Handle Jira comment delete command via MCP
-pub async fn handle_jira_comment_delete(
+/// Handle Jira epic command via MCP
+pub async fn handle_jira_epic(
     arguments: Option<serde_json::Value>,
     global: &crate::Global,
 ) -> Result<serde_json::Value, JsonRpcError> {
     #[derive(Deserialize)]
     struct Args {
-        #[serde(rename = "issueKey")]
-        issue_key: String,
-        #[serde(rename = "commentId")]
-        comment_id: String,
+        #[serde(rename = "epicKey")]
+        epic_key: String,
+        #[serde(rename = "storyPointsField")]
+        story_points_field: Option<String>,
     }
 
     let args: Args =
@@ -1283,14 +1514,18 @@ pub async fn handle_jira_comment_delete(
             }
         })?;
 
+    let points_field = args
+        .story_points_field
+        .or_else(|| std::env::var("JIRA_STORY_POINTS_FIELD").ok());
+
     if global.verbose {
         eprintln!(
-            "Calling jira_comment_delete: issueKey={}, commentId={}",
-            args.issue_key, args.comment_id
+            "Calling jira_epic: epicKey={}, storyPointsField={:?}",
+            args.epic_key, points_field
         );
     }
 
-    crate::atlassian::jira::delete_comment_data(args.issue_key.clone(), args.comment_id.clone())
+    let epic = crate::atlassian::jira::get_epic_data(args.epic_key, points_field)
         .await
         .map_err(|e| JsonRpcError {
             code: -32603,
@@ -1298,12 +1533,513 @@ pub async fn handle_jira_comment_delete(
             data: None,
         })?;
 
-    let json_string = serde_json::to_string_pretty(&serde_json::json!({
-        "deleted": true,
-        "issue_key": args.issue_key,
-        "comment_id": args.comment_id
-    }))
+    let json_string = serde_json::to_string_pretty(&epic).map_err(|e| JsonRpcError {
+        code: -32603,
+        message: format!("Serialization error: {e}"),
+        data: None,
+    })?;
+
+    let result = CallToolResult {
+        content: vec![Content::Text { text: json_string }],
+        is_error: None,
+    };
+
+    serde_json::to_value(result).map_err(|e| JsonRpcError {
+        code: -32603,
+        message: format!("Internal error: {e}"),
+        data: None,
+    })
+}
+
+/// Handle Jira link command via MCP
+pub async fn handle_jira_link(
+    arguments: Option<serde_json::Value>,
+    global: &crate::Global,
+) -> Result<serde_json::Value, JsonRpcError> {
+    #[derive(Deserialize)]
+    struct Args {
+        #[serde(rename = "issueKey")]
+        issue_key: String,
+        #[serde(default)]
+        blocks: Vec<String>,
+        #[serde(rename = "relatesTo", default)]
+        relates_to: Vec<String>,
+        #[serde(default)]
+        duplicates: Vec<String>,
+    }
+
+    let args: Args =
+        serde_json::from_value(arguments.unwrap_or(serde_json::Value::Null)).map_err(|e| {
+            JsonRpcError {
+                code: -32602,
+                message: format!("Invalid arguments: {e}"),
+                data: None,
+            }
+        })?;
+
+    if global.verbose {
+        eprintln!(
+            "Calling jira_link: issueKey={}, blocks={:?}, relatesTo={:?}, duplicates={:?}",
+            args.issue_key, args.blocks, args.relates_to, args.duplicates
+        );
+    }
+
+    let requested: Vec<(String, String)> = [
+        ("blocks", args.blocks),
+        ("relates to", args.relates_to),
+        ("duplicates", args.duplicates),
+    ]
+    .into_iter()
+    .flat_map(|(relationship, keys)| {
+        keys.into_iter()
+            .map(move |key| (relationship.to_string(), key))
+    })
+    .collect();
+
+    let links = if requested.is_empty() {
+        crate::atlassian::jira::list_issue_links_data(args.issue_key).await
+    } else {
+        crate::atlassian::jira::create_issue_links_data(args.issue_key, requested).await
+    }
     .map_err(|e| JsonRpcError {
+        code: -32603,
+        message: format!("Tool execution error: {e}"),
+        data: None,
+    })?;
+
+    let json_string = serde_json::to_string_pretty(&links).map_err(|e| JsonRpcError {
+        code: -32603,
+        message: format!("Serialization error: {e}"),
+        data: None,
+    })?;
+
+    let result = CallToolResult {
+        content: vec![Content::Text { text: json_string }],
+        is_error: None,
+    };
+
+    serde_json::to_value(result).map_err(|e| JsonRpcError {
+        code: -32603,
+        message: format!("Internal error: {e}"),
+        data: None,
+    })
+}
+
+/// Handle Jira watch command via MCP
+pub async fn handle_jira_watch(
+    arguments: Option<serde_json::Value>,
+    global: &crate::Global,
+) -> Result<serde_json::Value, JsonRpcError> {
+    #[derive(Deserialize)]
+    struct Args {
+        #[serde(rename = "issueKey")]
+        issue_key: String,
+        #[serde(default)]
+        add: Vec<String>,
+        #[serde(default)]
+        remove: Vec<String>,
+    }
+
+    let args: Args =
+        serde_json::from_value(arguments.unwrap_or(serde_json::Value::Null)).map_err(|e| {
+            JsonRpcError {
+                code: -32602,
+                message: format!("Invalid arguments: {e}"),
+                data: None,
+            }
+        })?;
+
+    if global.verbose {
+        eprintln!(
+            "Calling jira_watch: issueKey={}, add={:?}, remove={:?}",
+            args.issue_key, args.add, args.remove
+        );
+    }
+
+    let watchers = if args.add.is_empty() && args.remove.is_empty() {
+        crate::atlassian::jira::list_watchers_data(args.issue_key).await
+    } else {
+        crate::atlassian::jira::update_watchers_data(args.issue_key, args.add, args.remove).await
+    }
+    .map_err(|e| JsonRpcError {
+        code: -32603,
+        message: format!("Tool execution error: {e}"),
+        data: None,
+    })?;
+
+    let json_string = serde_json::to_string_pretty(&watchers).map_err(|e| JsonRpcError {
+        code: -32603,
+        message: format!("Serialization error: {e}"),
+        data: None,
+    })?;
+
+    let result = CallToolResult {
+        content: vec![Content::Text { text: json_string }],
+        is_error: None,
+    };
+
+    serde_json::to_value(result).map_err(|e| JsonRpcError {
+        code: -32603,
+        message: format!("Internal error: {e}"),
+        data: None,
+    })
+}
+
+/// Handle Jira worklog add command via MCP
+pub async fn handle_jira_worklog_add(
+    arguments: Option<serde_json::Value>,
+    global: &crate::Global,
+) -> Result<serde_json::Value, JsonRpcError> {
+    #[derive(Deserialize)]
+    struct Args {
+        #[serde(rename = "issueKey")]
+        issue_key: String,
+        time: String,
+        comment: Option<String>,
+    }
+
+    let args: Args =
+        serde_json::from_value(arguments.unwrap_or(serde_json::Value::Null)).map_err(|e| {
+            JsonRpcError {
+                code: -32602,
+                message: format!("Invalid arguments: {e}"),
+                data: None,
+            }
+        })?;
+
+    mcptools_core::atlassian::jira::parse_worklog_duration(&args.time).map_err(|e| {
+        JsonRpcError {
+            code: -32602,
+            message: format!("Invalid arguments: {e}"),
+            data: None,
+        }
+    })?;
+
+    if global.verbose {
+        eprintln!(
+            "Calling jira_worklog_add: issueKey={}, time={}",
+            args.issue_key, args.time
+        );
+    }
+
+    let output = crate::atlassian::jira::add_worklog_data(args.issue_key, args.time, args.comment)
+        .await
+        .map_err(|e| JsonRpcError {
+            code: -32603,
+            message: format!("Tool execution error: {e}"),
+            data: None,
+        })?;
+
+    let json_string = serde_json::to_string_pretty(&output).map_err(|e| JsonRpcError {
+        code: -32603,
+        message: format!("Serialization error: {e}"),
+        data: None,
+    })?;
+
+    let result = CallToolResult {
+        content: vec![Content::Text { text: json_string }],
+        is_error: None,
+    };
+
+    serde_json::to_value(result).map_err(|e| JsonRpcError {
+        code: -32603,
+        message: format!("Internal error: {e}"),
+        data: None,
+    })
+}
+
+/// Handle Jira worklog list command via MCP
+pub async fn handle_jira_worklog_list(
+    arguments: Option<serde_json::Value>,
+    global: &crate::Global,
+) -> Result<serde_json::Value, JsonRpcError> {
+    #[derive(Deserialize)]
+    struct Args {
+        #[serde(rename = "issueKey")]
+        issue_key: String,
+    }
+
+    let args: Args =
+        serde_json::from_value(arguments.unwrap_or(serde_json::Value::Null)).map_err(|e| {
+            JsonRpcError {
+                code: -32602,
+                message: format!("Invalid arguments: {e}"),
+                data: None,
+            }
+        })?;
+
+    if global.verbose {
+        eprintln!("Calling jira_worklog_list: issueKey={}", args.issue_key);
+    }
+
+    let worklogs = crate::atlassian::jira::list_worklogs_data(args.issue_key)
+        .await
+        .map_err(|e| JsonRpcError {
+            code: -32603,
+            message: format!("Tool execution error: {e}"),
+            data: None,
+        })?;
+
+    let json_string = serde_json::to_string_pretty(&worklogs).map_err(|e| JsonRpcError {
+        code: -32603,
+        message: format!("Serialization error: {e}"),
+        data: None,
+    })?;
+
+    let result = CallToolResult {
+        content: vec![Content::Text { text: json_string }],
+        is_error: None,
+    };
+
+    serde_json::to_value(result).map_err(|e| JsonRpcError {
+        code: -32603,
+        message: format!("Internal error: {e}"),
+        data: None,
+    })
+}
+
+/// Handle Jira comment update command via MCP
+pub async fn handle_jira_comment_update(
+    arguments: Option<serde_json::Value>,
+    global: &crate::Global,
+) -> Result<serde_json::Value, JsonRpcError> {
+    #[derive(Deserialize)]
+    struct Args {
+        #[serde(rename = "issueKey")]
+        issue_key: String,
+        #[serde(rename = "commentId")]
+        comment_id: String,
+        comment: String,
+    }
+
+    let args: Args =
+        serde_json::from_value(arguments.unwrap_or(serde_json::Value::Null)).map_err(|e| {
+            JsonRpcError {
+                code: -32602,
+                message: format!("Invalid arguments: {e}"),
+                data: None,
+            }
+        })?;
+
+    if global.verbose {
+        let comment_preview: String = args.comment.chars().take(50).collect();
+        eprintln!(
+            "Calling jira_comment_update: issueKey={}, commentId={}, comment={}",
+            args.issue_key, args.comment_id, comment_preview
+        );
+    }
+
+    let output =
+        crate::atlassian::jira::update_comment_data(args.issue_key, args.comment_id, args.comment)
+            .await
+            .map_err(|e| JsonRpcError {
+                code: -32603,
+                message: format!("Tool execution error: {e}"),
+                data: None,
+            })?;
+
+    let json_string = serde_json::to_string_pretty(&output).map_err(|e| JsonRpcError {
+        code: -32603,
+        message: format!("Serialization error: {e}"),
+        data: None,
+    })?;
+
+    let result = CallToolResult {
+        content: vec![Content::Text { text: json_string }],
+        is_error: None,
+    };
+
+    serde_json::to_value(result).map_err(|e| JsonRpcError {
+        code: -32603,
+        message: format!("Internal error: {e}"),
+        data: None,
+    })
+}
+
+/// Handle Jira comment delete command via MCP
+pub async fn handle_jira_comment_delete(
+    arguments: Option<serde_json::Value>,
+    global: &crate::Global,
+) -> Result<serde_json::Value, JsonRpcError> {
+    #[derive(Deserialize)]
+    struct Args {
+        #[serde(rename = "issueKey")]
+        issue_key: String,
+        #[serde(rename = "commentId")]
+        comment_id: String,
+    }
+
+    let args: Args =
+        serde_json::from_value(arguments.unwrap_or(serde_json::Value::Null)).map_err(|e| {
+            JsonRpcError {
+                code: -32602,
+                message: format!("Invalid arguments: {e}"),
+                data: None,
+            }
+        })?;
+
+    if global.verbose {
+        eprintln!(
+            "Calling jira_comment_delete: issueKey={}, commentId={}",
+            args.issue_key, args.comment_id
+        );
+    }
+
+    crate::atlassian::jira::delete_comment_data(args.issue_key.clone(), args.comment_id.clone())
+        .await
+        .map_err(|e| JsonRpcError {
+            code: -32603,
+            message: format!("Tool execution error: {e}"),
+            data: None,
+        })?;
+
+    let json_string = serde_json::to_string_pretty(&serde_json::json!({
+        "deleted": true,
+        "issue_key": args.issue_key,
+        "comment_id": args.comment_id
+    }))
+    .map_err(|e| JsonRpcError {
+        code: -32603,
+        message: format!("Serialization error: {e}"),
+        data: None,
+    })?;
+
+    let result = CallToolResult {
+        content: vec![Content::Text { text: json_string }],
+        is_error: None,
+    };
+
+    serde_json::to_value(result).map_err(|e| JsonRpcError {
+        code: -32603,
+        message: format!("Internal error: {e}"),
+        data: None,
+    })
+}
+
+/// Handle Jira bulk comment command via MCP
+pub async fn handle_jira_bulk_comment(
+    arguments: Option<serde_json::Value>,
+    global: &crate::Global,
+) -> Result<serde_json::Value, JsonRpcError> {
+    #[derive(Deserialize)]
+    struct Args {
+        jql: String,
+        template: String,
+        #[serde(default)]
+        vars: std::collections::BTreeMap<String, String>,
+        limit: Option<usize>,
+        #[serde(rename = "delayMs")]
+        delay_ms: Option<u64>,
+        #[serde(rename = "dryRun", default)]
+        dry_run: bool,
+    }
+
+    let args: Args =
+        serde_json::from_value(arguments.unwrap_or(serde_json::Value::Null)).map_err(|e| {
+            JsonRpcError {
+                code: -32602,
+                message: format!("Invalid arguments: {e}"),
+                data: None,
+            }
+        })?;
+
+    if global.verbose {
+        eprintln!(
+            "Calling jira_bulk_comment: jql={}, template={}, vars={:?}, dryRun={}",
+            args.jql, args.template, args.vars, args.dry_run
+        );
+    }
+
+    let options = crate::atlassian::jira::bulk_comment::BulkCommentOptions {
+        jql: args.jql,
+        template: args.template,
+        vars: args
+            .vars
+            .into_iter()
+            .map(|(name, value)| format!("{name}={value}"))
+            .collect(),
+        limit: args.limit.unwrap_or(50),
+        delay_ms: args.delay_ms.unwrap_or(500),
+        dry_run: args.dry_run,
+        json: true,
+    };
+
+    let output = crate::atlassian::jira::bulk_comment_data(options)
+        .await
+        .map_err(|e| JsonRpcError {
+            code: -32603,
+            message: format!("Tool execution error: {e}"),
+            data: None,
+        })?;
+
+    let json_string = serde_json::to_string_pretty(&output).map_err(|e| JsonRpcError {
+        code: -32603,
+        message: format!("Serialization error: {e}"),
+        data: None,
+    })?;
+
+    let result = CallToolResult {
+        content: vec![Content::Text { text: json_string }],
+        is_error: None,
+    };
+
+    serde_json::to_value(result).map_err(|e| JsonRpcError {
+        code: -32603,
+        message: format!("Internal error: {e}"),
+        data: None,
+    })
+}
+
+/// Handle Jira bulk update command via MCP
+pub async fn handle_jira_bulk_update(
+    arguments: Option<serde_json::Value>,
+    global: &crate::Global,
+) -> Result<serde_json::Value, JsonRpcError> {
+    #[derive(Deserialize)]
+    struct Args {
+        updates: serde_json::Value,
+        concurrency: Option<usize>,
+        #[serde(rename = "dryRun", default)]
+        dry_run: bool,
+    }
+
+    let args: Args =
+        serde_json::from_value(arguments.unwrap_or(serde_json::Value::Null)).map_err(|e| {
+            JsonRpcError {
+                code: -32602,
+                message: format!("Invalid arguments: {e}"),
+                data: None,
+            }
+        })?;
+
+    let rows =
+        mcptools_core::atlassian::jira::parse_bulk_update_json(&args.updates).map_err(|e| {
+            JsonRpcError {
+                code: -32602,
+                message: format!("Invalid updates:\n{e}"),
+                data: None,
+            }
+        })?;
+
+    if global.verbose {
+        eprintln!(
+            "Calling jira_bulk_update: rows={}, concurrency={:?}, dryRun={}",
+            rows.len(),
+            args.concurrency,
+            args.dry_run
+        );
+    }
+
+    let output =
+        crate::atlassian::jira::bulk_update_data(rows, args.concurrency.unwrap_or(4), args.dry_run)
+            .await
+            .map_err(|e| JsonRpcError {
+                code: -32603,
+                message: format!("Tool execution error: {e}"),
+                data: None,
+            })?;
+
+    let json_string = serde_json::to_string_pretty(&output).map_err(|e| JsonRpcError {
         code: -32603,
         message: format!("Serialization error: {e}"),
         data: None,
@@ -0,0 +1,97 @@
+use serde::Deserialize;
+
+use super::{CallToolResult, Content, JsonRpcError};
+use crate::cal::CalendarQuery;
+use mcptools_core::cal::render_events_markdown;
+
+fn invalid(message: String) -> JsonRpcError {
+    JsonRpcError {
+        code: -32602,
+        message,
+        data: None,
+    }
+}
+
+fn parse_date(
+    value: Option<String>,
+    name: &str,
+) -> Result<Option<chrono::NaiveDate>, JsonRpcError> {
+    value
+        .map(|v| {
+            chrono::NaiveDate::parse_from_str(&v, "%Y-%m-%d")
+                .map_err(|_| invalid(format!("Invalid {name} date '{v}' (expected YYYY-MM-DD)")))
+        })
+        .transpose()
+}
+
+pub async fn handle_calendar_events(
+    arguments: Option<serde_json::Value>,
+    global: &crate::Global,
+) -> Result<serde_json::Value, JsonRpcError> {
+    #[derive(Deserialize)]
+    #[serde(rename_all = "camelCase")]
+    struct CalendarEventsArgs {
+        source: String,
+        from: Option<String>,
+        to: Option<String>,
+        days: Option<u32>,
+        timezone: Option<String>,
+        format: Option<String>,
+    }
+
+    let args: CalendarEventsArgs =
+        serde_json::from_value(arguments.unwrap_or(serde_json::Value::Null))
+            .map_err(|e| invalid(format!("Invalid arguments: {e}")))?;
+
+    let json = match args.format.as_deref() {
+        None | Some("markdown") => false,
+        Some("json") => true,
+        Some(other) => {
+            return Err(invalid(format!(
+                "Invalid format '{other}' (expected 'markdown' or 'json')"
+            )))
+        }
+    };
+
+    if global.verbose {
+        anstream::eprintln!("Calling calendar_events: source='{}'", args.source);
+    }
+
+    let query = CalendarQuery {
+        source: args.source,
+        from: parse_date(args.from, "from")?,
+        to: parse_date(args.to, "to")?,
+        days: args.days.unwrap_or(7),
+        timezone: args.timezone.or_else(|| std::env::var("CAL_TIMEZONE").ok()),
+        timeout: 30,
+        verbose: global.verbose,
+    };
+
+    let output = crate::cal::calendar_events_data(query)
+        .await
+        .map_err(|e| JsonRpcError {
+            code: -32603,
+            message: format!("Tool execution error: {e}"),
+            data: None,
+        })?;
+
+    let text = if json {
+        serde_json::to_string_pretty(&output).map_err(|e| JsonRpcError {
+            code: -32603,
+            message: format!("Serialization error: {e}"),
+            data: None,
+        })?
+    } else {
+        render_events_markdown(&output)
+    };
+
+    serde_json::to_value(CallToolResult {
+        content: vec![Content::Text { text }],
+        is_error: None,
+    })
+    .map_err(|e| JsonRpcError {
+        code: -32603,
+        message: format!("Internal error: {e}"),
+        data: None,
+    })
+}
@@ -0,0 +1,104 @@
+use serde::Deserialize;
+
+use super::{CallToolResult, Content, JsonRpcError};
+use mcptools_core::generate::{GenerateKind, PasswordOptions, UuidFormat, UuidVersion};
+
+fn invalid(message: String) -> JsonRpcError {
+    JsonRpcError {
+        code: -32602,
+        message,
+        data: None,
+    }
+}
+
+pub async fn handle_generate_id(
+    arguments: Option<serde_json::Value>,
+    global: &crate::Global,
+) -> Result<serde_json::Value, JsonRpcError> {
+    #[derive(Deserialize)]
+    #[serde(rename_all = "camelCase")]
+    struct GenerateIdArgs {
+        kind: String,
+        count: Option<usize>,
+        seed: Option<u64>,
+        version: Option<serde_json::Value>,
+        format: Option<String>,
+        #[serde(default)]
+        uppercase: bool,
+        #[serde(default)]
+        lowercase: bool,
+        length: Option<usize>,
+        symbols: Option<bool>,
+        digits: Option<bool>,
+        #[serde(default)]
+        exclude_ambiguous: bool,
+    }
+
+    let args: GenerateIdArgs = serde_json::from_value(arguments.unwrap_or(serde_json::Value::Null))
+        .map_err(|e| invalid(format!("Invalid arguments: {e}")))?;
+
+    let kind = match args.kind.as_str() {
+        "uuid" => {
+            // Accept 4, "4", or "v4"
+            let version: UuidVersion = match &args.version {
+                None => UuidVersion::V4,
+                Some(serde_json::Value::String(s)) => s.parse().map_err(invalid)?,
+                Some(other) => other.to_string().parse().map_err(invalid)?,
+            };
+            let format: UuidFormat = args
+                .format
+                .as_deref()
+                .unwrap_or("hyphenated")
+                .parse()
+                .map_err(invalid)?;
+            GenerateKind::Uuid {
+                version,
+                format,
+                uppercase: args.uppercase,
+            }
+        }
+        "ulid" => GenerateKind::Ulid {
+            lowercase: args.lowercase,
+        },
+        "password" => GenerateKind::Password(PasswordOptions {
+            length: args.length.unwrap_or(20),
+            digits: args.digits.unwrap_or(true),
+            symbols: args.symbols.unwrap_or(true),
+            exclude_ambiguous: args.exclude_ambiguous,
+            ..Default::default()
+        }),
+        other => {
+            return Err(invalid(format!(
+                "Invalid kind '{other}' (expected 'uuid', 'ulid', or 'password')"
+            )))
+        }
+    };
+
+    if global.verbose {
+        anstream::eprintln!(
+            "Calling generate_id: kind={}, count={:?}, seed={:?}",
+            kind.name(),
+            args.count,
+            args.seed
+        );
+    }
+
+    let output = crate::generate::generate_data(&kind, args.count.unwrap_or(1), args.seed)
+        .map_err(|e| invalid(e.to_string()))?;
+
+    let text = serde_json::to_string_pretty(&output).map_err(|e| JsonRpcError {
+        code: -32603,
+        message: format!("Serialization error: {e}"),
+        data: None,
+    })?;
+
+    serde_json::to_value(CallToolResult {
+        content: vec![Content::Text { text }],
+        is_error: None,
+    })
+    .map_err(|e| JsonRpcError {
+        code: -32603,
+        message: format!("Internal error: {e}"),
+        data: None,
+    })
+}
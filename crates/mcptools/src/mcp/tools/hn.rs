@@ -12,7 +12,15 @@ pub async fn handle_hn_read_item(
         item: String,
         limit: Option<usize>,
         page: Option<usize>,
+        cursor: Option<String>,
         thread: Option<String>,
+        depth: Option<usize>,
+        thread_limit: Option<usize>,
+        thread_page: Option<usize>,
+        #[serde(rename = "showDead", default)]
+        show_dead: bool,
+        #[serde(default)]
+        no_cache: bool,
     }
 
     let args: HnReadItemArgs = serde_json::from_value(arguments.unwrap_or(serde_json::Value::Null))
@@ -29,12 +37,104 @@ pub async fn handle_hn_read_item(
         );
     }
 
+    let tool_error = |e: color_eyre::eyre::Report| JsonRpcError {
+        code: -32603,
+        message: format!("Tool execution error: {e}"),
+        data: None,
+    };
+
     // Call the HN module's data function
-    let post_data = crate::hn::read_item_data(
+    let post_data = match &args.thread {
+        Some(thread) => serde_json::to_value(
+            crate::hn::read_thread_data(
+                args.item,
+                thread,
+                args.depth,
+                args.thread_limit,
+                args.thread_page.unwrap_or(1),
+                args.show_dead,
+                !args.no_cache,
+            )
+            .await
+            .map_err(tool_error)?,
+        ),
+        None => serde_json::to_value(
+            crate::hn::read_item_data(
+                args.item,
+                args.limit.unwrap_or(10),
+                args.page.unwrap_or(1),
+                args.cursor.as_deref(),
+                args.show_dead,
+                !args.no_cache,
+            )
+            .await
+            .map_err(tool_error)?,
+        ),
+    }
+    .map_err(|e| JsonRpcError {
+        code: -32603,
+        message: format!("Serialization error: {e}"),
+        data: None,
+    })?;
+
+    // Convert to JSON and wrap in MCP result format
+    let json_string = serde_json::to_string_pretty(&post_data).map_err(|e| JsonRpcError {
+        code: -32603,
+        message: format!("Serialization error: {e}"),
+        data: None,
+    })?;
+
+    let result = CallToolResult {
+        content: vec![Content::Text { text: json_string }],
+        is_error: None,
+    };
+
+    serde_json::to_value(result).map_err(|e| JsonRpcError {
+        code: -32603,
+        message: format!("Internal error: {e}"),
+        data: None,
+    })
+}
+
+pub async fn handle_hn_read_article(
+    arguments: Option<serde_json::Value>,
+    global: &crate::Global,
+) -> Result<serde_json::Value, JsonRpcError> {
+    #[derive(Deserialize)]
+    struct HnReadArticleArgs {
+        item: String,
+        limit: Option<usize>,
+        article_limit: Option<usize>,
+        article_page: Option<usize>,
+        #[serde(default)]
+        no_browser: bool,
+        #[serde(default)]
+        no_cache: bool,
+    }
+
+    let args: HnReadArticleArgs =
+        serde_json::from_value(arguments.unwrap_or(serde_json::Value::Null)).map_err(|e| {
+            JsonRpcError {
+                code: -32602,
+                message: format!("Invalid arguments: {e}"),
+                data: None,
+            }
+        })?;
+
+    if global.verbose {
+        eprintln!(
+            "Calling hn_read_article: item={}, limit={:?}, article_page={:?}",
+            args.item, args.limit, args.article_page
+        );
+    }
+
+    let post_data = crate::hn::read_article_data(
         args.item,
         args.limit.unwrap_or(10),
-        args.page.unwrap_or(1),
-        args.thread,
+        args.article_limit.unwrap_or(5000),
+        args.article_page.unwrap_or(1),
+        args.no_browser,
+        !args.no_cache,
     )
     .await
     .map_err(|e| JsonRpcError {
@@ -43,7 +143,6 @@ pub async fn handle_hn_read_item(
         data: None,
     })?;
 
-    // Convert to JSON and wrap in MCP result format
     let json_string = serde_json::to_string_pretty(&post_data).map_err(|e| JsonRpcError {
         code: -32603,
         message: format!("Serialization error: {e}"),
@@ -71,6 +170,17 @@ pub async fn handle_hn_list_items(
         story_type: Option<String>,
         limit: Option<usize>,
         page: Option<usize>,
+        cursor: Option<String>,
+        min_score: Option<u64>,
+        domain: Option<String>,
+        author: Option<String>,
+        keyword: Option<String>,
+        since: Option<String>,
+        until: Option<String>,
+        #[serde(default)]
+        sort: mcptools_core::hn::ListSort,
+        #[serde(default)]
+        no_cache: bool,
     }
 
     let args: HnListItemsArgs =
@@ -89,11 +199,35 @@ pub async fn handle_hn_list_items(
         );
     }
 
+    let now = chrono::Utc::now().timestamp();
+    let bound = |value: Option<String>| {
+        value
+            .map(|v| mcptools_core::hn::parse_time_bound(&v, now))
+            .transpose()
+            .map_err(|e| JsonRpcError {
+                code: -32602,
+                message: format!("Invalid arguments: {e}"),
+                data: None,
+            })
+    };
+    let filters = mcptools_core::hn::ListFilters {
+        min_score: args.min_score,
+        domain: args.domain,
+        author: args.author,
+        keyword: args.keyword,
+        since: bound(args.since)?,
+        until: bound(args.until)?,
+        sort: args.sort,
+    };
+
     // Call the HN module's data function
     let list_data = crate::hn::list_items_data(
         args.story_type.unwrap_or("top".to_string()),
         args.limit.unwrap_or(30),
         args.page.unwrap_or(1),
+        &filters,
+        args.cursor.as_deref(),
+        !args.no_cache,
     )
     .await
     .map_err(|e| JsonRpcError {
@@ -120,3 +254,121 @@ pub async fn handle_hn_list_items(
         data: None,
     })
 }
+
+pub async fn handle_hn_search(
+    arguments: Option<serde_json::Value>,
+    global: &crate::Global,
+) -> Result<serde_json::Value, JsonRpcError> {
+    #[derive(Deserialize)]
+    struct HnSearchArgs {
+        query: String,
+        tags: Option<String>,
+        since: Option<String>,
+        until: Option<String>,
+        #[serde(default)]
+        by_date: bool,
+        limit: Option<usize>,
+        page: Option<usize>,
+    }
+
+    let args: HnSearchArgs = serde_json::from_value(arguments.unwrap_or(serde_json::Value::Null))
+        .map_err(|e| JsonRpcError {
+        code: -32602,
+        message: format!("Invalid arguments: {e}"),
+        data: None,
+    })?;
+
+    if global.verbose {
+        eprintln!(
+            "Calling hn_search: query={}, tags={:?}, page={:?}",
+            args.query, args.tags, args.page
+        );
+    }
+
+    let options = crate::hn::search::SearchOptions {
+        query: args.query,
+        tags: args.tags.unwrap_or_else(|| "story".to_string()),
+        since: args.since,
+        until: args.until,
+        by_date: args.by_date,
+        limit: args.limit.unwrap_or(20),
+        page: args.page.unwrap_or(1),
+        json: true,
+    };
+    let search_data = crate::hn::search_data(&options)
+        .await
+        .map_err(|e| JsonRpcError {
+            code: -32603,
+            message: format!("Tool execution error: {e}"),
+            data: None,
+        })?;
+
+    let json_string = serde_json::to_string_pretty(&search_data).map_err(|e| JsonRpcError {
+        code: -32603,
+        message: format!("Serialization error: {e}"),
+        data: None,
+    })?;
+
+    let result = CallToolResult {
+        content: vec![Content::Text { text: json_string }],
+        is_error: None,
+    };
+
+    serde_json::to_value(result).map_err(|e| JsonRpcError {
+        code: -32603,
+        message: format!("Internal error: {e}"),
+        data: None,
+    })
+}
+
+pub async fn handle_hn_user(
+    arguments: Option<serde_json::Value>,
+    global: &crate::Global,
+) -> Result<serde_json::Value, JsonRpcError> {
+    #[derive(Deserialize)]
+    struct HnUserArgs {
+        username: String,
+        limit: Option<usize>,
+        #[serde(default)]
+        no_cache: bool,
+    }
+
+    let args: HnUserArgs = serde_json::from_value(arguments.unwrap_or(serde_json::Value::Null))
+        .map_err(|e| JsonRpcError {
+            code: -32602,
+            message: format!("Invalid arguments: {e}"),
+            data: None,
+        })?;
+
+    if global.verbose {
+        eprintln!(
+            "Calling hn_user: username={}, limit={:?}",
+            args.username, args.limit
+        );
+    }
+
+    let user_data = crate::hn::user_data(args.username, args.limit.unwrap_or(10), !args.no_cache)
+        .await
+        .map_err(|e| JsonRpcError {
+            code: -32603,
+            message: format!("Tool execution error: {e}"),
+            data: None,
+        })?;
+
+    let json_string = serde_json::to_string_pretty(&user_data).map_err(|e| JsonRpcError {
+        code: -32603,
+        message: format!("Serialization error: {e}"),
+        data: None,
+    })?;
+
+    let result = CallToolResult {
+        content: vec![Content::Text { text: json_string }],
+        is_error: None,
+    };
+
+    serde_json::to_value(result).map_err(|e| JsonRpcError {
+        code: -32603,
+        message: format!("Internal error: {e}"),
+        data: None,
+    })
+}
@@ -0,0 +1,91 @@
+use serde::Deserialize;
+
+use super::{CallToolResult, Content, JsonRpcError};
+use crate::html::Format;
+use mcptools_core::html::{format_matches, select_html};
+
+fn invalid(message: String) -> JsonRpcError {
+    JsonRpcError {
+        code: -32602,
+        message,
+        data: None,
+    }
+}
+
+pub async fn handle_html_select(
+    arguments: Option<serde_json::Value>,
+    global: &crate::Global,
+) -> Result<serde_json::Value, JsonRpcError> {
+    #[derive(Deserialize)]
+    struct HtmlSelectArgs {
+        html: Option<String>,
+        path: Option<String>,
+        url: Option<String>,
+        css: Option<String>,
+        xpath: Option<String>,
+        attribute: Option<String>,
+        format: Option<Format>,
+        limit: Option<usize>,
+        timeout: Option<u64>,
+        wait_for: Option<String>,
+    }
+
+    let args: HtmlSelectArgs = serde_json::from_value(arguments.unwrap_or(serde_json::Value::Null))
+        .map_err(|e| invalid(format!("Invalid arguments: {e}")))?;
+
+    let (css, attribute) =
+        crate::html::resolve_selector(args.css, args.xpath.as_deref(), args.attribute)
+            .map_err(invalid)?;
+    select_html("", &css, None, 0).map_err(invalid)?;
+
+    let html = match (args.html, args.path, args.url) {
+        (Some(html), None, None) => html,
+        (None, Some(path), None) => {
+            tokio::fs::read_to_string(&path)
+                .await
+                .map_err(|e| JsonRpcError {
+                    code: -32603,
+                    message: format!("Failed to read {path}: {e}"),
+                    data: None,
+                })?
+        }
+        (None, None, Some(url)) => {
+            if !url.starts_with("http://") && !url.starts_with("https://") {
+                return Err(invalid(format!("'url' must be http(s): {url}")));
+            }
+            if global.verbose {
+                anstream::eprintln!("Calling html_select: fetching {}", url);
+            }
+            crate::html::load_html(&url, args.timeout.unwrap_or(30), args.wait_for)
+                .await
+                .map_err(|e| JsonRpcError {
+                    code: -32603,
+                    message: format!("Failed to fetch {url}: {e}"),
+                    data: None,
+                })?
+        }
+        _ => {
+            return Err(invalid(
+                "Provide exactly one of 'html', 'path', or 'url'".into(),
+            ))
+        }
+    };
+
+    if global.verbose {
+        anstream::eprintln!("Calling html_select: css='{}', {} bytes", css, html.len());
+    }
+
+    let matches = select_html(&html, &css, attribute.as_deref(), args.limit.unwrap_or(100))
+        .map_err(invalid)?;
+    let text = format_matches(&matches, args.format.unwrap_or(Format::Text).into());
+
+    serde_json::to_value(CallToolResult {
+        content: vec![Content::Text { text }],
+        is_error: None,
+    })
+    .map_err(|e| JsonRpcError {
+        code: -32603,
+        message: format!("Internal error: {e}"),
+        data: None,
+    })
+}
@@ -0,0 +1,228 @@
+use serde::Deserialize;
+
+use super::{CallToolResult, Content, JsonRpcError, Tool};
+use crate::mail::{default_mailbox, MailConfig};
+use mcptools_core::mail::imap::{build_search_criteria, quote, SearchQuery};
+use mcptools_core::mail::message_to_markdown;
+
+/// Mail tools expose a personal inbox, so they are only listed and callable
+/// when MAIL_MCP_ENABLED is set to a truthy value.
+pub fn mail_tools_enabled() -> bool {
+    std::env::var("MAIL_MCP_ENABLED")
+        .map(|v| matches!(v.to_lowercase().as_str(), "1" | "true" | "yes" | "on"))
+        .unwrap_or(false)
+}
+
+pub fn mail_tools() -> Vec<Tool> {
+    vec![
+        Tool {
+            name: "mail_search".to_string(),
+            description: "Search an IMAP mailbox (read-only). Returns the newest matching messages with UID, date, sender, subject, and read state. Use mail_read with a UID to read a message.".to_string(),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "from": { "type": "string", "description": "Match the From header" },
+                    "to": { "type": "string", "description": "Match the To header" },
+                    "subject": { "type": "string", "description": "Match the Subject header" },
+                    "text": { "type": "string", "description": "Full-text search over headers and body" },
+                    "since": { "type": "string", "description": "Messages on or after this date (YYYY-MM-DD)" },
+                    "before": { "type": "string", "description": "Messages before this date (YYYY-MM-DD)" },
+                    "unseen": { "type": "boolean", "description": "Only unread messages (default: false)" },
+                    "mailbox": { "type": "string", "description": "Mailbox to search (default: MAIL_MAILBOX or INBOX)" },
+                    "limit": { "type": "integer", "description": "Maximum number of messages to return (default: 20)" }
+                }
+            }),
+        },
+        Tool {
+            name: "mail_read".to_string(),
+            description: "Read an email message by UID as Markdown (headers, body, and an attachment listing). The message is not marked as read.".to_string(),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "uid": { "type": "integer", "description": "Message UID from mail_search" },
+                    "mailbox": { "type": "string", "description": "Mailbox containing the message (default: MAIL_MAILBOX or INBOX)" },
+                    "format": { "type": "string", "enum": ["markdown", "json"], "description": "Output format (default: markdown)" }
+                },
+                "required": ["uid"]
+            }),
+        },
+    ]
+}
+
+fn disabled_error() -> JsonRpcError {
+    JsonRpcError {
+        code: -32602,
+        message: "Mail tools are disabled. Set MAIL_MCP_ENABLED=true to enable them.".to_string(),
+        data: None,
+    }
+}
+
+fn invalid(message: String) -> JsonRpcError {
+    JsonRpcError {
+        code: -32602,
+        message,
+        data: None,
+    }
+}
+
+fn execution_error(e: impl std::fmt::Display) -> JsonRpcError {
+    JsonRpcError {
+        code: -32603,
+        message: format!("Tool execution error: {e}"),
+        data: None,
+    }
+}
+
+fn text_result(text: String) -> Result<serde_json::Value, JsonRpcError> {
+    serde_json::to_value(CallToolResult {
+        content: vec![Content::Text { text }],
+        is_error: None,
+    })
+    .map_err(|e| JsonRpcError {
+        code: -32603,
+        message: format!("Internal error: {e}"),
+        data: None,
+    })
+}
+
+fn parse_date(
+    value: Option<String>,
+    name: &str,
+) -> Result<Option<chrono::NaiveDate>, JsonRpcError> {
+    value
+        .map(|v| {
+            chrono::NaiveDate::parse_from_str(&v, "%Y-%m-%d")
+                .map_err(|_| invalid(format!("Invalid {name} date '{v}' (expected YYYY-MM-DD)")))
+        })
+        .transpose()
+}
+
+/// Reject mailbox names that cannot be sent as an IMAP string.
+fn check_mailbox(mailbox: &str) -> Result<(), JsonRpcError> {
+    quote(mailbox).map(|_| ()).map_err(|e| {
+        invalid(format!(
+            "Invalid mailbox '{}': {e}",
+            mailbox.escape_default()
+        ))
+    })
+}
+
+pub async fn handle_mail_search(
+    arguments: Option<serde_json::Value>,
+    global: &crate::Global,
+) -> Result<serde_json::Value, JsonRpcError> {
+    if !mail_tools_enabled() {
+        return Err(disabled_error());
+    }
+
+    #[derive(Deserialize)]
+    #[serde(rename_all = "camelCase")]
+    struct MailSearchArgs {
+        from: Option<String>,
+        to: Option<String>,
+        subject: Option<String>,
+        text: Option<String>,
+        since: Option<String>,
+        before: Option<String>,
+        #[serde(default)]
+        unseen: bool,
+        mailbox: Option<String>,
+        limit: Option<usize>,
+    }
+
+    let args: MailSearchArgs = serde_json::from_value(arguments.unwrap_or(serde_json::json!({})))
+        .map_err(|e| invalid(format!("Invalid arguments: {e}")))?;
+
+    let query = SearchQuery {
+        from: args.from,
+        to: args.to,
+        subject: args.subject,
+        text: args.text,
+        since: parse_date(args.since, "since")?,
+        before: parse_date(args.before, "before")?,
+        unseen: args.unseen,
+    };
+    build_search_criteria(&query).map_err(|e| invalid(format!("Invalid search: {e}")))?;
+    let mailbox = args.mailbox.unwrap_or_else(default_mailbox);
+    check_mailbox(&mailbox)?;
+
+    if global.verbose {
+        anstream::eprintln!(
+            "Calling mail_search: mailbox='{}', query={:?}",
+            mailbox,
+            query
+        );
+    }
+
+    let config = MailConfig::from_env().map_err(execution_error)?;
+    let output = crate::mail::search_messages_data(
+        config,
+        query,
+        mailbox,
+        args.limit.unwrap_or(20),
+        global.verbose,
+    )
+    .await
+    .map_err(execution_error)?;
+
+    text_result(
+        serde_json::to_string_pretty(&output).map_err(|e| JsonRpcError {
+            code: -32603,
+            message: format!("Serialization error: {e}"),
+            data: None,
+        })?,
+    )
+}
+
+pub async fn handle_mail_read(
+    arguments: Option<serde_json::Value>,
+    global: &crate::Global,
+) -> Result<serde_json::Value, JsonRpcError> {
+    if !mail_tools_enabled() {
+        return Err(disabled_error());
+    }
+
+    #[derive(Deserialize)]
+    #[serde(rename_all = "camelCase")]
+    struct MailReadArgs {
+        uid: u32,
+        mailbox: Option<String>,
+        format: Option<String>,
+    }
+
+    let args: MailReadArgs = serde_json::from_value(arguments.unwrap_or(serde_json::Value::Null))
+        .map_err(|e| invalid(format!("Invalid arguments: {e}")))?;
+
+    let json = match args.format.as_deref() {
+        None | Some("markdown") => false,
+        Some("json") => true,
+        Some(other) => {
+            return Err(invalid(format!(
+                "Invalid format '{other}' (expected 'markdown' or 'json')"
+            )))
+        }
+    };
+    let mailbox = args.mailbox.unwrap_or_else(default_mailbox);
+    check_mailbox(&mailbox)?;
+
+    if global.verbose {
+        anstream::eprintln!("Calling mail_read: uid={}, mailbox='{}'", args.uid, mailbox);
+    }
+
+    let config = MailConfig::from_env().map_err(execution_error)?;
+    let message = crate::mail::read_message_data(config, args.uid, mailbox, global.verbose)
+        .await
+        .map_err(execution_error)?;
+
+    let text = if json {
+        serde_json::to_string_pretty(&message).map_err(|e| JsonRpcError {
+            code: -32603,
+            message: format!("Serialization error: {e}"),
+            data: None,
+        })?
+    } else {
+        message_to_markdown(&message)
+    };
+
+    text_result(text)
+}
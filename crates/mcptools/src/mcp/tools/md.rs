@@ -1,5 +1,44 @@
 use super::{CallToolResult, Content, JsonRpcError};
 use serde::Deserialize;
+use std::collections::BTreeMap;
+
+/// Convert the `headers` object and `cookies` string tool arguments into the
+/// `--header`/`--cookie` string form, validating them up front.
+fn request_context_args(
+    headers: Option<BTreeMap<String, String>>,
+    cookies: Option<String>,
+) -> Result<(Vec<String>, Vec<String>), JsonRpcError> {
+    let headers: Vec<String> = headers
+        .unwrap_or_default()
+        .into_iter()
+        .map(|(name, value)| format!("{name}: {value}"))
+        .collect();
+    let cookies: Vec<String> = cookies.into_iter().collect();
+    crate::md::parse_request_context(&headers, &cookies).map_err(|e| JsonRpcError {
+        code: -32602,
+        message: format!("Invalid arguments: {e}"),
+        data: None,
+    })?;
+    Ok((headers, cookies))
+}
+
+/// Browser proxy and User-Agent, falling back to MD_PROXY and MD_USER_AGENT
+/// so a server started behind a corporate proxy applies it to every call.
+fn browser_args(
+    proxy: Option<String>,
+    user_agent: Option<String>,
+) -> Result<(Option<String>, Option<String>), JsonRpcError> {
+    let proxy = proxy.or_else(|| std::env::var("MD_PROXY").ok());
+    if let Some(proxy) = &proxy {
+        mcptools_core::md::parse_proxy_arg(proxy).map_err(|e| JsonRpcError {
+            code: -32602,
+            message: format!("Invalid arguments: {e}"),
+            data: None,
+        })?;
+    }
+    let user_agent = user_agent.or_else(|| std::env::var("MD_USER_AGENT").ok());
+    Ok((proxy, user_agent))
+}
 
 pub async fn handle_md_fetch(
     arguments: Option<serde_json::Value>,
@@ -19,11 +58,43 @@ pub async fn handle_md_fetch(
         #[serde(default)]
         index: Option<usize>,
         #[serde(default)]
+        wait_for: Option<String>,
+        #[serde(default)]
+        headers: Option<BTreeMap<String, String>>,
+        #[serde(default)]
+        cookies: Option<String>,
+        #[serde(default)]
+        proxy: Option<String>,
+        #[serde(default)]
+        user_agent: Option<String>,
+        #[serde(default)]
         offset: Option<usize>,
         #[serde(default)]
         limit: Option<usize>,
         #[serde(default)]
         page: Option<usize>,
+        #[serde(default)]
+        metadata: bool,
+        #[serde(default)]
+        scroll: Option<usize>,
+        #[serde(default)]
+        scroll_until_idle: bool,
+        #[serde(default)]
+        idle_ms: Option<u64>,
+        #[serde(default)]
+        idle_timeout: Option<u64>,
+        #[serde(default)]
+        cache_ttl: Option<u64>,
+        #[serde(default)]
+        no_cache: bool,
+        #[serde(default)]
+        retries: Option<u32>,
+        #[serde(default)]
+        no_browser: bool,
+        #[serde(default)]
+        heading: Option<String>,
+        #[serde(flatten)]
+        conversion: crate::md::ConversionArgs,
     }
 
     let args: MdFetchArgs = serde_json::from_value(arguments.unwrap_or(serde_json::Value::Null))
@@ -42,6 +113,33 @@ pub async fn handle_md_fetch(
         });
     }
 
+    if args.heading.is_some() && args.raw_html == Some(true) {
+        return Err(JsonRpcError {
+            code: -32602,
+            message: "'heading' selects a Markdown section and cannot be combined with 'raw_html'"
+                .to_string(),
+            data: None,
+        });
+    }
+
+    // The server's stdin may be the MCP transport itself
+    if args.url.trim() == "-" {
+        return Err(JsonRpcError {
+            code: -32602,
+            message: "'url' cannot be '-' (stdin); use a file:// URL for saved HTML".to_string(),
+            data: None,
+        });
+    }
+
+    let (headers, cookies) = request_context_args(args.headers, args.cookies)?;
+    let (headers, cookies) =
+        crate::md::parse_request_context(&headers, &cookies).map_err(|e| JsonRpcError {
+            code: -32602,
+            message: format!("Invalid arguments: {e}"),
+            data: None,
+        })?;
+    let (proxy, user_agent) = browser_args(args.proxy, args.user_agent)?;
+
     // Use spawn_blocking since fetch_and_convert_data is synchronous
     let fetch_data = tokio::task::spawn_blocking(move || {
         crate::md::fetch_and_convert_data(crate::md::FetchConfig {
@@ -51,10 +149,33 @@ pub async fn handle_md_fetch(
             selector: args.selector,
             strategy: args.strategy.unwrap_or(crate::md::SelectionStrategy::First),
             index: args.index,
+            wait_for: args.wait_for,
+            headers,
+            cookies,
+            proxy,
+            user_agent,
             offset: args.offset.unwrap_or(0),
             limit: args.limit.unwrap_or(1000),
             page: args.page.unwrap_or(1),
             paginated: true, // MCP always uses pagination for context safety
+            metadata: args.metadata,
+            scroll: mcptools_core::md::ScrollPlan::from_options(
+                args.scroll,
+                args.scroll_until_idle,
+            ),
+            cache_ttl: (!args.no_cache).then_some(args.cache_ttl.unwrap_or(3600)),
+            retry: mcptools_core::md::RetryPolicy {
+                max_retries: args.retries.unwrap_or(2),
+                ..Default::default()
+            },
+            no_browser: args.no_browser,
+            heading: args.heading,
+            conversion: (&args.conversion).into(),
+            network_idle: mcptools_core::md::NetworkIdlePolicy {
+                idle_ms: args.idle_ms.unwrap_or(500),
+                max_wait_ms: args.idle_timeout.unwrap_or(10).saturating_mul(1000),
+            },
+            ..Default::default()
         })
     })
     .await
@@ -104,6 +225,14 @@ pub async fn handle_md_toc(
         index: Option<usize>,
         #[serde(default)]
         output: Option<String>,
+        #[serde(default)]
+        headers: Option<BTreeMap<String, String>>,
+        #[serde(default)]
+        cookies: Option<String>,
+        #[serde(default)]
+        proxy: Option<String>,
+        #[serde(default)]
+        user_agent: Option<String>,
     }
 
     let args: MdTocArgs = serde_json::from_value(arguments.unwrap_or(serde_json::Value::Null))
@@ -138,6 +267,9 @@ pub async fn handle_md_toc(
         }
     };
 
+    let (headers, cookies) = request_context_args(args.headers, args.cookies)?;
+    let (proxy, user_agent) = browser_args(args.proxy, args.user_agent)?;
+
     // Create TocOptions
     let toc_options = crate::md::TocOptions {
         url: args.url,
@@ -145,6 +277,13 @@ pub async fn handle_md_toc(
         selector: args.selector,
         strategy: args.strategy.unwrap_or(crate::md::SelectionStrategy::First),
         index: args.index,
+        network: crate::md::NetworkArgs {
+            headers,
+            cookies,
+            proxy,
+            user_agent,
+        },
+        browser: Default::default(),
         output: output_format,
         json: false,
     };
@@ -181,3 +320,382 @@ pub async fn handle_md_toc(
         data: None,
     })
 }
+
+pub async fn handle_md_grep(
+    arguments: Option<serde_json::Value>,
+    _global: &crate::Global,
+) -> Result<serde_json::Value, JsonRpcError> {
+    #[derive(Deserialize)]
+    struct MdGrepArgs {
+        url: String,
+        pattern: String,
+        #[serde(default)]
+        flags: Option<String>,
+        #[serde(default)]
+        context: Option<usize>,
+        #[serde(default)]
+        max_matches: Option<usize>,
+        #[serde(default)]
+        selector: Option<String>,
+        #[serde(default)]
+        strategy: Option<crate::md::SelectionStrategy>,
+        #[serde(default)]
+        index: Option<usize>,
+        #[serde(default)]
+        timeout: Option<u64>,
+        #[serde(default)]
+        wait_for: Option<String>,
+        #[serde(default)]
+        no_cache: bool,
+        #[serde(default)]
+        no_browser: bool,
+        #[serde(default)]
+        headers: Option<BTreeMap<String, String>>,
+        #[serde(default)]
+        cookies: Option<String>,
+        #[serde(default)]
+        proxy: Option<String>,
+        #[serde(default)]
+        user_agent: Option<String>,
+    }
+
+    let invalid = |message: String| JsonRpcError {
+        code: -32602,
+        message,
+        data: None,
+    };
+    let args: MdGrepArgs = serde_json::from_value(arguments.unwrap_or(serde_json::Value::Null))
+        .map_err(|e| invalid(format!("Invalid arguments: {e}")))?;
+
+    if matches!(args.strategy, Some(crate::md::SelectionStrategy::N)) && args.index.is_none() {
+        return Err(invalid(
+            "Strategy 'n' requires 'index' parameter".to_string(),
+        ));
+    }
+    let flags = args.flags.unwrap_or_default();
+    let regex_flags: mcptools_core::extract::RegexFlags = flags.parse().map_err(invalid)?;
+    mcptools_core::extract::build_regex(&args.pattern, regex_flags).map_err(invalid)?;
+
+    let (headers, cookies) = request_context_args(args.headers, args.cookies)?;
+    let (proxy, user_agent) = browser_args(args.proxy, args.user_agent)?;
+
+    let options = crate::md::GrepOptions {
+        url: args.url,
+        pattern: args.pattern,
+        flags,
+        context: args.context.unwrap_or(80),
+        max_matches: args.max_matches.unwrap_or(100),
+        selector: args.selector,
+        strategy: args.strategy.unwrap_or(crate::md::SelectionStrategy::First),
+        index: args.index,
+        json: true,
+        timeout: args.timeout.unwrap_or(30),
+        cache_ttl: 3600,
+        no_cache: args.no_cache,
+        network: crate::md::NetworkArgs {
+            headers,
+            cookies,
+            proxy,
+            user_agent,
+        },
+        browser: crate::md::BrowserArgs {
+            wait_for: args.wait_for,
+            no_browser: args.no_browser,
+        },
+    };
+
+    let output = tokio::task::spawn_blocking(move || crate::md::grep::grep_page_data(options))
+        .await
+        .map_err(|e| JsonRpcError {
+            code: -32603,
+            message: format!("Task join error: {e}"),
+            data: None,
+        })?
+        .map_err(|e| JsonRpcError {
+            code: -32603,
+            message: format!("Tool execution error: {e}"),
+            data: None,
+        })?;
+
+    let text = serde_json::to_string_pretty(&output).map_err(|e| JsonRpcError {
+        code: -32603,
+        message: format!("Serialization error: {e}"),
+        data: None,
+    })?;
+
+    serde_json::to_value(CallToolResult {
+        content: vec![Content::Text { text }],
+        is_error: None,
+    })
+    .map_err(|e| JsonRpcError {
+        code: -32603,
+        message: format!("Internal error: {e}"),
+        data: None,
+    })
+}
+
+pub async fn handle_md_screenshot(
+    arguments: Option<serde_json::Value>,
+    _global: &crate::Global,
+) -> Result<serde_json::Value, JsonRpcError> {
+    use base64::Engine;
+    use mcptools_core::md::screenshot_format_for_path;
+
+    #[derive(Deserialize)]
+    struct MdScreenshotArgs {
+        url: String,
+        #[serde(default)]
+        full_page: Option<bool>,
+        #[serde(default)]
+        selector: Option<String>,
+        #[serde(default)]
+        output_path: Option<String>,
+        #[serde(default)]
+        timeout: Option<u64>,
+    }
+
+    let args: MdScreenshotArgs =
+        serde_json::from_value(arguments.unwrap_or(serde_json::Value::Null)).map_err(|e| {
+            JsonRpcError {
+                code: -32602,
+                message: format!("Invalid arguments: {e}"),
+                data: None,
+            }
+        })?;
+
+    let full_page = args.full_page.unwrap_or(false);
+    if full_page && args.selector.is_some() {
+        return Err(JsonRpcError {
+            code: -32602,
+            message: "'full_page' cannot be combined with 'selector'".to_string(),
+            data: None,
+        });
+    }
+
+    let config = crate::md::ScreenshotConfig {
+        url: args.url,
+        full_page,
+        selector: args.selector,
+        format: args
+            .output_path
+            .as_deref()
+            .map(screenshot_format_for_path)
+            .unwrap_or_default(),
+        timeout: args.timeout.unwrap_or(30),
+    };
+
+    // Use spawn_blocking since capture_screenshot_data is synchronous
+    let (mut output, bytes) =
+        tokio::task::spawn_blocking(move || crate::md::capture_screenshot_data(config))
+            .await
+            .map_err(|e| JsonRpcError {
+                code: -32603,
+                message: format!("Task join error: {e}"),
+                data: None,
+            })?
+            .map_err(|e| JsonRpcError {
+                code: -32603,
+                message: format!("Tool execution error: {e}"),
+                data: None,
+            })?;
+
+    let mut content = Vec::new();
+    match args.output_path {
+        Some(path) => {
+            std::fs::write(&path, &bytes).map_err(|e| JsonRpcError {
+                code: -32603,
+                message: format!("Failed to write screenshot to {path}: {e}"),
+                data: None,
+            })?;
+            output.path = Some(path);
+        }
+        None => content.push(Content::Image {
+            data: base64::engine::general_purpose::STANDARD.encode(&bytes),
+            mime_type: output.format.mime_type().to_string(),
+        }),
+    }
+
+    let json_string = serde_json::to_string_pretty(&output).map_err(|e| JsonRpcError {
+        code: -32603,
+        message: format!("Serialization error: {e}"),
+        data: None,
+    })?;
+    content.insert(0, Content::Text { text: json_string });
+
+    serde_json::to_value(CallToolResult {
+        content,
+        is_error: None,
+    })
+    .map_err(|e| JsonRpcError {
+        code: -32603,
+        message: format!("Internal error: {e}"),
+        data: None,
+    })
+}
+
+pub async fn handle_youtube_transcript(
+    arguments: Option<serde_json::Value>,
+    _global: &crate::Global,
+) -> Result<serde_json::Value, JsonRpcError> {
+    #[derive(Deserialize)]
+    struct YoutubeTranscriptArgs {
+        url: String,
+        #[serde(default)]
+        lang: Option<String>,
+        #[serde(default)]
+        timeout: Option<u64>,
+        #[serde(default)]
+        offset: Option<usize>,
+        #[serde(default)]
+        limit: Option<usize>,
+        #[serde(default)]
+        page: Option<usize>,
+    }
+
+    let args: YoutubeTranscriptArgs =
+        serde_json::from_value(arguments.unwrap_or(serde_json::Value::Null)).map_err(|e| {
+            JsonRpcError {
+                code: -32602,
+                message: format!("Invalid arguments: {e}"),
+                data: None,
+            }
+        })?;
+
+    let transcript = crate::md::fetch_transcript_data(crate::md::YoutubeConfig {
+        url: args.url,
+        lang: args.lang,
+        timeout: args.timeout.unwrap_or(30),
+        offset: args.offset.unwrap_or(0),
+        limit: args.limit.unwrap_or(1000),
+        page: args.page.unwrap_or(1),
+        paginated: true, // MCP always uses pagination for context safety
+    })
+    .await
+    .map_err(|e| JsonRpcError {
+        code: -32603,
+        message: format!("Tool execution error: {e}"),
+        data: None,
+    })?;
+
+    let json_string = serde_json::to_string_pretty(&transcript).map_err(|e| JsonRpcError {
+        code: -32603,
+        message: format!("Serialization error: {e}"),
+        data: None,
+    })?;
+
+    serde_json::to_value(CallToolResult {
+        content: vec![Content::Text { text: json_string }],
+        is_error: None,
+    })
+    .map_err(|e| JsonRpcError {
+        code: -32603,
+        message: format!("Internal error: {e}"),
+        data: None,
+    })
+}
+
+pub async fn handle_md_normalize(
+    arguments: Option<serde_json::Value>,
+    global: &crate::Global,
+) -> Result<serde_json::Value, JsonRpcError> {
+    use mcptools_core::normalize::{normalize_markdown, NormalizeOptions};
+
+    #[derive(Deserialize)]
+    struct MdNormalizeArgs {
+        #[serde(default)]
+        markdown: Option<String>,
+        #[serde(default)]
+        path: Option<String>,
+        #[serde(default)]
+        width: Option<usize>,
+        #[serde(default)]
+        keep_line_breaks: bool,
+        #[serde(default)]
+        bullet: Option<char>,
+        #[serde(default)]
+        links: Option<crate::md::normalize::Links>,
+        #[serde(default)]
+        write: bool,
+    }
+
+    let invalid = |message: String| JsonRpcError {
+        code: -32602,
+        message,
+        data: None,
+    };
+    let execution_error = |message: String| JsonRpcError {
+        code: -32603,
+        message,
+        data: None,
+    };
+
+    let args: MdNormalizeArgs =
+        serde_json::from_value(arguments.unwrap_or(serde_json::Value::Null))
+            .map_err(|e| invalid(format!("Invalid arguments: {e}")))?;
+
+    let bullet = args.bullet.unwrap_or('-');
+    if !matches!(bullet, '-' | '*' | '+') {
+        return Err(invalid(format!(
+            "Invalid bullet '{bullet}'; use '-', '*', or '+'"
+        )));
+    }
+    if args.write && args.path.is_none() {
+        return Err(invalid("'write' requires 'path'".to_string()));
+    }
+
+    let text = match (args.markdown, &args.path) {
+        (Some(markdown), None) => markdown,
+        (None, Some(path)) => tokio::fs::read_to_string(path)
+            .await
+            .map_err(|e| execution_error(format!("Failed to read {path}: {e}")))?,
+        (Some(_), Some(_)) => {
+            return Err(invalid(
+                "Provide either 'markdown' or 'path', not both".to_string(),
+            ))
+        }
+        (None, None) => return Err(invalid("Missing 'markdown' or 'path'".to_string())),
+    };
+
+    if global.verbose {
+        anstream::eprintln!(
+            "Calling md_normalize: {} bytes, path={:?}, write={}",
+            text.len(),
+            args.path,
+            args.write
+        );
+    }
+
+    let options = NormalizeOptions {
+        width: (!args.keep_line_breaks).then_some(args.width.unwrap_or(0)),
+        bullet,
+        links: args
+            .links
+            .unwrap_or(crate::md::normalize::Links::Inline)
+            .into(),
+    };
+    let normalized = normalize_markdown(&text, &options);
+
+    let text = match args.path.filter(|_| args.write) {
+        Some(path) => {
+            let changed = normalized != text;
+            if changed {
+                tokio::fs::write(&path, &normalized)
+                    .await
+                    .map_err(|e| execution_error(format!("Failed to write {path}: {e}")))?;
+            }
+            serde_json::to_string_pretty(&serde_json::json!({
+                "path": path,
+                "written": true,
+                "changed": changed,
+            }))
+            .map_err(|e| execution_error(format!("Serialization error: {e}")))?
+        }
+        None => normalized,
+    };
+
+    serde_json::to_value(CallToolResult {
+        content: vec![Content::Text { text }],
+        is_error: None,
+    })
+    .map_err(|e| execution_error(format!("Internal error: {e}")))
+}
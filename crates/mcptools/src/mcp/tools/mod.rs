@@ -1,11 +1,20 @@
 mod annotations;
 mod atlas;
 mod atlassian;
+mod cal;
+mod generate;
 mod greprag;
 mod hn;
+mod html;
+mod mail;
 mod md;
 mod pdf;
+mod search;
 mod strand;
+mod template;
+mod text;
+mod transcribe;
+mod vision;
 
 use serde::{Deserialize, Serialize};
 
@@ -63,6 +72,12 @@ pub struct CallToolResult {
 pub enum Content {
     #[serde(rename = "text")]
     Text { text: String },
+    #[serde(rename = "image")]
+    Image {
+        data: String,
+        #[serde(rename = "mimeType")]
+        mime_type: String,
+    },
 }
 
 pub fn handle_initialize() -> Result<serde_json::Value, JsonRpcError> {
@@ -86,7 +101,7 @@ pub fn handle_initialize() -> Result<serde_json::Value, JsonRpcError> {
 }
 
 pub fn handle_tools_list() -> Result<serde_json::Value, JsonRpcError> {
-    let tools = vec![
+    let mut tools = vec![
         Tool {
             name: "jira_search".to_string(),
             description: "Search Jira issues using JQL (Jira Query Language) or a saved query. Returns a list of issues matching the query with details like key, summary, status, and assignee. Supports token-based pagination using nextPageToken. Requires JIRA_BASE_URL, JIRA_EMAIL, and JIRA_API_TOKEN environment variables (or ATLASSIAN_* as fallback).".to_string(),
@@ -108,6 +123,15 @@ pub fn handle_tools_list() -> Result<serde_json::Value, JsonRpcError> {
                     "nextPageToken": {
                         "type": "string",
                         "description": "Pagination token for fetching the next page. Use the nextPageToken from the previous response to get additional results. Tokens expire after 7 days."
+                    },
+                    "sprint": {
+                        "type": "string",
+                        "description": "Only return issues in this sprint: 'active', 'future', 'closed', a sprint ID, or a sprint name"
+                    },
+                    "vars": {
+                        "type": "object",
+                        "additionalProperties": { "type": "string" },
+                        "description": "Values for {name} placeholders in the query or saved query, e.g. {\"project\": \"PROJ\"}"
                     }
                 },
                 "required": []
@@ -133,7 +157,7 @@ pub fn handle_tools_list() -> Result<serde_json::Value, JsonRpcError> {
         },
         Tool {
             name: "hn_read_item".to_string(),
-            description: "Read a HackerNews post and its comments. Accepts HackerNews item ID (e.g., '8863') or full URL (e.g., 'https://news.ycombinator.com/item?id=8863'). Returns post details with paginated comments.".to_string(),
+            description: "Read a HackerNews post and its comments. Accepts HackerNews item ID (e.g., '8863') or full URL (e.g., 'https://news.ycombinator.com/item?id=8863'). Returns post details with paginated comments; pass `pagination.next_cursor` back as `cursor` to get the next comments without repeats even if their order shifted. Polls also include their options with vote counts in `poll_options`. With `thread`, returns that comment's replies instead, limited by `depth` and paginated with `thread_limit`/`thread_page` so large sub-threads can be read incrementally.".to_string(),
             input_schema: serde_json::json!({
                 "type": "object",
                 "properties": {
@@ -149,9 +173,67 @@ pub fn handle_tools_list() -> Result<serde_json::Value, JsonRpcError> {
                         "type": "number",
                         "description": "Page number, 1-indexed (default: 1)"
                     },
+                    "cursor": {
+                        "type": "string",
+                        "description": "Continuation token from a previous response's pagination.next_cursor; returns the next top-level comments not yet returned and overrides page"
+                    },
                     "thread": {
                         "type": "string",
-                        "description": "Comment thread ID to read (optional)"
+                        "description": "Comment thread ID to read (optional); returns the thread root and its replies, each with its depth, instead of the post"
+                    },
+                    "depth": {
+                        "type": "number",
+                        "description": "With thread: levels of replies to include below the root, 1 = direct replies only (default: all)"
+                    },
+                    "thread_limit": {
+                        "type": "number",
+                        "description": "With thread: direct replies per page, each with its sub-thread (default: all)"
+                    },
+                    "thread_page": {
+                        "type": "number",
+                        "description": "With thread: page of direct replies, 1-indexed (default: 1)"
+                    },
+                    "showDead": {
+                        "type": "boolean",
+                        "description": "Include dead and deleted comments as placeholders instead of hiding them (default: false)"
+                    },
+                    "no_cache": {
+                        "type": "boolean",
+                        "description": "Always fetch items and don't store them in the item cache (default: false)"
+                    }
+                },
+                "required": ["item"]
+            }),
+        },
+        Tool {
+            name: "hn_read_article".to_string(),
+            description: "Read a HackerNews story together with its linked article in one call. Returns the post details, its top comments, and the article converted to Markdown in `article` (paginated by characters). If the article cannot be fetched, `article.error` says why and the post is still returned.".to_string(),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "item": {
+                        "type": "string",
+                        "description": "HackerNews item ID or URL"
+                    },
+                    "limit": {
+                        "type": "number",
+                        "description": "Number of top comments to include (default: 10)"
+                    },
+                    "article_limit": {
+                        "type": "number",
+                        "description": "Characters of the article per page (default: 5000)"
+                    },
+                    "article_page": {
+                        "type": "number",
+                        "description": "Page of the article to return, 1-indexed (default: 1)"
+                    },
+                    "no_browser": {
+                        "type": "boolean",
+                        "description": "Fetch the article with a plain HTTP request instead of headless Chrome (default: false)"
+                    },
+                    "no_cache": {
+                        "type": "boolean",
+                        "description": "Always fetch items and don't store them in the item cache (default: false)"
                     }
                 },
                 "required": ["item"]
@@ -159,7 +241,7 @@ pub fn handle_tools_list() -> Result<serde_json::Value, JsonRpcError> {
         },
         Tool {
             name: "hn_list_items".to_string(),
-            description: "List HackerNews stories with pagination. Supports different story types: top, new, best, ask, show, job. Returns a paginated list of stories with their details.".to_string(),
+            description: "List HackerNews stories with pagination. Supports different story types: top, new, best, ask, show, job. Returns a paginated list of stories with their details; pass `pagination.next_cursor` back as `cursor` to get the next stories without duplicates as the list shifts. Optional filters (min_score, domain, author, keyword, since, until) and sort are applied to the whole list before paginating.".to_string(),
             input_schema: serde_json::json!({
                 "type": "object",
                 "properties": {
@@ -175,11 +257,108 @@ pub fn handle_tools_list() -> Result<serde_json::Value, JsonRpcError> {
                     "page": {
                         "type": "number",
                         "description": "Page number, 1-indexed (default: 1)"
+                    },
+                    "cursor": {
+                        "type": "string",
+                        "description": "Continuation token from a previous response's pagination.next_cursor; returns the next stories not yet returned and overrides page"
+                    },
+                    "min_score": {
+                        "type": "number",
+                        "description": "Only include stories with at least this many points"
+                    },
+                    "domain": {
+                        "type": "string",
+                        "description": "Only include stories linking to this domain or its subdomains (e.g., github.com)"
+                    },
+                    "author": {
+                        "type": "string",
+                        "description": "Only include stories submitted by this user"
+                    },
+                    "keyword": {
+                        "type": "string",
+                        "description": "Only include stories whose title or text contains this, case-insensitive"
+                    },
+                    "since": {
+                        "type": "string",
+                        "description": "Only include stories posted since this time: a duration back from now (30m, 24h, 7d, 2w), a date (2024-05-01), or an RFC 3339 timestamp"
+                    },
+                    "until": {
+                        "type": "string",
+                        "description": "Only include stories posted before this time (same formats as since)"
+                    },
+                    "sort": {
+                        "type": "string",
+                        "description": "Order of the stories: rank (HN's own order, default), date (newest first), or points (highest first). Non-default sorts apply to the whole list before paginating",
+                        "enum": ["rank", "date", "points"]
+                    },
+                    "no_cache": {
+                        "type": "boolean",
+                        "description": "Always fetch items and don't store them in the item cache (default: false)"
                     }
                 },
                 "required": []
             }),
         },
+        Tool {
+            name: "hn_search".to_string(),
+            description: "Search HackerNews stories and comments by topic using the Algolia HN search API. Supports tag filters (story, comment, ask_hn, show_hn, author_<user>, story_<id>), a posting-date range, newest-first ordering, and pagination. Returns matching items with points, comment counts, and text excerpts; read a result with hn_read_item.".to_string(),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "query": {
+                        "type": "string",
+                        "description": "Words to search for in titles, URLs, and text"
+                    },
+                    "tags": {
+                        "type": "string",
+                        "description": "Algolia tags, comma-separated to require all: story, comment, ask_hn, show_hn, poll, author_<user>, story_<id> (default: story)"
+                    },
+                    "since": {
+                        "type": "string",
+                        "description": "Only items posted since this time: a duration back from now (24h, 7d), a date (2024-05-01), or an RFC 3339 timestamp"
+                    },
+                    "until": {
+                        "type": "string",
+                        "description": "Only items posted before this time (same formats as since)"
+                    },
+                    "by_date": {
+                        "type": "boolean",
+                        "description": "Order newest first instead of by relevance (default: false)"
+                    },
+                    "limit": {
+                        "type": "number",
+                        "description": "Number of results per page (default: 20)"
+                    },
+                    "page": {
+                        "type": "number",
+                        "description": "Page number, 1-indexed (default: 1)"
+                    }
+                },
+                "required": ["query"]
+            }),
+        },
+        Tool {
+            name: "hn_user".to_string(),
+            description: "Look up a HackerNews user: karma, account creation date, about text, and their most recent submissions (stories with titles, comments as excerpts).".to_string(),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "username": {
+                        "type": "string",
+                        "description": "HackerNews username (case-sensitive)"
+                    },
+                    "limit": {
+                        "type": "number",
+                        "description": "Number of recent submissions to resolve (default: 10)"
+                    },
+                    "no_cache": {
+                        "type": "boolean",
+                        "description": "Always fetch items and don't store them in the item cache (default: false)"
+                    }
+                },
+                "required": ["username"]
+            }),
+        },
         Tool {
             name: "md_fetch".to_string(),
             description: "Fetch a web page using headless Chrome, wait for all XHR requests to complete (network idle), and convert the HTML to Markdown. Supports CSS selector filtering to extract specific page elements. Returns the page title, markdown content, selector metadata, and fetch statistics.".to_string(),
@@ -188,7 +367,7 @@ pub fn handle_tools_list() -> Result<serde_json::Value, JsonRpcError> {
                 "properties": {
                     "url": {
                         "type": "string",
-                        "description": "URL of the web page to fetch"
+                        "description": "URL of the web page to fetch, or a file:// URL to convert saved HTML without the browser"
                     },
                     "timeout": {
                         "type": "number",
@@ -211,6 +390,86 @@ pub fn handle_tools_list() -> Result<serde_json::Value, JsonRpcError> {
                         "type": "number",
                         "description": "Index for 'n' strategy (0-indexed). Required when strategy is 'n'. Specifies which matching element to select."
                     },
+                    "wait_for": {
+                        "type": "string",
+                        "description": "CSS selector to wait for before extracting content. Use for single-page apps that render after load. Fails if the element does not appear within the timeout."
+                    },
+                    "scroll": {
+                        "type": "number",
+                        "description": "Scroll to the bottom this many times, waiting for network activity to settle after each pass, before extracting content. Use for infinite-scroll feeds and lazy-loaded pages."
+                    },
+                    "scroll_until_idle": {
+                        "type": "boolean",
+                        "description": "Keep scrolling until the page stops growing (at most 50 passes, or 'scroll' when given). Default: false"
+                    },
+                    "idle_ms": {
+                        "type": "integer",
+                        "description": "After the page loads, wait until no request has been in flight for this many milliseconds so XHR-rendered content is present; 0 disables. Default: 500"
+                    },
+                    "idle_timeout": {
+                        "type": "integer",
+                        "description": "Longest to wait in seconds for the network to go idle before extracting anyway. Default: 10"
+                    },
+                    "cache_ttl": {
+                        "type": "integer",
+                        "description": "Reuse a cached conversion of the same URL and selector younger than this many seconds; 0 disables the cache. Default: 3600"
+                    },
+                    "no_cache": {
+                        "type": "boolean",
+                        "description": "Always fetch the page and don't store the result in the cache. Default: false"
+                    },
+                    "retries": {
+                        "type": "integer",
+                        "description": "Retries with exponential backoff after transient browser errors (tab creation, navigation, content extraction). Default: 2"
+                    },
+                    "heading": {
+                        "type": "string",
+                        "description": "Return only the section under the heading best matching this title (case, punctuation, and small typos are ignored), saving an md_toc round trip. offset/limit/page then apply within the section. Errors list the page's headings when nothing matches."
+                    },
+                    "clean": {
+                        "type": "boolean",
+                        "description": "Strip navigation, footers, sidebars (nav, footer, aside), cookie banners, and ad containers before converting, to save tokens on cluttered pages. Default: false"
+                    },
+                    "md_headings": {
+                        "type": "string",
+                        "enum": ["setext", "atx"],
+                        "description": "Heading syntax: 'setext' (h1/h2 underlined, default) or 'atx' (# at every level)"
+                    },
+                    "md_links": {
+                        "type": "string",
+                        "enum": ["inline", "reference"],
+                        "description": "Link syntax: 'inline' [text](url) (default) or 'reference' [text][1] with definitions at the end"
+                    },
+                    "md_images": {
+                        "type": "string",
+                        "enum": ["keep", "alt", "drop"],
+                        "description": "Images: 'keep' as ![alt](src) (default), 'alt' to keep only the alt text, or 'drop' to remove them"
+                    },
+                    "md_code_lang": {
+                        "type": "boolean",
+                        "description": "Label code fences with the language named by the block's class (language-*, lang-*, highlight-source-*) or lang attribute. Default: false"
+                    },
+                    "no_browser": {
+                        "type": "boolean",
+                        "description": "Fetch with a plain HTTP request instead of headless Chrome; faster for static pages, but JavaScript does not run and wait_for/scroll are ignored. Used automatically when Chrome is not installed. Default: false"
+                    },
+                    "headers": {
+                        "type": "object",
+                        "additionalProperties": { "type": "string" },
+                        "description": "Extra request headers, e.g. {\"Authorization\": \"Bearer ...\"}. Set before navigation and sent with every request."
+                    },
+                    "cookies": {
+                        "type": "string",
+                        "description": "Cookies in Cookie header form ('name=value; other=value'), set for the target URL before navigation. Use to convert pages behind a login session."
+                    },
+                    "proxy": {
+                        "type": "string",
+                        "description": "Proxy server for all browser traffic, 'host:port' or 'scheme://host:port' (http, https, socks4, socks5). Default: MD_PROXY"
+                    },
+                    "user_agent": {
+                        "type": "string",
+                        "description": "User-Agent override for sites that block the default headless Chrome UA. Default: MD_USER_AGENT"
+                    },
                     "offset": {
                         "type": "number",
                         "description": "Character offset to start from (default: 0). When provided, takes precedence over page parameter. Use with limit to extract specific sections."
@@ -222,6 +481,10 @@ pub fn handle_tools_list() -> Result<serde_json::Value, JsonRpcError> {
                     "page": {
                         "type": "number",
                         "description": "Page number, 1-indexed (default: 1). Ignored if offset is provided. Use pagination metadata in response to navigate to other pages."
+                    },
+                    "metadata": {
+                        "type": "boolean",
+                        "description": "Include a 'metadata' object with the canonical URL, meta description, OpenGraph (og:*, article:*) and Twitter card (twitter:*) properties, and parsed JSON-LD entities (default: false)"
                     }
                 },
                 "required": ["url"]
@@ -254,6 +517,23 @@ pub fn handle_tools_list() -> Result<serde_json::Value, JsonRpcError> {
                         "type": "number",
                         "description": "Index for 'n' strategy (0-indexed). Required when strategy is 'n'. Specifies which matching element to select."
                     },
+                    "headers": {
+                        "type": "object",
+                        "additionalProperties": { "type": "string" },
+                        "description": "Extra request headers, e.g. {\"Authorization\": \"Bearer ...\"}. Set before navigation and sent with every request."
+                    },
+                    "cookies": {
+                        "type": "string",
+                        "description": "Cookies in Cookie header form ('name=value; other=value'), set for the target URL before navigation. Use to convert pages behind a login session."
+                    },
+                    "proxy": {
+                        "type": "string",
+                        "description": "Proxy server for all browser traffic, 'host:port' or 'scheme://host:port' (http, https, socks4, socks5). Default: MD_PROXY"
+                    },
+                    "user_agent": {
+                        "type": "string",
+                        "description": "User-Agent override for sites that block the default headless Chrome UA. Default: MD_USER_AGENT"
+                    },
                     "output": {
                         "type": "string",
                         "description": "Output format: 'indented' (2 spaces per level), 'markdown' (nested list), or 'json' (structured data). Default: 'indented'",
@@ -263,9 +543,149 @@ pub fn handle_tools_list() -> Result<serde_json::Value, JsonRpcError> {
                 "required": ["url"]
             }),
         },
+        Tool {
+            name: "md_grep".to_string(),
+            description: "Search a web page's converted markdown for a regular expression. Returns every match with its line, character offset and length, and a context window on each side. Each match's context_offset and context_limit can be passed as offset and limit to md_fetch (with the same selector) to read the surrounding section. Shares md_fetch's response cache, so follow-up reads see the same content.".to_string(),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "url": {
+                        "type": "string",
+                        "description": "URL of the web page to search"
+                    },
+                    "pattern": {
+                        "type": "string",
+                        "description": "Regular expression (Rust regex syntax) to search for"
+                    },
+                    "flags": {
+                        "type": "string",
+                        "description": "Regex flags: 'i' ignore case, 'm' multi-line, 's' dot matches newline, 'x' verbose. Default: none"
+                    },
+                    "context": {
+                        "type": "integer",
+                        "description": "Characters of context on each side of a match (default: 80)"
+                    },
+                    "max_matches": {
+                        "type": "integer",
+                        "description": "Maximum matches returned; 'truncated' is true when more exist (default: 100)"
+                    },
+                    "selector": {
+                        "type": "string",
+                        "description": "CSS selector to search only part of the page. Use the same selector with md_fetch so offsets line up."
+                    },
+                    "strategy": {
+                        "type": "string",
+                        "description": "Selection strategy when multiple elements match the selector (default: 'first')",
+                        "enum": ["first", "last", "all", "n"]
+                    },
+                    "index": {
+                        "type": "number",
+                        "description": "Index for 'n' strategy (0-indexed). Required when strategy is 'n'."
+                    },
+                    "timeout": {
+                        "type": "number",
+                        "description": "Timeout in seconds (default: 30)"
+                    },
+                    "wait_for": {
+                        "type": "string",
+                        "description": "CSS selector to wait for before searching (for client-rendered pages)"
+                    },
+                    "no_cache": {
+                        "type": "boolean",
+                        "description": "Always fetch the page and don't store the result in the cache. Default: false"
+                    },
+                    "no_browser": {
+                        "type": "boolean",
+                        "description": "Fetch with a plain HTTP request instead of headless Chrome. Default: false"
+                    },
+                    "headers": {
+                        "type": "object",
+                        "additionalProperties": { "type": "string" },
+                        "description": "Extra request headers, e.g. {\"Authorization\": \"Bearer ...\"}"
+                    },
+                    "cookies": {
+                        "type": "string",
+                        "description": "Cookies in Cookie header form ('name=value; other=value')"
+                    },
+                    "proxy": {
+                        "type": "string",
+                        "description": "Proxy server, 'host:port' or 'scheme://host:port'. Default: MD_PROXY"
+                    },
+                    "user_agent": {
+                        "type": "string",
+                        "description": "User-Agent override. Default: MD_USER_AGENT"
+                    }
+                },
+                "required": ["url", "pattern"]
+            }),
+        },
+        Tool {
+            name: "md_screenshot".to_string(),
+            description: "Capture a screenshot of a web page using headless Chrome. Captures the viewport by default, the full scrollable page with full_page, or a single element with selector. Returns the image inline (PNG) with its dimensions, or writes it to output_path and returns the path and dimensions.".to_string(),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "url": {
+                        "type": "string",
+                        "description": "URL of the web page to capture"
+                    },
+                    "full_page": {
+                        "type": "boolean",
+                        "description": "Capture the full scrollable page instead of the viewport (default: false). Cannot be combined with selector."
+                    },
+                    "selector": {
+                        "type": "string",
+                        "description": "CSS selector of a single element to capture"
+                    },
+                    "output_path": {
+                        "type": "string",
+                        "description": "Absolute path to write the image to (.png or .jpg). When omitted, the image is returned inline."
+                    },
+                    "timeout": {
+                        "type": "number",
+                        "description": "Timeout in seconds (default: 30)"
+                    }
+                },
+                "required": ["url"]
+            }),
+        },
+        Tool {
+            name: "youtube_transcript".to_string(),
+            description: "Fetch a YouTube video's captions as a timestamped Markdown transcript. Uses manually created captions when available, falling back to auto-generated ones. Short caption cues are merged into paragraphs, each starting with a timestamp linking to that point in the video. Output is paginated like md_fetch.".to_string(),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "url": {
+                        "type": "string",
+                        "description": "YouTube video URL (watch, youtu.be, shorts, embed) or 11-character video ID"
+                    },
+                    "lang": {
+                        "type": "string",
+                        "description": "Caption language code (e.g., 'en', 'es', 'pt-BR'). 'en' also matches regional variants like 'en-US'. Defaults to the first available track."
+                    },
+                    "timeout": {
+                        "type": "number",
+                        "description": "Timeout in seconds (default: 30)"
+                    },
+                    "offset": {
+                        "type": "number",
+                        "description": "Character offset to start from (default: 0). When provided, takes precedence over page."
+                    },
+                    "limit": {
+                        "type": "number",
+                        "description": "Number of characters per page (default: 1000)"
+                    },
+                    "page": {
+                        "type": "number",
+                        "description": "Page number, 1-indexed (default: 1). Ignored if offset is provided."
+                    }
+                },
+                "required": ["url"]
+            }),
+        },
         Tool {
             name: "jira_create".to_string(),
-            description: "Create a new Jira ticket with required summary. Supports optional fields like description, issue type, priority, assignee, and sprint assignment. Returns the created ticket key. Requires JIRA_BASE_URL, JIRA_EMAIL, and JIRA_API_TOKEN environment variables (or ATLASSIAN_* as fallback).".to_string(),
+            description: "Create a new Jira ticket with required summary. Supports optional fields like a markdown description (converted to Atlassian Document Format), issue type, priority, assignee, labels, and sprint assignment. Returns the created ticket key. Requires JIRA_BASE_URL, JIRA_EMAIL, and JIRA_API_TOKEN environment variables (or ATLASSIAN_* as fallback).".to_string(),
             input_schema: serde_json::json!({
                 "type": "object",
                 "properties": {
@@ -275,7 +695,7 @@ pub fn handle_tools_list() -> Result<serde_json::Value, JsonRpcError> {
                     },
                     "description": {
                         "type": "string",
-                        "description": "Description of the ticket"
+                        "description": "Description of the ticket in markdown (headings, lists, code blocks, bold, italic, links)"
                     },
                     "project": {
                         "type": "string",
@@ -293,6 +713,11 @@ pub fn handle_tools_list() -> Result<serde_json::Value, JsonRpcError> {
                         "type": "string",
                         "description": "Assignee (email, display name, account ID, or \"me\" for current user)"
                     },
+                    "labels": {
+                        "type": "array",
+                        "items": { "type": "string" },
+                        "description": "Labels to add to the ticket (labels cannot contain spaces)"
+                    },
                     "sprint": {
                         "type": "string",
                         "description": "Sprint name to assign the issue to after creation (resolves name to ID automatically)"
@@ -307,13 +732,36 @@ pub fn handle_tools_list() -> Result<serde_json::Value, JsonRpcError> {
         },
         Tool {
             name: "jira_get".to_string(),
-            description: "Get detailed information about a Jira ticket. Returns comprehensive information about a specific issue using its issue key. Requires JIRA_BASE_URL, JIRA_EMAIL, and JIRA_API_TOKEN environment variables (or ATLASSIAN_* as fallback).".to_string(),
+            description: "Get detailed information about a Jira ticket. Returns comprehensive information about a specific issue using its issue key, including populated custom fields keyed by field name. Set expand to 'changelog' to include the field change history. Requires JIRA_BASE_URL, JIRA_EMAIL, and JIRA_API_TOKEN environment variables (or ATLASSIAN_* as fallback).".to_string(),
             input_schema: serde_json::json!({
                 "type": "object",
                 "properties": {
                     "issueKey": {
                         "type": "string",
                         "description": "Unique identifier for the Jira issue (e.g., 'PROJ-123')"
+                    },
+                    "expand": {
+                        "type": "string",
+                        "enum": ["changelog"],
+                        "description": "Extra section to include: 'changelog' adds the field change history"
+                    }
+                },
+                "required": ["issueKey"]
+            }),
+        },
+        Tool {
+            name: "jira_history".to_string(),
+            description: "Get the field change history of a Jira ticket: who changed which field, from what value to what value, and when, oldest first. Optionally limit it to one field by name or ID (e.g., 'status'). Requires JIRA_BASE_URL, JIRA_EMAIL, and JIRA_API_TOKEN environment variables (or ATLASSIAN_* as fallback).".to_string(),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "issueKey": {
+                        "type": "string",
+                        "description": "The Jira issue key (e.g., PROJ-123)"
+                    },
+                    "field": {
+                        "type": "string",
+                        "description": "Only return changes to this field, by name or ID (e.g., 'status', 'assignee')"
                     }
                 },
                 "required": ["issueKey"]
@@ -321,7 +769,7 @@ pub fn handle_tools_list() -> Result<serde_json::Value, JsonRpcError> {
         },
         Tool {
             name: "jira_update".to_string(),
-            description: "Update Jira ticket fields. Supports updating Status, Priority, Type, Assignee, Description (markdown), and Sprint assignment. Can update multiple fields in a single call. Handles status transitions automatically and supports assignee lookup by email, display name, or account ID. Requires JIRA_BASE_URL, JIRA_EMAIL, and JIRA_API_TOKEN environment variables (or ATLASSIAN_* as fallback).".to_string(),
+            description: "Update Jira ticket fields. Supports updating Status, Priority, Type, Assignee, Summary, Description (markdown), Due Date, Components, Labels (add/remove), and Sprint assignment. Can update multiple fields in a single call. Handles status transitions automatically and supports assignee lookup by email, display name, or account ID. Requires JIRA_BASE_URL, JIRA_EMAIL, and JIRA_API_TOKEN environment variables (or ATLASSIAN_* as fallback).".to_string(),
             input_schema: serde_json::json!({
                 "type": "object",
                 "properties": {
@@ -347,23 +795,208 @@ pub fn handle_tools_list() -> Result<serde_json::Value, JsonRpcError> {
                     },
                     "description": {
                         "type": "string",
-                        "description": "New description for the ticket (supports markdown: headings, bold, italic, lists, code blocks, inline code, links)"
+                        "description": "New description for the ticket (supports markdown: headings, bold, italic, lists, code blocks, inline code, links)"
+                    },
+                    "summary": {
+                        "type": "string",
+                        "description": "New summary (title) for the ticket"
+                    },
+                    "dueDate": {
+                        "type": "string",
+                        "description": "New due date as YYYY-MM-DD, or \"none\" to clear it"
+                    },
+                    "components": {
+                        "type": "array",
+                        "items": { "type": "string" },
+                        "description": "Component names that replace the ticket's current components"
+                    },
+                    "addLabels": {
+                        "type": "array",
+                        "items": { "type": "string" },
+                        "description": "Labels to add (existing labels are kept; labels cannot contain spaces)"
+                    },
+                    "removeLabels": {
+                        "type": "array",
+                        "items": { "type": "string" },
+                        "description": "Labels to remove"
+                    },
+                    "fields": {
+                        "type": "object",
+                        "additionalProperties": true,
+                        "description": "Fields to set, keyed by [jira.fields] alias, field name, or field ID (e.g. {\"story_points\": 5}). Arrays set multi-value fields; null or an empty string clears a field. See jira_field_list"
+                    },
+                    "sprint": {
+                        "type": "string",
+                        "description": "Sprint name to assign the issue to (resolves name to ID automatically)"
+                    },
+                    "boardId": {
+                        "type": "number",
+                        "description": "Board ID for sprint operations (required when sprint is provided)"
+                    }
+                },
+                "required": ["ticketKey"]
+            }),
+        },
+        Tool {
+            name: "jira_transitions_list".to_string(),
+            description: "List the workflow transitions available from a Jira ticket's current status, with each transition's ID, name, target status, and required fields. Use this to see which statuses jira_update can move a ticket to and why a status update failed. Requires JIRA_BASE_URL, JIRA_EMAIL, and JIRA_API_TOKEN environment variables (or ATLASSIAN_* as fallback).".to_string(),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "issueKey": {
+                        "type": "string",
+                        "description": "The Jira issue key (e.g., PROJ-123)"
+                    }
+                },
+                "required": ["issueKey"]
+            }),
+        },
+        Tool {
+            name: "jira_comment_add".to_string(),
+            description: "Post a comment on a Jira ticket. Supports markdown in the comment body (bold, italic, headings, lists, code blocks, links) which is automatically converted to Atlassian Document Format. Requires JIRA_BASE_URL, JIRA_EMAIL, and JIRA_API_TOKEN environment variables (or ATLASSIAN_* as fallback).".to_string(),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "issueKey": {
+                        "type": "string",
+                        "description": "The Jira issue key (e.g., PROJ-123)"
+                    },
+                    "comment": {
+                        "type": "string",
+                        "description": "Comment body text (supports markdown: headings, bold, italic, lists, code blocks, inline code, links)"
+                    }
+                },
+                "required": ["issueKey", "comment"]
+            }),
+        },
+        Tool {
+            name: "jira_comment_list".to_string(),
+            description: "List all comments on a Jira ticket. Returns comment details including ID, author, body text, and creation date. Requires JIRA_BASE_URL, JIRA_EMAIL, and JIRA_API_TOKEN environment variables (or ATLASSIAN_* as fallback).".to_string(),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "issueKey": {
+                        "type": "string",
+                        "description": "The Jira issue key (e.g., PROJ-123)"
+                    }
+                },
+                "required": ["issueKey"]
+            }),
+        },
+        Tool {
+            name: "jira_comment_update".to_string(),
+            description: "Update an existing comment on a Jira ticket. Supports markdown in the comment body. Use jira_comment_list first to get comment IDs. Requires JIRA_BASE_URL, JIRA_EMAIL, and JIRA_API_TOKEN environment variables (or ATLASSIAN_* as fallback).".to_string(),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "issueKey": {
+                        "type": "string",
+                        "description": "The Jira issue key (e.g., PROJ-123)"
+                    },
+                    "commentId": {
+                        "type": "string",
+                        "description": "The comment ID to update"
+                    },
+                    "comment": {
+                        "type": "string",
+                        "description": "New comment body text (supports markdown)"
+                    }
+                },
+                "required": ["issueKey", "commentId", "comment"]
+            }),
+        },
+        Tool {
+            name: "jira_comment_delete".to_string(),
+            description: "Delete a comment from a Jira ticket by comment ID. Use jira_comment_list first to get comment IDs. Requires JIRA_BASE_URL, JIRA_EMAIL, and JIRA_API_TOKEN environment variables (or ATLASSIAN_* as fallback).".to_string(),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "issueKey": {
+                        "type": "string",
+                        "description": "The Jira issue key (e.g., PROJ-123)"
+                    },
+                    "commentId": {
+                        "type": "string",
+                        "description": "The comment ID to delete"
+                    }
+                },
+                "required": ["issueKey", "commentId"]
+            }),
+        },
+        Tool {
+            name: "jira_epic".to_string(),
+            description: "Get a Jira epic's details with all of its child issues and a progress rollup: issue counts and story points per status, done totals, and percent done. Story points are read from the storyPointsField custom field. Requires JIRA_BASE_URL, JIRA_EMAIL, and JIRA_API_TOKEN environment variables (or ATLASSIAN_* as fallback).".to_string(),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "epicKey": {
+                        "type": "string",
+                        "description": "The epic's issue key (e.g., PROJ-100)"
+                    },
+                    "storyPointsField": {
+                        "type": "string",
+                        "description": "Custom field holding story points, by ID or [jira.fields] alias (default: JIRA_STORY_POINTS_FIELD, the story_points alias, or customfield_10016)"
+                    }
+                },
+                "required": ["epicKey"]
+            }),
+        },
+        Tool {
+            name: "jira_link".to_string(),
+            description: "Link a Jira ticket to other issues as blocking, relating to, or duplicating them, or list its existing links when no link is given. Link types are discovered from the server. Returns all of the ticket's links with relationship, issue key, status, and summary. Requires JIRA_BASE_URL, JIRA_EMAIL, and JIRA_API_TOKEN environment variables (or ATLASSIAN_* as fallback).".to_string(),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "issueKey": {
+                        "type": "string",
+                        "description": "The Jira issue key (e.g., PROJ-123)"
+                    },
+                    "blocks": {
+                        "type": "array",
+                        "items": { "type": "string" },
+                        "description": "Issue keys this ticket blocks"
+                    },
+                    "relatesTo": {
+                        "type": "array",
+                        "items": { "type": "string" },
+                        "description": "Issue keys this ticket relates to"
+                    },
+                    "duplicates": {
+                        "type": "array",
+                        "items": { "type": "string" },
+                        "description": "Issue keys this ticket duplicates"
+                    }
+                },
+                "required": ["issueKey"]
+            }),
+        },
+        Tool {
+            name: "jira_watch".to_string(),
+            description: "Add or remove watchers on a Jira ticket, or list its watchers when no change is given. Users may be given as email, display name, account ID, or \"me\", resolved the same way as assignees. Returns the ticket's watchers. Requires JIRA_BASE_URL, JIRA_EMAIL, and JIRA_API_TOKEN environment variables (or ATLASSIAN_* as fallback).".to_string(),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "issueKey": {
+                        "type": "string",
+                        "description": "The Jira issue key (e.g., PROJ-123)"
                     },
-                    "sprint": {
-                        "type": "string",
-                        "description": "Sprint name to assign the issue to (resolves name to ID automatically)"
+                    "add": {
+                        "type": "array",
+                        "items": { "type": "string" },
+                        "description": "Users to add as watchers (email, display name, account ID, or \"me\")"
                     },
-                    "boardId": {
-                        "type": "number",
-                        "description": "Board ID for sprint operations (required when sprint is provided)"
+                    "remove": {
+                        "type": "array",
+                        "items": { "type": "string" },
+                        "description": "Users to remove as watchers (email, display name, account ID, or \"me\")"
                     }
                 },
-                "required": ["ticketKey"]
+                "required": ["issueKey"]
             }),
         },
         Tool {
-            name: "jira_comment_add".to_string(),
-            description: "Post a comment on a Jira ticket. Supports markdown in the comment body (bold, italic, headings, lists, code blocks, links) which is automatically converted to Atlassian Document Format. Requires JIRA_BASE_URL, JIRA_EMAIL, and JIRA_API_TOKEN environment variables (or ATLASSIAN_* as fallback).".to_string(),
+            name: "jira_worklog_add".to_string(),
+            description: "Log time spent on a Jira ticket. The time uses Jira's duration notation (e.g. 1h30m, 2d, 45m; 1d = 8h, 1w = 5d); the optional comment supports markdown. Returns the created worklog. Requires JIRA_BASE_URL, JIRA_EMAIL, and JIRA_API_TOKEN environment variables (or ATLASSIAN_* as fallback).".to_string(),
             input_schema: serde_json::json!({
                 "type": "object",
                 "properties": {
@@ -371,17 +1004,21 @@ pub fn handle_tools_list() -> Result<serde_json::Value, JsonRpcError> {
                         "type": "string",
                         "description": "The Jira issue key (e.g., PROJ-123)"
                     },
+                    "time": {
+                        "type": "string",
+                        "description": "Time spent, e.g. 1h30m, 2d 4h, 45m"
+                    },
                     "comment": {
                         "type": "string",
-                        "description": "Comment body text (supports markdown: headings, bold, italic, lists, code blocks, inline code, links)"
+                        "description": "Description of the work done (supports markdown)"
                     }
                 },
-                "required": ["issueKey", "comment"]
+                "required": ["issueKey", "time"]
             }),
         },
         Tool {
-            name: "jira_comment_list".to_string(),
-            description: "List all comments on a Jira ticket. Returns comment details including ID, author, body text, and creation date. Requires JIRA_BASE_URL, JIRA_EMAIL, and JIRA_API_TOKEN environment variables (or ATLASSIAN_* as fallback).".to_string(),
+            name: "jira_worklog_list".to_string(),
+            description: "List the worklogs on a Jira ticket with author, start time, time spent, and comment, plus the total time logged. Requires JIRA_BASE_URL, JIRA_EMAIL, and JIRA_API_TOKEN environment variables (or ATLASSIAN_* as fallback).".to_string(),
             input_schema: serde_json::json!({
                 "type": "object",
                 "properties": {
@@ -394,43 +1031,101 @@ pub fn handle_tools_list() -> Result<serde_json::Value, JsonRpcError> {
             }),
         },
         Tool {
-            name: "jira_comment_update".to_string(),
-            description: "Update an existing comment on a Jira ticket. Supports markdown in the comment body. Use jira_comment_list first to get comment IDs. Requires JIRA_BASE_URL, JIRA_EMAIL, and JIRA_API_TOKEN environment variables (or ATLASSIAN_* as fallback).".to_string(),
+            name: "jira_bulk_comment".to_string(),
+            description: "Post a templated comment on every Jira issue matching a JQL query (e.g., release announcements). Placeholders {key}, {summary}, {status}, and {assignee} come from each issue; other placeholders come from vars. Unknown placeholders abort before anything is posted. Use dryRun to preview rendered comments. Returns per-issue results. Requires JIRA_BASE_URL, JIRA_EMAIL, and JIRA_API_TOKEN environment variables (or ATLASSIAN_* as fallback).".to_string(),
             input_schema: serde_json::json!({
                 "type": "object",
                 "properties": {
-                    "issueKey": {
+                    "jql": {
                         "type": "string",
-                        "description": "The Jira issue key (e.g., PROJ-123)"
+                        "description": "JQL query selecting the issues to comment on"
                     },
-                    "commentId": {
+                    "template": {
                         "type": "string",
-                        "description": "The comment ID to update"
+                        "description": "Comment template in markdown, e.g. 'Released in {version}'. Use {{ and }} for literal braces."
                     },
-                    "comment": {
+                    "vars": {
+                        "type": "object",
+                        "additionalProperties": { "type": "string" },
+                        "description": "Template variables, e.g. {\"version\": \"1.2.0\"}"
+                    },
+                    "limit": {
+                        "type": "number",
+                        "description": "Maximum number of issues to comment on (default: 50)"
+                    },
+                    "delayMs": {
+                        "type": "number",
+                        "description": "Delay between comments in milliseconds (default: 500)"
+                    },
+                    "dryRun": {
+                        "type": "boolean",
+                        "description": "Render comments without posting them (default: false)"
+                    }
+                },
+                "required": ["jql", "template"]
+            }),
+        },
+        Tool {
+            name: "jira_bulk_update".to_string(),
+            description: "Update many Jira tickets at once. Each update object has a required key plus any of status, priority, type, assignee, summary, description (markdown), due_date (YYYY-MM-DD or 'none'), components, add_labels, and remove_labels (lists as arrays or ';'-separated strings). All updates are validated before any is applied; invalid rows abort with one error per row. Returns a per-row success/failure report with the fields updated. Requires JIRA_BASE_URL, JIRA_EMAIL, and JIRA_API_TOKEN environment variables (or ATLASSIAN_* as fallback).".to_string(),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "updates": {
+                        "type": "array",
+                        "items": { "type": "object" },
+                        "description": "Update objects, e.g. [{\"key\": \"PROJ-1\", \"status\": \"Done\", \"add_labels\": [\"released\"]}]"
+                    },
+                    "concurrency": {
+                        "type": "number",
+                        "description": "Maximum number of tickets updated at once (default: 4)"
+                    },
+                    "dryRun": {
+                        "type": "boolean",
+                        "description": "Validate and return the planned field changes without applying them (default: false)"
+                    }
+                },
+                "required": ["updates"]
+            }),
+        },
+        Tool {
+            name: "jira_field_list".to_string(),
+            description: "List the fields defined on the Jira site. Returns field ID, name, type, and whether it is a custom field. Use this to find the customfield_* ID behind a field name. Requires JIRA_BASE_URL, JIRA_EMAIL, and JIRA_API_TOKEN environment variables (or ATLASSIAN_* as fallback).".to_string(),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "customOnly": {
+                        "type": "boolean",
+                        "description": "Only list custom fields (default: false)"
+                    },
+                    "search": {
                         "type": "string",
-                        "description": "New comment body text (supports markdown)"
+                        "description": "Only fields whose name or ID contains this text (case-insensitive)"
                     }
                 },
-                "required": ["issueKey", "commentId", "comment"]
+                "required": []
             }),
         },
         Tool {
-            name: "jira_comment_delete".to_string(),
-            description: "Delete a comment from a Jira ticket by comment ID. Use jira_comment_list first to get comment IDs. Requires JIRA_BASE_URL, JIRA_EMAIL, and JIRA_API_TOKEN environment variables (or ATLASSIAN_* as fallback).".to_string(),
+            name: "jira_board_list".to_string(),
+            description: "List the Jira boards visible to the user via the Agile API. Returns board ID, name, type, and project. Use this to find the board ID for jira_sprint_list. Requires JIRA_BASE_URL, JIRA_EMAIL, and JIRA_API_TOKEN environment variables (or ATLASSIAN_* as fallback).".to_string(),
             input_schema: serde_json::json!({
                 "type": "object",
                 "properties": {
-                    "issueKey": {
+                    "project": {
                         "type": "string",
-                        "description": "The Jira issue key (e.g., PROJ-123)"
+                        "description": "Only boards for this project key or ID"
                     },
-                    "commentId": {
+                    "type": {
                         "type": "string",
-                        "description": "The comment ID to delete"
+                        "description": "Only boards of this type: scrum, kanban, or simple"
+                    },
+                    "name": {
+                        "type": "string",
+                        "description": "Only boards whose name contains this text"
                     }
                 },
-                "required": ["issueKey", "commentId"]
+                "required": []
             }),
         },
         Tool {
@@ -553,7 +1248,7 @@ pub fn handle_tools_list() -> Result<serde_json::Value, JsonRpcError> {
         },
         Tool {
             name: "jira_query_load".to_string(),
-            description: "Load and display the contents of a saved Jira query. Returns the query name and the JQL query text.".to_string(),
+            description: "Load and display the contents of a saved Jira query. Returns the query name, the JQL query text, and the {name} placeholder variables it expects.".to_string(),
             input_schema: serde_json::json!({
                 "type": "object",
                 "properties": {
@@ -769,6 +1464,84 @@ pub fn handle_tools_list() -> Result<serde_json::Value, JsonRpcError> {
                 "required": ["instruction"]
             }),
         },
+        Tool {
+            name: "describe_image".to_string(),
+            description: "Describe an image using a local Ollama vision model (llava, qwen-vl, ...). Accepts an image file, or a PDF file plus an image ID from pdf_images. Set altText for a single short sentence suitable for alt text. Requires a running Ollama instance with a vision model pulled.".to_string(),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "path": {
+                        "type": "string",
+                        "description": "Absolute path to an image file (PNG, JPEG, GIF, WebP, BMP) or a PDF file"
+                    },
+                    "imageId": {
+                        "type": "string",
+                        "description": "Image ID (XObject name) from pdf_images. Required when path is a PDF."
+                    },
+                    "altText": {
+                        "type": "boolean",
+                        "description": "Return short alt text (under 125 characters) instead of a detailed description (default: false)"
+                    },
+                    "prompt": {
+                        "type": "string",
+                        "description": "Custom prompt. In altText mode, extra guidance appended to the alt text prompt."
+                    },
+                    "model": {
+                        "type": "string",
+                        "description": "Ollama vision model (default: VISION_MODEL env var or 'llava')"
+                    },
+                    "ollamaUrl": {
+                        "type": "string",
+                        "description": "Ollama base URL (default: OLLAMA_URL env var or http://localhost:11434)"
+                    }
+                },
+                "required": ["path"]
+            }),
+        },
+        Tool {
+            name: "transcribe_audio".to_string(),
+            description: "Transcribe an audio file into a timestamped transcript using a local whisper.cpp server or an OpenAI-compatible speech-to-text endpoint. Short speech segments are merged into paragraphs, each prefixed with its start time. Returns Markdown by default, or JSON with merged segments.".to_string(),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "path": {
+                        "type": "string",
+                        "description": "Absolute path to an audio file (wav, mp3, m4a, ogg, flac, webm)"
+                    },
+                    "endpoint": {
+                        "type": "string",
+                        "description": "Speech-to-text server base URL (default: TRANSCRIBE_URL env var or http://localhost:8080)"
+                    },
+                    "api": {
+                        "type": "string",
+                        "enum": ["whisper-cpp", "openai"],
+                        "description": "Server API flavor (default: TRANSCRIBE_API env var or 'whisper-cpp')"
+                    },
+                    "model": {
+                        "type": "string",
+                        "description": "Model name for OpenAI-compatible endpoints (default: TRANSCRIBE_MODEL env var or 'whisper-1')"
+                    },
+                    "language": {
+                        "type": "string",
+                        "description": "Spoken language as an ISO-639-1 code (auto-detected when omitted)"
+                    },
+                    "maxGap": {
+                        "type": "number",
+                        "description": "Silence in seconds that starts a new paragraph (default: 2)"
+                    },
+                    "maxParagraph": {
+                        "type": "number",
+                        "description": "Maximum paragraph length in seconds (default: 60)"
+                    },
+                    "format": {
+                        "type": "string",
+                        "enum": ["markdown", "json"],
+                        "description": "Output format (default: markdown)"
+                    }
+                },
+                "required": ["path"]
+            }),
+        },
         Tool {
             name: "greprag_retrieve".to_string(),
             description: "Retrieve relevant cross-file code context from a repository. Pass a code snippet you're working with and get back the most relevant code from across the repo — function definitions, type declarations, usages, and related logic. Use this when you need to understand how a symbol is defined or used elsewhere, find related code before making changes, or gather context for a code review. Powered by a local Ollama model + ripgrep + BM25 ranking. Requires a running Ollama instance with the greprag model.".to_string(),
@@ -869,13 +1642,18 @@ pub fn handle_tools_list() -> Result<serde_json::Value, JsonRpcError> {
         },
         Tool {
             name: "pdf_toc".to_string(),
-            description: "Parse a PDF file and return its document tree (table of contents) with section IDs, headings, content previews, and image counts. Use the section IDs with pdf_read to read specific sections.".to_string(),
+            description: "Parse a PDF file and return its document tree (table of contents) with section IDs, stable slugs, headings, content previews, image counts, and detected languages (ISO 639-3). Use the section IDs or slugs with pdf_read to read specific sections. The 'indented' and 'markdown' outputs are compact outlines with each section's ID, page range, and character count for planning reads.".to_string(),
             input_schema: serde_json::json!({
                 "type": "object",
                 "properties": {
                     "path": {
                         "type": "string",
                         "description": "Absolute path to the PDF file"
+                    },
+                    "output": {
+                        "type": "string",
+                        "description": "Output format: 'indented' (2 spaces per level), 'markdown' (nested list), or 'json' (full tree). Default: 'json'",
+                        "enum": ["indented", "markdown", "json"]
                     }
                 },
                 "required": ["path"]
@@ -893,7 +1671,7 @@ pub fn handle_tools_list() -> Result<serde_json::Value, JsonRpcError> {
                     },
                     "sectionId": {
                         "type": "string",
-                        "description": "Section ID from pdf_toc (e.g., 's-1-0'). Omit for whole document."
+                        "description": "Section ID from pdf_toc (e.g., 's-1-0') or its stable slug (e.g., 'introduction'). Omit for whole document."
                     }
                 },
                 "required": ["path"]
@@ -911,12 +1689,11 @@ pub fn handle_tools_list() -> Result<serde_json::Value, JsonRpcError> {
                     },
                     "sectionId": {
                         "type": "string",
-                        "description": "Section ID from pdf_toc (e.g., 's-1-0'). Omit for whole document."
+                        "description": "Section ID from pdf_toc (e.g., 's-1-0') or its stable slug (e.g., 'introduction'). Omit for whole document."
                     },
                     "position": {
                         "type": "string",
-                        "enum": ["beginning", "middle", "ending", "random"],
-                        "description": "Where to sample from (default: beginning)"
+                        "description": "Where to sample from: 'beginning' (default), 'middle', 'ending', 'random', 'page:N' (text starting on 1-based page N), or 'N%' (N percent through the text)"
                     },
                     "limit": {
                         "type": "number",
@@ -970,15 +1747,45 @@ pub fn handle_tools_list() -> Result<serde_json::Value, JsonRpcError> {
                 "required": ["path"]
             }),
         },
+        Tool {
+            name: "pdf_thumbnail".to_string(),
+            description: "Render a PDF page as a small PNG thumbnail for a visual preview. Pass a page number, or a section ID to render that section's first page. Returns image content. Requires a build with the `raster` feature and a Pdfium library available on the system.".to_string(),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "path": {
+                        "type": "string",
+                        "description": "Absolute path to the PDF file"
+                    },
+                    "page": {
+                        "type": "number",
+                        "description": "1-based page number to render (default: 1). Cannot be used with sectionId."
+                    },
+                    "sectionId": {
+                        "type": "string",
+                        "description": "Section ID from pdf_toc; renders the section's first page"
+                    },
+                    "size": {
+                        "type": "number",
+                        "description": "Maximum width/height in pixels (default: 256)"
+                    }
+                },
+                "required": ["path"]
+            }),
+        },
         Tool {
             name: "pdf_info".to_string(),
-            description: "Get metadata about a PDF document including title, author, page count, and creator.".to_string(),
+            description: "Get metadata about a PDF document including title, author, page count, and creator. With stats, also returns per-page word, image, and heading counts to locate where the main content starts.".to_string(),
             input_schema: serde_json::json!({
                 "type": "object",
                 "properties": {
                     "path": {
                         "type": "string",
                         "description": "Absolute path to the PDF file"
+                    },
+                    "stats": {
+                        "type": "boolean",
+                        "description": "Include per-page word, image, and heading counts (default: false). Requires a full parse."
                     }
                 },
                 "required": ["path"]
@@ -1014,8 +1821,203 @@ pub fn handle_tools_list() -> Result<serde_json::Value, JsonRpcError> {
                 "properties": {}
             }),
         },
+        Tool {
+            name: "calendar_events".to_string(),
+            description: "List events from an iCalendar (.ics) file or URL in a date range. Expands recurring events (RRULE, RDATE, EXDATE, overrides) and converts times to the requested time zone. Returns Markdown grouped by day, or JSON.".to_string(),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "source": { "type": "string", "description": "Path to an .ics file, or an http(s)/webcal URL" },
+                    "from": { "type": "string", "description": "First day of the range (YYYY-MM-DD, default: today)" },
+                    "to": { "type": "string", "description": "Last day of the range (YYYY-MM-DD, default: from + days - 1)" },
+                    "days": { "type": "integer", "description": "Number of days to list when 'to' is not given (default: 7)" },
+                    "timezone": { "type": "string", "description": "IANA time zone for output, e.g. 'Europe/Berlin' (default: CAL_TIMEZONE or system zone)" },
+                    "format": { "type": "string", "enum": ["markdown", "json"], "description": "Output format (default: markdown)" }
+                },
+                "required": ["source"]
+            }),
+        },
+        Tool {
+            name: "text_diff".to_string(),
+            description: "Compute a unified diff between two texts or files. Returns the diff with hunk, addition, and deletion counts. Pair with text_patch_apply to make precise edits instead of rewriting whole documents.".to_string(),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "old": { "type": "string", "description": "Original text (or use oldPath)" },
+                    "new": { "type": "string", "description": "Updated text (or use newPath)" },
+                    "oldPath": { "type": "string", "description": "Path to the original file" },
+                    "newPath": { "type": "string", "description": "Path to the updated file" },
+                    "context": { "type": "integer", "description": "Unchanged lines around each change (default: 3)" }
+                }
+            }),
+        },
+        Tool {
+            name: "text_patch_apply".to_string(),
+            description: "Apply a unified diff to a text or file. Hunks must match exactly but may have moved; moved hunks are reported as offsets. Fails without changes if any hunk does not apply. Returns the patched text, or writes it back to 'path' when 'write' is true.".to_string(),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "patch": { "type": "string", "description": "Unified diff with @@ hunk headers (file headers are optional)" },
+                    "text": { "type": "string", "description": "Text to patch (or use path)" },
+                    "path": { "type": "string", "description": "Path to the file to patch" },
+                    "write": { "type": "boolean", "description": "Write the result back to 'path' instead of returning it (default: false)" }
+                },
+                "required": ["patch"]
+            }),
+        },
+        Tool {
+            name: "regex_extract".to_string(),
+            description: "Find regex matches in a text or file, returning each match with byte offsets, line number, and capture groups (numbered and named). Uses a linear-time engine (no backreferences or lookaround), so patterns cannot backtrack catastrophically; pattern size, input size, and match count are limited.".to_string(),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "pattern": { "type": "string", "description": "Regular expression (Rust regex syntax)" },
+                    "text": { "type": "string", "description": "Text to search (or use path)" },
+                    "path": { "type": "string", "description": "Path to a file to search" },
+                    "flags": { "type": "string", "description": "Any of 'i' (case-insensitive), 'm' (multi-line ^/$), 's' (dot matches newline), 'x' (verbose)" },
+                    "maxMatches": { "type": "integer", "description": "Maximum matches to return (default: 100)" }
+                },
+                "required": ["pattern"]
+            }),
+        },
+        Tool {
+            name: "hash".to_string(),
+            description: "Compute a SHA-256 or MD5 checksum of a text or file. Files are hashed as raw bytes. Returns the lowercase hex digest and the number of bytes hashed.".to_string(),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "text": { "type": "string", "description": "Text to hash, as UTF-8 (or use path)" },
+                    "path": { "type": "string", "description": "Path to a file to hash" },
+                    "algorithm": { "type": "string", "enum": ["sha256", "md5"], "description": "Hash algorithm (default: sha256)" }
+                }
+            }),
+        },
+        Tool {
+            name: "encode".to_string(),
+            description: "Encode or decode text as base64, base64url (URL-safe, unpadded), url (percent-encoding), or hex. Decoding ignores whitespace and missing base64 padding; decoded bytes must be valid UTF-8.".to_string(),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "encoding": { "type": "string", "enum": ["base64", "base64url", "url", "hex"], "description": "Encoding to apply or reverse" },
+                    "text": { "type": "string", "description": "Text to convert (or use path)" },
+                    "path": { "type": "string", "description": "Path to a UTF-8 file to convert" },
+                    "decode": { "type": "boolean", "description": "Decode instead of encode (default: false)" }
+                },
+                "required": ["encoding"]
+            }),
+        },
+        Tool {
+            name: "generate_id".to_string(),
+            description: "Generate UUIDs (v4 random or v7 time-ordered), ULIDs, or random passwords. Pass 'seed' for reproducible output; UUIDv7 and ULID timestamps still come from the clock.".to_string(),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "kind": { "type": "string", "enum": ["uuid", "ulid", "password"], "description": "What to generate" },
+                    "count": { "type": "integer", "description": "Number of values, 1-1000 (default: 1)" },
+                    "seed": { "type": "integer", "description": "Seed for the random generator, for reproducible output" },
+                    "version": { "type": "integer", "enum": [4, 7], "description": "UUID version (default: 4)" },
+                    "format": { "type": "string", "enum": ["hyphenated", "simple", "urn", "braced"], "description": "UUID format (default: hyphenated)" },
+                    "uppercase": { "type": "boolean", "description": "Uppercase UUID hex digits (default: false)" },
+                    "lowercase": { "type": "boolean", "description": "Lowercase ULIDs (default: false)" },
+                    "length": { "type": "integer", "description": "Password length (default: 20)" },
+                    "digits": { "type": "boolean", "description": "Include digits in passwords (default: true)" },
+                    "symbols": { "type": "boolean", "description": "Include symbols in passwords (default: true)" },
+                    "excludeAmbiguous": { "type": "boolean", "description": "Leave look-alike characters out of passwords (default: false)" }
+                },
+                "required": ["kind"]
+            }),
+        },
+        Tool {
+            name: "render_template".to_string(),
+            description: "Render a Handlebars template against a JSON context and return the text. Supports the standard blocks ({{#if}}, {{#unless}}, {{#each}}, {{#with}}), the built-in helpers (lookup, eq, ne, gt, gte, lt, lte, and, or, not, len), and inline partials. {{value}} is HTML-escaped; use {{{value}}} for raw text. Sandboxed: no file, directory, or environment access; output is capped at 1 MiB.".to_string(),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "template": { "type": "string", "description": "Template text" },
+                    "context": { "description": "JSON value the template is rendered against (default: null)" },
+                    "strict": { "type": "boolean", "description": "Fail on missing values instead of rendering them empty (default: false)" }
+                },
+                "required": ["template"]
+            }),
+        },
+        Tool {
+            name: "json_query".to_string(),
+            description: "Filter JSON with a jq expression (or JSONPath when the query starts with '$') and return only the results, one per line. Runs on the embedded jaq engine with the jq standard library: variables and destructuring, reduce/foreach, def (non-recursive), assignment, regex functions, and @csv/@tsv/@base64 formats. No file, module, or environment access. NDJSON input runs the query once per value.".to_string(),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "query": { "type": "string", "description": "jq expression (e.g. '.items[] | select(.price > 10) | {name, price}') or JSONPath (e.g. '$..name')" },
+                    "json": { "type": "string", "description": "JSON text to query (or use path)" },
+                    "path": { "type": "string", "description": "Path to a JSON or NDJSON file" },
+                    "slurp": { "type": "boolean", "description": "Collect all input values into one array before querying (default: false)" },
+                    "raw": { "type": "boolean", "description": "Print string results without quotes (default: false)" },
+                    "compact": { "type": "boolean", "description": "One line per result instead of pretty-printed JSON (default: false)" }
+                },
+                "required": ["query"]
+            }),
+        },
+        Tool {
+            name: "html_select".to_string(),
+            description: "Return only the HTML elements matching a CSS selector (or an XPath location path) as text, Markdown, HTML, or JSON. Use it for precise extraction (table cells, links, prices) when converting the whole page with md_fetch is overkill. The source is inline HTML, a local file, or a URL rendered with headless Chrome. XPath supports / and // steps, *, and [n], [last()], [@a], [@a='v'], [contains(@a,'v')], [starts-with(@a,'v')] predicates, with an optional trailing /@attr or /text().".to_string(),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "html": { "type": "string", "description": "HTML text to search (or use path or url)" },
+                    "path": { "type": "string", "description": "Path to an HTML file" },
+                    "url": { "type": "string", "description": "http(s) URL to render with headless Chrome" },
+                    "css": { "type": "string", "description": "CSS selector (e.g. 'table.results td')" },
+                    "xpath": { "type": "string", "description": "XPath location path (e.g. \"//a[contains(@class,'title')]/@href\"); use instead of css" },
+                    "attribute": { "type": "string", "description": "Return this attribute's value instead of the element; elements without it are skipped" },
+                    "format": { "type": "string", "enum": ["text", "markdown", "html", "json"], "description": "Output format (default: text)" },
+                    "limit": { "type": "integer", "description": "Maximum number of matches (default: 100)" },
+                    "timeout": { "type": "integer", "description": "Timeout in seconds for url sources (default: 30)" },
+                    "wait_for": { "type": "string", "description": "CSS selector to wait for before reading a url source" }
+                }
+            }),
+        },
+        Tool {
+            name: "md_normalize".to_string(),
+            description: "Clean up Markdown before publishing (e.g. to Confluence): reflow paragraphs and list items, convert setext headings to '#' and repair heading level jumps, unify bullet markers and renumber ordered lists, and convert links to inline or reference style. Code blocks, tables, block quotes, HTML, and front matter are left untouched. Returns the normalized Markdown, or writes it back to 'path' with 'write'.".to_string(),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "markdown": { "type": "string", "description": "Markdown text to normalize (or use path)" },
+                    "path": { "type": "string", "description": "Path to a Markdown file" },
+                    "width": { "type": "integer", "description": "Wrap width for paragraphs and list items; 0 puts each on one line (default: 0)" },
+                    "keep_line_breaks": { "type": "boolean", "description": "Keep the original line breaks instead of reflowing (default: false)" },
+                    "bullet": { "type": "string", "enum": ["-", "*", "+"], "description": "Marker for unordered list items (default: '-')" },
+                    "links": { "type": "string", "enum": ["keep", "inline", "reference"], "description": "Link style: keep as written, inline [text](url), or reference [text][n] (default: inline)" },
+                    "write": { "type": "boolean", "description": "Write the result back to 'path' (default: false)" }
+                }
+            }),
+        },
+        Tool {
+            name: "unified_search".to_string(),
+            description: "Search Jira issues, Confluence pages, Bitbucket code, HackerNews stories, and the local Atlas index concurrently and return one ranked list. Each result has its source, title, URL, and snippet; 'sources' reports per-source counts and why a source was skipped (not configured) or failed, so one unavailable source never fails the search.".to_string(),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "query": { "type": "string", "description": "Free-text query" },
+                    "sources": {
+                        "type": "array",
+                        "items": { "type": "string", "enum": ["jira", "confluence", "bitbucket", "hn", "local"] },
+                        "description": "Sources to search (default: all)"
+                    },
+                    "limit": { "type": "integer", "description": "Maximum results after merging (default: 20)" },
+                    "per_source": { "type": "integer", "description": "Results requested from each source (default: 10)" },
+                    "workspace": { "type": "string", "description": "Bitbucket workspace for code search (default: BITBUCKET_WORKSPACE)" },
+                    "repo_path": { "type": "string", "description": "Repository whose Atlas index is searched (default: '.')" },
+                    "timeout": { "type": "integer", "description": "Seconds to wait for each source (default: 20)" }
+                },
+                "required": ["query"]
+            }),
+        },
     ];
 
+    if mail::mail_tools_enabled() {
+        tools.extend(mail::mail_tools());
+    }
+
     let result = ToolsList { tools };
 
     serde_json::to_value(result).map_err(|e| JsonRpcError {
@@ -1036,11 +2038,61 @@ pub async fn handle_tools_call(
             data: None,
         })?;
 
+    let mut params = params;
+    match take_credential_overrides(&mut params.arguments)? {
+        Some(overrides) => {
+            crate::atlassian::with_credential_overrides(overrides, dispatch_tool(params, global))
+                .await
+        }
+        None => dispatch_tool(params, global).await,
+    }
+}
+
+/// Remove `_credentials` from the tool arguments and parse it.
+///
+/// Overrides are rejected unless the server was started with
+/// `--allow-credential-overrides`.
+fn take_credential_overrides(
+    arguments: &mut Option<serde_json::Value>,
+) -> Result<Option<crate::atlassian::CredentialOverrides>, JsonRpcError> {
+    let Some(value) = arguments
+        .as_mut()
+        .and_then(|a| a.as_object_mut())
+        .and_then(|a| a.remove("_credentials"))
+    else {
+        return Ok(None);
+    };
+
+    if !super::credential_overrides_allowed() {
+        return Err(JsonRpcError {
+            code: -32602,
+            message: "Credential overrides are disabled; start the server with --allow-credential-overrides to accept '_credentials'".to_string(),
+            data: None,
+        });
+    }
+
+    serde_json::from_value(value)
+        .map(Some)
+        .map_err(|e| JsonRpcError {
+            code: -32602,
+            message: format!("Invalid _credentials: {e}"),
+            data: None,
+        })
+}
+
+async fn dispatch_tool(
+    params: CallToolParams,
+    global: &crate::Global,
+) -> Result<serde_json::Value, JsonRpcError> {
     match params.name.as_str() {
         "jira_search" => atlassian::handle_jira_search(params.arguments, global).await,
         "jira_create" => atlassian::handle_jira_create(params.arguments, global).await,
         "jira_get" => atlassian::handle_jira_get(params.arguments, global).await,
+        "jira_history" => atlassian::handle_jira_history(params.arguments, global).await,
         "jira_update" => atlassian::handle_jira_update(params.arguments, global).await,
+        "jira_transitions_list" => {
+            atlassian::handle_jira_transitions_list(params.arguments, global).await
+        }
         "jira_comment_add" => atlassian::handle_jira_comment_add(params.arguments, global).await,
         "jira_comment_list" => atlassian::handle_jira_comment_list(params.arguments, global).await,
         "jira_comment_update" => {
@@ -1049,6 +2101,15 @@ pub async fn handle_tools_call(
         "jira_comment_delete" => {
             atlassian::handle_jira_comment_delete(params.arguments, global).await
         }
+        "jira_epic" => atlassian::handle_jira_epic(params.arguments, global).await,
+        "jira_link" => atlassian::handle_jira_link(params.arguments, global).await,
+        "jira_watch" => atlassian::handle_jira_watch(params.arguments, global).await,
+        "jira_worklog_add" => atlassian::handle_jira_worklog_add(params.arguments, global).await,
+        "jira_worklog_list" => atlassian::handle_jira_worklog_list(params.arguments, global).await,
+        "jira_bulk_comment" => atlassian::handle_jira_bulk_comment(params.arguments, global).await,
+        "jira_field_list" => atlassian::handle_jira_field_list(params.arguments, global).await,
+        "jira_board_list" => atlassian::handle_jira_board_list(params.arguments, global).await,
+        "jira_bulk_update" => atlassian::handle_jira_bulk_update(params.arguments, global).await,
         "jira_sprint_list" => atlassian::handle_jira_sprint_list(params.arguments, global).await,
         "jira_attachment_list" => {
             atlassian::handle_jira_attachment_list(params.arguments, global).await
@@ -1079,10 +2140,20 @@ pub async fn handle_tools_call(
             atlassian::handle_bitbucket_repo_branches(params.arguments, global).await
         }
         "hn_read_item" => hn::handle_hn_read_item(params.arguments, global).await,
+        "hn_read_article" => hn::handle_hn_read_article(params.arguments, global).await,
         "hn_list_items" => hn::handle_hn_list_items(params.arguments, global).await,
+        "hn_search" => hn::handle_hn_search(params.arguments, global).await,
+        "hn_user" => hn::handle_hn_user(params.arguments, global).await,
+        "mail_search" => mail::handle_mail_search(params.arguments, global).await,
+        "mail_read" => mail::handle_mail_read(params.arguments, global).await,
         "md_fetch" => md::handle_md_fetch(params.arguments, global).await,
         "md_toc" => md::handle_md_toc(params.arguments, global).await,
+        "md_grep" => md::handle_md_grep(params.arguments, global).await,
+        "md_screenshot" => md::handle_md_screenshot(params.arguments, global).await,
+        "youtube_transcript" => md::handle_youtube_transcript(params.arguments, global).await,
         "generate_code" => strand::handle_generate_code(params.arguments, global).await,
+        "describe_image" => vision::handle_describe_image(params.arguments, global).await,
+        "transcribe_audio" => transcribe::handle_transcribe_audio(params.arguments, global).await,
         "greprag_retrieve" => greprag::handle_greprag_retrieve(params.arguments, global).await,
         "ui_annotations_list" => {
             annotations::handle_ui_annotations_list(params.arguments, global).await
@@ -1101,10 +2172,23 @@ pub async fn handle_tools_call(
         "pdf_peek" => pdf::handle_pdf_peek(params.arguments, global).await,
         "pdf_images" => pdf::handle_pdf_images(params.arguments, global).await,
         "pdf_image" => pdf::handle_pdf_image(params.arguments, global).await,
+        "pdf_thumbnail" => pdf::handle_pdf_thumbnail(params.arguments, global).await,
         "pdf_info" => pdf::handle_pdf_info(params.arguments, global).await,
         "atlas_tree_view" => atlas::handle_atlas_tree_view(params.arguments, global).await,
         "atlas_peek" => atlas::handle_atlas_peek(params.arguments, global).await,
         "atlas_status" => atlas::handle_atlas_status(params.arguments, global).await,
+        "calendar_events" => cal::handle_calendar_events(params.arguments, global).await,
+        "text_diff" => text::handle_text_diff(params.arguments, global).await,
+        "text_patch_apply" => text::handle_text_patch_apply(params.arguments, global).await,
+        "regex_extract" => text::handle_regex_extract(params.arguments, global).await,
+        "hash" => text::handle_hash(params.arguments, global).await,
+        "encode" => text::handle_encode(params.arguments, global).await,
+        "generate_id" => generate::handle_generate_id(params.arguments, global).await,
+        "render_template" => template::handle_render_template(params.arguments, global).await,
+        "json_query" => text::handle_json_query(params.arguments, global).await,
+        "html_select" => html::handle_html_select(params.arguments, global).await,
+        "md_normalize" => md::handle_md_normalize(params.arguments, global).await,
+        "unified_search" => search::handle_unified_search(params.arguments, global).await,
         _ => Err(JsonRpcError {
             code: -32602,
             message: format!("Unknown tool: {}", params.name),
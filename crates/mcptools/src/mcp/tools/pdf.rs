@@ -1,4 +1,5 @@
 use super::{CallToolResult, Content, JsonRpcError};
+use base64::Engine;
 use serde::Deserialize;
 
 // ---------------------------------------------------------------------------
@@ -50,7 +51,7 @@ where
 
 /// Parse an optional section ID string.
 fn parse_section_id(s: Option<&str>) -> Result<Option<pdf::SectionId>, String> {
-    s.map(|id| pdf::SectionId::parse(id).map_err(|e| format!("Invalid section ID: {e}")))
+    s.map(|id| pdf::SectionId::parse_or_slug(id).map_err(|e| format!("Invalid section ID: {e}")))
         .transpose()
 }
 
@@ -65,17 +66,42 @@ pub async fn handle_pdf_toc(
     #[derive(Deserialize)]
     struct Args {
         path: String,
+        output: Option<String>,
     }
 
     let args: Args = parse_args(arguments)?;
 
+    let render: Option<fn(&pdf::DocumentTree) -> String> = match args.output.as_deref() {
+        None | Some("json") => None,
+        Some("indented") => Some(pdf::render::toc::render_toc_indented),
+        Some("markdown") => Some(pdf::render::toc::render_toc_markdown),
+        Some(other) => {
+            return Err(JsonRpcError {
+                code: INVALID_PARAMS,
+                message: format!(
+                    "Invalid output format: '{other}'. Must be 'indented', 'markdown', or 'json'"
+                ),
+                data: None,
+            })
+        }
+    };
+
     let tree = run_blocking(move || {
-        let bytes = std::fs::read(&args.path).map_err(|e| format!("Failed to read file: {e}"))?;
+        let bytes = pdf::map_file(&args.path).map_err(|e| format!("Failed to read file: {e}"))?;
         pdf::parse(&bytes).map_err(|e| format!("PDF error: {e}"))
     })
     .await?;
 
-    to_text_result(&tree)
+    match render {
+        Some(render) => serde_json::to_value(CallToolResult {
+            content: vec![Content::Text {
+                text: render(&tree),
+            }],
+            is_error: None,
+        })
+        .map_err(|e| internal_err(format!("Internal error: {e}"))),
+        None => to_text_result(&tree),
+    }
 }
 
 pub async fn handle_pdf_read(
@@ -92,7 +118,7 @@ pub async fn handle_pdf_read(
     let args: Args = parse_args(arguments)?;
 
     let content = run_blocking(move || {
-        let bytes = std::fs::read(&args.path).map_err(|e| format!("Failed to read file: {e}"))?;
+        let bytes = pdf::map_file(&args.path).map_err(|e| format!("Failed to read file: {e}"))?;
         let id = parse_section_id(args.section_id.as_deref())?;
         pdf::read_section(&bytes, id.as_ref()).map_err(|e| format!("PDF error: {e}"))
     })
@@ -117,7 +143,7 @@ pub async fn handle_pdf_peek(
     let args: Args = parse_args(arguments)?;
 
     let content = run_blocking(move || {
-        let bytes = std::fs::read(&args.path).map_err(|e| format!("Failed to read file: {e}"))?;
+        let bytes = pdf::map_file(&args.path).map_err(|e| format!("Failed to read file: {e}"))?;
         let position: pdf::PeekPosition =
             args.position
                 .as_deref()
@@ -149,7 +175,7 @@ pub async fn handle_pdf_images(
     let args: Args = parse_args(arguments)?;
 
     let images = run_blocking(move || {
-        let bytes = std::fs::read(&args.path).map_err(|e| format!("Failed to read file: {e}"))?;
+        let bytes = pdf::map_file(&args.path).map_err(|e| format!("Failed to read file: {e}"))?;
         let id = parse_section_id(args.section_id.as_deref())?;
         pdf::list_section_images(&bytes, id.as_ref()).map_err(|e| format!("PDF error: {e}"))
     })
@@ -187,7 +213,7 @@ pub async fn handle_pdf_image(
     }
 
     let result = run_blocking(move || {
-        let bytes = std::fs::read(&args.path).map_err(|e| format!("Failed to read file: {e}"))?;
+        let bytes = pdf::map_file(&args.path).map_err(|e| format!("Failed to read file: {e}"))?;
 
         let image_id = if let Some(id_str) = args.image_id {
             pdf::ImageId::new(id_str)
@@ -204,7 +230,6 @@ pub async fn handle_pdf_image(
         };
 
         let img = pdf::get_image(&bytes, &image_id).map_err(|e| format!("PDF error: {e}"))?;
-        use base64::Engine;
         Ok(serde_json::json!({
             "id": image_id.as_str(),
             "format": format!("{}", img.format),
@@ -217,6 +242,50 @@ pub async fn handle_pdf_image(
     to_text_result(&result)
 }
 
+pub async fn handle_pdf_thumbnail(
+    arguments: Option<serde_json::Value>,
+    _global: &crate::Global,
+) -> Result<serde_json::Value, JsonRpcError> {
+    #[derive(Deserialize)]
+    struct Args {
+        path: String,
+        page: Option<usize>,
+        #[serde(rename = "sectionId")]
+        section_id: Option<String>,
+        size: Option<u32>,
+    }
+
+    let args: Args = parse_args(arguments)?;
+
+    if args.page.is_some() && args.section_id.is_some() {
+        return Err(JsonRpcError {
+            code: INVALID_PARAMS,
+            message: "Cannot specify both page and sectionId".to_string(),
+            data: None,
+        });
+    }
+
+    let img = run_blocking(move || {
+        let bytes = pdf::map_file(&args.path).map_err(|e| format!("Failed to read file: {e}"))?;
+        let size = args.size.unwrap_or(pdf::thumbnail::DEFAULT_THUMBNAIL_SIZE);
+        match parse_section_id(args.section_id.as_deref())? {
+            Some(id) => pdf::render_section_thumbnail(&bytes, &id, size),
+            None => pdf::render_page_thumbnail(&bytes, args.page.unwrap_or(1), size),
+        }
+        .map_err(|e| format!("PDF error: {e}"))
+    })
+    .await?;
+
+    serde_json::to_value(CallToolResult {
+        content: vec![Content::Image {
+            data: base64::engine::general_purpose::STANDARD.encode(&img.bytes),
+            mime_type: "image/png".to_string(),
+        }],
+        is_error: None,
+    })
+    .map_err(|e| internal_err(format!("Internal error: {e}")))
+}
+
 pub async fn handle_pdf_info(
     arguments: Option<serde_json::Value>,
     _global: &crate::Global,
@@ -224,15 +293,24 @@ pub async fn handle_pdf_info(
     #[derive(Deserialize)]
     struct Args {
         path: String,
+        #[serde(default)]
+        stats: bool,
     }
 
     let args: Args = parse_args(arguments)?;
 
-    let metadata = run_blocking(move || {
-        let bytes = std::fs::read(&args.path).map_err(|e| format!("Failed to read file: {e}"))?;
-        pdf::info(&bytes).map_err(|e| format!("PDF error: {e}"))
+    let info = run_blocking(move || {
+        let bytes = pdf::map_file(&args.path).map_err(|e| format!("Failed to read file: {e}"))?;
+        let value = if args.stats {
+            pdf::stats(&bytes).map(serde_json::to_value)
+        } else {
+            pdf::info(&bytes).map(serde_json::to_value)
+        };
+        value
+            .map_err(|e| format!("PDF error: {e}"))?
+            .map_err(|e| format!("Serialization error: {e}"))
     })
     .await?;
 
-    to_text_result(&metadata)
+    to_text_result(&info)
 }
@@ -0,0 +1,76 @@
+use serde::Deserialize;
+
+use super::{CallToolResult, Content, JsonRpcError};
+use crate::search::{SearchAllParams, Source};
+
+pub async fn handle_unified_search(
+    arguments: Option<serde_json::Value>,
+    global: &crate::Global,
+) -> Result<serde_json::Value, JsonRpcError> {
+    #[derive(Deserialize)]
+    struct UnifiedSearchArgs {
+        query: String,
+        #[serde(default)]
+        sources: Vec<Source>,
+        limit: Option<usize>,
+        per_source: Option<usize>,
+        workspace: Option<String>,
+        repo_path: Option<String>,
+        timeout: Option<u64>,
+    }
+
+    let args: UnifiedSearchArgs =
+        serde_json::from_value(arguments.unwrap_or(serde_json::Value::Null)).map_err(|e| {
+            JsonRpcError {
+                code: -32602,
+                message: format!("Invalid arguments: {e}"),
+                data: None,
+            }
+        })?;
+
+    if args.query.trim().is_empty() {
+        return Err(JsonRpcError {
+            code: -32602,
+            message: "'query' cannot be empty".to_string(),
+            data: None,
+        });
+    }
+
+    if global.verbose {
+        anstream::eprintln!("Calling unified_search: query='{}'", args.query);
+    }
+
+    let output = crate::search::search_all_data(SearchAllParams {
+        query: args.query,
+        sources: args.sources.into_iter().map(Into::into).collect(),
+        limit: args.limit.unwrap_or(20),
+        per_source: args.per_source.unwrap_or(10),
+        workspace: args
+            .workspace
+            .or_else(|| std::env::var("BITBUCKET_WORKSPACE").ok()),
+        repo_path: args.repo_path.unwrap_or_else(|| ".".to_string()).into(),
+        timeout: args.timeout.unwrap_or(20),
+    })
+    .await
+    .map_err(|e| JsonRpcError {
+        code: -32603,
+        message: format!("Search failed: {e}"),
+        data: None,
+    })?;
+
+    let text = serde_json::to_string_pretty(&output).map_err(|e| JsonRpcError {
+        code: -32603,
+        message: format!("Failed to serialize result: {e}"),
+        data: None,
+    })?;
+
+    serde_json::to_value(CallToolResult {
+        content: vec![Content::Text { text }],
+        is_error: None,
+    })
+    .map_err(|e| JsonRpcError {
+        code: -32603,
+        message: format!("Internal error: {e}"),
+        data: None,
+    })
+}
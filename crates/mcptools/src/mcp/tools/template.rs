@@ -0,0 +1,54 @@
+use serde::Deserialize;
+
+use super::{CallToolResult, Content, JsonRpcError};
+use mcptools_core::hbs::render_template;
+
+pub async fn handle_render_template(
+    arguments: Option<serde_json::Value>,
+    global: &crate::Global,
+) -> Result<serde_json::Value, JsonRpcError> {
+    #[derive(Deserialize)]
+    #[serde(rename_all = "camelCase")]
+    struct RenderTemplateArgs {
+        template: String,
+        #[serde(default)]
+        context: serde_json::Value,
+        #[serde(default)]
+        strict: bool,
+    }
+
+    let args: RenderTemplateArgs =
+        serde_json::from_value(arguments.unwrap_or(serde_json::Value::Null)).map_err(|e| {
+            JsonRpcError {
+                code: -32602,
+                message: format!("Invalid arguments: {e}"),
+                data: None,
+            }
+        })?;
+
+    if global.verbose {
+        anstream::eprintln!(
+            "Calling render_template: {} bytes, strict={}",
+            args.template.len(),
+            args.strict
+        );
+    }
+
+    // Template errors are caller mistakes, reported as invalid params.
+    let text =
+        render_template(&args.template, &args.context, args.strict).map_err(|e| JsonRpcError {
+            code: -32602,
+            message: e.to_string(),
+            data: None,
+        })?;
+
+    serde_json::to_value(CallToolResult {
+        content: vec![Content::Text { text }],
+        is_error: None,
+    })
+    .map_err(|e| JsonRpcError {
+        code: -32603,
+        message: format!("Internal error: {e}"),
+        data: None,
+    })
+}
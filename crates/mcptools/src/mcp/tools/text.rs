@@ -0,0 +1,342 @@
+use std::time::{Duration, Instant};
+
+use serde::Deserialize;
+
+use super::{CallToolResult, Content, JsonRpcError};
+use mcptools_core::codec::{hash, transcode, Encoding, HashAlgorithm};
+use mcptools_core::diff::{apply_patch, parse_patch, unified_diff};
+use mcptools_core::extract::{build_regex, extract_matches, RegexFlags};
+use mcptools_core::jq::{format_results, parse_inputs, Budget, JqError, Query};
+
+/// Time one regex_extract scan may take; the scan checks it between matches
+/// and stops with an error once it passes.
+const REGEX_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Time one json_query call may take across all its inputs; the jq
+/// evaluator checks it as it runs and stops with an error once it passes.
+const QUERY_TIMEOUT: Duration = Duration::from_secs(10);
+
+fn invalid(message: String) -> JsonRpcError {
+    JsonRpcError {
+        code: -32602,
+        message,
+        data: None,
+    }
+}
+
+fn execution_error(message: String) -> JsonRpcError {
+    JsonRpcError {
+        code: -32603,
+        message,
+        data: None,
+    }
+}
+
+fn json_result(value: &impl serde::Serialize) -> Result<serde_json::Value, JsonRpcError> {
+    let text = serde_json::to_string_pretty(value)
+        .map_err(|e| execution_error(format!("Serialization error: {e}")))?;
+    serde_json::to_value(CallToolResult {
+        content: vec![Content::Text { text }],
+        is_error: None,
+    })
+    .map_err(|e| execution_error(format!("Internal error: {e}")))
+}
+
+/// Resolve a side given either inline text or a file path (exactly one).
+async fn text_or_file(
+    text: Option<String>,
+    path: Option<String>,
+    text_name: &str,
+    path_name: &str,
+) -> Result<(String, Option<String>), JsonRpcError> {
+    match (text, path) {
+        (Some(text), None) => Ok((text, None)),
+        (None, Some(path)) => {
+            let text = tokio::fs::read_to_string(&path)
+                .await
+                .map_err(|e| execution_error(format!("Failed to read {path}: {e}")))?;
+            Ok((text, Some(path)))
+        }
+        (Some(_), Some(_)) => Err(invalid(format!(
+            "Provide either '{text_name}' or '{path_name}', not both"
+        ))),
+        (None, None) => Err(invalid(format!("Missing '{text_name}' or '{path_name}'"))),
+    }
+}
+
+pub async fn handle_text_diff(
+    arguments: Option<serde_json::Value>,
+    global: &crate::Global,
+) -> Result<serde_json::Value, JsonRpcError> {
+    #[derive(Deserialize)]
+    #[serde(rename_all = "camelCase")]
+    struct TextDiffArgs {
+        old: Option<String>,
+        new: Option<String>,
+        old_path: Option<String>,
+        new_path: Option<String>,
+        context: Option<usize>,
+    }
+
+    let args: TextDiffArgs = serde_json::from_value(arguments.unwrap_or(serde_json::json!({})))
+        .map_err(|e| invalid(format!("Invalid arguments: {e}")))?;
+
+    let (old, old_path) = text_or_file(args.old, args.old_path, "old", "oldPath").await?;
+    let (new, new_path) = text_or_file(args.new, args.new_path, "new", "newPath").await?;
+
+    if global.verbose {
+        anstream::eprintln!("Calling text_diff: {} -> {} bytes", old.len(), new.len());
+    }
+
+    let old_name = old_path.map_or_else(|| "old".to_string(), |p| format!("a/{p}"));
+    let new_name = new_path.map_or_else(|| "new".to_string(), |p| format!("b/{p}"));
+    let output = unified_diff(&old, &new, &old_name, &new_name, args.context.unwrap_or(3));
+
+    json_result(&output)
+}
+
+pub async fn handle_text_patch_apply(
+    arguments: Option<serde_json::Value>,
+    global: &crate::Global,
+) -> Result<serde_json::Value, JsonRpcError> {
+    #[derive(Deserialize)]
+    #[serde(rename_all = "camelCase")]
+    struct TextPatchApplyArgs {
+        patch: String,
+        text: Option<String>,
+        path: Option<String>,
+        #[serde(default)]
+        write: bool,
+    }
+
+    let args: TextPatchApplyArgs =
+        serde_json::from_value(arguments.unwrap_or(serde_json::Value::Null))
+            .map_err(|e| invalid(format!("Invalid arguments: {e}")))?;
+
+    if args.write && args.path.is_none() {
+        return Err(invalid("'write' requires 'path'".to_string()));
+    }
+
+    let hunks = parse_patch(&args.patch).map_err(|e| invalid(format!("Invalid patch: {e}")))?;
+    let (text, path) = text_or_file(args.text, args.path, "text", "path").await?;
+
+    if global.verbose {
+        anstream::eprintln!(
+            "Calling text_patch_apply: {} hunks, path={:?}, write={}",
+            hunks.len(),
+            path,
+            args.write
+        );
+    }
+
+    let output = apply_patch(&text, &hunks).map_err(execution_error)?;
+
+    match path.filter(|_| args.write) {
+        Some(path) => {
+            tokio::fs::write(&path, &output.text)
+                .await
+                .map_err(|e| execution_error(format!("Failed to write {path}: {e}")))?;
+            json_result(&serde_json::json!({
+                "path": path,
+                "written": true,
+                "hunks_applied": output.hunks_applied,
+                "offsets": output.offsets,
+            }))
+        }
+        None => json_result(&output),
+    }
+}
+
+pub async fn handle_regex_extract(
+    arguments: Option<serde_json::Value>,
+    global: &crate::Global,
+) -> Result<serde_json::Value, JsonRpcError> {
+    #[derive(Deserialize)]
+    #[serde(rename_all = "camelCase")]
+    struct RegexExtractArgs {
+        pattern: String,
+        text: Option<String>,
+        path: Option<String>,
+        flags: Option<String>,
+        max_matches: Option<usize>,
+    }
+
+    let args: RegexExtractArgs =
+        serde_json::from_value(arguments.unwrap_or(serde_json::Value::Null))
+            .map_err(|e| invalid(format!("Invalid arguments: {e}")))?;
+
+    let flags: RegexFlags = args
+        .flags
+        .as_deref()
+        .unwrap_or_default()
+        .parse()
+        .map_err(invalid)?;
+    let regex = build_regex(&args.pattern, flags).map_err(invalid)?;
+    let (text, _) = text_or_file(args.text, args.path, "text", "path").await?;
+    let max_matches = args.max_matches.unwrap_or(100);
+
+    if global.verbose {
+        anstream::eprintln!(
+            "Calling regex_extract: pattern='{}', {} bytes",
+            args.pattern,
+            text.len()
+        );
+    }
+
+    let deadline = Instant::now() + REGEX_TIMEOUT;
+    let output = tokio::task::spawn_blocking(move || {
+        extract_matches(&regex, &text, max_matches, Some(deadline))
+    })
+    .await
+    .map_err(|e| execution_error(format!("Task join error: {e}")))?
+    .map_err(|e| {
+        if Instant::now() >= deadline {
+            execution_error(format!(
+                "regex_extract timed out after {}s: {e}",
+                REGEX_TIMEOUT.as_secs()
+            ))
+        } else {
+            invalid(e)
+        }
+    })?;
+
+    json_result(&output)
+}
+
+pub async fn handle_hash(
+    arguments: Option<serde_json::Value>,
+    global: &crate::Global,
+) -> Result<serde_json::Value, JsonRpcError> {
+    #[derive(Deserialize)]
+    #[serde(rename_all = "camelCase")]
+    struct HashArgs {
+        text: Option<String>,
+        path: Option<String>,
+        algorithm: Option<String>,
+    }
+
+    let args: HashArgs = serde_json::from_value(arguments.unwrap_or(serde_json::Value::Null))
+        .map_err(|e| invalid(format!("Invalid arguments: {e}")))?;
+
+    let algorithm: HashAlgorithm = args
+        .algorithm
+        .as_deref()
+        .unwrap_or("sha256")
+        .parse()
+        .map_err(invalid)?;
+
+    // Files are hashed as raw bytes so binary content is supported.
+    let data = match (args.text, args.path) {
+        (Some(text), None) => text.into_bytes(),
+        (None, Some(path)) => tokio::fs::read(&path)
+            .await
+            .map_err(|e| execution_error(format!("Failed to read {path}: {e}")))?,
+        (Some(_), Some(_)) => {
+            return Err(invalid(
+                "Provide either 'text' or 'path', not both".to_string(),
+            ))
+        }
+        (None, None) => return Err(invalid("Missing 'text' or 'path'".to_string())),
+    };
+
+    if global.verbose {
+        anstream::eprintln!(
+            "Calling hash: algorithm={}, {} bytes",
+            algorithm.as_str(),
+            data.len()
+        );
+    }
+
+    json_result(&hash(&data, algorithm))
+}
+
+pub async fn handle_encode(
+    arguments: Option<serde_json::Value>,
+    global: &crate::Global,
+) -> Result<serde_json::Value, JsonRpcError> {
+    #[derive(Deserialize)]
+    #[serde(rename_all = "camelCase")]
+    struct EncodeArgs {
+        encoding: String,
+        text: Option<String>,
+        path: Option<String>,
+        #[serde(default)]
+        decode: bool,
+    }
+
+    let args: EncodeArgs = serde_json::from_value(arguments.unwrap_or(serde_json::Value::Null))
+        .map_err(|e| invalid(format!("Invalid arguments: {e}")))?;
+
+    let encoding: Encoding = args.encoding.parse().map_err(invalid)?;
+    let (text, _) = text_or_file(args.text, args.path, "text", "path").await?;
+
+    if global.verbose {
+        anstream::eprintln!(
+            "Calling encode: encoding={}, decode={}, {} bytes",
+            encoding.as_str(),
+            args.decode,
+            text.len()
+        );
+    }
+
+    let output = transcode(&text, encoding, args.decode).map_err(invalid)?;
+    json_result(&output)
+}
+
+pub async fn handle_json_query(
+    arguments: Option<serde_json::Value>,
+    global: &crate::Global,
+) -> Result<serde_json::Value, JsonRpcError> {
+    #[derive(Deserialize)]
+    #[serde(rename_all = "camelCase")]
+    struct JsonQueryArgs {
+        query: String,
+        json: Option<String>,
+        path: Option<String>,
+        #[serde(default)]
+        slurp: bool,
+        #[serde(default)]
+        raw: bool,
+        #[serde(default)]
+        compact: bool,
+    }
+
+    let args: JsonQueryArgs = serde_json::from_value(arguments.unwrap_or(serde_json::Value::Null))
+        .map_err(|e| invalid(format!("Invalid arguments: {e}")))?;
+
+    let query = Query::parse(&args.query).map_err(|e| invalid(e.to_string()))?;
+    let (text, _) = text_or_file(args.json, args.path, "json", "path").await?;
+
+    if global.verbose {
+        anstream::eprintln!(
+            "Calling json_query: query='{}', {} bytes",
+            args.query,
+            text.len()
+        );
+    }
+
+    let budget = Budget::with_timeout(QUERY_TIMEOUT);
+    let text = tokio::task::spawn_blocking(move || {
+        let mut inputs = parse_inputs(&text).map_err(invalid)?;
+        if args.slurp {
+            inputs = vec![serde_json::Value::Array(inputs)];
+        }
+        let mut results = Vec::new();
+        for input in &inputs {
+            let output = query.run_within(input, budget).map_err(|e| match e {
+                JqError::Aborted(message) => execution_error(message),
+                other => invalid(other.to_string()),
+            })?;
+            results.extend(output);
+        }
+        Ok(format_results(&results, args.raw, args.compact))
+    })
+    .await
+    .map_err(|e| execution_error(format!("Task join error: {e}")))??;
+
+    serde_json::to_value(CallToolResult {
+        content: vec![Content::Text { text }],
+        is_error: None,
+    })
+    .map_err(|e| execution_error(format!("Internal error: {e}")))
+}
@@ -0,0 +1,110 @@
+use serde::Deserialize;
+
+use super::{CallToolResult, Content, JsonRpcError};
+use crate::transcribe::{TranscribeConfig, DEFAULT_TRANSCRIBE_MODEL, DEFAULT_TRANSCRIBE_URL};
+use mcptools_core::transcribe::{render_markdown, MergeOptions, TranscribeApi};
+
+pub async fn handle_transcribe_audio(
+    arguments: Option<serde_json::Value>,
+    global: &crate::Global,
+) -> Result<serde_json::Value, JsonRpcError> {
+    #[derive(Deserialize)]
+    #[serde(rename_all = "camelCase")]
+    struct TranscribeAudioArgs {
+        path: String,
+        endpoint: Option<String>,
+        api: Option<String>,
+        model: Option<String>,
+        language: Option<String>,
+        max_gap: Option<f64>,
+        max_paragraph: Option<f64>,
+        format: Option<String>,
+    }
+
+    let invalid = |message: String| JsonRpcError {
+        code: -32602,
+        message,
+        data: None,
+    };
+
+    let args: TranscribeAudioArgs =
+        serde_json::from_value(arguments.unwrap_or(serde_json::Value::Null))
+            .map_err(|e| invalid(format!("Invalid arguments: {e}")))?;
+
+    let api: TranscribeApi = args
+        .api
+        .or_else(|| std::env::var("TRANSCRIBE_API").ok())
+        .map(|api| api.parse())
+        .transpose()
+        .map_err(invalid)?
+        .unwrap_or_default();
+
+    let json = match args.format.as_deref() {
+        None | Some("markdown") => false,
+        Some("json") => true,
+        Some(other) => {
+            return Err(invalid(format!(
+                "Invalid format '{other}' (expected 'markdown' or 'json')"
+            )))
+        }
+    };
+
+    let defaults = MergeOptions::default();
+    let options = MergeOptions {
+        max_gap: args.max_gap.unwrap_or(defaults.max_gap),
+        max_duration: args.max_paragraph.unwrap_or(defaults.max_duration),
+    };
+
+    let config = TranscribeConfig {
+        endpoint: args
+            .endpoint
+            .or_else(|| std::env::var("TRANSCRIBE_URL").ok())
+            .unwrap_or_else(|| DEFAULT_TRANSCRIBE_URL.to_string()),
+        api,
+        model: args
+            .model
+            .or_else(|| std::env::var("TRANSCRIBE_MODEL").ok())
+            .unwrap_or_else(|| DEFAULT_TRANSCRIBE_MODEL.to_string()),
+        api_key: std::env::var("TRANSCRIBE_API_KEY").ok(),
+        language: args.language,
+        timeout: 600,
+    };
+
+    if global.verbose {
+        anstream::eprintln!(
+            "Calling transcribe_audio: path='{}', endpoint='{}', api={:?}",
+            args.path,
+            config.endpoint,
+            config.api
+        );
+    }
+
+    let transcript =
+        crate::transcribe::transcribe_file(std::path::Path::new(&args.path), &config, &options)
+            .await
+            .map_err(|e| JsonRpcError {
+                code: -32603,
+                message: format!("Tool execution error: {e}"),
+                data: None,
+            })?;
+
+    let text = if json {
+        serde_json::to_string_pretty(&transcript).map_err(|e| JsonRpcError {
+            code: -32603,
+            message: format!("Serialization error: {e}"),
+            data: None,
+        })?
+    } else {
+        render_markdown(&transcript)
+    };
+
+    serde_json::to_value(CallToolResult {
+        content: vec![Content::Text { text }],
+        is_error: None,
+    })
+    .map_err(|e| JsonRpcError {
+        code: -32603,
+        message: format!("Internal error: {e}"),
+        data: None,
+    })
+}
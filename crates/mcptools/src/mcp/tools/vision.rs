@@ -0,0 +1,101 @@
+use serde::Deserialize;
+
+use super::{CallToolResult, Content, JsonRpcError};
+use crate::vision::VisionModelArgs;
+use mcptools_core::vision::{DescribeMode, ImageDescription, DEFAULT_VISION_MODEL};
+
+pub async fn handle_describe_image(
+    arguments: Option<serde_json::Value>,
+    global: &crate::Global,
+) -> Result<serde_json::Value, JsonRpcError> {
+    #[derive(Deserialize)]
+    #[serde(rename_all = "camelCase")]
+    struct DescribeImageArgs {
+        path: String,
+        image_id: Option<String>,
+        #[serde(default)]
+        alt_text: bool,
+        prompt: Option<String>,
+        model: Option<String>,
+        ollama_url: Option<String>,
+    }
+
+    let args: DescribeImageArgs =
+        serde_json::from_value(arguments.unwrap_or(serde_json::Value::Null)).map_err(|e| {
+            JsonRpcError {
+                code: -32602,
+                message: format!("Invalid arguments: {e}"),
+                data: None,
+            }
+        })?;
+
+    let model = VisionModelArgs {
+        ollama_url: args
+            .ollama_url
+            .or_else(|| std::env::var("OLLAMA_URL").ok())
+            .unwrap_or_else(|| "http://localhost:11434".to_string()),
+        vision_model: args
+            .model
+            .or_else(|| std::env::var("VISION_MODEL").ok())
+            .unwrap_or_else(|| DEFAULT_VISION_MODEL.to_string()),
+    };
+    let mode = if args.alt_text {
+        DescribeMode::AltText
+    } else {
+        DescribeMode::Description
+    };
+
+    if global.verbose {
+        anstream::eprintln!(
+            "Calling describe_image: path='{}', image_id={:?}, model='{}'",
+            args.path,
+            args.image_id,
+            model.vision_model
+        );
+    }
+
+    let exec_err = |e: color_eyre::eyre::Report| JsonRpcError {
+        code: -32603,
+        message: format!("Tool execution error: {e}"),
+        data: None,
+    };
+
+    let path = args.path;
+    let image_id = args.image_id;
+    let (source, bytes) = tokio::task::spawn_blocking(move || {
+        crate::vision::load_image(std::path::Path::new(&path), image_id.as_deref())
+    })
+    .await
+    .map_err(|e| JsonRpcError {
+        code: -32603,
+        message: format!("Task join error: {e}"),
+        data: None,
+    })?
+    .map_err(exec_err)?;
+
+    let description =
+        crate::vision::describe_image_data(&bytes, &model, mode, args.prompt.as_deref())
+            .await
+            .map_err(exec_err)?;
+
+    let output = ImageDescription {
+        source,
+        model: model.vision_model,
+        description,
+    };
+    let text = serde_json::to_string_pretty(&output).map_err(|e| JsonRpcError {
+        code: -32603,
+        message: format!("Serialization error: {e}"),
+        data: None,
+    })?;
+
+    serde_json::to_value(CallToolResult {
+        content: vec![Content::Text { text }],
+        is_error: None,
+    })
+    .map_err(|e| JsonRpcError {
+        code: -32603,
+        message: format!("Internal error: {e}"),
+        data: None,
+    })
+}
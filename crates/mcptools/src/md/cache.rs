@@ -0,0 +1,57 @@
+use crate::prelude::{eprintln, *};
+use std::path::PathBuf;
+
+use mcptools_core::md::{is_cache_fresh, CachedPage, FetchCacheKey};
+use mcptools_core::state::StateComponent;
+
+use super::FetchConfig;
+
+/// Cache file for `config`, keyed on everything that changes the converted
+/// page. Pagination is applied after the cache, so it is not part of the key.
+fn entry_path(config: &FetchConfig) -> Result<PathBuf> {
+    let strategy = config.strategy.clone().into();
+    let key = FetchCacheKey {
+        url: &config.url,
+        selector: config.selector.as_deref(),
+        strategy: &strategy,
+        index: config.index,
+        raw_html: config.raw_html,
+        metadata: config.metadata,
+        wait_for: config.wait_for.as_deref(),
+        scroll: config.scroll,
+        headers: &config.headers,
+        cookies: &config.cookies,
+        user_agent: config.user_agent.as_deref(),
+        no_browser: config.no_browser,
+        conversion: config.conversion,
+    };
+    let dir = crate::state::component_dir(StateComponent::Cache)?.join("md");
+    Ok(dir.join(format!("{}.json", key.digest())))
+}
+
+/// Return the cached page for `config` if caching is enabled and the entry
+/// is younger than the TTL. Unreadable entries count as misses.
+pub(super) fn load(config: &FetchConfig) -> Option<CachedPage> {
+    let ttl = config.cache_ttl.filter(|ttl| *ttl > 0)?;
+    let path = entry_path(config).ok()?;
+    let content = std::fs::read_to_string(path).ok()?;
+    let page: CachedPage = serde_json::from_str(&content).ok()?;
+    is_cache_fresh(page.fetched_at, chrono::Utc::now().timestamp(), ttl).then_some(page)
+}
+
+/// Write `page` to the cache. Failures only warn; the fetch already succeeded.
+pub(super) fn store(config: &FetchConfig, page: &CachedPage) {
+    if config.cache_ttl.unwrap_or(0) == 0 {
+        return;
+    }
+    let result = entry_path(config).and_then(|path| {
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        std::fs::write(&path, serde_json::to_vec(page)?)?;
+        Ok(())
+    });
+    if let Err(e) = result {
+        eprintln!("Warning: failed to write md cache: {}", e);
+    }
+}
@@ -0,0 +1,223 @@
+use crate::prelude::{eprintln, println, *};
+use mcptools_core::crawl::{
+    extract_links, normalize_url, page_file_path, same_origin, CrawlError, CrawlManifest, CrawlPage,
+};
+use std::collections::HashSet;
+use std::path::PathBuf;
+
+use super::{FetchConfig, SelectionStrategy};
+
+#[derive(Debug, clap::Args, Clone)]
+pub struct CrawlOptions {
+    /// URL to start crawling from
+    #[arg(env = "MD_URL")]
+    pub url: String,
+
+    /// How many links deep to follow from the start page (0 fetches only the start page)
+    #[arg(long, env = "MD_CRAWL_DEPTH", default_value = "1")]
+    pub depth: usize,
+
+    /// Only follow links with the same scheme, host, and port as the start page
+    #[arg(long)]
+    pub same_origin: bool,
+
+    /// Directory to write the Markdown snapshot to
+    #[arg(long, short, value_name = "DIR")]
+    pub out: PathBuf,
+
+    /// Stop after this many pages
+    #[arg(long, env = "MD_CRAWL_MAX_PAGES", default_value = "100")]
+    pub max_pages: usize,
+
+    /// Maximum pages fetched at once
+    #[arg(long, env = "MD_CONCURRENCY", default_value = "4")]
+    pub concurrency: usize,
+
+    /// Crawl pages that robots.txt disallows
+    #[arg(long)]
+    pub ignore_robots: bool,
+
+    #[command(flatten)]
+    pub network: super::NetworkArgs,
+
+    #[command(flatten)]
+    pub browser: super::BrowserArgs,
+
+    #[command(flatten)]
+    pub conversion: super::ConversionArgs,
+
+    /// Timeout in seconds per page (default: 30)
+    #[arg(short, long, env = "MD_TIMEOUT", default_value = "30")]
+    pub timeout: u64,
+
+    /// CSS selector to filter content (optional). Links are only followed
+    /// from the selected content
+    #[arg(long, env = "MD_SELECTOR")]
+    pub selector: Option<String>,
+
+    /// Strategy for selecting elements when multiple match (default: first)
+    #[arg(long, env = "MD_STRATEGY", default_value = "first")]
+    pub strategy: SelectionStrategy,
+
+    /// Index for 'n' strategy (0-indexed)
+    #[arg(long, env = "MD_INDEX")]
+    pub index: Option<usize>,
+}
+
+pub async fn crawl(options: CrawlOptions) -> Result<()> {
+    use futures::StreamExt;
+
+    if matches!(options.strategy, SelectionStrategy::N) && options.index.is_none() {
+        return Err(eyre!(
+            "Strategy 'n' requires --index parameter to specify which element to select"
+        ));
+    }
+
+    let root = normalize_url(&options.url).map_err(|e| eyre!(e))?;
+    let mut base_config = FetchConfig {
+        url: root.to_string(),
+        timeout: options.timeout,
+        selector: options.selector.clone(),
+        strategy: options.strategy.clone(),
+        index: options.index,
+        limit: usize::MAX,
+        conversion: (&options.conversion).into(),
+        ..FetchConfig::from_args(&options.network, &options.browser)?
+    };
+
+    std::fs::create_dir_all(&options.out)
+        .map_err(|e| eyre!("Failed to create {}: {}", options.out.display(), e))?;
+
+    base_config.no_browser = super::use_http_fetch(options.browser.no_browser);
+    let browser = if base_config.no_browser {
+        None
+    } else {
+        let proxy = options.network.proxy.clone();
+        Some(tokio::task::spawn_blocking(move || super::launch_browser(proxy.as_deref())).await??)
+    };
+
+    let mut manifest = CrawlManifest {
+        root: root.to_string(),
+        max_depth: options.depth,
+        same_origin: options.same_origin,
+        pages: Vec::new(),
+        errors: Vec::new(),
+        truncated: false,
+    };
+    let mut seen: HashSet<String> = HashSet::from([root.to_string()]);
+    let mut frontier = vec![root.to_string()];
+    let mut robots = super::robots::RobotsChecker::new(options.network.user_agent.as_deref());
+
+    for depth in 0..=options.depth {
+        if !options.ignore_robots {
+            let mut allowed = Vec::with_capacity(frontier.len());
+            for url in std::mem::take(&mut frontier) {
+                if robots.is_allowed(&url).await {
+                    allowed.push(url);
+                } else {
+                    manifest.errors.push(CrawlError {
+                        url,
+                        depth,
+                        error: super::robots::DISALLOWED.to_string(),
+                    });
+                }
+            }
+            frontier = allowed;
+        }
+
+        let budget = options
+            .max_pages
+            .saturating_sub(manifest.pages.len() + manifest.errors.len());
+        if frontier.len() > budget {
+            frontier.truncate(budget);
+            manifest.truncated = true;
+        }
+        if frontier.is_empty() {
+            break;
+        }
+        eprintln!("Depth {}: fetching {} pages", depth, frontier.len());
+
+        let mut results = futures::stream::iter(std::mem::take(&mut frontier))
+            .map(|url| {
+                let browser = browser.clone();
+                let config = FetchConfig {
+                    url: url.clone(),
+                    ..base_config.clone()
+                };
+                async move {
+                    let result = tokio::task::spawn_blocking(move || {
+                        match &browser {
+                            Some(browser) => super::fetch_with_browser(browser, config),
+                            None => super::fetch_and_convert_data(config),
+                        }
+                        .map_err(|e| e.to_string())
+                    })
+                    .await
+                    .unwrap_or_else(|e| Err(format!("Task join error: {}", e)));
+                    (url, result)
+                }
+            })
+            .buffered(options.concurrency.max(1));
+
+        while let Some((url, result)) = results.next().await {
+            let output = match result {
+                Ok(output) => output,
+                Err(error) => {
+                    eprintln!("Failed {}: {}", url, error);
+                    manifest.errors.push(CrawlError { url, depth, error });
+                    continue;
+                }
+            };
+
+            let page_url = normalize_url(&url).map_err(|e| eyre!(e))?;
+            let links = extract_links(&output.content, &page_url);
+            if depth < options.depth {
+                for link in &links {
+                    let Ok(link_url) = normalize_url(link) else {
+                        continue;
+                    };
+                    if options.same_origin && !same_origin(&root, &link_url) {
+                        continue;
+                    }
+                    if seen.insert(link.clone()) {
+                        frontier.push(link.clone());
+                    }
+                }
+            }
+
+            let file = page_file_path(&page_url);
+            let path = options.out.join(&file);
+            if let Some(parent) = path.parent() {
+                std::fs::create_dir_all(parent)?;
+            }
+            std::fs::write(&path, &output.content)
+                .map_err(|e| eyre!("Failed to write {}: {}", path.display(), e))?;
+
+            manifest.pages.push(CrawlPage {
+                url,
+                depth,
+                file,
+                title: output.title,
+                links_found: links.len(),
+            });
+        }
+    }
+
+    let manifest_path = options.out.join("crawl.json");
+    std::fs::write(&manifest_path, serde_json::to_string_pretty(&manifest)?)
+        .map_err(|e| eyre!("Failed to write {}: {}", manifest_path.display(), e))?;
+
+    println!(
+        "Crawled {} pages into {} ({} failed{})",
+        manifest.pages.len(),
+        options.out.display(),
+        manifest.errors.len(),
+        if manifest.truncated {
+            ", stopped at --max-pages"
+        } else {
+            ""
+        }
+    );
+
+    Ok(())
+}
@@ -0,0 +1,147 @@
+use crate::prelude::{eprintln, println, *};
+use colored::Colorize;
+use std::path::PathBuf;
+
+use mcptools_core::md::{
+    diff_snapshots, format_page_diff_summary, snapshot_key, PageDiff, PageSnapshot,
+};
+use mcptools_core::state::StateComponent;
+
+use super::{fetch_and_convert_data, FetchConfig, SelectionStrategy};
+
+#[derive(Debug, clap::Args, Clone)]
+pub struct DiffOptions {
+    /// URL of the page to compare (a file:// URL converts saved HTML)
+    #[arg(env = "MD_URL")]
+    pub url: String,
+
+    /// CSS selector to compare only part of the page; snapshots are kept
+    /// per URL and selector
+    #[arg(long, env = "MD_SELECTOR")]
+    pub selector: Option<String>,
+
+    /// Unchanged lines shown around each change
+    #[arg(long, default_value = "3")]
+    pub context: usize,
+
+    /// Output as JSON
+    #[arg(long)]
+    pub json: bool,
+
+    /// Compare without replacing the stored snapshot
+    #[arg(long)]
+    pub no_save: bool,
+
+    /// Exit with status 1 when the page changed, for cron jobs and scripts
+    #[arg(long)]
+    pub exit_code: bool,
+
+    /// Timeout in seconds (default: 30)
+    #[arg(short, long, env = "MD_TIMEOUT", default_value = "30")]
+    pub timeout: u64,
+
+    #[command(flatten)]
+    pub network: super::NetworkArgs,
+
+    #[command(flatten)]
+    pub browser: super::BrowserArgs,
+}
+
+pub(super) fn snapshot_path(url: &str, selector: Option<&str>) -> Result<PathBuf> {
+    let dir = crate::state::component_dir(StateComponent::Snapshots)?.join("md");
+    Ok(dir.join(format!("{}.json", snapshot_key(url, selector))))
+}
+
+pub(super) fn load_snapshot(path: &PathBuf) -> Result<Option<PageSnapshot>> {
+    if !path.exists() {
+        return Ok(None);
+    }
+    let content = std::fs::read_to_string(path)
+        .map_err(|e| eyre!("Failed to read snapshot {}: {}", path.display(), e))?;
+    let snapshot = serde_json::from_str(&content)
+        .map_err(|e| eyre!("Corrupt snapshot {}: {}", path.display(), e))?;
+    Ok(Some(snapshot))
+}
+
+pub(super) fn save_snapshot(path: &PathBuf, snapshot: &PageSnapshot) -> Result<()> {
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    std::fs::write(path, serde_json::to_vec_pretty(snapshot)?)
+        .map_err(|e| eyre!("Failed to write snapshot {}: {}", path.display(), e))
+}
+
+/// Print the summary and the colored unified diff.
+pub(super) fn print_page_diff(result: &PageDiff) {
+    println!("{}", format_page_diff_summary(result).bold());
+    if result.diff.is_empty() {
+        return;
+    }
+    println!();
+    for line in result.diff.lines() {
+        let line = if line.starts_with("+++") || line.starts_with("---") {
+            line.bold()
+        } else if line.starts_with('+') {
+            line.green()
+        } else if line.starts_with('-') {
+            line.red()
+        } else if line.starts_with("@@") {
+            line.cyan()
+        } else {
+            line.normal()
+        };
+        println!("{}", line);
+    }
+}
+
+pub async fn diff(options: DiffOptions) -> Result<()> {
+    let config = FetchConfig {
+        url: options.url.clone(),
+        timeout: options.timeout,
+        selector: options.selector.clone(),
+        // A diff is only meaningful against a fresh fetch.
+        cache_ttl: None,
+        ..FetchConfig::from_args(&options.network, &options.browser)?
+    };
+
+    let fetched = tokio::task::spawn_blocking(move || fetch_and_convert_data(config)).await??;
+    let current = PageSnapshot {
+        url: options.url.clone(),
+        selector: options.selector.clone(),
+        title: fetched.title,
+        content: fetched.content,
+        fetched_at: chrono::Utc::now().timestamp(),
+    };
+
+    let path = snapshot_path(&options.url, options.selector.as_deref())?;
+    let Some(previous) = load_snapshot(&path)? else {
+        if options.no_save {
+            return Err(eyre!(
+                "No snapshot of {} yet; run once without --no-save to record one",
+                options.url
+            ));
+        }
+        save_snapshot(&path, &current)?;
+        eprintln!(
+            "No previous snapshot of {}; saved a baseline to compare against next time",
+            options.url
+        );
+        return Ok(());
+    };
+
+    let result = diff_snapshots(&previous, &current, options.context);
+    if !options.no_save && !result.identical {
+        save_snapshot(&path, &current)?;
+    }
+
+    if options.json {
+        println!("{}", serde_json::to_string_pretty(&result)?);
+    } else {
+        print_page_diff(&result);
+    }
+
+    if options.exit_code && !result.identical {
+        std::process::exit(1);
+    }
+    Ok(())
+}
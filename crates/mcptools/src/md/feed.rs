@@ -0,0 +1,141 @@
+use crate::prelude::{eprintln, println, *};
+use mcptools_core::md::{discover_feed_links, format_feed_digest, parse_feed, Feed};
+use std::collections::{HashMap, HashSet};
+
+use super::{FetchConfig, SelectionStrategy};
+
+#[derive(Debug, clap::Args, Clone)]
+pub struct FeedOptions {
+    /// RSS or Atom feed URL, or a page that links to one
+    #[arg(env = "MD_FEED_URL")]
+    pub url: String,
+
+    /// Keep at most this many entries, in feed order
+    #[arg(long)]
+    pub limit: Option<usize>,
+
+    /// Fetch each entry's link and include the converted article instead of
+    /// the feed summary
+    #[arg(long)]
+    pub fetch: bool,
+
+    /// Print the feed and its entries as JSON instead of a Markdown digest
+    #[arg(long)]
+    pub json: bool,
+
+    /// Maximum articles fetched at once with --fetch
+    #[arg(long, env = "MD_CONCURRENCY", default_value = "4")]
+    pub concurrency: usize,
+
+    /// With --fetch, also fetch articles that robots.txt disallows
+    #[arg(long)]
+    pub ignore_robots: bool,
+
+    /// Timeout in seconds for the feed and each article (default: 30)
+    #[arg(short, long, env = "MD_TIMEOUT", default_value = "30")]
+    pub timeout: u64,
+
+    /// CSS selector to filter fetched articles (optional)
+    #[arg(long, env = "MD_SELECTOR")]
+    pub selector: Option<String>,
+
+    #[command(flatten)]
+    pub network: super::NetworkArgs,
+
+    #[command(flatten)]
+    pub browser: super::BrowserArgs,
+}
+
+async fn fetch_text(client: &reqwest::Client, url: &str) -> Result<String> {
+    let response = client
+        .get(url)
+        .send()
+        .await
+        .map_err(|e| eyre!("Failed to fetch {}: {}", url, e))?;
+    if !response.status().is_success() {
+        return Err(eyre!("Failed to fetch {}: HTTP {}", url, response.status()));
+    }
+    Ok(response.text().await?)
+}
+
+/// Read the feed at `url`. An HTML page is searched for a
+/// `<link rel="alternate">` feed, and the first one found is read instead.
+async fn load_feed(client: &reqwest::Client, url: &str) -> Result<Feed> {
+    let content = fetch_text(client, url).await?;
+    let error = match parse_feed(&content) {
+        Ok(feed) => return Ok(feed),
+        Err(e) => e,
+    };
+    let Some(feed_url) = discover_feed_links(&content, url).into_iter().next() else {
+        return Err(eyre!("{}: {}", url, error));
+    };
+    eprintln!("Reading the feed linked from the page: {}", feed_url);
+    let content = fetch_text(client, &feed_url).await?;
+    parse_feed(&content).map_err(|e| eyre!("{}: {}", feed_url, e))
+}
+
+pub async fn feed(options: FeedOptions) -> Result<()> {
+    let base_config = FetchConfig {
+        timeout: options.timeout,
+        selector: options.selector.clone(),
+        ..FetchConfig::from_args(&options.network, &options.browser)?
+    };
+    let client = super::sitemap::build_client(
+        base_config.timeout,
+        base_config.proxy.as_deref(),
+        base_config.user_agent.as_deref(),
+        &base_config.headers,
+        &base_config.cookies,
+    )?;
+
+    let mut feed = load_feed(&client, &options.url).await?;
+    if let Some(limit) = options.limit {
+        feed.entries.truncate(limit);
+    }
+
+    let mut seen = HashSet::new();
+    let links: Vec<String> = feed
+        .entries
+        .iter()
+        .filter_map(|entry| entry.link.clone())
+        .filter(|link| seen.insert(link.clone()))
+        .collect();
+    if options.fetch && !links.is_empty() {
+        let config = FetchConfig {
+            url: links[0].clone(),
+            ..base_config
+        };
+
+        let mut articles = HashMap::new();
+        super::fetch::fetch_each(
+            links,
+            config,
+            options.concurrency,
+            options.ignore_robots,
+            |url, result| {
+                match result {
+                    Ok(output) => {
+                        articles.insert(url, output.content);
+                    }
+                    Err(e) => eprintln!("Using the summary for {}: {}", url, e),
+                }
+                Ok(())
+            },
+        )
+        .await?;
+        for entry in &mut feed.entries {
+            entry.article = entry
+                .link
+                .as_ref()
+                .and_then(|link| articles.get(link).cloned());
+        }
+    }
+
+    if options.json {
+        println!("{}", serde_json::to_string_pretty(&feed)?);
+    } else {
+        println!("{}", format_feed_digest(&feed).trim_end());
+    }
+
+    Ok(())
+}
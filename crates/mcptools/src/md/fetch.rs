@@ -6,9 +6,21 @@ use super::{fetch_and_convert_data, FetchOutput, SelectionStrategy};
 
 #[derive(Debug, clap::Args, serde::Deserialize, Clone)]
 pub struct FetchOptions {
-    /// URL to fetch
-    #[arg(env = "MD_URL")]
-    pub url: String,
+    /// URL(s) to fetch. More than one URL, or --urls-file, fetches in batch
+    /// mode and prints one JSON record per line. A file:// URL or '-' (stdin)
+    /// converts saved HTML without launching the browser
+    #[arg(env = "MD_URL", value_name = "URL")]
+    #[serde(default)]
+    pub urls: Vec<String>,
+
+    /// Read URLs from a file, one per line ('-' for stdin; '#' starts a comment)
+    #[arg(long, value_name = "PATH")]
+    pub urls_file: Option<String>,
+
+    /// Maximum pages fetched at once in batch mode
+    #[arg(long, env = "MD_CONCURRENCY", default_value = "4")]
+    #[serde(default = "default_concurrency")]
+    pub concurrency: usize,
 
     /// Timeout in seconds (default: 30)
     #[arg(short, long, env = "MD_TIMEOUT", default_value = "30")]
@@ -26,6 +38,37 @@ pub struct FetchOptions {
     #[arg(long)]
     pub include_metadata: bool,
 
+    /// Include page metadata: canonical URL, description, OpenGraph, Twitter
+    /// cards, and JSON-LD
+    #[arg(long)]
+    pub meta: bool,
+
+    /// Prepend YAML frontmatter (url, title, fetch time, selector, pagination)
+    /// to the Markdown, for Obsidian and static-site pipelines
+    #[arg(long, conflicts_with = "json")]
+    pub frontmatter: bool,
+
+    /// Download the images in the selected content into DIR and rewrite the
+    /// Markdown image links to point at the local copies
+    #[arg(long, value_name = "DIR", conflicts_with = "raw_html")]
+    pub download_images: Option<std::path::PathBuf>,
+
+    /// Fill in missing alt text for the downloaded images with a local
+    /// vision model
+    #[arg(long, requires = "download_images")]
+    #[serde(default)]
+    pub describe_images: bool,
+
+    #[command(flatten)]
+    #[serde(flatten)]
+    pub vision: crate::vision::VisionModelArgs,
+
+    /// Write each page to DIR under a path derived from its URL
+    /// (host/path/page.md) with a .json metadata file next to it, and print
+    /// the file names instead of the content
+    #[arg(long, value_name = "DIR")]
+    pub out_dir: Option<std::path::PathBuf>,
+
     /// CSS selector to filter content (optional)
     #[arg(long, env = "MD_SELECTOR")]
     pub selector: Option<String>,
@@ -38,6 +81,95 @@ pub struct FetchOptions {
     #[arg(long, env = "MD_INDEX")]
     pub index: Option<usize>,
 
+    #[command(flatten)]
+    #[serde(flatten)]
+    pub network: super::NetworkArgs,
+
+    #[command(flatten)]
+    #[serde(flatten)]
+    pub browser: super::BrowserArgs,
+
+    /// JSON file of steps (goto, type, click, wait) run in the browser
+    /// before loading the URL, to get past a form login. `${VAR}` in values
+    /// reads the environment. Disables the response cache
+    #[arg(long, value_name = "FILE", env = "MD_LOGIN_SCRIPT")]
+    pub login_script: Option<std::path::PathBuf>,
+
+    /// Record every network request and response of the page load (and
+    /// login script) to a HAR file, to see why content is missing.
+    /// Disables the response cache
+    #[arg(long, value_name = "FILE")]
+    pub har: Option<std::path::PathBuf>,
+
+    /// Return only the section under the heading best matching this title
+    /// (case, punctuation, and small typos are ignored); pagination flags
+    /// then apply within the section
+    #[arg(long, conflicts_with = "raw_html")]
+    pub heading: Option<String>,
+
+    /// Scroll to the bottom this many times, waiting for the network to go
+    /// quiet after each pass, before extracting content (infinite-scroll pages)
+    #[arg(long, value_name = "N", env = "MD_SCROLL")]
+    pub scroll: Option<usize>,
+
+    /// Keep scrolling until the page stops growing (at most 50 passes, or
+    /// --scroll N when given)
+    #[arg(long)]
+    #[serde(default)]
+    pub scroll_until_idle: bool,
+
+    /// After the page loads, wait until no request has been in flight for
+    /// this long so XHR-rendered content is present (0 disables)
+    #[arg(long, value_name = "MS", env = "MD_IDLE_MS", default_value = "500")]
+    #[serde(default = "default_idle_ms")]
+    pub idle_ms: u64,
+
+    /// Longest to wait for the network to go idle before extracting anyway
+    #[arg(
+        long,
+        value_name = "SECONDS",
+        env = "MD_IDLE_TIMEOUT",
+        default_value = "10"
+    )]
+    #[serde(default = "default_idle_timeout")]
+    pub idle_timeout: u64,
+
+    /// Fetch URLs that robots.txt disallows (batch mode checks robots.txt
+    /// by default)
+    #[arg(long)]
+    #[serde(default)]
+    pub ignore_robots: bool,
+
+    #[command(flatten)]
+    #[serde(flatten)]
+    pub conversion: super::ConversionArgs,
+
+    /// Reuse a cached conversion younger than this many seconds (0 disables)
+    #[arg(
+        long,
+        value_name = "SECONDS",
+        env = "MD_CACHE_TTL",
+        default_value = "3600"
+    )]
+    #[serde(default = "default_cache_ttl")]
+    pub cache_ttl: u64,
+
+    /// Bypass the response cache: always fetch, and don't store the result
+    #[arg(long)]
+    #[serde(default)]
+    pub no_cache: bool,
+
+    /// Retries after transient browser errors (tab creation, navigation,
+    /// content extraction)
+    #[arg(long, env = "MD_RETRIES", default_value = "2")]
+    #[serde(default = "default_retries")]
+    pub retries: u32,
+
+    /// Base delay before the first retry; doubles on each further retry
+    #[arg(long, value_name = "MS", env = "MD_RETRY_DELAY", default_value = "500")]
+    #[serde(default = "default_retry_delay")]
+    pub retry_delay: u64,
+
     /// Enable pagination (automatically enabled when --offset, --limit, or --page are set)
     #[arg(long, env = "MD_PAGINATED")]
     pub paginated: bool,
@@ -55,6 +187,60 @@ pub struct FetchOptions {
     pub page: Option<usize>,
 }
 
+fn default_concurrency() -> usize {
+    4
+}
+
+fn default_cache_ttl() -> u64 {
+    3600
+}
+
+fn default_idle_ms() -> u64 {
+    500
+}
+
+fn default_idle_timeout() -> u64 {
+    10
+}
+
+fn default_retries() -> u32 {
+    2
+}
+
+fn default_retry_delay() -> u64 {
+    500
+}
+
+/// Collect positional URLs and `--urls-file` entries, dropping duplicates.
+fn collect_urls(options: &FetchOptions) -> Result<Vec<String>> {
+    use std::io::Read;
+
+    let mut text = options.urls.join("\n");
+    if let Some(path) = &options.urls_file {
+        let contents = if path == "-" {
+            let mut buffer = String::new();
+            std::io::stdin().read_to_string(&mut buffer)?;
+            buffer
+        } else {
+            std::fs::read_to_string(path)
+                .map_err(|e| eyre!("Failed to read URLs file {}: {}", path, e))?
+        };
+        text.push('\n');
+        text.push_str(&contents);
+    }
+
+    let urls = mcptools_core::md::parse_url_list(&text);
+    if urls.is_empty() {
+        return Err(eyre!("No URL given. Pass one or more URLs or --urls-file"));
+    }
+    if options.urls_file.as_deref() == Some("-") && urls.iter().any(|u| u == "-") {
+        return Err(eyre!(
+            "Stdin can hold either the URL list or the HTML, not both; save the HTML and pass a file:// path"
+        ));
+    }
+    Ok(urls)
+}
+
 pub async fn fetch(options: FetchOptions) -> Result<()> {
     // Validate strategy and index combination
     if matches!(options.strategy, SelectionStrategy::N) && options.index.is_none() {
@@ -63,31 +249,122 @@ pub async fn fetch(options: FetchOptions) -> Result<()> {
         ));
     }
 
+    let mut urls = collect_urls(&options)?;
+    let batch = urls.len() > 1 || options.urls_file.is_some();
+    if batch && options.frontmatter {
+        return Err(eyre!(
+            "--frontmatter applies to a single page; batch mode prints JSON records"
+        ));
+    }
+    if batch && options.har.is_some() {
+        return Err(eyre!(
+            "--har records a single page load; fetch pages one at a time"
+        ));
+    }
+    if batch && options.download_images.is_some() {
+        return Err(eyre!(
+            "--download-images applies to a single page; fetch pages one at a time"
+        ));
+    }
+
+    let login = options
+        .login_script
+        .as_ref()
+        .map(|path| {
+            let script = std::fs::read_to_string(path)
+                .map_err(|e| eyre!("Failed to read login script {}: {}", path.display(), e))?;
+            mcptools_core::md::parse_login_script(&script, |name| std::env::var(name).ok())
+                .map_err(|e| eyre!("{}: {}", path.display(), e))
+        })
+        .transpose()?;
+
     // Auto-enable pagination if any pagination-related flag is set
     let paginated = options.paginated
         || options.offset.is_some()
         || options.limit.is_some()
         || options.page.is_some();
 
+    let config = super::FetchConfig {
+        url: urls.remove(0),
+        timeout: options.timeout,
+        raw_html: options.raw_html,
+        selector: options.selector.clone(),
+        strategy: options.strategy.clone(),
+        index: options.index,
+        offset: options.offset.unwrap_or(0),
+        limit: options.limit.unwrap_or(1000),
+        page: options.page.unwrap_or(1),
+        paginated,
+        metadata: options.meta,
+        scroll: mcptools_core::md::ScrollPlan::from_options(
+            options.scroll,
+            options.scroll_until_idle,
+        ),
+        // Pages behind a login are never written to the shared cache, and a
+        // HAR needs a real page load.
+        cache_ttl: (!options.no_cache && login.is_none() && options.har.is_none())
+            .then_some(options.cache_ttl),
+        retry: mcptools_core::md::RetryPolicy {
+            max_retries: options.retries,
+            base_delay_ms: options.retry_delay,
+            ..Default::default()
+        },
+        heading: options.heading.clone(),
+        conversion: (&options.conversion).into(),
+        login,
+        har: options.har.clone(),
+        network_idle: mcptools_core::md::NetworkIdlePolicy {
+            idle_ms: options.idle_ms,
+            max_wait_ms: options.idle_timeout.saturating_mul(1000),
+        },
+        ..super::FetchConfig::from_args(&options.network, &options.browser)?
+    };
+
+    if batch {
+        urls.insert(0, config.url.clone());
+        return fetch_batch(
+            urls,
+            config,
+            options.concurrency,
+            options.ignore_robots,
+            options.out_dir.clone(),
+        )
+        .await;
+    }
+
+    let (image_headers, image_cookies) = (config.headers.clone(), config.cookies.clone());
+
     // Use spawn_blocking since headless_chrome is synchronous
-    let output = tokio::task::spawn_blocking({
-        let options = options.clone();
-        move || {
-            fetch_and_convert_data(super::FetchConfig {
-                url: options.url,
-                timeout: options.timeout,
-                raw_html: options.raw_html,
-                selector: options.selector,
-                strategy: options.strategy,
-                index: options.index,
-                offset: options.offset.unwrap_or(0),
-                limit: options.limit.unwrap_or(1000),
-                page: options.page.unwrap_or(1),
-                paginated,
-            })
+    let mut output = tokio::task::spawn_blocking(move || fetch_and_convert_data(config)).await??;
+
+    if let Some(dir) = &options.download_images {
+        output.content = super::images::download_images(
+            &output.content,
+            dir,
+            super::images::ImageRequestContext {
+                page_url: &output.url,
+                headers: &image_headers,
+                cookies: &image_cookies,
+                user_agent: options.network.user_agent.as_deref(),
+            },
+            options.describe_images.then_some(&options.vision),
+        )
+        .await?;
+    }
+
+    if let Some(dir) = &options.out_dir {
+        let frontmatter = options.frontmatter.then(|| {
+            let fetched_at = chrono::Utc::now().to_rfc3339_opts(chrono::SecondsFormat::Secs, true);
+            mcptools_core::md::render_frontmatter(&output, &fetched_at, paginated)
+        });
+        let saved = save_page(&output, dir, options.raw_html, paginated, frontmatter)?;
+        if options.json {
+            println!("{}", serde_json::to_string_pretty(&saved)?);
+        } else {
+            println!("{}", saved.file);
         }
-    })
-    .await??;
+        return Ok(());
+    }
 
     if options.json {
         output_json(&output, paginated)?;
@@ -98,6 +375,149 @@ pub async fn fetch(options: FetchOptions) -> Result<()> {
     Ok(())
 }
 
+/// Write the page content and its JSON metadata under `dir`, creating
+/// directories as needed. Existing files are overwritten.
+fn save_page(
+    output: &FetchOutput,
+    dir: &std::path::Path,
+    raw_html: bool,
+    paginated: bool,
+    frontmatter: Option<String>,
+) -> Result<mcptools_core::md::SavedPage> {
+    let path = dir.join(mcptools_core::md::output_file_path(&output.url, raw_html));
+    let metadata_path = path.with_extension("json");
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)
+            .map_err(|e| eyre!("Failed to create {}: {}", parent.display(), e))?;
+    }
+
+    let mut content = frontmatter.unwrap_or_default();
+    content.push_str(&output.content);
+    if !content.ends_with('\n') {
+        content.push('\n');
+    }
+    std::fs::write(&path, content)
+        .map_err(|e| eyre!("Failed to write {}: {}", path.display(), e))?;
+
+    let metadata = mcptools_core::md::saved_page_metadata(output, paginated);
+    std::fs::write(
+        &metadata_path,
+        serde_json::to_string_pretty(&metadata)? + "\n",
+    )
+    .map_err(|e| eyre!("Failed to write {}: {}", metadata_path.display(), e))?;
+
+    Ok(mcptools_core::md::SavedPage {
+        url: output.url.clone(),
+        title: output.title.clone(),
+        file: path.display().to_string(),
+        metadata_file: metadata_path.display().to_string(),
+    })
+}
+
+/// Fetch many URLs through one browser, at most `concurrency` tabs at a
+/// time, printing one JSON record per URL in input order. With `out_dir`,
+/// pages are written to files and the records name them instead.
+pub(super) async fn fetch_batch(
+    urls: Vec<String>,
+    config: super::FetchConfig,
+    concurrency: usize,
+    ignore_robots: bool,
+    out_dir: Option<std::path::PathBuf>,
+) -> Result<()> {
+    use mcptools_core::md::BatchFetchRecord;
+
+    let total = urls.len();
+    let (raw_html, paginated) = (config.raw_html, config.paginated);
+    let mut failed = 0;
+    fetch_each(urls, config, concurrency, ignore_robots, |url, result| {
+        let record = match (result, &out_dir) {
+            (Ok(output), Some(dir)) => match save_page(&output, dir, raw_html, paginated, None) {
+                Ok(page) => BatchFetchRecord::Saved { ok: true, page },
+                Err(e) => BatchFetchRecord::from_result(url, Err(e.to_string())),
+            },
+            (result, _) => BatchFetchRecord::from_result(url, result),
+        };
+        if matches!(record, BatchFetchRecord::Err { .. }) {
+            failed += 1;
+        }
+        println!("{}", serde_json::to_string(&record)?);
+        Ok(())
+    })
+    .await?;
+
+    if failed > 0 {
+        eprintln!("{} of {} URLs failed", failed, total);
+    }
+
+    Ok(())
+}
+
+/// Fetch `urls` up to `concurrency` at a time in one shared browser, passing
+/// each result to `on_page` in input order. URLs robots.txt disallows are
+/// reported as errors without being fetched unless `ignore_robots` is set.
+pub(super) async fn fetch_each(
+    urls: Vec<String>,
+    mut config: super::FetchConfig,
+    concurrency: usize,
+    ignore_robots: bool,
+    mut on_page: impl FnMut(String, std::result::Result<FetchOutput, String>) -> Result<()>,
+) -> Result<()> {
+    use futures::StreamExt;
+
+    super::check_browser_only(&config)?;
+    let mut allowed = Vec::with_capacity(urls.len());
+    let mut robots = super::robots::RobotsChecker::new(config.user_agent.as_deref());
+    for url in &urls {
+        allowed.push(ignore_robots || robots.is_allowed(url).await);
+    }
+
+    let proxy = config.proxy.clone();
+    // Local sources need no browser, so skip the launch when there is nothing else.
+    let browser = if urls
+        .iter()
+        .zip(&allowed)
+        .all(|(u, allowed)| !allowed || mcptools_core::md::parse_local_source(u).is_some())
+    {
+        None
+    } else if super::use_http_fetch(config.no_browser) {
+        config.no_browser = true;
+        None
+    } else {
+        Some(tokio::task::spawn_blocking(move || super::launch_browser(proxy.as_deref())).await??)
+    };
+
+    let mut results = futures::stream::iter(urls.into_iter().zip(allowed))
+        .map(|(url, allowed)| {
+            let browser = browser.clone();
+            let config = super::FetchConfig {
+                url: url.clone(),
+                ..config.clone()
+            };
+            async move {
+                if !allowed {
+                    return (url, Err(super::robots::DISALLOWED.to_string()));
+                }
+                let result = tokio::task::spawn_blocking(move || {
+                    match &browser {
+                        Some(browser) => super::fetch_with_browser(browser, config),
+                        None => fetch_and_convert_data(config),
+                    }
+                    .map_err(|e| e.to_string())
+                })
+                .await
+                .unwrap_or_else(|e| Err(format!("Task join error: {}", e)));
+                (url, result)
+            }
+        })
+        .buffered(concurrency.max(1));
+
+    while let Some((url, result)) = results.next().await {
+        on_page(url, result)?;
+    }
+
+    Ok(())
+}
+
 /// Formats output as JSON string
 fn format_output_json(output: &FetchOutput, paginated: bool) -> Result<String> {
     if paginated {
@@ -110,12 +530,17 @@ fn format_output_json(output: &FetchOutput, paginated: bool) -> Result<String> {
             content: &'a str,
             html_length: usize,
             fetch_time_ms: u64,
+            retries: u32,
             #[serde(skip_serializing_if = "Option::is_none")]
             selector_used: &'a Option<String>,
             #[serde(skip_serializing_if = "Option::is_none")]
+            heading: &'a Option<String>,
+            #[serde(skip_serializing_if = "Option::is_none")]
             elements_found: &'a Option<usize>,
             #[serde(skip_serializing_if = "Option::is_none")]
             strategy_applied: &'a Option<String>,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            metadata: &'a Option<mcptools_core::meta::PageMetadata>,
         }
 
         let output_without_pagination = OutputWithoutPagination {
@@ -124,9 +549,12 @@ fn format_output_json(output: &FetchOutput, paginated: bool) -> Result<String> {
             content: &output.content,
             html_length: output.html_length,
             fetch_time_ms: output.fetch_time_ms,
+            retries: output.retries,
             selector_used: &output.selector_used,
+            heading: &output.heading,
             elements_found: &output.elements_found,
             strategy_applied: &output.strategy_applied,
+            metadata: &output.metadata,
         };
 
         serde_json::to_string_pretty(&output_without_pagination)
@@ -190,6 +618,45 @@ fn format_output_text(output: &FetchOutput, options: &FetchOptions, paginated: b
         }
     }
 
+    if let Some(heading) = &output.heading {
+        result.push_str(&format!(
+            "{}: {}\n",
+            "Section".green(),
+            heading.bright_white().bold()
+        ));
+    }
+
+    // Page metadata (--meta)
+    if let Some(metadata) = &output.metadata {
+        if let Some(canonical) = &metadata.canonical_url {
+            result.push_str(&format!(
+                "{}: {}\n",
+                "Canonical URL".green(),
+                canonical.cyan()
+            ));
+        }
+        if let Some(description) = &metadata.description {
+            result.push_str(&format!(
+                "{}: {}\n",
+                "Description".green(),
+                description.bright_white()
+            ));
+        }
+        for (label, count) in [
+            ("OpenGraph", metadata.open_graph.len()),
+            ("Twitter Card", metadata.twitter.len()),
+            ("JSON-LD", metadata.json_ld.len()),
+        ] {
+            if count > 0 {
+                result.push_str(&format!(
+                    "{}: {}\n",
+                    label.green(),
+                    format!("{} entries (see --json)", count).bright_yellow()
+                ));
+            }
+        }
+    }
+
     // Metadata
     if options.include_metadata {
         result.push_str(&format!(
@@ -249,6 +716,13 @@ fn format_output_text(output: &FetchOutput, options: &FetchOptions, paginated: b
         "Fetch Time".green(),
         format!("{} ms", output.fetch_time_ms).bright_cyan().bold()
     ));
+    if output.retries > 0 {
+        result.push_str(&format!(
+            "{}: {}\n",
+            "Retries".green(),
+            output.retries.to_string().bright_cyan().bold()
+        ));
+    }
 
     // Usage help section
     result.push_str(&format!("\n{}\n", "=".repeat(80).bright_yellow()));
@@ -341,6 +815,14 @@ fn format_output_text(output: &FetchOutput, options: &FetchOptions, paginated: b
 fn output_formatted(output: &FetchOutput, options: &FetchOptions, paginated: bool) -> Result<()> {
     let is_tty = std::io::stdout().is_terminal();
 
+    if options.frontmatter {
+        let fetched_at = chrono::Utc::now().to_rfc3339_opts(chrono::SecondsFormat::Secs, true);
+        print!(
+            "{}",
+            mcptools_core::md::render_frontmatter(output, &fetched_at, paginated)
+        );
+    }
+
     if is_tty {
         // Print formatted metadata to stderr
         let formatted_metadata = format_output_text(output, options, paginated);
@@ -372,9 +854,11 @@ mod tests {
             content: "# Example\n\nThis is a test.".to_string(),
             html_length: 1234,
             fetch_time_ms: 500,
+            retries: 0,
             selector_used: Some("article".to_string()),
             elements_found: Some(1),
             strategy_applied: Some("first".to_string()),
+            heading: None,
             pagination: if paginated {
                 MdPaginationInfo {
                     current_page: 1,
@@ -392,19 +876,43 @@ mod tests {
                     has_more: false,
                 }
             },
+            metadata: None,
         }
     }
 
     fn create_test_options(include_metadata: bool, raw_html: bool) -> FetchOptions {
         FetchOptions {
-            url: "https://example.com".to_string(),
+            urls: vec!["https://example.com".to_string()],
+            urls_file: None,
+            concurrency: 4,
             timeout: 30,
             json: false,
             raw_html,
             include_metadata,
+            meta: false,
+            frontmatter: false,
+            download_images: None,
+            describe_images: false,
+            vision: Default::default(),
+            out_dir: None,
             selector: Some("article".to_string()),
             strategy: SelectionStrategy::First,
             index: None,
+            login_script: None,
+            har: None,
+            heading: None,
+            scroll: None,
+            scroll_until_idle: false,
+            idle_ms: 500,
+            idle_timeout: 10,
+            ignore_robots: false,
+            conversion: Default::default(),
+            cache_ttl: 3600,
+            no_cache: false,
+            retries: 2,
+            retry_delay: 500,
+            network: Default::default(),
+            browser: Default::default(),
             paginated: false,
             offset: None,
             limit: None,
@@ -0,0 +1,178 @@
+use crate::prelude::{println, *};
+use colored::Colorize;
+
+use mcptools_core::extract::{build_regex, RegexFlags};
+use mcptools_core::md::{grep_content, GrepOutput};
+
+use super::{fetch_and_convert_data, FetchConfig, SelectionStrategy};
+
+#[derive(Debug, clap::Args, serde::Deserialize, Clone)]
+pub struct GrepOptions {
+    /// URL of the page to search (a file:// URL searches saved HTML)
+    #[arg(env = "MD_URL")]
+    pub url: String,
+
+    /// Regular expression to search the converted Markdown for
+    pub pattern: String,
+
+    /// Regex flags: i (ignore case), m (multi-line), s (dot matches newline), x (verbose)
+    #[arg(long, default_value = "")]
+    #[serde(default)]
+    pub flags: String,
+
+    /// Characters of context shown on each side of a match
+    #[arg(short = 'C', long, default_value = "80")]
+    #[serde(default = "default_context")]
+    pub context: usize,
+
+    /// Stop after this many matches
+    #[arg(long, default_value = "100")]
+    #[serde(default = "default_max_matches")]
+    pub max_matches: usize,
+
+    /// CSS selector to search only part of the page (use the same one with
+    /// md fetch so offsets line up)
+    #[arg(long, env = "MD_SELECTOR")]
+    pub selector: Option<String>,
+
+    /// Strategy for selecting elements when multiple match (default: first)
+    #[arg(long, env = "MD_STRATEGY", default_value = "first")]
+    #[serde(default = "default_strategy")]
+    pub strategy: SelectionStrategy,
+
+    /// Index for 'n' strategy (0-indexed)
+    #[arg(long, env = "MD_INDEX")]
+    pub index: Option<usize>,
+
+    /// Output as JSON
+    #[arg(long)]
+    #[serde(default)]
+    pub json: bool,
+
+    /// Timeout in seconds (default: 30)
+    #[arg(short, long, env = "MD_TIMEOUT", default_value = "30")]
+    #[serde(default = "default_timeout")]
+    pub timeout: u64,
+
+    #[command(flatten)]
+    #[serde(flatten)]
+    pub network: super::NetworkArgs,
+
+    #[command(flatten)]
+    #[serde(flatten)]
+    pub browser: super::BrowserArgs,
+
+    /// Reuse a cached conversion younger than this many seconds (shared with
+    /// md fetch, so follow-up reads see the same content)
+    #[arg(
+        long,
+        value_name = "SECONDS",
+        env = "MD_CACHE_TTL",
+        default_value = "3600"
+    )]
+    #[serde(default = "default_cache_ttl")]
+    pub cache_ttl: u64,
+
+    /// Bypass the response cache: always fetch, and don't store the result
+    #[arg(long)]
+    #[serde(default)]
+    pub no_cache: bool,
+}
+
+fn default_context() -> usize {
+    80
+}
+
+fn default_max_matches() -> usize {
+    100
+}
+
+fn default_strategy() -> SelectionStrategy {
+    SelectionStrategy::First
+}
+
+fn default_timeout() -> u64 {
+    30
+}
+
+fn default_cache_ttl() -> u64 {
+    3600
+}
+
+pub async fn grep(options: GrepOptions) -> Result<()> {
+    let json = options.json;
+    let output = tokio::task::spawn_blocking(move || grep_page_data(options)).await??;
+
+    if json {
+        println!("{}", serde_json::to_string_pretty(&output)?);
+        return Ok(());
+    }
+
+    for m in &output.matches {
+        println!(
+            "{}:{}: {}{}{}",
+            m.line.to_string().green(),
+            m.offset.to_string().cyan(),
+            one_line(&m.before),
+            one_line(&m.text).red().bold(),
+            one_line(&m.after)
+        );
+    }
+
+    let summary = match (output.match_count, output.truncated) {
+        (0, _) => "No matches".to_string(),
+        (n, false) => format!("{} matches", n),
+        (n, true) => format!("First {} matches (more not shown; raise --max-matches)", n),
+    };
+    println!(
+        "\n{} in {} characters. Read around a match with: md fetch {} --offset <context_offset> --limit <context_limit>",
+        summary.bold(),
+        output.total_characters,
+        output.url
+    );
+
+    Ok(())
+}
+
+/// Fetch and convert the page, then search the whole Markdown (unpaginated).
+pub fn grep_page_data(options: GrepOptions) -> Result<GrepOutput> {
+    if matches!(options.strategy, SelectionStrategy::N) && options.index.is_none() {
+        return Err(eyre!(
+            "Strategy 'n' requires --index parameter to specify which element to select"
+        ));
+    }
+    let flags: RegexFlags = options.flags.parse().map_err(|e: String| eyre!(e))?;
+    let regex = build_regex(&options.pattern, flags).map_err(|e| eyre!(e))?;
+
+    let fetched = fetch_and_convert_data(FetchConfig {
+        url: options.url.clone(),
+        timeout: options.timeout,
+        selector: options.selector,
+        strategy: options.strategy,
+        index: options.index,
+        limit: usize::MAX,
+        cache_ttl: (!options.no_cache).then_some(options.cache_ttl),
+        ..FetchConfig::from_args(&options.network, &options.browser)?
+    })?;
+
+    let (matches, truncated) = grep_content(
+        &regex,
+        &fetched.content,
+        options.context,
+        options.max_matches,
+    );
+    Ok(GrepOutput {
+        url: fetched.url,
+        title: fetched.title,
+        pattern: options.pattern,
+        total_characters: fetched.pagination.total_characters,
+        match_count: matches.len(),
+        truncated,
+        matches,
+    })
+}
+
+/// Show a match and its context on one terminal line.
+fn one_line(text: &str) -> String {
+    text.replace(['\n', '\r'], " ")
+}
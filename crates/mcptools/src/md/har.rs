@@ -0,0 +1,138 @@
+use crate::prelude::*;
+use std::path::Path;
+use std::sync::{Arc, Mutex, Weak};
+
+use headless_chrome::browser::tab::EventListener;
+use headless_chrome::protocol::cdp::types::Event;
+use headless_chrome::protocol::cdp::Network;
+use mcptools_core::har::{to_har, HarPage, NetworkEvent, NetworkLog, NetworkResponse};
+
+type Listener = dyn EventListener<Event> + Send + Sync;
+
+/// Network events recorded from a tab for `md fetch --har`.
+pub(super) struct Capture {
+    log: Arc<Mutex<NetworkLog>>,
+    listener: Weak<Listener>,
+}
+
+/// Turn on the tab's Network domain so it emits request events.
+pub(super) fn enable_network(tab: &headless_chrome::Tab) -> Result<()> {
+    tab.call_method(Network::Enable {
+        max_total_buffer_size: None,
+        max_resource_buffer_size: None,
+        max_post_data_size: None,
+        report_direct_socket_traffic: None,
+        enable_durable_messages: None,
+    })
+    .map(|_| ())
+    .map_err(|e| eyre!("Failed to enable network events: {}", e))
+}
+
+/// Start recording the tab's network traffic. Call before navigating.
+pub(super) fn start(tab: &headless_chrome::Tab) -> Result<Capture> {
+    enable_network(tab)?;
+
+    let log = Arc::new(Mutex::new(NetworkLog::new()));
+    let sink = log.clone();
+    let listener: Arc<Listener> = Arc::new(move |event: &Event| {
+        if let Some(event) = network_event(event) {
+            if let Ok(mut log) = sink.lock() {
+                log.apply(event);
+            }
+        }
+    });
+    let listener = tab
+        .add_event_listener(listener)
+        .map_err(|e| eyre!("Failed to capture network events: {}", e))?;
+    Ok(Capture { log, listener })
+}
+
+/// Stop recording and write the HAR file.
+pub(super) fn finish(
+    tab: &headless_chrome::Tab,
+    capture: Capture,
+    path: &Path,
+    page: HarPage,
+) -> Result<()> {
+    let _ = tab.remove_event_listener(&capture.listener);
+    let log = capture
+        .log
+        .lock()
+        .map_err(|_| eyre!("Network capture was poisoned"))?
+        .clone();
+    let har = to_har(&log, &page, env!("CARGO_PKG_VERSION"));
+    if let Some(parent) = path.parent().filter(|p| !p.as_os_str().is_empty()) {
+        std::fs::create_dir_all(parent)
+            .map_err(|e| eyre!("Failed to create {}: {}", parent.display(), e))?;
+    }
+    std::fs::write(path, serde_json::to_string_pretty(&har)? + "\n")
+        .map_err(|e| eyre!("Failed to write {}: {}", path.display(), e))
+}
+
+fn headers(headers: &Network::Headers) -> Vec<(String, String)> {
+    let Some(serde_json::Value::Object(map)) = &headers.0 else {
+        return Vec::new();
+    };
+    map.iter()
+        .map(|(name, value)| {
+            let value = match value {
+                serde_json::Value::String(s) => s.clone(),
+                other => other.to_string(),
+            };
+            (name.clone(), value)
+        })
+        .collect()
+}
+
+fn response(response: &Network::Response) -> NetworkResponse {
+    NetworkResponse {
+        status: response.status as u16,
+        status_text: response.status_text.clone(),
+        protocol: response.protocol.clone(),
+        headers: headers(&response.headers),
+        mime_type: response.mime_type.clone(),
+        remote_ip: response.remote_ip_address.clone(),
+        encoded_data_length: response.encoded_data_length,
+    }
+}
+
+fn resource_type(kind: &Network::ResourceType) -> Option<String> {
+    serde_json::to_value(kind)
+        .ok()
+        .and_then(|v| v.as_str().map(str::to_string))
+}
+
+fn network_event(event: &Event) -> Option<NetworkEvent> {
+    Some(match event {
+        Event::NetworkRequestWillBeSent(e) => {
+            let p = &e.params;
+            NetworkEvent::RequestWillBeSent {
+                request_id: p.request_id.clone(),
+                url: p.request.url.clone(),
+                method: p.request.method.clone(),
+                headers: headers(&p.request.headers),
+                post_data: p.request.post_data.clone(),
+                resource_type: p.Type.as_ref().and_then(resource_type),
+                timestamp: p.timestamp,
+                wall_time: p.wall_time,
+                redirect_response: p.redirect_response.as_ref().map(response),
+            }
+        }
+        Event::NetworkResponseReceived(e) => NetworkEvent::ResponseReceived {
+            request_id: e.params.request_id.clone(),
+            timestamp: e.params.timestamp,
+            response: response(&e.params.response),
+        },
+        Event::NetworkLoadingFinished(e) => NetworkEvent::LoadingFinished {
+            request_id: e.params.request_id.clone(),
+            timestamp: e.params.timestamp,
+            encoded_data_length: e.params.encoded_data_length,
+        },
+        Event::NetworkLoadingFailed(e) => NetworkEvent::LoadingFailed {
+            request_id: e.params.request_id.clone(),
+            timestamp: e.params.timestamp,
+            error_text: e.params.error_text.clone(),
+        },
+        _ => return None,
+    })
+}
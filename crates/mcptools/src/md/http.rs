@@ -0,0 +1,98 @@
+use crate::prelude::{eprintln, *};
+use std::time::Duration;
+
+use mcptools_core::md::{
+    cookie_header, extract_title, is_pdf_content_type, is_transient_http_status, CachedPage,
+};
+
+use super::{convert_html, convert_pdf, AttemptError, FetchConfig};
+
+/// A response body, read according to its content type.
+enum Body {
+    Html(String),
+    Pdf(Vec<u8>),
+}
+
+/// Sent when no `--user-agent` is given; some sites reject requests without one.
+const DEFAULT_USER_AGENT: &str = concat!(
+    "Mozilla/5.0 (compatible; mcptools/",
+    env!("CARGO_PKG_VERSION"),
+    ")"
+);
+
+/// Fetch the page with a plain HTTP GET and run it through the same
+/// conversion as browser fetches; PDF responses go through the pdf crate. Must run on a blocking thread of the
+/// Tokio runtime (e.g. inside `spawn_blocking`).
+pub(super) fn fetch_page(config: &FetchConfig) -> std::result::Result<CachedPage, AttemptError> {
+    if config.wait_for.is_some() || config.scroll.is_some() {
+        eprintln!(
+            "Warning: --wait-for and --scroll need a browser; ignored for plain HTTP fetches"
+        );
+    }
+
+    let runtime = tokio::runtime::Handle::try_current()
+        .map_err(|e| eyre!("Plain HTTP fetch needs a Tokio runtime: {}", e))?;
+    match runtime.block_on(fetch_body(config))? {
+        Body::Html(html) => {
+            let title = extract_title(&html);
+            Ok(convert_html(html, title, config)?)
+        }
+        Body::Pdf(bytes) => Ok(convert_pdf(&bytes, config)?),
+    }
+}
+
+async fn fetch_body(config: &FetchConfig) -> std::result::Result<Body, AttemptError> {
+    let mut builder = reqwest::Client::builder()
+        .timeout(Duration::from_secs(config.timeout))
+        .user_agent(config.user_agent.as_deref().unwrap_or(DEFAULT_USER_AGENT));
+    if let Some(proxy) = &config.proxy {
+        let proxy = mcptools_core::md::parse_proxy_arg(proxy).map_err(|e| eyre!(e))?;
+        let proxy = if proxy.contains("://") {
+            proxy
+        } else {
+            format!("http://{}", proxy)
+        };
+        builder = builder.proxy(
+            reqwest::Proxy::all(&proxy).map_err(|e| eyre!("Invalid proxy '{}': {}", proxy, e))?,
+        );
+    }
+    let client = builder
+        .build()
+        .map_err(|e| eyre!("Failed to build HTTP client: {}", e))?;
+
+    let mut request = client.get(&config.url);
+    for (name, value) in &config.headers {
+        request = request.header(name, value);
+    }
+    if let Some(cookies) = cookie_header(&config.cookies) {
+        request = request.header(reqwest::header::COOKIE, cookies);
+    }
+
+    let response = request
+        .send()
+        .await
+        .map_err(|e| AttemptError::transient(eyre!("Failed to fetch {}: {}", config.url, e)))?;
+    let status = response.status();
+    if !status.is_success() {
+        let error = eyre!("Failed to fetch {}: HTTP {}", config.url, status);
+        return Err(if is_transient_http_status(status.as_u16()) {
+            AttemptError::transient(error)
+        } else {
+            error.into()
+        });
+    }
+    let read_error = |e: reqwest::Error| {
+        AttemptError::transient(eyre!("Failed to read response from {}: {}", config.url, e))
+    };
+    let is_pdf = response
+        .headers()
+        .get(reqwest::header::CONTENT_TYPE)
+        .and_then(|value| value.to_str().ok())
+        .is_some_and(is_pdf_content_type);
+    if is_pdf {
+        let bytes = response.bytes().await.map_err(read_error)?;
+        Ok(Body::Pdf(bytes.to_vec()))
+    } else {
+        response.text().await.map(Body::Html).map_err(read_error)
+    }
+}
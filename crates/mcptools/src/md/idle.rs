@@ -0,0 +1,67 @@
+use crate::prelude::*;
+use std::sync::{Arc, Mutex, Weak};
+use std::time::{Duration, Instant};
+
+use headless_chrome::browser::tab::EventListener;
+use headless_chrome::protocol::cdp::types::Event;
+use mcptools_core::md::{NetworkActivity, NetworkIdlePolicy};
+
+type Listener = dyn EventListener<Event> + Send + Sync;
+
+const POLL_INTERVAL: Duration = Duration::from_millis(50);
+
+/// Requests the tab has in flight, tracked from CDP network events.
+pub(super) struct IdleWatch {
+    activity: Arc<Mutex<NetworkActivity>>,
+    listener: Weak<Listener>,
+    origin: Instant,
+}
+
+/// Start tracking the tab's requests. Call before navigating.
+pub(super) fn start(tab: &headless_chrome::Tab) -> Result<IdleWatch> {
+    super::har::enable_network(tab)?;
+
+    let origin = Instant::now();
+    let activity = Arc::new(Mutex::new(NetworkActivity::new()));
+    let sink = activity.clone();
+    let listener: Arc<Listener> = Arc::new(move |event: &Event| {
+        let at = origin.elapsed().as_millis() as u64;
+        let Ok(mut activity) = sink.lock() else {
+            return;
+        };
+        match event {
+            Event::NetworkRequestWillBeSent(e) => activity.started(&e.params.request_id, at),
+            Event::NetworkLoadingFinished(e) => activity.finished(&e.params.request_id, at),
+            Event::NetworkLoadingFailed(e) => activity.finished(&e.params.request_id, at),
+            _ => {}
+        }
+    });
+    let listener = tab
+        .add_event_listener(listener)
+        .map_err(|e| eyre!("Failed to watch network events: {}", e))?;
+    Ok(IdleWatch {
+        activity,
+        listener,
+        origin,
+    })
+}
+
+/// Block until no request has been in flight for `policy.idle_ms`, or
+/// `policy.max_wait_ms` has passed. Reaching the limit is not an error:
+/// pages with long polling or analytics beacons never go fully quiet.
+pub(super) fn wait(tab: &headless_chrome::Tab, watch: IdleWatch, policy: NetworkIdlePolicy) {
+    let deadline = Instant::now() + Duration::from_millis(policy.max_wait_ms);
+    loop {
+        let now = watch.origin.elapsed().as_millis() as u64;
+        let idle = watch
+            .activity
+            .lock()
+            .map(|activity| activity.is_idle(now, policy.idle_ms))
+            .unwrap_or(true);
+        if idle || Instant::now() >= deadline {
+            break;
+        }
+        std::thread::sleep(POLL_INTERVAL);
+    }
+    let _ = tab.remove_event_listener(&watch.listener);
+}
@@ -0,0 +1,159 @@
+use crate::prelude::{eprintln, *};
+use std::collections::HashMap;
+use std::path::Path;
+
+use mcptools_core::md::{
+    fill_image_alt_text, images_missing_alt, plan_image_downloads, rewrite_image_links,
+    ImageDownload,
+};
+use mcptools_core::vision::DescribeMode;
+
+/// Images fetched at once.
+const IMAGE_CONCURRENCY: usize = 4;
+
+/// Request context for image downloads, taken from the page fetch.
+pub struct ImageRequestContext<'a> {
+    pub page_url: &'a str,
+    pub headers: &'a [(String, String)],
+    pub cookies: &'a [(String, String)],
+    pub user_agent: Option<&'a str>,
+}
+
+/// Headers and cookies only go to the page's own host, so credentials are
+/// not leaked to third-party CDNs.
+fn same_host(a: &str, b: &str) -> bool {
+    match (reqwest::Url::parse(a), reqwest::Url::parse(b)) {
+        (Ok(a), Ok(b)) => a.host_str().is_some() && a.host_str() == b.host_str(),
+        _ => false,
+    }
+}
+
+async fn fetch_image(
+    client: &reqwest::Client,
+    download: &ImageDownload,
+    context: &ImageRequestContext<'_>,
+) -> Result<Vec<u8>> {
+    let url = reqwest::Url::parse(&download.url)?;
+    if url.scheme() == "file" {
+        let path = url
+            .to_file_path()
+            .map_err(|_| eyre!("Invalid file URL: {}", download.url))?;
+        return std::fs::read(&path).map_err(|e| eyre!("{}: {}", path.display(), e));
+    }
+
+    let mut request = client.get(url.as_str());
+    if let Some(user_agent) = context.user_agent {
+        request = request.header(reqwest::header::USER_AGENT, user_agent);
+    }
+    if same_host(&download.url, context.page_url) {
+        for (name, value) in context.headers {
+            request = request.header(name.as_str(), value.as_str());
+        }
+        if !context.cookies.is_empty() {
+            let cookie = context
+                .cookies
+                .iter()
+                .map(|(name, value)| format!("{}={}", name, value))
+                .collect::<Vec<_>>()
+                .join("; ");
+            request = request.header(reqwest::header::COOKIE, cookie);
+        }
+    }
+
+    let response = request.send().await?;
+    if !response.status().is_success() {
+        return Err(eyre!("HTTP {}", response.status()));
+    }
+    Ok(response.bytes().await?.to_vec())
+}
+
+/// Download every image linked from `markdown` into `dir` and return the
+/// Markdown with those links pointing at `dir/<file>`. Images that fail to
+/// download keep their original link and are reported on stderr.
+///
+/// With a vision model, downloaded images whose links have no alt text get
+/// one generated; images the model cannot describe are skipped with a
+/// warning.
+pub async fn download_images(
+    markdown: &str,
+    dir: &Path,
+    context: ImageRequestContext<'_>,
+    describe: Option<&crate::vision::VisionModelArgs>,
+) -> Result<String> {
+    use futures::StreamExt;
+
+    let plan = plan_image_downloads(markdown, context.page_url);
+    if plan.is_empty() {
+        return Ok(markdown.to_string());
+    }
+    std::fs::create_dir_all(dir).map_err(|e| eyre!("Failed to create {}: {}", dir.display(), e))?;
+
+    let client = reqwest::Client::new();
+    let results: Vec<(ImageDownload, Result<Vec<u8>>)> = futures::stream::iter(plan)
+        .map(|download| {
+            let (client, context) = (&client, &context);
+            async move {
+                let result = fetch_image(client, &download, context).await;
+                (download, result)
+            }
+        })
+        .buffered(IMAGE_CONCURRENCY)
+        .collect()
+        .await;
+
+    let missing_alt = images_missing_alt(markdown);
+    let mut replacements = HashMap::new();
+    let mut alt_texts = HashMap::new();
+    let mut failed = 0;
+    for (download, result) in results {
+        let path = dir.join(&download.file_name);
+        match result.and_then(|bytes| {
+            std::fs::write(&path, &bytes).map_err(|e| eyre!("{}: {}", path.display(), e))?;
+            Ok(bytes)
+        }) {
+            Ok(bytes) => {
+                // Markdown links use forward slashes on every platform.
+                let target = path.to_string_lossy().replace('\\', "/");
+                if let Some(model) = describe.filter(|_| missing_alt.contains(&download.original)) {
+                    match crate::vision::describe_image_data(
+                        &bytes,
+                        model,
+                        DescribeMode::AltText,
+                        None,
+                    )
+                    .await
+                    {
+                        Ok(alt) => {
+                            alt_texts.insert(target.clone(), alt);
+                        }
+                        Err(e) => {
+                            eprintln!("Skipping alt text for {}: {}", download.url, e)
+                        }
+                    }
+                }
+                replacements.insert(download.original, target);
+            }
+            Err(e) => {
+                failed += 1;
+                eprintln!("Warning: failed to download {}: {}", download.url, e);
+            }
+        }
+    }
+    eprintln!(
+        "Downloaded {} image(s) to {}{}",
+        replacements.len(),
+        dir.display(),
+        if failed > 0 {
+            format!(" ({} failed)", failed)
+        } else {
+            String::new()
+        }
+    );
+
+    if describe.is_some() {
+        eprintln!("Generated alt text for {} image(s)", alt_texts.len());
+    }
+
+    let rewritten = rewrite_image_links(markdown, &replacements);
+    Ok(fill_image_alt_text(&rewritten, &alt_texts))
+}
@@ -0,0 +1,51 @@
+use crate::prelude::{println, *};
+use mcptools_core::meta::{format_meta_text, MetaOutput};
+
+use super::{fetch_and_convert_data, FetchConfig, SelectionStrategy};
+
+#[derive(Debug, clap::Args, Clone)]
+pub struct MetaOptions {
+    /// URL of the page to read
+    #[arg(env = "MD_URL")]
+    pub url: String,
+
+    /// Output as JSON
+    #[arg(long)]
+    pub json: bool,
+
+    /// Timeout in seconds (default: 30)
+    #[arg(short, long, env = "MD_TIMEOUT", default_value = "30")]
+    pub timeout: u64,
+
+    #[command(flatten)]
+    pub network: super::NetworkArgs,
+
+    #[command(flatten)]
+    pub browser: super::BrowserArgs,
+}
+
+pub async fn meta(options: MetaOptions) -> Result<()> {
+    let config = FetchConfig {
+        url: options.url,
+        timeout: options.timeout,
+        // Skip the Markdown conversion; only the metadata is printed.
+        raw_html: true,
+        metadata: true,
+        ..FetchConfig::from_args(&options.network, &options.browser)?
+    };
+
+    let fetched = tokio::task::spawn_blocking(move || fetch_and_convert_data(config)).await??;
+    let output = MetaOutput {
+        url: fetched.url,
+        title: fetched.title,
+        metadata: fetched.metadata.unwrap_or_default(),
+    };
+
+    if options.json {
+        println!("{}", serde_json::to_string_pretty(&output)?);
+    } else {
+        println!("{}", format_meta_text(&output));
+    }
+
+    Ok(())
+}
@@ -1,16 +1,36 @@
+mod cache;
+mod crawl;
+mod diff;
+mod feed;
 mod fetch;
+pub mod grep;
+mod har;
+mod http;
+mod idle;
+mod images;
+mod meta;
+pub mod normalize;
+pub mod pdf_export;
+mod robots;
+pub mod screenshot;
+mod sitemap;
+mod tables;
 pub mod toc;
+mod watch;
+pub mod youtube;
 
 use crate::prelude::{eprintln, println, *};
 use headless_chrome::Browser;
 use std::time::Instant;
 
+use mcptools_core::md::CachedPage;
 pub use mcptools_core::md::{FetchOutput, MdPaginationInfo};
 
-#[derive(Debug, Clone, clap::ValueEnum, serde::Deserialize)]
+#[derive(Debug, Clone, Default, clap::ValueEnum, serde::Deserialize)]
 #[serde(rename_all = "lowercase")]
 pub enum SelectionStrategy {
     /// Select the first matching element (default)
+    #[default]
     First,
     /// Select the last matching element
     Last,
@@ -31,14 +51,158 @@ impl From<SelectionStrategy> for mcptools_core::md::SelectionStrategy {
     }
 }
 
+#[derive(Debug, Clone, Copy, Default, clap::ValueEnum, serde::Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum HeadingStyle {
+    /// Underlined `===`/`---` for h1 and h2 (default)
+    #[default]
+    Setext,
+    /// `#` prefixes at every level
+    Atx,
+}
+
+#[derive(Debug, Clone, Copy, Default, clap::ValueEnum, serde::Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum LinkStyle {
+    /// `[text](url)` (default)
+    #[default]
+    Inline,
+    /// `[text][1]` with numbered definitions at the end
+    Reference,
+}
+
+#[derive(Debug, Clone, Copy, Default, clap::ValueEnum, serde::Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ImageHandling {
+    /// `![alt](src)` (default)
+    #[default]
+    Keep,
+    /// Replace images with their alt text
+    Alt,
+    /// Remove images
+    Drop,
+}
+
+/// Markdown style flags shared by the commands that convert pages.
+#[derive(Debug, Clone, Default, clap::Args, serde::Deserialize)]
+pub struct ConversionArgs {
+    /// Heading syntax
+    #[arg(long, value_enum, env = "MD_HEADINGS", default_value = "setext")]
+    #[serde(default)]
+    pub md_headings: HeadingStyle,
+
+    /// Link syntax
+    #[arg(long, value_enum, env = "MD_LINKS", default_value = "inline")]
+    #[serde(default)]
+    pub md_links: LinkStyle,
+
+    /// What to do with images
+    #[arg(long, value_enum, env = "MD_IMAGES", default_value = "keep")]
+    #[serde(default)]
+    pub md_images: ImageHandling,
+
+    /// Label code fences with the language from the block's class
+    /// (language-*, lang-*, highlight-source-*) or lang attribute
+    #[arg(long, env = "MD_CODE_LANG")]
+    #[serde(default)]
+    pub md_code_lang: bool,
+
+    /// Strip navigation, footers, sidebars, cookie banners, and ads before
+    /// converting
+    #[arg(long, env = "MD_CLEAN")]
+    #[serde(default)]
+    pub clean: bool,
+}
+
+impl From<&ConversionArgs> for mcptools_core::md::ConversionOptions {
+    fn from(args: &ConversionArgs) -> Self {
+        use mcptools_core::md as core;
+        Self {
+            headings: match args.md_headings {
+                HeadingStyle::Setext => core::HeadingStyle::Setext,
+                HeadingStyle::Atx => core::HeadingStyle::Atx,
+            },
+            links: match args.md_links {
+                LinkStyle::Inline => core::LinkStyle::Inline,
+                LinkStyle::Reference => core::LinkStyle::Reference,
+            },
+            images: match args.md_images {
+                ImageHandling::Keep => core::ImageHandling::Keep,
+                ImageHandling::Alt => core::ImageHandling::Alt,
+                ImageHandling::Drop => core::ImageHandling::Drop,
+            },
+            infer_code_language: args.md_code_lang,
+            strip_boilerplate: args.clean,
+        }
+    }
+}
+
+/// Request flags shared by the commands that fetch pages.
+#[derive(Debug, Clone, Default, clap::Args, serde::Deserialize)]
+pub struct NetworkArgs {
+    /// Extra request header, "Name: value" (repeatable)
+    #[arg(long = "header", short = 'H', value_name = "HEADER")]
+    #[serde(default)]
+    pub headers: Vec<String>,
+
+    /// Cookies to send, "name=value; other=value" (repeatable)
+    #[arg(long = "cookie", value_name = "COOKIE", env = "MD_COOKIE")]
+    #[serde(default)]
+    pub cookies: Vec<String>,
+
+    /// Proxy server, "host:port" or "scheme://host:port" (http, https, socks4, socks5)
+    #[arg(long, env = "MD_PROXY")]
+    #[serde(default)]
+    pub proxy: Option<String>,
+
+    /// Override the User-Agent string
+    #[arg(long, env = "MD_USER_AGENT")]
+    #[serde(default)]
+    pub user_agent: Option<String>,
+}
+
+/// Page-loading flags shared by the commands that render pages in Chrome.
+#[derive(Debug, Clone, Default, clap::Args, serde::Deserialize)]
+pub struct BrowserArgs {
+    /// Wait until an element matching this CSS selector appears before
+    /// extracting content
+    #[arg(long, env = "MD_WAIT_FOR")]
+    #[serde(default)]
+    pub wait_for: Option<String>,
+
+    /// Fetch with a plain HTTP request instead of headless Chrome (used
+    /// automatically when Chrome is not installed); JavaScript does not run
+    #[arg(long, env = "MD_NO_BROWSER")]
+    #[serde(default)]
+    pub no_browser: bool,
+}
+
 // Re-export command modules
+pub use crawl::CrawlOptions;
+pub use diff::DiffOptions;
+pub use feed::FeedOptions;
 pub use fetch::FetchOptions;
+pub use grep::GrepOptions;
+pub use meta::MetaOptions;
+pub use normalize::NormalizeOptions;
+pub use pdf_export::PdfOptions;
+pub use screenshot::{capture_screenshot_data, ScreenshotConfig, ScreenshotOptions};
+pub use sitemap::SitemapOptions;
+pub use tables::TablesOptions;
 pub use toc::{extract_toc_data, OutputFormat, TocOptions};
+pub use watch::WatchOptions;
+pub use youtube::{fetch_transcript_data, YoutubeConfig, YoutubeOptions};
 
 #[derive(Debug, clap::Parser)]
 #[command(name = "md")]
 #[command(about = "Convert web pages to Markdown using headless Chrome")]
 pub struct App {
+    /// Attach to an already running Chrome instead of launching one: its
+    /// DevTools WebSocket URL, or the http://host:port of its remote
+    /// debugging port
+    #[arg(long, global = true, env = "MCPTOOLS_CDP_URL", value_name = "URL")]
+    pub browser_ws_url: Option<String>,
+
     #[command(subcommand)]
     pub command: Commands,
 }
@@ -47,11 +211,59 @@ pub struct App {
 pub enum Commands {
     /// Fetch a web page and convert to Markdown
     #[clap(name = "fetch")]
-    Fetch(FetchOptions),
+    Fetch(Box<FetchOptions>),
 
     /// Extract table of contents from a web page
     #[clap(name = "toc")]
     Toc(TocOptions),
+
+    /// Search a page's converted Markdown for a regex, with character offsets for md fetch
+    #[clap(name = "grep")]
+    Grep(GrepOptions),
+
+    /// Compare a page with its previous snapshot: unified diff plus changed sections
+    #[clap(name = "diff")]
+    Diff(DiffOptions),
+
+    /// Poll a page and report changes until interrupted
+    #[clap(name = "watch")]
+    Watch(WatchOptions),
+
+    /// Crawl a site from a start URL, writing each page's Markdown to a directory
+    #[clap(name = "crawl")]
+    Crawl(CrawlOptions),
+
+    /// Read an RSS or Atom feed as a Markdown digest, optionally with each linked article
+    #[clap(name = "feed")]
+    Feed(FeedOptions),
+
+    /// Extract a page's HTML tables as JSON headers/rows or CSV
+    #[clap(name = "tables")]
+    Tables(TablesOptions),
+
+    /// Show a page's canonical URL, description, OpenGraph, Twitter card, and JSON-LD metadata
+    #[clap(name = "meta")]
+    Meta(MetaOptions),
+
+    /// Clean up Markdown: reflow, heading levels, list markers, and link style
+    #[clap(name = "normalize")]
+    Normalize(NormalizeOptions),
+
+    /// List or fetch the URLs in a sitemap or sitemap index
+    #[clap(name = "sitemap")]
+    Sitemap(SitemapOptions),
+
+    /// Capture a screenshot of a web page or a single element
+    #[clap(name = "screenshot")]
+    Screenshot(ScreenshotOptions),
+
+    /// Export a web page to PDF using Chrome's print-to-PDF
+    #[clap(name = "pdf")]
+    Pdf(PdfOptions),
+
+    /// Fetch a YouTube video's captions as a timestamped Markdown transcript
+    #[clap(name = "youtube")]
+    Youtube(YoutubeOptions),
 }
 
 #[derive(Debug, Clone)]
@@ -62,69 +274,735 @@ pub struct FetchConfig {
     pub selector: Option<String>,
     pub strategy: SelectionStrategy,
     pub index: Option<usize>,
+    /// CSS selector to wait for after navigation (for client-rendered pages)
+    pub wait_for: Option<String>,
+    /// Extra request headers sent with every request
+    pub headers: Vec<(String, String)>,
+    /// Cookies set for the target URL before navigation
+    pub cookies: Vec<(String, String)>,
+    /// Proxy server for all browser traffic (`host:port` or `scheme://host:port`)
+    pub proxy: Option<String>,
+    /// User-Agent override for the tab
+    pub user_agent: Option<String>,
     pub offset: usize,
     pub limit: usize,
     pub page: usize,
     pub paginated: bool,
+    /// Extract page metadata (OpenGraph, Twitter, JSON-LD) into `FetchOutput::metadata`
+    pub metadata: bool,
+    /// Scroll infinite-scroll pages before extracting HTML
+    pub scroll: Option<mcptools_core::md::ScrollPlan>,
+    /// Serve and store converted pages in the on-disk cache for this many
+    /// seconds; `None` bypasses the cache
+    pub cache_ttl: Option<u64>,
+    /// Retries for transient browser failures (tab creation, navigation,
+    /// content extraction)
+    pub retry: mcptools_core::md::RetryPolicy,
+    /// Fetch over plain HTTP instead of the browser; `wait_for` and
+    /// `scroll` have no effect
+    pub no_browser: bool,
+    /// Return only the section under the heading best matching this title
+    /// (fuzzy); pagination then applies within the section
+    pub heading: Option<String>,
+    /// Markdown dialect for the HTML conversion
+    pub conversion: mcptools_core::md::ConversionOptions,
+    /// Steps run in the tab before navigating to `url` (browser only)
+    pub login: Option<Vec<mcptools_core::md::LoginStep>>,
+    /// Record the tab's network traffic to this HAR file (browser only)
+    pub har: Option<std::path::PathBuf>,
+    /// Wait for in-flight requests to settle after navigation (browser only)
+    pub network_idle: mcptools_core::md::NetworkIdlePolicy,
+}
+
+impl Default for FetchConfig {
+    /// A 30-second, uncached fetch of the first matching element, converted
+    /// to Markdown with one 1000-character page.
+    fn default() -> Self {
+        Self {
+            url: String::new(),
+            timeout: 30,
+            raw_html: false,
+            selector: None,
+            strategy: SelectionStrategy::First,
+            index: None,
+            wait_for: None,
+            headers: Vec::new(),
+            cookies: Vec::new(),
+            proxy: None,
+            user_agent: None,
+            offset: 0,
+            limit: 1000,
+            page: 1,
+            paginated: false,
+            metadata: false,
+            scroll: None,
+            cache_ttl: None,
+            retry: mcptools_core::md::RetryPolicy::default(),
+            no_browser: false,
+            heading: None,
+            conversion: mcptools_core::md::ConversionOptions::default(),
+            login: None,
+            har: None,
+            network_idle: Default::default(),
+        }
+    }
+}
+
+impl FetchConfig {
+    /// Default config carrying the shared request and page-loading flags.
+    pub fn from_args(network: &NetworkArgs, browser: &BrowserArgs) -> Result<Self> {
+        let (headers, cookies) = parse_request_context(&network.headers, &network.cookies)?;
+        Ok(Self {
+            wait_for: browser.wait_for.clone(),
+            headers,
+            cookies,
+            proxy: network.proxy.clone(),
+            user_agent: network.user_agent.clone(),
+            no_browser: browser.no_browser,
+            ..Default::default()
+        })
+    }
 }
 
 pub async fn run(app: App, _global: crate::Global) -> Result<()> {
+    if let Some(url) = app.browser_ws_url {
+        let _ = BROWSER_WS_URL.set(url);
+    }
     match app.command {
-        Commands::Fetch(options) => fetch::fetch(options).await,
+        Commands::Fetch(options) => fetch::fetch(*options).await,
         Commands::Toc(options) => toc::toc(options).await,
+        Commands::Grep(options) => grep::grep(options).await,
+        Commands::Crawl(options) => crawl::crawl(options).await,
+        Commands::Diff(options) => diff::diff(options).await,
+        Commands::Watch(options) => watch::watch(options).await,
+        Commands::Meta(options) => meta::meta(options).await,
+        Commands::Tables(options) => tables::tables(options).await,
+        Commands::Normalize(options) => normalize::normalize(options).await,
+        Commands::Sitemap(options) => sitemap::sitemap(options).await,
+        Commands::Feed(options) => feed::feed(options).await,
+        Commands::Screenshot(options) => screenshot::screenshot(options).await,
+        Commands::Pdf(options) => pdf_export::pdf(options).await,
+        Commands::Youtube(options) => youtube::youtube(options).await,
     }
 }
 
-/// Public function for MCP reuse - fetch and convert web page to markdown/HTML
-pub fn fetch_and_convert_data(config: FetchConfig) -> Result<FetchOutput> {
-    use mcptools_core::md::{calculate_pagination, process_html_content, slice_content};
+/// Name/value pairs for request headers or cookies.
+pub type NameValuePairs = Vec<(String, String)>;
 
-    let start = Instant::now();
+/// Parse repeated `--header` and `--cookie` values.
+pub fn parse_request_context(
+    headers: &[String],
+    cookies: &[String],
+) -> Result<(NameValuePairs, NameValuePairs)> {
+    use mcptools_core::md::{parse_cookie_arg, parse_header_arg};
 
-    // Step 1: Browser I/O - Launch headless Chrome
-    let browser = Browser::default().map_err(|e| {
+    let headers = headers
+        .iter()
+        .map(|h| parse_header_arg(h))
+        .collect::<std::result::Result<Vec<_>, _>>()
+        .map_err(|e| eyre!(e))?;
+    let cookies = cookies
+        .iter()
+        .map(|c| parse_cookie_arg(c))
+        .collect::<std::result::Result<Vec<_>, _>>()
+        .map_err(|e| eyre!(e))?
+        .into_iter()
+        .flatten()
+        .collect();
+    Ok((headers, cookies))
+}
+
+/// Install extra headers and cookies on a tab through CDP. Must run before
+/// navigation so the first request already carries them.
+pub fn apply_request_context(
+    tab: &headless_chrome::Tab,
+    url: &str,
+    headers: &[(String, String)],
+    cookies: &[(String, String)],
+) -> Result<()> {
+    use headless_chrome::protocol::cdp::Network::CookieParam;
+
+    if !headers.is_empty() {
+        let headers = headers
+            .iter()
+            .map(|(name, value)| (name.as_str(), value.as_str()))
+            .collect();
+        tab.set_extra_http_headers(headers)
+            .map_err(|e| eyre!("Failed to set request headers: {}", e))?;
+    }
+
+    if !cookies.is_empty() {
+        let cookies = cookies
+            .iter()
+            .map(|(name, value)| {
+                // The tab is still on about:blank, so scope each cookie to the target URL.
+                serde_json::from_value::<CookieParam>(serde_json::json!({
+                    "name": name,
+                    "value": value,
+                    "url": url,
+                }))
+            })
+            .collect::<std::result::Result<Vec<_>, _>>()?;
+        tab.set_cookies(cookies)
+            .map_err(|e| eyre!("Failed to set cookies: {}", e))?;
+    }
+
+    Ok(())
+}
+
+/// Launch headless Chrome, optionally routing all traffic through a proxy.
+pub fn launch_browser(proxy: Option<&str>) -> Result<Browser> {
+    if let Some(endpoint) = browser_ws_url() {
+        if proxy.is_some() {
+            eprintln!("Warning: --proxy is ignored when attaching to a running browser");
+        }
+        return connect_browser(&endpoint);
+    }
+
+    let launch_error = |e: &dyn std::fmt::Display| {
         eyre!(
             "Failed to launch browser: {}. Make sure Chrome or Chromium is installed.",
             e
         )
+    };
+
+    let proxy = proxy
+        .map(mcptools_core::md::parse_proxy_arg)
+        .transpose()
+        .map_err(|e| eyre!(e))?;
+    let path = headless_chrome::browser::default_executable().map_err(|e| launch_error(&e))?;
+    let options = headless_chrome::LaunchOptions::default_builder()
+        .path(Some(path))
+        .proxy_server(proxy.as_deref())
+        .build()
+        .map_err(|e| launch_error(&e))?;
+    Browser::new(options).map_err(|e| launch_error(&e))
+}
+
+/// `--browser-ws-url` from the command line, set once in [`run`].
+static BROWSER_WS_URL: std::sync::OnceLock<String> = std::sync::OnceLock::new();
+
+/// The running browser to attach to, if any: `--browser-ws-url`, or
+/// `MCPTOOLS_CDP_URL` for callers that skip the CLI (the MCP server).
+fn browser_ws_url() -> Option<String> {
+    BROWSER_WS_URL.get().cloned().or_else(|| {
+        std::env::var("MCPTOOLS_CDP_URL")
+            .ok()
+            .filter(|url| !url.trim().is_empty())
+    })
+}
+
+/// Attach to a running Chrome. An HTTP endpoint is resolved to the current
+/// WebSocket URL through `/json/version`. Must run on a blocking thread of
+/// the Tokio runtime. Tabs opened here are closed after use; the browser
+/// itself is left running.
+fn connect_browser(endpoint: &str) -> Result<Browser> {
+    use mcptools_core::md::{parse_cdp_endpoint, websocket_debugger_url, CdpEndpoint};
+
+    let ws_url = match parse_cdp_endpoint(endpoint).map_err(|e| eyre!(e))? {
+        CdpEndpoint::WebSocket(url) => url,
+        CdpEndpoint::VersionUrl(url) => {
+            let runtime = tokio::runtime::Handle::try_current()
+                .map_err(|e| eyre!("Resolving the browser URL needs a Tokio runtime: {}", e))?;
+            let body = runtime
+                .block_on(async { reqwest::get(&url).await?.error_for_status()?.text().await })
+                .map_err(|e| eyre!("Failed to reach the browser at {}: {}", url, e))?;
+            websocket_debugger_url(&body).map_err(|e| eyre!(e))?
+        }
+    };
+    Browser::connect(ws_url.clone())
+        .map_err(|e| eyre!("Failed to connect to the browser at {}: {}", ws_url, e))
+}
+
+/// Whether a browser can be used: one to attach to, or a Chrome or
+/// Chromium executable to launch.
+pub fn browser_available() -> bool {
+    browser_ws_url().is_some() || headless_chrome::browser::default_executable().is_ok()
+}
+
+/// Whether to fetch over plain HTTP: when asked to, or when no browser is
+/// installed (with a warning, since client-side rendering won't run).
+pub fn use_http_fetch(no_browser: bool) -> bool {
+    if no_browser || browser_available() {
+        return no_browser;
+    }
+    eprintln!(
+        "Warning: Chrome or Chromium not found; fetching over plain HTTP without running JavaScript"
+    );
+    true
+}
+
+/// Login scripts and HAR capture drive a real page, so there is no plain
+/// HTTP fallback for them.
+pub(super) fn check_browser_only(config: &FetchConfig) -> Result<()> {
+    let flag = if config.login.is_some() {
+        "--login-script"
+    } else if config.har.is_some() {
+        "--har"
+    } else {
+        return Ok(());
+    };
+    if config.no_browser || !browser_available() {
+        return Err(eyre!(
+            "{} needs Chrome or Chromium; it cannot run over plain HTTP",
+            flag
+        ));
+    }
+    Ok(())
+}
+
+/// Public function for MCP reuse - fetch and convert web page to markdown/HTML
+pub fn fetch_and_convert_data(mut config: FetchConfig) -> Result<FetchOutput> {
+    if let Some(source) = mcptools_core::md::parse_local_source(&config.url) {
+        return fetch_local(&source, config);
+    }
+    check_browser_only(&config)?;
+    config.no_browser = skips_browser(&config) || use_http_fetch(config.no_browser);
+
+    // A fresh cache entry saves launching the browser at all.
+    let start = Instant::now();
+    if let Some(page) = cache::load(&config) {
+        return paginate_page(page, config, start, 0);
+    }
+
+    if config.no_browser {
+        let (page, retries) = with_retries(&config.retry, || http::fetch_page(&config))?;
+        cache::store(&config, &page);
+        return paginate_page(page, config, start, retries);
+    }
+
+    // Step 1: Browser I/O - Launch headless Chrome
+    let browser = launch_browser(config.proxy.as_deref())?;
+    fetch_uncached(&browser, config, start)
+}
+
+/// Fetch and convert one page in a new tab of an already running browser,
+/// so batch fetches pay the browser launch cost once. The tab is closed
+/// afterwards. `config.proxy` is ignored; it applies at launch.
+pub fn fetch_with_browser(browser: &Browser, config: FetchConfig) -> Result<FetchOutput> {
+    if let Some(source) = mcptools_core::md::parse_local_source(&config.url) {
+        return fetch_local(&source, config);
+    }
+
+    let start = Instant::now();
+    if let Some(page) = cache::load(&config) {
+        return paginate_page(page, config, start, 0);
+    }
+    if skips_browser(&config) {
+        let (page, retries) = with_retries(&config.retry, || http::fetch_page(&config))?;
+        cache::store(&config, &page);
+        return paginate_page(page, config, start, retries);
+    }
+    fetch_uncached(browser, config, start)
+}
+
+/// PDF links go straight to a plain download; Chrome only shows its viewer.
+/// A login script or HAR capture still needs the tab, which checks the
+/// document's content type after navigating.
+fn skips_browser(config: &FetchConfig) -> bool {
+    config.login.is_none() && config.har.is_none() && mcptools_core::md::is_pdf_url(&config.url)
+}
+
+/// A failed page load, and whether a fresh attempt could succeed.
+struct AttemptError {
+    error: color_eyre::eyre::Report,
+    transient: bool,
+}
+
+impl AttemptError {
+    fn transient(error: color_eyre::eyre::Report) -> Self {
+        Self {
+            error,
+            transient: true,
+        }
+    }
+}
+
+impl From<color_eyre::eyre::Report> for AttemptError {
+    fn from(error: color_eyre::eyre::Report) -> Self {
+        Self {
+            error,
+            transient: false,
+        }
+    }
+}
+
+/// Run `attempt` until it succeeds, retrying transient errors with
+/// exponential backoff. Returns the value and the number of retries made.
+fn with_retries<T>(
+    policy: &mcptools_core::md::RetryPolicy,
+    mut attempt: impl FnMut() -> std::result::Result<T, AttemptError>,
+) -> Result<(T, u32)> {
+    let mut retries = 0;
+    loop {
+        match attempt() {
+            Ok(value) => return Ok((value, retries)),
+            Err(e) if e.transient && retries < policy.max_retries => {
+                let delay = policy.delay_ms(retries, rand::random::<f64>());
+                retries += 1;
+                eprintln!(
+                    "Warning: {}; retrying in {}ms ({}/{})",
+                    e.error, delay, retries, policy.max_retries
+                );
+                std::thread::sleep(std::time::Duration::from_millis(delay));
+            }
+            Err(e) => return Err(e.error),
+        }
+    }
+}
+
+/// Load the page in a new tab, store it in the cache, and paginate it.
+/// Transient browser errors are retried in a fresh tab with exponential
+/// backoff; selector timeouts and conversion errors fail immediately.
+fn fetch_uncached(browser: &Browser, config: FetchConfig, start: Instant) -> Result<FetchOutput> {
+    let (page, retries) = with_retries(&config.retry, || {
+        let tab = browser
+            .new_tab()
+            .map_err(|e| AttemptError::transient(eyre!("Failed to create new tab: {}", e)))?;
+        let result = fetch_in_tab(&tab, &config);
+        let _ = tab.close(false);
+        result
     })?;
 
-    let tab = browser
-        .new_tab()
-        .map_err(|e| eyre!("Failed to create new tab: {}", e))?;
+    cache::store(&config, &page);
+    paginate_page(page, config, start, retries)
+}
+
+/// Convert saved HTML (`file://` path or `-` for stdin) without a browser.
+/// Navigation options (wait_for, headers, cookies, proxy, user_agent) have
+/// nothing to act on and are ignored.
+fn fetch_local(
+    source: &mcptools_core::md::LocalSource,
+    config: FetchConfig,
+) -> Result<FetchOutput> {
+    use mcptools_core::md::LocalSource;
 
+    let start = Instant::now();
+    let bytes = match source {
+        LocalSource::Stdin => crate::hash::read_input(None, None)?,
+        LocalSource::File(path) => {
+            std::fs::read(path).map_err(|e| eyre!("Failed to read {}: {}", path.display(), e))?
+        }
+    };
+    if mcptools_core::md::has_pdf_signature(&bytes) {
+        let page = convert_pdf(&bytes, &config)?;
+        return paginate_page(page, config, start, 0);
+    }
+    let html = String::from_utf8_lossy(&bytes).into_owned();
+    let title = mcptools_core::md::extract_title(&html);
+    let page = convert_html(html, title, &config)?;
+    paginate_page(page, config, start, 0)
+}
+
+fn fetch_in_tab(
+    tab: &headless_chrome::Tab,
+    config: &FetchConfig,
+) -> std::result::Result<CachedPage, AttemptError> {
     tab.set_default_timeout(std::time::Duration::from_secs(config.timeout));
+    if let Some(user_agent) = &config.user_agent {
+        tab.set_user_agent(user_agent, None, None)
+            .map_err(|e| AttemptError::transient(eyre!("Failed to set user agent: {}", e)))?;
+    }
+    apply_request_context(tab, &config.url, &config.headers, &config.cookies)
+        .map_err(AttemptError::transient)?;
+    let capture = match &config.har {
+        Some(_) => Some(har::start(tab).map_err(AttemptError::transient)?),
+        None => None,
+    };
+    if let Some(steps) = &config.login {
+        run_login_script(tab, steps, &config.url)?;
+    }
+    let idle = if config.network_idle.is_enabled() {
+        Some(idle::start(tab).map_err(AttemptError::transient)?)
+    } else {
+        None
+    };
 
     // Step 2: Browser I/O - Navigate and extract HTML
     tab.navigate_to(&config.url)
-        .map_err(|e| eyre!("Failed to navigate to {}: {}", config.url, e))?
+        .map_err(|e| AttemptError::transient(eyre!("Failed to navigate to {}: {}", config.url, e)))?
         .wait_until_navigated()
-        .map_err(|e| eyre!("Failed to wait for navigation: {}", e))?;
+        .map_err(|e| AttemptError::transient(eyre!("Failed to wait for navigation: {}", e)))?;
+
+    // Let XHR/fetch calls made after the load event finish rendering
+    if let Some(idle) = idle {
+        let mut policy = config.network_idle;
+        policy.max_wait_ms = policy.max_wait_ms.min(config.timeout.saturating_mul(1000));
+        idle::wait(tab, idle, policy);
+    }
+
+    // Block until client-side rendering produces the element (bounded by the tab timeout)
+    if let Some(wait_for) = &config.wait_for {
+        tab.wait_for_element(wait_for).map_err(|e| {
+            eyre!(
+                "Timed out after {}s waiting for selector '{}': {}",
+                config.timeout,
+                wait_for,
+                e
+            )
+        })?;
+    }
+
+    if is_pdf_document(tab) {
+        return http::fetch_page(config);
+    }
+
+    if let Some(plan) = config.scroll {
+        auto_scroll(tab, plan, config.timeout)?;
+    }
 
     let title = tab.get_title().ok().filter(|t| !t.is_empty());
     let html = tab
         .get_content()
-        .map_err(|e| eyre!("Failed to get page content: {}", e))?;
+        .map_err(|e| AttemptError::transient(eyre!("Failed to get page content: {}", e)))?;
+
+    if let (Some(path), Some(capture)) = (&config.har, capture) {
+        let page = mcptools_core::har::HarPage {
+            url: config.url.clone(),
+            title: title.clone(),
+        };
+        har::finish(tab, capture, path, page)?;
+    }
+
+    Ok(convert_html(html, title, config)?)
+}
+
+/// Whether the tab navigated to a PDF rather than an HTML page.
+fn is_pdf_document(tab: &headless_chrome::Tab) -> bool {
+    tab.evaluate("document.contentType", false)
+        .ok()
+        .and_then(|result| result.value)
+        .and_then(|value| value.as_str().map(mcptools_core::md::is_pdf_content_type))
+        .unwrap_or(false)
+}
+
+/// Run `--login-script` steps in the tab, leaving its session cookies in
+/// place for the target navigation. Typed values never appear in errors.
+fn run_login_script(
+    tab: &headless_chrome::Tab,
+    steps: &[mcptools_core::md::LoginStep],
+    target: &str,
+) -> std::result::Result<(), AttemptError> {
+    use mcptools_core::md::{LoginStep, LoginWait};
+
+    for (i, step) in steps.iter().enumerate() {
+        let fail = |what: String, e: &dyn std::fmt::Display| {
+            eyre!("Login step {} ({}) failed: {}", i + 1, what, e)
+        };
+        match step {
+            LoginStep::Goto(url) => {
+                let url = reqwest::Url::parse(target)
+                    .and_then(|base| base.join(url))
+                    .map_err(|e| fail(format!("goto {}", url), &e))?;
+                tab.navigate_to(url.as_str())
+                    .and_then(|tab| tab.wait_until_navigated())
+                    .map_err(|e| AttemptError::transient(fail(format!("goto {}", url), &e)))?;
+            }
+            LoginStep::Type { selector, value } => {
+                tab.wait_for_element(selector)
+                    .and_then(|element| {
+                        element.click()?;
+                        element.type_into(value)?;
+                        Ok(())
+                    })
+                    .map_err(|e| fail(format!("type into '{}'", selector), &e))?;
+            }
+            LoginStep::Click(selector) => {
+                tab.wait_for_element(selector)
+                    .and_then(|element| element.click().map(|_| ()))
+                    .map_err(|e| fail(format!("click '{}'", selector), &e))?;
+            }
+            LoginStep::Wait(LoginWait::Selector(selector)) => {
+                tab.wait_for_element(selector)
+                    .map_err(|e| fail(format!("wait for '{}'", selector), &e))?;
+            }
+            LoginStep::Wait(LoginWait::Millis(ms)) => {
+                std::thread::sleep(std::time::Duration::from_millis(*ms));
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Sample (resource entries loaded, document height) from the page.
+fn page_activity(tab: &headless_chrome::Tab) -> Result<(u64, u64)> {
+    let value = tab
+        .evaluate(
+            "JSON.stringify([performance.getEntriesByType('resource').length, \
+             document.documentElement.scrollHeight])",
+            false,
+        )
+        .map_err(|e| eyre!("Failed to read page activity: {}", e))?
+        .value
+        .and_then(|v| v.as_str().map(str::to_string))
+        .ok_or_else(|| eyre!("Failed to read page activity: no value"))?;
+    serde_json::from_str(&value).map_err(|e| eyre!("Failed to read page activity: {}", e))
+}
+
+/// Scroll to the bottom repeatedly, waiting after each pass until no new
+/// resources load and the height stops changing (or the per-pass budget
+/// runs out), so lazy-loaded content is in the DOM before extraction.
+fn auto_scroll(
+    tab: &headless_chrome::Tab,
+    plan: mcptools_core::md::ScrollPlan,
+    timeout: u64,
+) -> Result<()> {
+    use mcptools_core::md::is_page_settled;
+    use std::time::Duration;
+
+    const POLL_INTERVAL: Duration = Duration::from_millis(250);
+    const QUIET_SAMPLES: usize = 3;
+    let pass_budget = Duration::from_secs(timeout.clamp(1, 10));
+    let deadline = Instant::now() + Duration::from_secs(timeout);
+
+    let mut height = page_activity(tab)?.1;
+    let mut passes = 0;
+    while passes < plan.max_passes && Instant::now() < deadline {
+        tab.evaluate(
+            "window.scrollTo(0, document.documentElement.scrollHeight)",
+            false,
+        )
+        .map_err(|e| eyre!("Failed to scroll: {}", e))?;
+        passes += 1;
+
+        let pass_end = (Instant::now() + pass_budget).min(deadline);
+        let mut samples = vec![page_activity(tab)?];
+        while !is_page_settled(&samples, QUIET_SAMPLES) && Instant::now() < pass_end {
+            std::thread::sleep(POLL_INTERVAL);
+            samples.push(page_activity(tab)?);
+        }
+
+        let new_height = samples.last().map_or(height, |s| s.1);
+        if !plan.should_continue(passes, height, new_height) {
+            break;
+        }
+        height = new_height;
+    }
+    Ok(())
+}
+
+/// Turn page HTML into an unpaginated page; shared by browser and local sources.
+fn convert_html(html: String, title: Option<String>, config: &FetchConfig) -> Result<CachedPage> {
+    use mcptools_core::md::process_html_content;
 
     let html_length = html.len();
 
+    // Metadata lives in <head> and JSON-LD scripts, so read it before the
+    // selector and script stripping below.
+    let metadata = config
+        .metadata
+        .then(|| mcptools_core::meta::extract_metadata(&html, &config.url));
+
     // Step 3: Pure transformation - Process HTML content
     let processed = process_html_content(
         html,
-        config.selector,
-        config.strategy.into(),
+        config.selector.clone(),
+        config.strategy.clone().into(),
         config.index,
         config.raw_html,
+        &config.conversion,
     )
     .map_err(|e| eyre!("{}", e))?;
 
+    Ok(CachedPage {
+        url: config.url.clone(),
+        title,
+        content: processed.content,
+        html_length,
+        selector_used: processed.selector_used,
+        elements_found: processed.elements_found,
+        strategy_applied: processed.strategy_applied,
+        metadata,
+        fetched_at: chrono::Utc::now().timestamp(),
+    })
+}
+
+/// Turn a downloaded PDF into an unpaginated page: the whole document's text,
+/// as `pdf read` gives it. Selectors and HTML options don't apply.
+fn convert_pdf(bytes: &[u8], config: &FetchConfig) -> Result<CachedPage> {
+    if config.selector.is_some() || config.raw_html {
+        eprintln!("Warning: --selector and --raw-html don't apply to PDFs; ignored");
+    }
+    let section = pdf::read_section(bytes, None)
+        .map_err(|e| eyre!("Failed to read PDF from {}: {}", config.url, e))?;
+    let title = pdf::info(bytes)
+        .ok()
+        .and_then(|info| info.title)
+        .or(Some(section.title))
+        .filter(|t| !t.trim().is_empty());
+
+    Ok(CachedPage {
+        url: config.url.clone(),
+        title,
+        content: section.text,
+        html_length: bytes.len(),
+        selector_used: None,
+        elements_found: None,
+        strategy_applied: None,
+        metadata: None,
+        fetched_at: chrono::Utc::now().timestamp(),
+    })
+}
+
+/// Find the heading best matching `query` and return its title and its
+/// section: the heading line through the next same-or-higher-level heading.
+fn select_heading(content: &str, query: &str) -> Result<(String, String)> {
+    use mcptools_core::md::{find_heading, slice_content};
+
+    let entries = toc::extract_toc(content)?;
+    let titles: Vec<&str> = entries.iter().map(|e| e.text.as_str()).collect();
+    let Some(index) = find_heading(&titles, query) else {
+        const SHOWN: usize = 20;
+        let mut available = titles
+            .iter()
+            .take(SHOWN)
+            .copied()
+            .collect::<Vec<_>>()
+            .join(", ");
+        if titles.len() > SHOWN {
+            available.push_str(&format!(", ... ({} more)", titles.len() - SHOWN));
+        }
+        return Err(if titles.is_empty() {
+            eyre!("No heading matches '{}': the page has no headings", query)
+        } else {
+            eyre!("No heading matches '{}'. Headings: {}", query, available)
+        });
+    };
+    let entry = &entries[index];
+    let section = slice_content(
+        content.to_string(),
+        entry.char_offset,
+        entry.char_offset + entry.char_limit,
+    );
+    Ok((entry.text.clone(), section))
+}
+
+/// Narrow a converted page to the requested heading, if any, and apply the
+/// requested page or offset.
+fn paginate_page(
+    mut page: CachedPage,
+    config: FetchConfig,
+    start: Instant,
+    retries: u32,
+) -> Result<FetchOutput> {
+    use mcptools_core::md::{calculate_pagination, slice_content};
+
+    let heading = match &config.heading {
+        Some(query) => {
+            let (title, section) = select_heading(&page.content, query)?;
+            page.content = section;
+            Some(title)
+        }
+        None => None,
+    };
+
     // Step 4: Pure transformation - Calculate pagination and slice content
-    let total_characters = processed.content.chars().count();
+    let total_characters = page.content.chars().count();
     let (content, pagination) = if config.paginated {
         let pagination_result =
             calculate_pagination(total_characters, config.offset, config.limit, config.page);
         let content = slice_content(
-            processed.content,
+            page.content,
             pagination_result.start_offset,
             pagination_result.end_offset,
         );
@@ -137,20 +1015,23 @@ pub fn fetch_and_convert_data(config: FetchConfig) -> Result<FetchOutput> {
             limit: total_characters,
             has_more: false,
         };
-        (processed.content, pagination)
+        (page.content, pagination)
     };
 
     let fetch_time_ms = start.elapsed().as_millis() as u64;
 
     Ok(FetchOutput {
         url: config.url,
-        title,
+        title: page.title,
         content,
-        html_length,
+        html_length: page.html_length,
         fetch_time_ms,
-        selector_used: processed.selector_used,
-        elements_found: processed.elements_found,
-        strategy_applied: processed.strategy_applied,
+        retries,
+        selector_used: page.selector_used,
+        elements_found: page.elements_found,
+        strategy_applied: page.strategy_applied,
+        heading,
         pagination,
+        metadata: page.metadata,
     })
 }
@@ -0,0 +1,96 @@
+use crate::prelude::{println, *};
+use mcptools_core::diff::unified_diff;
+use mcptools_core::normalize::{normalize_markdown, LinkStyle};
+
+#[derive(Debug, Clone, Copy, clap::ValueEnum, serde::Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Links {
+    /// Leave links and reference definitions as written
+    Keep,
+    /// Resolve reference links to [text](url) and drop the definitions they used
+    Inline,
+    /// Turn inline links into [text][n] with definitions at the end
+    Reference,
+}
+
+impl From<Links> for LinkStyle {
+    fn from(links: Links) -> Self {
+        match links {
+            Links::Keep => LinkStyle::Keep,
+            Links::Inline => LinkStyle::Inline,
+            Links::Reference => LinkStyle::Reference,
+        }
+    }
+}
+
+#[derive(Debug, clap::Args, Clone)]
+pub struct NormalizeOptions {
+    /// Markdown file ('-' for stdin)
+    pub input: String,
+
+    /// Wrap paragraphs and list items at this width (0 puts each on one line)
+    #[arg(long, default_value = "0", conflicts_with = "keep_line_breaks")]
+    pub width: usize,
+
+    /// Keep the original line breaks instead of reflowing
+    #[arg(long)]
+    pub keep_line_breaks: bool,
+
+    /// Marker for unordered list items
+    #[arg(long, default_value = "-", value_parser = ["-", "*", "+"])]
+    pub bullet: String,
+
+    /// How links are written
+    #[arg(long, value_enum, default_value = "inline")]
+    pub links: Links,
+
+    /// Write the result back to the file instead of printing it
+    #[arg(short, long, conflicts_with = "check")]
+    pub write: bool,
+
+    /// Print a diff and fail if the input is not already normalized
+    #[arg(long)]
+    pub check: bool,
+}
+
+impl NormalizeOptions {
+    fn options(&self) -> mcptools_core::normalize::NormalizeOptions {
+        mcptools_core::normalize::NormalizeOptions {
+            width: (!self.keep_line_breaks).then_some(self.width),
+            bullet: self.bullet.chars().next().unwrap_or('-'),
+            links: self.links.into(),
+        }
+    }
+}
+
+pub async fn normalize(args: NormalizeOptions) -> Result<()> {
+    if args.write && args.input == "-" {
+        return Err(eyre!("--write needs a file, not stdin"));
+    }
+
+    let file = (args.input != "-").then_some(args.input.as_str());
+    let bytes = crate::hash::read_input(None, file)?;
+    let text = String::from_utf8(bytes).map_err(|_| eyre!("Input is not valid UTF-8"))?;
+    let normalized = normalize_markdown(&text, &args.options());
+
+    if args.check {
+        let diff = unified_diff(&text, &normalized, &args.input, &args.input, 3);
+        if diff.identical {
+            return Ok(());
+        }
+        print!("{}", diff.diff);
+        return Err(eyre!("{} is not normalized", args.input));
+    }
+
+    if args.write {
+        if normalized != text {
+            std::fs::write(&args.input, &normalized)
+                .map_err(|e| eyre!("Failed to write {}: {}", args.input, e))?;
+        }
+        println!("{}", args.input);
+        return Ok(());
+    }
+
+    print!("{}", normalized);
+    Ok(())
+}
@@ -0,0 +1,161 @@
+use crate::prelude::{println, *};
+use headless_chrome::types::PrintToPdfOptions;
+use headless_chrome::Browser;
+
+use mcptools_core::md::{parse_margins, PageMargins, PaperSize, PdfExportOutput};
+
+#[derive(Debug, Clone, Copy, clap::ValueEnum)]
+pub enum PaperSizeArg {
+    Letter,
+    Legal,
+    Tabloid,
+    A3,
+    A4,
+    A5,
+}
+
+impl From<PaperSizeArg> for PaperSize {
+    fn from(p: PaperSizeArg) -> Self {
+        match p {
+            PaperSizeArg::Letter => PaperSize::Letter,
+            PaperSizeArg::Legal => PaperSize::Legal,
+            PaperSizeArg::Tabloid => PaperSize::Tabloid,
+            PaperSizeArg::A3 => PaperSize::A3,
+            PaperSizeArg::A4 => PaperSize::A4,
+            PaperSizeArg::A5 => PaperSize::A5,
+        }
+    }
+}
+
+#[derive(Debug, clap::Args, Clone)]
+pub struct PdfOptions {
+    /// URL to export
+    #[arg(env = "MD_URL")]
+    pub url: String,
+
+    /// Output PDF file
+    #[arg(short, long)]
+    pub out: String,
+
+    /// Paper size
+    #[arg(long, value_enum, default_value = "letter")]
+    pub paper: PaperSizeArg,
+
+    /// Use landscape orientation
+    #[arg(long)]
+    pub landscape: bool,
+
+    /// Page margins, CSS shorthand: "1cm", "0.5in 1in", or "10mm 5mm 10mm 5mm"
+    #[arg(long)]
+    pub margin: Option<String>,
+
+    /// Print background colors and images
+    #[arg(long)]
+    pub background: bool,
+
+    /// Scale of the page rendering (0.1 to 2.0)
+    #[arg(long, default_value_t = 1.0)]
+    pub scale: f64,
+
+    /// Timeout in seconds (default: 30)
+    #[arg(short, long, env = "MD_TIMEOUT", default_value = "30")]
+    pub timeout: u64,
+
+    /// Output as JSON
+    #[arg(long)]
+    pub json: bool,
+}
+
+#[derive(Debug, Clone)]
+pub struct PdfExportConfig {
+    pub url: String,
+    pub paper: PaperSize,
+    pub landscape: bool,
+    pub margins: PageMargins,
+    pub background: bool,
+    pub scale: f64,
+    pub timeout: u64,
+}
+
+pub async fn pdf(options: PdfOptions) -> Result<()> {
+    if !(0.1..=2.0).contains(&options.scale) {
+        return Err(eyre!("--scale must be between 0.1 and 2.0"));
+    }
+    let margins = options
+        .margin
+        .as_deref()
+        .map(parse_margins)
+        .transpose()
+        .map_err(|e| eyre!(e))?
+        .unwrap_or_default();
+
+    let config = PdfExportConfig {
+        url: options.url.clone(),
+        paper: options.paper.into(),
+        landscape: options.landscape,
+        margins,
+        background: options.background,
+        scale: options.scale,
+        timeout: options.timeout,
+    };
+
+    // Use spawn_blocking since headless_chrome is synchronous
+    let bytes = tokio::task::spawn_blocking({
+        let config = config.clone();
+        move || print_to_pdf_data(config)
+    })
+    .await??;
+
+    std::fs::write(&options.out, &bytes)
+        .map_err(|e| eyre!("Failed to write PDF to {}: {}", options.out, e))?;
+
+    let output = PdfExportOutput {
+        url: config.url,
+        path: options.out,
+        bytes: bytes.len(),
+        paper: config.paper,
+        landscape: config.landscape,
+    };
+
+    if options.json {
+        println!("{}", serde_json::to_string_pretty(&output)?);
+    } else {
+        println!("{} ({} bytes)", output.path, output.bytes);
+    }
+
+    Ok(())
+}
+
+/// Load a web page in headless Chrome and print it to PDF.
+pub fn print_to_pdf_data(config: PdfExportConfig) -> Result<Vec<u8>> {
+    let browser = super::launch_browser(None)?;
+
+    let tab = browser
+        .new_tab()
+        .map_err(|e| eyre!("Failed to create new tab: {}", e))?;
+
+    tab.set_default_timeout(std::time::Duration::from_secs(config.timeout));
+
+    tab.navigate_to(&config.url)
+        .map_err(|e| eyre!("Failed to navigate to {}: {}", config.url, e))?
+        .wait_until_navigated()
+        .map_err(|e| eyre!("Failed to wait for navigation: {}", e))?;
+
+    // Chrome swaps width and height itself when landscape is set.
+    let (paper_width, paper_height) = config.paper.dimensions();
+    let options = PrintToPdfOptions {
+        landscape: Some(config.landscape),
+        print_background: Some(config.background),
+        scale: Some(config.scale),
+        paper_width: Some(paper_width),
+        paper_height: Some(paper_height),
+        margin_top: Some(config.margins.top),
+        margin_bottom: Some(config.margins.bottom),
+        margin_left: Some(config.margins.left),
+        margin_right: Some(config.margins.right),
+        ..Default::default()
+    };
+
+    tab.print_to_pdf(Some(options))
+        .map_err(|e| eyre!("Failed to print {} to PDF: {}", config.url, e))
+}
@@ -0,0 +1,75 @@
+use crate::prelude::{eprintln, *};
+use std::collections::HashMap;
+use std::time::Duration;
+
+use mcptools_core::md::{
+    parse_robots_txt, robots_product_token, robots_request_path, robots_txt_url, RobotsRules,
+};
+
+/// Error recorded for URLs that robots.txt excludes.
+pub(super) const DISALLOWED: &str = "Disallowed by robots.txt (use --ignore-robots to override)";
+
+const ROBOTS_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Fetches each origin's robots.txt once and answers whether URLs on it may
+/// be crawled. Non-http(s) URLs are always allowed.
+pub(super) struct RobotsChecker {
+    client: reqwest::Client,
+    user_agent: Option<String>,
+    product_token: String,
+    rules: HashMap<String, RobotsRules>,
+}
+
+impl RobotsChecker {
+    pub(super) fn new(user_agent: Option<&str>) -> Self {
+        Self {
+            client: reqwest::Client::new(),
+            user_agent: user_agent.map(str::to_string),
+            product_token: robots_product_token(user_agent),
+            rules: HashMap::new(),
+        }
+    }
+
+    pub(super) async fn is_allowed(&mut self, url: &str) -> bool {
+        let (Some(robots_url), Some(path)) = (robots_txt_url(url), robots_request_path(url)) else {
+            return true;
+        };
+        if !self.rules.contains_key(&robots_url) {
+            let rules = self.fetch_rules(&robots_url).await;
+            self.rules.insert(robots_url.clone(), rules);
+        }
+        self.rules[&robots_url].is_allowed(&path)
+    }
+
+    /// A missing robots.txt (4xx) allows everything; a server error or an
+    /// unreachable host disallows everything, as RFC 9309 asks.
+    async fn fetch_rules(&self, robots_url: &str) -> RobotsRules {
+        let mut request = self.client.get(robots_url).timeout(ROBOTS_TIMEOUT);
+        if let Some(user_agent) = &self.user_agent {
+            request = request.header(reqwest::header::USER_AGENT, user_agent);
+        }
+        let response = match request.send().await {
+            Ok(response) => response,
+            Err(e) => {
+                eprintln!(
+                    "Warning: could not fetch {} ({}); skipping that site",
+                    robots_url, e
+                );
+                return RobotsRules::disallow_all();
+            }
+        };
+        let status = response.status();
+        if status.is_success() {
+            let text = response.text().await.unwrap_or_default();
+            parse_robots_txt(&text, &self.product_token)
+        } else if status.is_server_error() {
+            eprintln!(
+                "Warning: {} returned {}; skipping that site",
+                robots_url, status
+            );
+            RobotsRules::disallow_all()
+        } else {
+            RobotsRules::allow_all()
+        }
+    }
+}
@@ -0,0 +1,166 @@
+use crate::prelude::{println, *};
+use base64::Engine;
+use headless_chrome::protocol::cdp::Page;
+use headless_chrome::Browser;
+
+use mcptools_core::md::{
+    image_dimensions, screenshot_format_for_path, ScreenshotFormat, ScreenshotOutput,
+};
+
+#[derive(Debug, clap::Args, Clone)]
+pub struct ScreenshotOptions {
+    /// URL to capture
+    #[arg(env = "MD_URL")]
+    pub url: String,
+
+    /// Output file (.png or .jpg). Prints base64 image data when omitted
+    #[arg(short, long)]
+    pub out: Option<String>,
+
+    /// Capture the full scrollable page instead of the viewport
+    #[arg(long, conflicts_with = "selector")]
+    pub full_page: bool,
+
+    /// CSS selector of a single element to capture
+    #[arg(long)]
+    pub selector: Option<String>,
+
+    /// Timeout in seconds (default: 30)
+    #[arg(short, long, env = "MD_TIMEOUT", default_value = "30")]
+    pub timeout: u64,
+
+    /// Output as JSON
+    #[arg(long)]
+    pub json: bool,
+}
+
+#[derive(Debug, Clone)]
+pub struct ScreenshotConfig {
+    pub url: String,
+    pub full_page: bool,
+    pub selector: Option<String>,
+    pub format: ScreenshotFormat,
+    pub timeout: u64,
+}
+
+pub async fn screenshot(options: ScreenshotOptions) -> Result<()> {
+    let format = options
+        .out
+        .as_deref()
+        .map(screenshot_format_for_path)
+        .unwrap_or_default();
+
+    let config = ScreenshotConfig {
+        url: options.url.clone(),
+        full_page: options.full_page,
+        selector: options.selector.clone(),
+        format,
+        timeout: options.timeout,
+    };
+
+    // Use spawn_blocking since headless_chrome is synchronous
+    let (mut output, bytes) =
+        tokio::task::spawn_blocking(move || capture_screenshot_data(config)).await??;
+
+    match &options.out {
+        Some(path) => {
+            std::fs::write(path, &bytes)
+                .map_err(|e| eyre!("Failed to write screenshot to {}: {}", path, e))?;
+            output.path = Some(path.clone());
+        }
+        None => {
+            output.data = Some(base64::engine::general_purpose::STANDARD.encode(&bytes));
+        }
+    }
+
+    if options.json {
+        println!("{}", serde_json::to_string_pretty(&output)?);
+    } else if let Some(path) = &output.path {
+        println!("{} ({}x{})", path, output.width, output.height);
+    } else if let Some(data) = &output.data {
+        println!("{}", data);
+    }
+
+    Ok(())
+}
+
+/// Public function for MCP reuse - capture a screenshot of a web page.
+///
+/// Returns the capture metadata (without `path` or `data`) and the image bytes.
+pub fn capture_screenshot_data(config: ScreenshotConfig) -> Result<(ScreenshotOutput, Vec<u8>)> {
+    let browser = super::launch_browser(None)?;
+
+    let tab = browser
+        .new_tab()
+        .map_err(|e| eyre!("Failed to create new tab: {}", e))?;
+
+    tab.set_default_timeout(std::time::Duration::from_secs(config.timeout));
+
+    tab.navigate_to(&config.url)
+        .map_err(|e| eyre!("Failed to navigate to {}: {}", config.url, e))?
+        .wait_until_navigated()
+        .map_err(|e| eyre!("Failed to wait for navigation: {}", e))?;
+
+    let (format, quality) = match config.format {
+        ScreenshotFormat::Png => (Page::CaptureScreenshotFormatOption::Png, None),
+        ScreenshotFormat::Jpeg => (Page::CaptureScreenshotFormatOption::Jpeg, Some(90)),
+    };
+
+    let bytes = if let Some(selector) = &config.selector {
+        let element = tab
+            .wait_for_element(selector)
+            .map_err(|e| eyre!("No element matches selector '{}': {}", selector, e))?;
+        element
+            .scroll_into_view()
+            .map_err(|e| eyre!("Failed to scroll to element: {}", e))?;
+        let clip = element
+            .get_box_model()
+            .map_err(|e| eyre!("Failed to measure element: {}", e))?
+            .border_viewport();
+        tab.capture_screenshot(format, quality, Some(clip), true)
+            .map_err(|e| eyre!("Failed to capture screenshot: {}", e))?
+    } else if config.full_page {
+        // Clip to the full content size and let Chrome render beyond the viewport.
+        let size = tab
+            .call_method(Page::GetLayoutMetrics(None))
+            .map_err(|e| eyre!("Failed to read page size: {}", e))?
+            .css_content_size;
+        let data = tab
+            .call_method(Page::CaptureScreenshot {
+                format: Some(format),
+                quality,
+                clip: Some(Page::Viewport {
+                    x: 0.0,
+                    y: 0.0,
+                    width: size.width,
+                    height: size.height,
+                    scale: 1.0,
+                }),
+                from_surface: Some(true),
+                capture_beyond_viewport: Some(true),
+                optimize_for_speed: None,
+            })
+            .map_err(|e| eyre!("Failed to capture screenshot: {}", e))?
+            .data;
+        base64::engine::general_purpose::STANDARD
+            .decode(data)
+            .map_err(|e| eyre!("Invalid screenshot data: {}", e))?
+    } else {
+        tab.capture_screenshot(format, quality, None, true)
+            .map_err(|e| eyre!("Failed to capture screenshot: {}", e))?
+    };
+
+    let (width, height) = image_dimensions(&bytes).unwrap_or((0, 0));
+    let output = ScreenshotOutput {
+        url: config.url,
+        format: config.format,
+        width,
+        height,
+        full_page: config.full_page,
+        selector: config.selector,
+        path: None,
+        data: None,
+    };
+
+    Ok((output, bytes))
+}
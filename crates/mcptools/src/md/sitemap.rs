@@ -0,0 +1,244 @@
+use crate::prelude::{eprintln, println, *};
+use mcptools_core::sitemap::{matches_filter, parse_sitemap, Sitemap, SitemapEntry, SitemapFilter};
+use std::collections::{HashSet, VecDeque};
+
+use super::{FetchConfig, SelectionStrategy};
+
+#[derive(Debug, clap::Args, Clone)]
+pub struct SitemapOptions {
+    /// Sitemap or sitemap index URL (e.g. https://example.com/sitemap.xml)
+    #[arg(env = "MD_SITEMAP_URL")]
+    pub url: String,
+
+    /// Keep URLs whose path starts with this prefix (or a full URL prefix)
+    #[arg(long)]
+    pub path_prefix: Option<String>,
+
+    /// Keep URLs whose <lastmod> is on or after this date (YYYY-MM-DD)
+    #[arg(long)]
+    pub since: Option<chrono::NaiveDate>,
+
+    /// Keep at most this many URLs, in sitemap order
+    #[arg(long)]
+    pub limit: Option<usize>,
+
+    /// Maximum sitemaps read when following a sitemap index
+    #[arg(long, default_value = "50")]
+    pub max_sitemaps: usize,
+
+    /// Fetch every matching URL and print one JSON record per line (same
+    /// format as batch `md fetch`) instead of listing URLs
+    #[arg(long)]
+    pub fetch: bool,
+
+    /// Maximum pages fetched at once with --fetch
+    #[arg(long, env = "MD_CONCURRENCY", default_value = "4")]
+    pub concurrency: usize,
+
+    /// With --fetch, also fetch URLs that robots.txt disallows
+    #[arg(long)]
+    pub ignore_robots: bool,
+
+    /// List entries as JSON (loc, lastmod, changefreq, priority)
+    #[arg(long, conflicts_with = "fetch")]
+    pub json: bool,
+
+    /// Timeout in seconds for each sitemap and page (default: 30)
+    #[arg(short, long, env = "MD_TIMEOUT", default_value = "30")]
+    pub timeout: u64,
+
+    /// CSS selector to filter fetched content (optional)
+    #[arg(long, env = "MD_SELECTOR")]
+    pub selector: Option<String>,
+
+    /// Strategy for selecting elements when multiple match (default: first)
+    #[arg(long, env = "MD_STRATEGY", default_value = "first")]
+    pub strategy: SelectionStrategy,
+
+    /// Index for 'n' strategy (0-indexed)
+    #[arg(long, env = "MD_INDEX")]
+    pub index: Option<usize>,
+
+    #[command(flatten)]
+    pub network: super::NetworkArgs,
+
+    #[command(flatten)]
+    pub browser: super::BrowserArgs,
+}
+
+/// Read a sitemap and, for an index, the sitemaps it lists (up to
+/// `max_sitemaps`), returning page entries in order without duplicates.
+async fn collect_entries(
+    client: &reqwest::Client,
+    url: &str,
+    max_sitemaps: usize,
+) -> Result<Vec<SitemapEntry>> {
+    let mut queue = VecDeque::from([url.to_string()]);
+    let mut visited = HashSet::new();
+    let mut seen_pages = HashSet::new();
+    let mut entries = Vec::new();
+
+    while let Some(sitemap_url) = queue.pop_front() {
+        if !visited.insert(sitemap_url.clone()) {
+            continue;
+        }
+        if visited.len() > max_sitemaps {
+            eprintln!(
+                "Stopped after {} sitemaps (--max-sitemaps); {} not read",
+                max_sitemaps,
+                queue.len() + 1
+            );
+            break;
+        }
+
+        let content = match fetch_sitemap(client, &sitemap_url).await {
+            Ok(content) => content,
+            // The top-level sitemap must load; nested ones are skipped.
+            Err(e) if sitemap_url != url => {
+                eprintln!("Skipping {}: {}", sitemap_url, e);
+                continue;
+            }
+            Err(e) => return Err(e),
+        };
+
+        match parse_sitemap(&content).map_err(|e| eyre!("{}: {}", sitemap_url, e))? {
+            Sitemap::Index(sitemaps) => queue.extend(sitemaps.into_iter().map(|s| s.loc)),
+            Sitemap::UrlSet(pages) => entries.extend(
+                pages
+                    .into_iter()
+                    .filter(|page| seen_pages.insert(page.loc.clone())),
+            ),
+        }
+    }
+
+    Ok(entries)
+}
+
+async fn fetch_sitemap(client: &reqwest::Client, url: &str) -> Result<String> {
+    let response = client
+        .get(url)
+        .send()
+        .await
+        .map_err(|e| eyre!("Failed to fetch {}: {}", url, e))?;
+    if !response.status().is_success() {
+        return Err(eyre!("Failed to fetch {}: HTTP {}", url, response.status()));
+    }
+    let bytes = response.bytes().await?;
+    if bytes.starts_with(&[0x1f, 0x8b]) {
+        return Err(eyre!(
+            "{} is gzip-compressed; use the uncompressed sitemap URL",
+            url
+        ));
+    }
+    Ok(String::from_utf8_lossy(&bytes).into_owned())
+}
+
+/// HTTP client for reading XML documents (sitemaps, feeds) with the
+/// request's headers, cookies, User-Agent, and proxy.
+pub(super) fn build_client(
+    timeout: u64,
+    proxy: Option<&str>,
+    user_agent: Option<&str>,
+    headers: &[(String, String)],
+    cookies: &[(String, String)],
+) -> Result<reqwest::Client> {
+    use reqwest::header::{HeaderMap, HeaderName, HeaderValue};
+
+    let mut header_map = HeaderMap::new();
+    for (name, value) in headers {
+        header_map.insert(
+            HeaderName::from_bytes(name.as_bytes())?,
+            HeaderValue::from_str(value)?,
+        );
+    }
+    if !cookies.is_empty() {
+        let cookie = cookies
+            .iter()
+            .map(|(name, value)| format!("{}={}", name, value))
+            .collect::<Vec<_>>()
+            .join("; ");
+        header_map.insert(reqwest::header::COOKIE, HeaderValue::from_str(&cookie)?);
+    }
+
+    let mut builder = reqwest::Client::builder()
+        .timeout(std::time::Duration::from_secs(timeout))
+        .default_headers(header_map);
+    if let Some(user_agent) = user_agent {
+        builder = builder.user_agent(user_agent);
+    }
+    if let Some(proxy) = proxy {
+        let proxy = mcptools_core::md::parse_proxy_arg(proxy).map_err(|e| eyre!(e))?;
+        let proxy = if proxy.contains("://") {
+            proxy
+        } else {
+            format!("http://{}", proxy)
+        };
+        builder = builder.proxy(reqwest::Proxy::all(&proxy)?);
+    }
+    Ok(builder.build()?)
+}
+
+pub async fn sitemap(options: SitemapOptions) -> Result<()> {
+    if matches!(options.strategy, SelectionStrategy::N) && options.index.is_none() {
+        return Err(eyre!(
+            "Strategy 'n' requires --index parameter to specify which element to select"
+        ));
+    }
+
+    let base_config = FetchConfig {
+        timeout: options.timeout,
+        selector: options.selector.clone(),
+        strategy: options.strategy.clone(),
+        index: options.index,
+        ..FetchConfig::from_args(&options.network, &options.browser)?
+    };
+    let client = build_client(
+        base_config.timeout,
+        base_config.proxy.as_deref(),
+        base_config.user_agent.as_deref(),
+        &base_config.headers,
+        &base_config.cookies,
+    )?;
+
+    let filter = SitemapFilter {
+        path_prefix: options.path_prefix.clone(),
+        lastmod_after: options.since,
+    };
+    let entries: Vec<SitemapEntry> = collect_entries(&client, &options.url, options.max_sitemaps)
+        .await?
+        .into_iter()
+        .filter(|entry| matches_filter(entry, &filter))
+        .take(options.limit.unwrap_or(usize::MAX))
+        .collect();
+
+    if entries.is_empty() {
+        eprintln!("No URLs matched");
+        return Ok(());
+    }
+
+    if options.fetch {
+        let urls: Vec<String> = entries.into_iter().map(|entry| entry.loc).collect();
+        let config = FetchConfig {
+            url: urls[0].clone(),
+            ..base_config
+        };
+        return super::fetch::fetch_batch(
+            urls,
+            config,
+            options.concurrency,
+            options.ignore_robots,
+            None,
+        )
+        .await;
+    }
+
+    if options.json {
+        println!("{}", serde_json::to_string_pretty(&entries)?);
+    } else {
+        for entry in &entries {
+            println!("{}", entry.loc);
+        }
+    }
+
+    Ok(())
+}
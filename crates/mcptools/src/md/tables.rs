@@ -0,0 +1,111 @@
+use crate::prelude::{println, *};
+use serde::Serialize;
+
+use mcptools_core::html::{extract_tables, table_to_csv, HtmlTable};
+
+use super::{fetch_and_convert_data, FetchConfig, SelectionStrategy};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum TableFormat {
+    /// One object per table with headers and rows
+    Json,
+    /// RFC 4180 CSV; several tables are separated by a blank line
+    Csv,
+}
+
+#[derive(Debug, clap::Args, Clone)]
+pub struct TablesOptions {
+    /// URL of the page to read (a file:// URL reads saved HTML)
+    #[arg(env = "MD_URL")]
+    pub url: String,
+
+    /// CSS selector limiting which part of the page tables are taken from
+    #[arg(long, env = "MD_SELECTOR")]
+    pub selector: Option<String>,
+
+    /// Strategy for selecting elements when multiple match (default: first)
+    #[arg(long, env = "MD_STRATEGY", default_value = "first")]
+    pub strategy: SelectionStrategy,
+
+    /// Index for 'n' strategy (0-indexed)
+    #[arg(long, env = "MD_INDEX")]
+    pub index: Option<usize>,
+
+    /// Output only this table (0-indexed, in document order)
+    #[arg(long, value_name = "N")]
+    pub table: Option<usize>,
+
+    /// Output format
+    #[arg(long, value_enum, default_value = "json")]
+    pub format: TableFormat,
+
+    /// Timeout in seconds (default: 30)
+    #[arg(short, long, env = "MD_TIMEOUT", default_value = "30")]
+    pub timeout: u64,
+
+    #[command(flatten)]
+    pub network: super::NetworkArgs,
+
+    #[command(flatten)]
+    pub browser: super::BrowserArgs,
+}
+
+#[derive(Debug, Serialize)]
+pub struct TablesOutput {
+    pub url: String,
+    pub title: Option<String>,
+    pub tables: Vec<HtmlTable>,
+}
+
+pub async fn tables(options: TablesOptions) -> Result<()> {
+    if matches!(options.strategy, SelectionStrategy::N) && options.index.is_none() {
+        return Err(eyre!(
+            "Strategy 'n' requires --index parameter to specify which element to select"
+        ));
+    }
+
+    let config = FetchConfig {
+        url: options.url,
+        timeout: options.timeout,
+        // Tables are read from the selected HTML, not the Markdown.
+        raw_html: true,
+        selector: options.selector,
+        strategy: options.strategy,
+        index: options.index,
+        limit: usize::MAX,
+        ..FetchConfig::from_args(&options.network, &options.browser)?
+    };
+
+    let fetched = tokio::task::spawn_blocking(move || fetch_and_convert_data(config)).await??;
+    let mut tables = extract_tables(&fetched.content);
+    if let Some(n) = options.table {
+        let count = tables.len();
+        tables.retain(|t| t.index == n);
+        if tables.is_empty() {
+            return Err(eyre!(
+                "No table {} on the page ({} tables found, numbered from 0)",
+                n,
+                count
+            ));
+        }
+    } else if tables.is_empty() {
+        return Err(eyre!("No tables found in the selected content"));
+    }
+
+    match options.format {
+        TableFormat::Json => {
+            let output = TablesOutput {
+                url: fetched.url,
+                title: fetched.title,
+                tables,
+            };
+            println!("{}", serde_json::to_string_pretty(&output)?);
+        }
+        TableFormat::Csv => {
+            let csv: Vec<String> = tables.iter().map(table_to_csv).collect();
+            anstream::print!("{}", csv.join("\n"));
+        }
+    }
+
+    Ok(())
+}
@@ -28,6 +28,14 @@ pub struct TocOptions {
     #[arg(long, env = "MD_INDEX")]
     pub index: Option<usize>,
 
+    #[command(flatten)]
+    #[serde(flatten)]
+    pub network: super::NetworkArgs,
+
+    #[command(flatten)]
+    #[serde(flatten)]
+    pub browser: super::BrowserArgs,
+
     /// Output format: indented, markdown, or json (default: indented)
     #[arg(long, env = "MD_OUTPUT", default_value = "indented")]
     pub output: OutputFormat,
@@ -104,14 +112,11 @@ pub fn extract_toc_data(options: TocOptions) -> Result<TocOutput> {
     let fetch_output = fetch_and_convert_data(super::FetchConfig {
         url: options.url.clone(),
         timeout: options.timeout,
-        raw_html: false, // Always convert to markdown
         selector: options.selector.clone(),
         strategy: options.strategy,
         index: options.index,
-        offset: 0,         // No offset
         limit: usize::MAX, // Get all content
-        page: 1,           // First page
-        paginated: false,  // No pagination for TOC
+        ..super::FetchConfig::from_args(&options.network, &options.browser)?
     })?;
 
     // Extract TOC entries from markdown
@@ -127,9 +132,12 @@ pub fn extract_toc_data(options: TocOptions) -> Result<TocOutput> {
     })
 }
 
-/// Parse markdown content and extract headings with character offsets
-fn extract_toc(markdown: &str) -> Result<Vec<TocEntry>> {
-    let heading_regex = Regex::new(r"^(#{1,6})\s+(.+)$").unwrap();
+/// Parse markdown content and extract headings with character offsets.
+/// Recognizes ATX headings (with or without closing `#`s) and the setext
+/// headings html2md writes for H1 and H2.
+pub(super) fn extract_toc(markdown: &str) -> Result<Vec<TocEntry>> {
+    let heading_regex = Regex::new(r"^(#{1,6})\s+(.+?)(?:\s+#+)?\s*$").unwrap();
+    let setext_regex = Regex::new(r"^(=+|-+)\s*$").unwrap();
     let mut entries = Vec::new();
     let mut char_position = 0;
 
@@ -141,9 +149,25 @@ fn extract_toc(markdown: &str) -> Result<Vec<TocEntry>> {
         char_offset: usize,
     }
 
-    let mut headings = Vec::new();
+    let mut headings: Vec<HeadingInfo> = Vec::new();
+    // Offset and text of the previous line, if it could be a setext title
+    let mut previous: Option<(usize, &str)> = None;
 
     for line in markdown.lines() {
+        if let (Some(caps), Some((offset, title))) = (setext_regex.captures(line), previous) {
+            let level = if caps[1].starts_with('=') { 1 } else { 2 };
+            headings.push(HeadingInfo {
+                level,
+                text: title.trim().to_string(),
+                char_offset: offset,
+            });
+            char_position += line.chars().count() + 1;
+            previous = None;
+            continue;
+        }
+        previous = (!line.trim().is_empty() && !heading_regex.is_match(line))
+            .then_some((char_position, line));
+
         if let Some(caps) = heading_regex.captures(line) {
             let level = caps.get(1).unwrap().as_str().len();
             let text = caps.get(2).unwrap().as_str().trim().to_string();
@@ -445,6 +469,8 @@ mod tests {
             selector: None,
             strategy: SelectionStrategy::First,
             index: None,
+            network: Default::default(),
+            browser: Default::default(),
             output: OutputFormat::Indented,
             json: false,
         };
@@ -479,6 +505,8 @@ mod tests {
             selector: None,
             strategy: SelectionStrategy::First,
             index: None,
+            network: Default::default(),
+            browser: Default::default(),
             output: OutputFormat::Markdown,
             json: false,
         };
@@ -500,6 +528,8 @@ mod tests {
             selector: Some("article".to_string()),
             strategy: SelectionStrategy::First,
             index: None,
+            network: Default::default(),
+            browser: Default::default(),
             output: OutputFormat::Indented,
             json: false,
         };
@@ -524,6 +554,8 @@ mod tests {
             selector: None,
             strategy: SelectionStrategy::First,
             index: None,
+            network: Default::default(),
+            browser: Default::default(),
             output: OutputFormat::Indented,
             json: false,
         };
@@ -546,6 +578,8 @@ mod tests {
             selector: None,
             strategy: SelectionStrategy::First,
             index: None,
+            network: Default::default(),
+            browser: Default::default(),
             output: OutputFormat::Indented,
             json: false,
         };
@@ -564,6 +598,8 @@ mod tests {
             selector: None,
             strategy: SelectionStrategy::First,
             index: None,
+            network: Default::default(),
+            browser: Default::default(),
             output: OutputFormat::Indented,
             json: false,
         };
@@ -584,6 +620,8 @@ mod tests {
             selector: None,
             strategy: SelectionStrategy::First,
             index: None,
+            network: Default::default(),
+            browser: Default::default(),
             output: OutputFormat::Indented,
             json: false,
         };
@@ -615,6 +653,23 @@ mod tests {
         assert_eq!(entries[0].char_limit, markdown.chars().count());
     }
 
+    #[test]
+    fn test_extract_toc_setext_and_closed_atx() {
+        // As html2md writes H1, H2, and H3
+        let markdown = "Guide\n==========\n\nIntro\n\nSetup\n----------\n\n### Env ###\n\nText";
+        let entries = extract_toc(markdown).unwrap();
+
+        let headings: Vec<(usize, &str, usize)> = entries
+            .iter()
+            .map(|e| (e.level, e.text.as_str(), e.char_offset))
+            .collect();
+        assert_eq!(
+            headings,
+            vec![(1, "Guide", 0), (2, "Setup", 25), (3, "Env", 43)]
+        );
+        assert_eq!(entries[1].char_limit, markdown.chars().count() - 25);
+    }
+
     #[test]
     fn test_extract_toc_multiple_same_level() {
         let markdown = "# First\nContent 1\n# Second\nContent 2\n# Third\nContent 3";
@@ -0,0 +1,179 @@
+use crate::prelude::{eprintln, println, *};
+use colored::Colorize;
+use std::time::Duration;
+
+use mcptools_core::md::{diff_snapshots, PageSnapshot, WatchEvent};
+
+use super::diff::{load_snapshot, print_page_diff, save_snapshot, snapshot_path};
+use super::{fetch_and_convert_data, FetchConfig, SelectionStrategy};
+
+#[derive(Debug, clap::Args, Clone)]
+pub struct WatchOptions {
+    /// URL of the page to watch
+    #[arg(env = "MD_URL")]
+    pub url: String,
+
+    /// Seconds between checks
+    #[arg(long, value_name = "SECONDS", default_value = "300")]
+    pub interval: u64,
+
+    /// CSS selector to watch only part of the page; snapshots are shared
+    /// with md diff per URL and selector
+    #[arg(long, env = "MD_SELECTOR")]
+    pub selector: Option<String>,
+
+    /// Stop after this many checks (default: run until interrupted)
+    #[arg(long, value_name = "N")]
+    pub count: Option<u64>,
+
+    /// Unchanged lines shown around each change
+    #[arg(long, default_value = "3")]
+    pub context: usize,
+
+    /// Print one JSON event per line: baseline, changed, or error
+    #[arg(long)]
+    pub json: bool,
+
+    /// Keep snapshots in memory only, leaving md diff's stored snapshot alone
+    #[arg(long)]
+    pub no_save: bool,
+
+    /// Timeout in seconds per check (default: 30)
+    #[arg(short, long, env = "MD_TIMEOUT", default_value = "30")]
+    pub timeout: u64,
+
+    #[command(flatten)]
+    pub network: super::NetworkArgs,
+
+    #[command(flatten)]
+    pub browser: super::BrowserArgs,
+}
+
+pub async fn watch(options: WatchOptions) -> Result<()> {
+    if options.interval == 0 {
+        return Err(eyre!("--interval must be at least 1 second"));
+    }
+    let config = FetchConfig {
+        url: options.url.clone(),
+        timeout: options.timeout,
+        selector: options.selector.clone(),
+        // Every check must see the live page.
+        cache_ttl: None,
+        ..FetchConfig::from_args(&options.network, &options.browser)?
+    };
+
+    let path = snapshot_path(&options.url, options.selector.as_deref())?;
+    let mut previous = if options.no_save {
+        None
+    } else {
+        load_snapshot(&path)?
+    };
+    if !options.json {
+        eprintln!(
+            "Watching {} every {}s{}; press Ctrl-C to stop",
+            options.url,
+            options.interval,
+            previous
+                .as_ref()
+                .map(|_| " against the stored snapshot")
+                .unwrap_or_default()
+        );
+    }
+
+    let mut checks = 0;
+    loop {
+        checks += 1;
+        let fetch_config = config.clone();
+        let fetched = tokio::task::spawn_blocking(move || fetch_and_convert_data(fetch_config))
+            .await
+            .map_err(|e| eyre!("Task join error: {}", e))
+            .and_then(|result| result);
+        let now = chrono::Utc::now().timestamp();
+
+        match fetched {
+            Err(e) => emit_error(&options, now, e.to_string())?,
+            Ok(fetched) => {
+                let current = PageSnapshot {
+                    url: options.url.clone(),
+                    selector: options.selector.clone(),
+                    title: fetched.title,
+                    content: fetched.content,
+                    fetched_at: now,
+                };
+                match &previous {
+                    None => {
+                        if options.json {
+                            println!(
+                                "{}",
+                                serde_json::to_string(&WatchEvent::baseline(&current))?
+                            );
+                        } else {
+                            eprintln!("Baseline recorded at {}", local_time(now));
+                        }
+                    }
+                    Some(previous) => {
+                        let result = diff_snapshots(previous, &current, options.context);
+                        if result.identical {
+                            if !options.json {
+                                eprintln!(
+                                    "{}",
+                                    format!("No changes at {}", local_time(now)).dimmed()
+                                );
+                            }
+                            if options.count == Some(checks) {
+                                break;
+                            }
+                            tokio::time::sleep(Duration::from_secs(options.interval)).await;
+                            continue;
+                        }
+                        if options.json {
+                            println!("{}", serde_json::to_string(&WatchEvent::Changed(result))?);
+                        } else {
+                            print_page_diff(&result);
+                            println!();
+                        }
+                    }
+                }
+                if !options.no_save {
+                    // A full disk should not end the watch; report and go on.
+                    if let Err(e) = save_snapshot(&path, &current) {
+                        emit_error(&options, now, e.to_string())?;
+                    }
+                }
+                previous = Some(current);
+            }
+        }
+
+        if options.count == Some(checks) {
+            break;
+        }
+        tokio::time::sleep(Duration::from_secs(options.interval)).await;
+    }
+    Ok(())
+}
+
+fn emit_error(options: &WatchOptions, at: i64, error: String) -> Result<()> {
+    if options.json {
+        println!(
+            "{}",
+            serde_json::to_string(&WatchEvent::error(&options.url, at, error))?
+        );
+    } else {
+        eprintln!(
+            "{} {}",
+            format!("Check at {} failed:", local_time(at)).red(),
+            error
+        );
+    }
+    Ok(())
+}
+
+fn local_time(secs: i64) -> String {
+    chrono::DateTime::from_timestamp(secs, 0)
+        .map(|t| {
+            t.with_timezone(&chrono::Local)
+                .format("%Y-%m-%d %H:%M:%S")
+                .to_string()
+        })
+        .unwrap_or_default()
+}
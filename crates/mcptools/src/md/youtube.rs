@@ -0,0 +1,216 @@
+use crate::prelude::{println, *};
+use colored::Colorize;
+
+use mcptools_core::md::{calculate_pagination, slice_content, MdPaginationInfo};
+use mcptools_core::transcribe::MergeOptions;
+use mcptools_core::youtube::{
+    extract_video_id, json3_url, parse_json3, parse_watch_page, render_transcript_markdown,
+    select_track, watch_url, VideoInfo, YoutubeTranscriptOutput,
+};
+
+/// Browser-like user agent; YouTube serves a stripped page to unknown clients.
+const USER_AGENT: &str = "Mozilla/5.0 (X11; Linux x86_64) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/126.0 Safari/537.36";
+
+#[derive(Debug, clap::Args, Clone)]
+pub struct YoutubeOptions {
+    /// YouTube video URL or ID
+    pub url: String,
+
+    /// Caption language code (e.g. en, es, pt-BR). Defaults to the first track
+    #[arg(long, env = "YOUTUBE_LANG")]
+    pub lang: Option<String>,
+
+    /// List available caption tracks instead of fetching a transcript
+    #[arg(long)]
+    pub list_tracks: bool,
+
+    /// Timeout in seconds (default: 30)
+    #[arg(short, long, env = "MD_TIMEOUT", default_value = "30")]
+    pub timeout: u64,
+
+    /// Output as JSON
+    #[arg(long)]
+    pub json: bool,
+
+    /// Enable pagination (automatically enabled when --offset, --limit, or --page are set)
+    #[arg(long)]
+    pub paginated: bool,
+
+    /// Character offset to start from (default: 0). When provided, takes precedence over --page
+    #[arg(long)]
+    pub offset: Option<usize>,
+
+    /// Number of characters per page (default: 1000)
+    #[arg(long)]
+    pub limit: Option<usize>,
+
+    /// Page number, 1-indexed (default: 1). Ignored if --offset is provided
+    #[arg(long)]
+    pub page: Option<usize>,
+}
+
+#[derive(Debug, Clone)]
+pub struct YoutubeConfig {
+    pub url: String,
+    pub lang: Option<String>,
+    pub timeout: u64,
+    pub offset: usize,
+    pub limit: usize,
+    pub page: usize,
+    pub paginated: bool,
+}
+
+pub async fn youtube(options: YoutubeOptions) -> Result<()> {
+    if options.list_tracks {
+        let info = fetch_video_info(&options.url, options.timeout).await?;
+        if options.json {
+            println!("{}", serde_json::to_string_pretty(&info)?);
+        } else if info.tracks.is_empty() {
+            println!("No captions available for {}", info.video_id);
+        } else {
+            for track in &info.tracks {
+                let kind = if track.is_generated {
+                    " (auto-generated)".dimmed().to_string()
+                } else {
+                    String::new()
+                };
+                println!("{}\t{}{}", track.language_code.cyan(), track.name, kind);
+            }
+        }
+        return Ok(());
+    }
+
+    let paginated = options.paginated
+        || options.offset.is_some()
+        || options.limit.is_some()
+        || options.page.is_some();
+
+    let output = fetch_transcript_data(YoutubeConfig {
+        url: options.url,
+        lang: options.lang,
+        timeout: options.timeout,
+        offset: options.offset.unwrap_or(0),
+        limit: options.limit.unwrap_or(1000),
+        page: options.page.unwrap_or(1),
+        paginated,
+    })
+    .await?;
+
+    if options.json {
+        println!("{}", serde_json::to_string_pretty(&output)?);
+    } else {
+        println!("{}", output.content.trim_end());
+        if output.pagination.has_more {
+            println!(
+                "\n{}",
+                format!(
+                    "--- Page {}/{} ({} characters total). Use --page {} for more. ---",
+                    output.pagination.current_page,
+                    output.pagination.total_pages,
+                    output.pagination.total_characters,
+                    output.pagination.current_page + 1
+                )
+                .dimmed()
+            );
+        }
+    }
+
+    Ok(())
+}
+
+fn http_client(timeout: u64) -> Result<reqwest::Client> {
+    reqwest::Client::builder()
+        .user_agent(USER_AGENT)
+        .timeout(std::time::Duration::from_secs(timeout))
+        .build()
+        .map_err(|e| eyre!("Failed to build HTTP client: {}", e))
+}
+
+/// Fetch a watch page and parse its video details and caption tracks.
+pub async fn fetch_video_info(url: &str, timeout: u64) -> Result<VideoInfo> {
+    let video_id =
+        extract_video_id(url).ok_or_else(|| eyre!("Not a YouTube video URL or ID: {}", url))?;
+
+    let html = http_client(timeout)?
+        .get(watch_url(&video_id))
+        .header("Accept-Language", "en-US,en;q=0.9")
+        // Skip the EU cookie consent interstitial.
+        .header("Cookie", "CONSENT=YES+1")
+        .send()
+        .await
+        .map_err(|e| eyre!("Failed to fetch YouTube page: {}", e))?
+        .error_for_status()
+        .map_err(|e| eyre!("Failed to fetch YouTube page: {}", e))?
+        .text()
+        .await?;
+
+    parse_watch_page(&video_id, &html).map_err(|e| eyre!(e))
+}
+
+/// Public function for MCP reuse - fetch a video's captions as paginated Markdown.
+pub async fn fetch_transcript_data(config: YoutubeConfig) -> Result<YoutubeTranscriptOutput> {
+    let info = fetch_video_info(&config.url, config.timeout).await?;
+
+    if info.tracks.is_empty() {
+        return Err(eyre!("No captions available for video {}", info.video_id));
+    }
+    let track = select_track(&info.tracks, config.lang.as_deref()).ok_or_else(|| {
+        let available: Vec<&str> = info
+            .tracks
+            .iter()
+            .map(|t| t.language_code.as_str())
+            .collect();
+        eyre!(
+            "No captions in '{}' (available: {})",
+            config.lang.as_deref().unwrap_or_default(),
+            available.join(", ")
+        )
+    })?;
+
+    let captions = http_client(config.timeout)?
+        .get(json3_url(track))
+        .send()
+        .await
+        .map_err(|e| eyre!("Failed to fetch captions: {}", e))?
+        .error_for_status()
+        .map_err(|e| eyre!("Failed to fetch captions: {}", e))?
+        .text()
+        .await?;
+    if captions.trim().is_empty() {
+        return Err(eyre!(
+            "YouTube returned empty captions for video {}",
+            info.video_id
+        ));
+    }
+
+    let segments = parse_json3(&captions).map_err(|e| eyre!(e))?;
+    let markdown = render_transcript_markdown(&info, track, &segments, &MergeOptions::default());
+
+    let total_characters = markdown.chars().count();
+    let (content, pagination) = if config.paginated {
+        let result =
+            calculate_pagination(total_characters, config.offset, config.limit, config.page);
+        let content = slice_content(markdown, result.start_offset, result.end_offset);
+        (content, result.pagination_info)
+    } else {
+        let pagination = MdPaginationInfo {
+            current_page: 1,
+            total_pages: 1,
+            total_characters,
+            limit: total_characters,
+            has_more: false,
+        };
+        (markdown, pagination)
+    };
+
+    Ok(YoutubeTranscriptOutput {
+        url: watch_url(&info.video_id),
+        video_id: info.video_id.clone(),
+        title: info.title.clone(),
+        author: info.author.clone(),
+        language: track.language_code.clone(),
+        is_generated: track.is_generated,
+        content,
+        pagination,
+    })
+}
@@ -16,13 +16,21 @@ pub enum Commands {
     Toc {
         /// Path to the PDF file
         path: std::path::PathBuf,
+        /// Output format: indented, markdown, or json (default: json)
+        #[arg(long, default_value = "json")]
+        output: TocFormat,
     },
     /// Read a section's content as Markdown
     Read {
         /// Path to the PDF file
         path: std::path::PathBuf,
-        /// Section ID (e.g., "s-1-0"). Omit for whole document.
+        /// Section ID (e.g., "s-1-0") or slug (e.g., "introduction"). Omit for whole document.
         section_id: Option<String>,
+        /// Generate alt text for images with a local vision model
+        #[arg(long)]
+        describe_images: bool,
+        #[clap(flatten)]
+        vision: crate::vision::VisionModelArgs,
     },
     /// Peek into a section's content (sample a text snippet)
     Peek {
@@ -30,7 +38,7 @@ pub enum Commands {
         path: std::path::PathBuf,
         /// Section ID. Omit for whole document.
         section_id: Option<String>,
-        /// Where to sample from (beginning, middle, ending, random)
+        /// Where to sample from (beginning, middle, ending, random, page:N, N%)
         #[arg(short, long, default_value = "beginning")]
         position: String,
         /// Maximum characters to return
@@ -60,16 +68,46 @@ pub enum Commands {
         #[arg(short, long)]
         random: bool,
     },
+    /// Render a page (or a section's first page) as a PNG thumbnail
+    Thumbnail {
+        /// Path to the PDF file
+        path: std::path::PathBuf,
+        /// 1-based page number to render
+        #[arg(long, conflicts_with = "section")]
+        page: Option<usize>,
+        /// Render the first page of this section instead of a page number
+        #[arg(short, long)]
+        section: Option<String>,
+        /// Maximum width/height of the thumbnail in pixels
+        #[arg(long, default_value_t = pdf::thumbnail::DEFAULT_THUMBNAIL_SIZE)]
+        size: u32,
+        /// Output file path (if omitted, prints base64 to stdout)
+        #[arg(short, long)]
+        out: Option<std::path::PathBuf>,
+    },
     /// Print document metadata
     Info {
         /// Path to the PDF file
         path: std::path::PathBuf,
+        /// Include per-page word, image, and heading counts
+        #[arg(long)]
+        stats: bool,
     },
 }
 
+#[derive(Debug, Clone, Copy, clap::ValueEnum)]
+pub enum TocFormat {
+    /// Indented text with section ID, page range, and size hints
+    Indented,
+    /// Markdown nested list with section ID, page range, and size hints
+    Markdown,
+    /// Full document tree as JSON
+    Json,
+}
+
 /// Parse an optional section ID string into Option<SectionId>.
 fn parse_section_id(s: Option<&str>) -> Result<Option<pdf::SectionId>> {
-    s.map(|id| pdf::SectionId::parse(id).map_err(|e| eyre!(e)))
+    s.map(|id| pdf::SectionId::parse_or_slug(id).map_err(|e| eyre!(e)))
         .transpose()
 }
 
@@ -88,16 +126,33 @@ fn output_image(img: &pdf::ImageData, output: Option<&std::path::Path>) -> Resul
 
 pub async fn run(app: App, _global: crate::Global) -> Result<()> {
     match app.command {
-        Commands::Toc { path } => {
-            let bytes = std::fs::read(&path)?;
+        Commands::Toc { path, output } => {
+            let bytes = pdf::map_file(&path).map_err(|e| eyre!(e))?;
             let tree = pdf::parse(&bytes).map_err(|e| eyre!(e))?;
-            println!("{}", serde_json::to_string_pretty(&tree)?);
+            let text = match output {
+                TocFormat::Indented => pdf::render::toc::render_toc_indented(&tree),
+                TocFormat::Markdown => pdf::render::toc::render_toc_markdown(&tree),
+                TocFormat::Json => serde_json::to_string_pretty(&tree)?,
+            };
+            println!("{}", text);
             Ok(())
         }
-        Commands::Read { path, section_id } => {
-            let bytes = std::fs::read(&path)?;
+        Commands::Read {
+            path,
+            section_id,
+            describe_images,
+            vision,
+        } => {
             let id = parse_section_id(section_id.as_deref())?;
-            let content = pdf::read_section(&bytes, id.as_ref()).map_err(|e| eyre!(e))?;
+            let doc = pdf::ParsedDocument::from_path(&path).map_err(|e| eyre!(e))?;
+            let alt_texts = if describe_images {
+                crate::vision::describe_pdf_images(&doc, id.as_ref(), &vision).await?
+            } else {
+                Default::default()
+            };
+            let content = doc
+                .read_section_with_alt_text(id.as_ref(), &alt_texts)
+                .map_err(|e| eyre!(e))?;
             println!("{}", serde_json::to_string_pretty(&content)?);
             Ok(())
         }
@@ -107,7 +162,7 @@ pub async fn run(app: App, _global: crate::Global) -> Result<()> {
             position,
             limit,
         } => {
-            let bytes = std::fs::read(&path)?;
+            let bytes = pdf::map_file(&path).map_err(|e| eyre!(e))?;
             let pos: pdf::PeekPosition = position
                 .parse()
                 .map_err(|e: pdf::InvalidPeekPosition| eyre!(e))?;
@@ -117,7 +172,7 @@ pub async fn run(app: App, _global: crate::Global) -> Result<()> {
             Ok(())
         }
         Commands::Images { path, section_id } => {
-            let bytes = std::fs::read(&path)?;
+            let bytes = pdf::map_file(&path).map_err(|e| eyre!(e))?;
             let id = parse_section_id(section_id.as_deref())?;
             let images = pdf::list_section_images(&bytes, id.as_ref()).map_err(|e| eyre!(e))?;
             println!("{}", serde_json::to_string_pretty(&images)?);
@@ -137,7 +192,7 @@ pub async fn run(app: App, _global: crate::Global) -> Result<()> {
                 return Err(eyre!("Either provide an image ID or use --random"));
             }
 
-            let bytes = std::fs::read(&path)?;
+            let bytes = pdf::map_file(&path).map_err(|e| eyre!(e))?;
 
             if let Some(id_str) = image_id {
                 let id = pdf::ImageId::new(id_str);
@@ -157,10 +212,32 @@ pub async fn run(app: App, _global: crate::Global) -> Result<()> {
             }
             Ok(())
         }
-        Commands::Info { path } => {
-            let bytes = std::fs::read(&path)?;
-            let meta = pdf::info(&bytes).map_err(|e| eyre!(e))?;
-            println!("{}", serde_json::to_string_pretty(&meta)?);
+        Commands::Thumbnail {
+            path,
+            page,
+            section,
+            size,
+            out,
+        } => {
+            let bytes = pdf::map_file(&path).map_err(|e| eyre!(e))?;
+            let img = match parse_section_id(section.as_deref())? {
+                Some(id) => pdf::render_section_thumbnail(&bytes, &id, size),
+                None => pdf::render_page_thumbnail(&bytes, page.unwrap_or(1), size),
+            }
+            .map_err(|e| eyre!(e))?;
+            output_image(&img, out.as_deref())?;
+            Ok(())
+        }
+        Commands::Info { path, stats } => {
+            let bytes = pdf::map_file(&path).map_err(|e| eyre!(e))?;
+            let json = if stats {
+                let stats = pdf::stats(&bytes).map_err(|e| eyre!(e))?;
+                serde_json::to_string_pretty(&stats)?
+            } else {
+                let meta = pdf::info(&bytes).map_err(|e| eyre!(e))?;
+                serde_json::to_string_pretty(&meta)?
+            };
+            println!("{}", json);
             Ok(())
         }
     }
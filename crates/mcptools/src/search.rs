@@ -0,0 +1,324 @@
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use crate::hn::HN_SEARCH_API_BASE;
+use crate::prelude::{println, *};
+use mcptools_core::search::{
+    confluence_text_cql, format_unified_text, hits_from_bitbucket_code, hits_from_confluence,
+    hits_from_hn, hits_from_jira, hits_from_local, jira_text_jql, parse_ranking_config,
+    query_terms, rank_hits, RankingConfig, SearchHit, SearchSource, SourceReport, SourceStatus,
+    UnifiedSearchOutput,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum, serde::Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Source {
+    /// Jira issues (text ~ query)
+    Jira,
+    /// Confluence pages (text ~ query)
+    Confluence,
+    /// Bitbucket Cloud code search in one workspace
+    Bitbucket,
+    /// HackerNews stories via the Algolia search API
+    Hn,
+    /// The local Atlas index (file paths, descriptions, and symbols)
+    Local,
+}
+
+impl From<Source> for SearchSource {
+    fn from(source: Source) -> Self {
+        match source {
+            Source::Jira => SearchSource::Jira,
+            Source::Confluence => SearchSource::Confluence,
+            Source::Bitbucket => SearchSource::Bitbucket,
+            Source::Hn => SearchSource::Hn,
+            Source::Local => SearchSource::Local,
+        }
+    }
+}
+
+#[derive(Debug, clap::Parser)]
+#[command(name = "search-all")]
+#[command(about = "Search Jira, Confluence, Bitbucket, HackerNews, and the local index at once")]
+pub struct App {
+    /// Free-text query
+    pub query: String,
+
+    /// Sources to search, comma-separated (default: all)
+    #[arg(long, value_enum, value_delimiter = ',')]
+    pub sources: Vec<Source>,
+
+    /// Maximum results after merging
+    #[arg(short, long, default_value = "20")]
+    pub limit: usize,
+
+    /// Results requested from each source
+    #[arg(long, default_value = "10")]
+    pub per_source: usize,
+
+    /// Bitbucket workspace for code search
+    #[arg(long, env = "BITBUCKET_WORKSPACE")]
+    pub workspace: Option<String>,
+
+    /// Repository whose Atlas index is searched
+    #[arg(long, default_value = ".")]
+    pub repo_path: PathBuf,
+
+    /// Seconds to wait for each source
+    #[arg(short, long, default_value = "20")]
+    pub timeout: u64,
+
+    /// Output as JSON
+    #[arg(long)]
+    pub json: bool,
+}
+
+/// Parameters for [`search_all_data`], shared by the CLI and MCP
+#[derive(Debug, Clone)]
+pub struct SearchAllParams {
+    pub query: String,
+    /// Empty means every source
+    pub sources: Vec<SearchSource>,
+    pub limit: usize,
+    pub per_source: usize,
+    pub workspace: Option<String>,
+    pub repo_path: PathBuf,
+    pub timeout: u64,
+}
+
+/// A source that has nothing to search reports why instead of failing.
+enum Fetched {
+    Hits(Vec<SearchHit>),
+    Skipped(String),
+}
+
+async fn search_jira(query: &str, limit: usize) -> Result<Fetched> {
+    let config = crate::atlassian::JiraConfig::from_env().await?;
+    let output =
+        crate::atlassian::jira::search::search_issues_data(jira_text_jql(query), limit, None)
+            .await?;
+    Ok(Fetched::Hits(hits_from_jira(&output, &config.base_url)))
+}
+
+async fn search_confluence(query: &str, limit: usize) -> Result<Fetched> {
+    use mcptools_core::atlassian::deployment::Deployment;
+
+    let config = crate::atlassian::ConfluenceConfig::from_env().await?;
+    let output =
+        crate::atlassian::confluence::search_pages_data(confluence_text_cql(query), limit).await?;
+    Ok(Fetched::Hits(hits_from_confluence(
+        &output,
+        &config.base_url,
+        config.deployment == Deployment::Cloud,
+    )))
+}
+
+async fn search_bitbucket(query: &str, limit: usize, workspace: Option<&str>) -> Result<Fetched> {
+    use crate::atlassian::{create_bitbucket_client, BitbucketConfig};
+
+    let Some(workspace) = workspace else {
+        return Ok(Fetched::Skipped(
+            "no workspace; pass --workspace or set BITBUCKET_WORKSPACE".to_string(),
+        ));
+    };
+    let config = BitbucketConfig::from_env()?;
+    let client = create_bitbucket_client(&config)?;
+    let url = format!(
+        "{}/workspaces/{}/search/code",
+        config.base_url.trim_end_matches('/'),
+        workspace
+    );
+    let response = client
+        .get(&url)
+        .query(&[
+            ("search_query", query),
+            ("pagelen", &limit.min(100).to_string()),
+        ])
+        .send()
+        .await
+        .map_err(|e| eyre!("Failed to send request to Bitbucket: {}", e))?;
+    if !response.status().is_success() {
+        let status = response.status();
+        let body = response.text().await.unwrap_or_default();
+        return Err(eyre!("Bitbucket code search failed [{}]: {}", status, body));
+    }
+    let body: serde_json::Value = response
+        .json()
+        .await
+        .map_err(|e| eyre!("Failed to parse Bitbucket code search response: {}", e))?;
+    Ok(Fetched::Hits(hits_from_bitbucket_code(&body)))
+}
+
+async fn search_hn(query: &str, limit: usize) -> Result<Fetched> {
+    let response = reqwest::Client::new()
+        .get(format!("{}/search", HN_SEARCH_API_BASE))
+        .query(&[
+            ("query", query),
+            ("tags", "story"),
+            ("hitsPerPage", &limit.to_string()),
+        ])
+        .send()
+        .await
+        .map_err(|e| eyre!("Failed to send request to HN search: {}", e))?;
+    if !response.status().is_success() {
+        return Err(eyre!("HN search failed [{}]", response.status()));
+    }
+    let body: serde_json::Value = response
+        .json()
+        .await
+        .map_err(|e| eyre!("Failed to parse HN search response: {}", e))?;
+    Ok(Fetched::Hits(hits_from_hn(&body)))
+}
+
+fn search_local(query: &str, limit: usize, repo_path: &Path) -> Result<Fetched> {
+    let start = repo_path
+        .canonicalize()
+        .map_err(|e| eyre!("Failed to resolve {}: {}", repo_path.display(), e))?;
+    let Some(root) = start.ancestors().find(|dir| dir.join(".git").exists()) else {
+        return Ok(Fetched::Skipped(format!(
+            "{} is not inside a git repository",
+            repo_path.display()
+        )));
+    };
+    let config = crate::atlas::config::load_config(root)?;
+    let db_path = config.db_path.resolve(root);
+    // Database::open would create an empty index; only read an existing one.
+    if !db_path.exists() {
+        return Ok(Fetched::Skipped(format!(
+            "no Atlas index at {}; run `mcptools atlas init`",
+            db_path.display()
+        )));
+    }
+    let db = crate::atlas::db::Database::open(&db_path)?;
+    let candidates = db.search(&query_terms(query), limit * 10)?;
+    Ok(Fetched::Hits(hits_from_local(query, candidates, limit)))
+}
+
+async fn search_source(source: SearchSource, params: &SearchAllParams) -> Result<Fetched> {
+    let (query, limit) = (params.query.as_str(), params.per_source);
+    match source {
+        SearchSource::Jira => search_jira(query, limit).await,
+        SearchSource::Confluence => search_confluence(query, limit).await,
+        SearchSource::Bitbucket => {
+            search_bitbucket(query, limit, params.workspace.as_deref()).await
+        }
+        SearchSource::Hn => search_hn(query, limit).await,
+        SearchSource::Local => search_local(query, limit, &params.repo_path),
+    }
+}
+
+/// Ranking weights and recency decay from the `[search]` table of
+/// `~/.config/mcptools/config.toml`; defaults when the file is missing.
+pub fn load_ranking_config() -> Result<RankingConfig> {
+    let path = std::env::var("HOME")
+        .ok()
+        .map(|home| PathBuf::from(home).join(".config/mcptools/config.toml"));
+    let content = match path {
+        Some(path) if path.exists() => Some(
+            std::fs::read_to_string(&path)
+                .map_err(|e| eyre!("Failed to read {}: {}", path.display(), e))?,
+        ),
+        _ => None,
+    };
+    parse_ranking_config(content.as_deref())
+        .map_err(|e| eyre!("~/.config/mcptools/config.toml: {}", e))
+}
+
+/// Public data function - used by both CLI and MCP
+///
+/// Queries every requested source concurrently, each bounded by
+/// `params.timeout`. A failing source is reported in `sources` and never
+/// fails the whole search.
+pub async fn search_all_data(params: SearchAllParams) -> Result<UnifiedSearchOutput> {
+    if params.query.trim().is_empty() {
+        return Err(eyre!("Query cannot be empty"));
+    }
+    // Read before querying so a typo in the config fails fast.
+    let ranking = load_ranking_config()?;
+    let sources = if params.sources.is_empty() {
+        SearchSource::ALL.to_vec()
+    } else {
+        params.sources.clone()
+    };
+
+    // Polled on this task (not spawned) so per-call Atlassian credential
+    // overrides stay in scope.
+    let timeout = Duration::from_secs(params.timeout);
+    let fetched = futures::future::join_all(sources.iter().map(|&source| {
+        let params = &params;
+        async move {
+            match tokio::time::timeout(timeout, search_source(source, params)).await {
+                Ok(result) => result,
+                Err(_) => Err(eyre!("timed out after {}s", params.timeout)),
+            }
+        }
+    }))
+    .await;
+
+    let mut batches = Vec::new();
+    let mut reports = Vec::new();
+    for (source, result) in sources.into_iter().zip(fetched) {
+        let report = match result {
+            Ok(Fetched::Hits(hits)) => {
+                let count = hits.len();
+                batches.push(hits);
+                SourceReport {
+                    source,
+                    status: SourceStatus::Ok,
+                    count,
+                    message: None,
+                }
+            }
+            Ok(Fetched::Skipped(message)) => SourceReport {
+                source,
+                status: SourceStatus::Skipped,
+                count: 0,
+                message: Some(message),
+            },
+            Err(e) => SourceReport {
+                source,
+                status: SourceStatus::Error,
+                count: 0,
+                message: Some(e.to_string()),
+            },
+        };
+        reports.push(report);
+    }
+
+    Ok(UnifiedSearchOutput {
+        results: rank_hits(
+            &params.query,
+            batches,
+            params.limit,
+            &ranking,
+            chrono::Utc::now(),
+        ),
+        query: params.query,
+        sources: reports,
+    })
+}
+
+pub async fn run(app: App, global: crate::Global) -> Result<()> {
+    if global.verbose {
+        println!("Searching for: {}", app.query);
+    }
+
+    let output = search_all_data(SearchAllParams {
+        query: app.query,
+        sources: app.sources.into_iter().map(Into::into).collect(),
+        limit: app.limit,
+        per_source: app.per_source,
+        workspace: app.workspace,
+        repo_path: app.repo_path,
+        timeout: app.timeout,
+    })
+    .await?;
+
+    if app.json {
+        println!("{}", serde_json::to_string_pretty(&output)?);
+    } else {
+        print!("{}", format_unified_text(&output));
+    }
+
+    Ok(())
+}
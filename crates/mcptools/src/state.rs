@@ -0,0 +1,312 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::prelude::{println, *};
+use mcptools_core::state::{
+    component_dir as core_component_dir, legacy_locations, parse_state_version, plan_migration,
+    state_root, LegacyLocation, MigrationStep, StateComponent, STATE_VERSION, VERSION_FILE,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum, serde::Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Component {
+    /// Cached fetches and API responses
+    Cache,
+    /// Seen-story state for HackerNews
+    Seen,
+    /// Saved sessions
+    Sessions,
+    /// Notes
+    Notes,
+    /// Audit log
+    Audit,
+    /// Jira pagination tokens
+    Pagination,
+    /// Page snapshots compared by md diff
+    Snapshots,
+}
+
+impl From<Component> for StateComponent {
+    fn from(component: Component) -> Self {
+        match component {
+            Component::Cache => StateComponent::Cache,
+            Component::Seen => StateComponent::Seen,
+            Component::Sessions => StateComponent::Sessions,
+            Component::Notes => StateComponent::Notes,
+            Component::Audit => StateComponent::Audit,
+            Component::Pagination => StateComponent::Pagination,
+            Component::Snapshots => StateComponent::Snapshots,
+        }
+    }
+}
+
+#[derive(Debug, clap::Parser)]
+#[command(name = "state")]
+#[command(about = "Manage the mcptools state directory (~/.local/state/mcptools)")]
+pub struct App {
+    #[command(subcommand)]
+    pub command: Commands,
+}
+
+#[derive(Debug, clap::Subcommand)]
+pub enum Commands {
+    /// Print the state directory, or one component's directory
+    Path {
+        /// Component whose directory is printed
+        #[arg(short, long, value_enum)]
+        component: Option<Component>,
+    },
+
+    /// Show the layout version and the size of each component
+    Status {
+        /// Output as JSON
+        #[arg(long)]
+        json: bool,
+    },
+
+    /// Bring the state directory to the current layout and import legacy files
+    Migrate {
+        /// Print the planned steps without touching the filesystem
+        #[arg(long)]
+        dry_run: bool,
+    },
+
+    /// Delete the contents of one or more components
+    Clear {
+        /// Components to clear, comma-separated
+        #[arg(
+            short,
+            long,
+            value_enum,
+            value_delimiter = ',',
+            required_unless_present = "all"
+        )]
+        component: Vec<Component>,
+
+        /// Clear every component
+        #[arg(long, conflicts_with = "component")]
+        all: bool,
+    },
+}
+
+fn home_dir() -> Result<PathBuf> {
+    std::env::var("HOME")
+        .ok()
+        .map(PathBuf::from)
+        .ok_or_else(|| eyre!("Could not determine home directory (HOME env var not set)"))
+}
+
+/// The state root for this user
+pub fn state_dir() -> Result<PathBuf> {
+    let xdg = std::env::var("XDG_STATE_HOME").ok();
+    Ok(state_root(xdg.as_deref(), &home_dir()?))
+}
+
+fn read_version(root: &Path) -> Result<u32> {
+    let path = root.join(VERSION_FILE);
+    let content = if path.exists() {
+        Some(
+            fs::read_to_string(&path)
+                .map_err(|e| eyre!("Failed to read {}: {}", path.display(), e))?,
+        )
+    } else {
+        None
+    };
+    parse_state_version(content.as_deref()).map_err(|e| eyre!("{}: {}", path.display(), e))
+}
+
+fn plan(root: &Path) -> Result<Vec<MigrationStep>> {
+    let existing: Vec<LegacyLocation> = legacy_locations(&home_dir()?)
+        .into_iter()
+        .filter(|legacy| legacy.path.is_dir())
+        .collect();
+    plan_migration(root, read_version(root)?, &existing).map_err(|e| eyre!("{}", e))
+}
+
+fn move_contents(from: &Path, to: &Path) -> Result<usize> {
+    fs::create_dir_all(to)?;
+    let mut moved = 0;
+    for entry in fs::read_dir(from)? {
+        let entry = entry?;
+        let target = to.join(entry.file_name());
+        if target.exists() {
+            continue;
+        }
+        // rename fails across filesystems; fall back to copy + remove for files.
+        if fs::rename(entry.path(), &target).is_err() {
+            fs::copy(entry.path(), &target)
+                .map_err(|e| eyre!("Failed to move {}: {}", entry.path().display(), e))?;
+            fs::remove_file(entry.path())?;
+        }
+        moved += 1;
+    }
+    if fs::read_dir(from)?.next().is_none() {
+        fs::remove_dir(from)?;
+    }
+    Ok(moved)
+}
+
+fn apply(steps: &[MigrationStep]) -> Result<()> {
+    for step in steps {
+        match step {
+            MigrationStep::CreateDir { path } => fs::create_dir_all(path)
+                .map_err(|e| eyre!("Failed to create {}: {}", path.display(), e))?,
+            MigrationStep::MoveContents { from, to, .. } => {
+                move_contents(from, to)?;
+            }
+            MigrationStep::WriteVersion { path, version } => {
+                fs::write(path, format!("{}\n", version))
+                    .map_err(|e| eyre!("Failed to write {}: {}", path.display(), e))?
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Directory of `component`, migrating the state directory first if needed
+///
+/// Features call this instead of building their own paths, so the first use
+/// after an upgrade imports legacy files transparently.
+pub fn component_dir(component: StateComponent) -> Result<PathBuf> {
+    let root = state_dir()?;
+    apply(&plan(&root)?)?;
+    let dir = core_component_dir(&root, component);
+    fs::create_dir_all(&dir)?;
+    Ok(dir)
+}
+
+fn dir_usage(path: &Path) -> (u64, u64) {
+    let Ok(entries) = fs::read_dir(path) else {
+        return (0, 0);
+    };
+    entries
+        .flatten()
+        .fold((0, 0), |(files, bytes), entry| match entry.metadata() {
+            Ok(meta) if meta.is_dir() => {
+                let (f, b) = dir_usage(&entry.path());
+                (files + f, bytes + b)
+            }
+            Ok(meta) => (files + 1, bytes + meta.len()),
+            Err(_) => (files, bytes),
+        })
+}
+
+#[derive(Debug, serde::Serialize)]
+struct ComponentStatus {
+    component: StateComponent,
+    path: PathBuf,
+    files: u64,
+    bytes: u64,
+}
+
+#[derive(Debug, serde::Serialize)]
+struct StatusOutput {
+    root: PathBuf,
+    version: u32,
+    supported_version: u32,
+    pending_steps: usize,
+    components: Vec<ComponentStatus>,
+}
+
+fn describe(step: &MigrationStep) -> String {
+    match step {
+        MigrationStep::CreateDir { path } => format!("create {}", path.display()),
+        MigrationStep::MoveContents {
+            component,
+            from,
+            to,
+        } => format!(
+            "move {} {} -> {}",
+            component.as_str(),
+            from.display(),
+            to.display()
+        ),
+        MigrationStep::WriteVersion { path, version } => {
+            format!("write version {} to {}", version, path.display())
+        }
+    }
+}
+
+pub async fn run(app: App, _global: crate::Global) -> Result<()> {
+    let root = state_dir()?;
+    match app.command {
+        Commands::Path { component } => match component {
+            Some(component) => {
+                println!("{}", core_component_dir(&root, component.into()).display())
+            }
+            None => println!("{}", root.display()),
+        },
+        Commands::Status { json } => {
+            let output = StatusOutput {
+                version: read_version(&root)?,
+                supported_version: STATE_VERSION,
+                pending_steps: plan(&root)?.len(),
+                components: StateComponent::ALL
+                    .iter()
+                    .map(|&component| {
+                        let path = core_component_dir(&root, component);
+                        let (files, bytes) = dir_usage(&path);
+                        ComponentStatus {
+                            component,
+                            path,
+                            files,
+                            bytes,
+                        }
+                    })
+                    .collect(),
+                root,
+            };
+            if json {
+                println!("{}", serde_json::to_string_pretty(&output)?);
+            } else {
+                println!("State directory: {}", output.root.display());
+                println!(
+                    "Layout version: {} (supported: {})",
+                    output.version, output.supported_version
+                );
+                if output.pending_steps > 0 {
+                    println!("Migration pending; run `mcptools state migrate`");
+                }
+                for c in &output.components {
+                    println!(
+                        "  {:<12} {:>6} files {:>10} bytes",
+                        c.component.as_str(),
+                        c.files,
+                        c.bytes
+                    );
+                }
+            }
+        }
+        Commands::Migrate { dry_run } => {
+            let steps = plan(&root)?;
+            if steps.is_empty() {
+                println!("State directory is up to date (version {})", STATE_VERSION);
+                return Ok(());
+            }
+            for step in &steps {
+                println!("{}{}", if dry_run { "would " } else { "" }, describe(step));
+            }
+            if !dry_run {
+                apply(&steps)?;
+                println!("Migrated {} to version {}", root.display(), STATE_VERSION);
+            }
+        }
+        Commands::Clear { component, all } => {
+            let components: Vec<StateComponent> = if all {
+                StateComponent::ALL.to_vec()
+            } else {
+                component.into_iter().map(Into::into).collect()
+            };
+            for component in components {
+                let path = core_component_dir(&root, component);
+                if path.exists() {
+                    fs::remove_dir_all(&path)
+                        .map_err(|e| eyre!("Failed to clear {}: {}", path.display(), e))?;
+                    fs::create_dir_all(&path)?;
+                }
+                println!("Cleared {}", component.as_str());
+            }
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,102 @@
+use crate::prelude::*;
+
+use mcptools_core::hbs::render_template;
+use mcptools_core::template::parse_vars;
+
+#[derive(Debug, clap::Parser)]
+#[command(name = "template")]
+#[command(about = "Render Handlebars templates against JSON data")]
+pub struct App {
+    #[command(subcommand)]
+    pub command: Commands,
+}
+
+#[derive(Debug, clap::Subcommand)]
+pub enum Commands {
+    /// Render a template with a JSON context
+    #[clap(name = "render")]
+    Render(RenderOptions),
+}
+
+#[derive(Debug, clap::Args, Clone)]
+pub struct RenderOptions {
+    /// Template file ('-' for stdin)
+    #[arg(required_unless_present = "inline", conflicts_with = "inline")]
+    pub template: Option<String>,
+
+    /// Template text given inline
+    #[arg(short, long)]
+    pub inline: Option<String>,
+
+    /// JSON context file ('-' for stdin)
+    #[arg(short, long, conflicts_with = "context_json")]
+    pub context: Option<String>,
+
+    /// JSON context given inline
+    #[arg(long)]
+    pub context_json: Option<String>,
+
+    /// Top-level string value, NAME=VALUE (repeatable; overrides the context)
+    #[arg(long = "var", value_name = "NAME=VALUE")]
+    pub vars: Vec<String>,
+
+    /// Fail on values missing from the context instead of rendering them empty
+    #[arg(long)]
+    pub strict: bool,
+}
+
+fn read_source(path: &str) -> Result<String> {
+    use std::io::Read;
+
+    if path == "-" {
+        let mut buffer = String::new();
+        std::io::stdin().read_to_string(&mut buffer)?;
+        Ok(buffer)
+    } else {
+        std::fs::read_to_string(path).map_err(|e| eyre!("Failed to read {}: {}", path, e))
+    }
+}
+
+pub async fn run(app: App, _global: crate::Global) -> Result<()> {
+    match app.command {
+        Commands::Render(options) => render(options),
+    }
+}
+
+fn render(options: RenderOptions) -> Result<()> {
+    if options.template.as_deref() == Some("-") && options.context.as_deref() == Some("-") {
+        return Err(eyre!(
+            "Only one of the template and --context can be read from stdin"
+        ));
+    }
+
+    let template = match (&options.inline, &options.template) {
+        (Some(inline), _) => inline.clone(),
+        (None, Some(path)) => read_source(path)?,
+        (None, None) => unreachable!("clap requires a template"),
+    };
+
+    let mut context: serde_json::Value = match (&options.context, &options.context_json) {
+        (Some(path), _) => serde_json::from_str(&read_source(path)?)
+            .map_err(|e| eyre!("Invalid JSON context in {}: {}", path, e))?,
+        (None, Some(json)) => {
+            serde_json::from_str(json).map_err(|e| eyre!("Invalid JSON context: {}", e))?
+        }
+        (None, None) => serde_json::json!({}),
+    };
+
+    let vars = parse_vars(&options.vars).map_err(|e| eyre!(e))?;
+    if !vars.is_empty() {
+        let object = context
+            .as_object_mut()
+            .ok_or_else(|| eyre!("--var requires the context to be a JSON object"))?;
+        for (name, value) in vars {
+            object.insert(name, serde_json::Value::String(value));
+        }
+    }
+
+    let output = render_template(&template, &context, options.strict).map_err(|e| eyre!(e))?;
+    print!("{}", output);
+
+    Ok(())
+}
@@ -0,0 +1,192 @@
+use crate::prelude::{eprintln, println, *};
+use mcptools_core::transcribe::{
+    build_transcript, render_markdown, transcription_url, MergeOptions, TranscribeApi,
+    TranscriptOutput, TranscriptionResponse,
+};
+
+/// Default speech-to-text endpoint (a local whisper.cpp server).
+pub const DEFAULT_TRANSCRIBE_URL: &str = "http://localhost:8080";
+
+/// Default model name sent to OpenAI-compatible endpoints.
+pub const DEFAULT_TRANSCRIBE_MODEL: &str = "whisper-1";
+
+#[derive(Debug, Clone, Copy, clap::ValueEnum)]
+pub enum ApiArg {
+    /// whisper.cpp server (POST /inference)
+    WhisperCpp,
+    /// OpenAI-compatible API (POST /v1/audio/transcriptions)
+    Openai,
+}
+
+impl From<ApiArg> for TranscribeApi {
+    fn from(api: ApiArg) -> Self {
+        match api {
+            ApiArg::WhisperCpp => TranscribeApi::WhisperCpp,
+            ApiArg::Openai => TranscribeApi::Openai,
+        }
+    }
+}
+
+#[derive(Debug, clap::Parser)]
+#[command(name = "transcribe")]
+#[command(about = "Transcribe an audio file into a timestamped Markdown transcript")]
+pub struct App {
+    /// Audio file (wav, mp3, m4a, ogg, flac, webm, ...)
+    pub file: std::path::PathBuf,
+
+    /// Base URL of the speech-to-text server
+    #[arg(long, env = "TRANSCRIBE_URL", default_value = DEFAULT_TRANSCRIBE_URL)]
+    pub endpoint: String,
+
+    /// Server API flavor
+    #[arg(
+        long,
+        value_enum,
+        env = "TRANSCRIBE_API",
+        default_value = "whisper-cpp"
+    )]
+    pub api: ApiArg,
+
+    /// Model name (OpenAI-compatible endpoints only)
+    #[arg(long, env = "TRANSCRIBE_MODEL", default_value = DEFAULT_TRANSCRIBE_MODEL)]
+    pub model: String,
+
+    /// API key sent as a Bearer token
+    #[arg(long, env = "TRANSCRIBE_API_KEY", hide_env_values = true)]
+    pub api_key: Option<String>,
+
+    /// Spoken language as an ISO-639-1 code (auto-detected when omitted)
+    #[arg(long)]
+    pub language: Option<String>,
+
+    /// Silence in seconds that starts a new paragraph
+    #[arg(long, default_value_t = 2.0)]
+    pub max_gap: f64,
+
+    /// Maximum paragraph length in seconds
+    #[arg(long, default_value_t = 60.0)]
+    pub max_paragraph: f64,
+
+    /// Output as JSON
+    #[arg(long)]
+    pub json: bool,
+
+    /// Request timeout in seconds
+    #[arg(short, long, default_value = "600")]
+    pub timeout: u64,
+}
+
+/// Connection and request settings for a transcription call.
+#[derive(Debug, Clone)]
+pub struct TranscribeConfig {
+    pub endpoint: String,
+    pub api: TranscribeApi,
+    pub model: String,
+    pub api_key: Option<String>,
+    pub language: Option<String>,
+    pub timeout: u64,
+}
+
+/// Module entry point
+pub async fn run(app: App, global: crate::Global) -> Result<()> {
+    let config = TranscribeConfig {
+        endpoint: app.endpoint,
+        api: app.api.into(),
+        model: app.model,
+        api_key: app.api_key,
+        language: app.language,
+        timeout: app.timeout,
+    };
+    let options = MergeOptions {
+        max_gap: app.max_gap,
+        max_duration: app.max_paragraph,
+    };
+
+    if global.verbose {
+        eprintln!(
+            "Transcription URL: {}",
+            transcription_url(config.api, &config.endpoint)
+        );
+    }
+
+    let transcript = transcribe_file(&app.file, &config, &options).await?;
+
+    if app.json {
+        println!("{}", serde_json::to_string_pretty(&transcript)?);
+    } else {
+        println!("{}", render_markdown(&transcript).trim_end());
+    }
+
+    Ok(())
+}
+
+/// Upload an audio file to the speech-to-text server and build its transcript.
+pub async fn transcribe_file(
+    path: &std::path::Path,
+    config: &TranscribeConfig,
+    options: &MergeOptions,
+) -> Result<TranscriptOutput> {
+    let bytes = tokio::fs::read(path)
+        .await
+        .map_err(|e| eyre!("Failed to read file '{}': {}", path.display(), e))?;
+    let filename = path
+        .file_name()
+        .map(|n| n.to_string_lossy().to_string())
+        .unwrap_or_else(|| "audio".to_string());
+
+    let part = reqwest::multipart::Part::bytes(bytes)
+        .file_name(filename)
+        .mime_str(audio_mime_type(path))?;
+    let mut form = reqwest::multipart::Form::new()
+        .part("file", part)
+        .text("response_format", "verbose_json");
+    if config.api == TranscribeApi::Openai {
+        form = form.text("model", config.model.clone());
+    }
+    if let Some(language) = &config.language {
+        form = form.text("language", language.clone());
+    }
+
+    let url = transcription_url(config.api, &config.endpoint);
+    let client = reqwest::Client::builder()
+        .timeout(std::time::Duration::from_secs(config.timeout))
+        .build()?;
+    let mut request = client.post(&url).multipart(form);
+    if let Some(key) = &config.api_key {
+        request = request.bearer_auth(key);
+    }
+
+    let response = request
+        .send()
+        .await
+        .map_err(|e| eyre!("Failed to reach speech-to-text server at {}: {}", url, e))?;
+
+    let status = response.status();
+    if !status.is_success() {
+        let body = response.text().await.unwrap_or_default();
+        return Err(eyre!("Transcription failed ({}): {}", status, body.trim()));
+    }
+
+    let body: TranscriptionResponse = response
+        .json()
+        .await
+        .map_err(|e| eyre!("Failed to parse transcription response: {}", e))?;
+
+    Ok(build_transcript(&path.display().to_string(), body, options))
+}
+
+fn audio_mime_type(path: &std::path::Path) -> &'static str {
+    let ext = path
+        .extension()
+        .map(|e| e.to_string_lossy().to_lowercase())
+        .unwrap_or_default();
+    match ext.as_str() {
+        "wav" => "audio/wav",
+        "mp3" => "audio/mpeg",
+        "m4a" | "mp4" => "audio/mp4",
+        "ogg" | "oga" => "audio/ogg",
+        "flac" => "audio/flac",
+        "webm" => "audio/webm",
+        _ => "application/octet-stream",
+    }
+}
@@ -0,0 +1,207 @@
+use std::collections::BTreeMap;
+
+use base64::Engine;
+
+use crate::prelude::{eprintln, println, *};
+use mcptools_core::vision::{
+    build_generate_request, build_prompt, clean_alt_text, detect_image_format,
+    parse_generate_response, DescribeMode, GenerateResponse, ImageDescription,
+    DEFAULT_VISION_MODEL,
+};
+
+#[derive(Debug, clap::Parser)]
+#[command(name = "describe-image")]
+#[command(about = "Describe an image using a local Ollama vision model")]
+pub struct App {
+    /// Image file, or a PDF file when IMAGE_ID is given
+    pub source: std::path::PathBuf,
+
+    /// Image ID (XObject name) inside the PDF, as listed by `pdf images`
+    pub image_id: Option<String>,
+
+    /// Generate short alt text instead of a detailed description
+    #[arg(long)]
+    pub alt_text: bool,
+
+    /// Custom prompt (in --alt-text mode, extra guidance for the alt text)
+    #[arg(long)]
+    pub prompt: Option<String>,
+
+    /// Output as JSON
+    #[arg(long)]
+    pub json: bool,
+
+    #[clap(flatten)]
+    pub model: VisionModelArgs,
+}
+
+/// Ollama connection settings shared by every command that describes images.
+#[derive(Debug, Clone, clap::Args, serde::Deserialize)]
+#[serde(default)]
+pub struct VisionModelArgs {
+    /// Ollama base URL
+    #[arg(long, env = "OLLAMA_URL", default_value = DEFAULT_OLLAMA_URL)]
+    pub ollama_url: String,
+
+    /// Ollama vision model (e.g. llava, qwen2.5vl)
+    #[arg(long = "vision-model", env = "VISION_MODEL", default_value = DEFAULT_VISION_MODEL)]
+    pub vision_model: String,
+}
+
+const DEFAULT_OLLAMA_URL: &str = "http://localhost:11434";
+
+impl Default for VisionModelArgs {
+    fn default() -> Self {
+        Self {
+            ollama_url: DEFAULT_OLLAMA_URL.to_string(),
+            vision_model: DEFAULT_VISION_MODEL.to_string(),
+        }
+    }
+}
+
+/// Module entry point
+pub async fn run(app: App, global: crate::Global) -> Result<()> {
+    let mode = if app.alt_text {
+        DescribeMode::AltText
+    } else {
+        DescribeMode::Description
+    };
+
+    let (source, bytes) = load_image(&app.source, app.image_id.as_deref())?;
+
+    if global.verbose {
+        eprintln!("Ollama URL: {}", app.model.ollama_url);
+        eprintln!("Model: {}", app.model.vision_model);
+    }
+
+    let description = describe_image_data(&bytes, &app.model, mode, app.prompt.as_deref()).await?;
+
+    if app.json {
+        let output = ImageDescription {
+            source,
+            model: app.model.vision_model,
+            description,
+        };
+        println!("{}", serde_json::to_string_pretty(&output)?);
+    } else {
+        println!("{}", description);
+    }
+
+    Ok(())
+}
+
+/// Read an image file, or extract an embedded image when `image_id` is given.
+///
+/// Returns the source label (`path` or `path#image-id`) and the image bytes.
+pub fn load_image(path: &std::path::Path, image_id: Option<&str>) -> Result<(String, Vec<u8>)> {
+    let bytes = std::fs::read(path)
+        .map_err(|e| eyre!("Failed to read file '{}': {}", path.display(), e))?;
+
+    match image_id {
+        Some(id) => {
+            let image = pdf::get_image(&bytes, &pdf::ImageId::new(id)).map_err(|e| eyre!(e))?;
+            Ok((format!("{}#{}", path.display(), id), image.bytes))
+        }
+        None if bytes.starts_with(b"%PDF") => Err(eyre!(
+            "'{}' is a PDF; pass an image ID (see `mcptools pdf images`)",
+            path.display()
+        )),
+        None => Ok((path.display().to_string(), bytes)),
+    }
+}
+
+/// Send one image to the vision model and return its description.
+///
+/// In alt-text mode the output is cleaned into a single short line.
+pub async fn describe_image_data(
+    bytes: &[u8],
+    model: &VisionModelArgs,
+    mode: DescribeMode,
+    prompt: Option<&str>,
+) -> Result<String> {
+    if detect_image_format(bytes).is_none() {
+        return Err(eyre!(
+            "Unsupported image format (expected PNG, JPEG, GIF, WebP, or BMP)"
+        ));
+    }
+
+    let request = build_generate_request(
+        &model.vision_model,
+        &build_prompt(mode, prompt),
+        base64::engine::general_purpose::STANDARD.encode(bytes),
+    );
+
+    let url = format!("{}/api/generate", model.ollama_url.trim_end_matches('/'));
+    let response = reqwest::Client::new()
+        .post(&url)
+        .json(&request)
+        .send()
+        .await
+        .map_err(|e| eyre!("Failed to reach Ollama at {}: {}", model.ollama_url, e))?;
+
+    let status = response.status();
+    let body: GenerateResponse = response
+        .json()
+        .await
+        .map_err(|e| eyre!("Failed to parse Ollama response ({}): {}", status, e))?;
+
+    let text = parse_generate_response(body)
+        .map_err(|e| eyre!("{}", check_model_error(&e, &model.vision_model)))?;
+
+    Ok(match mode {
+        DescribeMode::AltText => clean_alt_text(&text),
+        DescribeMode::Description => text,
+    })
+}
+
+/// Generate alt text for every describable image in a PDF section.
+///
+/// Images in formats the model cannot read (e.g. JPEG 2000) and images whose
+/// description fails are skipped with a warning, so one bad image does not
+/// abort a whole export.
+pub async fn describe_pdf_images(
+    doc: &pdf::ParsedDocument,
+    id: Option<&pdf::SectionId>,
+    model: &VisionModelArgs,
+) -> Result<BTreeMap<String, String>> {
+    let image_ids: Vec<pdf::ImageId> = doc
+        .list_section_images(id)
+        .map_err(|e| eyre!(e))?
+        .into_iter()
+        .map(|img| img.id)
+        .collect();
+
+    let mut alt_texts = BTreeMap::new();
+    for image_id in image_ids {
+        let key = image_id.to_string();
+        if alt_texts.contains_key(&key) {
+            continue;
+        }
+        let result = match doc.get_image(&image_id) {
+            Ok(image) => {
+                describe_image_data(&image.bytes, model, DescribeMode::AltText, None).await
+            }
+            Err(e) => Err(eyre!(e)),
+        };
+        match result {
+            Ok(alt) => {
+                alt_texts.insert(key, alt);
+            }
+            Err(e) => eprintln!("Skipping alt text for image {}: {}", key, e),
+        }
+    }
+
+    Ok(alt_texts)
+}
+
+fn check_model_error(error: &str, model: &str) -> String {
+    let lower = error.to_lowercase();
+    if lower.contains("not found") || lower.contains("pull") {
+        format!(
+            "Model '{}' not found. Run:\n\n  ollama pull {}\n\nOr specify a different model with --vision-model or VISION_MODEL.",
+            model, model
+        )
+    } else {
+        format!("Image description failed: {}", error)
+    }
+}
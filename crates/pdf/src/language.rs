@@ -0,0 +1,61 @@
+//! Natural-language detection for extracted text.
+//!
+//! Detection is statistical (trigram-based via `whatlang`) and only reported
+//! when the detector is confident, so short or mixed-language snippets yield
+//! `None` rather than a guess.
+
+/// Texts shorter than this (in characters) are too short to classify.
+pub const MIN_DETECTION_CHARS: usize = 40;
+
+/// Only the first this-many characters are analyzed; more text rarely
+/// changes the answer and keeps detection cheap on large documents.
+pub const MAX_DETECTION_CHARS: usize = 4000;
+
+/// Detect the language of `text` as an ISO 639-3 code (e.g. `eng`, `spa`).
+///
+/// Returns `None` when the text is too short or the detector is not
+/// confident in its result.
+pub fn detect_language(text: &str) -> Option<String> {
+    let sample = match text.char_indices().nth(MAX_DETECTION_CHARS) {
+        Some((end, _)) => &text[..end],
+        None => text,
+    };
+
+    if sample.trim().chars().count() < MIN_DETECTION_CHARS {
+        return None;
+    }
+
+    whatlang::detect(sample)
+        .filter(|info| info.is_reliable())
+        .map(|info| info.lang().code().to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_detect_english() {
+        let text = "The quick brown fox jumps over the lazy dog while the farmer \
+                    watches from the porch and drinks his morning coffee.";
+        assert_eq!(detect_language(text).as_deref(), Some("eng"));
+    }
+
+    #[test]
+    fn test_detect_spanish() {
+        let text = "El rápido zorro marrón salta sobre el perro perezoso mientras \
+                    el granjero observa desde el porche y toma su café de la mañana.";
+        assert_eq!(detect_language(text).as_deref(), Some("spa"));
+    }
+
+    #[test]
+    fn test_detect_short_text_is_none() {
+        assert_eq!(detect_language("Hello world"), None);
+        assert_eq!(detect_language(""), None);
+    }
+
+    #[test]
+    fn test_detect_non_linguistic_text_is_none() {
+        assert_eq!(detect_language(&"1234 5678 ".repeat(10)), None);
+    }
+}
@@ -5,8 +5,10 @@ use thiserror::Error;
 use parser::backend::PdfBackend;
 
 pub mod images;
+pub mod language;
 pub mod parser;
 pub mod render;
+pub mod thumbnail;
 pub mod tree;
 pub mod types;
 
@@ -22,6 +24,12 @@ pub enum PdfError {
     SectionNotFound(String),
     #[error("Image not found: {0}")]
     ImageNotFound(String),
+    #[error("Page not found: {0}")]
+    PageNotFound(usize),
+    #[error("Rendering error: {0}")]
+    Raster(String),
+    #[error("Unsupported: {0}")]
+    Unsupported(String),
     #[error("I/O error: {0}")]
     Io(#[from] std::io::Error),
 }
@@ -62,7 +70,19 @@ pub struct ParsedDocument {
 impl ParsedDocument {
     /// Parse PDF bytes into a navigable document.
     pub fn from_bytes(bytes: &[u8]) -> Result<Self, PdfError> {
-        let backend = parser::backend::LopdfBackend::load_bytes(bytes)?;
+        Self::from_backend(parser::backend::LopdfBackend::load_bytes(bytes)?)
+    }
+
+    /// Parse a PDF file through a memory mapping instead of reading it into
+    /// a buffer first, so only lopdf's own copy of the data stays resident.
+    ///
+    /// See [`map_file`] for the caveats of mapping files.
+    pub fn from_path(path: impl AsRef<std::path::Path>) -> Result<Self, PdfError> {
+        let mapped = map_file(path)?;
+        Self::from_bytes(&mapped)
+    }
+
+    fn from_backend(backend: parser::backend::LopdfBackend) -> Result<Self, PdfError> {
         let all_spans = parser::layout::extract_all_pages(&backend)?;
         let page_blocks = parser::layout::analyze(all_spans)?;
         let mut blocks = parser::table::classify_blocks(page_blocks);
@@ -94,7 +114,21 @@ impl ParsedDocument {
 
     /// Read a section's content rendered as Markdown.
     /// When `id` is `None`, reads the entire document.
+    ///
+    /// `id` may be a positional ID or a stable slug from the section index.
     pub fn read_section(&self, id: Option<&SectionId>) -> Result<SectionContent, PdfError> {
+        self.read_section_with_alt_text(id, &BTreeMap::new())
+    }
+
+    /// Like [`read_section`](Self::read_section), using `alt_texts` (keyed by
+    /// image ID) as the alt text of rendered image references.
+    pub fn read_section_with_alt_text(
+        &self,
+        id: Option<&SectionId>,
+        alt_texts: &BTreeMap<String, String>,
+    ) -> Result<SectionContent, PdfError> {
+        let id = id.map(|id| self.resolve_section_id(id)).transpose()?;
+        let id = id.as_ref();
         let (title, section_id) = match id {
             Some(id) => {
                 let entry = self
@@ -109,7 +143,14 @@ impl ParsedDocument {
             None => (self.tree.title.clone(), SectionId::new(0, 0)),
         };
 
-        let (content_blocks, image_ids) = collect_section_content(&self.blocks, id);
+        let (mut content_blocks, image_ids) = collect_section_content(&self.blocks, id);
+        for block in &mut content_blocks {
+            if let ContentBlock::Image { id, alt_text } = block {
+                if let Some(alt) = alt_texts.get(id.as_str()) {
+                    *alt_text = Some(alt.clone());
+                }
+            }
+        }
         let text = render::markdown::render_section_content(&content_blocks);
         let pages = self.backend.pages();
         let images = build_image_refs(&self.backend, &pages, &image_ids);
@@ -122,6 +163,33 @@ impl ParsedDocument {
         })
     }
 
+    /// Iterate over a section's content one rendered block at a time.
+    /// When `id` is `None`, iterates over the entire document.
+    ///
+    /// Blocks are produced lazily, so callers can stream large sections or
+    /// stop early without rendering the rest. Concatenating every chunk's
+    /// `markdown` and trimming trailing whitespace gives the same text as
+    /// [`read_section`](Self::read_section).
+    pub fn iter_section_blocks(
+        &self,
+        id: Option<&SectionId>,
+    ) -> Result<impl Iterator<Item = SectionChunk> + '_, PdfError> {
+        let id = id.map(|id| self.resolve_section_id(id)).transpose()?;
+        if let Some(id) = &id {
+            if !self.tree.index.entries.iter().any(|e| &e.id == id) {
+                return Err(PdfError::SectionNotFound(id.to_string()));
+            }
+        }
+
+        Ok(
+            SectionWalker::new(&self.blocks, id).map(|(block, page)| SectionChunk {
+                markdown: render::markdown::render_block(&block),
+                block,
+                page,
+            }),
+        )
+    }
+
     /// Peek at a section's text content with a window.
     ///
     /// Computes the character offset from `position` and `limit` automatically.
@@ -133,11 +201,16 @@ impl ParsedDocument {
     ) -> Result<PeekContent, PdfError> {
         let section = self.read_section(id)?;
         let total_chars = section.text.chars().count();
-        let offset = compute_peek_offset(position, total_chars, limit);
+        let offset = match position {
+            PeekPosition::Page(page) => self
+                .page_char_offset(id, page as usize)?
+                .min(total_chars.saturating_sub(limit)),
+            _ => compute_peek_offset(position, total_chars, limit),
+        };
         let snippet = extract_window(&section.text, offset, limit).to_string();
 
         Ok(PeekContent {
-            id: id.cloned(),
+            id: id.map(|_| section.id),
             title: section.title,
             snippet,
             position,
@@ -145,11 +218,36 @@ impl ParsedDocument {
         })
     }
 
+    /// Character offset in a section's rendered text where `page` begins.
+    ///
+    /// Blocks are sorted by page, so the content before `page` is the
+    /// section's content rendered from the prefix of blocks on earlier pages.
+    /// Pages before the section yield 0; pages after it yield its length.
+    fn page_char_offset(&self, id: Option<&SectionId>, page: usize) -> Result<usize, PdfError> {
+        if page == 0 || page > self.tree.metadata.page_count {
+            return Err(PdfError::PageNotFound(page));
+        }
+
+        let id = id.map(|id| self.resolve_section_id(id)).transpose()?;
+        let cut = self.blocks.partition_point(|b| b.page() < page);
+        let (before, _) = collect_section_content(&self.blocks[..cut], id.as_ref());
+        if before.is_empty() {
+            return Ok(0);
+        }
+        // Account for the blank line that separates blocks in the full render.
+        Ok(render::markdown::render_section_content(&before)
+            .chars()
+            .count()
+            + 2)
+    }
+
     /// List images in a section or the whole document with section and page context.
     pub fn list_section_images(
         &self,
         id: Option<&SectionId>,
     ) -> Result<Vec<EnrichedImageRef>, PdfError> {
+        let id = id.map(|id| self.resolve_section_id(id)).transpose()?;
+        let id = id.as_ref();
         if let Some(section_id) = id {
             if !self.tree.index.entries.iter().any(|e| &e.id == section_id) {
                 return Err(PdfError::SectionNotFound(section_id.to_string()));
@@ -161,6 +259,20 @@ impl ParsedDocument {
         Ok(build_enriched_image_refs(locations, &format_map))
     }
 
+    /// Map a stable slug to its positional ID. Positional IDs pass through.
+    pub fn resolve_section_id(&self, id: &SectionId) -> Result<SectionId, PdfError> {
+        if id.is_positional() {
+            return Ok(id.clone());
+        }
+        self.tree
+            .index
+            .entries
+            .iter()
+            .find(|e| e.slug == id.as_str())
+            .map(|e| e.id.clone())
+            .ok_or_else(|| PdfError::SectionNotFound(id.to_string()))
+    }
+
     /// Build a map of image ID to format by scanning all pages.
     fn build_image_format_map(&self) -> HashMap<String, ImageFormat> {
         self.backend
@@ -181,12 +293,42 @@ impl ParsedDocument {
     pub fn metadata(&self) -> &DocumentMetadata {
         &self.tree.metadata
     }
+
+    /// Word, image, and heading counts for every page.
+    pub fn page_stats(&self) -> Vec<PageStats> {
+        compute_page_stats(&self.blocks, self.tree.metadata.page_count)
+    }
+
+    /// Return the `(first, last)` page range covered by a section.
+    pub fn section_page_range(&self, id: &SectionId) -> Result<(usize, usize), PdfError> {
+        let id = &self.resolve_section_id(id)?;
+        find_section(&self.tree.sections, id)
+            .map(|section| section.page_range)
+            .ok_or_else(|| PdfError::SectionNotFound(id.to_string()))
+    }
 }
 
 // ---------------------------------------------------------------------------
 // Convenience free functions (stateless, re-parse each call)
 // ---------------------------------------------------------------------------
 
+/// Memory-map a PDF file for read-only parsing.
+///
+/// The mapping derefs to `&[u8]`, so it can be passed to any function taking
+/// PDF bytes without copying the file into memory first. Pages are loaded
+/// lazily by the OS and can be dropped under memory pressure, which keeps
+/// peak memory low for multi-hundred-MB documents.
+///
+/// The file must not be truncated or modified while the mapping is alive;
+/// doing so can crash the process (SIGBUS) or yield inconsistent data.
+pub fn map_file(path: impl AsRef<std::path::Path>) -> Result<memmap2::Mmap, PdfError> {
+    let file = std::fs::File::open(path)?;
+    // SAFETY: the mapping is read-only and private to this process. Callers
+    // are documented to keep the file unchanged while the mapping is alive.
+    let mapped = unsafe { memmap2::Mmap::map(&file)? };
+    Ok(mapped)
+}
+
 /// Parse PDF bytes into a document tree.
 pub fn parse(bytes: &[u8]) -> Result<DocumentTree, PdfError> {
     Ok(ParsedDocument::from_bytes(bytes)?.tree)
@@ -221,16 +363,89 @@ pub fn get_image(bytes: &[u8], id: &ImageId) -> Result<ImageData, PdfError> {
     images::extract_image(&backend, id)
 }
 
+/// Render a 1-based page as a PNG thumbnail (requires the `raster` feature).
+pub fn render_page_thumbnail(
+    bytes: &[u8],
+    page: usize,
+    max_size: u32,
+) -> Result<ImageData, PdfError> {
+    thumbnail::render_page(bytes, page, max_size)
+}
+
+/// Render the first page of a section as a PNG thumbnail (requires the `raster` feature).
+pub fn render_section_thumbnail(
+    bytes: &[u8],
+    id: &SectionId,
+    max_size: u32,
+) -> Result<ImageData, PdfError> {
+    let (first_page, _) = ParsedDocument::from_bytes(bytes)?.section_page_range(id)?;
+    thumbnail::render_page(bytes, first_page, max_size)
+}
+
 /// Get document metadata without building the full tree.
 pub fn info(bytes: &[u8]) -> Result<DocumentMetadata, PdfError> {
     let backend = parser::backend::LopdfBackend::load_bytes(bytes)?;
     Ok(extract_metadata(&backend))
 }
 
+/// Get document metadata along with per-page word, image, and heading counts.
+pub fn stats(bytes: &[u8]) -> Result<DocumentStats, PdfError> {
+    let doc = ParsedDocument::from_bytes(bytes)?;
+    Ok(DocumentStats {
+        metadata: doc.metadata().clone(),
+        pages: doc.page_stats(),
+    })
+}
+
 // ---------------------------------------------------------------------------
 // Internal helpers
 // ---------------------------------------------------------------------------
 
+/// Tally per-page counts from classified blocks.
+///
+/// Every page from 1 to `page_count` gets an entry, so blank pages show up
+/// as zeros. Blocks on pages beyond `page_count` are ignored.
+fn compute_page_stats(blocks: &[ClassifiedBlock], page_count: usize) -> Vec<PageStats> {
+    let mut pages: Vec<PageStats> = (1..=page_count)
+        .map(|page| PageStats {
+            page,
+            ..Default::default()
+        })
+        .collect();
+
+    let words = |text: &str| text.split_whitespace().count();
+
+    for block in blocks {
+        let Some(stats) = block
+            .page()
+            .checked_sub(1)
+            .and_then(|idx| pages.get_mut(idx))
+        else {
+            continue;
+        };
+        match block {
+            ClassifiedBlock::Heading { title, .. } => {
+                stats.heading_count += 1;
+                stats.word_count += words(title);
+            }
+            ClassifiedBlock::Paragraph { text, .. } => stats.word_count += words(text),
+            ClassifiedBlock::Table { headers, rows, .. } => {
+                stats.word_count += headers
+                    .iter()
+                    .chain(rows.iter().flatten())
+                    .map(|cell| words(cell))
+                    .sum::<usize>();
+            }
+            ClassifiedBlock::Image { .. } => stats.image_count += 1,
+            ClassifiedBlock::List { items, .. } => {
+                stats.word_count += items.iter().map(|item| words(&item.text)).sum::<usize>();
+            }
+        }
+    }
+
+    pages
+}
+
 fn extract_metadata(backend: &parser::backend::LopdfBackend) -> DocumentMetadata {
     let raw = backend.metadata();
     DocumentMetadata {
@@ -238,15 +453,34 @@ fn extract_metadata(backend: &parser::backend::LopdfBackend) -> DocumentMetadata
         author: raw.get("Author").cloned(),
         page_count: backend.page_count(),
         creator: raw.get("Creator").cloned(),
+        language: None,
     }
 }
 
+/// Depth-first search for a section by ID.
+fn find_section<'a>(sections: &'a [Section], id: &SectionId) -> Option<&'a Section> {
+    sections.iter().find_map(|section| {
+        if &section.id == id {
+            Some(section)
+        } else {
+            find_section(&section.children, id)
+        }
+    })
+}
+
 /// Compute the character offset for a given peek position, total character count, and limit.
 fn compute_peek_offset(position: PeekPosition, total_chars: usize, limit: usize) -> usize {
     match position {
         PeekPosition::Beginning => 0,
         PeekPosition::Middle => total_chars.saturating_sub(limit) / 2,
         PeekPosition::Ending => total_chars.saturating_sub(limit),
+        PeekPosition::Percent(pct) => {
+            let span = total_chars.saturating_sub(limit) as f32;
+            ((span * pct.clamp(0.0, 100.0) / 100.0).round() as usize)
+                .min(total_chars.saturating_sub(limit))
+        }
+        // Resolved against page boundaries by `ParsedDocument::peek_section`.
+        PeekPosition::Page(_) => 0,
         PeekPosition::Random => {
             if total_chars <= limit {
                 0
@@ -338,19 +572,17 @@ fn collect_image_locations(
 
                 stack.push((id, lvl, title.clone()));
             }
-            ClassifiedBlock::Image { id, page } => {
-                if ancestor_is_target(&stack) {
-                    let (section_id, section_title) = match stack.last() {
-                        Some((sid, _, title)) => (sid.clone(), title.clone()),
-                        None => (doc_section_id.clone(), doc_section_title.clone()),
-                    };
-                    locations.push(ImageLocation {
-                        id: id.clone(),
-                        page: *page,
-                        section_id,
-                        section_title,
-                    });
-                }
+            ClassifiedBlock::Image { id, page } if ancestor_is_target(&stack) => {
+                let (section_id, section_title) = match stack.last() {
+                    Some((sid, _, title)) => (sid.clone(), title.clone()),
+                    None => (doc_section_id.clone(), doc_section_title.clone()),
+                };
+                locations.push(ImageLocation {
+                    id: id.clone(),
+                    page: *page,
+                    section_id,
+                    section_title,
+                });
             }
             _ => {}
         }
@@ -387,71 +619,113 @@ fn collect_section_content(
     blocks: &[ClassifiedBlock],
     target_id: Option<&SectionId>,
 ) -> (Vec<ContentBlock>, Vec<String>) {
-    let mut level_counters: [usize; 7] = [0; 7];
-    let mut stack: Vec<(SectionId, u8)> = Vec::new();
     let mut content_blocks: Vec<ContentBlock> = Vec::new();
     let mut image_ids: Vec<String> = Vec::new();
 
-    let collect_all = target_id.is_none();
+    for (block, _) in SectionWalker::new(blocks, target_id.cloned()) {
+        if let ContentBlock::Image { id, .. } = &block {
+            image_ids.push(id.clone());
+        }
+        content_blocks.push(block);
+    }
 
-    // Check whether any ancestor in the stack matches the target.
-    let ancestor_is_target = |stack: &[(SectionId, u8)]| {
-        collect_all || stack.iter().any(|(sid, _)| Some(sid) == target_id)
-    };
+    (content_blocks, image_ids)
+}
 
-    for block in blocks {
-        match block {
-            ClassifiedBlock::Heading { level, title, .. } => {
-                let lvl = (*level).clamp(1, 6);
+/// Lazily walks classified blocks with the same stack algorithm as
+/// `build_tree`, yielding each `ContentBlock` (and its page) that belongs to
+/// the target section. When the target is `None`, every block is yielded.
+///
+/// Section IDs are unique, so once the target section has been closed by a
+/// later heading the walk stops without scanning the rest of the document.
+struct SectionWalker<'a> {
+    blocks: std::slice::Iter<'a, ClassifiedBlock>,
+    target: Option<SectionId>,
+    level_counters: [usize; 7],
+    stack: Vec<(SectionId, u8)>,
+    entered: bool,
+}
 
-                // Pop entries with level >= this heading.
-                while let Some((_, top_level)) = stack.last() {
-                    if *top_level >= lvl {
-                        stack.pop();
-                    } else {
-                        break;
+impl<'a> SectionWalker<'a> {
+    fn new(blocks: &'a [ClassifiedBlock], target: Option<SectionId>) -> Self {
+        SectionWalker {
+            blocks: blocks.iter(),
+            target,
+            level_counters: [0; 7],
+            stack: Vec::new(),
+            entered: false,
+        }
+    }
+
+    /// Whether the current position is inside the target section.
+    fn in_target(&self) -> bool {
+        match &self.target {
+            None => true,
+            Some(target) => self.stack.iter().any(|(sid, _)| sid == target),
+        }
+    }
+}
+
+impl Iterator for SectionWalker<'_> {
+    type Item = (ContentBlock, usize);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let block = self.blocks.next()?;
+            let in_target = match block {
+                ClassifiedBlock::Heading { level, .. } => {
+                    let lvl = (*level).clamp(1, 6);
+
+                    // Pop entries with level >= this heading.
+                    while let Some((_, top_level)) = self.stack.last() {
+                        if *top_level >= lvl {
+                            self.stack.pop();
+                        } else {
+                            break;
+                        }
                     }
-                }
 
-                let idx = level_counters[lvl as usize];
-                level_counters[lvl as usize] += 1;
-                let id = SectionId::new(lvl, idx);
+                    // Subheadings belong to the target only if an ancestor is
+                    // the target, so check before pushing this heading.
+                    let in_target = self.in_target();
 
-                if ancestor_is_target(&stack) {
-                    content_blocks.push(ContentBlock::SubHeading {
-                        level: HeadingLevel::try_from(lvl).unwrap_or(HeadingLevel::H1),
-                        title: title.clone(),
-                    });
-                }
+                    let idx = self.level_counters[lvl as usize];
+                    self.level_counters[lvl as usize] += 1;
+                    self.stack.push((SectionId::new(lvl, idx), lvl));
 
-                stack.push((id, lvl));
-            }
-            ClassifiedBlock::Paragraph { text, .. } => {
-                if ancestor_is_target(&stack) {
-                    content_blocks.push(ContentBlock::Paragraph(text.clone()));
-                }
-            }
-            ClassifiedBlock::Table { headers, rows, .. } => {
-                if ancestor_is_target(&stack) {
-                    content_blocks.push(ContentBlock::Table {
-                        headers: headers.clone(),
-                        rows: rows.clone(),
-                    });
-                }
-            }
-            ClassifiedBlock::Image { id, .. } => {
-                if ancestor_is_target(&stack) {
-                    image_ids.push(id.clone());
-                    content_blocks.push(ContentBlock::Image {
-                        id: id.clone(),
-                        alt_text: None,
-                    });
+                    if self.in_target() {
+                        self.entered = true;
+                    } else if self.entered {
+                        return None;
+                    }
+                    in_target
                 }
+                _ => self.in_target(),
+            };
+
+            if !in_target {
+                continue;
             }
+
+            let content = match block {
+                ClassifiedBlock::Heading { level, title, .. } => ContentBlock::SubHeading {
+                    level: HeadingLevel::try_from((*level).clamp(1, 6)).unwrap_or(HeadingLevel::H1),
+                    title: title.clone(),
+                },
+                ClassifiedBlock::Paragraph { text, .. } => ContentBlock::Paragraph(text.clone()),
+                ClassifiedBlock::Table { headers, rows, .. } => ContentBlock::Table {
+                    headers: headers.clone(),
+                    rows: rows.clone(),
+                },
+                ClassifiedBlock::List { items, .. } => ContentBlock::List(items.clone()),
+                ClassifiedBlock::Image { id, .. } => ContentBlock::Image {
+                    id: id.clone(),
+                    alt_text: None,
+                },
+            };
+            return Some((content, block.page()));
         }
     }
-
-    (content_blocks, image_ids)
 }
 
 /// Build `ImageRef`s for a set of image IDs by querying page resources.
@@ -481,6 +755,35 @@ fn build_image_refs(
 mod tests {
     use super::*;
 
+    fn temp_file(name: &str, contents: &[u8]) -> std::path::PathBuf {
+        let path = std::env::temp_dir().join(format!("pdf-{}-{}", std::process::id(), name));
+        std::fs::write(&path, contents).unwrap();
+        path
+    }
+
+    #[test]
+    fn test_map_file_matches_contents() {
+        let path = temp_file("map.bin", b"%PDF-1.7 not really");
+        let mapped = map_file(&path).unwrap();
+        assert_eq!(&mapped[..], b"%PDF-1.7 not really");
+        drop(mapped);
+        std::fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn test_map_file_missing() {
+        let err = map_file("/nonexistent/dir/file.pdf").unwrap_err();
+        assert!(matches!(err, PdfError::Io(_)));
+    }
+
+    #[test]
+    fn test_from_path_invalid_pdf() {
+        let path = temp_file("invalid.pdf", b"definitely not a pdf");
+        let result = ParsedDocument::from_path(&path);
+        std::fs::remove_file(path).unwrap();
+        assert!(matches!(result, Err(PdfError::Parse(_))));
+    }
+
     #[test]
     fn test_collect_section_content_empty() {
         let (blocks, images) = collect_section_content(&[], Some(&SectionId::new(1, 0)));
@@ -524,6 +827,65 @@ mod tests {
         assert_eq!(images, vec!["img1"]);
     }
 
+    #[test]
+    fn test_section_walker_stops_after_target() {
+        let blocks = vec![
+            ClassifiedBlock::Heading {
+                level: 1,
+                title: "Chapter 1".to_string(),
+                page: 1,
+            },
+            ClassifiedBlock::Paragraph {
+                text: "Intro".to_string(),
+                page: 1,
+            },
+            ClassifiedBlock::Heading {
+                level: 2,
+                title: "Details".to_string(),
+                page: 2,
+            },
+            ClassifiedBlock::Paragraph {
+                text: "Deep".to_string(),
+                page: 3,
+            },
+            ClassifiedBlock::Heading {
+                level: 1,
+                title: "Chapter 2".to_string(),
+                page: 4,
+            },
+            ClassifiedBlock::Paragraph {
+                text: "Elsewhere".to_string(),
+                page: 4,
+            },
+        ];
+
+        let mut walker = SectionWalker::new(&blocks, Some(SectionId::new(1, 0)));
+        let pages: Vec<usize> = walker.by_ref().map(|(_, page)| page).collect();
+        assert_eq!(pages, vec![1, 2, 3]);
+        // The walk ended at the closing heading instead of scanning to the end.
+        assert_eq!(walker.blocks.len(), 1);
+    }
+
+    #[test]
+    fn test_section_walker_whole_document() {
+        let blocks = vec![
+            ClassifiedBlock::Paragraph {
+                text: "Preamble".to_string(),
+                page: 1,
+            },
+            ClassifiedBlock::Heading {
+                level: 1,
+                title: "Chapter".to_string(),
+                page: 2,
+            },
+        ];
+        let items: Vec<_> = SectionWalker::new(&blocks, None).collect();
+        assert_eq!(items.len(), 2);
+        assert!(
+            matches!(&items[1].0, ContentBlock::SubHeading { title, .. } if title == "Chapter")
+        );
+    }
+
     #[test]
     fn test_collect_section_content_with_child_subheadings() {
         let blocks = vec![
@@ -673,6 +1035,24 @@ mod tests {
         assert_eq!(extract_window("Hello!", 5, 1), "!");
     }
 
+    #[test]
+    fn test_compute_peek_offset_percent() {
+        assert_eq!(
+            compute_peek_offset(PeekPosition::Percent(0.0), 1100, 100),
+            0
+        );
+        assert_eq!(
+            compute_peek_offset(PeekPosition::Percent(70.0), 1100, 100),
+            700
+        );
+        assert_eq!(
+            compute_peek_offset(PeekPosition::Percent(100.0), 1100, 100),
+            1000
+        );
+        // Window larger than the text always starts at 0.
+        assert_eq!(compute_peek_offset(PeekPosition::Percent(50.0), 50, 100), 0);
+    }
+
     #[test]
     fn test_extract_window_unicode() {
         let text = "😊😀😃😁😆";
@@ -687,6 +1067,39 @@ mod tests {
         assert!(result.is_err());
     }
 
+    // -- find_section tests --
+
+    fn section(id: SectionId, page_range: (usize, usize), children: Vec<Section>) -> Section {
+        Section {
+            id,
+            slug: String::new(),
+            level: HeadingLevel::H1,
+            title: "Title".to_string(),
+            children,
+            content_preview: String::new(),
+            char_count: 0,
+            image_count: 0,
+            page_range,
+            language: None,
+        }
+    }
+
+    #[test]
+    fn test_find_section_nested() {
+        let sections = vec![
+            section(SectionId::new(1, 0), (1, 3), vec![]),
+            section(
+                SectionId::new(1, 1),
+                (4, 9),
+                vec![section(SectionId::new(2, 0), (6, 9), vec![])],
+            ),
+        ];
+
+        let found = find_section(&sections, &SectionId::new(2, 0)).unwrap();
+        assert_eq!(found.page_range, (6, 9));
+        assert!(find_section(&sections, &SectionId::new(3, 0)).is_none());
+    }
+
     // -- collect_image_locations tests --
 
     #[test]
@@ -1118,4 +1531,73 @@ mod tests {
         assert_eq!(locations[0].section_id, SectionId::new(3, 0));
         assert_eq!(locations[0].section_title, "Leaf");
     }
+
+    #[test]
+    fn test_compute_page_stats() {
+        let blocks = vec![
+            ClassifiedBlock::Paragraph {
+                text: "Copyright 2024".to_string(),
+                page: 1,
+            },
+            ClassifiedBlock::Image {
+                id: "logo".to_string(),
+                page: 1,
+            },
+            ClassifiedBlock::Heading {
+                level: 1,
+                title: "Introduction".to_string(),
+                page: 3,
+            },
+            ClassifiedBlock::Paragraph {
+                text: "The real content starts here.".to_string(),
+                page: 3,
+            },
+            ClassifiedBlock::Table {
+                headers: vec!["Name".to_string(), "Value".to_string()],
+                rows: vec![vec!["a b".to_string(), "1".to_string()]],
+                page: 3,
+            },
+            ClassifiedBlock::List {
+                items: vec![ListItem {
+                    text: "one two".to_string(),
+                    ordered: false,
+                    depth: 0,
+                }],
+                page: 3,
+            },
+        ];
+
+        let stats = compute_page_stats(&blocks, 3);
+
+        assert_eq!(stats.len(), 3);
+        assert_eq!(
+            stats[0],
+            PageStats {
+                page: 1,
+                word_count: 2,
+                image_count: 1,
+                heading_count: 0,
+            }
+        );
+        assert_eq!(
+            stats[1],
+            PageStats {
+                page: 2,
+                ..Default::default()
+            }
+        );
+        assert_eq!(stats[2].heading_count, 1);
+        assert_eq!(stats[2].word_count, 1 + 5 + 5 + 2);
+        assert_eq!(stats[2].image_count, 0);
+    }
+
+    #[test]
+    fn test_compute_page_stats_ignores_out_of_range_pages() {
+        let blocks = vec![ClassifiedBlock::Paragraph {
+            text: "orphan".to_string(),
+            page: 5,
+        }];
+        let stats = compute_page_stats(&blocks, 2);
+        assert_eq!(stats.iter().map(|s| s.word_count).sum::<usize>(), 0);
+    }
 }
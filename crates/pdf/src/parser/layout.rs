@@ -384,21 +384,17 @@ pub fn extract_page_spans(
             "Tm" => {
                 handle_tm(&op.operands, &mut state);
             }
-            "Td" => {
-                if op.operands.len() >= 2 {
-                    let tx = get_number_from_value(&op.operands[0]).unwrap_or(0.0);
-                    let ty = get_number_from_value(&op.operands[1]).unwrap_or(0.0);
-                    state.translate_line(tx, ty);
-                }
+            "Td" if op.operands.len() >= 2 => {
+                let tx = get_number_from_value(&op.operands[0]).unwrap_or(0.0);
+                let ty = get_number_from_value(&op.operands[1]).unwrap_or(0.0);
+                state.translate_line(tx, ty);
             }
-            "TD" => {
+            "TD" if op.operands.len() >= 2 => {
                 // TD is equivalent to: -ty TL ; tx ty Td
-                if op.operands.len() >= 2 {
-                    let tx = get_number_from_value(&op.operands[0]).unwrap_or(0.0);
-                    let ty = get_number_from_value(&op.operands[1]).unwrap_or(0.0);
-                    state.leading = -ty;
-                    state.translate_line(tx, ty);
-                }
+                let tx = get_number_from_value(&op.operands[0]).unwrap_or(0.0);
+                let ty = get_number_from_value(&op.operands[1]).unwrap_or(0.0);
+                state.leading = -ty;
+                state.translate_line(tx, ty);
             }
             "T*" => {
                 // Move to start of next line: equivalent to 0 -TL Td
@@ -452,18 +448,16 @@ pub fn extract_page_spans(
                     emit_show_string(first, backend, page_id, &mut state, &mut spans);
                 }
             }
-            "\"" => {
+            "\"" if op.operands.len() >= 3 => {
                 // " aw ac string  =>  set Tw, Tc, T*, Tj
-                if op.operands.len() >= 3 {
-                    if let Some(aw) = get_number_from_value(&op.operands[0]) {
-                        state.word_spacing = aw;
-                    }
-                    if let Some(ac) = get_number_from_value(&op.operands[1]) {
-                        state.char_spacing = ac;
-                    }
-                    state.translate_line(0.0, -state.leading);
-                    emit_show_string(&op.operands[2], backend, page_id, &mut state, &mut spans);
+                if let Some(aw) = get_number_from_value(&op.operands[0]) {
+                    state.word_spacing = aw;
+                }
+                if let Some(ac) = get_number_from_value(&op.operands[1]) {
+                    state.char_spacing = ac;
                 }
+                state.translate_line(0.0, -state.leading);
+                emit_show_string(&op.operands[2], backend, page_id, &mut state, &mut spans);
             }
 
             _ => { /* Ignore non-text operators */ }
@@ -883,24 +877,32 @@ fn line_char_count(line: &TextLine) -> usize {
 /// number+period, or similar marker).
 fn is_list_item(line: &TextLine) -> bool {
     let text: String = line.spans.iter().map(|s| s.text.as_str()).collect();
+    split_list_marker(&text).is_some()
+}
+
+/// Split a list marker off the start of `text`.
+///
+/// Returns `(ordered, rest)` where `ordered` is true for numbered/lettered
+/// markers and `rest` is the item text with the marker and leading
+/// whitespace removed. Returns `None` when the text is not a list item.
+pub fn split_list_marker(text: &str) -> Option<(bool, &str)> {
     let trimmed = text.trim_start();
 
-    if trimmed.is_empty() {
-        return false;
-    }
+    let first = trimmed.chars().next()?;
 
     // Bullet characters.
-    let first = trimmed.chars().next().unwrap();
     if matches!(
         first,
         '\u{2022}' | '\u{2023}' | '\u{25E6}' | '\u{2043}' | '\u{2219}'
     ) {
-        return true;
+        return Some((false, trimmed[first.len_utf8()..].trim_start()));
     }
 
     // Dash-style bullets.
-    if trimmed.starts_with("- ") || trimmed.starts_with("-- ") || trimmed.starts_with("\u{2013} ") {
-        return true;
+    for dash in ["- ", "-- ", "\u{2013} "] {
+        if let Some(rest) = trimmed.strip_prefix(dash) {
+            return Some((false, rest.trim_start()));
+        }
     }
 
     // Numbered list: "1." "2)" "a." "a)" "(a)" "(1)" etc.
@@ -915,7 +917,7 @@ fn is_list_item(line: &TextLine) -> bool {
                     .chars()
                     .all(|c| c.is_ascii_digit() || c.is_ascii_alphabetic())
                 {
-                    return true;
+                    return Some((true, trimmed[close + 1..].trim_start()));
                 }
             }
         }
@@ -930,15 +932,19 @@ fn is_list_item(line: &TextLine) -> bool {
             {
                 // Ensure there is a space after the marker (or it ends the text).
                 if trimmed.get(pos + 1..pos + 2) == Some(" ") || pos + 1 == trimmed.len() {
-                    return true;
+                    return Some((true, trimmed[pos + 1..].trim_start()));
                 }
             }
         }
     }
 
-    false
+    None
 }
 
+/// Two list lines whose left edges differ by less than this many points are
+/// considered to be at the same nesting depth.
+pub const LIST_INDENT_TOLERANCE: f32 = 3.0;
+
 /// Determine the [`BlockType`] for a single line.
 fn classify_line(line: &TextLine) -> BlockType {
     if line.is_heading {
@@ -957,6 +963,11 @@ fn classify_line(line: &TextLine) -> BlockType {
 /// - The vertical gap between consecutive lines exceeds
 ///   [`BLOCK_GAP_FACTOR`] times the font size.
 /// - A heading line always starts its own single-line block.
+///
+/// Inside a list, a line without a marker that is indented past the left
+/// edge of the last marker line is a wrapped continuation of that item and
+/// stays in the list block, so the whole list keeps one set of indent
+/// levels.
 pub fn group_lines_into_blocks(lines: Vec<TextLine>) -> Vec<TextBlock> {
     if lines.is_empty() {
         return Vec::new();
@@ -965,9 +976,14 @@ pub fn group_lines_into_blocks(lines: Vec<TextLine>) -> Vec<TextBlock> {
     let mut blocks: Vec<TextBlock> = Vec::new();
     let mut current_lines: Vec<TextLine> = Vec::new();
     let mut current_type: Option<BlockType> = None;
+    // Left edge of the most recent marker line in the current list block.
+    let mut marker_x: Option<f32> = None;
 
     for line in lines {
-        let line_type = classify_line(&line);
+        let mut line_type = classify_line(&line);
+        if line_type == BlockType::ListItem {
+            marker_x = Some(line.x);
+        }
 
         // Headings always form their own block.
         if matches!(line_type, BlockType::Heading(_)) {
@@ -996,6 +1012,15 @@ pub fn group_lines_into_blocks(lines: Vec<TextLine>) -> Vec<TextBlock> {
             false
         };
 
+        // A wrapped list item continues the list block.
+        if line_type == BlockType::Paragraph
+            && !gap_break
+            && current_type == Some(BlockType::ListItem)
+            && marker_x.is_some_and(|x| line.x > x + LIST_INDENT_TOLERANCE)
+        {
+            line_type = BlockType::ListItem;
+        }
+
         // Check for block-type change.
         let type_change = current_type.as_ref().is_some_and(|ct| *ct != line_type);
 
@@ -1530,6 +1555,30 @@ mod tests {
         assert_eq!(blocks[1].block_type, BlockType::ListItem);
     }
 
+    #[test]
+    fn test_group_lines_wrapped_list_item_stays_in_list() {
+        let lines = vec![
+            make_line(vec![make_span("- item one", 0.0, 700.0, 12.0)], 12.0, 700.0),
+            make_line(
+                vec![make_span("wraps here", 10.0, 688.0, 12.0)],
+                12.0,
+                688.0,
+            ),
+            make_line(vec![make_span("- item two", 0.0, 676.0, 12.0)], 12.0, 676.0),
+            make_line(
+                vec![make_span("After list.", 0.0, 664.0, 12.0)],
+                12.0,
+                664.0,
+            ),
+        ];
+
+        let blocks = group_lines_into_blocks(lines);
+        assert_eq!(blocks.len(), 2);
+        assert_eq!(blocks[0].block_type, BlockType::ListItem);
+        assert_eq!(blocks[0].lines.len(), 3);
+        assert_eq!(blocks[1].block_type, BlockType::Paragraph);
+    }
+
     #[test]
     fn test_group_lines_consecutive_headings() {
         let lines = vec![
@@ -1602,6 +1651,17 @@ mod tests {
         assert!(is_list_item(&line));
     }
 
+    #[test]
+    fn test_split_list_marker() {
+        assert_eq!(split_list_marker("\u{2022} Item"), Some((false, "Item")));
+        assert_eq!(split_list_marker("  - Dashed"), Some((false, "Dashed")));
+        assert_eq!(split_list_marker("12. Twelfth"), Some((true, "Twelfth")));
+        assert_eq!(split_list_marker("(iv) Roman"), Some((true, "Roman")));
+        assert_eq!(split_list_marker("b) Letter"), Some((true, "Letter")));
+        assert_eq!(split_list_marker("Plain text"), None);
+        assert_eq!(split_list_marker(""), None);
+    }
+
     #[test]
     fn test_not_list_item() {
         let line = make_line(
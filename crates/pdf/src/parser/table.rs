@@ -1,7 +1,9 @@
 use std::collections::BTreeMap;
 
-use super::layout::{BlockType, TextBlock, TextLine, TextSpan};
-use crate::types::ClassifiedBlock;
+use super::layout::{
+    split_list_marker, BlockType, TextBlock, TextLine, TextSpan, LIST_INDENT_TOLERANCE,
+};
+use crate::types::{ClassifiedBlock, ListItem};
 
 // ---------------------------------------------------------------------------
 // Core types
@@ -72,8 +74,9 @@ impl Default for TableDetectorConfig {
 /// * `BlockType::Table` attempts structural table detection on the block's
 ///   spans; if detection succeeds the first detected table is used, otherwise
 ///   the block falls back to a paragraph.
-/// * `BlockType::Paragraph` and `BlockType::ListItem` produce
-///   `ClassifiedBlock::Paragraph`.
+/// * `BlockType::Paragraph` produces `ClassifiedBlock::Paragraph`.
+/// * `BlockType::ListItem` produces `ClassifiedBlock::List`, one item per
+///   marker line with nesting inferred from X offsets.
 pub fn classify_blocks(pages: Vec<(usize, Vec<TextBlock>)>) -> Vec<ClassifiedBlock> {
     let config = TableDetectorConfig::default();
     let mut result: Vec<ClassifiedBlock> = Vec::new();
@@ -102,10 +105,14 @@ pub fn classify_blocks(pages: Vec<(usize, Vec<TextBlock>)>) -> Vec<ClassifiedBlo
                         result.push(ClassifiedBlock::Paragraph { text, page });
                     }
                 }
-                BlockType::Paragraph | BlockType::ListItem => {
+                BlockType::Paragraph => {
                     let text = concat_block_text(&block.lines);
                     result.push(ClassifiedBlock::Paragraph { text, page });
                 }
+                BlockType::ListItem => {
+                    let items = lines_to_list_items(&block.lines);
+                    result.push(ClassifiedBlock::List { items, page });
+                }
             }
         }
     }
@@ -371,6 +378,56 @@ fn concat_block_text(lines: &[TextLine]) -> String {
         .join(" ")
 }
 
+/// Turn the lines of a list block into list items.
+///
+/// Each line that starts with a list marker becomes an item; any other line
+/// is treated as a wrapped continuation of the previous item. Depth is the
+/// rank of the line's X offset among the distinct indentation levels used
+/// by marker lines in the block.
+fn lines_to_list_items(lines: &[TextLine]) -> Vec<ListItem> {
+    let mut indents: Vec<f32> = Vec::new();
+    for line in lines {
+        if split_list_marker(&line.text()).is_some()
+            && !indents
+                .iter()
+                .any(|x| (x - line.x).abs() < LIST_INDENT_TOLERANCE)
+        {
+            indents.push(line.x);
+        }
+    }
+    indents.sort_by(|a, b| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal));
+
+    let mut items: Vec<ListItem> = Vec::new();
+    for line in lines {
+        let text = line.text();
+        match split_list_marker(&text) {
+            Some((ordered, rest)) => {
+                let depth = indents
+                    .iter()
+                    .position(|x| (x - line.x).abs() < LIST_INDENT_TOLERANCE)
+                    .unwrap_or(0);
+                items.push(ListItem {
+                    text: rest.to_string(),
+                    ordered,
+                    depth,
+                });
+            }
+            None => match items.last_mut() {
+                Some(prev) => {
+                    prev.text.push(' ');
+                    prev.text.push_str(text.trim());
+                }
+                None => items.push(ListItem {
+                    text: text.trim().to_string(),
+                    ordered: false,
+                    depth: 0,
+                }),
+            },
+        }
+    }
+    items
+}
+
 /// Flatten all spans out of a set of text lines.
 fn collect_spans(lines: &[TextLine]) -> Vec<TextSpan> {
     lines.iter().flat_map(|l| l.spans.iter().cloned()).collect()
@@ -808,7 +865,7 @@ mod tests {
     }
 
     #[test]
-    fn classify_list_item_as_paragraph() {
+    fn classify_list_item_as_list() {
         let block = text_block(
             vec![text_line(vec![span("- item", 10.0, 100.0)])],
             BlockType::ListItem,
@@ -816,14 +873,80 @@ mod tests {
         let result = classify_blocks(vec![(1, vec![block])]);
         assert_eq!(result.len(), 1);
         match &result[0] {
-            ClassifiedBlock::Paragraph { text, page } => {
-                assert_eq!(text, "- item");
+            ClassifiedBlock::List { items, page } => {
+                assert_eq!(items.len(), 1);
+                assert_eq!(items[0].text, "item");
+                assert!(!items[0].ordered);
+                assert_eq!(items[0].depth, 0);
                 assert_eq!(*page, 1);
             }
-            other => panic!("expected Paragraph, got {:?}", other),
+            other => panic!("expected List, got {:?}", other),
         }
     }
 
+    #[test]
+    fn classify_nested_list_depth_from_x_offset() {
+        let line_at = |text: &str, x: f32, y: f32| {
+            let mut line = text_line(vec![span(text, x, y)]);
+            line.x = x;
+            line
+        };
+        let block = text_block(
+            vec![
+                line_at("1. First", 10.0, 100.0),
+                line_at("\u{2022} Nested", 28.0, 114.0),
+                line_at("continued", 36.0, 128.0),
+                line_at("2. Second", 10.5, 142.0),
+            ],
+            BlockType::ListItem,
+        );
+        let result = classify_blocks(vec![(1, vec![block])]);
+        let ClassifiedBlock::List { items, .. } = &result[0] else {
+            panic!("expected List, got {:?}", result[0]);
+        };
+        let summary: Vec<(&str, bool, usize)> = items
+            .iter()
+            .map(|i| (i.text.as_str(), i.ordered, i.depth))
+            .collect();
+        assert_eq!(
+            summary,
+            vec![
+                ("First", true, 0),
+                ("Nested continued", false, 1),
+                ("Second", true, 0),
+            ]
+        );
+    }
+
+    #[test]
+    fn analyze_keeps_wrapped_nested_list_in_one_block() {
+        let spans = vec![
+            span("1. First", 10.0, 700.0),
+            span("\u{2022} Nested item that", 28.0, 688.0),
+            span("wraps onto a second line", 36.0, 676.0),
+            span("\u{2022} Another nested", 28.0, 664.0),
+            span("2. Second", 10.0, 652.0),
+        ];
+        let pages = super::super::layout::analyze(vec![(1, spans)]).unwrap();
+        assert_eq!(pages[0].1.len(), 1);
+
+        let result = classify_blocks(pages);
+        let ClassifiedBlock::List { items, .. } = &result[0] else {
+            panic!("expected List, got {:?}", result[0]);
+        };
+        let summary: Vec<(&str, usize)> =
+            items.iter().map(|i| (i.text.as_str(), i.depth)).collect();
+        assert_eq!(
+            summary,
+            vec![
+                ("First", 0),
+                ("Nested item that wraps onto a second line", 1),
+                ("Another nested", 1),
+                ("Second", 0),
+            ]
+        );
+    }
+
     #[test]
     fn classify_table_block_with_valid_grid() {
         // Build a block whose spans form a clear 3-column, 3-row table.
@@ -1,32 +1,61 @@
 use crate::render::cleanup::cleanup_text;
-use crate::types::ContentBlock;
+use crate::types::{ContentBlock, ListItem};
 
 /// Render a section's content blocks as Markdown.
 pub fn render_section_content(blocks: &[ContentBlock]) -> String {
-    let mut output = String::new();
+    let output: String = blocks.iter().map(render_block).collect();
+    output.trim_end().to_string()
+}
 
-    for block in blocks {
-        match block {
-            ContentBlock::Paragraph(text) => {
-                output.push_str(&cleanup_text(text));
-                output.push_str("\n\n");
-            }
-            ContentBlock::Table { headers, rows } => {
-                output.push_str(&render_table(headers, rows));
-                output.push('\n');
-            }
-            ContentBlock::Image { id, alt_text } => {
-                let alt = alt_text.as_deref().unwrap_or("");
-                output.push_str(&format!("![{}](image:{})\n\n", alt, id));
-            }
-            ContentBlock::SubHeading { level, title } => {
-                let hashes = "#".repeat(level.as_u8() as usize);
-                output.push_str(&format!("{} {}\n\n", hashes, title));
-            }
+/// Render a single content block as Markdown, including the blank line (or
+/// newline, for tables and lists) that separates it from the next block.
+///
+/// Concatenating the rendered blocks and trimming trailing whitespace yields
+/// exactly [`render_section_content`].
+pub fn render_block(block: &ContentBlock) -> String {
+    match block {
+        ContentBlock::Paragraph(text) => format!("{}\n\n", cleanup_text(text)),
+        ContentBlock::Table { headers, rows } => format!("{}\n", render_table(headers, rows)),
+        ContentBlock::Image { id, alt_text } => {
+            let alt = alt_text.as_deref().unwrap_or("");
+            format!("![{}](image:{})\n\n", alt, id)
+        }
+        ContentBlock::SubHeading { level, title } => {
+            let hashes = "#".repeat(level.as_u8() as usize);
+            format!("{} {}\n\n", hashes, title)
         }
+        ContentBlock::List(items) => format!("{}\n", render_list(items)),
     }
+}
 
-    output.trim_end().to_string()
+/// Render a (possibly nested) Markdown list.
+///
+/// Each depth level is indented by four spaces, which nests correctly under
+/// both `- ` and `1. ` parents. Ordered items are numbered per run of
+/// siblings, restarting whenever a shallower item closes the run.
+pub fn render_list(items: &[ListItem]) -> String {
+    let mut out = String::new();
+    let mut counters: Vec<usize> = Vec::new();
+
+    for item in items {
+        counters.truncate(item.depth + 1);
+        counters.resize(item.depth + 1, 0);
+        counters[item.depth] += 1;
+
+        let indent = "    ".repeat(item.depth);
+        let marker = if item.ordered {
+            format!("{}.", counters[item.depth])
+        } else {
+            "-".to_string()
+        };
+        out.push_str(&format!(
+            "{}{} {}\n",
+            indent,
+            marker,
+            cleanup_text(&item.text)
+        ));
+    }
+    out
 }
 
 /// Render a Markdown table from headers and rows.
@@ -100,6 +129,43 @@ mod tests {
         assert!(md.contains("| Alice |"));
     }
 
+    #[test]
+    fn test_render_list_nested() {
+        let item = |text: &str, ordered, depth| ListItem {
+            text: text.to_string(),
+            ordered,
+            depth,
+        };
+        let items = vec![
+            item("First", true, 0),
+            item("Detail", false, 1),
+            item("More", false, 1),
+            item("Second", true, 0),
+            item("Step", true, 1),
+        ];
+        assert_eq!(
+            render_list(&items),
+            "1. First\n    - Detail\n    - More\n2. Second\n    1. Step\n"
+        );
+    }
+
+    #[test]
+    fn test_render_section_content_list_between_paragraphs() {
+        let blocks = vec![
+            ContentBlock::Paragraph("Intro.".to_string()),
+            ContentBlock::List(vec![ListItem {
+                text: "Only".to_string(),
+                ordered: false,
+                depth: 0,
+            }]),
+            ContentBlock::Paragraph("Outro.".to_string()),
+        ];
+        assert_eq!(
+            render_section_content(&blocks),
+            "Intro.\n\n- Only\n\nOutro."
+        );
+    }
+
     #[test]
     fn test_render_table_empty_headers() {
         let md = render_table(&[], &[]);
@@ -133,6 +199,27 @@ mod tests {
         assert_eq!(md, "![A photo](image:img-1)");
     }
 
+    #[test]
+    fn test_render_blocks_concatenate_to_section_content() {
+        let blocks = vec![
+            ContentBlock::SubHeading {
+                level: HeadingLevel::try_from(2).unwrap(),
+                title: "Intro".to_string(),
+            },
+            ContentBlock::Paragraph("Hello".to_string()),
+            ContentBlock::Table {
+                headers: vec!["A".to_string()],
+                rows: vec![vec!["1".to_string()]],
+            },
+            ContentBlock::Image {
+                id: "Im1".to_string(),
+                alt_text: None,
+            },
+        ];
+        let joined: String = blocks.iter().map(render_block).collect();
+        assert_eq!(joined.trim_end(), render_section_content(&blocks));
+    }
+
     #[test]
     fn test_render_section_content_mixed() {
         let blocks = vec![
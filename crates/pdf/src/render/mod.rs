@@ -1,2 +1,3 @@
 pub mod cleanup;
 pub mod markdown;
+pub mod toc;
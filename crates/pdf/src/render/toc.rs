@@ -0,0 +1,129 @@
+use crate::types::{DocumentTree, Section};
+
+/// Render the document tree as indented text (2 spaces per level).
+///
+/// Each line ends with a hint (`[s-1-0, pages 2-4, 1830 chars]`) giving the
+/// section ID to pass to `pdf read` and how much a read would return.
+pub fn render_toc_indented(tree: &DocumentTree) -> String {
+    render_lines(tree, "")
+}
+
+/// Render the document tree as a nested Markdown list with the same hints
+/// as [`render_toc_indented`].
+pub fn render_toc_markdown(tree: &DocumentTree) -> String {
+    render_lines(tree, "* ")
+}
+
+fn render_lines(tree: &DocumentTree, bullet: &str) -> String {
+    let mut lines = Vec::new();
+    for section in &tree.sections {
+        push_section(section, 0, bullet, &mut lines);
+    }
+    lines.join("\n")
+}
+
+fn push_section(section: &Section, depth: usize, bullet: &str, lines: &mut Vec<String>) {
+    lines.push(format!(
+        "{}{}{}  [{}]",
+        "  ".repeat(depth),
+        bullet,
+        section.title,
+        section_hint(section)
+    ));
+    for child in &section.children {
+        push_section(child, depth + 1, bullet, lines);
+    }
+}
+
+/// Section ID, page range, and character count for a TOC entry.
+fn section_hint(section: &Section) -> String {
+    let (first, last) = section.page_range;
+    let pages = if first == last {
+        format!("page {}", first)
+    } else {
+        format!("pages {}-{}", first, last)
+    };
+    format!("{}, {}, {} chars", section.id, pages, section.char_count)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tree::build_tree;
+    use crate::types::{ClassifiedBlock, DocumentMetadata};
+
+    fn metadata() -> DocumentMetadata {
+        DocumentMetadata {
+            title: None,
+            author: None,
+            page_count: 4,
+            creator: None,
+            language: None,
+        }
+    }
+
+    fn sample_tree() -> DocumentTree {
+        let blocks = vec![
+            ClassifiedBlock::Heading {
+                level: 1,
+                title: "Introduction".to_string(),
+                page: 1,
+            },
+            ClassifiedBlock::Paragraph {
+                text: "Hello there.".to_string(),
+                page: 1,
+            },
+            ClassifiedBlock::Heading {
+                level: 2,
+                title: "Scope".to_string(),
+                page: 2,
+            },
+            ClassifiedBlock::Paragraph {
+                text: "Details.".to_string(),
+                page: 3,
+            },
+            ClassifiedBlock::Heading {
+                level: 1,
+                title: "Results".to_string(),
+                page: 4,
+            },
+        ];
+        build_tree(&blocks, metadata())
+    }
+
+    #[test]
+    fn test_render_toc_indented() {
+        let tree = sample_tree();
+        let out = render_toc_indented(&tree);
+        let lines: Vec<&str> = out.lines().collect();
+
+        assert_eq!(lines.len(), 3);
+        assert!(lines[0].starts_with("Introduction  [s-1-0, "));
+        assert!(lines[1].starts_with("  Scope  [s-2-0, pages 2-3, "));
+        assert!(lines[2].starts_with("Results  [s-1-1, page 4, "));
+        assert!(lines[1].ends_with(&format!(
+            "{} chars]",
+            tree.sections[0].children[0].char_count
+        )));
+    }
+
+    #[test]
+    fn test_render_toc_markdown() {
+        let out = render_toc_markdown(&sample_tree());
+        let lines: Vec<&str> = out.lines().collect();
+
+        assert!(lines[0].starts_with("* Introduction  [s-1-0"));
+        assert!(lines[1].starts_with("  * Scope  [s-2-0"));
+        assert!(lines[2].starts_with("* Results  [s-1-1"));
+    }
+
+    #[test]
+    fn test_render_toc_page_fallback() {
+        // Without headings the tree has one section per page.
+        let tree = build_tree(&[], metadata());
+        let out = render_toc_indented(&tree);
+
+        assert_eq!(out.lines().count(), 4);
+        assert_eq!(out.lines().next(), Some("Page 1  [s-1-0, page 1, 0 chars]"));
+    }
+}
@@ -0,0 +1,144 @@
+//! Page thumbnail rendering.
+//!
+//! Rasterizing a PDF page requires a full rendering engine, which lopdf does
+//! not provide. Rendering is delegated to Pdfium and only compiled in when the
+//! `raster` feature is enabled; without it, [`render_page`] returns
+//! [`PdfError::Unsupported`] so callers can surface a clear message.
+
+use crate::types::{ImageData, ImageId};
+use crate::PdfError;
+
+/// Default bounding box (in pixels) for the longest side of a thumbnail.
+pub const DEFAULT_THUMBNAIL_SIZE: u32 = 256;
+
+/// Build the image ID used for a rendered page thumbnail.
+pub fn thumbnail_id(page: usize) -> ImageId {
+    ImageId::new(format!("page-{}", page))
+}
+
+/// Scale page dimensions (in points) so the longest side equals `max_size`
+/// pixels, preserving the aspect ratio. Each side is at least 1 pixel.
+pub fn thumbnail_dimensions(width: f32, height: f32, max_size: u32) -> (u32, u32) {
+    if width <= 0.0 || height <= 0.0 || max_size == 0 {
+        return (max_size.max(1), max_size.max(1));
+    }
+
+    let scale = max_size as f32 / width.max(height);
+    let w = (width * scale).round().max(1.0) as u32;
+    let h = (height * scale).round().max(1.0) as u32;
+    (w, h)
+}
+
+/// Render a 1-based page of the PDF to a PNG thumbnail whose longest side is
+/// at most `max_size` pixels.
+#[cfg(feature = "raster")]
+pub fn render_page(bytes: &[u8], page: usize, max_size: u32) -> Result<ImageData, PdfError> {
+    use std::io::Cursor;
+
+    use pdfium_render::prelude::{PdfRenderConfig, Pdfium, PdfiumError};
+
+    use crate::types::ImageFormat;
+
+    let pdfium = match Pdfium::bind_to_system_library() {
+        Ok(bindings) => Pdfium::new(bindings),
+        Err(PdfiumError::PdfiumLibraryBindingsAlreadyInitialized) => Pdfium::default(),
+        Err(e) => {
+            return Err(PdfError::Raster(format!(
+                "failed to load the Pdfium library: {}",
+                e
+            )))
+        }
+    };
+
+    let document = pdfium
+        .load_pdf_from_byte_slice(bytes, None)
+        .map_err(|e| PdfError::Parse(e.to_string()))?;
+
+    let page_count = document.pages().len() as usize;
+    if page == 0 || page > page_count {
+        return Err(PdfError::PageNotFound(page));
+    }
+
+    let pdf_page = document
+        .pages()
+        .get((page - 1) as _)
+        .map_err(|e| PdfError::Raster(e.to_string()))?;
+
+    let (width, height) =
+        thumbnail_dimensions(pdf_page.width().value, pdf_page.height().value, max_size);
+    let config = PdfRenderConfig::new()
+        .set_target_width(width as _)
+        .set_maximum_height(height as _);
+
+    let image = pdf_page
+        .render_with_config(&config)
+        .and_then(|bitmap| bitmap.as_image())
+        .map_err(|e| PdfError::Raster(e.to_string()))?;
+
+    let mut buf = Vec::new();
+    image
+        .write_to(&mut Cursor::new(&mut buf), image::ImageFormat::Png)
+        .map_err(|e| PdfError::Raster(e.to_string()))?;
+
+    Ok(ImageData {
+        id: thumbnail_id(page),
+        format: ImageFormat::Png,
+        bytes: buf,
+    })
+}
+
+/// Render a 1-based page of the PDF to a PNG thumbnail.
+///
+/// This build does not include a raster backend; rebuild with the `raster`
+/// feature to enable page rendering.
+#[cfg(not(feature = "raster"))]
+pub fn render_page(_bytes: &[u8], _page: usize, _max_size: u32) -> Result<ImageData, PdfError> {
+    Err(PdfError::Unsupported(
+        "page rendering requires the `raster` feature".to_string(),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_thumbnail_id() {
+        assert_eq!(thumbnail_id(3).as_str(), "page-3");
+    }
+
+    #[test]
+    fn test_thumbnail_dimensions_portrait() {
+        // US Letter: 612 x 792 points.
+        assert_eq!(thumbnail_dimensions(612.0, 792.0, 256), (198, 256));
+    }
+
+    #[test]
+    fn test_thumbnail_dimensions_landscape() {
+        assert_eq!(thumbnail_dimensions(792.0, 612.0, 256), (256, 198));
+    }
+
+    #[test]
+    fn test_thumbnail_dimensions_square() {
+        assert_eq!(thumbnail_dimensions(500.0, 500.0, 128), (128, 128));
+    }
+
+    #[test]
+    fn test_thumbnail_dimensions_extreme_aspect_ratio() {
+        // Very thin strip never collapses to zero pixels.
+        assert_eq!(thumbnail_dimensions(10_000.0, 1.0, 100), (100, 1));
+    }
+
+    #[test]
+    fn test_thumbnail_dimensions_degenerate_page() {
+        assert_eq!(thumbnail_dimensions(0.0, 792.0, 64), (64, 64));
+        assert_eq!(thumbnail_dimensions(612.0, 792.0, 0), (1, 1));
+    }
+
+    #[cfg(not(feature = "raster"))]
+    #[test]
+    fn test_render_page_without_raster_feature() {
+        let err = render_page(&[], 1, DEFAULT_THUMBNAIL_SIZE).unwrap_err();
+        assert!(matches!(err, PdfError::Unsupported(_)));
+    }
+}
@@ -1,8 +1,9 @@
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, HashSet};
 
+use crate::language::{detect_language, MAX_DETECTION_CHARS};
 use crate::types::{
-    ClassifiedBlock, DocumentMetadata, DocumentTree, HeadingLevel, IndexEntry, Section, SectionId,
-    SectionIndex,
+    ClassifiedBlock, DocumentMetadata, DocumentTree, HeadingLevel, IndexEntry, ListItem, Section,
+    SectionId, SectionIndex,
 };
 
 /// Internal builder that accumulates content before finalizing into a `Section`.
@@ -72,8 +73,10 @@ impl SectionBuilder {
     fn build(self) -> Section {
         let full_text = self.content_parts.join(" ");
         let preview = content_preview(&full_text, 100);
+        let language = detect_language(&full_text);
         Section {
             id: self.id,
+            slug: String::new(),
             level: HeadingLevel::try_from(self.level).unwrap_or(HeadingLevel::H1),
             title: self.title,
             children: self.children,
@@ -81,6 +84,7 @@ impl SectionBuilder {
             char_count: self.char_count,
             image_count: self.image_count,
             page_range: (self.first_page, self.last_page),
+            language,
         }
     }
 }
@@ -90,16 +94,19 @@ impl SectionBuilder {
 /// Headings create nested sections based on their level. Content blocks (Paragraph, Table, Image)
 /// are attached to the most recent heading's section. If no headings are found, one section per
 /// page is created as a fallback.
-pub fn build_tree(blocks: &[ClassifiedBlock], metadata: DocumentMetadata) -> DocumentTree {
+pub fn build_tree(blocks: &[ClassifiedBlock], mut metadata: DocumentMetadata) -> DocumentTree {
+    metadata.language = detect_language(&text_sample(blocks));
+
     let has_headings = blocks
         .iter()
         .any(|b| matches!(b, ClassifiedBlock::Heading { .. }));
 
-    let sections = if has_headings {
+    let mut sections = if has_headings {
         build_sections_from_headings(blocks)
     } else {
         build_fallback_sections(blocks, &metadata)
     };
+    assign_slugs(&mut sections, &mut HashSet::new());
 
     let index = build_section_index(&sections);
     let title = metadata
@@ -177,6 +184,11 @@ fn build_sections_from_headings(blocks: &[ClassifiedBlock]) -> Vec<Section> {
                     builder.append_image(*page);
                 }
             }
+            ClassifiedBlock::List { items, page } => {
+                if let Some((builder, _)) = stack.last_mut() {
+                    builder.append_text(&list_text(items), *page);
+                }
+            }
         }
     }
 
@@ -218,6 +230,7 @@ fn build_fallback_sections(
                 }
                 (*page, Some(parts.join("\n")), false)
             }
+            ClassifiedBlock::List { items, page } => (*page, Some(list_text(items)), false),
             ClassifiedBlock::Image { page, .. } => (*page, None, true),
             ClassifiedBlock::Heading { page, .. } => {
                 // Shouldn't happen in fallback path, but handle gracefully.
@@ -241,6 +254,7 @@ fn build_fallback_sections(
                 let page_num = p + 1;
                 Section {
                     id: SectionId::new(1, p),
+                    slug: String::new(),
                     level: HeadingLevel::H1,
                     title: format!("Page {}", page_num),
                     children: Vec::new(),
@@ -248,6 +262,7 @@ fn build_fallback_sections(
                     char_count: 0,
                     image_count: 0,
                     page_range: (page_num, page_num),
+                    language: None,
                 }
             })
             .collect();
@@ -260,8 +275,10 @@ fn build_fallback_sections(
             let full_text = texts.join(" ");
             let char_count = full_text.chars().count();
             let preview = content_preview(&full_text, 100);
+            let language = detect_language(&full_text);
             Section {
                 id: SectionId::new(1, idx),
+                slug: String::new(),
                 level: HeadingLevel::H1,
                 title: format!("Page {}", page),
                 children: Vec::new(),
@@ -269,11 +286,82 @@ fn build_fallback_sections(
                 char_count,
                 image_count: img_count,
                 page_range: (page, page),
+                language,
             }
         })
         .collect()
 }
 
+/// Turn a heading title into a URL-style slug: lowercase alphanumerics (in
+/// any script) separated by single dashes. Titles with no usable characters
+/// become `section`.
+pub fn slugify(title: &str) -> String {
+    let mut slug = String::new();
+    for c in title.chars() {
+        if c.is_alphanumeric() {
+            slug.extend(c.to_lowercase().filter(|l| l.is_alphanumeric()));
+        } else if !slug.is_empty() && !slug.ends_with('-') {
+            slug.push('-');
+        }
+    }
+    let slug = slug.trim_end_matches('-');
+    if slug.is_empty() {
+        "section".to_string()
+    } else {
+        slug.to_string()
+    }
+}
+
+/// Assign unique slugs in document order. Repeated titles get a numeric
+/// disambiguator (`overview`, `overview-2`, ...), so a slug only changes when
+/// an earlier section with the same title appears or disappears. A slug that
+/// reads as a positional ID (a title like "S 1 0") is disambiguated the same
+/// way, so it cannot shadow the section at that position.
+fn assign_slugs(sections: &mut [Section], taken: &mut HashSet<String>) {
+    for section in sections {
+        let base = slugify(&section.title);
+        let mut slug = base.clone();
+        let mut n = 2;
+        while SectionId::parse(&slug).is_ok() || !taken.insert(slug.clone()) {
+            slug = format!("{}-{}", base, n);
+            n += 1;
+        }
+        section.slug = slug;
+        assign_slugs(&mut section.children, taken);
+    }
+}
+
+/// Concatenate text blocks in document order until there is enough text for
+/// language detection.
+fn text_sample(blocks: &[ClassifiedBlock]) -> String {
+    let mut sample = String::new();
+    for block in blocks {
+        let text = match block {
+            ClassifiedBlock::Heading { title, .. } => title.clone(),
+            ClassifiedBlock::Paragraph { text, .. } => text.clone(),
+            ClassifiedBlock::List { items, .. } => list_text(items),
+            ClassifiedBlock::Table { .. } | ClassifiedBlock::Image { .. } => continue,
+        };
+        if !sample.is_empty() {
+            sample.push(' ');
+        }
+        sample.push_str(&text);
+        if sample.chars().count() >= MAX_DETECTION_CHARS {
+            break;
+        }
+    }
+    sample
+}
+
+/// Plain-text form of a list used for previews and character counts.
+fn list_text(items: &[ListItem]) -> String {
+    items
+        .iter()
+        .map(|item| item.text.as_str())
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
 /// Recursively sum char_count and image_count across all sections.
 fn sum_section_stats(sections: &[Section]) -> (usize, usize) {
     let mut chars = 0;
@@ -304,11 +392,13 @@ fn flatten_section(section: &Section, parent_path: &[String], entries: &mut Vec<
 
     entries.push(IndexEntry {
         id: section.id.clone(),
+        slug: section.slug.clone(),
         level: section.level,
         title: section.title.clone(),
         path: path.clone(),
         char_count: section.char_count,
         image_count: section.image_count,
+        language: section.language.clone(),
     });
 
     for child in &section.children {
@@ -348,6 +438,7 @@ mod tests {
             author: None,
             page_count: 1,
             creator: None,
+            language: None,
         }
     }
 
@@ -419,6 +510,7 @@ mod tests {
             author: None,
             page_count: 0,
             creator: None,
+            language: None,
         };
         let tree = build_tree(&[], meta);
         assert_eq!(tree.title, "Empty");
@@ -560,6 +652,7 @@ mod tests {
             author: None,
             page_count: 2,
             creator: None,
+            language: None,
         };
         let tree = build_tree(&blocks, meta);
 
@@ -853,4 +946,122 @@ mod tests {
         let tree = build_tree(&blocks, default_metadata());
         assert_eq!(tree.index.entries[0].char_count, 9);
     }
+
+    // --- slugs ---
+
+    #[test]
+    fn test_slugify() {
+        assert_eq!(slugify("Getting Started"), "getting-started");
+        assert_eq!(
+            slugify("  1.2 Results & Discussion! "),
+            "1-2-results-discussion"
+        );
+        assert_eq!(slugify("日本語"), "日本語");
+        assert_eq!(slugify("Über Größe — Ελληνικά"), "über-größe-ελληνικά");
+        assert_eq!(slugify("İstanbul"), "istanbul");
+        assert_eq!(slugify("★ — ★"), "section");
+    }
+
+    #[test]
+    fn test_slugs_are_unique_and_stable() {
+        let heading = |level, title: &str| ClassifiedBlock::Heading {
+            level,
+            title: title.to_string(),
+            page: 1,
+        };
+        let blocks = vec![
+            heading(1, "Overview"),
+            heading(2, "Details"),
+            heading(1, "Usage"),
+            heading(2, "Details"),
+        ];
+
+        let tree = build_tree(&blocks, default_metadata());
+        let slugs: Vec<&str> = tree.index.entries.iter().map(|e| e.slug.as_str()).collect();
+        assert_eq!(slugs, vec!["overview", "details", "usage", "details-2"]);
+
+        // Demoting a heading changes positional IDs but not slugs.
+        let blocks = vec![
+            heading(1, "Overview"),
+            heading(1, "Details"),
+            heading(1, "Usage"),
+            heading(2, "Details"),
+        ];
+        let tree = build_tree(&blocks, default_metadata());
+        let entry = tree
+            .index
+            .entries
+            .iter()
+            .find(|e| e.title == "Usage")
+            .unwrap();
+        assert_eq!(entry.slug, "usage");
+        assert_eq!(entry.id, SectionId::new(1, 2));
+
+        // A title that slugifies to a positional ID gets a disambiguator.
+        let blocks = vec![heading(1, "S 1 0"), heading(1, "S-1-0")];
+        let tree = build_tree(&blocks, default_metadata());
+        let slugs: Vec<&str> = tree.index.entries.iter().map(|e| e.slug.as_str()).collect();
+        assert_eq!(slugs, vec!["s-1-0-2", "s-1-0-3"]);
+        assert!(!SectionId::parse_or_slug(slugs[0]).unwrap().is_positional());
+    }
+
+    #[test]
+    fn test_language_detection_per_section() {
+        let blocks = vec![
+            ClassifiedBlock::Heading {
+                level: 1,
+                title: "Introduction".to_string(),
+                page: 1,
+            },
+            ClassifiedBlock::Paragraph {
+                text: "This report describes the results of the annual survey and explains \
+                       how the data was collected from every region of the country."
+                    .to_string(),
+                page: 1,
+            },
+            ClassifiedBlock::Paragraph {
+                text: "Most respondents said that their working conditions had improved \
+                       over the last year, although many still reported long hours."
+                    .to_string(),
+                page: 1,
+            },
+            ClassifiedBlock::Heading {
+                level: 1,
+                title: "Resumen".to_string(),
+                page: 2,
+            },
+            ClassifiedBlock::Paragraph {
+                text: "Este informe describe los resultados de la encuesta anual y explica \
+                       cómo se recopilaron los datos en todas las regiones del país."
+                    .to_string(),
+                page: 2,
+            },
+            ClassifiedBlock::Heading {
+                level: 1,
+                title: "Notes".to_string(),
+                page: 3,
+            },
+        ];
+
+        let tree = build_tree(&blocks, default_metadata());
+
+        assert_eq!(tree.sections[0].language.as_deref(), Some("eng"));
+        assert_eq!(tree.sections[1].language.as_deref(), Some("spa"));
+        assert_eq!(tree.sections[2].language, None);
+        assert_eq!(tree.index.entries[1].language.as_deref(), Some("spa"));
+    }
+
+    #[test]
+    fn test_language_detection_document() {
+        let blocks = vec![ClassifiedBlock::Paragraph {
+            text: "This report describes the results of the annual survey and explains \
+                   how the data was collected from every region of the country."
+                .to_string(),
+            page: 1,
+        }];
+
+        let tree = build_tree(&blocks, default_metadata());
+
+        assert_eq!(tree.metadata.language.as_deref(), Some("eng"));
+    }
 }
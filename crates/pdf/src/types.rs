@@ -22,6 +22,28 @@ impl SectionId {
         Ok(SectionId(s.to_string()))
     }
 
+    /// Parse either a positional ID (`s-1-0`) or a stable title slug
+    /// (`introduction`, `methods-2`) as produced by [`crate::tree::slugify`].
+    pub fn parse_or_slug(s: &str) -> Result<Self, InvalidSectionId> {
+        Self::parse(s).or_else(|_| {
+            let is_slug = !s.is_empty()
+                && !s.starts_with('-')
+                && !s.ends_with('-')
+                && s.chars()
+                    .all(|c| c == '-' || (c.is_alphanumeric() && !c.is_uppercase()));
+            if is_slug {
+                Ok(SectionId(s.to_string()))
+            } else {
+                Err(InvalidSectionId)
+            }
+        })
+    }
+
+    /// Whether this is a positional `s-{depth}-{index}` ID rather than a slug.
+    pub fn is_positional(&self) -> bool {
+        Self::parse(&self.0).is_ok()
+    }
+
     pub fn as_str(&self) -> &str {
         &self.0
     }
@@ -116,6 +138,8 @@ pub struct DocumentTree {
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct Section {
     pub id: SectionId,
+    /// Stable, title-derived ID that survives changes in heading detection.
+    pub slug: String,
     pub level: HeadingLevel,
     pub title: String,
     pub children: Vec<Section>,
@@ -123,6 +147,9 @@ pub struct Section {
     pub char_count: usize,
     pub image_count: usize,
     pub page_range: (usize, usize),
+    /// Language of the section's own text (ISO 639-3), when it can be detected
+    #[serde(default)]
+    pub language: Option<String>,
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -133,11 +160,14 @@ pub struct SectionIndex {
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct IndexEntry {
     pub id: SectionId,
+    pub slug: String,
     pub level: HeadingLevel,
     pub title: String,
     pub path: Vec<String>,
     pub char_count: usize,
     pub image_count: usize,
+    #[serde(default)]
+    pub language: Option<String>,
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -176,6 +206,27 @@ pub struct DocumentMetadata {
     pub author: Option<String>,
     pub page_count: usize,
     pub creator: Option<String>,
+    /// Dominant language of the text (ISO 639-3), when it can be detected
+    #[serde(default)]
+    pub language: Option<String>,
+}
+
+/// Content counts for a single page.
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq, Eq)]
+pub struct PageStats {
+    /// 1-based page number.
+    pub page: usize,
+    pub word_count: usize,
+    pub image_count: usize,
+    pub heading_count: usize,
+}
+
+/// Document metadata together with per-page statistics.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct DocumentStats {
+    #[serde(flatten)]
+    pub metadata: DocumentMetadata,
+    pub pages: Vec<PageStats>,
 }
 
 /// Content block types for rendering.
@@ -194,6 +245,28 @@ pub enum ContentBlock {
         level: HeadingLevel,
         title: String,
     },
+    List(Vec<ListItem>),
+}
+
+/// A single entry of a (possibly nested) list, in document order.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ListItem {
+    pub text: String,
+    /// Numbered/lettered (`1.`, `a)`) rather than bulleted.
+    pub ordered: bool,
+    /// Nesting depth, 0 for top-level items.
+    pub depth: usize,
+}
+
+/// One rendered block of a section, yielded by
+/// [`ParsedDocument::iter_section_blocks`](crate::ParsedDocument::iter_section_blocks).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SectionChunk {
+    pub block: ContentBlock,
+    /// Markdown for this block, including its trailing separator.
+    pub markdown: String,
+    /// 1-based page the block starts on.
+    pub page: usize,
 }
 
 /// Classified block output from the table detection pipeline.
@@ -217,6 +290,10 @@ pub enum ClassifiedBlock {
         id: String,
         page: usize,
     },
+    List {
+        items: Vec<ListItem>,
+        page: usize,
+    },
 }
 
 impl ClassifiedBlock {
@@ -225,17 +302,22 @@ impl ClassifiedBlock {
             Self::Heading { page, .. }
             | Self::Paragraph { page, .. }
             | Self::Table { page, .. }
-            | Self::Image { page, .. } => *page,
+            | Self::Image { page, .. }
+            | Self::List { page, .. } => *page,
         }
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
 pub enum PeekPosition {
     Beginning,
     Middle,
     Ending,
     Random,
+    /// Start at the text that begins on this 1-based page.
+    Page(u32),
+    /// Start this far (0–100) through the text.
+    Percent(f32),
 }
 
 impl fmt::Display for PeekPosition {
@@ -245,6 +327,8 @@ impl fmt::Display for PeekPosition {
             PeekPosition::Middle => write!(f, "middle"),
             PeekPosition::Ending => write!(f, "ending"),
             PeekPosition::Random => write!(f, "random"),
+            PeekPosition::Page(page) => write!(f, "page:{}", page),
+            PeekPosition::Percent(pct) => write!(f, "{}%", pct),
         }
     }
 }
@@ -258,7 +342,23 @@ impl FromStr for PeekPosition {
             "middle" => Ok(PeekPosition::Middle),
             "ending" => Ok(PeekPosition::Ending),
             "random" => Ok(PeekPosition::Random),
-            _ => Err(InvalidPeekPosition),
+            other => {
+                if let Some(page) = other.strip_prefix("page:") {
+                    let page: u32 = page.trim().parse().map_err(|_| InvalidPeekPosition)?;
+                    if page == 0 {
+                        return Err(InvalidPeekPosition);
+                    }
+                    Ok(PeekPosition::Page(page))
+                } else if let Some(pct) = other.strip_suffix('%') {
+                    let pct: f32 = pct.trim().parse().map_err(|_| InvalidPeekPosition)?;
+                    if !(0.0..=100.0).contains(&pct) {
+                        return Err(InvalidPeekPosition);
+                    }
+                    Ok(PeekPosition::Percent(pct))
+                } else {
+                    Err(InvalidPeekPosition)
+                }
+            }
         }
     }
 }
@@ -277,11 +377,13 @@ pub struct PeekContent {
 pub struct InvalidHeadingLevel;
 
 #[derive(Debug, Error)]
-#[error("Invalid section ID format (expected 's-{{depth}}-{{index}}')")]
+#[error("Invalid section ID format (expected 's-{{depth}}-{{index}}' or a section slug)")]
 pub struct InvalidSectionId;
 
 #[derive(Debug, PartialEq, Eq, Error)]
-#[error("Invalid peek position (expected 'beginning', 'middle', 'ending', or 'random')")]
+#[error(
+    "Invalid peek position (expected 'beginning', 'middle', 'ending', 'random', 'page:N', or 'N%')"
+)]
 pub struct InvalidPeekPosition;
 
 #[cfg(test)]
@@ -325,6 +427,23 @@ mod tests {
         assert!(SectionId::parse("x-1-0").is_err());
     }
 
+    #[test]
+    fn test_section_id_parse_or_slug() {
+        assert!(SectionId::parse_or_slug("s-1-0").unwrap().is_positional());
+        let slug = SectionId::parse_or_slug("getting-started-2").unwrap();
+        assert_eq!(slug.as_str(), "getting-started-2");
+        assert!(!slug.is_positional());
+        assert!(SectionId::parse_or_slug("").is_err());
+        assert!(SectionId::parse_or_slug("Has Spaces").is_err());
+        assert!(SectionId::parse_or_slug("-leading").is_err());
+        assert_eq!(
+            SectionId::parse_or_slug("über-größe").unwrap().as_str(),
+            "über-größe"
+        );
+        assert!(SectionId::parse_or_slug("日本語").is_ok());
+        assert!(SectionId::parse_or_slug("Über").is_err());
+    }
+
     #[test]
     fn test_image_format_display() {
         assert_eq!(format!("{}", ImageFormat::Jpeg), "jpeg");
@@ -340,6 +459,18 @@ mod tests {
         assert_eq!("middle".parse::<PeekPosition>(), Ok(PeekPosition::Middle));
         assert_eq!("ending".parse::<PeekPosition>(), Ok(PeekPosition::Ending));
         assert_eq!("random".parse::<PeekPosition>(), Ok(PeekPosition::Random));
+        assert_eq!(
+            "page:140".parse::<PeekPosition>(),
+            Ok(PeekPosition::Page(140))
+        );
+        assert_eq!(
+            "70%".parse::<PeekPosition>(),
+            Ok(PeekPosition::Percent(70.0))
+        );
+        assert_eq!(
+            "12.5%".parse::<PeekPosition>(),
+            Ok(PeekPosition::Percent(12.5))
+        );
     }
 
     #[test]
@@ -354,6 +485,9 @@ mod tests {
     #[test]
     fn test_peek_position_invalid() {
         assert!("invalid".parse::<PeekPosition>().is_err());
+        assert!("page:0".parse::<PeekPosition>().is_err());
+        assert!("page:x".parse::<PeekPosition>().is_err());
+        assert!("120%".parse::<PeekPosition>().is_err());
         assert!("".parse::<PeekPosition>().is_err());
     }
 
@@ -364,6 +498,8 @@ mod tests {
             PeekPosition::Middle,
             PeekPosition::Ending,
             PeekPosition::Random,
+            PeekPosition::Page(3),
+            PeekPosition::Percent(42.5),
         ] {
             let s = format!("{}", pos);
             assert_eq!(s.parse::<PeekPosition>().unwrap(), *pos);